@@ -0,0 +1,83 @@
+use crate::{Color32, Rgba, linear_u8_from_linear_f32};
+
+/// How the colors handed to the backend are encoded, and thus how the backend should blend them.
+///
+/// egui works with sRGB colors ([`Color32`]) everywhere.
+/// By default these are handed to the backend as-is, which means
+/// gradients and alpha blending happen in the non-linear sRGB space.
+/// This is fast and portable, but differs from what most design tools do.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ColorSpace {
+    /// sRGB-encoded colors, blended in sRGB space.
+    #[default]
+    Srgb,
+
+    /// Linear sRGB colors, for backends that blend in linear space (e.g. into an sRGB framebuffer).
+    ///
+    /// Note that storing linear colors in 8 bits loses some precision in dark colors.
+    LinearSrgb,
+
+    /// sRGB-encoded colors using the Display-P3 primaries, for wide-gamut displays.
+    ///
+    /// The colors look the same as [`Self::Srgb`] on a display that is configured for Display-P3.
+    DisplayP3,
+}
+
+impl ColorSpace {
+    pub const ALL: [Self; 3] = [Self::Srgb, Self::LinearSrgb, Self::DisplayP3];
+
+    /// Convert a (premultiplied) sRGB color into this color space.
+    ///
+    /// ```
+    /// use ecolor::{Color32, ColorSpace};
+    /// assert_eq!(ColorSpace::Srgb.from_srgb(Color32::GRAY), Color32::GRAY);
+    /// assert!(ColorSpace::LinearSrgb.from_srgb(Color32::GRAY).r() < Color32::GRAY.r());
+    /// ```
+    pub fn from_srgb(self, color: Color32) -> Color32 {
+        match self {
+            Self::Srgb => color,
+            Self::LinearSrgb => {
+                let [r, g, b, a] = Rgba::from(color).to_array();
+                Color32::from_rgba_premultiplied(
+                    linear_u8_from_linear_f32(r),
+                    linear_u8_from_linear_f32(g),
+                    linear_u8_from_linear_f32(b),
+                    linear_u8_from_linear_f32(a),
+                )
+            }
+            Self::DisplayP3 => {
+                // Linear sRGB to linear Display-P3:
+                const MATRIX: [[f32; 3]; 3] = [
+                    [0.822_462, 0.177_538, 0.0],
+                    [0.033_194, 0.966_806, 0.0],
+                    [0.017_083, 0.072_397, 0.910_520],
+                ];
+                let [r, g, b, a] = Rgba::from(color).to_array();
+                // Premultiplied alpha is fine, since the transform is linear.
+                let [r, g, b] = MATRIX.map(|row| row[0] * r + row[1] * g + row[2] * b);
+                Rgba::from_rgba_premultiplied(r, g, b, a).into()
+            }
+        }
+    }
+}
+
+#[test]
+fn test_color_space() {
+    for color_space in ColorSpace::ALL {
+        // Black, white and transparency are unaffected:
+        for color in [Color32::BLACK, Color32::WHITE, Color32::TRANSPARENT] {
+            let converted = color_space.from_srgb(color);
+            for (a, b) in converted.to_array().into_iter().zip(color.to_array()) {
+                assert!(
+                    a.abs_diff(b) <= 1,
+                    "{color_space:?}: {color:?} -> {converted:?}"
+                );
+            }
+        }
+    }
+
+    // Pure sRGB red is not fully saturated in the wider Display-P3 gamut:
+    let red = ColorSpace::DisplayP3.from_srgb(Color32::RED);
+    assert!(red.r() < 255 && 0 < red.g(), "{red:?}");
+}
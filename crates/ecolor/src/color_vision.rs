@@ -0,0 +1,100 @@
+use crate::{Color32, Rgba};
+
+/// A kind of color vision deficiency ("color blindness"), used to simulate how colors are perceived.
+///
+/// The simulation uses the model by Machado, Oliveira and Fernandes (2009), at full severity.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ColorVisionDeficiency {
+    /// No red cones. About 1% of men.
+    Protanopia,
+
+    /// No green cones. The most common kind, about 1% of men (and many more with the milder deuteranomaly).
+    Deuteranopia,
+
+    /// No blue cones. Very rare.
+    Tritanopia,
+
+    /// No color vision at all, only brightness.
+    Achromatopsia,
+}
+
+impl ColorVisionDeficiency {
+    pub const ALL: [Self; 4] = [
+        Self::Protanopia,
+        Self::Deuteranopia,
+        Self::Tritanopia,
+        Self::Achromatopsia,
+    ];
+
+    /// Applied to linear RGB.
+    fn matrix(self) -> [[f32; 3]; 3] {
+        match self {
+            Self::Protanopia => [
+                [0.152_286, 1.052_583, -0.204_868],
+                [0.114_503, 0.786_281, 0.099_216],
+                [-0.003_882, -0.048_116, 1.051_998],
+            ],
+            Self::Deuteranopia => [
+                [0.367_322, 0.860_646, -0.227_968],
+                [0.280_085, 0.672_501, 0.047_413],
+                [-0.011_820, 0.042_940, 0.968_881],
+            ],
+            Self::Tritanopia => [
+                [1.255_528, -0.076_749, -0.178_779],
+                [-0.078_411, 0.930_809, 0.147_602],
+                [0.004_733, 0.691_367, 0.303_900],
+            ],
+            Self::Achromatopsia => {
+                // Relative luminance:
+                let luminance = [0.2126, 0.7152, 0.0722];
+                [luminance; 3]
+            }
+        }
+    }
+
+    /// Approximately how the given color looks to someone with this deficiency.
+    ///
+    /// ```
+    /// use ecolor::{Color32, ColorVisionDeficiency};
+    /// let gray = ColorVisionDeficiency::Achromatopsia.simulate(Color32::RED);
+    /// assert_eq!(gray.r(), gray.g());
+    /// assert_eq!(gray.g(), gray.b());
+    /// ```
+    pub fn simulate(self, color: Color32) -> Color32 {
+        let [r, g, b, a] = Rgba::from(color).to_array();
+        // Premultiplied alpha is fine, since the transform is linear.
+        // Additive colors (zero alpha) have no alpha to clamp against:
+        let max = if a == 0.0 { 1.0 } else { a };
+        let [r, g, b] = self
+            .matrix()
+            .map(|row| (row[0] * r + row[1] * g + row[2] * b).clamp(0.0, max));
+        Rgba::from_rgba_premultiplied(r, g, b, a).into()
+    }
+}
+
+#[test]
+fn test_simulate_color_vision_deficiency() {
+    for deficiency in ColorVisionDeficiency::ALL {
+        // Grays and transparency are unaffected:
+        for color in [
+            Color32::BLACK,
+            Color32::WHITE,
+            Color32::GRAY,
+            Color32::TRANSPARENT,
+        ] {
+            let simulated = deficiency.simulate(color);
+            for (a, b) in simulated.to_array().into_iter().zip(color.to_array()) {
+                assert!(
+                    a.abs_diff(b) <= 1,
+                    "{deficiency:?}: {color:?} -> {simulated:?}"
+                );
+            }
+        }
+    }
+
+    // Red and green are hard to tell apart with deuteranopia:
+    let red = ColorVisionDeficiency::Deuteranopia.simulate(Color32::from_rgb(200, 50, 50));
+    let green = ColorVisionDeficiency::Deuteranopia.simulate(Color32::from_rgb(50, 150, 50));
+    assert!(red.r().abs_diff(green.r()) < 50, "{red:?} vs {green:?}");
+}
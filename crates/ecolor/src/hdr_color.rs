@@ -0,0 +1,55 @@
+use crate::{Color32, Rgba};
+
+/// A [`Color32`] with an intensity that may go above 1.0, for glowing (emissive) colors.
+///
+/// An intensity of 1.0 is the normal color.
+/// Anything above that only makes a difference when painting into an HDR target,
+/// otherwise the color is clamped back to [`Self::color`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct HdrColor {
+    /// The color at intensity 1.0.
+    pub color: Color32,
+
+    /// How much to brighten [`Self::color`] (in linear space).
+    pub intensity: f32,
+}
+
+impl HdrColor {
+    #[inline]
+    pub const fn new(color: Color32, intensity: f32) -> Self {
+        Self { color, intensity }
+    }
+
+    /// Is this brighter than what fits in a [`Color32`]?
+    #[inline]
+    pub fn is_overbright(&self) -> bool {
+        1.0 < self.intensity
+    }
+
+    /// The linear, premultiplied color, with components that may go above 1.0.
+    ///
+    /// ```
+    /// use ecolor::{Color32, HdrColor};
+    /// let [r, _, _, a] = HdrColor::new(Color32::WHITE, 4.0).to_rgba().to_array();
+    /// assert!((r - 4.0).abs() < 1e-3);
+    /// assert_eq!(a, 1.0);
+    /// ```
+    #[inline]
+    pub fn to_rgba(&self) -> Rgba {
+        let [r, g, b, a] = Rgba::from(self.color).to_array();
+        Rgba::from_rgba_premultiplied(
+            r * self.intensity,
+            g * self.intensity,
+            b * self.intensity,
+            a,
+        )
+    }
+}
+
+impl From<Color32> for HdrColor {
+    #[inline]
+    fn from(color: Color32) -> Self {
+        Self::new(color, 1.0)
+    }
+}
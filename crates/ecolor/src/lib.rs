@@ -27,6 +27,15 @@ mod cint_impl;
 mod color32;
 pub use color32::*;
 
+mod color_space;
+pub use color_space::*;
+
+mod color_vision;
+pub use color_vision::*;
+
+mod hdr_color;
+pub use hdr_color::*;
+
 mod hsva_gamma;
 pub use hsva_gamma::*;
 
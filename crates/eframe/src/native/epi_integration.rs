@@ -136,7 +136,7 @@ pub fn create_storage(_app_name: &str) -> Option<Box<dyn epi::Storage>> {
     None
 }
 
-#[expect(clippy::unnecessary_wraps)]
+#[cfg_attr(feature = "persistence", expect(clippy::unnecessary_wraps))]
 pub fn create_storage_with_file(_file: impl Into<PathBuf>) -> Option<Box<dyn epi::Storage>> {
     #[cfg(feature = "persistence")]
     return Some(Box::new(
@@ -165,11 +165,20 @@ pub struct EpiIntegration {
     can_drag_window: bool,
     #[cfg(feature = "persistence")]
     persist_window: bool,
+
+    /// Set if [`crate::NativeOptions::persistence_path`] is set,
+    /// which does nothing without the `persistence` feature.
+    #[cfg(not(feature = "persistence"))]
+    has_persistence_path: bool,
+
     app_icon_setter: super::app_icon::AppTitleIconSetter,
 }
 
 impl EpiIntegration {
-    #[expect(clippy::too_many_arguments)]
+    #[cfg_attr(
+        all(feature = "glow", feature = "wgpu"),
+        expect(clippy::too_many_arguments)
+    )]
     pub fn new(
         egui_ctx: egui::Context,
         window: &winit::window::Window,
@@ -219,6 +228,8 @@ impl EpiIntegration {
             can_drag_window: false,
             #[cfg(feature = "persistence")]
             persist_window: native_options.persist_window,
+            #[cfg(not(feature = "persistence"))]
+            has_persistence_path: native_options.persistence_path.is_some(),
             app_icon_setter,
             beginning: Instant::now(),
             is_first_frame: true,
@@ -326,8 +337,19 @@ impl EpiIntegration {
         }
     }
 
-    #[allow(clippy::unused_self, clippy::allow_attributes)]
+    #[allow(
+        clippy::unused_self,
+        clippy::needless_pass_by_ref_mut,
+        clippy::allow_attributes
+    )]
     pub fn save(&mut self, _app: &mut dyn epi::App, _window: Option<&winit::window::Window>) {
+        #[cfg(not(feature = "persistence"))]
+        if self.has_persistence_path {
+            self.egui_ctx.report_misuse(
+                "NativeOptions::persistence_path is set, but eframe was compiled without the `persistence` feature, so nothing is saved",
+            );
+        }
+
         #[cfg(feature = "persistence")]
         if let Some(storage) = self.frame.storage_mut() {
             profiling::function_scope!();
@@ -552,6 +552,7 @@ impl GlowWinitRunning<'_> {
             self.integration.pre_update();
 
             raw_input.time = Some(self.integration.beginning.elapsed().as_secs_f64());
+            raw_input.capabilities.paint_callbacks = true;
             raw_input.viewports = glutin
                 .viewports
                 .iter()
@@ -626,6 +627,8 @@ impl GlowWinitRunning<'_> {
             shapes,
             pixels_per_point,
             viewport_output,
+            unchanged_rects: _, // We always repaint everything
+            color_space: _,     // We only support sRGB
         } = full_output;
 
         glutin.remove_viewports_not_in(&viewport_output);
@@ -1293,6 +1296,8 @@ impl GlutinWindowContext {
                 viewport_ui_cb,
                 mut commands,
                 repaint_delay: _, // ignored - we listened to the repaint callback instead
+                repaint_deadline: _,
+                repaint_reasons: _,
             },
         ) in viewport_output.clone()
         {
@@ -1458,6 +1463,7 @@ fn render_immediate_viewport(
             .map(|(id, viewport)| (*id, viewport.info.clone()))
             .collect();
         raw_input.time = Some(beginning.elapsed().as_secs_f64());
+        raw_input.capabilities.paint_callbacks = true;
         raw_input
     };
 
@@ -1471,6 +1477,8 @@ fn render_immediate_viewport(
         shapes,
         pixels_per_point,
         viewport_output,
+        unchanged_rects: _, // We always repaint everything
+        color_space: _,     // We only support sRGB
     } = egui_ctx.run(input, |ctx| {
         viewport_ui_cb(ctx);
     });
@@ -604,6 +604,7 @@ impl WgpuWinitRunning<'_> {
             integration.pre_update();
 
             raw_input.time = Some(integration.beginning.elapsed().as_secs_f64());
+            raw_input.capabilities.paint_callbacks = true;
             raw_input.viewports = viewports
                 .iter()
                 .map(|(id, viewport)| (*id, viewport.info.clone()))
@@ -638,6 +639,8 @@ impl WgpuWinitRunning<'_> {
             shapes,
             pixels_per_point,
             viewport_output,
+            unchanged_rects: _, // We always repaint everything
+            color_space: _,     // We only support sRGB
         } = full_output;
 
         remove_viewports_not_in(viewports, painter, viewport_from_window, &viewport_output);
@@ -965,6 +968,7 @@ fn render_immediate_viewport(
             .map(|(id, viewport)| (*id, viewport.info.clone()))
             .collect();
         input.time = Some(beginning.elapsed().as_secs_f64());
+        input.capabilities.paint_callbacks = true;
         input
     };
 
@@ -980,6 +984,8 @@ fn render_immediate_viewport(
         shapes,
         pixels_per_point,
         viewport_output,
+        unchanged_rects: _, // We always repaint everything
+        color_space: _,     // We only support sRGB
     } = egui_ctx.run(input, |ctx| {
         viewport_ui_cb(ctx);
     });
@@ -1064,6 +1070,8 @@ fn handle_viewport_output(
             viewport_ui_cb,
             mut commands,
             repaint_delay: _, // ignored - we listened to the repaint callback instead
+            repaint_deadline: _,
+            repaint_reasons: _,
         },
     ) in viewport_output.clone()
     {
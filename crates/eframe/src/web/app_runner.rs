@@ -120,6 +120,8 @@ impl AppRunner {
         };
 
         runner.input.raw.max_texture_side = Some(runner.painter.max_texture_side());
+        runner.input.raw.capabilities.paint_callbacks = true;
+        runner.input.raw.capabilities.clipboard_images = super::supports_clipboard_images();
         runner
             .input
             .raw
@@ -239,6 +241,8 @@ impl AppRunner {
             shapes,
             pixels_per_point,
             viewport_output,
+            unchanged_rects: _, // We always repaint everything
+            color_space: _,     // We only support sRGB
         } = full_output;
 
         if viewport_output.len() > 1 {
@@ -303,7 +307,7 @@ impl AppRunner {
         self.frame.info.cpu_usage = Some(cpu_usage_seconds);
     }
 
-    fn handle_platform_output(&self, platform_output: egui::PlatformOutput) {
+    fn handle_platform_output(&mut self, platform_output: egui::PlatformOutput) {
         #![allow(deprecated)]
 
         #[cfg(feature = "web_screen_reader")]
@@ -336,6 +340,17 @@ impl AppRunner {
                 egui::OutputCommand::OpenUrl(open_url) => {
                     super::open_url(&open_url.url, open_url.new_tab);
                 }
+                egui::OutputCommand::SetTrayIcon(_) | egui::OutputCommand::RemoveTrayIcon => {
+                    // There is no system tray on the web.
+                }
+                egui::OutputCommand::FileDialog(request) => {
+                    // Let egui show its own fallback dialog:
+                    self.input.raw.events.push(egui::Event::FileDialogResult {
+                        id: request.id,
+                        result: egui::FileDialogResult::Unsupported,
+                    });
+                    self.egui_ctx.request_repaint();
+                }
             }
         }
 
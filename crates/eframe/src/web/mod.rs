@@ -210,6 +210,14 @@ fn set_clipboard_text(s: &str) {
     }
 }
 
+/// Can [`set_clipboard_image`] work, i.e. are we in a secure context in a browser with `ClipboardItem`?
+fn supports_clipboard_images() -> bool {
+    web_sys::window().is_some_and(|window| {
+        window.is_secure_context()
+            && js_sys::Reflect::has(&window, &JsValue::from_str("ClipboardItem")).unwrap_or(false)
+    })
+}
+
 /// Set the clipboard image.
 fn set_clipboard_image(image: &egui::ColorImage) {
     if let Some(window) = web_sys::window() {
@@ -34,10 +34,7 @@ impl Callback {
         rect: epaint::emath::Rect,
         callback: impl CallbackTrait + 'static,
     ) -> epaint::PaintCallback {
-        epaint::PaintCallback {
-            rect,
-            callback: std::sync::Arc::new(Self(Box::new(callback))),
-        }
+        epaint::PaintCallback::new(rect, std::sync::Arc::new(Self(Box::new(callback))))
     }
 }
 
@@ -456,7 +453,9 @@ impl Renderer {
 
                 if rect.width == 0 || rect.height == 0 {
                     // Skip rendering zero-sized clip areas.
-                    if let Primitive::Mesh(_) = primitive {
+                    if let Primitive::Mesh(_) | Primitive::Instanced(_) | Primitive::Hdr(_) =
+                        primitive
+                    {
                         // If this is a mesh, we need to advance the index and vertex buffer iterators:
                         index_buffer_slices.next().unwrap();
                         vertex_buffer_slices.next().unwrap();
@@ -469,27 +468,35 @@ impl Renderer {
 
             match primitive {
                 Primitive::Mesh(mesh) => {
-                    let index_buffer_slice = index_buffer_slices.next().unwrap();
-                    let vertex_buffer_slice = vertex_buffer_slices.next().unwrap();
-
-                    if let Some(Texture { bind_group, .. }) = self.textures.get(&mesh.texture_id) {
-                        render_pass.set_bind_group(1, bind_group, &[]);
-                        render_pass.set_index_buffer(
-                            self.index_buffer.buffer.slice(
-                                index_buffer_slice.start as u64..index_buffer_slice.end as u64,
-                            ),
-                            wgpu::IndexFormat::Uint32,
-                        );
-                        render_pass.set_vertex_buffer(
-                            0,
-                            self.vertex_buffer.buffer.slice(
-                                vertex_buffer_slice.start as u64..vertex_buffer_slice.end as u64,
-                            ),
-                        );
-                        render_pass.draw_indexed(0..mesh.indices.len() as u32, 0, 0..1);
-                    } else {
-                        log::warn!("Missing texture: {:?}", mesh.texture_id);
-                    }
+                    self.paint_mesh(
+                        render_pass,
+                        mesh.texture_id,
+                        index_buffer_slices.next().unwrap(),
+                        vertex_buffer_slices.next().unwrap(),
+                    );
+                }
+                Primitive::Instanced(instanced) => {
+                    // We don't set `BackendCapabilities::instancing`, so this should not happen.
+                    // If it does, it was uploaded as one mesh by `update_buffers`.
+                    self.paint_mesh(
+                        render_pass,
+                        instanced.mesh.texture_id,
+                        index_buffer_slices.next().unwrap(),
+                        vertex_buffer_slices.next().unwrap(),
+                    );
+                }
+                Primitive::Hdr(hdr) => {
+                    // We don't set `BackendCapabilities::hdr`, so this should not happen.
+                    self.paint_mesh(
+                        render_pass,
+                        hdr.mesh.texture_id,
+                        index_buffer_slices.next().unwrap(),
+                        vertex_buffer_slices.next().unwrap(),
+                    );
+                }
+                Primitive::Composite(_) => {
+                    // We don't set `BackendCapabilities::composite`, so this should not happen.
+                    log::warn!("Composite primitives are not supported by egui-wgpu");
                 }
                 Primitive::Callback(callback) => {
                     let Some(cbfn) = callback.callback.downcast_ref::<Callback>() else {
@@ -502,6 +509,8 @@ impl Renderer {
                         clip_rect: *clip_rect,
                         pixels_per_point,
                         screen_size_px: size_in_pixels,
+                        scaling: callback.scaling,
+                        user_data: callback.user_data.clone(),
                     };
 
                     let viewport_px = info.viewport_in_pixels();
@@ -536,6 +545,35 @@ impl Renderer {
         render_pass.set_scissor_rect(0, 0, size_in_pixels[0], size_in_pixels[1]);
     }
 
+    /// Paint a mesh uploaded by [`Self::update_buffers`].
+    fn paint_mesh(
+        &self,
+        render_pass: &mut wgpu::RenderPass<'static>,
+        texture_id: epaint::TextureId,
+        index_buffer_slice: &Range<usize>,
+        vertex_buffer_slice: &Range<usize>,
+    ) {
+        if let Some(Texture { bind_group, .. }) = self.textures.get(&texture_id) {
+            render_pass.set_bind_group(1, bind_group, &[]);
+            render_pass.set_index_buffer(
+                self.index_buffer
+                    .buffer
+                    .slice(index_buffer_slice.start as u64..index_buffer_slice.end as u64),
+                wgpu::IndexFormat::Uint32,
+            );
+            render_pass.set_vertex_buffer(
+                0,
+                self.vertex_buffer
+                    .buffer
+                    .slice(vertex_buffer_slice.start as u64..vertex_buffer_slice.end as u64),
+            );
+            let index_count = index_buffer_slice.len() / std::mem::size_of::<u32>();
+            render_pass.draw_indexed(0..index_count as u32, 0, 0..1);
+        } else {
+            log::warn!("Missing texture: {texture_id:?}");
+        }
+    }
+
     /// Should be called before [`Self::render`].
     pub fn update_texture(
         &mut self,
@@ -859,26 +897,31 @@ impl Renderer {
             self.previous_uniform_buffer_content = uniform_buffer_content;
         }
 
-        // Determine how many vertices & indices need to be rendered, and gather prepare callbacks
+        // Gather the meshes to upload and the prepare callbacks
         let mut callbacks = Vec::new();
-        let (vertex_count, index_count) = {
-            profiling::scope!("count_vertices_indices");
-            paint_jobs.iter().fold((0, 0), |acc, clipped_primitive| {
+        let mut meshes: Vec<Cow<'_, epaint::Mesh>> = Vec::with_capacity(paint_jobs.len());
+        {
+            profiling::scope!("gather_meshes");
+            for clipped_primitive in paint_jobs {
                 match &clipped_primitive.primitive {
-                    Primitive::Mesh(mesh) => {
-                        (acc.0 + mesh.vertices.len(), acc.1 + mesh.indices.len())
-                    }
+                    Primitive::Mesh(mesh) => meshes.push(Cow::Borrowed(mesh)),
+                    // We don't set `BackendCapabilities::instancing`, so this should not happen:
+                    Primitive::Instanced(instanced) => meshes.push(Cow::Owned(instanced.to_mesh())),
+                    // We don't set `BackendCapabilities::hdr`, so this should not happen:
+                    Primitive::Hdr(hdr) => meshes.push(Cow::Borrowed(&hdr.mesh)),
                     Primitive::Callback(callback) => {
                         if let Some(c) = callback.callback.downcast_ref::<Callback>() {
                             callbacks.push(c.0.as_ref());
                         } else {
                             log::warn!("Unknown paint callback: expected `egui_wgpu::Callback`");
                         };
-                        acc
                     }
+                    Primitive::Composite(_) => {}
                 }
-            })
-        };
+            }
+        }
+        let vertex_count: usize = meshes.iter().map(|mesh| mesh.vertices.len()).sum();
+        let index_count: usize = meshes.iter().map(|mesh| mesh.indices.len()).sum();
 
         if index_count > 0 {
             profiling::scope!("indices", index_count.to_string().as_str());
@@ -908,18 +951,13 @@ impl Renderer {
             };
 
             let mut index_offset = 0;
-            for epaint::ClippedPrimitive { primitive, .. } in paint_jobs {
-                match primitive {
-                    Primitive::Mesh(mesh) => {
-                        let size = mesh.indices.len() * std::mem::size_of::<u32>();
-                        let slice = index_offset..(size + index_offset);
-                        index_buffer_staging[slice.clone()]
-                            .copy_from_slice(bytemuck::cast_slice(&mesh.indices));
-                        self.index_buffer.slices.push(slice);
-                        index_offset += size;
-                    }
-                    Primitive::Callback(_) => {}
-                }
+            for mesh in &meshes {
+                let size = mesh.indices.len() * std::mem::size_of::<u32>();
+                let slice = index_offset..(size + index_offset);
+                index_buffer_staging[slice.clone()]
+                    .copy_from_slice(bytemuck::cast_slice(&mesh.indices));
+                self.index_buffer.slices.push(slice);
+                index_offset += size;
             }
         }
         if vertex_count > 0 {
@@ -951,18 +989,13 @@ impl Renderer {
             };
 
             let mut vertex_offset = 0;
-            for epaint::ClippedPrimitive { primitive, .. } in paint_jobs {
-                match primitive {
-                    Primitive::Mesh(mesh) => {
-                        let size = mesh.vertices.len() * std::mem::size_of::<Vertex>();
-                        let slice = vertex_offset..(size + vertex_offset);
-                        vertex_buffer_staging[slice.clone()]
-                            .copy_from_slice(bytemuck::cast_slice(&mesh.vertices));
-                        self.vertex_buffer.slices.push(slice);
-                        vertex_offset += size;
-                    }
-                    Primitive::Callback(_) => {}
-                }
+            for mesh in &meshes {
+                let size = mesh.vertices.len() * std::mem::size_of::<Vertex>();
+                let slice = vertex_offset..(size + vertex_offset);
+                vertex_buffer_staging[slice.clone()]
+                    .copy_from_slice(bytemuck::cast_slice(&mesh.vertices));
+                self.vertex_buffer.slices.push(slice);
+                vertex_offset += size;
             }
         }
 
@@ -109,6 +109,19 @@ impl Clipboard {
         self.clipboard = text;
     }
 
+    /// Can we copy images to the OS clipboard?
+    pub fn supports_images(&self) -> bool {
+        #[cfg(all(feature = "arboard", not(target_os = "android")))]
+        {
+            self.arboard.is_some()
+        }
+
+        #[cfg(not(all(feature = "arboard", not(target_os = "android"))))]
+        {
+            false
+        }
+    }
+
     pub fn set_image(&mut self, image: &egui::ColorImage) {
         #[cfg(all(feature = "arboard", not(target_os = "android")))]
         if let Some(clipboard) = &mut self.arboard {
@@ -156,6 +156,7 @@ impl State {
             .or_default()
             .native_pixels_per_point = native_pixels_per_point;
         slf.egui_input.system_theme = theme.map(to_egui_theme);
+        slf.egui_input.capabilities.clipboard_images = slf.clipboard.supports_images();
 
         if let Some(max_texture_side) = max_texture_side {
             slf.set_max_texture_side(max_texture_side);
@@ -417,6 +418,13 @@ impl State {
                     consumed: false,
                 }
             }
+            WindowEvent::Occluded(occluded) => {
+                self.egui_input.occluded = *occluded;
+                EventResponse {
+                    repaint: true,
+                    consumed: false,
+                }
+            }
             WindowEvent::ThemeChanged(winit_theme) => {
                 self.egui_input.system_theme = Some(to_egui_theme(*winit_theme));
                 EventResponse {
@@ -480,7 +488,6 @@ impl State {
             WindowEvent::RedrawRequested
             | WindowEvent::CursorEntered { .. }
             | WindowEvent::Destroyed
-            | WindowEvent::Occluded(_)
             | WindowEvent::Resized(_)
             | WindowEvent::Moved(_)
             | WindowEvent::TouchpadPressure { .. }
@@ -856,6 +863,18 @@ impl State {
                 egui::OutputCommand::OpenUrl(open_url) => {
                     open_url_in_browser(&open_url.url);
                 }
+                egui::OutputCommand::SetTrayIcon(_) | egui::OutputCommand::RemoveTrayIcon => {
+                    // winit has no tray icon support; this is left to the app (or a tray crate).
+                    log::debug!("System tray icons are not supported by egui-winit");
+                }
+                egui::OutputCommand::FileDialog(request) => {
+                    // Let egui show its own fallback dialog:
+                    self.egui_input.events.push(egui::Event::FileDialogResult {
+                        id: request.id,
+                        result: egui::FileDialogResult::Unsupported,
+                    });
+                    self.egui_ctx.request_repaint_of(self.viewport_id);
+                }
             }
         }
 
@@ -1469,11 +1488,14 @@ fn process_viewport_command(
             window.set_fullscreen(v.then_some(winit::window::Fullscreen::Borderless(None)));
         }
         ViewportCommand::Decorations(v) => window.set_decorations(v),
-        ViewportCommand::WindowLevel(l) => window.set_window_level(match l {
-            egui::viewport::WindowLevel::AlwaysOnBottom => WindowLevel::AlwaysOnBottom,
-            egui::viewport::WindowLevel::AlwaysOnTop => WindowLevel::AlwaysOnTop,
-            egui::viewport::WindowLevel::Normal => WindowLevel::Normal,
-        }),
+        ViewportCommand::WindowLevel(l) => {
+            window.set_window_level(match l {
+                egui::viewport::WindowLevel::AlwaysOnBottom => WindowLevel::AlwaysOnBottom,
+                egui::viewport::WindowLevel::AlwaysOnTop => WindowLevel::AlwaysOnTop,
+                egui::viewport::WindowLevel::Normal => WindowLevel::Normal,
+            });
+            info.window_level = Some(l);
+        }
         ViewportCommand::Icon(icon) => {
             let winit_icon = icon.and_then(|icon| to_winit_icon(&icon));
             window.set_window_icon(winit_icon);
@@ -1532,12 +1554,33 @@ fn process_viewport_command(
                 log::warn!("{command:?}: {err}");
             }
         }
+        ViewportCommand::PointerCapture(capture) => {
+            // winit has no real pointer capture, so we confine the cursor to the window instead.
+            // A locked pointer is already grabbed, so leave that alone:
+            if egui_ctx.pointer_lock_id().is_none() {
+                let mode = if capture {
+                    CursorGrabMode::Confined
+                } else {
+                    CursorGrabMode::None
+                };
+                if let Err(err) = window.set_cursor_grab(mode) {
+                    // E.g. macOS can't confine the cursor, but keeps delivering
+                    // pointer events to the window while a mouse button is held.
+                    log::debug!("{command:?}: {err}");
+                }
+            }
+        }
         ViewportCommand::CursorVisible(v) => window.set_cursor_visible(v),
         ViewportCommand::MousePassthrough(passthrough) => {
             if let Err(err) = window.set_cursor_hittest(!passthrough) {
                 log::warn!("{command:?}: {err}");
             }
         }
+        ViewportCommand::Opacity(_) | ViewportCommand::SetHitTestRegions(_) => {
+            // winit has no API for the opacity of a whole window,
+            // and can only turn hit-testing on or off for the whole window.
+            log::debug!("{command:?} is not supported by winit");
+        }
         ViewportCommand::Screenshot(user_data) => {
             actions_requested.insert(ActionRequested::Screenshot(user_data));
         }
@@ -1616,6 +1659,7 @@ pub fn create_winit_window_attributes(
         window_type: _window_type,
 
         mouse_passthrough: _, // handled in `apply_viewport_builder_to_window`
+        opacity: _,           // not supported by winit
         clamp_size_to_monitor_size: _, // Handled in `viewport_builder` in `epi_integration.rs`
     } = viewport_builder;
 
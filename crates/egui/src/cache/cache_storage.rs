@@ -41,6 +41,11 @@ impl CacheStorage {
         self.caches.values().map(|cache| cache.len()).sum()
     }
 
+    /// Remove all cached values, e.g. to free memory.
+    pub fn clear(&mut self) {
+        self.caches.clear();
+    }
+
     /// Call once per frame to evict cache.
     pub fn update(&mut self) {
         self.caches.retain(|_, cache| {
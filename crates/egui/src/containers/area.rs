@@ -2,6 +2,8 @@
 //! It has no frame or own size. It is potentially movable.
 //! It is the foundation for windows and popups.
 
+use std::sync::Arc;
+
 use emath::GuiRounding as _;
 
 use crate::{
@@ -39,6 +41,19 @@ pub struct AreaState {
     /// Used to fade in the area.
     #[cfg_attr(feature = "serde", serde(skip))]
     pub last_became_visible_at: Option<f64>,
+
+    /// A signature of the monitor layout this area was last positioned on.
+    ///
+    /// If the monitor layout changes (e.g. a laptop is undocked) and the area
+    /// ends up off-screen, its position is forgotten so it can be placed again.
+    ///
+    /// Only recorded if [`crate::Options::remember_monitor_layout`] is set.
+    pub monitor_signature: Option<u64>,
+
+    /// If the area is a maximized [`crate::Window`], this is the rectangle to restore it to.
+    ///
+    /// See [`crate::Window::maximizable`].
+    pub restore_rect: Option<Rect>,
 }
 
 impl Default for AreaState {
@@ -49,6 +64,8 @@ impl Default for AreaState {
             size: None,
             interactable: true,
             last_became_visible_at: None,
+            monitor_signature: None,
+            restore_rect: None,
         }
     }
 }
@@ -87,6 +104,58 @@ impl AreaState {
     }
 }
 
+/// The rectangle an [`Area`] is kept inside of, see [`Area::constrain_to`].
+#[derive(Clone)]
+pub enum AreaConstraint {
+    /// Always the same rectangle.
+    Rect(Rect),
+
+    /// Called each pass, so the constraint can follow the screen size, side panels etc.
+    Dynamic(Arc<dyn Fn(&Context) -> Rect + Send + Sync>),
+}
+
+impl AreaConstraint {
+    /// Constrain to the rectangle returned by the callback, which is called each pass.
+    ///
+    /// For instance, to keep windows from hiding under the side panels:
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// egui::Window::new("Tools")
+    ///     .constrain_to(egui::AreaConstraint::from_fn(|ctx| ctx.available_rect()))
+    ///     .show(ctx, |ui| {
+    ///         ui.label("Never under a panel");
+    ///     });
+    /// # });
+    /// ```
+    pub fn from_fn(constrain_rect: impl Fn(&Context) -> Rect + Send + Sync + 'static) -> Self {
+        Self::Dynamic(Arc::new(constrain_rect))
+    }
+
+    /// The rectangle to constrain to this pass.
+    pub fn rect(&self, ctx: &Context) -> Rect {
+        match self {
+            Self::Rect(rect) => *rect,
+            Self::Dynamic(constrain_rect) => constrain_rect(ctx),
+        }
+    }
+}
+
+impl From<Rect> for AreaConstraint {
+    #[inline]
+    fn from(rect: Rect) -> Self {
+        Self::Rect(rect)
+    }
+}
+
+impl std::fmt::Debug for AreaConstraint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Rect(rect) => f.debug_tuple("Rect").field(rect).finish(),
+            Self::Dynamic(_) => f.write_str("Dynamic(..)"),
+        }
+    }
+}
+
 /// An area on the screen that can be moved by dragging.
 ///
 /// This forms the base of the [`crate::Window`] container.
@@ -112,7 +181,7 @@ pub struct Area {
     interactable: bool,
     enabled: bool,
     constrain: bool,
-    constrain_rect: Option<Rect>,
+    constrain_rect: Option<AreaConstraint>,
     order: Order,
     default_pos: Option<Pos2>,
     default_size: Vec2,
@@ -292,10 +361,13 @@ impl Area {
     /// Constrain the movement of the window to the given rectangle.
     ///
     /// For instance: `.constrain_to(ctx.screen_rect())`.
+    ///
+    /// Use [`AreaConstraint::from_fn`] for a rectangle that is recomputed each pass,
+    /// e.g. to follow the screen size or to stay clear of the side panels.
     #[inline]
-    pub fn constrain_to(mut self, constrain_rect: Rect) -> Self {
+    pub fn constrain_to(mut self, constrain_rect: impl Into<AreaConstraint>) -> Self {
         self.constrain = true;
-        self.constrain_rect = Some(constrain_rect);
+        self.constrain_rect = Some(constrain_rect.into());
         self
     }
 
@@ -346,6 +418,9 @@ impl Area {
 
     /// If `true`, quickly fade in the area.
     ///
+    /// This sets the opacity of the whole layer each pass, see [`Context::set_layer_opacity`].
+    /// Turn it off if you want to control that yourself.
+    ///
     /// Default: `true`.
     #[inline]
     pub fn fade_in(mut self, fade_in: bool) -> Self {
@@ -399,6 +474,10 @@ pub(crate) struct Prepared {
     sizing_pass: bool,
 
     fade_in: bool,
+
+    /// If set, the opacity of the layer this pass, e.g. while fading in.
+    opacity: Option<f32>,
+
     layout: Layout,
 }
 
@@ -436,7 +515,7 @@ impl Area {
             sizing_pass: force_sizing_pass,
         } = self;
 
-        let constrain_rect = constrain_rect.unwrap_or_else(|| ctx.screen_rect());
+        let constrain_rect = constrain_rect.map_or_else(|| ctx.screen_rect(), |c| c.rect(ctx));
 
         let layer_id = LayerId::new(order, id);
 
@@ -448,6 +527,8 @@ impl Area {
             size: None,
             interactable,
             last_became_visible_at: None,
+            monitor_signature: None,
+            restore_rect: None,
         });
         if force_sizing_pass {
             sizing_pass = true;
@@ -521,6 +602,9 @@ impl Area {
                     interact_rect: state.rect().intersect(constrain_rect),
                     sense,
                     enabled,
+                    hit_test_mode: Default::default(),
+                    interact_priority: 0,
+                    interact_expand: Default::default(),
                 },
                 true,
             );
@@ -564,6 +648,7 @@ impl Area {
             constrain_rect,
             sizing_pass,
             fade_in,
+            opacity: None,
             layout,
         }
     }
@@ -586,6 +671,11 @@ impl Prepared {
         self.constrain_rect
     }
 
+    /// Fade the whole layer of the area, see [`Context::set_layer_opacity`].
+    pub(crate) fn multiply_opacity(&mut self, opacity: f32) {
+        self.opacity = Some(self.opacity.unwrap_or(1.0) * opacity);
+    }
+
     pub(crate) fn content_ui(&mut self, ctx: &Context) -> Ui {
         let max_rect = self.state.rect();
 
@@ -607,16 +697,18 @@ impl Prepared {
         ui.set_clip_rect(self.constrain_rect); // Don't paint outside our bounds
 
         if self.fade_in {
+            let mut opacity = 1.0;
             if let Some(last_became_visible_at) = self.state.last_became_visible_at {
                 let age =
                     ctx.input(|i| (i.time - last_became_visible_at) as f32 + i.predicted_dt / 2.0);
-                let opacity = crate::remap_clamp(age, 0.0..=ctx.style().animation_time, 0.0..=1.0);
-                let opacity = emath::easing::quadratic_out(opacity); // slow fade-out = quick fade-in
-                ui.multiply_opacity(opacity);
+                opacity = crate::remap_clamp(age, 0.0..=ctx.style().animation_time, 0.0..=1.0);
+                opacity = emath::easing::quadratic_out(opacity); // slow fade-out = quick fade-in
                 if opacity < 1.0 {
                     ctx.request_repaint();
                 }
             }
+            // Set even when fully faded in, to restore the opacity of the layer:
+            self.multiply_opacity(opacity);
         }
 
         ui
@@ -638,6 +730,7 @@ impl Prepared {
             mut state,
             move_response: mut response,
             sizing_pass,
+            opacity,
             ..
         } = self;
 
@@ -657,6 +750,10 @@ impl Prepared {
 
         ctx.memory_mut(|m| m.areas_mut().set_state(layer_id, state));
 
+        if let Some(opacity) = opacity {
+            ctx.set_layer_opacity(layer_id, opacity);
+        }
+
         if sizing_pass {
             // If we didn't know the size, we were likely drawing the area in the wrong place.
             ctx.request_repaint();
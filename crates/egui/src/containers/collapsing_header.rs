@@ -1,7 +1,7 @@
 use std::hash::Hash;
 
 use crate::{
-    Context, Id, InnerResponse, NumExt as _, Rect, Response, Sense, Stroke, TextStyle,
+    Context, Id, Image, InnerResponse, NumExt as _, Rect, Response, Sense, Stroke, TextStyle,
     TextWrapMode, Ui, UiBuilder, UiKind, UiStackInfo, Vec2, WidgetInfo, WidgetText, WidgetType,
     emath, epaint, pos2, remap, remap_clamp, vec2,
 };
@@ -256,6 +256,25 @@ impl CollapsingState {
         }
     }
 
+    /// If the open body would be outside the clip rectangle, reserve the height it had
+    /// last time instead of showing it.
+    fn skip_body_if_hidden(&self, ui: &mut Ui, header_response: &Response) -> Option<Response> {
+        let height = self.state.open_height?;
+        let rect = Rect::from_min_size(
+            ui.available_rect_before_wrap().min,
+            vec2(header_response.rect.width(), height),
+        );
+        let must_show = ui.is_sizing_pass()
+            || ui.ctx().memory(|mem| mem.everything_is_visible())
+            || ui.clip_rect().intersects(rect);
+        if must_show {
+            return None;
+        }
+        self.store(ui.ctx()); // we store any earlier toggling as promised in the docstring
+        let (id, rect) = ui.allocate_space(rect.size());
+        Some(ui.interact(rect, id, Sense::hover()))
+    }
+
     /// Paint this [`CollapsingState`]'s toggle button. Takes an [`IconPainter`] as the icon.
     /// ```
     /// # egui::__run_test_ui(|ui| {
@@ -401,6 +420,8 @@ pub struct CollapsingHeader {
     selected: bool,
     show_background: bool,
     icon: Option<IconPainter>,
+    header_icon: Option<Image<'static>>,
+    header_icon_open: Option<Image<'static>>,
 }
 
 impl CollapsingHeader {
@@ -423,6 +444,8 @@ impl CollapsingHeader {
             selected: false,
             show_background: false,
             icon: None,
+            header_icon: None,
+            header_icon_open: None,
         }
     }
 
@@ -507,6 +530,30 @@ impl CollapsingHeader {
         self.icon = Some(Box::new(icon_fn));
         self
     }
+
+    /// Show an image between the toggle icon and the text, e.g. a folder icon.
+    ///
+    /// The image is painted at [`crate::style::Spacing::icon_width`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// egui::CollapsingHeader::new("Assets")
+    ///     .header_icon(egui::include_image!("../../assets/ferris.png"))
+    ///     .show(ui, |ui| { ui.label("ferris.png"); });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn header_icon(mut self, image: impl Into<Image<'static>>) -> Self {
+        self.header_icon = Some(image.into());
+        self
+    }
+
+    /// Show a different [`Self::header_icon`] while open, e.g. an open folder.
+    #[inline]
+    pub fn header_icon_open(mut self, image: impl Into<Image<'static>>) -> Self {
+        self.header_icon_open = Some(image.into());
+        self
+    }
 }
 
 struct Prepared {
@@ -531,15 +578,21 @@ impl CollapsingHeader {
             selectable,
             selected,
             show_background,
+            header_icon,
+            header_icon_open,
         } = self;
 
         // TODO(emilk): horizontal layout, with icon and text as labels. Insert background behind using Frame.
 
         let id = ui.make_persistent_id(id_salt);
         let button_padding = ui.spacing().button_padding;
+        let icon_width = ui.spacing().icon_width;
 
         let available = ui.available_rect_before_wrap();
-        let text_pos = available.min + vec2(ui.spacing().indent, 0.0);
+        let mut text_pos = available.min + vec2(ui.spacing().indent, 0.0);
+        if header_icon.is_some() || header_icon_open.is_some() {
+            text_pos.x += icon_width + ui.spacing().icon_spacing;
+        }
         let wrap_width = available.right() - text_pos.x;
         let galley = text.into_galley(
             ui,
@@ -582,7 +635,7 @@ impl CollapsingHeader {
         let openness = state.openness(ui.ctx());
 
         if ui.is_rect_visible(rect) {
-            let visuals = ui.style().interact_selectable(&header_response, selected);
+            let visuals = ui.interact_selectable_visuals(&header_response, selected);
 
             if ui.visuals().collapsing_header_frame || show_background {
                 ui.painter().add(epaint::RectShape::new(
@@ -594,8 +647,7 @@ impl CollapsingHeader {
                 ));
             }
 
-            if selected || selectable && (header_response.hovered() || header_response.has_focus())
-            {
+            if selected || selectable && header_response.hovered() {
                 let rect = rect.expand(visuals.expansion);
 
                 ui.painter().rect(
@@ -621,6 +673,22 @@ impl CollapsingHeader {
                 }
             }
 
+            let header_icon = if state.is_open() {
+                header_icon_open.or(header_icon)
+            } else {
+                header_icon
+            };
+            if let Some(header_icon) = header_icon {
+                let icon_rect = Rect::from_center_size(
+                    pos2(
+                        header_response.rect.left() + ui.spacing().indent + icon_width / 2.0,
+                        header_response.rect.center().y,
+                    ),
+                    Vec2::splat(icon_width),
+                );
+                header_icon.paint_at(ui, icon_rect);
+            }
+
             ui.painter().galley(text_pos, galley, visuals.text_color());
         }
 
@@ -637,7 +705,13 @@ impl CollapsingHeader {
         ui: &mut Ui,
         add_body: impl FnOnce(&mut Ui) -> R,
     ) -> CollapsingResponse<R> {
-        self.show_dyn(ui, Box::new(add_body), true)
+        let mut add_body = Some(add_body);
+        self.show_dyn(
+            ui,
+            &mut |ui| add_body.take().map(|add_body| add_body(ui)),
+            true,
+            false,
+        )
     }
 
     #[inline]
@@ -646,14 +720,47 @@ impl CollapsingHeader {
         ui: &mut Ui,
         add_body: impl FnOnce(&mut Ui) -> R,
     ) -> CollapsingResponse<R> {
-        self.show_dyn(ui, Box::new(add_body), false)
+        let mut add_body = Some(add_body);
+        self.show_dyn(
+            ui,
+            &mut |ui| add_body.take().map(|add_body| add_body(ui)),
+            false,
+            false,
+        )
+    }
+
+    /// Like [`Self::show`], but for bodies that are expensive to build, e.g. the nodes of a deep tree.
+    ///
+    /// As with [`Self::show`], `add_body` is not called while the header is fully collapsed
+    /// (and not animating).
+    /// While open, `add_body` is also skipped if the body is outside the clip rectangle
+    /// (e.g. scrolled away in a [`crate::ScrollArea`]),
+    /// and the height the body had last time is reserved instead.
+    ///
+    /// When the body is skipped, [`CollapsingResponse::body_returned`] is `None`.
+    #[inline]
+    pub fn show_lazy<R>(
+        self,
+        ui: &mut Ui,
+        add_body: impl FnOnce(&mut Ui) -> R,
+    ) -> CollapsingResponse<R> {
+        let mut add_body = Some(add_body);
+        self.show_dyn(
+            ui,
+            &mut |ui| add_body.take().map(|add_body| add_body(ui)),
+            true,
+            true,
+        )
     }
 
-    fn show_dyn<'c, R>(
+    /// `add_body` returns `None` if called more than once.
+    /// It is borrowed rather than boxed, so that nothing is allocated for a collapsed body.
+    fn show_dyn<R>(
         self,
         ui: &mut Ui,
-        add_body: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
+        add_body: &mut dyn FnMut(&mut Ui) -> Option<R>,
         indented: bool,
+        lazy: bool,
     ) -> CollapsingResponse<R> {
         // Make sure body is bellow header,
         // and make sure it is one unit (necessary for putting a [`CollapsingHeader`] in a grid).
@@ -668,6 +775,17 @@ impl CollapsingHeader {
                 openness,
             } = self.begin(ui); // show the header
 
+            if lazy && openness >= 1.0 {
+                if let Some(response) = state.skip_body_if_hidden(ui, &header_response) {
+                    return CollapsingResponse {
+                        header_response,
+                        body_response: Some(response),
+                        body_returned: None,
+                        openness,
+                    };
+                }
+            }
+
             let ret_response = if indented {
                 state.show_body_indented(&header_response, ui, add_body)
             } else {
@@ -678,7 +796,7 @@ impl CollapsingHeader {
                 CollapsingResponse {
                     header_response,
                     body_response: Some(ret_response.response),
-                    body_returned: Some(ret_response.inner),
+                    body_returned: ret_response.inner,
                     openness,
                 }
             } else {
@@ -702,7 +820,7 @@ pub struct CollapsingResponse<R> {
     /// None iff collapsed.
     pub body_response: Option<Response>,
 
-    /// None iff collapsed.
+    /// None if collapsed, or if the body was skipped by [`CollapsingHeader::show_lazy`].
     pub body_returned: Option<R>,
 
     /// 0.0 if fully closed, 1.0 if fully open, and something in-between while animating.
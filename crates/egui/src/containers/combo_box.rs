@@ -1,9 +1,10 @@
 use epaint::Shape;
 
 use crate::{
-    Align2, Context, Id, InnerResponse, NumExt as _, Painter, Popup, PopupCloseBehavior, Rect,
-    Response, ScrollArea, Sense, Stroke, TextStyle, TextWrapMode, Ui, UiBuilder, Vec2, WidgetInfo,
-    WidgetText, WidgetType, epaint, style::StyleModifier, style::WidgetVisuals, vec2,
+    Align2, Button, Context, EventFilter, Id, InnerResponse, Key, Modifiers, NumExt as _, Painter,
+    Popup, PopupCloseBehavior, PopupSizePolicy, Rect, Response, ScrollArea, Sense, Stroke,
+    TextEdit, TextStyle, TextWrapMode, Ui, UiBuilder, Vec2, Widget, WidgetInfo, WidgetText,
+    WidgetType, epaint, output::OutputEvent, style::StyleModifier, style::WidgetVisuals, vec2,
 };
 
 #[expect(unused_imports)] // Documentation
@@ -44,6 +45,12 @@ pub struct ComboBox {
     icon: Option<IconPainter>,
     wrap_mode: Option<TextWrapMode>,
     close_behavior: Option<PopupCloseBehavior>,
+    filter: bool,
+    wrap_around: bool,
+
+    /// Wrap the menu contents in a [`ScrollArea`].
+    /// Off when the contents have their own.
+    scroll_menu: bool,
 }
 
 impl ComboBox {
@@ -58,6 +65,9 @@ impl ComboBox {
             icon: None,
             wrap_mode: None,
             close_behavior: None,
+            filter: false,
+            wrap_around: false,
+            scroll_menu: true,
         }
     }
 
@@ -73,6 +83,9 @@ impl ComboBox {
             icon: None,
             wrap_mode: None,
             close_behavior: None,
+            filter: false,
+            wrap_around: false,
+            scroll_menu: true,
         }
     }
 
@@ -87,6 +100,21 @@ impl ComboBox {
             icon: None,
             wrap_mode: None,
             close_behavior: None,
+            filter: false,
+            wrap_around: false,
+            scroll_menu: true,
+        }
+    }
+
+    /// A combo box listing all the variants of an enum, with no boilerplate.
+    ///
+    /// See [`SelectableEnum`] for an example.
+    pub fn from_enum<T: SelectableEnum>(value: &mut T) -> EnumComboBox<'_, T> {
+        EnumComboBox {
+            value,
+            id_salt: None,
+            label: None,
+            width: None,
         }
     }
 
@@ -191,6 +219,30 @@ impl ComboBox {
         self
     }
 
+    /// Show a text field at the top of the menu for filtering the items.
+    ///
+    /// Without a filter, typing the start of an item jumps to it instead.
+    ///
+    /// Only used by [`Self::show_index`], [`Self::show_index_virtual`] and [`Self::show_enum`].
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn filter(mut self, filter: bool) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Should the arrow keys wrap around from the last item to the first, and vice versa?
+    ///
+    /// Only used by [`Self::show_index`], [`Self::show_index_virtual`] and [`Self::show_enum`].
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn wrap_around(mut self, wrap_around: bool) -> Self {
+        self.wrap_around = wrap_around;
+        self
+    }
+
     /// Show the combo box, with the given ui code for the menu contents.
     ///
     /// Returns `InnerResponse { inner: None }` if the combo box is closed.
@@ -216,6 +268,9 @@ impl ComboBox {
             icon,
             wrap_mode,
             close_behavior,
+            filter: _,
+            wrap_around: _,
+            scroll_menu,
         } = self;
 
         let button_id = ui.make_persistent_id(id_salt);
@@ -230,6 +285,7 @@ impl ComboBox {
                 wrap_mode,
                 close_behavior,
                 (width, height),
+                scroll_menu,
             );
             if let Some(label) = label {
                 ir.response.widget_info(|| {
@@ -247,6 +303,9 @@ impl ComboBox {
 
     /// Show a list of items with the given selected index.
     ///
+    /// The menu can be used with the keyboard:
+    /// the arrow keys, Home/End and PageUp/PageDown move the highlighted item,
+    /// typing the start of an item jumps to it, and enter selects it.
     ///
     /// ```
     /// # #[derive(Debug, PartialEq)]
@@ -270,23 +329,337 @@ impl ComboBox {
         len: usize,
         get: impl Fn(usize) -> Text,
     ) -> Response {
-        let slf = self.selected_text(get(*selected));
+        let InnerResponse {
+            inner: changed,
+            mut response,
+        } = if self.filter {
+            self.show_index_rows(ui, selected, len, &|i| get(i).into())
+        } else {
+            self.show_index_list(ui, selected, len, &|i| get(i).into())
+        };
+        if changed {
+            response.mark_changed();
+        }
+        response
+    }
+
+    /// Like [`Self::show_index`], but only the visible items are shown,
+    /// so it stays fast with many thousands of items.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let mut selected = 2;
+    /// egui::ComboBox::from_label("Select one!")
+    ///     .filter(true)
+    ///     .show_index_virtual(ui, &mut selected, 100_000, |i| format!("Item {i}"));
+    /// # });
+    /// ```
+    pub fn show_index_virtual<Text: Into<WidgetText>>(
+        self,
+        ui: &mut Ui,
+        selected: &mut usize,
+        len: usize,
+        get: impl Fn(usize) -> Text,
+    ) -> Response {
+        let InnerResponse {
+            inner: changed,
+            mut response,
+        } = self.show_index_rows(ui, selected, len, &|i| get(i).into());
+        if changed {
+            response.mark_changed();
+        }
+        response
+    }
+
+    /// Show the items with [`ScrollArea::show_rows`], with optional filtering
+    /// and keyboard navigation.
+    ///
+    /// Returns whether an item was picked.
+    fn show_index_rows(
+        mut self,
+        ui: &mut Ui,
+        selected: &mut usize,
+        len: usize,
+        get: &dyn Fn(usize) -> WidgetText,
+    ) -> InnerResponse<bool> {
+        let filter = self.filter;
+        let wrap_around = self.wrap_around;
+        let button_id = ui.make_persistent_id(self.id_salt);
+        let state_id = button_id.with("list");
+        self.scroll_menu = false;
+        if *selected < len {
+            self.selected_text = get(*selected);
+        }
+
+        let enter = Self::consume_list_enter(ui, button_id, state_id);
 
         let mut changed = false;
+        let mut selected_with_keyboard = false;
 
-        let mut response = slf
+        let response = self
             .show_ui(ui, |ui| {
+                let mut state = ui
+                    .data(|data| data.get_temp::<ListState>(state_id))
+                    .unwrap_or_default();
+
+                let first_frame = !std::mem::replace(&mut state.was_open, true);
+                if first_frame {
+                    state.highlighted = *selected;
+                }
+
+                let mut matches = None;
+                if filter {
+                    let text_edit = ui.add(
+                        TextEdit::singleline(&mut state.query)
+                            .hint_text(ui.ctx().tr("combo_box.filter", "Filter…"))
+                            .desired_width(f32::INFINITY),
+                    );
+                    if first_frame {
+                        text_edit.request_focus();
+                    }
+                    if text_edit.changed() {
+                        state.highlighted = 0;
+                        state.scroll_offset = 0.0;
+                    }
+                    state.keyboard_owner = Some(text_edit.id);
+
+                    let query = state.query.to_lowercase();
+                    if !query.is_empty() {
+                        matches = Some(
+                            (0..len)
+                                .filter(|&i| get(i).text().to_lowercase().contains(&query))
+                                .collect::<Vec<_>>(),
+                        );
+                    }
+                } else {
+                    // Don't let the arrow keys move the focus away from the combo box button:
+                    ui.memory_mut(|mem| {
+                        mem.set_focus_lock_filter(
+                            button_id,
+                            EventFilter {
+                                tab: false,
+                                horizontal_arrows: false,
+                                vertical_arrows: true,
+                                escape: false,
+                            },
+                        );
+                    });
+                    state.keyboard_owner = Some(button_id);
+                }
+                let num_rows = matches.as_ref().map_or(len, Vec::len);
+                let item = |row: usize| matches.as_ref().map_or(row, |matches| matches[row]);
+
+                let row_height = ui.spacing().interact_size.y;
+                let row_spacing = ui.spacing().item_spacing.y;
+
+                let mut moved_highlight = false;
+                if 0 < num_rows && state.has_focus(ui.ctx()) {
+                    let rows_per_page =
+                        (state.viewport_height / (row_height + row_spacing)).floor() as usize;
+                    let row_text = |row: usize| get(item(row)).text().to_owned();
+                    moved_highlight = state.navigate(
+                        ui,
+                        num_rows,
+                        rows_per_page.at_least(1),
+                        wrap_around,
+                        (!filter).then_some(&row_text as &dyn Fn(usize) -> String),
+                    );
+                    if enter {
+                        *selected = item(state.highlighted);
+                        changed = true;
+                        selected_with_keyboard = true;
+                        ui.close();
+                    }
+                }
+
+                let mut scroll_area = ScrollArea::vertical().max_height(ui.available_height());
+                if moved_highlight {
+                    // Keep the highlighted row in view:
+                    let top = state.highlighted as f32 * (row_height + row_spacing);
+                    let bottom = top + row_height;
+                    state.scroll_offset = state
+                        .scroll_offset
+                        .at_most(top)
+                        .at_least(bottom - state.viewport_height);
+                    scroll_area = scroll_area.vertical_scroll_offset(state.scroll_offset);
+                }
+
+                let show_highlight = filter || state.used_keyboard;
+                let output = scroll_area.show_rows(ui, row_height, num_rows, |ui, rows| {
+                    for row in rows {
+                        let i = item(row);
+                        let mut response = ui.add(Button::selectable(i == *selected, get(i)));
+                        if show_highlight && row == state.highlighted {
+                            response = response.highlight();
+                            state.announce_highlighted(ui.ctx(), response.id);
+                        }
+                        if response.clicked() {
+                            *selected = i;
+                            changed = true;
+                        }
+                    }
+                });
+                state.scroll_offset = output.state.offset.y;
+                state.viewport_height = output.inner_rect.height();
+
+                ui.data_mut(|data| data.insert_temp(state_id, state));
+            })
+            .response;
+
+        if !Self::is_open(ui.ctx(), response.id) {
+            // Start with a fresh list state next time the menu is opened:
+            ui.data_mut(|data| data.remove::<ListState>(state_id));
+        }
+
+        if selected_with_keyboard {
+            // There was no click on an item to announce, so announce the new value instead:
+            let mut info = WidgetInfo::new(WidgetType::ComboBox);
+            info.enabled = ui.is_enabled();
+            info.current_text_value = Some(get(*selected).text().to_owned());
+            response.output_event(OutputEvent::ValueChanged(info));
+        }
+        InnerResponse::new(changed, response)
+    }
+
+    /// Show all the items with [`Ui::selectable_label`], like [`Self::show_ui`] would,
+    /// with keyboard navigation on top.
+    ///
+    /// Returns whether an item was picked.
+    fn show_index_list(
+        mut self,
+        ui: &mut Ui,
+        selected: &mut usize,
+        len: usize,
+        get: &dyn Fn(usize) -> WidgetText,
+    ) -> InnerResponse<bool> {
+        let wrap_around = self.wrap_around;
+        let button_id = ui.make_persistent_id(self.id_salt);
+        let state_id = button_id.with("list");
+        if *selected < len {
+            self.selected_text = get(*selected);
+        }
+
+        let enter = Self::consume_list_enter(ui, button_id, state_id);
+
+        let mut changed = false;
+        let mut selected_with_keyboard = false;
+
+        let response = self
+            .show_ui(ui, |ui| {
+                let mut state = ui
+                    .data(|data| data.get_temp::<ListState>(state_id))
+                    .unwrap_or_default();
+
+                if !std::mem::replace(&mut state.was_open, true) {
+                    state.highlighted = *selected;
+                }
+
+                // Don't let the arrow keys move the focus away from the combo box button:
+                ui.memory_mut(|mem| {
+                    mem.set_focus_lock_filter(
+                        button_id,
+                        EventFilter {
+                            tab: false,
+                            horizontal_arrows: false,
+                            vertical_arrows: true,
+                            escape: false,
+                        },
+                    );
+                });
+                state.keyboard_owner = Some(button_id);
+
+                let mut moved_highlight = false;
+                if 0 < len && state.has_focus(ui.ctx()) {
+                    // The rows are laid out freely, so this is only an estimate:
+                    let row_height = ui.spacing().interact_size.y + ui.spacing().item_spacing.y;
+                    let rows_per_page = (ui.clip_rect().height() / row_height).floor() as usize;
+                    let row_text = |i: usize| get(i).text().to_owned();
+                    moved_highlight = state.navigate(
+                        ui,
+                        len,
+                        rows_per_page.at_least(1),
+                        wrap_around,
+                        Some(&row_text),
+                    );
+                    if enter {
+                        *selected = state.highlighted;
+                        changed = true;
+                        selected_with_keyboard = true;
+                        ui.close();
+                    }
+                }
+
                 for i in 0..len {
-                    if ui.selectable_label(i == *selected, get(i)).clicked() {
+                    let mut response = ui.selectable_label(i == *selected, get(i));
+                    if state.used_keyboard && i == state.highlighted {
+                        response = response.highlight();
+                        if moved_highlight {
+                            response.scroll_to_me(None);
+                        }
+                        state.announce_highlighted(ui.ctx(), response.id);
+                    }
+                    if response.clicked() {
                         *selected = i;
                         changed = true;
                     }
                 }
+
+                ui.data_mut(|data| data.insert_temp(state_id, state));
             })
             .response;
 
+        if !Self::is_open(ui.ctx(), response.id) {
+            // Start with a fresh list state next time the menu is opened:
+            ui.data_mut(|data| data.remove::<ListState>(state_id));
+        }
+
+        if selected_with_keyboard {
+            // There was no click on an item to announce, so announce the new value instead:
+            let mut info = WidgetInfo::new(WidgetType::ComboBox);
+            info.enabled = ui.is_enabled();
+            info.current_text_value = Some(get(*selected).text().to_owned());
+            response.output_event(OutputEvent::ValueChanged(info));
+        }
+        InnerResponse::new(changed, response)
+    }
+
+    /// Take the enter key if it picks the highlighted item of this open list.
+    ///
+    /// This has to happen before the button is shown,
+    /// as pressing enter would otherwise also toggle the menu if the button has focus.
+    fn consume_list_enter(ui: &Ui, button_id: Id, state_id: Id) -> bool {
+        Self::is_open(ui.ctx(), button_id)
+            && ui
+                .data(|data| data.get_temp::<ListState>(state_id))
+                .is_some_and(|state| state.has_focus(ui.ctx()))
+            && ui.input_mut(|i| i.consume_key(Modifiers::NONE, Key::Enter))
+    }
+
+    /// Show all the variants of an enum, and select the one the user clicks.
+    ///
+    /// The returned [`Response`] is marked changed when the value changes.
+    pub fn show_enum<T: SelectableEnum>(self, ui: &mut Ui, value: &mut T) -> Response {
+        let variants = T::variants();
+        let mut index = variants
+            .iter()
+            .position(|variant| variant == value)
+            .unwrap_or(variants.len());
+
+        let InnerResponse {
+            inner: changed,
+            mut response,
+        } = self.selected_text(value.display_name()).show_index_list(
+            ui,
+            &mut index,
+            variants.len(),
+            &|i| variants[i].display_name().into(),
+        );
+
         if changed {
-            response.mark_changed();
+            if let Some(variant) = variants.get(index).filter(|variant| *variant != value) {
+                *value = variant.clone();
+                response.mark_changed();
+            }
         }
         response
     }
@@ -302,6 +675,267 @@ impl ComboBox {
     }
 }
 
+/// An enum that can be picked from a [`ComboBox`] with [`ComboBox::from_enum`] or [`ComboBox::show_enum`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// #[derive(Clone, PartialEq)]
+/// enum Quality {
+///     Low,
+///     Medium,
+///     High,
+/// }
+///
+/// impl egui::SelectableEnum for Quality {
+///     fn variants() -> &'static [Self] {
+///         &[Self::Low, Self::Medium, Self::High]
+///     }
+///
+///     fn display_name(&self) -> &str {
+///         match self {
+///             Self::Low => "Low",
+///             Self::Medium => "Medium",
+///             Self::High => "High",
+///         }
+///     }
+/// }
+///
+/// let mut quality = Quality::Medium;
+/// if ui.add(egui::ComboBox::from_enum(&mut quality).label("Quality")).changed() {
+///     // …
+/// }
+/// # });
+/// ```
+pub trait SelectableEnum: Clone + PartialEq + 'static {
+    /// All the values to choose between, in the order they are listed.
+    fn variants() -> &'static [Self];
+
+    /// The name shown for this value.
+    fn display_name(&self) -> &str;
+}
+
+/// A [`ComboBox`] over a [`SelectableEnum`], created with [`ComboBox::from_enum`].
+#[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
+pub struct EnumComboBox<'a, T: SelectableEnum> {
+    value: &'a mut T,
+    id_salt: Option<Id>,
+    label: Option<WidgetText>,
+    width: Option<f32>,
+}
+
+impl<T: SelectableEnum> EnumComboBox<'_, T> {
+    /// Label shown next to the combo box.
+    ///
+    /// Unless you set [`Self::id_salt`], the label is also used for the id.
+    #[inline]
+    pub fn label(mut self, label: impl Into<WidgetText>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Set the id salt of the combo box.
+    ///
+    /// By default the label is used, or else an automatic id.
+    #[inline]
+    pub fn id_salt(mut self, id_salt: impl std::hash::Hash) -> Self {
+        self.id_salt = Some(Id::new(id_salt));
+        self
+    }
+
+    /// Set the outer width of the button and menu.
+    ///
+    /// Default is [`Spacing::combo_width`].
+    #[inline]
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = Some(width);
+        self
+    }
+}
+
+impl<T: SelectableEnum> Widget for EnumComboBox<'_, T> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            value,
+            id_salt,
+            label,
+            width,
+        } = self;
+
+        let mut combo_box = match (id_salt, label) {
+            (Some(id_salt), Some(label)) => ComboBox::new(id_salt, label),
+            (None, Some(label)) => ComboBox::from_label(label),
+            (Some(id_salt), None) => ComboBox::from_id_salt(id_salt),
+            (None, None) => ComboBox::from_id_salt(ui.next_auto_id()),
+        };
+        if let Some(width) = width {
+            combo_box = combo_box.width(width);
+        }
+        combo_box.show_enum(ui, value)
+    }
+}
+
+/// The state of the list in the menu of [`ComboBox::show_index`] etc, while the menu is open.
+#[derive(Clone, Default)]
+struct ListState {
+    /// The text in the [`ComboBox::filter`] field.
+    query: String,
+
+    /// Which of the shown rows is highlighted, for keyboard navigation.
+    highlighted: usize,
+
+    /// Has the highlight been moved with the keyboard?
+    used_keyboard: bool,
+
+    /// Is this not the first frame the menu is open?
+    was_open: bool,
+
+    /// The widget that steers the list while the menu is open:
+    /// the filter field, or else the combo box button.
+    ///
+    /// It also tells screen readers about the highlighted row.
+    keyboard_owner: Option<Id>,
+
+    /// What has been typed so far to jump to an item, if there is no filter field.
+    type_ahead: String,
+
+    /// When a key was last typed into [`Self::type_ahead`].
+    type_ahead_time: f64,
+
+    scroll_offset: f32,
+    viewport_height: f32,
+}
+
+impl ListState {
+    /// Forget what was typed if nothing has been typed for this long.
+    const TYPE_AHEAD_TIMEOUT: f64 = 1.0;
+
+    /// Should the keyboard steer the list?
+    ///
+    /// That is the case unless some other widget has keyboard focus.
+    fn has_focus(&self, ctx: &Context) -> bool {
+        ctx.memory(|mem| mem.focused())
+            .is_none_or(|focused| Some(focused) == self.keyboard_owner)
+    }
+
+    /// Move the highlighted row in response to the keyboard.
+    ///
+    /// If `row_text` is set, typed text jumps to the next row starting with it.
+    ///
+    /// Returns `true` if the highlight moved.
+    fn navigate(
+        &mut self,
+        ui: &Ui,
+        num_rows: usize,
+        rows_per_page: usize,
+        wrap_around: bool,
+        row_text: Option<&dyn Fn(usize) -> String>,
+    ) -> bool {
+        let last_row = num_rows - 1;
+        let highlighted = self.highlighted.min(last_row);
+
+        let (up, down, home, end, page_up, page_down, typed, time) = ui.input_mut(|i| {
+            let typed = if row_text.is_some() {
+                let mut typed = String::new();
+                i.events.retain(|event| match event {
+                    crate::Event::Text(text) => {
+                        typed += text;
+                        false
+                    }
+                    _ => true,
+                });
+                typed
+            } else {
+                String::new()
+            };
+            (
+                i.consume_key(Modifiers::NONE, Key::ArrowUp),
+                i.consume_key(Modifiers::NONE, Key::ArrowDown),
+                i.consume_key(Modifiers::NONE, Key::Home),
+                i.consume_key(Modifiers::NONE, Key::End),
+                i.consume_key(Modifiers::NONE, Key::PageUp),
+                i.consume_key(Modifiers::NONE, Key::PageDown),
+                typed,
+                i.time,
+            )
+        });
+
+        let mut new_highlighted = if up {
+            if highlighted == 0 && wrap_around {
+                last_row
+            } else {
+                highlighted.saturating_sub(1)
+            }
+        } else if down {
+            if highlighted == last_row && wrap_around {
+                0
+            } else {
+                (highlighted + 1).min(last_row)
+            }
+        } else if home {
+            0
+        } else if end {
+            last_row
+        } else if page_up {
+            highlighted.saturating_sub(rows_per_page)
+        } else if page_down {
+            (highlighted + rows_per_page).min(last_row)
+        } else {
+            highlighted
+        };
+
+        if let Some(row_text) = row_text.filter(|_| !typed.is_empty()) {
+            if Self::TYPE_AHEAD_TIMEOUT < time - self.type_ahead_time {
+                self.type_ahead.clear();
+            }
+            self.type_ahead += &typed.to_lowercase();
+            self.type_ahead_time = time;
+            if let Some(row) = self.type_ahead_match(highlighted, num_rows, row_text) {
+                new_highlighted = row;
+            }
+        }
+
+        let moved = new_highlighted != self.highlighted;
+        self.highlighted = new_highlighted;
+        self.used_keyboard |= moved;
+        moved
+    }
+
+    /// The row that [`Self::type_ahead`] should jump to.
+    fn type_ahead_match(
+        &self,
+        highlighted: usize,
+        num_rows: usize,
+        row_text: &dyn Fn(usize) -> String,
+    ) -> Option<usize> {
+        let mut chars = self.type_ahead.chars();
+        let first = chars.next()?;
+        let (prefix, start) = if chars.all(|c| c == first) {
+            // Typing the same letter again cycles through the rows starting with it:
+            (first.to_string(), highlighted + 1)
+        } else {
+            // Keep the current row if it still matches:
+            (self.type_ahead.clone(), highlighted)
+        };
+        (start..start + num_rows)
+            .map(|row| row % num_rows)
+            .find(|&row| row_text(row).to_lowercase().starts_with(&prefix))
+    }
+
+    /// Tell screen readers which row is highlighted.
+    #[cfg_attr(
+        not(feature = "accesskit"),
+        expect(clippy::unused_self, unused_variables)
+    )]
+    fn announce_highlighted(&self, ctx: &Context, row_id: Id) {
+        #[cfg(feature = "accesskit")]
+        if let Some(keyboard_owner) = self.keyboard_owner {
+            ctx.accesskit_node_builder(keyboard_owner, |node| {
+                node.set_active_descendant(row_id.accesskit_id());
+            });
+        }
+    }
+}
+
 #[expect(clippy::too_many_arguments)]
 fn combo_box_dyn<'c, R>(
     ui: &mut Ui,
@@ -312,6 +946,7 @@ fn combo_box_dyn<'c, R>(
     wrap_mode: Option<TextWrapMode>,
     close_behavior: Option<PopupCloseBehavior>,
     (width, height): (Option<f32>, Option<f32>),
+    scroll_menu: bool,
 ) -> InnerResponse<Option<R>> {
     let button_id = button_id.into();
     let popup_id = ComboBox::widget_to_popup_id(button_id);
@@ -383,21 +1018,21 @@ fn combo_box_dyn<'c, R>(
         .style(StyleModifier::default())
         .width(button_response.rect.width())
         .close_behavior(close_behavior)
+        .size_policy(
+            PopupSizePolicy::default()
+                .max_height(height)
+                .scroll(scroll_menu),
+        )
         .show(|ui| {
             ui.set_min_width(ui.available_width());
 
-            ScrollArea::vertical()
-                .max_height(height)
-                .show(ui, |ui| {
-                    // Often the button is very narrow, which means this popup
-                    // is also very narrow. Having wrapping on would therefore
-                    // result in labels that wrap very early.
-                    // Instead, we turn it off by default so that the labels
-                    // expand the width of the menu.
-                    ui.style_mut().wrap_mode = Some(TextWrapMode::Extend);
-                    menu_contents(ui)
-                })
-                .inner
+            // Often the button is very narrow, which means this popup
+            // is also very narrow. Having wrapping on would therefore
+            // result in labels that wrap very early.
+            // Instead, we turn it off by default so that the labels
+            // expand the width of the menu.
+            ui.style_mut().wrap_mode = Some(TextWrapMode::Extend);
+            menu_contents(ui)
         })
         .map(|r| r.inner);
 
@@ -471,3 +1106,142 @@ fn paint_default_icon(painter: &Painter, rect: Rect, visuals: &WidgetVisuals) {
         Stroke::NONE,
     ));
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CentralPanel, Event, RawInput};
+
+    #[test]
+    fn test_filter_keyboard_navigation() {
+        let ctx = Context::default();
+        let mut selected = 0;
+        let mut run = |events: Vec<Event>| {
+            let input = RawInput {
+                events,
+                ..Default::default()
+            };
+            let mut button_id = Id::NULL;
+            let _ = ctx.run(input, |ctx| {
+                CentralPanel::default().show(ctx, |ui| {
+                    button_id = ComboBox::from_id_salt("combo")
+                        .filter(true)
+                        .show_index_virtual(ui, &mut selected, 10_000, |i| format!("Item {i}"))
+                        .id;
+                });
+            });
+            button_id
+        };
+        let key = |key| Event::Key {
+            key,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers: Modifiers::NONE,
+        };
+
+        let button_id = run(vec![]);
+        Popup::open_id(&ctx, ComboBox::widget_to_popup_id(button_id));
+        run(vec![]);
+        run(vec![Event::Text("item 12".to_owned())]);
+        run(vec![key(Key::ArrowDown)]);
+        run(vec![key(Key::Enter)]);
+
+        // "Item 12", "Item 120", "Item 121", …
+        assert_eq!(selected, 120);
+        assert!(!ComboBox::is_open(&ctx, button_id));
+    }
+
+    #[test]
+    fn test_list_keyboard_navigation() {
+        let ctx = Context::default();
+        let mut selected = 0;
+        let mut run = |events: Vec<Event>| {
+            let input = RawInput {
+                events,
+                ..Default::default()
+            };
+            let mut button_id = Id::NULL;
+            let _ = ctx.run(input, |ctx| {
+                CentralPanel::default().show(ctx, |ui| {
+                    button_id = ComboBox::from_id_salt("combo")
+                        .wrap_around(true)
+                        .show_index(ui, &mut selected, 100, |i| {
+                            ["Apple", "Banana", "Blueberry", "Cherry"][i % 4]
+                        })
+                        .id;
+                });
+            });
+            (button_id, selected)
+        };
+        let key = |key| Event::Key {
+            key,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers: Modifiers::NONE,
+        };
+
+        let (button_id, _) = run(vec![]);
+        ctx.memory_mut(|mem| mem.request_focus(button_id));
+        let mut pick = |events: Vec<Event>| {
+            Popup::open_id(&ctx, ComboBox::widget_to_popup_id(button_id));
+            run(vec![]);
+            for event in events {
+                run(vec![event]);
+            }
+            let (_, selected) = run(vec![key(Key::Enter)]);
+            assert!(!ComboBox::is_open(&ctx, button_id));
+            selected
+        };
+
+        assert_eq!(pick(vec![key(Key::End)]), 99);
+        assert_eq!(pick(vec![key(Key::ArrowDown)]), 0, "Should wrap around");
+        assert_eq!(pick(vec![key(Key::ArrowUp)]), 99, "Should wrap around");
+        assert!(1 < pick(vec![key(Key::Home), key(Key::PageDown)]));
+        assert_eq!(pick(vec![key(Key::Home), Event::Text("b".to_owned())]), 1);
+        assert_eq!(pick(vec![Event::Text("b".to_owned())]), 2, "Should cycle");
+        assert_eq!(pick(vec![Event::Text("ch".to_owned())]), 3);
+    }
+
+    #[test]
+    fn test_list_leaves_keys_to_focused_widget() {
+        let ctx = Context::default();
+        let mut selected = 0;
+        let mut text = String::new();
+        let mut run = |events: Vec<Event>| {
+            let input = RawInput {
+                events,
+                ..Default::default()
+            };
+            let mut ids = (Id::NULL, Id::NULL);
+            let _ = ctx.run(input, |ctx| {
+                CentralPanel::default().show(ctx, |ui| {
+                    ids.0 = ui.text_edit_multiline(&mut text).id;
+                    ids.1 = ComboBox::from_id_salt("combo")
+                        .show_index(ui, &mut selected, 4, |i| format!("Item {i}"))
+                        .id;
+                });
+            });
+            ids
+        };
+        let key = |key| Event::Key {
+            key,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers: Modifiers::NONE,
+        };
+
+        let (text_edit_id, button_id) = run(vec![]);
+        ctx.memory_mut(|mem| mem.request_focus(text_edit_id));
+        Popup::open_id(&ctx, ComboBox::widget_to_popup_id(button_id));
+        run(vec![]);
+        run(vec![key(Key::ArrowDown)]);
+        run(vec![key(Key::Enter)]);
+
+        assert!(ComboBox::is_open(&ctx, button_id));
+        assert_eq!(selected, 0);
+        assert_eq!(text, "\n");
+    }
+}
@@ -6,15 +6,18 @@
 //! - [`MenuBar`]
 //! - [`Response::context_menu`]
 //!
+//! For actions and contents that take a while to load, see [`AsyncMenuItem`] and [`SubMenuButton::ui_lazy`].
+//!
 //! See [`MenuBar`] for an example.
 
 use crate::style::StyleModifier;
 use crate::{
-    Button, Color32, Context, Frame, Id, InnerResponse, IntoAtoms, Layout, Popup,
-    PopupCloseBehavior, Response, Style, Ui, UiBuilder, UiKind, UiStack, UiStackInfo, Widget as _,
+    Atom, Button, Color32, Context, Frame, Id, InnerResponse, IntoAtoms, Layout, Popup,
+    PopupCloseBehavior, Response, Spinner, Style, Ui, UiBuilder, UiKind, UiStack, UiStackInfo,
+    Widget as _,
 };
-use emath::{Align, RectAlign, Vec2, vec2};
-use epaint::Stroke;
+use emath::{Align, RectAlign, Vec2, pos2, vec2};
+use epaint::{Shape, Stroke};
 
 /// Apply a menu style to the [`Style`].
 ///
@@ -137,6 +140,9 @@ pub struct MenuState {
     /// The currently open sub menu in this menu.
     pub open_item: Option<Id>,
     last_visible_pass: u64,
+
+    /// The pass in which [`Self::keep_open`] was last called.
+    keep_open_pass: Option<u64>,
 }
 
 impl MenuState {
@@ -156,6 +162,7 @@ impl MenuState {
             let state = data.get_temp_mut_or_insert_with(id.with(Self::ID), || Self {
                 open_item: None,
                 last_visible_pass: pass_nr,
+                keep_open_pass: None,
             });
             // If the menu was closed for at least a frame, reset the open item
             if state.last_visible_pass + 1 < pass_nr {
@@ -170,6 +177,25 @@ impl MenuState {
     pub fn is_deepest_sub_menu(ctx: &Context, id: impl Into<Id>) -> bool {
         Self::from_id(ctx, id, |state| state.open_item.is_none())
     }
+
+    /// Don't close the menu this pass, even if the user clicked inside of it.
+    ///
+    /// Used by e.g. [`AsyncMenuItem`], so that starting an action doesn't close the menu.
+    pub fn keep_open(ui: &Ui) {
+        let pass_nr = ui.ctx().cumulative_pass_nr();
+        let menu_id = Self::from_ui(ui, |state, stack| {
+            state.keep_open_pass = Some(pass_nr);
+            stack.id
+        });
+        // Sub menus check `keep_open_pass` themselves, but the root menu is closed by its popup:
+        Popup::ignore_clicks_inside(ui.ctx(), menu_id);
+    }
+
+    /// Was [`Self::keep_open`] called for the menu with this id this pass?
+    pub fn should_keep_open(ctx: &Context, id: impl Into<Id>) -> bool {
+        let pass_nr = ctx.cumulative_pass_nr();
+        Self::from_id(ctx, id, |state| state.keep_open_pass == Some(pass_nr))
+    }
 }
 
 /// Horizontal menu bar where you can add [`MenuButton`]s.
@@ -369,6 +395,131 @@ impl<'a> SubMenuButton<'a> {
 
         (response, popup_response)
     }
+
+    /// Show the submenu button, for contents that have to be loaded first.
+    ///
+    /// `load` is only called while the submenu is open, so it is a good place to start
+    /// e.g. a network request. Return `None` while still loading,
+    /// and the submenu shows a spinner instead of the contents.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let recent_files: Option<Vec<String>> = None;
+    /// use egui::containers::menu::SubMenuButton;
+    /// SubMenuButton::new("Open recent").ui_lazy(
+    ///     ui,
+    ///     || recent_files.clone(), // Start fetching them here, if not already started
+    ///     |ui, files| {
+    ///         for file in files {
+    ///             if ui.button(file).clicked() { /* … */ }
+    ///         }
+    ///     },
+    /// );
+    /// # });
+    /// ```
+    pub fn ui_lazy<T, R>(
+        self,
+        ui: &mut Ui,
+        load: impl FnOnce() -> Option<T>,
+        content: impl FnOnce(&mut Ui, T) -> R,
+    ) -> (Response, Option<InnerResponse<Option<R>>>) {
+        self.ui(ui, |ui| {
+            if let Some(loaded) = load() {
+                Some(content(ui, loaded))
+            } else {
+                AsyncMenuItem::new(ui.ctx().tr("menu.loading", "Loading…"), AsyncState::Pending)
+                    .ui(ui);
+                None
+            }
+        })
+    }
+}
+
+/// The state of a slow action started from a menu, see [`AsyncMenuItem`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum AsyncState {
+    /// The action hasn't been started.
+    #[default]
+    Idle,
+
+    /// The action is running.
+    Pending,
+
+    /// The action has finished.
+    Done,
+}
+
+/// A menu item for an action that takes a while, e.g. a network request.
+///
+/// While [`AsyncState::Pending`], the item is disabled and shows a spinner.
+/// When [`AsyncState::Done`], it shows a check mark.
+/// Clicking the item doesn't close the menu, so the user can see how the action goes.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut sync_state = egui::containers::menu::AsyncState::Idle;
+/// use egui::containers::menu::{AsyncMenuItem, AsyncState};
+/// ui.menu_button("File", |ui| {
+///     if AsyncMenuItem::new("Sync", sync_state).ui(ui).clicked() {
+///         // Start syncing, and set the state to `Done` when finished:
+///         sync_state = AsyncState::Pending;
+///     }
+/// });
+/// # });
+/// ```
+pub struct AsyncMenuItem<'a> {
+    pub button: Button<'a>,
+    pub state: AsyncState,
+}
+
+impl<'a> AsyncMenuItem<'a> {
+    pub fn new(atoms: impl IntoAtoms<'a>, state: AsyncState) -> Self {
+        Self::from_button(Button::new(atoms.into_atoms()), state)
+    }
+
+    /// Create a new async menu item from a [`Button`].
+    pub fn from_button(button: Button<'a>, state: AsyncState) -> Self {
+        Self { button, state }
+    }
+
+    /// Show the menu item.
+    pub fn ui(self, ui: &mut Ui) -> Response {
+        let Self { mut button, state } = self;
+
+        let icon_id = Id::new("egui::async_menu_item");
+        if state != AsyncState::Idle {
+            let icon_size = Vec2::splat(ui.spacing().icon_width);
+            button = button.right_text(Atom::custom(icon_id, icon_size));
+        }
+
+        let response = ui
+            .add_enabled_ui(state != AsyncState::Pending, |ui| {
+                let response = button.atom_ui(ui);
+                if let Some(rect) = response.rect(icon_id) {
+                    if state == AsyncState::Pending {
+                        Spinner::new().size(rect.height()).paint_at(ui, rect);
+                    } else {
+                        let (small_icon_rect, _) = ui.spacing().icon_rectangles(rect);
+                        ui.painter().add(Shape::line(
+                            vec![
+                                pos2(small_icon_rect.left(), small_icon_rect.center().y),
+                                pos2(small_icon_rect.center().x, small_icon_rect.bottom()),
+                                pos2(small_icon_rect.right(), small_icon_rect.top()),
+                            ],
+                            ui.style().interact(&response.response).fg_stroke,
+                        ));
+                    }
+                }
+                response.response
+            })
+            .inner;
+
+        if state == AsyncState::Pending || response.clicked() {
+            MenuState::keep_open(ui);
+        }
+
+        response
+    }
 }
 
 /// Show a submenu in a menu.
@@ -509,6 +660,8 @@ impl SubMenu {
                 && response.ctx.input(|i| i.pointer.any_click())
                 && hover_pos.is_some_and(|pos| popup_response.response.interact_rect.contains(pos));
 
+            let clicked_inside = clicked_inside && !MenuState::should_keep_open(ui.ctx(), id);
+
             let click_close = match menu_config.close_behavior {
                 PopupCloseBehavior::CloseOnClick => clicked_outside || clicked_inside,
                 PopupCloseBehavior::CloseOnClickOutside => clicked_outside,
@@ -18,9 +18,11 @@ pub mod scroll_area;
 mod sides;
 mod tooltip;
 pub(crate) mod window;
+mod window_chrome;
+mod wizard;
 
 pub use {
-    area::{Area, AreaState},
+    area::{Area, AreaConstraint, AreaState},
     close_tag::ClosableTag,
     collapsing_header::{CollapsingHeader, CollapsingResponse},
     combo_box::*,
@@ -35,4 +37,6 @@ pub use {
     sides::Sides,
     tooltip::*,
     window::Window,
+    window_chrome::WindowChrome,
+    wizard::{Wizard, WizardResponse},
 };
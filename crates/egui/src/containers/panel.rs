@@ -26,6 +26,30 @@ fn animate_expansion(ctx: &Context, id: impl Into<Id>, is_expanded: bool) -> f32
     ctx.animate_bool_responsive(id, is_expanded)
 }
 
+/// Animate the size of a panel towards `target`.
+///
+/// Returns the current size, and while animating also the size to lay out the contents at:
+/// the larger of the sizes we are animating between, so that the contents don't reflow every frame.
+fn animate_size(ctx: &Context, id: Id, target: f32) -> (f32, Option<f32>) {
+    let size = ctx.animate_value_with_time(id, target, ctx.style().animation_time);
+
+    // Remember where we are animating from:
+    let from = ctx.data_mut(|data| {
+        let (from, to) = data.get_temp_mut_or(id, (target, target));
+        if *to != target {
+            *from = *to;
+            *to = target;
+        }
+        *from
+    });
+
+    if size == target {
+        (size, None)
+    } else {
+        (size, Some(from.max(target)))
+    }
+}
+
 /// State regarding panels.
 #[derive(Clone, Copy, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -114,6 +138,9 @@ pub struct SidePanel {
     show_separator_line: bool,
     default_width: f32,
     width_range: Rangef,
+
+    /// Lay out the contents at this width, and clip them to the panel.
+    content_width: Option<f32>,
 }
 
 impl SidePanel {
@@ -137,6 +164,7 @@ impl SidePanel {
             show_separator_line: true,
             default_width: 200.0,
             width_range: Rangef::new(96.0, f32::INFINITY),
+            content_width: None,
         }
     }
 
@@ -240,6 +268,7 @@ impl SidePanel {
             show_separator_line,
             default_width,
             width_range,
+            content_width,
         } = self;
 
         let available_rect = ui.available_rect_before_wrap();
@@ -275,6 +304,15 @@ impl SidePanel {
 
         panel_rect = panel_rect.round_ui();
 
+        // While animating, the contents stick to the inner edge and slide out past the outer edge:
+        let mut content_rect = panel_rect;
+        let mut min_width = width_range.min;
+        if let Some(content_width) = content_width.filter(|&w| w > panel_rect.width()) {
+            side.opposite()
+                .set_rect_width(&mut content_rect, content_width.round_ui());
+            min_width = content_rect.width();
+        }
+
         let mut panel_ui = ui.new_child(
             UiBuilder::new()
                 .id_salt(id)
@@ -282,7 +320,7 @@ impl SidePanel {
                     Side::Left => UiKind::LeftPanel,
                     Side::Right => UiKind::RightPanel,
                 }))
-                .max_rect(panel_rect)
+                .max_rect(content_rect)
                 .layout(Layout::top_down(Align::Min)),
         );
         panel_ui.expand_to_include_rect(panel_rect);
@@ -291,11 +329,14 @@ impl SidePanel {
         let frame = frame.unwrap_or_else(|| Frame::side_top_panel(ui.style()));
         let inner_response = frame.show(&mut panel_ui, |ui| {
             ui.set_min_height(ui.max_rect().height()); // Make sure the frame fills the full height
-            ui.set_min_width((width_range.min - frame.inner_margin.sum().x).at_least(0.0));
+            ui.set_min_width((min_width - frame.inner_margin.sum().x).at_least(0.0));
             add_contents(ui)
         });
 
-        let rect = inner_response.response.rect;
+        let mut rect = inner_response.response.rect;
+        if content_rect != panel_rect {
+            rect = rect.intersect(panel_rect);
+        }
 
         {
             let mut cursor = ui.cursor();
@@ -533,6 +574,55 @@ impl SidePanel {
             expanded_panel.show_inside(ui, |ui| add_contents(ui, how_expanded))
         }
     }
+
+    /// Show the panel, smoothly animating its width whenever `target_width` changes.
+    ///
+    /// While animating, the contents are laid out at their final width and clipped to the panel,
+    /// so they slide in and out instead of being squeezed.
+    ///
+    /// The width is controlled by `target_width`, so the user can't resize the panel.
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// # let is_expanded = true;
+    /// let width = if is_expanded { 240.0 } else { 48.0 };
+    /// egui::SidePanel::left("sidebar")
+    ///     .min_width(48.0)
+    ///     .show_animated_width(ctx, width, |ui| {
+    ///         ui.label("Sidebar");
+    ///     });
+    /// # });
+    /// ```
+    pub fn show_animated_width<R>(
+        self,
+        ctx: &Context,
+        target_width: f32,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        self.animating_width(ctx, target_width)
+            .show(ctx, add_contents)
+    }
+
+    /// Show the panel inside a [`Ui`], smoothly animating its width whenever `target_width` changes.
+    ///
+    /// See [`Self::show_animated_width`].
+    pub fn show_animated_width_inside<R>(
+        self,
+        ui: &mut Ui,
+        target_width: f32,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        self.animating_width(ui.ctx(), target_width)
+            .show_inside(ui, add_contents)
+    }
+
+    fn animating_width(mut self, ctx: &Context, target_width: f32) -> Self {
+        let target_width = clamp_to_range(target_width, self.width_range);
+        let (width, content_width) =
+            animate_size(ctx, self.id.with("animated_width"), target_width);
+        self.content_width = content_width;
+        self.resizable(false).exact_width(width)
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -601,6 +691,9 @@ pub struct TopBottomPanel {
     show_separator_line: bool,
     default_height: Option<f32>,
     height_range: Rangef,
+
+    /// Lay out the contents at this height, and clip them to the panel.
+    content_height: Option<f32>,
 }
 
 impl TopBottomPanel {
@@ -624,6 +717,7 @@ impl TopBottomPanel {
             show_separator_line: true,
             default_height: None,
             height_range: Rangef::new(20.0, f32::INFINITY),
+            content_height: None,
         }
     }
 
@@ -730,6 +824,7 @@ impl TopBottomPanel {
             show_separator_line,
             default_height,
             height_range,
+            content_height,
         } = self;
 
         let frame = frame.unwrap_or_else(|| Frame::side_top_panel(ui.style()));
@@ -772,6 +867,15 @@ impl TopBottomPanel {
 
         panel_rect = panel_rect.round_ui();
 
+        // While animating, the contents stick to the inner edge and slide out past the outer edge:
+        let mut content_rect = panel_rect;
+        let mut min_height = height_range.min;
+        if let Some(content_height) = content_height.filter(|&h| h > panel_rect.height()) {
+            side.opposite()
+                .set_rect_height(&mut content_rect, content_height.round_ui());
+            min_height = content_rect.height();
+        }
+
         let mut panel_ui = ui.new_child(
             UiBuilder::new()
                 .id_salt(id)
@@ -779,7 +883,7 @@ impl TopBottomPanel {
                     TopBottomSide::Top => UiKind::TopPanel,
                     TopBottomSide::Bottom => UiKind::BottomPanel,
                 }))
-                .max_rect(panel_rect)
+                .max_rect(content_rect)
                 .layout(Layout::top_down(Align::Min)),
         );
         panel_ui.expand_to_include_rect(panel_rect);
@@ -787,11 +891,14 @@ impl TopBottomPanel {
 
         let inner_response = frame.show(&mut panel_ui, |ui| {
             ui.set_min_width(ui.max_rect().width()); // Make the frame fill full width
-            ui.set_min_height((height_range.min - frame.inner_margin.sum().y).at_least(0.0));
+            ui.set_min_height((min_height - frame.inner_margin.sum().y).at_least(0.0));
             add_contents(ui)
         });
 
-        let rect = inner_response.response.rect;
+        let mut rect = inner_response.response.rect;
+        if content_rect != panel_rect {
+            rect = rect.intersect(panel_rect);
+        }
 
         {
             let mut cursor = ui.cursor();
@@ -1052,6 +1159,43 @@ impl TopBottomPanel {
             expanded_panel.show_inside(ui, |ui| add_contents(ui, how_expanded))
         }
     }
+
+    /// Show the panel, smoothly animating its height whenever `target_height` changes.
+    ///
+    /// While animating, the contents are laid out at their final height and clipped to the panel,
+    /// so they slide in and out instead of being squeezed.
+    ///
+    /// The height is controlled by `target_height`, so the user can't resize the panel.
+    pub fn show_animated_height<R>(
+        self,
+        ctx: &Context,
+        target_height: f32,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        self.animating_height(ctx, target_height)
+            .show(ctx, add_contents)
+    }
+
+    /// Show the panel inside a [`Ui`], smoothly animating its height whenever `target_height` changes.
+    ///
+    /// See [`Self::show_animated_height`].
+    pub fn show_animated_height_inside<R>(
+        self,
+        ui: &mut Ui,
+        target_height: f32,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        self.animating_height(ui.ctx(), target_height)
+            .show_inside(ui, add_contents)
+    }
+
+    fn animating_height(mut self, ctx: &Context, target_height: f32) -> Self {
+        let target_height = clamp_to_range(target_height, self.height_range);
+        let (height, content_height) =
+            animate_size(ctx, self.id.with("animated_height"), target_height);
+        self.content_height = content_height;
+        self.resizable(false).exact_height(height)
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -2,11 +2,11 @@
 
 use std::iter::once;
 
-use emath::{Align, Pos2, Rect, RectAlign, Vec2, vec2};
+use emath::{Align, Align2, Pos2, Rect, RectAlign, Vec2, vec2};
 
 use crate::{
     Area, AreaState, Context, Frame, Id, InnerResponse, Key, LayerId, Layout, Order, Response,
-    Sense, Ui, UiKind, UiStackInfo,
+    ScrollArea, Sense, Ui, UiKind, UiStackInfo,
     containers::menu::{MenuConfig, MenuState, menu_style},
     style::StyleModifier,
 };
@@ -134,6 +134,93 @@ impl OpenKind<'_> {
     }
 }
 
+/// How big a [`Popup`] may get, and what to do with contents that don't fit.
+///
+/// See [`Popup::size_policy`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PopupSizePolicy {
+    /// The maximum outer width of the popup.
+    ///
+    /// Default: no limit.
+    pub max_width: f32,
+
+    /// The maximum outer height of the popup.
+    ///
+    /// Default: no limit.
+    pub max_height: f32,
+
+    /// Make the popup smaller if it would otherwise extend past the edge of the screen.
+    ///
+    /// If the popup doesn't fit on any side of the anchor,
+    /// it is put above or below the anchor, wherever there is more room.
+    ///
+    /// Default: `true`.
+    pub shrink_to_fit_screen: bool,
+
+    /// Wrap the contents in a vertical [`ScrollArea`], so contents that don't fit can be scrolled to.
+    ///
+    /// Default: `true`.
+    pub scroll: bool,
+}
+
+impl Default for PopupSizePolicy {
+    fn default() -> Self {
+        Self {
+            max_width: f32::INFINITY,
+            max_height: f32::INFINITY,
+            shrink_to_fit_screen: true,
+            scroll: true,
+        }
+    }
+}
+
+impl PopupSizePolicy {
+    /// See [`Self::max_width`].
+    #[inline]
+    pub fn max_width(mut self, max_width: f32) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// See [`Self::max_height`].
+    #[inline]
+    pub fn max_height(mut self, max_height: f32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    /// See [`Self::shrink_to_fit_screen`].
+    #[inline]
+    pub fn shrink_to_fit_screen(mut self, shrink_to_fit_screen: bool) -> Self {
+        self.shrink_to_fit_screen = shrink_to_fit_screen;
+        self
+    }
+
+    /// See [`Self::scroll`].
+    #[inline]
+    pub fn scroll(mut self, scroll: bool) -> Self {
+        self.scroll = scroll;
+        self
+    }
+
+    /// The maximum outer size of a popup growing away from `pos` in the direction given by `pivot`.
+    fn max_size(&self, pivot: Align2, pos: Pos2, screen_rect: Rect) -> Vec2 {
+        let mut max_size = vec2(self.max_width, self.max_height);
+        if self.shrink_to_fit_screen {
+            let available = |align: Align, pos: f32, min: f32, max: f32| match align {
+                Align::Min => max - pos,
+                Align::Center => 2.0 * (pos - min).min(max - pos),
+                Align::Max => pos - min,
+            };
+            max_size = max_size.min(vec2(
+                available(pivot.x(), pos.x, screen_rect.left(), screen_rect.right()),
+                available(pivot.y(), pos.y, screen_rect.top(), screen_rect.bottom()),
+            ));
+        }
+        max_size.max(Vec2::ZERO)
+    }
+}
+
 /// Is the popup a popup, tooltip or menu?
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum PopupKind {
@@ -188,6 +275,7 @@ pub struct Popup<'a> {
     layout: Layout,
     frame: Option<Frame>,
     style: StyleModifier,
+    size_policy: Option<PopupSizePolicy>,
 }
 
 impl<'a> Popup<'a> {
@@ -211,6 +299,7 @@ impl<'a> Popup<'a> {
             layout: Layout::default(),
             frame: None,
             style: StyleModifier::default(),
+            size_policy: None,
         }
     }
 
@@ -398,6 +487,28 @@ impl<'a> Popup<'a> {
         self
     }
 
+    /// Limit the size of the popup, and scroll contents that don't fit.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let response = ui.button("Open");
+    /// egui::Popup::menu(&response)
+    ///     .size_policy(egui::PopupSizePolicy::default().max_height(200.0))
+    ///     .show(|ui| {
+    ///         for i in 0..1000 {
+    ///             ui.label(format!("Item {i}"));
+    ///         }
+    ///     });
+    /// # });
+    /// ```
+    ///
+    /// Default: `None`, meaning the popup grows as big as its contents.
+    #[inline]
+    pub fn size_policy(mut self, size_policy: impl Into<Option<PopupSizePolicy>>) -> Self {
+        self.size_policy = size_policy.into();
+        self
+    }
+
     /// Set the id of the Area.
     #[inline]
     pub fn id(mut self, id: impl Into<Id>) -> Self {
@@ -463,10 +574,39 @@ impl<'a> Popup<'a> {
     }
 
     /// Get the expected size of the popup.
+    ///
+    /// With a [`Self::size_policy`], this is the size it would have if it wasn't limited.
     pub fn get_expected_size(&self) -> Option<Vec2> {
+        if self.size_policy.is_some() {
+            let desired_size = self
+                .ctx
+                .data(|data| data.get_temp(Self::desired_size_id(self.id)));
+            if desired_size.is_some() {
+                return desired_size;
+            }
+        }
         AreaState::load(&self.ctx, self.id).and_then(|area| area.size)
     }
 
+    fn desired_size_id(popup_id: Id) -> Id {
+        popup_id.with("desired_size")
+    }
+
+    fn ignore_clicks_inside_id(popup_id: Id) -> Id {
+        popup_id.with("ignore_clicks_inside")
+    }
+
+    /// Don't let clicks inside the popup with the given id close it this pass.
+    ///
+    /// For this pass, [`PopupCloseBehavior::CloseOnClick`] acts like
+    /// [`PopupCloseBehavior::CloseOnClickOutside`].
+    /// Call this from the contents of the popup, e.g. to keep a menu open while an action
+    /// started from it is running.
+    pub fn ignore_clicks_inside(ctx: &Context, popup_id: Id) {
+        let pass_nr = ctx.cumulative_pass_nr();
+        ctx.data_mut(|d| d.insert_temp(Self::ignore_clicks_inside_id(popup_id), pass_nr));
+    }
+
     /// Calculate the best alignment for the popup, based on the last size and screen rect.
     pub fn get_best_align(&self) -> RectAlign {
         let expected_popup_size = self
@@ -477,7 +617,8 @@ impl<'a> Popup<'a> {
             return self.rect_align;
         };
 
-        RectAlign::find_best_align(
+        let screen_rect = self.ctx.screen_rect();
+        let best_align = RectAlign::find_best_align(
             #[expect(clippy::iter_on_empty_collections)]
             once(self.rect_align).chain(
                 self.alternative_aligns
@@ -491,12 +632,32 @@ impl<'a> Popup<'a> {
                             .chain(RectAlign::MENU_ALIGNS.iter().copied()),
                     ),
             ),
-            self.ctx.screen_rect(),
+            screen_rect,
             anchor_rect,
             self.gap,
             expected_popup_size,
         )
-        .unwrap_or_default()
+        .unwrap_or_default();
+
+        let fits = |align: RectAlign| {
+            screen_rect.contains_rect(align.align_rect(&anchor_rect, expected_popup_size, self.gap))
+        };
+        match self.size_policy {
+            Some(policy) if policy.shrink_to_fit_screen && !fits(best_align) => {
+                // It will be shrunk, so put it on the side where it can be the tallest:
+                let max_height = |align: RectAlign| {
+                    let (pivot, pos) = align.pivot_pos(&anchor_rect, self.gap);
+                    policy.max_size(pivot, pos, screen_rect).y
+                };
+                let flipped = best_align.flip_y();
+                if max_height(best_align) < max_height(flipped) {
+                    flipped
+                } else {
+                    best_align
+                }
+            }
+            _ => best_align,
+        }
     }
 
     /// Show the popup.
@@ -553,6 +714,7 @@ impl<'a> Popup<'a> {
             layout,
             frame,
             style,
+            size_policy,
         } = self;
 
         if kind != PopupKind::Tooltip {
@@ -588,13 +750,49 @@ impl<'a> Popup<'a> {
             area = area.default_width(width);
         }
 
+        let max_size =
+            size_policy.map(|policy| (policy, policy.max_size(pivot, anchor, ctx.screen_rect())));
+
         let mut response = area.show(&ctx, |ui| {
             style.apply(ui.style_mut());
             let frame = frame.unwrap_or_else(|| Frame::popup(ui.style()));
-            frame.show(ui, content).inner
+            let Some((policy, max_size)) = max_size else {
+                return frame.show(ui, content).inner;
+            };
+
+            let max_inner_size = (max_size - frame.total_margin().sum()).max(Vec2::ZERO);
+            frame
+                .show(ui, |ui| {
+                    ui.set_max_width(ui.max_rect().width().min(max_inner_size.x));
+                    if max_inner_size.y.is_finite() {
+                        // The area is only as big as it was last frame, so make room to grow:
+                        ui.set_max_height(max_inner_size.y);
+                    }
+                    if policy.scroll {
+                        let output = ScrollArea::vertical()
+                            .max_height(max_inner_size.y)
+                            .show(ui, content);
+                        let desired_size = vec2(output.inner_rect.width(), output.content_size.y)
+                            + frame.total_margin().sum();
+                        ui.data_mut(|data| {
+                            data.insert_temp(Self::desired_size_id(id), desired_size);
+                        });
+                        output.inner
+                    } else {
+                        content(ui)
+                    }
+                })
+                .inner
         });
 
+        let pass_nr = ctx.cumulative_pass_nr();
+        let ignore_clicks_inside = ctx
+            .data_mut(|d| d.remove_temp::<u64>(Self::ignore_clicks_inside_id(id)))
+            == Some(pass_nr);
         let closed_by_click = match close_behavior {
+            PopupCloseBehavior::CloseOnClick if ignore_clicks_inside => {
+                widget_clicked_elsewhere && response.response.clicked_elsewhere()
+            }
             PopupCloseBehavior::CloseOnClick => widget_clicked_elsewhere,
             PopupCloseBehavior::CloseOnClickOutside => {
                 widget_clicked_elsewhere && response.response.clicked_elsewhere()
@@ -692,3 +890,38 @@ impl Popup<'_> {
         ctx.memory(|mem| mem.popup_position(popup_id))
     }
 }
+
+#[test]
+fn test_popup_size_policy() {
+    let ctx = Context::default();
+    let screen_rect = Rect::from_min_size(Pos2::ZERO, vec2(800.0, 600.0));
+
+    let mut popup_rect = Rect::NOTHING;
+    for _ in 0..3 {
+        let input = crate::RawInput {
+            screen_rect: Some(screen_rect),
+            ..Default::default()
+        };
+        let _ = ctx.run(input, |ctx| {
+            // Near the bottom, with more space above than below:
+            let anchor = Rect::from_min_size(Pos2::new(100.0, 500.0), vec2(100.0, 20.0));
+            popup_rect = Popup::new("popup", ctx.clone(), anchor, LayerId::background())
+                .size_policy(PopupSizePolicy::default())
+                .show(|ui| {
+                    for i in 0..100 {
+                        ui.label(format!("Item {i}"));
+                    }
+                })
+                .unwrap()
+                .response
+                .rect;
+        });
+    }
+
+    assert!(screen_rect.contains_rect(popup_rect), "{popup_rect:?}");
+    assert!(
+        popup_rect.bottom() <= 500.0,
+        "Should flip above: {popup_rect:?}"
+    );
+    assert!(popup_rect.height() > 400.0, "{popup_rect:?}");
+}
@@ -17,6 +17,11 @@ pub(crate) struct State {
 
     /// Externally requested size (e.g. by Window) for the next frame
     pub(crate) requested_size: Option<Vec2>,
+
+    /// The smallest size the content has been measured to need,
+    /// see [`Resize::min_size_from_content`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) content_min_size: Option<Vec2>,
 }
 
 impl State {
@@ -27,6 +32,30 @@ impl State {
     pub fn store(self, ctx: &Context, id: impl Into<Id>) {
         ctx.data_mut(|d| d.insert_persisted(id, self));
     }
+
+    /// Re-derive [`Self::content_min_size`] from what the content used this pass.
+    fn update_content_min_size(&mut self, ctx: &Context) {
+        let measured = self.last_content_size;
+        let previous = self.content_min_size.unwrap_or(Vec2::ZERO);
+        let mut content_min_size = previous;
+
+        for d in 0..2 {
+            if self.desired_size[d] + 0.5 < measured[d] {
+                // The content did not fit, so it needs at least this much:
+                content_min_size[d] = measured[d];
+            } else if measured[d] < content_min_size[d] {
+                // The content fits in less than we thought, so it must have shrunk:
+                content_min_size[d] = measured[d];
+            }
+        }
+
+        self.content_min_size = Some(content_min_size);
+
+        if content_min_size.x > previous.x || content_min_size.y > previous.y {
+            // Lay out again with the new minimum size, so the user never sees the squeezed content:
+            ctx.request_discard("Resize content min size grew");
+        }
+    }
 }
 
 /// A region that can be resized by dragging the bottom right corner.
@@ -45,6 +74,8 @@ pub struct Resize {
     default_size: Vec2,
 
     with_stroke: bool,
+
+    min_size_from_content: bool,
 }
 
 impl Default for Resize {
@@ -57,6 +88,7 @@ impl Default for Resize {
             max_size: Vec2::splat(f32::INFINITY),
             default_size: vec2(320.0, 128.0), // TODO(emilk): preferred size of [`Resize`] area.
             with_stroke: true,
+            min_size_from_content: false,
         }
     }
 }
@@ -135,6 +167,21 @@ impl Resize {
         self
     }
 
+    /// Don't let the user shrink the area to smaller than what the content needs.
+    ///
+    /// The minimum size is measured from the content, and is updated when the content changes.
+    /// When it grows, egui discards the current pass (see [`Context::request_discard`])
+    /// so that the corrected size is shown without a glitch.
+    ///
+    /// This is combined with [`Self::min_size`].
+    ///
+    /// Default is `false`.
+    #[inline]
+    pub fn min_size_from_content(mut self, min_size_from_content: bool) -> Self {
+        self.min_size_from_content = min_size_from_content;
+        self
+    }
+
     /// Won't expand to larger than this
     #[inline]
     pub fn max_size(mut self, max_size: impl Into<Vec2>) -> Self {
@@ -195,6 +242,17 @@ impl Resize {
         self.with_stroke = with_stroke;
         self
     }
+
+    /// [`Self::min_size`], combined with the measured size of the content
+    /// if [`Self::min_size_from_content`] is set.
+    pub(crate) fn effective_min_size(&self, state: Option<&State>) -> Vec2 {
+        match state.and_then(|state| state.content_min_size) {
+            Some(content_min_size) if self.min_size_from_content => {
+                self.min_size.max(content_min_size)
+            }
+            _ => self.min_size,
+        }
+    }
 }
 
 struct Prepared {
@@ -228,12 +286,15 @@ impl Resize {
                 desired_size: default_size,
                 last_content_size: vec2(0.0, 0.0),
                 requested_size: None,
+                content_min_size: None,
             }
         });
 
+        let min_size = self.effective_min_size(Some(&state));
+
         state.desired_size = state
             .desired_size
-            .at_least(self.min_size)
+            .at_least(min_size)
             .at_most(self.max_size)
             .round_ui();
 
@@ -260,10 +321,7 @@ impl Resize {
             state.desired_size = state.desired_size.max(state.last_content_size);
         }
 
-        state.desired_size = state
-            .desired_size
-            .at_least(self.min_size)
-            .at_most(self.max_size);
+        state.desired_size = state.desired_size.at_least(min_size).at_most(self.max_size);
 
         // ------------------------------
 
@@ -314,6 +372,10 @@ impl Resize {
 
         state.last_content_size = content_ui.min_size();
 
+        if self.min_size_from_content {
+            state.update_content_min_size(ui.ctx());
+        }
+
         // ------------------------------
 
         let mut size = state.last_content_size;
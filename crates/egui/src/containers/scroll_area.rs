@@ -3,8 +3,9 @@
 use std::ops::{Add, AddAssign, BitOr, BitOrAssign};
 
 use crate::{
-    Context, CursorIcon, Id, NumExt as _, Pos2, Rangef, Rect, Sense, Ui, UiBuilder, UiKind,
-    UiStackInfo, Vec2, Vec2b, emath, epaint, lerp, pass_state, pos2, remap, remap_clamp,
+    Context, CursorIcon, DragAndDrop, Id, LayerId, NumExt as _, Pos2, Rangef, Rect, Sense, Ui,
+    UiBuilder, UiKind, UiStackInfo, Vec2, Vec2b, emath, emath::TSTransform, epaint, lerp,
+    pass_state, pos2, remap, remap_clamp, style::ScrollAnimation,
 };
 
 #[derive(Clone, Copy, Debug)]
@@ -47,6 +48,9 @@ pub struct State {
 
     /// Area that can be dragged. This is the size of the content from the last frame.
     interact_rect: Option<Rect>,
+
+    /// How much the content is scaled, see [`ScrollArea::zoom_range`].
+    zoom: f32,
 }
 
 impl Default for State {
@@ -61,6 +65,7 @@ impl Default for State {
             scroll_start_offset_from_top_left: [None; 2],
             scroll_stuck_to_end: Vec2b::TRUE,
             interact_rect: None,
+            zoom: 1.0,
         }
     }
 }
@@ -78,6 +83,68 @@ impl State {
     pub fn velocity(&self) -> Vec2 {
         self.vel
     }
+
+    /// How much the content is scaled, if zooming is enabled with [`ScrollArea::zoom_range`].
+    ///
+    /// `2.0` means the content is shown at twice its size.
+    pub fn zoom(&self) -> f32 {
+        self.zoom
+    }
+
+    /// Scroll to the given offset, with an animation.
+    ///
+    /// The scrolling happens when the [`ScrollArea`] is shown,
+    /// so that it is reported in [`ScrollAreaOutput::on_scroll`].
+    pub(crate) fn scroll_to_offset(&mut self, offset: Vec2, animation: ScrollAnimation, now: f64) {
+        for d in 0..2 {
+            self.scroll_stuck_to_end[d] = false;
+            self.vel[d] = 0.0;
+            self.offset_target[d] = None; // Restart any ongoing animation
+            self.animate_to(d, offset[d], animation, now);
+        }
+    }
+
+    /// Smoothly scroll to the given offset along one axis.
+    ///
+    /// If we are already scrolling there, only the target is updated.
+    fn animate_to(&mut self, d: usize, target_offset: f32, animation: ScrollAnimation, now: f64) {
+        if let Some(scrolling) = &mut self.offset_target[d] {
+            // For instance: the user is continuously calling `ui.scroll_to_cursor`,
+            // so we don't want to reset the animation, but perhaps update the target:
+            scrolling.target_offset = target_offset;
+        } else {
+            // The further we scroll, the more time we take.
+            let delta = target_offset - self.offset[d];
+            let animation_duration = (delta.abs() / animation.points_per_second)
+                .clamp(animation.duration.min, animation.duration.max);
+            self.offset_target[d] = Some(ScrollingToTarget {
+                animation_time_span: (now, now + animation_duration as f64),
+                target_offset,
+            });
+        }
+    }
+}
+
+/// Information about the scrolling of a [`ScrollArea`] during a pass,
+/// see [`ScrollAreaOutput::on_scroll`].
+#[derive(Clone, Copy, Debug)]
+pub struct ScrollEvent {
+    /// The offset at the end of the pass.
+    ///
+    /// Positive offset means scrolling down/right.
+    pub offset: Vec2,
+
+    /// How much the offset changed during the pass.
+    pub delta: Vec2,
+
+    /// How fast the offset is changing, in points per second.
+    pub velocity: Vec2,
+
+    /// Did the user scroll (e.g. with the mouse wheel, scroll bars or by dragging)?
+    ///
+    /// If `false`, the scroll area was scrolled by code,
+    /// e.g. with [`Ui::scroll_to_rect`] or [`Context::scroll_to_offset_animated`].
+    pub user_driven: bool,
 }
 
 pub struct ScrollAreaOutput<R> {
@@ -96,6 +163,11 @@ pub struct ScrollAreaOutput<R> {
 
     /// Where on the screen the content is (excludes scroll bars).
     pub inner_rect: Rect,
+
+    /// Set if the scroll area was scrolled during this pass.
+    ///
+    /// Useful for keeping other scroll areas in sync, or for remembering the scroll position.
+    pub on_scroll: Option<ScrollEvent>,
 }
 
 /// Indicate whether the horizontal and vertical scroll bars must be always visible, hidden or visible when needed.
@@ -299,6 +371,16 @@ pub struct ScrollArea {
 
     /// If false, `scroll_to_*` functions will not be animated
     animated: bool,
+
+    /// Scroll when a drag hovers near the edges.
+    drag_scroll: bool,
+
+    /// Share the offset with other scroll areas in this group.
+    sync_group: Option<Id>,
+    sync_axes: Vec2b,
+
+    /// If set, the content can be zoomed.
+    zoom_range: Option<Rangef>,
 }
 
 impl ScrollArea {
@@ -346,6 +428,10 @@ impl ScrollArea {
             wheel_scroll_multiplier: Vec2::splat(1.0),
             stick_to_end: Vec2b::FALSE,
             animated: true,
+            drag_scroll: false,
+            sync_group: None,
+            sync_axes: Vec2b::TRUE,
+            zoom_range: None,
         }
     }
 
@@ -586,6 +672,86 @@ impl ScrollArea {
         self
     }
 
+    /// Scroll automatically while something is dragged near the edges of the scroll area.
+    ///
+    /// This applies to drag-and-drop payloads (see [`crate::DragAndDrop`]),
+    /// and to drags that started inside the scroll area, like selecting text.
+    /// The closer the pointer is to the edge (or the further past it), the faster it scrolls.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn drag_scroll(mut self, drag_scroll: bool) -> Self {
+        self.drag_scroll = drag_scroll;
+        self
+    }
+
+    /// Keep the scroll offset in sync with all other scroll areas in the same group.
+    ///
+    /// When any of them is scrolled, the others follow.
+    /// Useful for side-by-side diffs, tables with frozen columns, or a timeline with a ruler.
+    ///
+    /// Use [`Self::sync_axes`] to only sync one axis.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let group = egui::Id::new("diff_panes");
+    /// ui.columns(2, |columns| {
+    ///     egui::ScrollArea::vertical()
+    ///         .id_salt("left")
+    ///         .sync_group(group)
+    ///         .show(&mut columns[0], |ui| ui.label("Old"));
+    ///     egui::ScrollArea::vertical()
+    ///         .id_salt("right")
+    ///         .sync_group(group)
+    ///         .show(&mut columns[1], |ui| ui.label("New"));
+    /// });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn sync_group(mut self, group: impl Into<Id>) -> Self {
+        self.sync_group = Some(group.into());
+        self
+    }
+
+    /// Allow zooming the content with ctrl+scroll (or pinch), within the given range.
+    ///
+    /// The point under the mouse stays fixed while zooming,
+    /// and the scroll bars reflect the zoomed size of the content.
+    /// The current zoom level is [`State::zoom`].
+    ///
+    /// The contents are laid out at their normal size, and then scaled
+    /// (like [`crate::Scene`] does it), so text may become blurry when zooming in.
+    /// Interaction with the contents works as usual.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let output = egui::ScrollArea::both()
+    ///     .zoom_range(0.1..=10.0)
+    ///     .show(ui, |ui| {
+    ///         ui.label("Zoom me with ctrl+scroll");
+    ///     });
+    /// ui.label(format!("Zoom: {:.0}%", 100.0 * output.state.zoom()));
+    /// # });
+    /// ```
+    ///
+    /// Default: no zooming.
+    #[inline]
+    pub fn zoom_range(mut self, zoom_range: impl Into<Rangef>) -> Self {
+        self.zoom_range = Some(zoom_range.into());
+        self
+    }
+
+    /// Which axes to keep in sync with the [`Self::sync_group`].
+    ///
+    /// For instance, `[false, true]` only syncs the vertical offset.
+    ///
+    /// Default: both axes.
+    #[inline]
+    pub fn sync_axes(mut self, axes: impl Into<Vec2b>) -> Self {
+        self.sync_axes = axes.into();
+        self
+    }
+
     /// Is any scrolling enabled?
     pub(crate) fn is_any_scroll_enabled(&self) -> bool {
         self.direction_enabled[0] || self.direction_enabled[1]
@@ -660,6 +826,24 @@ struct Prepared {
     saved_scroll_target: [Option<pass_state::ScrollTarget>; 2],
 
     animated: bool,
+    drag_scroll: bool,
+
+    /// The offset at the start of the pass, before anything scrolled.
+    offset_at_start: Vec2,
+
+    sync_group: Option<Id>,
+    sync_axes: Vec2b,
+
+    /// The offset of the [`ScrollArea::sync_group`] at the start of the pass.
+    group_offset: Option<Vec2>,
+
+    zoom_range: Option<Rangef>,
+
+    /// The layer of the contents, if they are zoomed.
+    zoom_layer: Option<LayerId>,
+
+    /// Did the user scroll in [`ScrollArea::begin`]?
+    user_scrolled: bool,
 }
 
 impl ScrollArea {
@@ -680,6 +864,10 @@ impl ScrollArea {
             wheel_scroll_multiplier,
             stick_to_end,
             animated,
+            drag_scroll,
+            sync_group,
+            sync_axes,
+            zoom_range,
         } = self;
 
         let ctx = ui.ctx().clone();
@@ -692,10 +880,32 @@ impl ScrollArea {
             "ScrollArea",
         );
         let mut state = State::load(&ctx, id).unwrap_or_default();
+        let offset_at_start = state.offset;
+        let mut user_scrolled = false;
+
+        // Follow the other scroll areas in the group:
+        let group_offset = sync_group
+            .and_then(|group| ctx.data(|data| data.get_temp::<SyncGroupState>(group)))
+            .map(|group| group.offset);
+        if let Some(group_offset) = group_offset {
+            for d in 0..2 {
+                if sync_axes[d] && state.offset[d] != group_offset[d] {
+                    state.offset[d] = group_offset[d];
+                    state.vel[d] = 0.0;
+                }
+            }
+        }
 
         state.offset.x = offset_x.unwrap_or(state.offset.x);
         state.offset.y = offset_y.unwrap_or(state.offset.y);
 
+        let zoom = if let Some(zoom_range) = zoom_range {
+            state.zoom = zoom_range.clamp(state.zoom);
+            state.zoom
+        } else {
+            1.0
+        };
+
         let show_bars: Vec2b = match scroll_bar_visibility {
             ScrollBarVisibility::AlwaysHidden => Vec2b::FALSE,
             ScrollBarVisibility::VisibleWhenNeeded => state.show_scroll,
@@ -744,12 +954,25 @@ impl ScrollArea {
             }
         }
 
-        let content_max_rect = Rect::from_min_size(inner_rect.min - state.offset, content_max_size);
-        let mut content_ui = ui.new_child(
-            UiBuilder::new()
-                .ui_stack_info(UiStackInfo::new(UiKind::ScrollArea))
-                .max_rect(content_max_rect),
-        );
+        let zoom_layer = zoom_range.map(|_| {
+            // Zoomed contents are laid out unscrolled, and moved into place by transforming their layer:
+            let zoom_layer = LayerId::new(ui.layer_id().order, id.with("zoom"));
+            ctx.set_sublayer(ui.layer_id(), zoom_layer);
+            zoom_layer
+        });
+
+        let content_max_rect = if zoom_layer.is_some() {
+            Rect::from_min_size(inner_rect.min, content_max_size / zoom)
+        } else {
+            Rect::from_min_size(inner_rect.min - state.offset, content_max_size)
+        };
+        let mut content_builder = UiBuilder::new()
+            .ui_stack_info(UiStackInfo::new(UiKind::ScrollArea))
+            .max_rect(content_max_rect);
+        if let Some(zoom_layer) = zoom_layer {
+            content_builder = content_builder.layer_id(zoom_layer);
+        }
+        let mut content_ui = ui.new_child(content_builder);
 
         {
             // Clip the content, but only when we really need to:
@@ -766,10 +989,15 @@ impl ScrollArea {
             }
             // Make sure we didn't accidentally expand the clip rect
             content_clip_rect = content_clip_rect.intersect(ui.clip_rect());
+            if let Some(zoom_layer) = zoom_layer {
+                let to_parent = zoom_transform(inner_rect.min, state.offset, zoom);
+                set_zoom_transform(ui, zoom_layer, to_parent);
+                content_clip_rect = to_parent.inverse() * content_clip_rect;
+            }
             content_ui.set_clip_rect(content_clip_rect);
         }
 
-        let viewport = Rect::from_min_size(Pos2::ZERO + state.offset, inner_size);
+        let viewport = Rect::from_min_size(Pos2::ZERO + state.offset / zoom, inner_size / zoom);
         let dt = ui.input(|i| i.stable_dt).at_most(0.1);
 
         if scroll_source.drag
@@ -796,14 +1024,15 @@ impl ScrollArea {
                         state.offset_target[d] = None;
                     }
                 }
+                user_scrolled = true;
             } else {
                 // Apply the cursor velocity to the scroll area when the user releases the drag.
                 if content_response_option
                     .as_ref()
                     .is_some_and(|response| response.drag_stopped())
                 {
-                    state.vel =
-                        direction_enabled.to_vec2() * ui.input(|input| input.pointer.velocity());
+                    state.vel = direction_enabled.to_vec2()
+                        * ui.input(|input| input.pointer.smoothed_velocity());
                 }
                 for d in 0..2 {
                     // Kinetic scrolling
@@ -818,6 +1047,7 @@ impl ScrollArea {
                         // Offset has an inverted coordinate system compared to
                         // the velocity, so we subtract it instead of adding it
                         state.offset[d] -= state.vel[d] * dt;
+                        user_scrolled = true;
                         ctx.request_repaint();
                     }
                 }
@@ -889,6 +1119,14 @@ impl ScrollArea {
             stick_to_end,
             saved_scroll_target,
             animated,
+            drag_scroll,
+            offset_at_start,
+            sync_group,
+            sync_axes,
+            group_offset,
+            zoom_range,
+            zoom_layer,
+            user_scrolled,
         }
     }
 
@@ -973,20 +1211,21 @@ impl ScrollArea {
         let id = prepared.id;
         let inner_rect = prepared.inner_rect;
         let inner = add_contents(&mut prepared.content_ui, prepared.viewport);
-        let (content_size, state) = prepared.end(ui);
+        let (content_size, state, on_scroll) = prepared.end(ui);
         ScrollAreaOutput {
             inner,
             id,
             state,
             content_size,
             inner_rect,
+            on_scroll,
         }
     }
 }
 
 impl Prepared {
-    /// Returns content size and state
-    fn end(self, ui: &mut Ui) -> (Vec2, State) {
+    /// Returns content size, state, and how it was scrolled
+    fn end(self, ui: &mut Ui) -> (Vec2, State, Option<ScrollEvent>) {
         let Self {
             id,
             mut state,
@@ -1004,14 +1243,36 @@ impl Prepared {
             stick_to_end,
             saved_scroll_target,
             animated,
+            drag_scroll,
+            offset_at_start,
+            sync_group,
+            sync_axes,
+            group_offset,
+            zoom_range,
+            zoom_layer,
+            mut user_scrolled,
         } = self;
 
-        let content_size = content_ui.min_size();
+        if let Some(zoom_range) = zoom_range {
+            if scroll_source.mouse_wheel && ui.is_enabled() {
+                user_scrolled |= zoom_around_pointer(ui, &mut state, inner_rect, zoom_range);
+            }
+        }
+        let zoom = if zoom_layer.is_some() {
+            state.zoom
+        } else {
+            1.0
+        };
+
+        let content_size = content_ui.min_size() * zoom;
 
         let scroll_delta = content_ui
             .ctx()
             .pass_state_mut(|state| std::mem::take(&mut state.scroll_delta));
 
+        // Targets that should also scroll the ScrollAreas up the stack:
+        let mut parent_scroll_target: [Option<pass_state::ScrollTarget>; 2] = [None, None];
+
         for d in 0..2 {
             // PassState::scroll_delta is inverted from the way we apply the delta, so we need to negate it.
             let mut delta = -scroll_delta.0[d];
@@ -1023,12 +1284,25 @@ impl Prepared {
                 .ctx()
                 .pass_state_mut(|state| state.scroll_target[d].take());
 
+            if let Some(target) = scroll_target.as_ref().filter(|t| t.scroll_parents) {
+                parent_scroll_target[d] = Some(if direction_enabled[d] {
+                    // We scroll to the target, our parents should scroll to us:
+                    pass_state::ScrollTarget {
+                        range: Rangef::new(inner_rect.min[d], inner_rect.max[d]),
+                        ..target.clone()
+                    }
+                } else {
+                    target.clone()
+                });
+            }
+
             if direction_enabled[d] {
                 if let Some(target) = scroll_target {
                     let pass_state::ScrollTarget {
                         range,
                         align,
                         animation: animation_update,
+                        scroll_parents: _,
                     } = target;
                     let min = content_ui.min_rect().min[d];
                     let clip_rect = content_ui.clip_rect();
@@ -1047,7 +1321,7 @@ impl Prepared {
                         // Depending on the alignment we need to add or subtract the spacing
                         spacing *= remap(center_factor, 0.0..=1.0, -1.0..=1.0);
 
-                        offset + spacing - state.offset[d]
+                        offset + spacing - state.offset[d] / zoom
                     } else if start < clip_start && end < clip_end {
                         -(clip_start - start + spacing).min(clip_end - end - spacing)
                     } else if end > clip_end && start > clip_start {
@@ -1057,28 +1331,18 @@ impl Prepared {
                         0.0
                     };
 
-                    delta += delta_update;
+                    delta += delta_update * zoom;
                     animation = animation_update;
                 };
 
                 if delta != 0.0 {
                     let target_offset = state.offset[d] + delta;
 
-                    if !animated {
-                        state.offset[d] = target_offset;
-                    } else if let Some(animation) = &mut state.offset_target[d] {
-                        // For instance: the user is continuously calling `ui.scroll_to_cursor`,
-                        // so we don't want to reset the animation, but perhaps update the target:
-                        animation.target_offset = target_offset;
-                    } else {
-                        // The further we scroll, the more time we take.
+                    if animated {
                         let now = ui.input(|i| i.time);
-                        let animation_duration = (delta.abs() / animation.points_per_second)
-                            .clamp(animation.duration.min, animation.duration.max);
-                        state.offset_target[d] = Some(ScrollingToTarget {
-                            animation_time_span: (now, now + animation_duration as f64),
-                            target_offset,
-                        });
+                        state.animate_to(d, target_offset, animation, now);
+                    } else {
+                        state.offset[d] = target_offset;
                     }
                     ui.ctx().request_repaint();
                 }
@@ -1090,7 +1354,9 @@ impl Prepared {
             for d in 0..2 {
                 if saved_scroll_target[d].is_some() {
                     state.scroll_target[d] = saved_scroll_target[d].clone();
-                };
+                } else if parent_scroll_target[d].is_some() {
+                    state.scroll_target[d] = parent_scroll_target[d].take();
+                }
             }
         });
 
@@ -1152,11 +1418,27 @@ impl Prepared {
 
                         state.scroll_stuck_to_end[d] = false;
                         state.offset_target[d] = None;
+                        user_scrolled = true;
                     }
                 }
             }
         }
 
+        if drag_scroll && ui.is_enabled() {
+            let delta = drag_scroll_delta(ui, id, inner_rect, direction_enabled);
+            for d in 0..2 {
+                let scrolling_up = state.offset[d] > 0.0 && delta[d] < 0.0;
+                let scrolling_down = state.offset[d] < max_offset[d] && delta[d] > 0.0;
+                if scrolling_up || scrolling_down {
+                    state.offset[d] += delta[d];
+                    state.scroll_stuck_to_end[d] = false;
+                    state.offset_target[d] = None;
+                    user_scrolled = true;
+                    ui.ctx().request_repaint();
+                }
+            }
+        }
+
         let show_scroll_this_frame = match scroll_bar_visibility {
             ScrollBarVisibility::AlwaysHidden => Vec2b::FALSE,
             ScrollBarVisibility::VisibleWhenNeeded => content_is_too_large,
@@ -1324,6 +1606,7 @@ impl Prepared {
                 // some manual action taken, scroll not stuck
                 state.scroll_stuck_to_end[d] = false;
                 state.offset_target[d] = None;
+                user_scrolled = true;
             } else {
                 state.scroll_start_offset_from_top_left[d] = None;
             }
@@ -1443,6 +1726,392 @@ impl Prepared {
 
         state.store(ui.ctx(), id);
 
-        (content_size, state)
+        if let Some(zoom_layer) = zoom_layer {
+            // Avoid frame-delay by moving the contents to where we scrolled:
+            set_zoom_transform(
+                ui,
+                zoom_layer,
+                zoom_transform(inner_rect.min, state.offset, zoom),
+            );
+        }
+
+        if let Some(group) = sync_group {
+            sync_with_group(
+                ui.ctx(),
+                group,
+                sync_axes,
+                group_offset,
+                state.offset,
+                available_offset,
+            );
+        }
+
+        let delta = state.offset - offset_at_start;
+        let on_scroll = (delta != Vec2::ZERO).then(|| {
+            let dt = ui.input(|i| i.stable_dt);
+            ScrollEvent {
+                offset: state.offset,
+                delta,
+                velocity: if 0.0 < dt { delta / dt } else { Vec2::ZERO },
+                user_driven: user_scrolled,
+            }
+        });
+
+        (content_size, state, on_scroll)
+    }
+}
+
+/// How to transform the zoomed contents of a [`ScrollArea`] into the layer of its parent.
+fn zoom_transform(inner_rect_min: Pos2, offset: Vec2, zoom: f32) -> TSTransform {
+    // Scale around the top left corner of the scroll area, then scroll:
+    TSTransform::new(inner_rect_min.to_vec2() * (1.0 - zoom) - offset, zoom)
+}
+
+fn set_zoom_transform(ui: &Ui, zoom_layer: LayerId, to_parent: TSTransform) {
+    let parent_to_global = ui
+        .ctx()
+        .layer_transform_to_global(ui.layer_id())
+        .unwrap_or_default();
+    ui.ctx()
+        .set_transform_layer(zoom_layer, parent_to_global * to_parent);
+}
+
+/// Zoom with ctrl+scroll or pinch, keeping the point under the pointer fixed.
+///
+/// Returns `true` if we zoomed.
+fn zoom_around_pointer(ui: &Ui, state: &mut State, inner_rect: Rect, zoom_range: Rangef) -> bool {
+    let hover_rect = state.interact_rect.unwrap_or(inner_rect);
+    if !ui.rect_contains_pointer(hover_rect) {
+        return false;
+    }
+    let (zoom_delta, pointer_pos) = ui.input(|i| (i.zoom_delta(), i.pointer.latest_pos()));
+    let Some(pointer_pos) = pointer_pos else {
+        return false;
+    };
+    let new_zoom = zoom_range.clamp(state.zoom * zoom_delta);
+    if new_zoom == state.zoom {
+        return false;
+    }
+
+    let pointer_pos = ui
+        .ctx()
+        .layer_transform_from_global(ui.layer_id())
+        .map_or(pointer_pos, |from_global| from_global * pointer_pos);
+    let anchor = pointer_pos - inner_rect.min;
+    state.offset = (state.offset + anchor) * (new_zoom / state.zoom) - anchor;
+    state.zoom = new_zoom;
+    state.offset_target = [None; 2];
+    state.vel = Vec2::ZERO;
+    state.scroll_stuck_to_end = Vec2b::FALSE;
+    ui.ctx().request_repaint();
+    true
+}
+
+/// The shared state of a [`ScrollArea::sync_group`].
+#[derive(Clone, Copy, Default)]
+struct SyncGroupState {
+    offset: Vec2,
+}
+
+/// Tell the other scroll areas in the group if we scrolled this pass.
+fn sync_with_group(
+    ctx: &Context,
+    group: Id,
+    axes: Vec2b,
+    group_offset: Option<Vec2>,
+    offset: Vec2,
+    available_offset: Vec2,
+) {
+    let mut new_group_offset = group_offset.unwrap_or(offset);
+    for d in 0..2 {
+        if !axes[d] {
+            continue;
+        }
+        // If we have less content than the others, we could not follow all the way.
+        // That is not a reason to pull the others back:
+        let followed = new_group_offset[d].clamp(0.0, available_offset[d].at_least(0.0));
+        if offset[d] != followed {
+            new_group_offset[d] = offset[d];
+        }
+    }
+    if Some(new_group_offset) != group_offset {
+        ctx.data_mut(|data| {
+            data.insert_temp(
+                group,
+                SyncGroupState {
+                    offset: new_group_offset,
+                },
+            );
+        });
+        ctx.request_repaint(); // Make the others follow
+    }
+}
+
+/// How much to scroll this frame because of a drag near the edges of the scroll area.
+///
+/// See [`ScrollArea::drag_scroll`].
+fn drag_scroll_delta(ui: &Ui, id: Id, inner_rect: Rect, direction_enabled: Vec2b) -> Vec2 {
+    let ctx = ui.ctx();
+    let Some(dragged_id) = ctx.dragged_id() else {
+        return Vec2::ZERO;
+    };
+    if dragged_id == id.with("area") || dragged_id == id.with(0) || dragged_id == id.with(1) {
+        return Vec2::ZERO; // We are scrolling ourselves
+    }
+
+    let (pointer_pos, press_origin, dt) = ui.input(|i| {
+        (
+            i.pointer.interact_pos(),
+            i.pointer.press_origin(),
+            i.stable_dt.at_most(0.1),
+        )
+    });
+    let from_global = ctx.layer_transform_from_global(ui.layer_id());
+    let to_layer = |pos: Pos2| from_global.map_or(pos, |from_global| from_global * pos);
+    let (pointer_pos, press_origin) = (pointer_pos.map(to_layer), press_origin.map(to_layer));
+    let Some(pointer_pos) = pointer_pos else {
+        return Vec2::ZERO;
+    };
+
+    let started_inside = press_origin.is_some_and(|pos| inner_rect.contains(pos));
+    if !started_inside && !DragAndDrop::has_any_payload(ctx) {
+        return Vec2::ZERO;
+    }
+
+    edge_scroll_delta(ui, inner_rect, pointer_pos, direction_enabled, dt)
+}
+
+/// How much to scroll this frame when dragging something to `pointer_pos`,
+/// which is faster the closer it is to (or the further past) the edges of `inner_rect`.
+///
+/// A positive delta means scrolling down/right, i.e. towards the end of the contents.
+pub(crate) fn edge_scroll_delta(
+    ui: &Ui,
+    inner_rect: Rect,
+    pointer_pos: Pos2,
+    direction_enabled: Vec2b,
+    dt: f32,
+) -> Vec2 {
+    let max_speed = 1000.0; // points per second
+
+    let mut delta = Vec2::ZERO;
+    for d in 0..2 {
+        let cross_range = if d == 0 {
+            inner_rect.y_range()
+        } else {
+            inner_rect.x_range()
+        };
+        if !direction_enabled[d] || !cross_range.contains(pointer_pos[1 - d]) {
+            continue;
+        }
+
+        // The edge zones where we scroll:
+        let zone = (2.0 * ui.spacing().interact_size.y).at_most(inner_rect.size()[d] / 4.0);
+        if zone <= 0.0 {
+            continue;
+        }
+        let closeness = if pointer_pos[d] < inner_rect.min[d] + zone {
+            -(inner_rect.min[d] + zone - pointer_pos[d]) / zone
+        } else if inner_rect.max[d] - zone < pointer_pos[d] {
+            (pointer_pos[d] - (inner_rect.max[d] - zone)) / zone
+        } else {
+            0.0
+        };
+
+        // Start slow, to allow precise positioning, and speed up towards (and past) the edge:
+        let closeness = closeness.clamp(-2.0, 2.0) / 2.0;
+        delta[d] = closeness * closeness.abs() * max_speed * dt;
+    }
+    delta
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CentralPanel, Event, PointerButton, RawInput, vec2};
+
+    #[test]
+    fn test_drag_scroll() {
+        let ctx = Context::default();
+        let run = |events: Vec<Event>| {
+            let input = RawInput {
+                events,
+                screen_rect: Some(Rect::from_min_size(Pos2::ZERO, Vec2::splat(400.0))),
+                ..Default::default()
+            };
+            let mut output = None;
+            let _ = ctx.run(input, |ctx| {
+                CentralPanel::default().show(ctx, |ui| {
+                    let scroll_area = ScrollArea::vertical()
+                        .max_height(200.0)
+                        .drag_scroll(true)
+                        .show(ui, |ui| {
+                            for i in 0..100 {
+                                ui.label(format!("Row {i}"));
+                            }
+                        });
+                    output = Some((scroll_area.inner_rect, scroll_area.state.offset.y));
+                });
+            });
+            output.unwrap()
+        };
+
+        run(vec![]);
+        let (inner_rect, offset) = run(vec![]);
+        assert_eq!(offset, 0.0);
+
+        // Select text, dragging past the bottom edge:
+        let start = inner_rect.left_top() + vec2(10.0, 10.0);
+        let end = pos2(start.x, inner_rect.bottom() + 10.0);
+        let button = |pos, pressed| Event::PointerButton {
+            pos,
+            button: PointerButton::Primary,
+            pressed,
+            modifiers: Default::default(),
+        };
+        run(vec![Event::PointerMoved(start), button(start, true)]);
+        run(vec![Event::PointerMoved(start + vec2(0.0, 10.0))]);
+        let mut offset = 0.0;
+        for _ in 0..5 {
+            (_, offset) = run(vec![Event::PointerMoved(end)]);
+        }
+        assert!(0.0 < offset, "Should scroll down, but offset is {offset}");
+
+        // Releasing stops the scrolling:
+        let (_, released_offset) = run(vec![button(end, false)]);
+        let (_, later_offset) = run(vec![]);
+        assert_eq!(released_offset, later_offset);
+    }
+
+    #[test]
+    fn test_scroll_to_offset_animated() {
+        let ctx = Context::default();
+        let run = || {
+            let mut output = None;
+            let _ = ctx.run(Default::default(), |ctx| {
+                CentralPanel::default().show(ctx, |ui| {
+                    let scroll_area = ScrollArea::vertical().max_height(200.0).show(ui, |ui| {
+                        for i in 0..100 {
+                            ui.label(format!("Row {i}"));
+                        }
+                    });
+                    output = Some((scroll_area.id, scroll_area.on_scroll));
+                });
+            });
+            output.unwrap()
+        };
+
+        run();
+        let (id, on_scroll) = run();
+        assert!(on_scroll.is_none());
+
+        ctx.scroll_to_offset_animated(id, vec2(0.0, 100.0), ScrollAnimation::none());
+        let (_, on_scroll) = run();
+        let on_scroll = on_scroll.expect("Should have scrolled");
+        assert_eq!(on_scroll.offset.y, 100.0);
+        assert_eq!(on_scroll.delta.y, 100.0);
+        assert!(!on_scroll.user_driven);
+        assert_eq!(ctx.scroll_area_state(id).unwrap().offset.y, 100.0);
+
+        let (_, on_scroll) = run();
+        assert!(on_scroll.is_none());
+    }
+
+    #[test]
+    fn test_sync_group() {
+        let ctx = Context::default();
+        let run = || {
+            let mut output = Vec::new();
+            let _ = ctx.run(Default::default(), |ctx| {
+                CentralPanel::default().show(ctx, |ui| {
+                    for (salt, num_rows) in [("a", 100), ("b", 20)] {
+                        let scroll_area = ScrollArea::vertical()
+                            .id_salt(salt)
+                            .max_height(100.0)
+                            .sync_group(Id::new("group"))
+                            .show(ui, |ui| {
+                                for i in 0..num_rows {
+                                    ui.label(format!("Row {i}"));
+                                }
+                            });
+                        output.push((scroll_area.id, scroll_area.state.offset.y));
+                    }
+                });
+            });
+            output
+        };
+
+        run();
+        let output = run();
+        let (a, b) = (output[0].0, output[1].0);
+
+        // Scroll the first one, and the second one follows:
+        ctx.scroll_to_offset_animated(a, vec2(0.0, 50.0), ScrollAnimation::none());
+        run();
+        let output = run();
+        assert_eq!(output[0].1, 50.0);
+        assert_eq!(output[1].1, 50.0);
+
+        // The second one can't scroll as far, but shouldn't hold the first one back:
+        ctx.scroll_to_offset_animated(a, vec2(0.0, 1000.0), ScrollAnimation::none());
+        run();
+        let output = run();
+        assert!(output[1].1 < output[0].1);
+        let max_offset_a = output[0].1;
+
+        // Scroll the second one, and the first one follows:
+        ctx.scroll_to_offset_animated(b, vec2(0.0, 10.0), ScrollAnimation::none());
+        run();
+        let output = run();
+        assert!(output[0].1 < max_offset_a);
+        assert_eq!(output[0].1, 10.0);
+        assert_eq!(output[1].1, 10.0);
+    }
+
+    #[test]
+    fn test_zoom() {
+        let ctx = Context::default();
+        let run = |events: Vec<Event>| {
+            let input = RawInput {
+                events,
+                screen_rect: Some(Rect::from_min_size(Pos2::ZERO, Vec2::splat(400.0))),
+                ..Default::default()
+            };
+            let mut output = None;
+            let _ = ctx.run(input, |ctx| {
+                CentralPanel::default().show(ctx, |ui| {
+                    let scroll_area = ScrollArea::both()
+                        .max_height(200.0)
+                        .zoom_range(0.5..=4.0)
+                        .show(ui, |ui| {
+                            ui.allocate_space(Vec2::splat(1000.0));
+                        });
+                    output = Some((
+                        scroll_area.inner_rect,
+                        scroll_area.state,
+                        scroll_area.content_size,
+                    ));
+                });
+            });
+            output.unwrap()
+        };
+
+        run(vec![]);
+        let (inner_rect, state, content_size) = run(vec![]);
+        assert_eq!(state.zoom(), 1.0);
+        assert_eq!(content_size, Vec2::splat(1000.0));
+
+        // Zoom in around a point, which should stay under the pointer:
+        let pointer_pos = inner_rect.min + vec2(50.0, 50.0);
+        run(vec![Event::PointerMoved(pointer_pos), Event::Zoom(2.0)]);
+        let (_, state, content_size) = run(vec![]);
+        assert_eq!(state.zoom(), 2.0);
+        assert_eq!(state.offset, vec2(50.0, 50.0));
+        assert_eq!(content_size, Vec2::splat(2000.0));
+
+        // Zooming is limited to the zoom range:
+        run(vec![Event::Zoom(100.0)]);
+        let (_, state, _) = run(vec![]);
+        assert_eq!(state.zoom(), 4.0);
     }
 }
@@ -3,7 +3,7 @@ use crate::{
     AreaState, Context, Id, InnerResponse, LayerId, Layout, Order, Popup, PopupAnchor, PopupKind,
     Response, Sense,
 };
-use emath::Vec2;
+use emath::{RectAlign, Vec2};
 
 pub struct Tooltip<'a> {
     pub popup: Popup<'a>,
@@ -15,7 +15,7 @@ pub struct Tooltip<'a> {
     parent_widget: Id,
 }
 
-impl Tooltip<'_> {
+impl<'a> Tooltip<'a> {
     /// Show a tooltip that is always open.
     #[deprecated = "Use `Tooltip::always_open` instead."]
     pub fn new(
@@ -94,6 +94,39 @@ impl Tooltip<'_> {
         self
     }
 
+    /// Show the tooltip relative to the given [`PopupAnchor`] instead of the widget.
+    ///
+    /// * [`PopupAnchor::Pointer`] follows the mouse pointer.
+    /// * [`PopupAnchor::ParentRect`] places it next to some rectangle, e.g. a table cell.
+    ///   Use [`Self::align`] to choose which edge.
+    /// * [`PopupAnchor::Position`] places it at a fixed position.
+    ///
+    /// The tooltip is moved to the other side of the anchor if it doesn't fit on the screen,
+    /// see [`Self::align_alternatives`].
+    #[inline]
+    pub fn anchor(mut self, anchor: impl Into<PopupAnchor>) -> Self {
+        self.popup = self.popup.anchor(anchor);
+        self
+    }
+
+    /// Where to put the tooltip relative to the anchor, if it fits.
+    ///
+    /// See [`Popup::align`].
+    #[inline]
+    pub fn align(mut self, align: RectAlign) -> Self {
+        self.popup = self.popup.align(align);
+        self
+    }
+
+    /// Where to try to put the tooltip if it doesn't fit where [`Self::align`] says.
+    ///
+    /// See [`Popup::align_alternatives`].
+    #[inline]
+    pub fn align_alternatives(mut self, alternatives: &'a [RectAlign]) -> Self {
+        self.popup = self.popup.align_alternatives(alternatives);
+        self
+    }
+
     /// Set the gap between the tooltip and the anchor
     ///
     /// Default: 5.0
@@ -145,6 +178,7 @@ impl Tooltip<'_> {
                 .unwrap_or(PerWidgetTooltipState {
                     bounding_rect: rect,
                     tooltip_count: 0,
+                    last_tooltip_rect: None,
                 })
         });
 
@@ -166,6 +200,7 @@ impl Tooltip<'_> {
         if let Some(response) = &response {
             state.tooltip_count += 1;
             state.bounding_rect |= response.response.rect;
+            state.last_tooltip_rect = Some(response.response.rect);
             response
                 .response
                 .ctx
@@ -253,9 +288,10 @@ impl Tooltip<'_> {
         if time_since_last_scroll < tooltip_delay {
             // See https://github.com/emilk/egui/issues/4781
             // Note that this means we cannot have `ScrollArea`s in a tooltip.
-            response
-                .ctx
-                .request_repaint_after_secs(tooltip_delay - time_since_last_scroll);
+            request_repaint_after_tooltip_delay(
+                &response.ctx,
+                tooltip_delay - time_since_last_scroll,
+            );
             return false;
         }
 
@@ -372,7 +408,7 @@ impl Tooltip<'_> {
 
             if 0.0 < time_til_tooltip {
                 // Wait until the mouse has been still for a while
-                response.ctx.request_repaint_after_secs(time_til_tooltip);
+                request_repaint_after_tooltip_delay(&response.ctx, time_til_tooltip);
                 return false;
             }
         }
@@ -400,3 +436,57 @@ impl Tooltip<'_> {
         })
     }
 }
+
+/// Wake up when the tooltip may be shown.
+fn request_repaint_after_tooltip_delay(ctx: &Context, seconds: f32) {
+    if let Ok(delay) = std::time::Duration::try_from_secs_f32(seconds) {
+        ctx.request_repaint_after_because(delay, crate::RepaintReason::TooltipDelay);
+    }
+}
+
+#[test]
+fn test_tooltip_anchor() {
+    use crate::{CentralPanel, Label, Rect, pos2, vec2};
+
+    let ctx = Context::default();
+    ctx.memory_mut(|mem| mem.set_everything_is_visible(true));
+    let screen_rect = Rect::from_min_size(pos2(0.0, 0.0), vec2(800.0, 600.0));
+
+    let cell = Rect::from_min_size(pos2(300.0, 200.0), vec2(100.0, 20.0));
+    let at_bottom_of_screen = Rect::from_min_size(pos2(300.0, 590.0), vec2(100.0, 10.0));
+    let mut tooltip_rects = vec![];
+
+    // The first pass is for measuring the size of the tooltips.
+    for _ in 0..2 {
+        let input = crate::RawInput {
+            screen_rect: Some(screen_rect),
+            ..Default::default()
+        };
+        let _ = ctx.run(input, |ctx| {
+            CentralPanel::default().show(ctx, |ui| {
+                tooltip_rects = [cell, at_bottom_of_screen]
+                    .into_iter()
+                    .map(|anchor| {
+                        ui.add(Label::new("Hover me"))
+                            .on_hover_ui_at(anchor, |ui| {
+                                ui.label("Tooltip");
+                            })
+                            .tooltip_rect()
+                            .unwrap()
+                    })
+                    .collect();
+            });
+        });
+    }
+
+    assert!(
+        (tooltip_rects[0].top() - cell.bottom() - 4.0).abs() < 1.0,
+        "{tooltip_rects:?}"
+    );
+
+    // Flips above the anchor if there is no space below:
+    assert!(
+        tooltip_rects[1].bottom() <= at_bottom_of_screen.top(),
+        "{tooltip_rects:?}"
+    );
+}
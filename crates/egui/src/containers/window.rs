@@ -3,7 +3,7 @@
 use std::sync::Arc;
 
 use emath::GuiRounding as _;
-use epaint::{CornerRadiusF32, RectShape};
+use epaint::{CornerRadiusF32, MarginF32, RectShape};
 
 use crate::collapsing_header::CollapsingState;
 use crate::*;
@@ -41,6 +41,7 @@ pub struct Window<'open> {
     resize: Resize,
     scroll: ScrollArea,
     collapsible: bool,
+    maximizable: bool,
     default_open: bool,
     with_title_bar: bool,
     fade_out: bool,
@@ -64,6 +65,7 @@ impl<'open> Window<'open> {
                 .default_size([340.0, 420.0]), // Default inner size of a window
             scroll: ScrollArea::neither().auto_shrink(false),
             collapsible: true,
+            maximizable: false,
             default_open: true,
             with_title_bar: true,
             fade_out: true,
@@ -132,6 +134,7 @@ impl<'open> Window<'open> {
     /// If `true`, quickly fade out the `Window` when it closes.
     ///
     /// This only works if you use [`Self::open`] to close the window.
+    /// The window is removed once it has faded out.
     ///
     /// Default: `true`.
     #[inline]
@@ -184,6 +187,18 @@ impl<'open> Window<'open> {
         self
     }
 
+    /// Don't let the user shrink the window to smaller than what its content needs,
+    /// so that widgets never end up overlapping.
+    ///
+    /// See [`Resize::min_size_from_content`].
+    ///
+    /// Default is `false`.
+    #[inline]
+    pub fn min_size_from_content(mut self, min_size_from_content: bool) -> Self {
+        self.resize = self.resize.min_size_from_content(min_size_from_content);
+        self
+    }
+
     /// Set maximum width of the window.
     #[inline]
     pub fn max_width(mut self, max_width: f32) -> Self {
@@ -241,8 +256,10 @@ impl<'open> Window<'open> {
     /// Constrain the movement of the window to the given rectangle.
     ///
     /// For instance: `.constrain_to(ctx.screen_rect())`.
+    ///
+    /// See [`AreaConstraint::from_fn`] to e.g. keep the window out of the side panels.
     #[inline]
-    pub fn constrain_to(mut self, constrain_rect: Rect) -> Self {
+    pub fn constrain_to(mut self, constrain_rect: impl Into<AreaConstraint>) -> Self {
         self.area = self.area.constrain_to(constrain_rect);
         self
     }
@@ -349,6 +366,19 @@ impl<'open> Window<'open> {
         self
     }
 
+    /// Can the window be maximized to fill its [`Self::constrain_to`] rectangle?
+    ///
+    /// If `true`, the title bar gets a maximize-button,
+    /// and double-clicking the title maximizes the window instead of collapsing it.
+    /// Doing either again restores the window to where it was.
+    ///
+    /// Default is `false`.
+    #[inline]
+    pub fn maximizable(mut self, maximizable: bool) -> Self {
+        self.maximizable = maximizable;
+        self
+    }
+
     /// Show title bar on top of the window?
     /// If `false`, the window will not be collapsible nor have a close-button.
     #[inline]
@@ -435,6 +465,7 @@ impl Window<'_> {
             resize,
             scroll,
             collapsible,
+            maximizable,
             default_open,
             with_title_bar,
             fade_out,
@@ -446,6 +477,21 @@ impl Window<'_> {
 
         let is_explicitly_closed = matches!(open, Some(false));
         let is_open = !is_explicitly_closed || ctx.memory(|mem| mem.everything_is_visible());
+        if ctx.options(|o| o.ui_events) {
+            let was_open = ctx.data_mut(|data| {
+                let was_open = data.get_temp_mut_or_default::<bool>(area.id.with("was_open"));
+                std::mem::replace(was_open, is_open)
+            });
+            if was_open != is_open {
+                let info = WidgetInfo::labeled(WidgetType::Window, true, title.text());
+                let event = if is_open {
+                    output::OutputEvent::WindowOpened(info)
+                } else {
+                    output::OutputEvent::WindowClosed(info)
+                };
+                ctx.output_mut(|o| o.events.push(event));
+            }
+        }
         let opacity = ctx.animate_bool_with_easing(
             area.id.with("fade-out"),
             is_open,
@@ -515,34 +561,38 @@ impl Window<'_> {
             )
         });
 
-        {
-            let margins = window_frame.total_margin().sum()
-                + vec2(0.0, title_bar_height_with_margin + title_content_spacing);
+        let margins = window_frame.total_margin().sum()
+            + vec2(0.0, title_bar_height_with_margin + title_content_spacing);
 
-            resize_response(
-                resize_interaction,
-                ctx,
-                margins,
-                area_layer_id,
-                &mut area,
-                resize_id,
-            );
-        }
+        let min_inner_size =
+            resize.effective_min_size(resize::State::load(ctx, resize_id).as_ref());
 
-        let mut area_content_ui = area.content_ui(ctx);
-        if is_open {
+        resize_response(
+            resize_interaction,
+            ctx,
+            margins,
+            min_inner_size,
+            area_layer_id,
+            &mut area,
+            resize_id,
+        );
+
+        if fade_out {
             // `Area` already takes care of fade-in animations,
             // so we only need to handle fade-out animations here.
-        } else if fade_out {
-            area_content_ui.multiply_opacity(opacity);
+            // Set even when open, to restore the opacity of the layer after a fade-out:
+            area.multiply_opacity(if is_open { 1.0 } else { opacity });
         }
+        let mut area_content_ui = area.content_ui(ctx);
 
+        let mut toggle_maximized = false;
         let content_inner = {
             ctx.with_accessibility_parent(area.id(), || {
                 // BEGIN FRAME --------------------------------
                 let mut frame = window_frame.begin(&mut area_content_ui);
 
-                let show_close_button = open.is_some();
+                // Close- and maximize buttons:
+                let num_right_buttons = usize::from(open.is_some()) + usize::from(maximizable);
 
                 let where_to_put_header_background = &area_content_ui.painter().add(Shape::Noop);
 
@@ -550,7 +600,7 @@ impl Window<'_> {
                     let title_bar = TitleBar::new(
                         &frame.content_ui,
                         title,
-                        show_close_button,
+                        num_right_buttons,
                         collapsible,
                         window_frame,
                         title_bar_height_with_margin,
@@ -626,12 +676,14 @@ impl Window<'_> {
                         );
                     }
 
-                    title_bar.ui(
+                    let is_maximized = area.state().restore_rect.is_some();
+                    toggle_maximized = title_bar.ui(
                         &mut area_content_ui,
                         &content_response,
                         open.as_deref_mut(),
                         &mut collapsing,
                         collapsible,
+                        maximizable.then_some(is_maximized),
                     );
                 }
 
@@ -643,8 +695,21 @@ impl Window<'_> {
             })
         };
 
+        let constrain_rect = area.constrain_rect();
         let full_response = area.end(ctx, area_content_ui);
 
+        if toggle_maximized {
+            // Done after `Area::end`, which would otherwise overwrite the new size:
+            toggle_maximized_window(
+                ctx,
+                area_layer_id,
+                full_response.rect,
+                constrain_rect,
+                margins,
+                resize_id,
+            );
+        }
+
         if full_response.should_close() {
             if let Some(open) = open {
                 *open = false;
@@ -820,10 +885,42 @@ impl ResizeInteraction {
     }
 }
 
+/// Maximize the window to its constrain rect, or restore it to where it was before.
+fn toggle_maximized_window(
+    ctx: &Context,
+    area_layer_id: LayerId,
+    outer_rect: Rect,
+    constrain_rect: Rect,
+    margins: Vec2,
+    resize_id: Id,
+) {
+    let Some(mut state) = AreaState::load(ctx, area_layer_id.id) else {
+        return;
+    };
+    let new_rect = if let Some(restore_rect) = state.restore_rect.take() {
+        restore_rect
+    } else {
+        state.restore_rect = Some(outer_rect);
+        constrain_rect
+    };
+
+    // Set the size right away, so the old size won't get constrained to the new position:
+    state.size = Some(new_rect.size());
+    state.set_left_top_pos(new_rect.left_top());
+    ctx.memory_mut(|mem| mem.areas_mut().set_state(area_layer_id, state));
+
+    if let Some(mut state) = resize::State::load(ctx, resize_id) {
+        state.requested_size = Some(new_rect.size() - margins);
+        state.store(ctx, resize_id);
+    }
+    ctx.request_repaint();
+}
+
 fn resize_response(
     resize_interaction: ResizeInteraction,
     ctx: &Context,
     margins: Vec2,
+    min_inner_size: Vec2,
     area_layer_id: LayerId,
     area: &mut area::Prepared,
     resize_id: impl Into<Id>,
@@ -832,6 +929,15 @@ fn resize_response(
         return;
     };
 
+    // Keep the opposite edge still when dragging the left or top edge past the minimum size:
+    let min_outer_size = min_inner_size + margins;
+    if resize_interaction.left.drag && new_rect.width() < min_outer_size.x {
+        new_rect.min.x = new_rect.max.x - min_outer_size.x;
+    }
+    if resize_interaction.top.drag && new_rect.height() < min_outer_size.y {
+        new_rect.min.y = new_rect.max.y - min_outer_size.y;
+    }
+
     if area.constrain() {
         new_rect = Context::constrain_window_rect_to_area(new_rect, area.constrain_rect());
     }
@@ -841,6 +947,9 @@ fn resize_response(
 
     let resize_id = resize_id.into();
     if resize_interaction.any_dragged() {
+        // The user is picking a new size, so there is nothing to restore anymore:
+        area.state_mut().restore_rect = None;
+
         if let Some(mut state) = resize::State::load(ctx, resize_id) {
             state.requested_size = Some(new_rect.size() - margins);
             state.store(ctx, resize_id);
@@ -901,7 +1010,10 @@ fn resize_interaction(
     // The rect that is in the middle of the stroke:
     let rect = outer_rect.shrink(window_frame.stroke.width / 2.0);
 
-    let side_response = |rect, id| {
+    // Only expand outwards, so we don't cover more of the contents of the window:
+    let outside = ctx.style().interaction.resize_grab_outside;
+    let side_response = |rect, [left, right, top, bottom]: [bool; 4], id| {
+        let expand = |expand: bool| if expand { outside } else { 0.0 };
         let response = ctx.create_widget(
             WidgetRect {
                 layer_id,
@@ -910,6 +1022,15 @@ fn resize_interaction(
                 interact_rect: rect,
                 sense: Sense::drag(),
                 enabled: true,
+                hit_test_mode: Default::default(),
+                interact_priority: 0,
+                interact_expand: MarginF32 {
+                    left: expand(left),
+                    right: expand(right),
+                    top: expand(top),
+                    bottom: expand(bottom),
+                }
+                .into(),
             },
             true,
         );
@@ -942,6 +1063,7 @@ fn resize_interaction(
     if possible.resize_right {
         let response = side_response(
             vetrtical_rect(rect.right_top(), rect.right_bottom()),
+            [false, true, false, false],
             id.with("right"),
         );
         right |= response;
@@ -949,6 +1071,7 @@ fn resize_interaction(
     if possible.resize_left {
         let response = side_response(
             vetrtical_rect(rect.left_top(), rect.left_bottom()),
+            [true, false, false, false],
             id.with("left"),
         );
         left |= response;
@@ -956,6 +1079,7 @@ fn resize_interaction(
     if possible.resize_bottom {
         let response = side_response(
             horizontal_rect(rect.left_bottom(), rect.right_bottom()),
+            [false, false, false, true],
             id.with("bottom"),
         );
         bottom |= response;
@@ -963,6 +1087,7 @@ fn resize_interaction(
     if possible.resize_top {
         let response = side_response(
             horizontal_rect(rect.left_top(), rect.right_top()),
+            [false, false, true, false],
             id.with("top"),
         );
         top |= response;
@@ -977,7 +1102,11 @@ fn resize_interaction(
     // the whole corner is grabbable:
 
     if possible.resize_right || possible.resize_bottom {
-        let response = side_response(corner_rect(rect.right_bottom()), id.with("right_bottom"));
+        let response = side_response(
+            corner_rect(rect.right_bottom()),
+            [false, true, false, true],
+            id.with("right_bottom"),
+        );
         if possible.resize_right {
             right |= response;
         }
@@ -987,7 +1116,11 @@ fn resize_interaction(
     }
 
     if possible.resize_right || possible.resize_top {
-        let response = side_response(corner_rect(rect.right_top()), id.with("right_top"));
+        let response = side_response(
+            corner_rect(rect.right_top()),
+            [false, true, true, false],
+            id.with("right_top"),
+        );
         if possible.resize_right {
             right |= response;
         }
@@ -997,7 +1130,11 @@ fn resize_interaction(
     }
 
     if possible.resize_left || possible.resize_bottom {
-        let response = side_response(corner_rect(rect.left_bottom()), id.with("left_bottom"));
+        let response = side_response(
+            corner_rect(rect.left_bottom()),
+            [true, false, false, true],
+            id.with("left_bottom"),
+        );
         if possible.resize_left {
             left |= response;
         }
@@ -1007,7 +1144,11 @@ fn resize_interaction(
     }
 
     if possible.resize_left || possible.resize_top {
-        let response = side_response(corner_rect(rect.left_top()), id.with("left_top"));
+        let response = side_response(
+            corner_rect(rect.left_top()),
+            [true, false, true, false],
+            id.with("left_top"),
+        );
         if possible.resize_left {
             left |= response;
         }
@@ -1126,7 +1267,7 @@ impl TitleBar {
     fn new(
         ui: &Ui,
         title: WidgetText,
-        show_close_button: bool,
+        num_right_buttons: usize,
         collapsible: bool,
         window_frame: Frame,
         title_bar_height_with_margin: f32,
@@ -1151,9 +1292,11 @@ impl TitleBar {
             TextStyle::Heading,
         );
 
-        let minimum_width = if collapsible || show_close_button {
-            // If at least one button is shown we make room for both buttons (since title should be centered):
-            2.0 * (left_pad + button_size.x + item_spacing.x) + title_galley.size().x
+        let num_side_buttons = num_right_buttons.max(usize::from(collapsible));
+        let minimum_width = if 0 < num_side_buttons {
+            // If at least one button is shown we make room for buttons on both sides (since title should be centered):
+            2.0 * (left_pad + num_side_buttons as f32 * (button_size.x + item_spacing.x))
+                + title_galley.size().x
         } else {
             left_pad + title_galley.size().x + left_pad
         };
@@ -1187,6 +1330,10 @@ impl TitleBar {
     ///   title if `collapsible` is `true`
     /// - `collapsible`: if `true`, double click on the title bar will be handled for a change
     ///   of `collapsing` state
+    /// - `maximized`: if `None`, no "Maximize" button will be rendered, otherwise whether the
+    ///   window is currently maximized. Double click on the title bar then toggles that instead.
+    ///
+    /// Returns `true` if the user wants to toggle the maximized state.
     fn ui(
         self,
         ui: &mut Ui,
@@ -1194,7 +1341,8 @@ impl TitleBar {
         open: Option<&mut bool>,
         collapsing: &mut CollapsingState,
         collapsible: bool,
-    ) {
+        maximized: Option<bool>,
+    ) -> bool {
         let window_frame = self.window_frame;
         let title_inner_rect = self.inner_rect;
 
@@ -1218,11 +1366,20 @@ impl TitleBar {
             });
         }
 
+        let mut num_right_buttons = 0;
         if let Some(open) = open {
             // Add close button now that we know our full width:
-            if self.close_button_ui(ui).clicked() {
+            if self.close_button_ui(ui, num_right_buttons).clicked() {
                 *open = false;
             }
+            num_right_buttons += 1;
+        }
+
+        let mut toggle_maximized = false;
+        if let Some(maximized) = maximized {
+            let button_rect = self.right_button_rect(ui, num_right_buttons);
+            toggle_maximized |= maximize_button(ui, button_rect, maximized).clicked();
+            num_right_buttons += 1;
         }
 
         let text_pos =
@@ -1250,8 +1407,9 @@ impl TitleBar {
                 .hline(title_inner_rect.x_range(), y, window_frame.stroke);
         }
 
-        // Don't cover the close- and collapse buttons:
-        let double_click_rect = title_inner_rect.shrink2(vec2(32.0, 0.0));
+        // Don't cover the close-, maximize- and collapse buttons:
+        let mut double_click_rect = title_inner_rect.shrink2(vec2(32.0, 0.0));
+        double_click_rect.max.x -= 32.0 * num_right_buttons.saturating_sub(1) as f32;
 
         if false {
             ui.ctx().debug_painter().debug_rect(
@@ -1266,10 +1424,15 @@ impl TitleBar {
         if ui
             .interact(double_click_rect, id, Sense::click())
             .double_clicked()
-            && collapsible
         {
-            collapsing.toggle(ui);
+            if maximized.is_some() {
+                toggle_maximized = true;
+            } else if collapsible {
+                collapsing.toggle(ui);
+            }
         }
+
+        toggle_maximized
     }
 
     /// Paints the "Close" button at the right side of the title bar
@@ -1277,14 +1440,21 @@ impl TitleBar {
     ///
     /// The button is square and its size is determined by the
     /// [`crate::style::Spacing::icon_width`] setting.
-    fn close_button_ui(&self, ui: &mut Ui) -> Response {
+    fn close_button_ui(&self, ui: &mut Ui, index: usize) -> Response {
+        let button_rect = self.right_button_rect(ui, index);
+        close_button(ui, button_rect)
+    }
+
+    /// Where to put the button with the given index, counting from the right side of the title bar.
+    fn right_button_rect(&self, ui: &Ui, index: usize) -> Rect {
+        let slot_size = Vec2::splat(self.inner_rect.height());
         let button_center = Align2::RIGHT_CENTER
-            .align_size_within_rect(Vec2::splat(self.inner_rect.height()), self.inner_rect)
-            .center();
+            .align_size_within_rect(slot_size, self.inner_rect)
+            .center()
+            - vec2(index as f32 * slot_size.x, 0.0);
         let button_size = Vec2::splat(ui.spacing().icon_width);
         let button_rect = Rect::from_center_size(button_center, button_size);
-        let button_rect = button_rect.round_to_pixels(ui.pixels_per_point());
-        close_button(ui, button_rect)
+        button_rect.round_to_pixels(ui.pixels_per_point())
     }
 }
 
@@ -1315,3 +1485,47 @@ fn close_button(ui: &mut Ui, rect: Rect) -> Response {
         .line_segment([rect.right_top(), rect.left_bottom()], stroke);
     response
 }
+
+/// Paints the "Maximize" (or "Restore") button of the window and processes clicks on it.
+///
+/// The maximize button is a square, and the restore button two overlapping squares,
+/// painted by a current stroke for foreground elements (such as a label text).
+fn maximize_button(ui: &mut Ui, rect: Rect, is_maximized: bool) -> Response {
+    let maximize_id = ui.auto_id_with("window_maximize_button");
+    let response = ui.interact(rect, maximize_id, Sense::click());
+    response.widget_info(|| {
+        let label = if is_maximized {
+            "Restore window"
+        } else {
+            "Maximize window"
+        };
+        WidgetInfo::labeled(WidgetType::Button, ui.is_enabled(), label)
+    });
+
+    ui.expand_to_include_rect(response.rect);
+
+    let visuals = ui.style().interact(&response);
+    let rect = rect.shrink(2.0).expand(visuals.expansion);
+    let stroke = visuals.fg_stroke;
+    if is_maximized {
+        let offset = (0.25 * rect.width()).round_ui();
+        let back = Rect::from_min_max(rect.min + vec2(offset, 0.0), rect.max - vec2(0.0, offset));
+        let front = back.translate(vec2(-offset, offset));
+        ui.painter().line(
+            vec![
+                pos2(back.left(), front.top()),
+                back.left_top(),
+                back.right_top(),
+                back.right_bottom(),
+                pos2(front.right(), back.bottom()),
+            ],
+            stroke,
+        );
+        ui.painter()
+            .rect_stroke(front, 0.0, stroke, StrokeKind::Middle);
+    } else {
+        ui.painter()
+            .rect_stroke(rect, 0.0, stroke, StrokeKind::Middle);
+    }
+    response
+}
@@ -0,0 +1,366 @@
+//! Custom window decorations ("chrome") for native windows without OS decorations.
+
+use emath::{Align, Rect, pos2, vec2};
+
+use crate::{
+    Button, CentralPanel, Context, CursorIcon, Frame, Id, InnerResponse, Layout, PointerButton,
+    ResizeDirection, RichText, Sense, TextStyle, TextWrapMode, Ui, UiBuilder, ViewportCommand,
+    WidgetText,
+};
+
+/// Custom window decorations for a native window without OS decorations.
+///
+/// This fills the whole viewport, and shows:
+/// * a title bar that can be dragged to move the window, and double-clicked to maximize it
+/// * minimize, maximize and close buttons
+/// * resize handles along the edges and corners of the window, with the correct cursors
+///
+/// Use this together with [`crate::ViewportBuilder::with_decorations`] set to `false`.
+/// To get rounded corners you also need [`crate::ViewportBuilder::with_transparent`].
+///
+/// ```
+/// # egui::__run_test_ctx(|ctx| {
+/// egui::WindowChrome::new("My app").show(ctx, |ui| {
+///     ui.label("The contents of the window");
+/// });
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct WindowChrome {
+    title: WidgetText,
+    frame: Option<Frame>,
+    title_bar_height: f32,
+    resize_grab_width: f32,
+    resizable: bool,
+    minimize_button: bool,
+    maximize_button: bool,
+    close_button: bool,
+}
+
+impl WindowChrome {
+    /// The given title is shown in the middle of the title bar.
+    pub fn new(title: impl Into<WidgetText>) -> Self {
+        Self {
+            title: title.into(),
+            frame: None,
+            title_bar_height: 32.0,
+            resize_grab_width: 6.0,
+            resizable: true,
+            minimize_button: true,
+            maximize_button: true,
+            close_button: true,
+        }
+    }
+
+    /// Change the frame around the whole window.
+    ///
+    /// Default is a [`Frame`] in the style of a [`crate::Window`].
+    #[inline]
+    pub fn frame(mut self, frame: Frame) -> Self {
+        self.frame = Some(frame);
+        self
+    }
+
+    /// Height of the title bar, in points.
+    ///
+    /// Default: `32.0`.
+    #[inline]
+    pub fn title_bar_height(mut self, height: f32) -> Self {
+        self.title_bar_height = height;
+        self
+    }
+
+    /// How far from the edge of the window the user can grab it to resize it, in points.
+    ///
+    /// The corners are twice as large, to make them easier to hit.
+    ///
+    /// Default: `6.0`.
+    #[inline]
+    pub fn resize_grab_width(mut self, width: f32) -> Self {
+        self.resize_grab_width = width;
+        self
+    }
+
+    /// Can the user resize the window by dragging its edges?
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn resizable(mut self, resizable: bool) -> Self {
+        self.resizable = resizable;
+        self
+    }
+
+    /// Show a button for minimizing the window?
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn minimize_button(mut self, show: bool) -> Self {
+        self.minimize_button = show;
+        self
+    }
+
+    /// Show a button for maximizing and restoring the window?
+    ///
+    /// This also controls if double-clicking the title bar maximizes the window.
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn maximize_button(mut self, show: bool) -> Self {
+        self.maximize_button = show;
+        self
+    }
+
+    /// Show a button for closing the window?
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn close_button(mut self, show: bool) -> Self {
+        self.close_button = show;
+        self
+    }
+
+    /// Show the window chrome, with the given contents below the title bar.
+    pub fn show<R>(
+        self,
+        ctx: &Context,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        let Self {
+            title,
+            frame,
+            title_bar_height,
+            resize_grab_width,
+            resizable,
+            minimize_button,
+            maximize_button,
+            close_button,
+        } = self;
+
+        let frame = frame.unwrap_or_else(|| {
+            let style = ctx.style();
+            Frame::new()
+                .fill(style.visuals.window_fill())
+                .corner_radius(style.visuals.window_corner_radius)
+                .stroke(style.visuals.window_stroke())
+                .outer_margin(1) // so the stroke is within the bounds
+        });
+
+        CentralPanel::default().frame(frame).show(ctx, |ui| {
+            let id = Id::new("__egui_window_chrome");
+            let (is_maximized, is_fullscreen) = ui.input(|i| {
+                let viewport = i.viewport();
+                (
+                    viewport.maximized.unwrap_or(false),
+                    viewport.fullscreen.unwrap_or(false),
+                )
+            });
+
+            let app_rect = ui.max_rect();
+            let title_bar_rect = Rect::from_min_max(
+                app_rect.min,
+                pos2(app_rect.max.x, app_rect.min.y + title_bar_height),
+            );
+
+            // The drag region goes first, so the buttons end up on top of it:
+            let title_bar_response = ui.interact(
+                title_bar_rect,
+                id.with("title_bar"),
+                Sense::click_and_drag(),
+            );
+
+            if maximize_button && title_bar_response.double_clicked() {
+                ui.ctx()
+                    .send_viewport_cmd(ViewportCommand::Maximized(!is_maximized));
+            }
+            if title_bar_response.drag_started_by(PointerButton::Primary) {
+                ui.ctx().send_viewport_cmd(ViewportCommand::StartDrag);
+            }
+
+            let galley = title.into_galley(
+                ui,
+                Some(TextWrapMode::Truncate),
+                title_bar_rect.width(),
+                TextStyle::Heading,
+            );
+            let title_rect =
+                emath::Align2::CENTER_CENTER.anchor_size(title_bar_rect.center(), galley.size());
+            ui.painter()
+                .galley(title_rect.min, galley, ui.visuals().text_color());
+
+            ui.painter().hline(
+                title_bar_rect.x_range().shrink(1.0),
+                title_bar_rect.bottom(),
+                ui.visuals().widgets.noninteractive.bg_stroke,
+            );
+
+            ui.scope_builder(
+                UiBuilder::new()
+                    .max_rect(title_bar_rect)
+                    .layout(Layout::right_to_left(Align::Center)),
+                |ui| {
+                    ui.spacing_mut().item_spacing.x = 0.0;
+                    ui.visuals_mut().button_frame = false;
+                    ui.add_space(8.0);
+
+                    // Right-to-left:
+                    let button_height = 12.0;
+
+                    if close_button {
+                        let response = ui
+                            .add(Button::new(RichText::new("❌").size(button_height)))
+                            .on_hover_text(ui.ctx().tr("window_chrome.close", "Close the window"));
+                        if response.clicked() {
+                            ui.ctx().send_viewport_cmd(ViewportCommand::Close);
+                        }
+                    }
+
+                    if maximize_button {
+                        let hover_text = if is_maximized {
+                            ui.ctx().tr("window_chrome.restore", "Restore window")
+                        } else {
+                            ui.ctx().tr("window_chrome.maximize", "Maximize window")
+                        };
+                        let response = ui
+                            .add(Button::new(RichText::new("🗗").size(button_height)))
+                            .on_hover_text(hover_text);
+                        if response.clicked() {
+                            ui.ctx()
+                                .send_viewport_cmd(ViewportCommand::Maximized(!is_maximized));
+                        }
+                    }
+
+                    if minimize_button {
+                        let response = ui
+                            .add(Button::new(RichText::new("🗕").size(button_height)))
+                            .on_hover_text(
+                                ui.ctx().tr("window_chrome.minimize", "Minimize the window"),
+                            );
+                        if response.clicked() {
+                            ui.ctx().send_viewport_cmd(ViewportCommand::Minimized(true));
+                        }
+                    }
+                },
+            );
+
+            let content_rect =
+                Rect::from_min_max(pos2(app_rect.min.x, title_bar_rect.max.y), app_rect.max)
+                    .shrink(4.0);
+            let mut content_ui = ui.new_child(UiBuilder::new().max_rect(content_rect));
+            let inner = add_contents(&mut content_ui);
+
+            // The resize handles go last, so they end up on top of everything else:
+            if resizable && !is_maximized && !is_fullscreen {
+                let window_rect = ui.ctx().screen_rect();
+                for (direction, rect) in resize_zones(window_rect, resize_grab_width) {
+                    let response = ui.interact(rect, id.with(("resize", direction)), Sense::drag());
+                    let response = response.on_hover_cursor(resize_cursor(direction));
+                    if response.drag_started_by(PointerButton::Primary) {
+                        ui.ctx()
+                            .send_viewport_cmd(ViewportCommand::BeginResize(direction));
+                    }
+                }
+            }
+
+            inner
+        })
+    }
+}
+
+/// The areas along the edges and corners of `window_rect` that can be dragged to resize it.
+///
+/// The corners are twice as large as the edges, and come last so that they take precedence
+/// when used for hit-testing.
+fn resize_zones(window_rect: Rect, grab_width: f32) -> [(ResizeDirection, Rect); 8] {
+    let Rect { min, max } = window_rect;
+    let corner = 2.0 * grab_width;
+    let edge = |min_x: f32, min_y: f32, max_x: f32, max_y: f32| {
+        Rect::from_min_max(pos2(min_x, min_y), pos2(max_x, max_y))
+    };
+    let corner_at = |x: f32, y: f32| Rect::from_min_size(pos2(x, y), vec2(corner, corner));
+
+    [
+        (
+            ResizeDirection::North,
+            edge(min.x, min.y, max.x, min.y + grab_width),
+        ),
+        (
+            ResizeDirection::South,
+            edge(min.x, max.y - grab_width, max.x, max.y),
+        ),
+        (
+            ResizeDirection::West,
+            edge(min.x, min.y, min.x + grab_width, max.y),
+        ),
+        (
+            ResizeDirection::East,
+            edge(max.x - grab_width, min.y, max.x, max.y),
+        ),
+        (ResizeDirection::NorthWest, corner_at(min.x, min.y)),
+        (ResizeDirection::NorthEast, corner_at(max.x - corner, min.y)),
+        (ResizeDirection::SouthWest, corner_at(min.x, max.y - corner)),
+        (
+            ResizeDirection::SouthEast,
+            corner_at(max.x - corner, max.y - corner),
+        ),
+    ]
+}
+
+fn resize_cursor(direction: ResizeDirection) -> CursorIcon {
+    match direction {
+        ResizeDirection::North => CursorIcon::ResizeNorth,
+        ResizeDirection::South => CursorIcon::ResizeSouth,
+        ResizeDirection::East => CursorIcon::ResizeEast,
+        ResizeDirection::West => CursorIcon::ResizeWest,
+        ResizeDirection::NorthEast => CursorIcon::ResizeNorthEast,
+        ResizeDirection::SouthEast => CursorIcon::ResizeSouthEast,
+        ResizeDirection::NorthWest => CursorIcon::ResizeNorthWest,
+        ResizeDirection::SouthWest => CursorIcon::ResizeSouthWest,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Like hit-testing: the last zone containing the point wins.
+    fn direction_at(window_rect: Rect, pos: emath::Pos2) -> Option<ResizeDirection> {
+        resize_zones(window_rect, 6.0)
+            .into_iter()
+            .rev()
+            .find(|(_, rect)| rect.contains(pos))
+            .map(|(direction, _)| direction)
+    }
+
+    #[test]
+    fn test_resize_zones() {
+        let window_rect = Rect::from_min_size(pos2(0.0, 0.0), vec2(400.0, 300.0));
+
+        assert_eq!(direction_at(window_rect, pos2(200.0, 150.0)), None);
+        assert_eq!(
+            direction_at(window_rect, pos2(200.0, 2.0)),
+            Some(ResizeDirection::North)
+        );
+        assert_eq!(
+            direction_at(window_rect, pos2(399.0, 150.0)),
+            Some(ResizeDirection::East)
+        );
+        assert_eq!(
+            direction_at(window_rect, pos2(200.0, 299.0)),
+            Some(ResizeDirection::South)
+        );
+        assert_eq!(
+            direction_at(window_rect, pos2(1.0, 150.0)),
+            Some(ResizeDirection::West)
+        );
+
+        // Corners are larger than the edges:
+        assert_eq!(
+            direction_at(window_rect, pos2(10.0, 2.0)),
+            Some(ResizeDirection::NorthWest)
+        );
+        assert_eq!(
+            direction_at(window_rect, pos2(398.0, 290.0)),
+            Some(ResizeDirection::SouthEast)
+        );
+    }
+}
@@ -0,0 +1,234 @@
+use emath::TSTransform;
+
+use crate::{
+    Align, Button, Id, InnerResponse, Layout, Response, RichText, Sense, Ui, WidgetText, vec2,
+};
+
+/// A sequence of steps, e.g. for a setup flow, with a step indicator at the top
+/// and back/next buttons at the bottom.
+///
+/// The current step is stored in [`crate::Memory`].
+/// Your closure is called with the index of the current step, and returns whether that step is valid.
+/// The user can only go on to the next step once the current one is valid,
+/// but can always go back to an earlier step.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut name = String::new();
+/// # let mut subscribe = false;
+/// let response = egui::containers::Wizard::new("setup", ["Name", "Newsletter", "Done"])
+///     .show(ui, |ui, step| match step {
+///         0 => {
+///             ui.text_edit_singleline(&mut name);
+///             !name.is_empty()
+///         }
+///         1 => {
+///             ui.checkbox(&mut subscribe, "Subscribe");
+///             true
+///         }
+///         _ => {
+///             ui.label(format!("Welcome, {name}!"));
+///             true
+///         }
+///     });
+///
+/// if response.finished {
+///     // …
+/// }
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct Wizard {
+    id_salt: Id,
+    steps: Vec<WidgetText>,
+    finish_text: Option<WidgetText>,
+}
+
+/// What happened with a [`Wizard`] this pass.
+pub struct WizardResponse<R> {
+    /// The response of the whole wizard.
+    pub response: Response,
+
+    /// What the contents closure returned, apart from the validity.
+    pub inner: R,
+
+    /// The step we are now at.
+    pub step: usize,
+
+    /// Did the step change this pass?
+    pub changed: bool,
+
+    /// Did the user click the finish button on the last step this pass?
+    pub finished: bool,
+}
+
+/// Stored in [`crate::Memory`].
+#[derive(Clone, Copy, Debug, Default)]
+struct State {
+    step: usize,
+}
+
+impl Wizard {
+    /// `steps` are the titles of the steps, shown in the step indicator.
+    pub fn new(
+        id_salt: impl std::hash::Hash,
+        steps: impl IntoIterator<Item = impl Into<WidgetText>>,
+    ) -> Self {
+        Self {
+            id_salt: Id::new(id_salt),
+            steps: steps.into_iter().map(Into::into).collect(),
+            finish_text: None,
+        }
+    }
+
+    /// The text on the button of the last step.
+    ///
+    /// Default: "Finish", translated with [`crate::Context::tr`].
+    #[inline]
+    pub fn finish_text(mut self, finish_text: impl Into<WidgetText>) -> Self {
+        self.finish_text = Some(finish_text.into());
+        self
+    }
+
+    /// The current step of the wizard with the given id salt, if it has been shown.
+    pub fn step(ui: &Ui, id_salt: impl std::hash::Hash) -> Option<usize> {
+        let id = ui.make_persistent_id(Id::new(id_salt));
+        ui.data(|d| d.get_temp::<State>(id)).map(|state| state.step)
+    }
+
+    /// Go to the given step of the wizard with the given id salt, e.g. to restart it.
+    pub fn set_step(ui: &Ui, id_salt: impl std::hash::Hash, step: usize) {
+        let id = ui.make_persistent_id(Id::new(id_salt));
+        ui.data_mut(|d| d.insert_temp(id, State { step }));
+    }
+
+    /// Show the current step.
+    ///
+    /// The closure returns whether the current step is valid.
+    pub fn show(
+        self,
+        ui: &mut Ui,
+        add_step: impl FnOnce(&mut Ui, usize) -> bool,
+    ) -> WizardResponse<()> {
+        self.show_with(ui, |ui, step| ((), add_step(ui, step)))
+    }
+
+    /// Like [`Self::show`], but the closure can also return a value,
+    /// which ends up in [`WizardResponse::inner`].
+    pub fn show_with<R>(
+        self,
+        ui: &mut Ui,
+        add_step: impl FnOnce(&mut Ui, usize) -> (R, bool),
+    ) -> WizardResponse<R> {
+        let Self {
+            id_salt,
+            steps,
+            finish_text,
+        } = self;
+
+        let id = ui.make_persistent_id(id_salt);
+        let mut state = ui.data(|d| d.get_temp::<State>(id)).unwrap_or_default();
+        let last_step = steps.len().saturating_sub(1);
+        state.step = state.step.min(last_step);
+        let shown_step = state.step;
+
+        let InnerResponse { inner, response } = ui.vertical(|ui| {
+            // Step indicator. Earlier steps can be clicked to go back to them:
+            ui.horizontal_wrapped(|ui| {
+                for (i, title) in steps.iter().enumerate() {
+                    if 0 < i {
+                        ui.weak("›");
+                    }
+                    if let Some(clicked) = step_indicator(ui, i, title, shown_step) {
+                        state.step = clicked;
+                    }
+                }
+            });
+            ui.separator();
+
+            // Slide the new step in from the direction we came from:
+            let animated_step = ui.ctx().animate_value_with_time(
+                id.with("transition"),
+                shown_step as f32,
+                ui.style().animation_time,
+            );
+            let distance = (shown_step as f32 - animated_step).clamp(-1.0, 1.0);
+            let offset = vec2(distance * 4.0 * ui.spacing().item_spacing.x, 0.0);
+            let (inner, valid) = ui
+                .with_visual_transform(TSTransform::from_translation(offset), |ui| {
+                    ui.multiply_opacity(1.0 - distance.abs());
+                    add_step(ui, shown_step)
+                })
+                .inner;
+
+            ui.separator();
+
+            let mut finished = false;
+            ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                let next_text = if shown_step == last_step {
+                    finish_text.unwrap_or_else(|| ui.ctx().tr("wizard.finish", "Finish").into())
+                } else {
+                    format!("{} ⏵", ui.ctx().tr("wizard.next", "Next")).into()
+                };
+                let next = ui.add_enabled(valid, Button::new(next_text));
+                if next.clicked() {
+                    if shown_step == last_step {
+                        finished = true;
+                    } else {
+                        state.step = shown_step + 1;
+                    }
+                }
+
+                let back = ui.add_enabled(
+                    0 < shown_step,
+                    Button::new(format!("⏴ {}", ui.ctx().tr("wizard.back", "Back"))),
+                );
+                if back.clicked() {
+                    state.step = shown_step - 1;
+                }
+            });
+
+            (inner, finished)
+        });
+        let (inner, finished) = inner;
+
+        let changed = state.step != shown_step;
+        if changed {
+            // Don't leave keyboard focus on a widget of the step we just left:
+            ui.memory_mut(|mem| mem.stop_text_input());
+            ui.ctx().request_repaint();
+        }
+        ui.data_mut(|d| d.insert_temp(id, state));
+
+        WizardResponse {
+            response,
+            inner,
+            step: state.step,
+            changed,
+            finished,
+        }
+    }
+}
+
+/// A numbered step title, highlighted if it is the current step.
+///
+/// Returns the step if the user clicked it to go back to it.
+fn step_indicator(ui: &mut Ui, step: usize, title: &WidgetText, current: usize) -> Option<usize> {
+    let text = RichText::new(format!("{} {}", step + 1, title.text()));
+    match step.cmp(&current) {
+        std::cmp::Ordering::Equal => {
+            ui.label(text.strong().color(ui.visuals().selection.stroke.color));
+            None
+        }
+        std::cmp::Ordering::Less => {
+            let response = ui
+                .add(crate::Label::new(format!("✔ {}", title.text())).sense(Sense::click()))
+                .on_hover_cursor(crate::CursorIcon::PointingHand);
+            response.clicked().then_some(step)
+        }
+        std::cmp::Ordering::Greater => {
+            ui.weak(text.text());
+            None
+        }
+    }
+}
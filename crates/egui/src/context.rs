@@ -4,8 +4,8 @@ use std::{borrow::Cow, cell::RefCell, panic::Location, sync::Arc, time::Duration
 
 use emath::{GuiRounding as _, OrderedFloat};
 use epaint::{
-    ClippedPrimitive, ClippedShape, Color32, ImageData, ImageDelta, Pos2, Rect, StrokeKind,
-    TessellationOptions, TextureAtlas, TextureId, Vec2,
+    ClippedPrimitive, ClippedShape, Color32, ColorImage, FontId, Galley, ImageData, ImageDelta,
+    Pos2, Rect, StrokeKind, TessellationOptions, TextureAtlas, TextureId, Vec2,
     emath::{self, TSTransform},
     mutex::RwLock,
     stats::PaintStats,
@@ -15,24 +15,24 @@ use epaint::{
 };
 
 use crate::{
-    Align2, CursorIcon, DeferredViewportUiCallback, FontDefinitions, Grid, Id, ImmediateViewport,
-    ImmediateViewportRendererCallback, Key, KeyboardShortcut, Label, LayerId, Memory,
-    ModifierNames, Modifiers, NumExt as _, Order, Painter, RawInput, Response, RichText,
+    Align2, ColorTransform, CursorIcon, DeferredViewportUiCallback, FontDefinitions, Grid, Id,
+    ImmediateViewport, ImmediateViewportRendererCallback, Key, KeyboardShortcut, Label, LayerId,
+    Memory, ModifierNames, Modifiers, NumExt as _, Order, Painter, RawInput, Response, RichText,
     ScrollArea, Sense, Style, TextStyle, TextureHandle, TextureOptions, Ui, ViewportBuilder,
     ViewportCommand, ViewportId, ViewportIdMap, ViewportIdPair, ViewportIdSet, ViewportOutput,
-    Widget as _, WidgetRect, WidgetText,
+    Warning, WarningKind, WarningSeverity, Widget as _, WidgetRect, WidgetText,
     animation_manager::AnimationManager,
     containers::{self, area::AreaState},
     data::output::PlatformOutput,
     epaint, hit_test,
     input_state::{InputState, MultiTouchInfo, PointerEvent},
     interaction,
-    layers::GraphicLayers,
+    layers::{GraphicLayers, ShapeIdx},
     load::{self, Bytes, Loaders, SizedTexture},
-    memory::{Options, Theme},
+    memory::{Options, SessionState, Theme},
     os::OperatingSystem,
     output::FullOutput,
-    pass_state::PassState,
+    pass_state::{PassState, StaticRegion, StaticRegionState},
     resize, response, scroll_area,
     util::IdTypeMap,
     viewport::ViewportClass,
@@ -102,9 +102,21 @@ struct NamedContextCallback {
     callback: ContextCallback,
 }
 
+/// A callback that can inspect and modify the [`RawInput`] before egui consumes it.
+///
+/// See [`Context::add_input_middleware`].
+pub type InputMiddleware = Arc<dyn Fn(&Context, &mut RawInput) + Send + Sync>;
+
+#[derive(Clone)]
+struct NamedInputMiddleware {
+    debug_name: &'static str,
+    middleware: InputMiddleware,
+}
+
 /// Callbacks that users can register
 #[derive(Clone, Default)]
 struct Plugins {
+    pub input_middlewares: Vec<NamedInputMiddleware>,
     pub on_begin_pass: Vec<NamedContextCallback>,
     pub on_end_pass: Vec<NamedContextCallback>,
 }
@@ -122,6 +134,18 @@ impl Plugins {
         }
     }
 
+    fn on_input(&self, ctx: &Context, raw_input: &mut RawInput) {
+        profiling::scope!("plugins", "input_middlewares");
+        for NamedInputMiddleware {
+            debug_name: _name,
+            middleware,
+        } in &self.input_middlewares
+        {
+            profiling::scope!("plugin", _name);
+            (middleware)(ctx, raw_input);
+        }
+    }
+
     fn on_begin_pass(&self, ctx: &Context) {
         Self::call(ctx, "on_begin_pass", &self.on_begin_pass);
     }
@@ -150,6 +174,7 @@ impl ContextImpl {
         if viewport.repaint.outstanding == 0 {
             // We are repainting now, so we can wait a while for the next repaint.
             viewport.repaint.repaint_delay = Duration::MAX;
+            viewport.repaint.reasons = Default::default();
         } else {
             viewport.repaint.repaint_delay = Duration::ZERO;
             viewport.repaint.outstanding -= 1;
@@ -164,7 +189,7 @@ impl ContextImpl {
     }
 
     fn request_repaint(&mut self, viewport_id: ViewportId, cause: RepaintCause) {
-        self.request_repaint_after(Duration::ZERO, viewport_id, cause);
+        self.request_repaint_after(Duration::ZERO, viewport_id, cause, RepaintReason::Requested);
     }
 
     fn request_repaint_after(
@@ -172,9 +197,19 @@ impl ContextImpl {
         mut delay: Duration,
         viewport_id: ViewportId,
         cause: RepaintCause,
+        reason: RepaintReason,
     ) {
         let viewport = self.viewports.entry(viewport_id).or_default();
 
+        delay = self
+            .memory
+            .options
+            .throttle_repaint(&viewport.input.raw, delay);
+        if delay == Duration::MAX {
+            return; // Paused, e.g. because the window is hidden
+        }
+        viewport.repaint.reasons.insert(reason);
+
         if delay == Duration::ZERO {
             // Each request results in two repaints, just to give some things time to settle.
             // This solves some corner-cases of missing repaints on frame-delayed responses.
@@ -284,6 +319,29 @@ pub struct ViewportState {
     // ----------------------
     // Cross-frame statistics:
     pub num_multipass_in_row: usize,
+
+    /// The last regions sent by [`Context::update_hit_test_regions`].
+    hit_test_regions: Option<Vec<Rect>>,
+
+    /// When the current pass started, see [`Context::pass_elapsed`].
+    #[cfg(feature = "frame_budget")]
+    pass_start: Option<web_time::Instant>,
+}
+
+impl ViewportState {
+    /// Give the cursor back to the user after a pointer lock,
+    /// moving it back to where the lock started.
+    fn unlock_pointer(&mut self, lock_origin: Option<Pos2>) {
+        self.commands.push(ViewportCommand::CursorGrab(
+            crate::viewport::CursorGrab::None,
+        ));
+        self.commands.push(ViewportCommand::CursorVisible(true));
+        if let Some(lock_origin) = lock_origin {
+            self.commands
+                .push(ViewportCommand::CursorPosition(lock_origin));
+            self.input.pointer.expect_warp(lock_origin);
+        }
+    }
 }
 
 /// What called [`Context::request_repaint`] or [`Context::request_discard`]?
@@ -337,6 +395,79 @@ impl RepaintCause {
     }
 }
 
+/// Why a repaint was requested, see [`Context::request_repaint_after_because`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RepaintReason {
+    /// Something is moving, e.g. a spinner, a blinking text cursor or a window that is opening.
+    Animation,
+
+    /// Waiting for a tooltip to show once the pointer has been still for a while.
+    TooltipDelay,
+
+    /// Anything else, e.g. a call to [`Context::request_repaint`].
+    Requested,
+}
+
+/// The [`RepaintReason`]s of all repaint requests, see [`crate::ViewportOutput::repaint_reasons`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RepaintReasons {
+    /// See [`RepaintReason::Animation`].
+    pub animation: bool,
+
+    /// See [`RepaintReason::TooltipDelay`].
+    pub tooltip_delay: bool,
+
+    /// See [`RepaintReason::Requested`].
+    pub requested: bool,
+}
+
+impl RepaintReasons {
+    /// Add this reason.
+    pub fn insert(&mut self, reason: RepaintReason) {
+        *self.get_mut(reason) = true;
+    }
+
+    /// Was a repaint requested for this reason?
+    pub fn contains(&self, reason: RepaintReason) -> bool {
+        match reason {
+            RepaintReason::Animation => self.animation,
+            RepaintReason::TooltipDelay => self.tooltip_delay,
+            RepaintReason::Requested => self.requested,
+        }
+    }
+
+    /// No repaint was requested.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// The repaints are only for animations, so it is fine to run them at a lower frame rate.
+    pub fn is_only_animation(&self) -> bool {
+        *self
+            == Self {
+                animation: true,
+                ..Default::default()
+            }
+    }
+
+    /// All the reasons in either.
+    pub fn union(self, other: Self) -> Self {
+        Self {
+            animation: self.animation || other.animation,
+            tooltip_delay: self.tooltip_delay || other.tooltip_delay,
+            requested: self.requested || other.requested,
+        }
+    }
+
+    fn get_mut(&mut self, reason: RepaintReason) -> &mut bool {
+        match reason {
+            RepaintReason::Animation => &mut self.animation,
+            RepaintReason::TooltipDelay => &mut self.tooltip_delay,
+            RepaintReason::Requested => &mut self.requested,
+        }
+    }
+}
+
 /// Per-viewport state related to repaint scheduling.
 struct ViewportRepaintInfo {
     /// Monotonically increasing counter.
@@ -365,6 +496,11 @@ struct ViewportRepaintInfo {
     /// What caused repaints during this pass?
     causes: Vec<RepaintCause>,
 
+    /// Why repaints were requested since the last repaint.
+    ///
+    /// This is returned in [`crate::ViewportOutput::repaint_reasons`].
+    reasons: RepaintReasons,
+
     /// What triggered a repaint the previous pass?
     /// (i.e: why are we updating now?)
     prev_causes: Vec<RepaintCause>,
@@ -389,6 +525,7 @@ impl Default for ViewportRepaintInfo {
             outstanding: 1,
 
             causes: Default::default(),
+            reasons: Default::default(),
             prev_causes: Default::default(),
 
             prev_pass_paint_delay: Duration::MAX,
@@ -442,6 +579,20 @@ struct ContextImpl {
 
     request_repaint_callback: Option<Box<dyn Fn(RequestRepaintInfo) + Send + Sync>>,
 
+    /// Set by [`Context::set_pointer_sampler`].
+    pointer_sampler: Option<Arc<dyn Fn(ViewportId) -> Option<Pos2> + Send + Sync>>,
+
+    file_dialogs: crate::file_dialog::FileDialogs,
+
+    /// See [`Context::start_macro_recording`] and [`Context::play_macro`].
+    macros: crate::macro_recording::Macros,
+
+    /// Set by [`Context::set_translator`].
+    translator: Option<Arc<dyn crate::Translator>>,
+
+    /// See [`Context::take_warnings`].
+    warnings: crate::warnings::Warnings,
+
     viewport_parents: ViewportIdMap<ViewportId>,
     viewports: ViewportIdMap<ViewportState>,
 
@@ -454,7 +605,8 @@ struct ContextImpl {
 }
 
 impl ContextImpl {
-    fn begin_pass(&mut self, mut new_raw_input: RawInput) {
+    /// `sampled_pointer_pos` is from [`Context::set_pointer_sampler`].
+    fn begin_pass(&mut self, mut new_raw_input: RawInput, sampled_pointer_pos: Option<Pos2>) {
         let viewport_id = new_raw_input.viewport_id;
         let parent_id = new_raw_input
             .viewports
@@ -466,9 +618,18 @@ impl ContextImpl {
         let is_outermost_viewport = self.viewport_stack.is_empty(); // not necessarily root, just outermost immediate viewport
         self.viewport_stack.push(ids);
 
+        #[cfg(debug_assertions)]
+        if self.memory.options.strict_mode {
+            STRICT_MODE_IN_USE.store(true, std::sync::atomic::Ordering::Relaxed);
+        }
+
         self.begin_pass_repaint_logic(viewport_id);
 
         let viewport = self.viewports.entry(viewport_id).or_default();
+        #[cfg(feature = "frame_budget")]
+        {
+            viewport.pass_start = Some(web_time::Instant::now());
+        }
 
         if is_outermost_viewport {
             if let Some(new_zoom_factor) = self.new_zoom_factor.take() {
@@ -491,6 +652,44 @@ impl ContextImpl {
             .unwrap_or(1.0);
         let pixels_per_point = self.memory.options.zoom_factor * native_pixels_per_point;
 
+        self.file_dialogs.begin_pass(&mut new_raw_input.events);
+
+        if is_outermost_viewport {
+            let (widgets, to_global) = (&viewport.prev_pass.widgets, &self.memory.to_global);
+            self.macros.begin_pass(&mut new_raw_input.events, |id| {
+                let widget = widgets.get(id)?;
+                let to_global = to_global.get(&widget.layer_id).copied().unwrap_or_default();
+                Some(to_global * widget.interact_rect.center())
+            });
+        }
+
+        if let Some(sampled_pos) = sampled_pointer_pos {
+            // Where egui thinks the pointer is after the events of this pass:
+            let known_pos = new_raw_input
+                .events
+                .iter()
+                .rev()
+                .find_map(|event| match event {
+                    crate::Event::PointerMoved(pos) | crate::Event::PointerButton { pos, .. } => {
+                        Some(Some(*pos))
+                    }
+                    crate::Event::PointerGone => Some(None),
+                    _ => None,
+                })
+                .unwrap_or_else(|| {
+                    self.viewports
+                        .get(&viewport_id)
+                        .and_then(|viewport| viewport.input.pointer.latest_pos())
+                });
+
+            // Only refine the position of a pointer we already know is over the viewport:
+            if known_pos.is_some_and(|known_pos| known_pos != sampled_pos) {
+                new_raw_input
+                    .events
+                    .push(crate::Event::PointerMoved(sampled_pos));
+            }
+        }
+
         let all_viewport_ids: ViewportIdSet = self.all_viewport_ids();
 
         let viewport = self.viewports.entry(self.viewport_id()).or_default();
@@ -527,6 +726,47 @@ impl ContextImpl {
                 WidgetHits::default()
             };
 
+            let pointer_capture = self.memory.interaction().pointer_capture;
+            if let Some(captured) =
+                pointer_capture.and_then(|id| viewport.prev_pass.widgets.get(id).copied())
+            {
+                // The widget that captured the pointer gets all pointer input,
+                // no matter where the pointer is:
+                viewport.hits = WidgetHits {
+                    close: vec![captured],
+                    contains_pointer: vec![captured],
+                    click: captured.sense.senses_click().then_some(captured),
+                    drag: captured.sense.senses_drag().then_some(captured),
+                };
+            } else if pointer_capture.is_some() {
+                // The widget that captured the pointer is gone.
+                self.memory.interaction_mut().pointer_capture = None;
+                viewport
+                    .commands
+                    .push(ViewportCommand::PointerCapture(false));
+            }
+
+            if let Some((lock_id, lock_origin)) = self.memory.interaction().pointer_lock {
+                if !viewport.prev_pass.widgets.contains(lock_id) {
+                    // The widget that locked the pointer is gone.
+                    self.memory.interaction_mut().pointer_lock = None;
+                    viewport.unlock_pointer(lock_origin);
+                } else if let Some(pos) = viewport.input.pointer.latest_pos() {
+                    // If the integration could not lock the cursor, it will eventually reach
+                    // the edge of the viewport. Move it back to the center so the drag can go on:
+                    let edge_margin = 32.0;
+                    if !viewport.input.pointer.is_warp_pending()
+                        && !screen_rect.shrink(edge_margin).contains(pos)
+                    {
+                        let center = screen_rect.center();
+                        viewport
+                            .commands
+                            .push(ViewportCommand::CursorPosition(center));
+                        viewport.input.pointer.expect_warp(center);
+                    }
+                }
+            }
+
             viewport.interact_widgets = crate::interaction::interact(
                 &viewport.interact_widgets,
                 &viewport.prev_pass.widgets,
@@ -545,6 +785,8 @@ impl ContextImpl {
                 size: Some(screen_rect.size()),
                 interactable: true,
                 last_became_visible_at: None,
+                monitor_signature: None,
+                restore_rect: None,
             },
         );
 
@@ -567,7 +809,12 @@ impl ContextImpl {
         self.update_fonts_mut();
 
         if let Some(delay) = repaint_after {
-            self.request_repaint_after(delay, viewport_id, RepaintCause::new());
+            self.request_repaint_after(
+                delay,
+                viewport_id,
+                RepaintCause::new(),
+                RepaintReason::Requested,
+            );
         }
     }
 
@@ -784,15 +1031,107 @@ impl Default for Context {
     }
 }
 
+/// Set once any [`Context`] has started a pass in [`Options::strict_mode`],
+/// so that the [`LockTracker`] costs nothing for everyone else.
+#[cfg(debug_assertions)]
+static STRICT_MODE_IN_USE: std::sync::atomic::AtomicBool =
+    std::sync::atomic::AtomicBool::new(false);
+
+#[cfg(debug_assertions)]
+thread_local! {
+    /// The [`Context`]s this thread has locked, see [`LockTracker`].
+    static LOCKED_CONTEXTS: std::cell::RefCell<Vec<LockedContext>> =
+        const { std::cell::RefCell::new(Vec::new()) };
+}
+
+#[cfg(debug_assertions)]
+struct LockedContext {
+    ptr: *const RwLock<ContextImpl>,
+    is_write: bool,
+    strict_mode: bool,
+}
+
+/// Detects when a thread locks a [`Context`] it has already locked,
+/// which would deadlock, and panics with a helpful message in [`Options::strict_mode`].
+#[cfg(debug_assertions)]
+struct LockTracker {
+    /// Index into [`LOCKED_CONTEXTS`].
+    index: usize,
+}
+
+#[cfg(debug_assertions)]
+impl LockTracker {
+    /// Returns `None` unless strict mode is in use.
+    fn lock(ctx: &Context, is_write: bool) -> Option<Self> {
+        if !STRICT_MODE_IN_USE.load(std::sync::atomic::Ordering::Relaxed) {
+            return None;
+        }
+        let ptr = Arc::as_ptr(&ctx.0);
+        LOCKED_CONTEXTS.with_borrow_mut(|locked| {
+            let is_recursive = locked
+                .iter()
+                .any(|l| l.ptr == ptr && l.strict_mode && (l.is_write || is_write));
+            if is_recursive {
+                locked.clear(); // So that the panic doesn't trigger further errors
+                panic!(
+                    "egui strict mode: the Context was locked while it was already locked by the same thread, which would deadlock. \
+                    This happens when calling a Context method from within the closure of another one, \
+                    e.g. `ctx.input(|i| ctx.memory(|m| …))`. Read what you need first, then call the next method."
+                );
+            }
+            locked.push(LockedContext {
+                ptr,
+                is_write,
+                strict_mode: false,
+            });
+            Some(Self {
+                index: locked.len() - 1,
+            })
+        })
+    }
+
+    /// Called once the lock has been acquired.
+    fn set_strict_mode(&self, strict_mode: bool) {
+        LOCKED_CONTEXTS.with_borrow_mut(|locked| {
+            if let Some(entry) = locked.get_mut(self.index) {
+                entry.strict_mode = strict_mode;
+            }
+        });
+    }
+}
+
+#[cfg(debug_assertions)]
+impl Drop for LockTracker {
+    fn drop(&mut self) {
+        LOCKED_CONTEXTS.with_borrow_mut(|locked| {
+            locked.pop();
+        });
+    }
+}
+
 impl Context {
     /// Do read-only (shared access) transaction on Context
     fn read<R>(&self, reader: impl FnOnce(&ContextImpl) -> R) -> R {
-        reader(&self.0.read())
+        #[cfg(debug_assertions)]
+        let tracker = LockTracker::lock(self, false);
+        let ctx = self.0.read();
+        #[cfg(debug_assertions)]
+        if let Some(tracker) = &tracker {
+            tracker.set_strict_mode(ctx.memory.options.strict_mode);
+        }
+        reader(&ctx)
     }
 
     /// Do read-write (exclusive access) transaction on Context
     fn write<R>(&self, writer: impl FnOnce(&mut ContextImpl) -> R) -> R {
-        writer(&mut self.0.write())
+        #[cfg(debug_assertions)]
+        let tracker = LockTracker::lock(self, true);
+        let mut ctx = self.0.write();
+        #[cfg(debug_assertions)]
+        if let Some(tracker) = &tracker {
+            tracker.set_strict_mode(ctx.memory.options.strict_mode);
+        }
+        writer(&mut ctx)
     }
 
     /// Run the ui code for one frame.
@@ -888,6 +1227,74 @@ impl Context {
         output
     }
 
+    /// Run frames until the layout has settled, e.g. for tests and screenshots.
+    ///
+    /// This calls [`Self::run`] up to `max_frames` times, until a frame
+    /// has no [`Self::request_discard`], no ongoing animations,
+    /// and the same widget rectangles as the frame before it.
+    ///
+    /// The events of `raw_input` are only given to the first frame,
+    /// and [`RawInput::time`] is advanced by [`RawInput::predicted_dt`] each frame.
+    ///
+    /// The returned output has the shapes of the last frame,
+    /// and the texture changes and platform output of all frames.
+    ///
+    /// ```
+    /// let ctx = egui::Context::default();
+    /// let full_output = ctx.run_until_stable(egui::RawInput::default(), |ctx| {
+    ///     egui::CentralPanel::default().show(&ctx, |ui| {
+    ///         egui::Grid::new("grid").show(ui, |ui| {
+    ///             ui.label("Hello egui!");
+    ///         });
+    ///     });
+    /// }, 10);
+    /// ```
+    #[must_use]
+    pub fn run_until_stable(
+        &self,
+        mut raw_input: RawInput,
+        mut run_ui: impl FnMut(&Self),
+        max_frames: usize,
+    ) -> FullOutput {
+        profiling::function_scope!();
+        let viewport_id = raw_input.viewport_id;
+
+        let mut output = FullOutput::default();
+        let mut prev_widgets = None;
+
+        for frame in 0..max_frames.max(1) {
+            let mut frame_input = raw_input.take();
+            if let Some(time) = &mut frame_input.time {
+                *time += frame as f64 * frame_input.predicted_dt as f64;
+            }
+
+            let frame_output = self.run(frame_input, &mut run_ui);
+
+            let repaint_reasons = frame_output
+                .viewport_output
+                .get(&viewport_id)
+                .map(|viewport| viewport.repaint_reasons)
+                .unwrap_or_default();
+            let is_animating = repaint_reasons.contains(RepaintReason::Animation);
+            let requested_discard = frame_output.platform_output.requested_discard();
+            let widgets =
+                self.viewport_for(viewport_id, |viewport| viewport.prev_pass.widgets.clone());
+            let same_layout = prev_widgets.as_ref() == Some(&widgets);
+            prev_widgets = Some(widgets);
+
+            output.append(frame_output);
+
+            if same_layout && !requested_discard && !is_animating {
+                return output;
+            }
+        }
+
+        #[cfg(feature = "log")]
+        log::debug!("The layout was still not stable after {max_frames} frames");
+
+        output
+    }
+
     /// An alternative to calling [`Self::run`].
     ///
     /// It is usually better to use [`Self::run`], because
@@ -908,13 +1315,25 @@ impl Context {
     /// let full_output = ctx.end_pass();
     /// // handle full_output
     /// ```
-    pub fn begin_pass(&self, new_input: RawInput) {
+    pub fn begin_pass(&self, mut new_input: RawInput) {
         profiling::function_scope!();
 
-        self.write(|ctx| ctx.begin_pass(new_input));
+        let plugins = self.read(|ctx| ctx.plugins.clone());
+
+        // Input middlewares get to see (and change) the input before anyone else:
+        plugins.on_input(self, &mut new_input);
+
+        // Called without holding the lock, so that the sampler can use the context:
+        let pointer_sampler = self.read(|ctx| ctx.pointer_sampler.clone());
+        let sampled_pointer_pos =
+            pointer_sampler.and_then(|pointer_sampler| pointer_sampler(new_input.viewport_id));
+
+        self.write(|ctx| ctx.begin_pass(new_input, sampled_pointer_pos));
+
+        self.update_theme_transition();
 
         // Plugins run just after the pass starts:
-        self.read(|ctx| ctx.plugins.clone()).on_begin_pass(self);
+        plugins.on_begin_pass(self);
     }
 
     /// See [`Self::begin_pass`].
@@ -922,6 +1341,22 @@ impl Context {
     pub fn begin_frame(&self, new_input: RawInput) {
         self.begin_pass(new_input);
     }
+
+    /// Animate the switch between dark and light mode, see [`crate::Options::theme_transition_time`].
+    fn update_theme_transition(&self) {
+        let (is_dark, transition_time) = self.options(|options| {
+            (
+                options.theme() == Theme::Dark,
+                options.theme_transition_time,
+            )
+        });
+        let how_dark = self.animate_bool_with_time(
+            Id::new("__egui_theme_transition"),
+            is_dark,
+            transition_time,
+        );
+        self.options_mut(|options| options.set_theme_transition(how_dark));
+    }
 }
 
 /// ## Borrows parts of [`Context`]
@@ -977,6 +1412,21 @@ impl Context {
         self.write(move |ctx| writer(&mut ctx.memory))
     }
 
+    /// Capture the state of the ui, so that you can restore it with [`Self::restore_session`].
+    ///
+    /// See [`Memory::save_session`].
+    pub fn save_session(&self) -> SessionState {
+        self.memory(|mem| mem.save_session())
+    }
+
+    /// Restore the state captured with [`Self::save_session`].
+    ///
+    /// See [`Memory::restore_session`].
+    pub fn restore_session(&self, session: SessionState) {
+        self.memory_mut(|mem| mem.restore_session(session));
+        self.request_repaint();
+    }
+
     /// Read-only access to [`IdTypeMap`], which stores superficial widget state.
     #[inline]
     pub fn data<R>(&self, reader: impl FnOnce(&IdTypeMap) -> R) -> R {
@@ -1060,6 +1510,40 @@ impl Context {
         })
     }
 
+    /// The size of some text, wrapped at `wrap_width`.
+    ///
+    /// Use [`f32::INFINITY`] to only break lines at `\n`.
+    /// This uses the same cached layout as labels, so it is cheap to call every frame.
+    ///
+    /// Not valid until first call to [`Context::run()`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let font_id = egui::FontId::proportional(14.0);
+    /// let column_width = ui.ctx().measure_text("Longest cell", font_id, f32::INFINITY).x;
+    /// let num_columns = (ui.available_width() / column_width).floor().max(1.0) as usize;
+    /// # });
+    /// ```
+    ///
+    /// For more details, like the rect of each row, use [`Self::layout_text`].
+    pub fn measure_text(&self, text: impl Into<String>, font_id: FontId, wrap_width: f32) -> Vec2 {
+        self.layout_text(text, font_id, wrap_width).size()
+    }
+
+    /// Lay out some text, wrapped at `wrap_width`, to be painted later.
+    ///
+    /// The galley has a placeholder color, so paint it with [`Painter::galley`] and a fallback color.
+    ///
+    /// Not valid until first call to [`Context::run()`].
+    pub fn layout_text(
+        &self,
+        text: impl Into<String>,
+        font_id: FontId,
+        wrap_width: f32,
+    ) -> Arc<Galley> {
+        self.fonts(|fonts| fonts.layout_delayed_color(text.into(), font_id, wrap_width))
+    }
+
     /// Read-only access to [`Options`].
     #[inline]
     pub fn options<R>(&self, reader: impl FnOnce(&Options) -> R) -> R {
@@ -1153,7 +1637,8 @@ impl Context {
                         format!("Widget is {} this text.\n\n\
                              ID clashes happens when things like Windows or CollapsingHeaders share names,\n\
                              or when things like Plot and Grid:s aren't given unique id_salt:s.\n\n\
-                             Sometimes the solution is to use ui.push_id.",
+                             Sometimes the solution is to use ui.push_id,\n\
+                             or ui.push_stable_id for the items of a list.",
                                 if below { "above" } else { "below" }),
                     );
                 }
@@ -1162,6 +1647,11 @@ impl Context {
 
         let id_str = id.short_debug_format();
 
+        self.report_warning(Warning::new(
+            WarningKind::IdClash,
+            format!("Two {what}s use the same ID {id_str}"),
+        ));
+
         if prev_rect.min.distance(new_rect.min) < 4.0 {
             show_error(new_rect, format!("Double use of {what} ID {id_str}"));
         } else {
@@ -1188,7 +1678,8 @@ impl Context {
             && self.memory(|mem| mem.allows_interaction(w.layer_id));
 
         // Remember this widget
-        self.write(|ctx| {
+        let outside_pass = self.write(|ctx| {
+            let outside_pass = ctx.memory.options.strict_mode && ctx.viewport_stack.is_empty();
             let viewport = ctx.viewport();
 
             // We add all widgets here, even non-interactive ones,
@@ -1198,9 +1689,36 @@ impl Context {
 
             if allow_focus && interested_in_focus {
                 ctx.memory.interested_in_focus(w.id, w.layer_id);
+
+                if ctx.memory.take_scroll_to_focused(w.id) {
+                    // Keyboard navigation moved focus here, so make sure the user can see it:
+                    let animation = ctx.memory.options.style().scroll_animation;
+                    let viewport = ctx.viewport();
+                    viewport.this_pass.scroll_target = [
+                        Some(crate::pass_state::ScrollTarget::new(
+                            w.rect.x_range(),
+                            None,
+                            animation,
+                        )),
+                        Some(crate::pass_state::ScrollTarget::new(
+                            w.rect.y_range(),
+                            None,
+                            animation,
+                        )),
+                    ];
+                }
             }
+
+            outside_pass
         });
 
+        if outside_pass {
+            self.report_misuse(format!(
+                "Widget {} was created outside of a pass. Create widgets between Context::begin_pass and Context::end_pass, e.g. in the closure given to Context::run.",
+                w.id.short_debug_format()
+            ));
+        }
+
         if allow_focus && !interested_in_focus {
             // Not interested or allowed input:
             self.memory_mut(|mem| mem.surrender_focus(w.id));
@@ -1269,6 +1787,19 @@ impl Context {
         res
     }
 
+    /// The structure of the widgets in the current viewport, as of the last finished pass.
+    ///
+    /// This includes the [`Id`], kind, rect and parent [`Ui`] of each widget,
+    /// and is cheap enough to call every frame,
+    /// e.g. for tooling that diffs the structure of the UI across code reloads.
+    pub fn widget_tree_snapshot(&self) -> crate::WidgetTreeSnapshot {
+        self.write(|ctx| {
+            let mut layers: Vec<LayerId> = ctx.viewport().prev_pass.widgets.layer_ids().collect();
+            layers.sort_by(|&a, &b| ctx.memory.areas().compare_order(a, b));
+            ctx.viewport().prev_pass.widgets.tree_snapshot(&layers)
+        })
+    }
+
     /// Read the response of some widget, which may be called _before_ creating the widget (!).
     ///
     /// This is because widget interaction happens at the start of the pass, using the widget rects from the previous pass.
@@ -1279,6 +1810,7 @@ impl Context {
     pub fn read_response(&self, id: impl Into<Id>) -> Option<Response> {
         let id = id.into();
         self.write(|ctx| {
+            let strict_mode = ctx.memory.options.strict_mode;
             let viewport = ctx.viewport();
             let widget_rect = viewport
                 .this_pass
@@ -1286,6 +1818,11 @@ impl Context {
                 .get(id)
                 .or_else(|| viewport.prev_pass.widgets.get(id))
                 .copied();
+            if widget_rect.is_none() && strict_mode {
+                // It is fine to read the response before creating the widget the first time,
+                // but it must be created later this pass:
+                viewport.this_pass.unknown_response_ids.insert(id);
+            }
             widget_rect.map(|mut rect| {
                 // If the Rect is invalid the Ui hasn't registered its final Rect yet.
                 // We return the Rect from last frame instead.
@@ -1311,6 +1848,9 @@ impl Context {
             interact_rect,
             sense,
             enabled,
+            hit_test_mode: _,
+            interact_priority: _,
+            interact_expand: _,
         } = widget_rect;
 
         // previous pass + "highlight next pass" == "highlight this pass"
@@ -1488,6 +2028,31 @@ impl Context {
         crate::debug_text::print(self, text);
     }
 
+    /// Find all text painted so far this pass with a contrast ratio against its background below `min_ratio`.
+    ///
+    /// Call this at the end of your ui code, just before [`Self::end_pass`],
+    /// with e.g. [`crate::contrast::WCAG_AA`].
+    ///
+    /// The background is found by looking at filled rectangles painted before the text,
+    /// on top of [`crate::Visuals::panel_fill`]. Images and other shapes are not taken into account.
+    ///
+    /// This is also shown as an overlay with [`crate::style::DebugOptions::show_contrast_issues`].
+    pub fn contrast_issues(&self, min_ratio: f32) -> Vec<crate::contrast::ContrastIssue> {
+        profiling::function_scope!();
+        let base_background = self.style().visuals.panel_fill;
+        self.write(|ctx| {
+            let viewport_id = ctx.viewport_id();
+            let Some(viewport) = ctx.viewports.get(&viewport_id) else {
+                return vec![];
+            };
+            let layers = viewport
+                .graphics
+                .layers_in_paint_order(ctx.memory.areas().order());
+            crate::contrast::ContrastChecker::new(base_background, min_ratio)
+                .check(&layers, &ctx.memory.to_global)
+        })
+    }
+
     /// What operating system are we running on?
     ///
     /// When compiling natively, this is
@@ -1536,6 +2101,89 @@ impl Context {
         self.send_cmd(crate::OutputCommand::OpenUrl(open_url));
     }
 
+    /// Show a system tray icon, or update the one already shown.
+    ///
+    /// Interactions with it are reported as [`crate::Event::Tray`].
+    /// You only need to call this when the icon or its menu changes.
+    /// Not all integrations support this.
+    pub fn set_tray_icon(&self, tray_icon: crate::TrayIcon) {
+        self.send_cmd(crate::OutputCommand::SetTrayIcon(tray_icon));
+    }
+
+    /// Ask the integration to show a native file dialog.
+    ///
+    /// The answer arrives as a [`crate::Event::FileDialogResult`],
+    /// see [`crate::InputState::file_dialog_result`].
+    /// If the integration doesn't support native file dialogs,
+    /// egui shows a simple dialog of its own instead.
+    pub fn open_file_dialog(&self, request: crate::FileDialogRequest) {
+        self.write(|ctx| ctx.file_dialogs.request(request.clone()));
+        self.send_cmd(crate::OutputCommand::FileDialog(request));
+    }
+
+    /// Start recording the actions of the user on widgets, e.g. clicks and changed values.
+    ///
+    /// Stop with [`Self::stop_macro_recording`], and replay with [`Self::play_macro`].
+    pub fn start_macro_recording(&self) {
+        self.write(|ctx| ctx.macros.start_recording());
+    }
+
+    /// Stop the recording started with [`Self::start_macro_recording`], and return the actions.
+    pub fn stop_macro_recording(&self) -> Vec<crate::MacroAction> {
+        self.write(|ctx| ctx.macros.stop_recording())
+    }
+
+    /// Are we recording a macro?
+    pub fn is_recording_macro(&self) -> bool {
+        self.read(|ctx| ctx.macros.is_recording())
+    }
+
+    /// Replay recorded actions, one per pass.
+    ///
+    /// The widgets are found by their [`Id`], so the layout may have changed since the recording.
+    /// Actions on widgets that are not shown are skipped.
+    pub fn play_macro(&self, actions: impl IntoIterator<Item = crate::MacroAction>) {
+        self.write(|ctx| ctx.macros.play(actions));
+        self.request_repaint();
+    }
+
+    /// Are there actions from [`Self::play_macro`] left to play?
+    pub fn is_playing_macro(&self) -> bool {
+        self.read(|ctx| ctx.macros.is_playing())
+    }
+
+    // These are called by every slider, drag value and text edit each pass,
+    // so we only take the write lock when there is a macro being recorded or played.
+
+    pub(crate) fn record_macro_event(&self, id: Id, event: &crate::output::OutputEvent) {
+        if self.is_recording_macro() {
+            self.write(|ctx| ctx.macros.record(id, event));
+        }
+    }
+
+    /// The value a [`Self::play_macro`] wants to set for this widget, if any.
+    pub(crate) fn take_macro_value(&self, id: Id) -> Option<f64> {
+        if self.read(|ctx| ctx.macros.has_values()) {
+            self.write(|ctx| ctx.macros.take_value(id))
+        } else {
+            None
+        }
+    }
+
+    /// The text a [`Self::play_macro`] wants to set for this widget, if any.
+    pub(crate) fn take_macro_text(&self, id: Id) -> Option<String> {
+        if self.read(|ctx| ctx.macros.has_texts()) {
+            self.write(|ctx| ctx.macros.take_text(id))
+        } else {
+            None
+        }
+    }
+
+    /// Remove the system tray icon set with [`Self::set_tray_icon`].
+    pub fn remove_tray_icon(&self) {
+        self.send_cmd(crate::OutputCommand::RemoveTrayIcon);
+    }
+
     /// Copy the given text to the system clipboard.
     ///
     /// Note that in web applications, the clipboard is only accessible in secure contexts (e.g.,
@@ -1645,6 +2293,41 @@ impl Context {
         })
     }
 
+    /// How long the current pass of the current viewport has been running.
+    ///
+    /// Always zero without the `frame_budget` feature.
+    pub fn pass_elapsed(&self) -> Duration {
+        #[cfg(feature = "frame_budget")]
+        {
+            self.read(|ctx| {
+                ctx.viewports
+                    .get(&ctx.viewport_id())
+                    .and_then(|v| v.pass_start)
+                    .map_or(Duration::ZERO, |start| start.elapsed())
+            })
+        }
+
+        #[cfg(not(feature = "frame_budget"))]
+        {
+            _ = self;
+            Duration::ZERO
+        }
+    }
+
+    /// Has the current pass taken longer than [`crate::Options::frame_budget`]?
+    ///
+    /// Expensive widgets can use this to show a cheap placeholder instead,
+    /// and show themselves in the next pass.
+    /// See also [`crate::Ui::defer_if_over_budget`].
+    ///
+    /// Always `false` without the `frame_budget` feature.
+    pub fn frame_budget_exceeded(&self) -> bool {
+        cfg!(feature = "frame_budget")
+            && self
+                .options(|o| o.frame_budget)
+                .is_some_and(|budget| budget <= self.pass_elapsed().as_secs_f32())
+    }
+
     /// The index of the current pass in the current frame, starting at zero.
     ///
     /// Usually this is zero, but if something called [`Self::request_discard`] to do multi-pass layout,
@@ -1766,7 +2449,19 @@ impl Context {
     #[track_caller]
     pub fn request_repaint_after_for(&self, duration: Duration, id: ViewportId) {
         let cause = RepaintCause::new();
-        self.write(|ctx| ctx.request_repaint_after(duration, id, cause));
+        self.write(|ctx| ctx.request_repaint_after(duration, id, cause, RepaintReason::Requested));
+    }
+
+    /// Like [`Self::request_repaint_after`], but tells the integration why,
+    /// see [`crate::ViewportOutput::repaint_reasons`].
+    ///
+    /// E.g. use [`RepaintReason::Animation`] for your own animations,
+    /// so that an integration can treat them like the ones of egui.
+    #[track_caller]
+    pub fn request_repaint_after_because(&self, duration: Duration, reason: RepaintReason) {
+        let cause = RepaintCause::new();
+        let id = self.viewport_id();
+        self.write(|ctx| ctx.request_repaint_after(duration, id, cause, reason));
     }
 
     /// Was a repaint requested last pass for the current viewport?
@@ -1818,6 +2513,28 @@ impl Context {
         self.write(|ctx| ctx.request_repaint_callback = Some(callback));
     }
 
+    /// For integrations: this callback will be called at the start of each pass,
+    /// right before hit-testing, to sample the latest pointer position.
+    ///
+    /// The callback is given the [`ViewportId`] of the pass and should return the
+    /// current position of the OS cursor in egui points (relative to the viewport),
+    /// or `None` if it is not known.
+    ///
+    /// This lets egui use fresher cursor data than the events in [`RawInput`],
+    /// which makes dragging feel less laggy, especially at low frame rates.
+    /// The sampled position is only used if the pointer is already known to be over the viewport.
+    ///
+    /// The callback is called without holding any lock, so it may use the [`Context`].
+    ///
+    /// Note that only one callback can be set. Any new call overrides the previous callback.
+    pub fn set_pointer_sampler(
+        &self,
+        callback: impl Fn(ViewportId) -> Option<Pos2> + Send + Sync + 'static,
+    ) {
+        let callback = Arc::new(callback);
+        self.write(|ctx| ctx.pointer_sampler = Some(callback));
+    }
+
     /// Request to discard the visual output of this pass,
     /// and to immediately do another one.
     ///
@@ -1869,10 +2586,54 @@ impl Context {
                 && vp.output.num_completed_passes + 1 < ctx.memory.options.max_passes.get()
         })
     }
+
+    /// Mark a [`Ui::static_region`] as changed, so it is not part of [`FullOutput::unchanged_rects`].
+    ///
+    /// The `id` is the [`Response::id`] of the region.
+    pub fn invalidate_static_region(&self, id: Id) {
+        self.data_mut(|data| {
+            data.get_temp_mut_or_default::<StaticRegionState>(id)
+                .invalidated = true;
+        });
+    }
 }
 
 /// Callbacks
 impl Context {
+    /// Register a middleware that can inspect and modify the [`RawInput`]
+    /// at the start of each pass of each viewport, before egui consumes it.
+    ///
+    /// This can be used to remap keys, inject synthetic events,
+    /// or suppress input (e.g. during a screen capture) without touching the backend.
+    ///
+    /// Middlewares are called in the order they were added,
+    /// each one seeing the changes of the previous ones.
+    ///
+    /// ```
+    /// # let ctx = egui::Context::default();
+    /// ctx.add_input_middleware(
+    ///     "swap_tab_and_enter",
+    ///     std::sync::Arc::new(|_ctx, raw_input| {
+    ///         for event in &mut raw_input.events {
+    ///             if let egui::Event::Key { key, .. } = event {
+    ///                 *key = match *key {
+    ///                     egui::Key::Tab => egui::Key::Enter,
+    ///                     egui::Key::Enter => egui::Key::Tab,
+    ///                     other => other,
+    ///                 };
+    ///             }
+    ///         }
+    ///     }),
+    /// );
+    /// ```
+    pub fn add_input_middleware(&self, debug_name: &'static str, middleware: InputMiddleware) {
+        let named_middleware = NamedInputMiddleware {
+            debug_name,
+            middleware,
+        };
+        self.write(|ctx| ctx.plugins.input_middlewares.push(named_middleware));
+    }
+
     /// Call the given callback at the start of each pass of each viewport.
     ///
     /// This can be used for egui _plugins_.
@@ -1960,6 +2721,11 @@ impl Context {
         }
     }
 
+    /// What the backend supports, as reported in [`crate::RawInput::capabilities`].
+    pub fn backend_capabilities(&self) -> crate::BackendCapabilities {
+        self.input(|i| i.raw.capabilities)
+    }
+
     /// Does the OS use dark or light mode?
     /// This is used when the theme preference is set to [`crate::ThemePreference::System`].
     pub fn system_theme(&self) -> Option<Theme> {
@@ -2094,6 +2860,60 @@ impl Context {
         self.style_mut_of(self.theme(), |style| style.visuals = visuals);
     }
 
+    /// Translate the strings that egui itself shows, e.g. in the color picker.
+    ///
+    /// See [`crate::translation`] for the keys.
+    /// Strings the translator doesn't know are shown in English.
+    pub fn set_translator(&self, translator: impl crate::Translator + 'static) {
+        self.write(|ctx| ctx.translator = Some(Arc::new(translator)));
+    }
+
+    /// Go back to showing the built-in strings in English.
+    pub fn remove_translator(&self) {
+        self.write(|ctx| ctx.translator = None);
+    }
+
+    /// Translate a built-in string, using the [`crate::Translator`] set with [`Self::set_translator`].
+    ///
+    /// Falls back to `english` if there is no translation.
+    pub fn tr(&self, key: &str, english: &str) -> String {
+        let translator = self.read(|ctx| ctx.translator.clone());
+        translator
+            .and_then(|translator| translator.translate(key))
+            .unwrap_or_else(|| english.to_owned())
+    }
+
+    /// Translate a built-in string that depends on a count.
+    ///
+    /// Any `{count}` in the result is replaced by `count`.
+    /// Falls back to `english_one` if `count` is one, otherwise `english_other`.
+    ///
+    /// ```
+    /// # let ctx = egui::Context::default();
+    /// assert_eq!(ctx.tr_plural("items", 1, "{count} item", "{count} items"), "1 item");
+    /// assert_eq!(ctx.tr_plural("items", 3, "{count} item", "{count} items"), "3 items");
+    /// ```
+    #[expect(clippy::literal_string_with_formatting_args)] // `{count}` is our own placeholder
+    pub fn tr_plural(
+        &self,
+        key: &str,
+        count: u64,
+        english_one: &str,
+        english_other: &str,
+    ) -> String {
+        let translator = self.read(|ctx| ctx.translator.clone());
+        let text = translator
+            .and_then(|translator| translator.translate_plural(key, count))
+            .unwrap_or_else(|| {
+                if count == 1 {
+                    english_one.to_owned()
+                } else {
+                    english_other.to_owned()
+                }
+            });
+        text.replace("{count}", &count.to_string())
+    }
+
     /// The number of physical pixels for each logical point.
     ///
     /// This is calculated as [`Self::zoom_factor`] * [`Self::native_pixels_per_point`]
@@ -2208,6 +3028,16 @@ impl Context {
         let name = name.into();
         let image = image.into();
         let max_texture_side = self.input(|i| i.max_texture_side);
+        if max_texture_side < image.width() || max_texture_side < image.height() {
+            self.report_warning(Warning::new(
+                WarningKind::TextureTooLarge,
+                format!(
+                    "Texture {name:?} has size {}x{}, but the maximum texture side is {max_texture_side}",
+                    image.width(),
+                    image.height(),
+                ),
+            ));
+        }
         debug_assert!(
             image.width() <= max_texture_side && image.height() <= max_texture_side,
             "Texture {:?} has size {}x{}, but the maximum texture side is {}",
@@ -2230,6 +3060,82 @@ impl Context {
         self.read(|ctx| ctx.tex_manager.0.clone())
     }
 
+    /// Show some ui offscreen, and paint it into an image on the CPU.
+    ///
+    /// The ui is shown in a [`crate::CentralPanel`] of the given size (in points),
+    /// in a separate [`Context`] with the same style, fonts and [`Self::pixels_per_point`] as this one.
+    /// That means the ui has its own memory, so e.g. collapsing headers start out closed.
+    ///
+    /// Only textures created by the offscreen ui (like the font atlas) are available to it,
+    /// other images are painted white. [`crate::PaintCallback`]s are not painted.
+    ///
+    /// Can only be called after the first call to [`Self::run`].
+    /// Painting on the CPU is slow, so only do this when the contents change.
+    ///
+    /// See also [`Self::render_ui_to_texture`].
+    pub fn render_ui_to_image(
+        &self,
+        size: Vec2,
+        mut add_contents: impl FnMut(&mut Ui),
+    ) -> ColorImage {
+        profiling::function_scope!();
+
+        let offscreen = Self::default();
+        offscreen.options_mut(|options| *options = self.options(|options| options.clone()));
+        offscreen.set_fonts(self.fonts(|fonts| fonts.lock().fonts.definitions().clone()));
+
+        let mut input = RawInput {
+            screen_rect: Some(Rect::from_min_size(Pos2::ZERO, size)),
+            max_texture_side: Some(self.input(|i| i.max_texture_side)),
+            ..Default::default()
+        };
+        input
+            .viewports
+            .entry(ViewportId::ROOT)
+            .or_default()
+            .native_pixels_per_point = self.native_pixels_per_point();
+
+        let output = offscreen.run(input, |ctx| {
+            crate::CentralPanel::default().show(ctx, |ui| add_contents(ui));
+        });
+
+        let mut textures = crate::offscreen::Textures::default();
+        textures.apply(&output.textures_delta);
+        let primitives = offscreen.tessellate(output.shapes, output.pixels_per_point);
+        let size_px = (size * output.pixels_per_point).round();
+        crate::offscreen::paint(
+            &primitives,
+            &textures,
+            output.pixels_per_point,
+            [size_px.x as usize, size_px.y as usize],
+        )
+    }
+
+    /// Show some ui offscreen, and upload it as a texture.
+    ///
+    /// Useful for previews, like theme thumbnails or a minimap.
+    /// Keep the returned [`TextureHandle`] around, and only render again when the contents change.
+    ///
+    /// See [`Self::render_ui_to_image`] for details.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let preview = ui.ctx().render_ui_to_texture(egui::vec2(120.0, 80.0), |ui| {
+    ///     ui.heading("Preview");
+    ///     let _ = ui.button("Click me");
+    /// });
+    /// ui.image((preview.id(), preview.size_vec2() / ui.pixels_per_point()));
+    /// # });
+    /// ```
+    pub fn render_ui_to_texture(
+        &self,
+        size: Vec2,
+        add_contents: impl FnMut(&mut Ui),
+    ) -> TextureHandle {
+        let image = self.render_ui_to_image(size, add_contents);
+        self.load_texture("render_ui_to_texture", image, TextureOptions::LINEAR)
+    }
+
     // ---------------------------------------------------------------------
 
     /// Constrain the position of a window/area so it fits within the provided boundary.
@@ -2259,9 +3165,20 @@ impl Context {
             crate::gui_zoom::zoom_with_keyboard(self);
         }
 
+        if self.read(|ctx| ctx.file_dialogs.has_fallbacks()) {
+            // Can't hold the lock while showing the ui:
+            let mut file_dialogs = self.write(|ctx| std::mem::take(&mut ctx.file_dialogs));
+            file_dialogs.show_fallbacks(self);
+            self.write(|ctx| ctx.file_dialogs = file_dialogs);
+        }
+
         // Plugins run just before the pass ends.
         self.read(|ctx| ctx.plugins.clone()).on_end_pass(self);
 
+        self.paint_focus_ring();
+
+        self.report_end_of_pass_warnings();
+
         #[cfg(debug_assertions)]
         self.debug_painting();
 
@@ -2275,6 +3192,79 @@ impl Context {
         self.end_pass()
     }
 
+    /// Called at the end of the pass, see [`crate::style::FocusRing`].
+    fn paint_focus_ring(&self) {
+        let focus_ring = self.style().visuals.focus_ring.clone();
+        if focus_ring.stroke.is_empty() {
+            return;
+        }
+
+        let Some(widget) = self.write(|ctx| {
+            let id = if focus_ring.keyboard_only {
+                ctx.memory.keyboard_focused()
+            } else {
+                ctx.memory.focused()
+            }?;
+            ctx.viewport().this_pass.widgets.get(id).copied()
+        }) else {
+            return;
+        };
+        if !widget.interact_rect.is_positive() {
+            return;
+        }
+
+        // The ring may stick out of the clip rect, except where the widget itself is clipped:
+        let (rect, visible) = (widget.rect, widget.interact_rect);
+        let mut clip_rect = visible.expand(focus_ring.offset.max(0.0) + focus_ring.stroke.width);
+        if rect.min.x < visible.min.x {
+            clip_rect.min.x = visible.min.x;
+        }
+        if rect.min.y < visible.min.y {
+            clip_rect.min.y = visible.min.y;
+        }
+        if visible.max.x < rect.max.x {
+            clip_rect.max.x = visible.max.x;
+        }
+        if visible.max.y < rect.max.y {
+            clip_rect.max.y = visible.max.y;
+        }
+
+        let painter = Painter::new(self.clone(), widget.layer_id, clip_rect);
+        let ring_rect = rect.expand(focus_ring.offset + 0.5 * focus_ring.stroke.width);
+
+        if let Some(dash_length) = focus_ring.dash_length {
+            let mut path = vec![];
+            epaint::tessellator::path::rounded_rectangle(
+                &mut path,
+                ring_rect,
+                focus_ring.corner_radius.into(),
+            );
+            if let Some(&first) = path.first() {
+                path.push(first);
+            }
+
+            let time = self.input(|i| i.time) as f32;
+            let dash_offset = (time * focus_ring.dash_speed).rem_euclid(2.0 * dash_length);
+            painter.extend(epaint::Shape::dashed_line_with_offset(
+                &path,
+                focus_ring.stroke,
+                &[dash_length],
+                &[dash_length],
+                dash_offset,
+            ));
+            if focus_ring.dash_speed != 0.0 {
+                self.request_repaint();
+            }
+        } else {
+            painter.rect_stroke(
+                ring_rect,
+                focus_ring.corner_radius,
+                focus_ring.stroke,
+                StrokeKind::Middle,
+            );
+        }
+    }
+
     /// Called at the end of the pass.
     #[cfg(debug_assertions)]
     fn debug_painting(&self) {
@@ -2399,6 +3389,18 @@ impl Context {
             }
         }
 
+        if self.style().debug.show_contrast_issues {
+            let painter = self.debug_painter();
+            for issue in self.contrast_issues(crate::contrast::WCAG_AA) {
+                let color = if issue.ratio < crate::contrast::WCAG_AA_LARGE_TEXT {
+                    Color32::RED
+                } else {
+                    Color32::ORANGE
+                };
+                painter.debug_rect(issue.rect, color, format!("{:.1}:1", issue.ratio));
+            }
+        }
+
         if let Some(debug_rect) = self.pass_state_mut(|fs| fs.debug_rect.take()) {
             debug_rect.paint(&self.debug_painter());
         }
@@ -2491,9 +3493,21 @@ impl ContextImpl {
             }
         }
 
-        let shapes = viewport
-            .graphics
-            .drain(self.memory.areas().order(), &self.memory.to_global);
+        let unchanged_rects = unchanged_static_rects(
+            &viewport.this_pass.static_regions,
+            &viewport.graphics,
+            &mut self.memory,
+            viewport.input.time,
+        );
+
+        let shapes = viewport.graphics.drain(
+            self.memory.areas().order(),
+            &self.memory.to_global,
+            &self.memory.layer_colors,
+            &self.memory.layer_opacity,
+        );
+
+        viewport.this_pass.widgets.sort_elevated_last();
 
         let mut repaint_needed = false;
 
@@ -2578,6 +3592,11 @@ impl ContextImpl {
                         viewport_ui_cb: viewport.viewport_ui_cb.clone(),
                         commands,
                         repaint_delay: viewport.repaint.repaint_delay,
+                        repaint_deadline: (viewport.repaint.repaint_delay < Duration::MAX)
+                            .then_some(
+                                viewport.input.time + viewport.repaint.repaint_delay.as_secs_f64(),
+                            ),
+                        repaint_reasons: viewport.repaint.reasons,
                     },
                 )
             })
@@ -2619,8 +3638,95 @@ impl ContextImpl {
             shapes,
             pixels_per_point,
             viewport_output,
+            unchanged_rects,
+            color_space: self.memory.options.color_space,
+        }
+    }
+}
+
+/// The global rectangles of the [`StaticRegion`]s that look the same as in the previous pass.
+///
+/// A region has changed if it was interacted with, invalidated, moved,
+/// or covered by something painted after it.
+/// It is only unchanged once it has not changed for the animation time of the style,
+/// so that any hover animations have finished.
+fn unchanged_static_rects(
+    regions: &[StaticRegion],
+    graphics: &GraphicLayers,
+    memory: &mut Memory,
+    time: f64,
+) -> Vec<Rect> {
+    if regions.is_empty() {
+        return vec![];
+    }
+
+    let animation_time = memory.options.style().animation_time as f64;
+    let layers = graphics.layers_in_paint_order(memory.areas().order());
+    let to_global =
+        |layer_id: LayerId| memory.to_global.get(&layer_id).copied().unwrap_or_default();
+
+    let mut unchanged_rects = vec![];
+    for region in regions {
+        let rect = to_global(region.layer_id) * region.rect;
+
+        let mut is_covered = false;
+        let mut is_above_region = false;
+        for (layer_id, paint_list) in &layers {
+            let shapes = if *layer_id == region.layer_id {
+                is_above_region = true;
+                paint_list.entries_in_range(region.shapes.1, paint_list.next_idx())
+            } else if is_above_region {
+                paint_list.entries_in_range(ShapeIdx(0), paint_list.next_idx())
+            } else {
+                continue;
+            };
+            let transform = to_global(*layer_id);
+            is_covered = shapes.iter().any(|clipped| {
+                let shape_rect = clipped
+                    .shape
+                    .visual_bounding_rect()
+                    .intersect(clipped.clip_rect);
+                (transform * shape_rect).intersects(rect)
+            });
+            if is_covered {
+                break;
+            }
+        }
+
+        // We only look at the shapes above the region, so what is beneath must be hidden:
+        let is_see_through = !region.opaque
+            || memory
+                .layer_opacity
+                .get(&region.layer_id)
+                .is_some_and(|opacity| *opacity < 1.0);
+
+        let prev = memory.data.get_temp::<StaticRegionState>(region.id);
+        let changed = region.interacted
+            || is_see_through
+            || is_covered
+            || prev.is_none_or(|prev| {
+                prev.invalidated
+                    || prev.rect != rect
+                    || prev.font_atlas_generation != region.font_atlas_generation
+            });
+        let last_changed = match prev {
+            Some(prev) if !changed => prev.last_changed,
+            _ => time,
+        };
+        if animation_time < time - last_changed {
+            unchanged_rects.push(rect);
         }
+        memory.data.insert_temp(
+            region.id,
+            StaticRegionState {
+                rect,
+                last_changed,
+                invalidated: false,
+                font_atlas_generation: region.font_atlas_generation,
+            },
+        );
     }
+    unchanged_rects
 }
 
 impl Context {
@@ -2641,7 +3747,12 @@ impl Context {
         // it takes to tessellate them, so it is not a worth optimization.
 
         self.write(|ctx| {
-            let tessellation_options = ctx.memory.options.tessellation_options;
+            let mut tessellation_options = ctx.memory.options.tessellation_options;
+            let capabilities = ctx.viewport().input.raw.capabilities;
+            tessellation_options.instancing = capabilities.instancing;
+            tessellation_options.hdr = capabilities.hdr;
+            tessellation_options.composite = capabilities.composite;
+            tessellation_options.color_space = ctx.memory.options.color_space;
             let texture_atlas = if let Some(fonts) = ctx.fonts.get(&pixels_per_point.into()) {
                 fonts.texture_atlas()
             } else {
@@ -2659,16 +3770,19 @@ impl Context {
                 (atlas.size(), atlas.prepared_discs())
             };
 
-            let paint_stats = PaintStats::from_shapes(&shapes);
+            let mut paint_stats = PaintStats::from_shapes(&shapes);
             let clipped_primitives = {
                 profiling::scope!("tessellator::tessellate_shapes");
-                tessellator::Tessellator::new(
+                let mut tessellator = tessellator::Tessellator::new(
                     pixels_per_point,
                     tessellation_options,
                     font_tex_size,
                     prepared_discs,
-                )
-                .tessellate_shapes(shapes)
+                );
+                let clipped_primitives = tessellator.tessellate_shapes(shapes);
+                paint_stats.num_coarsened_curves = tessellator.num_coarsened_curves();
+                paint_stats.num_occluded_shapes = tessellator.num_occluded_shapes();
+                clipped_primitives
             };
             ctx.paint_stats = paint_stats.with_clipped_primitives(&clipped_primitives);
             clipped_primitives
@@ -2705,6 +3819,65 @@ impl Context {
         (self.used_rect().max - Pos2::ZERO).round_ui()
     }
 
+    /// The parts of the current viewport that egui is using and that should receive mouse input.
+    ///
+    /// This is the space used by panels, plus the rectangle of every visible [`crate::Area`],
+    /// [`crate::Window`], popup and tooltip.
+    /// Call this at the end of the pass, after all the ui has been added.
+    ///
+    /// For overlay/HUD apps with a transparent window, this is a good default for
+    /// [`ViewportCommand::SetHitTestRegions`]; see also [`Self::update_hit_test_regions`].
+    pub fn hit_test_regions(&self) -> Vec<Rect> {
+        self.write(|ctx| {
+            let mut regions = vec![];
+
+            let used_by_panels = ctx.viewport().this_pass.used_by_panels;
+            if used_by_panels.is_positive() {
+                regions.push(used_by_panels);
+            }
+
+            let areas = ctx.memory.areas();
+            for layer_id in areas.order() {
+                if layer_id.order == Order::Background || !areas.is_visible(layer_id) {
+                    continue;
+                }
+                if let Some(state) = areas.get(layer_id.id) {
+                    let mut rect = state.rect();
+                    if let Some(to_global) = ctx.memory.to_global.get(layer_id) {
+                        rect = *to_global * rect;
+                    }
+                    if rect.is_positive() {
+                        regions.push(rect.round_ui());
+                    }
+                }
+            }
+
+            regions
+        })
+    }
+
+    /// Send [`Self::hit_test_regions`] to the current viewport, if they have changed.
+    ///
+    /// Call this at the end of each pass in an overlay/HUD app,
+    /// so that mouse clicks pass through the parts of the window where there is no ui.
+    ///
+    /// Only has an effect with integrations that support [`ViewportCommand::SetHitTestRegions`].
+    pub fn update_hit_test_regions(&self) {
+        let regions = self.hit_test_regions();
+        let changed = self.write(|ctx| {
+            let viewport = ctx.viewport();
+            if viewport.hit_test_regions.as_ref() == Some(&regions) {
+                false
+            } else {
+                viewport.hit_test_regions = Some(regions.clone());
+                true
+            }
+        });
+        if changed {
+            self.send_viewport_cmd(ViewportCommand::SetHitTestRegions(regions));
+        }
+    }
+
     // ---------------------------------------------------------------------
 
     /// Is the pointer (mouse/touch) over any egui area?
@@ -2758,6 +3931,31 @@ impl Context {
         self.pass_state_mut(|fs| fs.highlight_next_pass.insert(id.into()));
     }
 
+    /// Scroll the [`crate::ScrollArea`]s containing the [`Ui::anchor`] with the given name,
+    /// so that the anchor ends up at the top.
+    ///
+    /// If `highlight` is set, the anchor briefly flashes once we get there.
+    ///
+    /// If the anchor is not currently shown (e.g. it is on a closed tab),
+    /// we navigate to it once it is.
+    pub fn navigate_to_anchor(&self, name: impl Into<String>, highlight: bool) {
+        self.memory_mut(|mem| {
+            mem.anchor_navigation = Some(crate::memory::AnchorNavigation {
+                name: name.into(),
+                highlight,
+                arrived_at: None,
+            });
+        });
+        self.request_repaint();
+    }
+
+    /// The names of all [`Ui::anchor`]s shown during the previous pass, in order.
+    ///
+    /// Useful for building a table of contents.
+    pub fn anchors(&self) -> Vec<String> {
+        self.prev_pass_state(|fs| fs.anchors.iter().map(|(name, _)| name.clone()).collect())
+    }
+
     /// Is an egui context menu open?
     ///
     /// This only works with the old, deprecated [`crate::menu`] API.
@@ -2851,6 +4049,50 @@ impl Context {
             .map(|t| t.inverse())
     }
 
+    /// Set the opacity of everything painted on the given layer, in the range `0.0..=1.0`.
+    ///
+    /// This is applied at the end of the pass, so it affects all shapes of the layer,
+    /// and stays in effect until changed.
+    ///
+    /// [`crate::Area`]:s that fade in or out (see [`crate::Area::fade_in`]) set the opacity
+    /// of their layer themselves, each pass.
+    pub fn set_layer_opacity(&self, layer_id: LayerId, opacity: f32) {
+        let opacity = opacity.clamp(0.0, 1.0);
+        self.memory_mut(|m| {
+            if opacity == 1.0 {
+                m.layer_opacity.remove(&layer_id)
+            } else {
+                m.layer_opacity.insert(layer_id, opacity)
+            }
+        });
+    }
+
+    /// The opacity of the given layer, set with [`Self::set_layer_opacity`].
+    pub fn layer_opacity(&self, layer_id: LayerId) -> f32 {
+        self.memory(|m| m.layer_opacity.get(&layer_id).copied().unwrap_or(1.0))
+    }
+
+    /// Adjust the colors of everything painted on the given layer,
+    /// e.g. to desaturate the background behind a modal dialog.
+    ///
+    /// This is applied at the end of the pass, so it affects all shapes of the layer,
+    /// and stays in effect until changed.
+    /// Use [`ColorTransform::IDENTITY`] to go back to normal.
+    pub fn set_layer_color_transform(&self, layer_id: LayerId, transform: ColorTransform) {
+        self.memory_mut(|m| {
+            if transform.is_identity() {
+                m.layer_colors.remove(&layer_id)
+            } else {
+                m.layer_colors.insert(layer_id, transform)
+            }
+        });
+    }
+
+    /// The color adjustment of the given layer, set with [`Self::set_layer_color_transform`].
+    pub fn layer_color_transform(&self, layer_id: LayerId) -> ColorTransform {
+        self.memory(|m| m.layer_colors.get(&layer_id).copied().unwrap_or_default())
+    }
+
     /// Transform all the graphics at the given layer.
     ///
     /// Is used to implement drag-and-drop preview.
@@ -3010,7 +4252,7 @@ impl Context {
 
         let animation_in_progress = 0.0 < animated_value && animated_value < 1.0;
         if animation_in_progress {
-            self.request_repaint();
+            self.request_repaint_after_because(Duration::ZERO, RepaintReason::Animation);
         }
 
         if target_value {
@@ -3036,7 +4278,7 @@ impl Context {
         });
         let animation_in_progress = animated_value != target_value;
         if animation_in_progress {
-            self.request_repaint();
+            self.request_repaint_after_because(Duration::ZERO, RepaintReason::Animation);
         }
 
         animated_value
@@ -3048,6 +4290,36 @@ impl Context {
     }
 }
 
+/// ## Scrolling
+impl Context {
+    /// The state of the [`crate::ScrollArea`] with the given id, if it has been shown.
+    ///
+    /// The id is [`crate::scroll_area::ScrollAreaOutput::id`].
+    pub fn scroll_area_state(&self, id: Id) -> Option<crate::scroll_area::State> {
+        crate::scroll_area::State::load(self, id)
+    }
+
+    /// Smoothly scroll the [`crate::ScrollArea`] with the given id to the given offset.
+    ///
+    /// This can be called from outside the scroll area,
+    /// e.g. to keep two panes in sync, or to restore a scroll position.
+    /// Use [`crate::style::ScrollAnimation::none`] to jump there directly.
+    ///
+    /// The id is [`crate::scroll_area::ScrollAreaOutput::id`].
+    pub fn scroll_to_offset_animated(
+        &self,
+        id: Id,
+        offset: Vec2,
+        animation: crate::style::ScrollAnimation,
+    ) {
+        let mut state = self.scroll_area_state(id).unwrap_or_default();
+        let now = self.input(|i| i.time);
+        state.scroll_to_offset(offset, animation, now);
+        state.store(self, id);
+        self.request_repaint_after_because(Duration::ZERO, RepaintReason::Animation);
+    }
+}
+
 impl Context {
     /// Show a ui for settings (style and tessellation options).
     pub fn settings_ui(&self, ui: &mut Ui) {
@@ -3172,6 +4444,13 @@ impl Context {
                 paint_stats.ui(ui);
             });
 
+        #[cfg(feature = "memory_stats")]
+        CollapsingHeader::new("💾 Memory usage")
+            .default_open(false)
+            .show(ui, |ui| {
+                self.memory_stats().ui(ui);
+            });
+
         CollapsingHeader::new("🖼 Textures")
             .default_open(false)
             .show(ui, |ui| {
@@ -3425,6 +4704,20 @@ impl Context {
             }
         });
 
+        #[cfg(feature = "id_names")]
+        ui.collapsing("Id names", |ui| {
+            let mut names = crate::id::all_debug_names();
+            names.sort_by(|a, b| a.1.cmp(&b.1));
+            ui.label(format!("{} named ids", names.len()));
+            crate::ScrollArea::vertical()
+                .max_height(200.0)
+                .show(ui, |ui| {
+                    for (id, name) in names {
+                        ui.monospace(format!("{:016X} {name}", id.value()));
+                    }
+                });
+        });
+
         ui.shrink_width_to_current(); // don't let the text below grow this window wider
         ui.label("NOTE: the position of this window cannot be reset from within itself.");
 
@@ -3748,44 +5041,348 @@ impl Context {
     }
 }
 
-/// ## Viewports
-impl Context {
-    /// Return the `ViewportId` of the current viewport.
-    ///
-    /// If this is the root viewport, this will return [`ViewportId::ROOT`].
-    ///
-    /// Don't use this outside of `Self::run`, or after `Self::end_pass`.
-    pub fn viewport_id(&self) -> ViewportId {
-        self.read(|ctx| ctx.viewport_id())
+/// How much memory [`Context::trim_memory`] should free.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MemoryPressure {
+    /// Free what is cheap to recreate: caches and spare buffer capacity.
+    Moderate,
+
+    /// Also forget all font sizes and loaded images, which then need to be rasterized and loaded again.
+    Critical,
+}
+
+/// Approximate heap usage of egui's subsystems, see [`Context::memory_stats`].
+///
+/// All numbers are in bytes, and are estimates: they count the big buffers,
+/// but not every small allocation.
+#[cfg(feature = "memory_stats")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MemoryStats {
+    /// The shapes, clipped primitives and meshes of the last pass, excluding text.
+    pub shapes: usize,
+
+    /// The laid out text in the galley cache.
+    pub galleys: usize,
+
+    /// [`crate::Memory::data`].
+    pub memory_data: usize,
+
+    /// The textures allocated by egui, including the font atlas.
+    pub textures: usize,
+
+    /// The bytes and images held by the image loaders.
+    pub loaders: usize,
+}
+
+#[cfg(feature = "memory_stats")]
+impl MemoryStats {
+    /// The sum of all subsystems.
+    pub fn total(&self) -> usize {
+        let Self {
+            shapes,
+            galleys,
+            memory_data,
+            textures,
+            loaders,
+        } = *self;
+        shapes + galleys + memory_data + textures + loaders
     }
 
-    /// Return the `ViewportId` of his parent.
+    /// Show the stats in a table.
+    pub fn ui(&self, ui: &mut Ui) {
+        let Self {
+            shapes,
+            galleys,
+            memory_data,
+            textures,
+            loaders,
+        } = *self;
+
+        fn megabytes(bytes: usize) -> String {
+            format!("{:.2} MB", bytes as f64 * 1e-6)
+        }
+
+        crate::Grid::new("memory_stats")
+            .num_columns(2)
+            .striped(true)
+            .show(ui, |ui| {
+                for (name, bytes) in [
+                    ("Shapes", shapes),
+                    ("Galleys", galleys),
+                    ("Memory data", memory_data),
+                    ("Textures", textures),
+                    ("Image loaders", loaders),
+                ] {
+                    ui.label(name);
+                    ui.monospace(megabytes(bytes));
+                    ui.end_row();
+                }
+
+                ui.strong("Total");
+                ui.monospace(megabytes(self.total()));
+                ui.end_row();
+            });
+    }
+}
+
+/// ## Memory pressure
+impl Context {
+    /// Free memory, e.g. in response to a low-memory warning from the OS.
     ///
-    /// If this is the root viewport, this will return [`ViewportId::ROOT`].
+    /// * Clears [`crate::Memory::caches`]
+    /// * Shrinks the buffers of the painted shapes
+    /// * With [`MemoryPressure::Critical`], drops all font sizes and their glyphs, see [`epaint::text::Fonts::clear_font_sizes`].
+    ///   This also re-runs [`Ui::cached`] contents and marks [`Ui::static_region`]s as changed in the next pass.
+    /// * With [`MemoryPressure::Critical`], calls [`Self::forget_all_images`]
     ///
-    /// Don't use this outside of `Self::run`, or after `Self::end_pass`.
-    pub fn parent_viewport_id(&self) -> ViewportId {
-        self.read(|ctx| ctx.parent_viewport_id())
-    }
+    /// Returns an estimate of the number of bytes freed.
+    /// This does not include the memory of [`crate::Memory::caches`], which is unknown.
+    pub fn trim_memory(&self, pressure: MemoryPressure) -> usize {
+        profiling::function_scope!();
 
-    /// Read the state of the current viewport.
-    pub fn viewport<R>(&self, reader: impl FnOnce(&ViewportState) -> R) -> R {
-        self.write(|ctx| reader(ctx.viewport()))
-    }
+        let mut bytes_freed = self.write(|ctx| {
+            ctx.memory.caches.clear();
 
-    /// Read the state of a specific current viewport.
-    pub fn viewport_for<R>(
-        &self,
-        viewport_id: ViewportId,
-        reader: impl FnOnce(&ViewportState) -> R,
-    ) -> R {
-        self.write(|ctx| reader(ctx.viewport_for(viewport_id)))
+            let mut bytes_freed = 0;
+            for viewport in ctx.viewports.values_mut() {
+                bytes_freed += viewport.graphics.shrink_to_fit();
+            }
+
+            if pressure == MemoryPressure::Critical {
+                for fonts in ctx.fonts.values() {
+                    bytes_freed += fonts.clear_font_sizes();
+                }
+            }
+
+            bytes_freed
+        });
+
+        if pressure == MemoryPressure::Critical {
+            let loaders_byte_size = self.loaders_byte_size();
+            self.forget_all_images();
+            bytes_freed += loaders_byte_size.saturating_sub(self.loaders_byte_size());
+        }
+
+        bytes_freed
     }
 
-    /// For integrations: Set this to render a sync viewport.
+    /// Estimate how much heap memory egui's subsystems are using.
     ///
-    /// This will only set the callback for the current thread,
-    /// which most likely should be the main thread.
+    /// The shape numbers are from the last finished pass.
+    /// Text is counted under [`MemoryStats::galleys`].
+    #[cfg(feature = "memory_stats")]
+    pub fn memory_stats(&self) -> MemoryStats {
+        profiling::function_scope!();
+
+        let (shapes, galleys, memory_data) = self.read(|ctx| {
+            let stats = &ctx.paint_stats;
+            let shapes = [
+                stats.shapes,
+                stats.shape_path,
+                stats.shape_mesh,
+                stats.shape_vec,
+                stats.clipped_primitives,
+                stats.vertices,
+                stats.indices,
+            ]
+            .iter()
+            .map(|info| info.num_bytes())
+            .sum();
+            let galleys = ctx
+                .fonts
+                .values()
+                .map(|fonts| fonts.galley_cache_byte_size())
+                .sum();
+            (shapes, galleys, ctx.memory.data.approximate_byte_size())
+        });
+
+        let textures = self
+            .tex_manager()
+            .read()
+            .allocated()
+            .map(|(_, tex)| tex.bytes_used())
+            .sum();
+
+        MemoryStats {
+            shapes,
+            galleys,
+            memory_data,
+            textures,
+            loaders: self.loaders_byte_size(),
+        }
+    }
+
+    /// How many bytes all image loaders use, see [`load::BytesLoader::byte_size`].
+    fn loaders_byte_size(&self) -> usize {
+        use load::BytesLoader as _;
+
+        let loaders = self.loaders();
+        loaders.include.byte_size()
+            + loaders
+                .bytes
+                .lock()
+                .iter()
+                .map(|l| l.byte_size())
+                .sum::<usize>()
+            + loaders
+                .image
+                .lock()
+                .iter()
+                .map(|l| l.byte_size())
+                .sum::<usize>()
+            + loaders
+                .texture
+                .lock()
+                .iter()
+                .map(|l| l.byte_size())
+                .sum::<usize>()
+    }
+}
+
+/// ## Warnings
+impl Context {
+    /// Take all the warnings egui has found since the last call,
+    /// e.g. to show them in the error console of your app.
+    ///
+    /// The same warning is only reported once until it is taken.
+    /// See also [`Options::show_warnings_badge`].
+    pub fn take_warnings(&self) -> Vec<Warning> {
+        self.write(|ctx| ctx.warnings.take())
+    }
+
+    /// Report that egui was used the wrong way, if [`Options::strict_mode`] is on.
+    ///
+    /// In debug builds this panics with the given message,
+    /// in release builds it is reported as a [`WarningKind::Misuse`].
+    /// If strict mode is off, this does nothing.
+    ///
+    /// Integrations and libraries can use this to report misuse of their own APIs.
+    #[track_caller]
+    pub fn report_misuse(&self, message: impl Into<String>) {
+        if !self.options(|o| o.strict_mode) {
+            return;
+        }
+        let message = message.into();
+        // Panicking while already panicking (e.g. in a destructor) would abort:
+        assert!(
+            !cfg!(debug_assertions) || std::thread::panicking(),
+            "egui strict mode: {message}"
+        );
+        self.report_warning(Warning::new(WarningKind::Misuse, message));
+    }
+
+    /// Add a warning to the ones returned by [`Self::take_warnings`].
+    ///
+    /// With the `log` feature, new warnings are also logged.
+    pub fn report_warning(&self, warning: Warning) {
+        self.write(|ctx| ctx.warnings.report(warning));
+    }
+
+    /// Look for problems that are only known at the end of the pass.
+    fn report_end_of_pass_warnings(&self) {
+        let never_created: Vec<Id> = self.pass_state(|state| {
+            state
+                .unknown_response_ids
+                .iter()
+                .copied()
+                .filter(|&id| !state.widgets.contains(id))
+                .collect()
+        });
+        for id in never_created {
+            self.report_misuse(format!(
+                "Context::read_response was called with the id {}, but no widget with that id was created in this or the previous pass",
+                id.short_debug_format()
+            ));
+        }
+
+        let missing_chars: std::collections::BTreeSet<char> = self.read(|ctx| {
+            ctx.fonts
+                .values()
+                .flat_map(|fonts| fonts.take_missing_chars())
+                .collect()
+        });
+        if !missing_chars.is_empty() {
+            let chars: String = missing_chars.into_iter().collect();
+            self.report_warning(Warning::new(
+                WarningKind::MissingGlyphs,
+                format!("No font has the characters {chars:?}"),
+            ));
+        }
+
+        // Report once when it starts, and not every frame after that:
+        if self.viewport(|vp| vp.num_multipass_in_row) == 3 {
+            let mut message = "request_discard has been called 3 frames in a row".to_owned();
+            self.viewport(|vp| {
+                for reason in &vp.output.request_discard_reasons {
+                    message += &format!("\n  {reason}");
+                }
+            });
+            self.report_warning(Warning::new(WarningKind::RepeatedDiscard, message));
+        }
+
+        if self.options(|o| o.show_warnings_badge) {
+            let (num_warnings, worst_severity) =
+                self.read(|ctx| (ctx.warnings.len(), ctx.warnings.worst_severity()));
+            if let Some(worst_severity) = worst_severity {
+                let visuals = self.style().visuals.clone();
+                let color = match worst_severity {
+                    WarningSeverity::Warning => visuals.warn_fg_color,
+                    WarningSeverity::Error => visuals.error_fg_color,
+                };
+                let text = if num_warnings == 1 {
+                    "⚠ 1 egui warning".to_owned()
+                } else {
+                    format!("⚠ {num_warnings} egui warnings")
+                };
+                self.debug_painter().debug_text(
+                    self.screen_rect().right_top(),
+                    Align2::RIGHT_TOP,
+                    color,
+                    text,
+                );
+            }
+        }
+    }
+}
+
+/// ## Viewports
+impl Context {
+    /// Return the `ViewportId` of the current viewport.
+    ///
+    /// If this is the root viewport, this will return [`ViewportId::ROOT`].
+    ///
+    /// Don't use this outside of `Self::run`, or after `Self::end_pass`.
+    pub fn viewport_id(&self) -> ViewportId {
+        self.read(|ctx| ctx.viewport_id())
+    }
+
+    /// Return the `ViewportId` of his parent.
+    ///
+    /// If this is the root viewport, this will return [`ViewportId::ROOT`].
+    ///
+    /// Don't use this outside of `Self::run`, or after `Self::end_pass`.
+    pub fn parent_viewport_id(&self) -> ViewportId {
+        self.read(|ctx| ctx.parent_viewport_id())
+    }
+
+    /// Read the state of the current viewport.
+    pub fn viewport<R>(&self, reader: impl FnOnce(&ViewportState) -> R) -> R {
+        self.write(|ctx| reader(ctx.viewport()))
+    }
+
+    /// Read the state of a specific current viewport.
+    pub fn viewport_for<R>(
+        &self,
+        viewport_id: ViewportId,
+        reader: impl FnOnce(&ViewportState) -> R,
+    ) -> R {
+        self.write(|ctx| reader(ctx.viewport_for(viewport_id)))
+    }
+
+    /// For integrations: Set this to render a sync viewport.
+    ///
+    /// This will only set the callback for the current thread,
+    /// which most likely should be the main thread.
     ///
     /// When an immediate viewport is created with [`Self::show_viewport_immediate`] it will be rendered by this function.
     ///
@@ -4045,6 +5642,74 @@ impl Context {
         });
     }
 
+    /// The widget that has captured the pointer, if any.
+    ///
+    /// See [`crate::Response::request_pointer_capture`].
+    pub fn pointer_capture_id(&self) -> Option<Id> {
+        self.memory(|mem| mem.interaction().pointer_capture)
+    }
+
+    /// Let the given widget capture the pointer, or release the capture with `None`.
+    ///
+    /// While captured, the widget receives all pointer input (hover, clicks, drags),
+    /// even if the pointer leaves its rectangle or the viewport.
+    ///
+    /// This also sends [`ViewportCommand::PointerCapture`] to the integration.
+    ///
+    /// See [`crate::Response::request_pointer_capture`].
+    pub fn set_pointer_capture(&self, id: Option<Id>) {
+        let previous = self
+            .memory_mut(|mem| std::mem::replace(&mut mem.interaction_mut().pointer_capture, id));
+        if previous.is_some() != id.is_some() {
+            self.send_viewport_cmd(ViewportCommand::PointerCapture(id.is_some()));
+        }
+    }
+
+    /// The widget that has locked the pointer, if any.
+    ///
+    /// See [`crate::Response::request_pointer_lock`].
+    pub fn pointer_lock_id(&self) -> Option<Id> {
+        self.memory(|mem| mem.interaction().pointer_lock.map(|(id, _)| id))
+    }
+
+    /// Let the given widget lock the pointer, or release the lock with `None`.
+    ///
+    /// While locked, the cursor is hidden and held in place using [`ViewportCommand::CursorGrab`],
+    /// so that the widget can be dragged forever.
+    /// Read the movement with [`crate::Response::drag_motion`] or [`crate::PointerState::delta`].
+    ///
+    /// If the integration can not lock the cursor, egui will instead move it back to the
+    /// center of the viewport whenever it gets close to the edge.
+    /// When the lock is released, the cursor is moved back to where it was when the lock started.
+    ///
+    /// A locked pointer is also captured, see [`Self::set_pointer_capture`].
+    pub fn set_pointer_lock(&self, id: Option<Id>) {
+        self.write(|ctx| {
+            let previous = ctx.memory.interaction().pointer_lock;
+            match (previous, id) {
+                (None, Some(id)) => {
+                    let lock_origin = ctx.viewport().input.pointer.latest_pos();
+                    ctx.memory.interaction_mut().pointer_lock = Some((id, lock_origin));
+                    let commands = &mut ctx.viewport().commands;
+                    commands.push(ViewportCommand::CursorGrab(
+                        crate::viewport::CursorGrab::Locked,
+                    ));
+                    commands.push(ViewportCommand::CursorVisible(false));
+                }
+                (Some((_, lock_origin)), Some(id)) => {
+                    ctx.memory.interaction_mut().pointer_lock = Some((id, lock_origin));
+                }
+                (Some((_, lock_origin)), None) => {
+                    ctx.memory.interaction_mut().pointer_lock = None;
+                    ctx.viewport().unlock_pointer(lock_origin);
+                }
+                (None, None) => {}
+            }
+        });
+        self.set_pointer_capture(id);
+        self.request_repaint();
+    }
+
     /// Is something else being dragged?
     ///
     /// Returns true if we are dragging something, but not the given widget.
@@ -4066,136 +5731,1682 @@ mod test {
     use super::Context;
 
     #[test]
-    fn test_single_pass() {
-        let ctx = Context::default();
-        ctx.options_mut(|o| o.max_passes = 1.try_into().unwrap());
+    fn test_justify_content() {
+        use crate::{Align, JustifyContent, Layout};
 
-        // A single call, no request to discard:
-        {
-            let mut num_calls = 0;
-            let output = ctx.run(Default::default(), |ctx| {
-                num_calls += 1;
-                assert_eq!(ctx.output(|o| o.num_completed_passes), 0);
-                assert!(!ctx.output(|o| o.requested_discard()));
-                assert!(!ctx.will_discard());
+        let ctx = Context::default();
+        let mut rects = None;
+        let _ = ctx.run(Default::default(), |ctx| {
+            crate::CentralPanel::default().show(ctx, |ui| {
+                let layout = Layout::left_to_right(Align::Center)
+                    .with_justify_content(JustifyContent::SpaceBetween);
+                ui.with_layout(layout, |ui| {
+                    let max_rect = ui.max_rect();
+                    let left = ui.label("Left").rect;
+                    let middle = ui.label("Middle").rect;
+                    let right = ui.label("Right").rect;
+                    rects = Some((max_rect, left, middle, right));
+                });
             });
-            assert_eq!(num_calls, 1);
-            assert_eq!(output.platform_output.num_completed_passes, 1);
-            assert!(!output.platform_output.requested_discard());
-        }
+        });
+        let (max_rect, left, middle, right) = rects.unwrap();
+        assert_eq!(left.left(), max_rect.left());
+        assert!(
+            (right.right() - max_rect.right()).abs() < 1.0,
+            "{right:?} {max_rect:?}"
+        );
+        let (gap_before, gap_after) = (middle.left() - left.right(), right.left() - middle.right());
+        assert!(
+            (gap_before - gap_after).abs() < 1.0,
+            "{gap_before} {gap_after}"
+        );
+    }
 
-        // A single call, with a denied request to discard:
-        {
-            let mut num_calls = 0;
-            let output = ctx.run(Default::default(), |ctx| {
-                num_calls += 1;
-                ctx.request_discard("test");
-                assert!(!ctx.will_discard(), "The request should have been denied");
+    #[test]
+    fn test_add_sized_range() {
+        let ctx = Context::default();
+        let mut rects = None;
+        let _ = ctx.run(Default::default(), |ctx| {
+            crate::CentralPanel::default().show(ctx, |ui| {
+                let short = ui
+                    .add_sized_range(
+                        [80.0, 0.0],
+                        [120.0, f32::INFINITY],
+                        crate::Button::new("OK"),
+                    )
+                    .rect;
+                let long = ui
+                    .add_sized_range(
+                        [80.0, 0.0],
+                        [120.0, f32::INFINITY],
+                        crate::Button::new("A button with a lot of text that has to wrap"),
+                    )
+                    .rect;
+                rects = Some((short, long));
             });
-            assert_eq!(num_calls, 1);
-            assert_eq!(output.platform_output.num_completed_passes, 1);
-            assert!(
-                output.platform_output.requested_discard(),
-                "The request should be reported"
-            );
-            assert_eq!(
-                output
-                    .platform_output
-                    .request_discard_reasons
-                    .first()
-                    .unwrap()
-                    .reason,
-                "test"
-            );
-        }
+        });
+        let (short, long) = rects.unwrap();
+        assert_eq!(short.width(), 80.0, "Grown to the minimum width");
+        assert!(long.width() <= 120.0, "{long:?}");
+        assert!(long.height() > short.height(), "The long text wraps");
     }
 
     #[test]
-    fn test_dual_pass() {
+    fn test_ui_builder_transform() {
+        use crate::{Event, RawInput, UiBuilder, emath::TSTransform};
+
         let ctx = Context::default();
-        ctx.options_mut(|o| o.max_passes = 2.try_into().unwrap());
+        let transform = TSTransform::new(crate::vec2(100.0, 50.0), 2.0);
+        let run = |events: Vec<Event>| {
+            let mut result = None;
+            let _ = ctx.run(
+                RawInput {
+                    events,
+                    ..Default::default()
+                },
+                |ctx| {
+                    crate::CentralPanel::default().show(ctx, |ui| {
+                        let parent_layer_id = ui.layer_id();
+                        ui.scope_builder(UiBuilder::new().transform(transform), |ui| {
+                            let response = ui.button("Scaled");
+                            result = Some((
+                                parent_layer_id,
+                                ui.layer_id(),
+                                ui.ctx().layer_transform_to_global(ui.layer_id()),
+                                response,
+                            ));
+                        });
+                    });
+                },
+            );
+            result.unwrap()
+        };
 
-        // Normal single pass:
-        {
-            let mut num_calls = 0;
-            let output = ctx.run(Default::default(), |ctx| {
-                assert_eq!(ctx.output(|o| o.num_completed_passes), 0);
-                assert!(!ctx.output(|o| o.requested_discard()));
-                assert!(!ctx.will_discard());
-                num_calls += 1;
-            });
-            assert_eq!(num_calls, 1);
-            assert_eq!(output.platform_output.num_completed_passes, 1);
-            assert!(!output.platform_output.requested_discard());
-        }
+        let (parent_layer_id, layer_id, to_global, response) = run(vec![]);
+        assert_ne!(parent_layer_id, layer_id);
+        assert_eq!(to_global, Some(transform));
 
-        // Request discard once:
-        {
-            let mut num_calls = 0;
-            let output = ctx.run(Default::default(), |ctx| {
-                assert_eq!(ctx.output(|o| o.num_completed_passes), num_calls);
+        // The pointer is over the button where it is painted, not where it was laid out:
+        let pos = transform * response.rect.right_bottom() - crate::vec2(2.0, 2.0);
+        assert!(!response.rect.contains(pos));
+        let (_, _, _, response) = run(vec![Event::PointerMoved(pos)]);
+        assert!(response.hovered());
+    }
 
-                assert!(!ctx.will_discard());
-                if num_calls == 0 {
-                    ctx.request_discard("test");
-                    assert!(ctx.will_discard());
-                }
+    #[test]
+    fn test_ui_cached() {
+        use crate::{Event, RawInput, Rect};
 
-                num_calls += 1;
-            });
-            assert_eq!(num_calls, 2);
-            assert_eq!(output.platform_output.num_completed_passes, 2);
-            assert!(
-                !output.platform_output.requested_discard(),
-                "The request should have been cleared when fulfilled"
+        let ctx = Context::default();
+        let num_runs = std::cell::Cell::new(0);
+        let run = |cache_key: &str, events: Vec<Event>| {
+            let mut rect = Rect::NOTHING;
+            let output = ctx.run(
+                RawInput {
+                    events,
+                    ..Default::default()
+                },
+                |ctx| {
+                    crate::CentralPanel::default().show(ctx, |ui| {
+                        rect = ui
+                            .cached("cached", cache_key, |ui| {
+                                num_runs.set(num_runs.get() + 1);
+                                ui.label(cache_key);
+                                let _ = ui.button("Button");
+                            })
+                            .rect;
+                    });
+                },
             );
-        }
+            (rect, output.shapes.len())
+        };
 
-        // Request discard twice:
-        {
-            let mut num_calls = 0;
-            let output = ctx.run(Default::default(), |ctx| {
-                assert_eq!(ctx.output(|o| o.num_completed_passes), num_calls);
+        let (rect, num_shapes) = run("a", vec![]);
+        assert_eq!(run("a", vec![]), (rect, num_shapes), "Replayed");
+        assert_eq!(num_runs.get(), 1);
 
-                assert!(!ctx.will_discard());
-                ctx.request_discard("test");
-                if num_calls == 0 {
-                    assert!(ctx.will_discard(), "First request granted");
-                } else {
-                    assert!(!ctx.will_discard(), "Second request should be denied");
-                }
+        run("b", vec![]);
+        assert_eq!(num_runs.get(), 2, "A new cache key re-runs the contents");
 
-                num_calls += 1;
-            });
-            assert_eq!(num_calls, 2);
-            assert_eq!(output.platform_output.num_completed_passes, 2);
-            assert!(
-                output.platform_output.requested_discard(),
-                "The unfulfilled request should be reported"
-            );
+        run("b", vec![Event::PointerMoved(rect.center())]);
+        run("b", vec![]);
+        assert_eq!(num_runs.get(), 4, "Hovering re-runs the contents");
+
+        #[cfg(feature = "accesskit")]
+        {
+            ctx.enable_accesskit();
+            run("b", vec![]);
+            run("b", vec![]);
+            assert_eq!(num_runs.get(), 6, "AccessKit nodes can't be replayed");
         }
     }
 
     #[test]
-    fn test_multi_pass() {
+    fn test_static_region() {
+        use crate::{Event, RawInput};
+
         let ctx = Context::default();
-        ctx.options_mut(|o| o.max_passes = 10.try_into().unwrap());
+        let run = |time: f64, events: Vec<Event>| {
+            let mut response = None;
+            let output = ctx.run(
+                RawInput {
+                    time: Some(time),
+                    events,
+                    ..Default::default()
+                },
+                |ctx| {
+                    crate::CentralPanel::default().show(ctx, |ui| {
+                        response = Some(
+                            ui.static_region("static", |ui| {
+                                crate::Frame::new()
+                                    .fill(crate::Color32::WHITE)
+                                    .show(ui, |ui| ui.label("Static"));
+                            })
+                            .response,
+                        );
+                        ui.static_region("see_through", |ui| {
+                            ui.label("Static, but we can't tell what is beneath it");
+                        });
+                    });
+                },
+            );
+            (output.unchanged_rects, response.unwrap())
+        };
 
-        // Request discard three times:
-        {
-            let mut num_calls = 0;
-            let output = ctx.run(Default::default(), |ctx| {
-                assert_eq!(ctx.output(|o| o.num_completed_passes), num_calls);
+        assert!(run(0.0, vec![]).0.is_empty(), "New regions have changed");
+        let (unchanged_rects, response) = run(1.0, vec![]);
+        assert_eq!(unchanged_rects, vec![response.rect]);
 
-                assert!(!ctx.will_discard());
-                if num_calls <= 2 {
-                    ctx.request_discard("test");
-                    assert!(ctx.will_discard());
-                }
+        ctx.invalidate_static_region(response.id);
+        assert!(run(2.0, vec![]).0.is_empty());
+        assert_eq!(run(3.0, vec![]).0.len(), 1);
 
-                num_calls += 1;
+        let hover = vec![Event::PointerMoved(response.rect.center())];
+        assert!(run(4.0, hover).0.is_empty(), "Hovered regions change");
+    }
+
+    #[test]
+    fn test_ui_events() {
+        use crate::{WidgetInfo, output::OutputEvent};
+
+        let ctx = Context::default();
+        ctx.options_mut(|o| o.ui_events = true);
+        let run = |open: bool| {
+            let output = ctx.run(Default::default(), |ctx| {
+                let mut open = open;
+                crate::Window::new("Settings")
+                    .open(&mut open)
+                    .show(ctx, |_ui| {});
             });
-            assert_eq!(num_calls, 4);
+            output.platform_output.events
+        };
+
+        let events = run(true);
+        assert!(
+            matches!(&events[..], [OutputEvent::WindowOpened(info)] if info.label.as_deref() == Some("Settings"))
+        );
+        assert!(run(true).is_empty());
+        assert!(matches!(&run(false)[..], [OutputEvent::WindowClosed(_)]));
+
+        let info = WidgetInfo::slider(true, 2.0, "").with_prev_value(1.0);
+        assert_eq!(info.prev_value, Some(1.0));
+        let info = WidgetInfo::slider(true, 2.0, "").with_prev_value(2.0);
+        assert_eq!(info.prev_value, None, "Only set if changed");
+    }
+
+    #[test]
+    fn test_macro_recording() {
+        use crate::{Event, Id, MacroAction, Modifiers, PointerButton, Pos2, RawInput, Rect};
+
+        struct App {
+            top_space: f32,
+            clicks: usize,
+            value: f64,
+        }
+
+        /// Returns the button and slider.
+        fn run(ctx: &Context, app: &mut App, events: Vec<Event>) -> [(Id, Rect); 2] {
+            let mut widgets = None;
+            let _ = ctx.run(
+                RawInput {
+                    events,
+                    ..Default::default()
+                },
+                |ctx| {
+                    crate::CentralPanel::default().show(ctx, |ui| {
+                        ui.add_space(app.top_space);
+                        let button = ui.button("Click me");
+                        if button.clicked() {
+                            app.clicks += 1;
+                        }
+                        let slider = ui.add(crate::Slider::new(&mut app.value, 0.0..=100.0));
+                        widgets = Some([(button.id, button.rect), (slider.id, slider.rect)]);
+                    });
+                },
+            );
+            widgets.unwrap()
+        }
+
+        fn button(pos: Pos2, pressed: bool) -> Event {
+            Event::PointerButton {
+                pos,
+                button: PointerButton::Primary,
+                pressed,
+                modifiers: Modifiers::default(),
+            }
+        }
+
+        let ctx = Context::default();
+        let mut app = App {
+            top_space: 0.0,
+            clicks: 0,
+            value: 0.0,
+        };
+        let [(button_id, button_rect), (slider_id, slider_rect)] = run(&ctx, &mut app, vec![]);
+        ctx.start_macro_recording();
+        let pos = button_rect.center();
+        run(
+            &ctx,
+            &mut app,
+            vec![
+                Event::PointerMoved(pos),
+                button(pos, true),
+                button(pos, false),
+            ],
+        );
+        // The slider only reacts to presses that last a pass:
+        let pos = slider_rect.lerp_inside([0.3, 0.5].into());
+        run(
+            &ctx,
+            &mut app,
+            vec![Event::PointerMoved(pos), button(pos, true)],
+        );
+        run(&ctx, &mut app, vec![button(pos, false)]);
+        let actions = ctx.stop_macro_recording();
+        assert_eq!(app.clicks, 1);
+        assert!(0.0 < app.value);
+        assert_eq!(
+            actions,
+            vec![
+                MacroAction::Click(button_id),
+                MacroAction::SetValue(slider_id, app.value)
+            ]
+        );
+
+        // Replay with a different layout:
+        let ctx = Context::default();
+        let mut replay_app = App {
+            top_space: 50.0,
+            clicks: 0,
+            value: 0.0,
+        };
+        run(&ctx, &mut replay_app, vec![]);
+        ctx.play_macro(actions);
+        for _ in 0..3 {
+            run(&ctx, &mut replay_app, vec![]);
+        }
+        assert!(!ctx.is_playing_macro());
+        assert_eq!(replay_app.clicks, 1);
+        assert_eq!(replay_app.value, app.value);
+    }
+
+    #[cfg(feature = "persistence")]
+    #[test]
+    fn test_session_state() {
+        use crate::{Id, ScrollArea, containers::scroll_area::State, memory::SessionState};
+
+        fn run(ctx: &Context) -> Id {
+            let mut scroll_id = None;
+            let _ = ctx.run(Default::default(), |ctx| {
+                crate::CentralPanel::default().show(ctx, |ui| {
+                    let output = ScrollArea::vertical().max_height(100.0).show(ui, |ui| {
+                        for i in 0..100 {
+                            ui.label(format!("Row {i}"));
+                        }
+                    });
+                    scroll_id = Some(output.id);
+                });
+            });
+            scroll_id.unwrap()
+        }
+
+        let ctx = Context::default();
+        let scroll_id = run(&ctx);
+        let mut state = State::load(&ctx, scroll_id).unwrap();
+        state.offset.y = 200.0;
+        state.store(&ctx, scroll_id);
+        let focused = Id::new("focused");
+        let popup = Id::new("popup");
+        let tab = Id::new("tab");
+        let hover = Id::new("hover");
+        ctx.memory_mut(|mem| {
+            mem.include_in_session::<usize>(tab);
+            mem.request_focus(focused);
+            #[expect(deprecated)]
+            mem.open_popup(popup);
+            mem.data.insert_temp(tab, 3_usize);
+            mem.data.insert_temp(hover, 4_usize);
+        });
+        let session = ron::to_string(&ctx.save_session()).unwrap();
+
+        let ctx = Context::default();
+        run(&ctx);
+        ctx.memory_mut(|mem| mem.include_in_session::<usize>(tab));
+        ctx.restore_session(ron::from_str::<SessionState>(&session).unwrap());
+        assert_eq!(ctx.memory(|mem| mem.focused()), Some(focused));
+        #[expect(deprecated)]
+        let popup_open = ctx.memory(|mem| mem.is_popup_open(popup));
+        assert!(popup_open);
+        assert_eq!(ctx.data(|data| data.get_temp::<usize>(tab)), Some(3));
+        assert_eq!(
+            ctx.data(|data| data.get_temp::<usize>(hover)),
+            None,
+            "Only registered temporary values are saved"
+        );
+        run(&ctx);
+        assert_eq!(State::load(&ctx, scroll_id).unwrap().offset.y, 200.0);
+    }
+
+    #[cfg(feature = "frame_budget")]
+    #[test]
+    fn test_defer_if_over_budget() {
+        fn run(ctx: &Context) -> Option<()> {
+            let mut shown = None;
+            let _ = ctx.run(Default::default(), |ctx| {
+                crate::CentralPanel::default().show(ctx, |ui| {
+                    shown = ui
+                        .defer_if_over_budget("expensive", |ui| {
+                            ui.label("Expensive");
+                        })
+                        .inner;
+                });
+            });
+            shown
+        }
+
+        let ctx = Context::default();
+        assert!(!ctx.frame_budget_exceeded());
+        assert!(run(&ctx).is_some());
+
+        ctx.options_mut(|o| o.frame_budget = Some(0.0));
+        assert!(run(&ctx).is_none());
+        assert!(ctx.has_requested_repaint());
+        assert!(run(&ctx).is_some(), "Never deferred twice in a row");
+        assert!(run(&ctx).is_none());
+    }
+
+    #[test]
+    fn test_text_events_and_key_repeat() {
+        use crate::{Event, Key, Modifiers, RawInput};
+
+        let ctx = Context::default();
+        let run = |time: f64, events: Vec<Event>| {
+            let mut input = None;
+            let _ = ctx.run(
+                RawInput {
+                    time: Some(time),
+                    events,
+                    ..Default::default()
+                },
+                |ctx| input = Some(ctx.input(|i| i.clone())),
+            );
+            input.unwrap()
+        };
+        let key = |key, pressed, repeat| Event::Key {
+            key,
+            physical_key: None,
+            pressed,
+            repeat,
+            modifiers: Modifiers::default(),
+        };
+        let text = |text: &str| Event::Text(text.to_owned());
+
+        let input = run(
+            1.0,
+            vec![
+                key(Key::A, true, false),
+                text("a"),
+                key(Key::A, true, false),
+                text("a"),
+            ],
+        );
+        assert_eq!(
+            input.events,
+            [
+                key(Key::A, true, false),
+                text("aa"),
+                key(Key::A, true, true)
+            ]
+        );
+        let repeat = input.key_repeat(Key::A).unwrap();
+        assert_eq!((repeat.count, repeat.first_press_time), (1, 1.0));
+
+        let input = run(
+            2.0,
+            vec![
+                key(Key::A, true, false),
+                text("a"),
+                key(Key::Backspace, true, false),
+                text("b"),
+                text("\n"),
+                text("c"),
+                key(Key::A, false, false),
+            ],
+        );
+        assert_eq!(
+            input.events,
+            [
+                key(Key::A, true, true),
+                text("a"),
+                key(Key::Backspace, true, false),
+                text("b"),
+                text("\n"),
+                text("c"),
+                key(Key::A, false, false),
+            ]
+        );
+        assert_eq!(input.key_repeat(Key::A), None);
+        assert_eq!(input.key_repeat(Key::Backspace).unwrap().count, 0);
+    }
+
+    #[test]
+    fn test_window_resize_from_outside() {
+        use crate::{Event, Modifiers, PointerButton, Pos2, RawInput, Rect};
+
+        fn run(ctx: &Context, events: Vec<Event>) -> Rect {
+            let mut rect = None;
+            let _ = ctx.run(
+                RawInput {
+                    events,
+                    ..Default::default()
+                },
+                |ctx| {
+                    let response = crate::Window::new("Window")
+                        .default_pos([100.0, 100.0])
+                        .default_size([200.0, 100.0])
+                        .show(ctx, |ui| ui.allocate_space(ui.available_size()));
+                    rect = Some(response.unwrap().response.rect);
+                },
+            );
+            rect.unwrap()
+        }
+        fn button(pos: Pos2, pressed: bool) -> Event {
+            Event::PointerButton {
+                pos,
+                button: PointerButton::Primary,
+                pressed,
+                modifiers: Modifiers::default(),
+            }
+        }
+
+        let ctx = Context::default();
+        ctx.style_mut(|style| style.interaction.interact_radius = 0.0);
+        run(&ctx, vec![]);
+        let rect = run(&ctx, vec![]);
+
+        // Just outside of `resize_grab_radius_side`, but within `resize_grab_outside`:
+        let pos = rect.left_center() - crate::vec2(7.0, 0.0);
+        run(&ctx, vec![Event::PointerMoved(pos), button(pos, true)]);
+        let pos = pos - crate::vec2(20.0, 0.0);
+        run(&ctx, vec![Event::PointerMoved(pos)]);
+        run(&ctx, vec![button(pos, false)]);
+        let new_rect = run(&ctx, vec![]);
+        assert!((new_rect.right() - rect.right()).abs() < 1.0);
+        assert!(new_rect.left() < rect.left() - 20.0);
+    }
+
+    #[test]
+    fn test_window_maximize_on_double_click() {
+        use crate::{Event, Modifiers, PointerButton, RawInput, Rect};
+
+        fn run(ctx: &Context, events: Vec<Event>) -> Rect {
+            let mut rect = None;
+            let _ = ctx.run(
+                RawInput {
+                    screen_rect: Some(Rect::from_min_size(
+                        Default::default(),
+                        [800.0, 600.0].into(),
+                    )),
+                    // Keep the double-clicks apart:
+                    time: Some(ctx.input(|i| i.time) + 1.0),
+                    events,
+                    ..Default::default()
+                },
+                |ctx| {
+                    let response = crate::Window::new("Window")
+                        .maximizable(true)
+                        .default_pos([100.0, 100.0])
+                        .default_size([200.0, 100.0])
+                        .show(ctx, |ui| ui.allocate_space(ui.available_size()));
+                    rect = Some(response.unwrap().response.rect);
+                },
+            );
+            rect.unwrap()
+        }
+        fn double_click(rect: Rect) -> Vec<Event> {
+            let pos = rect.center_top() + crate::vec2(0.0, 10.0);
+            let mut events = vec![Event::PointerMoved(pos)];
+            for _ in 0..2 {
+                for pressed in [true, false] {
+                    events.push(Event::PointerButton {
+                        pos,
+                        button: PointerButton::Primary,
+                        pressed,
+                        modifiers: Modifiers::default(),
+                    });
+                }
+            }
+            events
+        }
+
+        let ctx = Context::default();
+        run(&ctx, vec![]);
+        let rect = run(&ctx, vec![]);
+
+        run(&ctx, double_click(rect));
+        run(&ctx, vec![]);
+        let maximized = run(&ctx, vec![]);
+        assert!(800.0 - 20.0 < maximized.width(), "{maximized:?}");
+        assert!(600.0 - 20.0 < maximized.height(), "{maximized:?}");
+
+        run(&ctx, double_click(maximized));
+        run(&ctx, vec![]);
+        let restored = run(&ctx, vec![]);
+        assert!(
+            (restored.min - rect.min).length() < 1.0,
+            "{restored:?} vs {rect:?}"
+        );
+        assert!(
+            (restored.size() - rect.size()).length() < 1.0,
+            "{restored:?} vs {rect:?}"
+        );
+    }
+
+    #[test]
+    fn test_area_constraint_from_fn() {
+        use crate::{RawInput, Rect};
+
+        fn run(ctx: &Context, screen_width: f32) -> (Rect, Rect) {
+            let mut rects = None;
+            let _ = ctx.run(
+                RawInput {
+                    screen_rect: Some(Rect::from_min_size(
+                        Default::default(),
+                        [screen_width, 600.0].into(),
+                    )),
+                    ..Default::default()
+                },
+                |ctx| {
+                    let panel = crate::SidePanel::right("tools")
+                        .exact_width(100.0)
+                        .show(ctx, |_| {})
+                        .response
+                        .rect;
+                    let window = crate::Window::new("Window")
+                        .default_pos([700.0, 100.0])
+                        .constrain_to(crate::AreaConstraint::from_fn(|ctx| ctx.available_rect()))
+                        .show(ctx, |ui| ui.label("Hello"))
+                        .unwrap()
+                        .response
+                        .rect;
+                    rects = Some((panel, window));
+                },
+            );
+            rects.unwrap()
+        }
+
+        let ctx = Context::default();
+        for screen_width in [800.0, 800.0, 600.0, 600.0] {
+            let (panel, window) = run(&ctx, screen_width);
+            assert!(window.right() <= panel.left(), "{window:?} vs {panel:?}");
+        }
+    }
+
+    #[test]
+    fn test_custom_order() {
+        use crate::{Area, Id, Order};
+
+        let mut orders = vec![
+            Order::Tooltip,
+            Order::Custom(250),
+            Order::Foreground,
+            Order::Custom(Order::Foreground.z()),
+            Order::Middle,
+        ];
+        orders.sort();
+        assert_eq!(
+            orders,
+            [
+                Order::Middle,
+                Order::Foreground,
+                Order::Custom(200),
+                Order::Custom(250),
+                Order::Tooltip,
+            ]
+        );
+
+        let ctx = Context::default();
+        let mut painted = vec![];
+        let mut run = || {
+            ctx.run(Default::default(), |ctx| {
+                for (name, order) in [
+                    ("tooltip", Order::Tooltip),
+                    ("custom", Order::Custom(250)),
+                    ("popup", Order::Foreground),
+                ] {
+                    Area::new(Id::new(name)).order(order).show(ctx, |ui| {
+                        ui.label(name);
+                    });
+                }
+                let area_order = ctx.memory(|mem| mem.areas().order().to_vec());
+                painted = ctx.graphics(|graphics| {
+                    graphics
+                        .layers_in_paint_order(&area_order)
+                        .into_iter()
+                        .map(|(layer_id, _)| layer_id.order)
+                        .collect()
+                });
+            })
+        };
+        let _ = run(); // sizing pass
+        let _ = run();
+        assert_eq!(
+            painted,
+            [Order::Foreground, Order::Custom(250), Order::Tooltip]
+        );
+    }
+
+    #[test]
+    fn test_layer_opacity() {
+        use crate::{Area, Color32, Id, LayerId, RawInput, Shape};
+
+        let ctx = Context::default();
+        let layer_id = LayerId::new(crate::Order::Middle, Id::new("area"));
+        ctx.set_layer_opacity(layer_id, 0.5);
+        let run = || {
+            ctx.run(Default::default(), |ctx| {
+                Area::new(layer_id.id).fade_in(false).show(ctx, |ui| {
+                    ui.painter()
+                        .rect_filled(ui.max_rect(), 0.0, Color32::from_gray(200));
+                });
+            })
+        };
+        let _ = run(); // sizing pass
+        let output = run();
+        let colors: Vec<Color32> = output
+            .shapes
+            .iter()
+            .filter_map(|clipped| match &clipped.shape {
+                Shape::Rect(rect) => Some(rect.fill),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(colors, [Color32::from_gray(200).gamma_multiply(0.5)]);
+
+        // A closed window fades out before it goes away:
+        let ctx = Context::default();
+        let mut open = true;
+        let mut shown = vec![];
+        for time in [0.0, 1.0, 1.01, 1.02, 2.0] {
+            if time == 1.01 {
+                open = false;
+            }
+            let _ = ctx.run(
+                RawInput {
+                    time: Some(time),
+                    ..Default::default()
+                },
+                |ctx| {
+                    let window = crate::Window::new("Window")
+                        .open(&mut open)
+                        .show(ctx, |ui| ui.label("Hello"));
+                    if let Some(window) = window {
+                        shown.push(ctx.layer_opacity(window.response.layer_id));
+                    }
+                },
+            );
+        }
+        assert_eq!(shown.len(), 4, "{shown:?}");
+        assert!(shown[1] == 1.0 && shown[3] < 1.0, "{shown:?}");
+    }
+
+    #[test]
+    fn test_layer_color_transform() {
+        use crate::{Area, Color32, ColorTransform, Id, LayerId, Shape};
+
+        let ctx = Context::default();
+        let layer_id = LayerId::new(crate::Order::Middle, Id::new("area"));
+        ctx.set_layer_color_transform(layer_id, ColorTransform::GRAYSCALE);
+        let run = || {
+            ctx.run(Default::default(), |ctx| {
+                Area::new(layer_id.id).fade_in(false).show(ctx, |ui| {
+                    ui.painter().rect_filled(ui.max_rect(), 0.0, Color32::RED);
+                });
+            })
+        };
+        let _ = run(); // sizing pass
+        let output = run();
+        let Some(Shape::Rect(rect)) = output.shapes.last().map(|clipped| &clipped.shape) else {
+            panic!("Expected a rect");
+        };
+        let [r, g, b, a] = rect.fill.to_array();
+        assert!(r == g && g == b && 0 < r && a == 255, "{:?}", rect.fill);
+
+        ctx.set_layer_color_transform(layer_id, ColorTransform::IDENTITY);
+        assert_eq!(
+            ctx.layer_color_transform(layer_id),
+            ColorTransform::IDENTITY
+        );
+    }
+
+    #[test]
+    fn test_navigate_to_anchor() {
+        use crate::{RawInput, Rect, ScrollArea};
+
+        // Returns the visible area of the outer scroll area, and where the anchored heading is.
+        fn run(ctx: &Context) -> (Rect, Rect) {
+            let mut rects = None;
+            let _ = ctx.run(RawInput::default(), |ctx| {
+                crate::CentralPanel::default().show(ctx, |ui| {
+                    let mut heading = Rect::NOTHING;
+                    let outer = ScrollArea::vertical()
+                        .id_salt("outer")
+                        .max_height(200.0)
+                        .show(ui, |ui| {
+                            for i in 0..20 {
+                                ui.label(format!("Outer {i}"));
+                            }
+                            ScrollArea::vertical()
+                                .id_salt("inner")
+                                .max_height(100.0)
+                                .show(ui, |ui| {
+                                    for i in 0..50 {
+                                        if i == 40 {
+                                            ui.anchor("inner/40");
+                                            heading = ui.heading("Heading").rect;
+                                        }
+                                        ui.label(format!("Inner {i}"));
+                                    }
+                                });
+                        });
+                    rects = Some((outer.inner_rect, heading));
+                });
+            });
+            rects.unwrap()
+        }
+
+        let ctx = Context::default();
+        ctx.all_styles_mut(|style| style.scroll_animation = crate::style::ScrollAnimation::none());
+
+        let (outer, heading) = run(&ctx);
+        assert!(!outer.contains_rect(heading));
+        assert_eq!(ctx.anchors(), vec!["inner/40".to_owned()]);
+
+        ctx.navigate_to_anchor("inner/40", false);
+        for _ in 0..3 {
+            run(&ctx);
+        }
+        let (outer, heading) = run(&ctx);
+        assert!(outer.contains_rect(heading), "{outer:?} vs {heading:?}");
+    }
+
+    #[test]
+    fn test_scroll_to_focused() {
+        use crate::{CentralPanel, Event, Key, Modifiers, RawInput, ScrollArea};
+
+        /// Returns whether the focused button is fully visible.
+        fn run(ctx: &Context, press_tab: bool) -> Option<bool> {
+            let mut input = RawInput::default();
+            if press_tab {
+                input.events.push(Event::Key {
+                    key: Key::Tab,
+                    physical_key: None,
+                    pressed: true,
+                    repeat: false,
+                    modifiers: Modifiers::NONE,
+                });
+            }
+            let mut visible = None;
+            let _ = ctx.run(input, |ctx| {
+                CentralPanel::default().show(ctx, |ui| {
+                    ScrollArea::vertical().max_height(100.0).show(ui, |ui| {
+                        for i in 0..30 {
+                            let response = ui.button(format!("Button {i}"));
+                            if response.has_focus() {
+                                visible = Some(response.is_fully_visible());
+                            }
+                        }
+                    });
+                });
+            });
+            visible
+        }
+
+        let check = |scroll_to_focused: bool| {
+            let ctx = Context::default();
+            ctx.options_mut(|o| o.scroll_to_focused = scroll_to_focused);
+            ctx.all_styles_mut(|style| {
+                style.scroll_animation = crate::style::ScrollAnimation::none();
+            });
+            run(&ctx, false);
+            for _ in 0..20 {
+                run(&ctx, true);
+            }
+            run(&ctx, false);
+            run(&ctx, false).unwrap()
+        };
+
+        assert!(check(true));
+        assert!(!check(false));
+    }
+
+    #[test]
+    fn test_repaint_throttle() {
+        use super::Duration;
+        use crate::{Options, RawInput, RepaintThrottle, ViewportId};
+
+        let repaint_delay = |options: &dyn Fn(&mut Options), occluded: bool, power_saving: bool| {
+            let ctx = Context::default();
+            ctx.options_mut(options);
+            let input = RawInput {
+                occluded,
+                power_saving,
+                ..Default::default()
+            };
+            for _ in 0..3 {
+                let _ = ctx.run(input.clone(), |_| {}); // Settle the repaints of the first frames
+            }
+            let _ = ctx.run(input, |ctx| ctx.request_repaint());
+            ctx.read(|ctx| ctx.viewports[&ViewportId::ROOT].repaint.repaint_delay)
+        };
+
+        let defaults = |_: &mut Options| {};
+        assert_eq!(repaint_delay(&defaults, false, false), Duration::ZERO);
+        assert_eq!(repaint_delay(&defaults, true, false), Duration::MAX);
+        let delay = repaint_delay(&defaults, false, true);
+        assert!(Duration::ZERO < delay && delay < Duration::MAX);
+
+        let no_throttle = |o: &mut Options| o.occluded_repaint = RepaintThrottle::Off;
+        assert_eq!(repaint_delay(&no_throttle, true, false), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_repaint_reasons() {
+        use crate::{CentralPanel, RawInput, RepaintReason, Spinner, ViewportId};
+
+        let ctx = Context::default();
+        let run = |add_contents: &dyn Fn(&mut crate::Ui)| {
+            let input = RawInput {
+                time: Some(10.0),
+                ..Default::default()
+            };
+            let output = ctx.run(input, |ctx| {
+                CentralPanel::default().show(ctx, add_contents);
+            });
+            output.viewport_output[&ViewportId::ROOT].clone()
+        };
+        for _ in 0..3 {
+            run(&|_| {}); // Settle the repaints of the first frames
+        }
+
+        let output = run(&|_| {});
+        assert!(output.repaint_reasons.is_empty());
+        assert_eq!(output.repaint_deadline, None);
+
+        let output = run(&|ui| {
+            ui.add(Spinner::new());
+        });
+        assert!(output.repaint_reasons.is_only_animation());
+        assert!(
+            output
+                .repaint_deadline
+                .is_some_and(|deadline| deadline <= 10.0)
+        );
+
+        run(&|_| {}); // The extra repaint after an immediate one
+        let output = run(&|ui| ui.ctx().request_repaint_after_secs(5.0));
+        assert!(output.repaint_reasons.contains(RepaintReason::Requested));
+        assert!(
+            output
+                .repaint_deadline
+                .is_some_and(|deadline| 14.0 < deadline)
+        );
+    }
+
+    #[test]
+    fn test_trim_memory() {
+        use crate::{CentralPanel, MemoryPressure, RawInput};
+
+        let ctx = Context::default();
+        let num_runs = std::cell::Cell::new(0);
+        let run = |ctx: &Context| {
+            let _ = ctx.run(RawInput::default(), |ctx| {
+                CentralPanel::default().show(ctx, |ui| {
+                    ui.cached("cached", (), |ui| {
+                        num_runs.set(num_runs.get() + 1);
+                        ui.label("Hello");
+                    })
+                });
+            });
+        };
+        run(&ctx);
+        run(&ctx);
+        assert_eq!(num_runs.get(), 1);
+
+        let font_image_size = || ctx.fonts(|fonts| fonts.font_image_size());
+        let size_before = font_image_size();
+        assert!(0 < ctx.trim_memory(MemoryPressure::Critical));
+        assert!(font_image_size()[1] < size_before[1]);
+
+        run(&ctx);
+        assert_eq!(
+            num_runs.get(),
+            2,
+            "The cached text must be laid out again with the new font atlas"
+        );
+    }
+
+    fn strict_context() -> Context {
+        let ctx = Context::default();
+        ctx.options_mut(|o| o.strict_mode = true);
+        ctx
+    }
+
+    #[test]
+    fn test_strict_mode_allows_correct_use() {
+        use crate::{CentralPanel, ComboBox, RawInput, Window};
+
+        let ctx = strict_context();
+        for _ in 0..3 {
+            let _ = ctx.run(RawInput::default(), |ctx| {
+                let id = crate::Id::new("button");
+                let _ = ctx.read_response(id); // Before the widget is created
+                CentralPanel::default().show(ctx, |ui| {
+                    let _ = ui.interact(ui.max_rect(), id, crate::Sense::click());
+                    ComboBox::from_id_salt("combo")
+                        .selected_text("A")
+                        .show_ui(ui, |ui| ui.label("B"));
+                    ui.label("Hover me").on_hover_text("Tooltip");
+                    let _focused = ui.ctx().memory(|m| m.focused());
+                });
+                Window::new("Window").show(ctx, |ui| ui.label("Content"));
+            });
+        }
+    }
+
+    #[cfg(debug_assertions)] // In release builds, misuse is reported as a warning
+    #[test]
+    #[should_panic(expected = "created outside of a pass")]
+    fn test_strict_mode_widget_outside_pass() {
+        let ctx = strict_context();
+        let _ = ctx.run(crate::RawInput::default(), |_| {});
+        let _ = crate::Ui::new(
+            ctx,
+            crate::Id::new("ui"),
+            crate::UiBuilder::new().layer_id(crate::LayerId::background()),
+        );
+    }
+
+    #[cfg(debug_assertions)] // In release builds, misuse is reported as a warning
+    #[test]
+    #[should_panic(expected = "no widget with that id was created")]
+    fn test_strict_mode_read_unknown_response() {
+        let ctx = strict_context();
+        let _ = ctx.run(crate::RawInput::default(), |ctx| {
+            let _ = ctx.read_response(crate::Id::new("never created"));
+        });
+    }
+
+    #[cfg(debug_assertions)] // In release builds, misuse is reported as a warning
+    #[test]
+    #[should_panic(expected = "would deadlock")]
+    fn test_strict_mode_recursive_lock() {
+        let ctx = strict_context();
+        let _ = ctx.run(crate::RawInput::default(), |ctx| {
+            ctx.input(|_| ctx.memory_mut(|_| {}));
+        });
+    }
+
+    #[test]
+    fn test_warnings() {
+        use crate::{CentralPanel, RawInput, Sense, WarningKind, WarningSeverity};
+
+        let ctx = Context::default();
+        ctx.options_mut(|o| o.warn_on_id_clash = true);
+        let run = || {
+            let _ = ctx.run(RawInput::default(), |ctx| {
+                CentralPanel::default().show(ctx, |ui| {
+                    ui.label("Missing glyph: \u{10FFFD}");
+                    let id = crate::Id::new("clash");
+                    ui.interact(ui.max_rect(), id, Sense::click());
+                    ui.interact(
+                        ui.max_rect().translate(crate::vec2(50.0, 50.0)),
+                        id,
+                        Sense::click(),
+                    );
+                });
+            });
+        };
+        run();
+        run();
+
+        let warnings = ctx.take_warnings();
+        let id_clashes: Vec<_> = warnings
+            .iter()
+            .filter(|w| w.kind == WarningKind::IdClash)
+            .collect();
+        assert_eq!(
+            id_clashes.len(),
+            1,
+            "The same warning is only reported once"
+        );
+        assert_eq!(id_clashes[0].severity, WarningSeverity::Error);
+        assert!(
+            warnings
+                .iter()
+                .any(|w| w.kind == WarningKind::MissingGlyphs && w.message.contains("10fffd"))
+        );
+
+        run();
+        let warnings = ctx.take_warnings();
+        assert!(warnings.iter().any(|w| w.kind == WarningKind::IdClash));
+        assert!(
+            !warnings
+                .iter()
+                .any(|w| w.kind == WarningKind::MissingGlyphs),
+            "Missing glyphs are only reported the first time they are laid out"
+        );
+    }
+
+    #[test]
+    fn test_nan_layout() {
+        use crate::{CentralPanel, RawInput, Rect, Sense, WarningKind, vec2};
+
+        let ctx = Context::default();
+        let mut after_nan = Rect::NOTHING;
+        let _ = ctx.run(RawInput::default(), |ctx| {
+            CentralPanel::default().show(ctx, |ui| {
+                ui.allocate_response(vec2(f32::NAN, 20.0), Sense::hover());
+                ui.set_min_width(f32::NAN);
+                ui.allocate_ui(vec2(10.0, f32::NAN), |ui| ui.label("Inside"));
+                ui.advance_cursor_after_rect(Rect::from_min_size(
+                    ui.next_widget_position(),
+                    vec2(f32::NAN, 10.0),
+                ));
+                after_nan = ui.label("After").rect;
+            });
+        });
+
+        assert!(!after_nan.any_nan(), "NaN must not spread: {after_nan:?}");
+        let warnings = ctx.take_warnings();
+        assert_eq!(
+            warnings
+                .iter()
+                .filter(|w| w.kind == WarningKind::NanLayout)
+                .count(),
+            4
+        );
+
+        let mut after_inf = Rect::NOTHING;
+        let mut unlimited = Rect::NOTHING;
+        let _ = ctx.run(RawInput::default(), |ctx| {
+            CentralPanel::default().show(ctx, |ui| {
+                ui.allocate_response(vec2(f32::INFINITY, 20.0), Sense::hover());
+                ui.allocate_response(vec2(20.0, f32::NEG_INFINITY), Sense::hover());
+                ui.set_min_width(f32::INFINITY);
+                ui.set_max_width(f32::NEG_INFINITY);
+                ui.advance_cursor_after_rect(Rect::from_min_size(
+                    ui.next_widget_position(),
+                    vec2(10.0, f32::INFINITY),
+                ));
+                // Positive infinity means "as much as possible", and is fine:
+                ui.set_max_width(f32::INFINITY);
+                ui.allocate_ui(vec2(f32::INFINITY, 10.0), |ui| {
+                    unlimited = ui.max_rect();
+                });
+                after_inf = ui.label("After").rect;
+            });
+        });
+
+        assert!(
+            after_inf.is_finite(),
+            "Infinity must not spread: {after_inf:?}"
+        );
+        assert_eq!(unlimited.max.x, f32::INFINITY);
+        let warnings = ctx.take_warnings();
+        assert_eq!(
+            warnings
+                .iter()
+                .filter(|w| w.kind == WarningKind::NanLayout)
+                .count(),
+            5
+        );
+    }
+
+    #[test]
+    fn test_run_until_stable() {
+        use crate::{CentralPanel, Grid, RawInput, Spinner};
+
+        let ctx = Context::default();
+        let mut num_frames = 0;
+        let output = ctx.run_until_stable(
+            RawInput::default(),
+            |ctx| {
+                num_frames += 1;
+                CentralPanel::default().show(ctx, |ui| {
+                    Grid::new("grid").show(ui, |ui| {
+                        ui.label("Settles after a sizing pass");
+                    });
+                });
+            },
+            10,
+        );
+        assert!(1 < num_frames && num_frames < 10, "{num_frames}");
+        assert!(!output.platform_output.requested_discard());
+        assert!(
+            !output.textures_delta.set.is_empty(),
+            "The font atlas of the first frame should be kept"
+        );
+
+        // Never stable:
+        let mut num_frames = 0;
+        let _ = ctx.run_until_stable(
+            RawInput::default(),
+            |ctx| {
+                num_frames += 1;
+                CentralPanel::default().show(ctx, |ui| {
+                    ui.add(Spinner::new());
+                });
+            },
+            5,
+        );
+        assert_eq!(num_frames, 5);
+    }
+
+    #[test]
+    fn test_widget_tree_snapshot() {
+        use crate::{CentralPanel, Id, RawInput, Sense, UiKind, WidgetNodeKind};
+
+        let ctx = Context::default();
+        let mut ids = None;
+        let mut run = || {
+            let _ = ctx.run(RawInput::default(), |ctx| {
+                CentralPanel::default().show(ctx, |ui| {
+                    let panel = ui.unique_id();
+                    let child = ui.scope(|ui| ui.unique_id()).inner;
+                    let button = ui.interact(ui.max_rect(), Id::new("button"), Sense::click());
+                    ids = Some((panel, child, button.id));
+                });
+            });
+        };
+        run();
+        run();
+        let (panel, child, button) = ids.unwrap();
+
+        let tree = ctx.widget_tree_snapshot();
+        let mut ancestor = tree.get(panel).unwrap().parent;
+        let mut ancestor_kinds = vec![];
+        while let Some(id) = ancestor {
+            let node = tree.get(id).unwrap();
+            ancestor_kinds.push(node.kind);
+            ancestor = node.parent;
+        }
+        assert!(ancestor_kinds.contains(&WidgetNodeKind::Ui(Some(UiKind::CentralPanel))));
+        assert_eq!(tree.get(child).unwrap().parent, Some(panel));
+        assert_eq!(tree.get(button).unwrap().parent, Some(panel));
+        assert!(matches!(
+            tree.get(button).unwrap().kind,
+            WidgetNodeKind::Widget(_)
+        ));
+
+        let children: Vec<Id> = tree.children(panel).map(|node| node.id).collect();
+        assert_eq!(children, vec![child, button]);
+        assert!(tree.roots().count() > 0);
+    }
+
+    #[cfg(feature = "memory_stats")]
+    #[test]
+    fn test_memory_stats() {
+        let ctx = Context::default();
+        let output = ctx.run(crate::RawInput::default(), |ctx| {
+            crate::CentralPanel::default().show(ctx, |ui| {
+                ui.label("Some text to lay out");
+                ui.data_mut(|data| data.insert_temp(crate::Id::new("stats"), [0_u8; 256]));
+            });
+        });
+        let _ = ctx.tessellate(output.shapes, output.pixels_per_point);
+
+        let stats = ctx.memory_stats();
+        assert!(0 < stats.shapes);
+        assert!(0 < stats.galleys);
+        assert!(256 <= stats.memory_data);
+        assert!(0 < stats.textures, "The font atlas should be counted");
+        assert_eq!(
+            stats.total(),
+            stats.shapes + stats.galleys + stats.memory_data + stats.textures + stats.loaders
+        );
+    }
+
+    #[test]
+    fn test_paint_on_top_of_siblings() {
+        use crate::{CentralPanel, Color32, RawInput, Rect, Sense, Shape, pos2, vec2};
+
+        let ctx = Context::default();
+        let rect = Rect::from_min_size(pos2(10.0, 10.0), vec2(50.0, 50.0));
+        let mut ids = None;
+        let output = ctx.run(RawInput::default(), |ctx| {
+            CentralPanel::default().show(ctx, |ui| {
+                let top = ui.paint_on_top_of_siblings(|ui| {
+                    ui.painter().rect_filled(rect, 0.0, Color32::RED);
+                    ui.interact(rect, ui.id().with("top"), Sense::click()).id
+                });
+                ui.painter().rect_filled(rect, 0.0, Color32::BLUE);
+                let below = ui.interact(rect, ui.id().with("below"), Sense::click()).id;
+                ids = Some((top.inner, below));
+            });
+        });
+
+        let fills: Vec<Color32> = output
+            .shapes
+            .iter()
+            .filter_map(|clipped| match &clipped.shape {
+                Shape::Rect(rect_shape) if rect_shape.rect == rect => Some(rect_shape.fill),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(fills, [Color32::BLUE, Color32::RED]);
+
+        let (top, below) = ids.unwrap();
+        ctx.write(|ctx| {
+            let widgets = &ctx.viewport().prev_pass.widgets;
+            assert!(widgets.order(below).unwrap().1 < widgets.order(top).unwrap().1);
+        });
+    }
+
+    #[test]
+    fn test_widget_transition() {
+        use crate::{
+            CentralPanel, Event, Pos2, RawInput, Sense, pos2,
+            style::{Easing, WidgetVisuals},
+            vec2,
+        };
+
+        let ctx = Context::default();
+        ctx.all_styles_mut(|style| {
+            style.widget_transition.duration = 1.0;
+            style.widget_transition.easing = Easing::Linear;
+        });
+
+        let run = |time: f64, pointer: Option<Pos2>| {
+            let mut input = RawInput {
+                time: Some(time),
+                ..Default::default()
+            };
+            input.events.extend(pointer.map(Event::PointerMoved));
+            let mut visuals = None;
+            let _ = ctx.run(input, |ctx| {
+                CentralPanel::default().show(ctx, |ui| {
+                    let (_, response) = ui.allocate_exact_size(vec2(50.0, 50.0), Sense::click());
+                    visuals = Some(ui.interact_visuals(&response));
+                });
+            });
+            visuals.unwrap()
+        };
+
+        let widgets = ctx.style().visuals.widgets.clone();
+        let fill = |visuals: WidgetVisuals| visuals.bg_fill;
+
+        assert_eq!(fill(run(0.0, None)), widgets.inactive.bg_fill);
+        assert_eq!(
+            fill(run(0.1, Some(pos2(20.0, 20.0)))),
+            widgets.inactive.bg_fill
+        );
+        assert_eq!(
+            fill(run(0.6, None)),
+            widgets.inactive.lerp(&widgets.hovered, 0.5).bg_fill
+        );
+        assert_eq!(fill(run(2.0, None)), widgets.hovered.bg_fill);
+    }
+
+    #[test]
+    fn test_keyboard_focus() {
+        use crate::{CentralPanel, Event, Key, Modifiers, RawInput};
+
+        let ctx = Context::default();
+        let run = |press_tab: bool, request_focus: bool| {
+            let mut input = RawInput::default();
+            if press_tab {
+                input.events.push(Event::Key {
+                    key: Key::Tab,
+                    physical_key: None,
+                    pressed: true,
+                    repeat: false,
+                    modifiers: Modifiers::NONE,
+                });
+            }
+            let mut ids = vec![];
+            let _ = ctx.run(input, |ctx| {
+                CentralPanel::default().show(ctx, |ui| {
+                    for i in 0..3 {
+                        let response = ui.button(format!("Button {i}"));
+                        if request_focus && i == 2 {
+                            response.request_focus();
+                        }
+                        ids.push(response.id);
+                    }
+                });
+            });
+            ids
+        };
+
+        let ids = run(false, false);
+        run(true, false);
+        assert_eq!(ctx.memory(|m| m.keyboard_focused()), Some(ids[0]));
+
+        run(false, true);
+        assert_eq!(ctx.memory(|m| m.focused()), Some(ids[2]));
+        assert_eq!(
+            ctx.memory(|m| m.keyboard_focused()),
+            None,
+            "Focus from code doesn't show the focus ring"
+        );
+    }
+
+    #[test]
+    fn test_with_bottom_up() {
+        let ctx = Context::default();
+        let mut rects = None;
+        let _ = ctx.run(Default::default(), |ctx| {
+            crate::CentralPanel::default().show(ctx, |ui| {
+                let max_rect = ui.max_rect();
+                ui.label("Header");
+                ui.with_bottom_up(|ui| {
+                    let footer = ui.label("Footer").rect;
+                    let content = ui.allocate_remaining_space().max_rect();
+                    rects = Some((max_rect, footer, content));
+                });
+            });
+        });
+        let (max_rect, footer, content) = rects.unwrap();
+        assert_eq!(
+            footer.bottom(),
+            max_rect.bottom(),
+            "The footer is pinned to the bottom"
+        );
+        assert!(content.top() < footer.top() && content.bottom() <= footer.top());
+        assert!(
+            max_rect.top() < content.top(),
+            "The content is below the header"
+        );
+    }
+
+    #[test]
+    fn test_render_ui_to_image() {
+        use crate::{Color32, Rect, pos2, vec2};
+
+        let ctx = Context::default();
+        let mut image = None;
+        let _ = ctx.run(Default::default(), |ctx| {
+            image = Some(ctx.render_ui_to_image(vec2(40.0, 30.0), |ui| {
+                let rect = Rect::from_min_size(pos2(0.0, 0.0), vec2(10.0, 10.0));
+                ui.painter().rect_filled(rect, 0.0, Color32::RED);
+                ui.label("Hello");
+            }));
+        });
+        let image = image.unwrap();
+        assert_eq!(image.size, [40, 30]);
+        assert_eq!(image[(5, 5)], Color32::RED);
+        assert_eq!(image[(35, 25)], ctx.style().visuals.panel_fill);
+    }
+
+    #[test]
+    fn test_push_stable_id() {
+        let ctx = Context::default();
+
+        // The ids of the widgets in each item should follow the key of the item:
+        let ids_for_order = |order: &[u32], labels_before: usize| {
+            let mut ids = std::collections::BTreeMap::new();
+            let _ = ctx.run(Default::default(), |ctx| {
+                crate::CentralPanel::default().show(ctx, |ui| {
+                    for _ in 0..labels_before {
+                        ui.label("Shifts the automatic ids");
+                    }
+                    for &key in order {
+                        ui.push_stable_id(key, |ui| {
+                            ids.insert(key, (ui.id(), ui.next_auto_id()));
+                        });
+                    }
+                });
+            });
+            ids
+        };
+
+        let ids = ids_for_order(&[1, 2, 3], 0);
+        assert_eq!(ids, ids_for_order(&[3, 1, 2], 0));
+        assert_eq!(ids, ids_for_order(&[2, 3, 1], 5));
+    }
+
+    #[test]
+    fn test_capture() {
+        use crate::{Event, PointerButton, RawInput};
+
+        let ctx = Context::default();
+        let mut checked = false;
+        let mut run = |events: Vec<Event>| {
+            let input = RawInput {
+                events,
+                ..Default::default()
+            };
+            let mut result = None;
+            let _ = ctx.run(input, |ctx| {
+                crate::CentralPanel::default().show(ctx, |ui| {
+                    ui.label("Outside");
+                    let mut checkbox_rect = crate::Rect::NOTHING;
+                    let response = ui.capture(|ui| {
+                        ui.label("Inside");
+                        checkbox_rect = ui.checkbox(&mut checked, "Check").rect;
+                    });
+                    result = Some((response, checkbox_rect));
+                });
+            });
+            result.unwrap()
+        };
+
+        let (response, checkbox_rect) = run(vec![]);
+        assert!(response.rect.contains_rect(checkbox_rect));
+        assert!(!response.clicked());
+
+        let pos = checkbox_rect.center();
+        let button = |pressed| Event::PointerButton {
+            pos,
+            button: PointerButton::Primary,
+            pressed,
+            modifiers: Default::default(),
+        };
+        let (response, _) = run(vec![Event::PointerMoved(pos)]);
+        assert!(response.hovered());
+        run(vec![button(true)]);
+        let (response, _) = run(vec![button(false)]);
+        assert!(response.clicked());
+        assert!(response.changed());
+        assert!(checked);
+    }
+
+    #[test]
+    fn test_reorderable_list() {
+        use crate::{Event, PointerButton, RawInput, pos2, vec2};
+
+        let ctx = Context::default();
+        let mut items = vec!["a", "b", "c"];
+        let mut run = |events: Vec<Event>| {
+            let input = RawInput {
+                events,
+                ..Default::default()
+            };
+            let mut result = None;
+            let mut rects = Vec::new();
+            let _ = ctx.run(input, |ctx| {
+                crate::CentralPanel::default().show(ctx, |ui| {
+                    result = ui.reorderable_list("list", &mut items, |ui, item| {
+                        rects.push(ui.label(*item).rect);
+                    });
+                });
+            });
+            (result, rects, items.clone())
+        };
+
+        run(vec![]);
+        let (_, rects, _) = run(vec![]);
+
+        // Drag the first item by its handle to below the last item:
+        let start = pos2(rects[0].left() - 8.0, rects[0].center().y);
+        let end = pos2(start.x, rects[2].bottom() + 2.0);
+        let button = |pos, pressed| Event::PointerButton {
+            pos,
+            button: PointerButton::Primary,
+            pressed,
+            modifiers: Default::default(),
+        };
+        run(vec![Event::PointerMoved(start), button(start, true)]);
+        run(vec![Event::PointerMoved(start + vec2(0.0, 10.0))]);
+        let (result, _, _) = run(vec![Event::PointerMoved(end)]);
+        assert_eq!(result, None);
+        let (result, _, items) = run(vec![button(end, false)]);
+        assert_eq!(result, Some((0, 2)));
+        assert_eq!(items, vec!["b", "c", "a"]);
+    }
+
+    #[test]
+    fn test_single_pass() {
+        let ctx = Context::default();
+        ctx.options_mut(|o| o.max_passes = 1.try_into().unwrap());
+
+        // A single call, no request to discard:
+        {
+            let mut num_calls = 0;
+            let output = ctx.run(Default::default(), |ctx| {
+                num_calls += 1;
+                assert_eq!(ctx.output(|o| o.num_completed_passes), 0);
+                assert!(!ctx.output(|o| o.requested_discard()));
+                assert!(!ctx.will_discard());
+            });
+            assert_eq!(num_calls, 1);
+            assert_eq!(output.platform_output.num_completed_passes, 1);
+            assert!(!output.platform_output.requested_discard());
+        }
+
+        // A single call, with a denied request to discard:
+        {
+            let mut num_calls = 0;
+            let output = ctx.run(Default::default(), |ctx| {
+                num_calls += 1;
+                ctx.request_discard("test");
+                assert!(!ctx.will_discard(), "The request should have been denied");
+            });
+            assert_eq!(num_calls, 1);
+            assert_eq!(output.platform_output.num_completed_passes, 1);
+            assert!(
+                output.platform_output.requested_discard(),
+                "The request should be reported"
+            );
+            assert_eq!(
+                output
+                    .platform_output
+                    .request_discard_reasons
+                    .first()
+                    .unwrap()
+                    .reason,
+                "test"
+            );
+        }
+    }
+
+    #[test]
+    fn test_dual_pass() {
+        let ctx = Context::default();
+        ctx.options_mut(|o| o.max_passes = 2.try_into().unwrap());
+
+        // Normal single pass:
+        {
+            let mut num_calls = 0;
+            let output = ctx.run(Default::default(), |ctx| {
+                assert_eq!(ctx.output(|o| o.num_completed_passes), 0);
+                assert!(!ctx.output(|o| o.requested_discard()));
+                assert!(!ctx.will_discard());
+                num_calls += 1;
+            });
+            assert_eq!(num_calls, 1);
+            assert_eq!(output.platform_output.num_completed_passes, 1);
+            assert!(!output.platform_output.requested_discard());
+        }
+
+        // Request discard once:
+        {
+            let mut num_calls = 0;
+            let output = ctx.run(Default::default(), |ctx| {
+                assert_eq!(ctx.output(|o| o.num_completed_passes), num_calls);
+
+                assert!(!ctx.will_discard());
+                if num_calls == 0 {
+                    ctx.request_discard("test");
+                    assert!(ctx.will_discard());
+                }
+
+                num_calls += 1;
+            });
+            assert_eq!(num_calls, 2);
+            assert_eq!(output.platform_output.num_completed_passes, 2);
+            assert!(
+                !output.platform_output.requested_discard(),
+                "The request should have been cleared when fulfilled"
+            );
+        }
+
+        // Request discard twice:
+        {
+            let mut num_calls = 0;
+            let output = ctx.run(Default::default(), |ctx| {
+                assert_eq!(ctx.output(|o| o.num_completed_passes), num_calls);
+
+                assert!(!ctx.will_discard());
+                ctx.request_discard("test");
+                if num_calls == 0 {
+                    assert!(ctx.will_discard(), "First request granted");
+                } else {
+                    assert!(!ctx.will_discard(), "Second request should be denied");
+                }
+
+                num_calls += 1;
+            });
+            assert_eq!(num_calls, 2);
+            assert_eq!(output.platform_output.num_completed_passes, 2);
+            assert!(
+                output.platform_output.requested_discard(),
+                "The unfulfilled request should be reported"
+            );
+        }
+    }
+
+    #[test]
+    fn test_multi_pass() {
+        let ctx = Context::default();
+        ctx.options_mut(|o| o.max_passes = 10.try_into().unwrap());
+
+        // Request discard three times:
+        {
+            let mut num_calls = 0;
+            let output = ctx.run(Default::default(), |ctx| {
+                assert_eq!(ctx.output(|o| o.num_completed_passes), num_calls);
+
+                assert!(!ctx.will_discard());
+                if num_calls <= 2 {
+                    ctx.request_discard("test");
+                    assert!(ctx.will_discard());
+                }
+
+                num_calls += 1;
+            });
+            assert_eq!(num_calls, 4);
             assert_eq!(output.platform_output.num_completed_passes, 4);
             assert!(
                 !output.platform_output.requested_discard(),
@@ -4203,4 +7414,253 @@ mod test {
             );
         }
     }
+
+    #[test]
+    fn test_theme_transition() {
+        use crate::{RawInput, Theme, Visuals};
+
+        let ctx = Context::default();
+        ctx.set_theme(Theme::Dark);
+        let run = |time: f64| {
+            let input = RawInput {
+                time: Some(time),
+                ..Default::default()
+            };
+            let _ = ctx.run(input, |_| {});
+            ctx.style().visuals.panel_fill
+        };
+
+        assert_eq!(run(0.0), Visuals::dark().panel_fill);
+
+        ctx.set_theme(Theme::Light);
+        let panel_fill = run(0.1);
+        assert_ne!(panel_fill, Visuals::dark().panel_fill);
+        assert_ne!(panel_fill, Visuals::light().panel_fill);
+
+        for i in 2..100 {
+            run(0.1 * i as f64);
+        }
+        assert_eq!(run(10.0), Visuals::light().panel_fill);
+    }
+
+    #[test]
+    fn test_pointer_sampler_can_use_context() {
+        use crate::{Event, RawInput, Vec2, pos2};
+
+        let ctx = Context::default();
+        let sampler_ctx = ctx.clone();
+        ctx.set_pointer_sampler(move |_viewport_id| {
+            // Must not deadlock:
+            let latest_pos = sampler_ctx.input(|i| i.pointer.latest_pos());
+            sampler_ctx.request_repaint();
+            latest_pos.map(|pos| pos + Vec2::splat(1.0))
+        });
+
+        let run = |events: Vec<Event>| {
+            let input = RawInput {
+                events,
+                ..Default::default()
+            };
+            let _ = ctx.run(input, |_| {});
+            ctx.input(|i| i.pointer.latest_pos())
+        };
+
+        run(vec![Event::PointerMoved(pos2(10.0, 10.0))]);
+        assert_eq!(run(vec![]), Some(pos2(11.0, 11.0)));
+    }
+
+    #[test]
+    fn test_input_middleware_order() {
+        use crate::{Event, RawInput, mutex::Mutex};
+        use std::sync::Arc;
+
+        let ctx = Context::default();
+        let calls = Arc::new(Mutex::new(Vec::new()));
+
+        let texts = |raw_input: &RawInput| -> Vec<String> {
+            raw_input
+                .events
+                .iter()
+                .filter_map(|event| match event {
+                    Event::Text(text) => Some(text.clone()),
+                    _ => None,
+                })
+                .collect()
+        };
+
+        {
+            let calls = calls.clone();
+            ctx.add_input_middleware(
+                "remove",
+                Arc::new(move |_ctx, raw_input| {
+                    calls.lock().push(("remove", texts(raw_input)));
+                    raw_input
+                        .events
+                        .retain(|event| *event != Event::Text("secret".to_owned()));
+                }),
+            );
+        }
+        {
+            let calls = calls.clone();
+            ctx.add_input_middleware(
+                "add",
+                Arc::new(move |_ctx, raw_input| {
+                    calls.lock().push(("add", texts(raw_input)));
+                    raw_input.events.push(Event::Text("added".to_owned()));
+                }),
+            );
+        }
+
+        let input = RawInput {
+            events: vec![
+                Event::Text("hello".to_owned()),
+                Event::Text("secret".to_owned()),
+            ],
+            ..Default::default()
+        };
+        let mut seen_by_ui = vec![];
+        let _ = ctx.run(input, |ctx| {
+            seen_by_ui = ctx.input(|i| {
+                i.events
+                    .iter()
+                    .filter_map(|event| match event {
+                        Event::Text(text) => Some(text.clone()),
+                        _ => None,
+                    })
+                    .collect()
+            });
+        });
+
+        assert_eq!(
+            *calls.lock(),
+            vec![
+                ("remove", vec!["hello".to_owned(), "secret".to_owned()]),
+                ("add", vec!["hello".to_owned()]),
+            ]
+        );
+        // egui merges consecutive text events:
+        assert_eq!(seen_by_ui, vec!["helloadded".to_owned()]);
+    }
+
+    #[test]
+    fn test_pointer_capture_overrides_hit_test() {
+        use crate::{
+            CentralPanel, Event, PointerButton, RawInput, Sense, ViewportCommand, ViewportId, vec2,
+        };
+
+        let ctx = Context::default();
+        let run = |events: Vec<Event>| {
+            let input = RawInput {
+                events,
+                ..Default::default()
+            };
+            let mut responses = None;
+            let output = ctx.run(input, |ctx| {
+                CentralPanel::default().show(ctx, |ui| {
+                    let a = ui.allocate_response(vec2(100.0, 100.0), Sense::drag());
+                    if a.drag_started() {
+                        a.request_pointer_capture();
+                    }
+                    let b = ui.allocate_response(vec2(100.0, 100.0), Sense::drag());
+                    responses = Some((a, b));
+                });
+            });
+            let (a, b) = responses.unwrap();
+            let commands = output.viewport_output[&ViewportId::ROOT].commands.clone();
+            (a, b, commands)
+        };
+        let button = |pos, pressed| Event::PointerButton {
+            pos,
+            button: PointerButton::Primary,
+            pressed,
+            modifiers: Default::default(),
+        };
+
+        let (a, b, _) = run(vec![]);
+        let on_a = a.rect.center();
+        let on_b = b.rect.center();
+
+        let (_, _, mut commands) = run(vec![Event::PointerMoved(on_a), button(on_a, true)]);
+        let (a, _, more_commands) = run(vec![Event::PointerMoved(on_a + vec2(0.0, 10.0))]);
+        commands.extend(more_commands);
+        assert!(a.has_pointer_capture());
+        assert!(commands.contains(&ViewportCommand::PointerCapture(true)));
+
+        // Releasing the button over another widget doesn't end the capture:
+        run(vec![Event::PointerMoved(on_b)]);
+        let (a, b, _) = run(vec![button(on_b, false)]);
+        assert!(a.contains_pointer());
+        assert!(!b.contains_pointer());
+
+        // A drag that starts outside of the capturing widget still goes to it:
+        run(vec![button(on_b, true)]);
+        let (a, b, _) = run(vec![Event::PointerMoved(on_b + vec2(0.0, 20.0))]);
+        assert!(a.dragged());
+        assert!(!b.dragged());
+    }
+
+    #[test]
+    fn test_pointer_lock_lifetime() {
+        use crate::{
+            CentralPanel, Event, PointerButton, RawInput, Sense, ViewportCommand, ViewportId, vec2,
+            viewport::CursorGrab,
+        };
+
+        let ctx = Context::default();
+        let run = |show: bool, events: Vec<Event>| {
+            let input = RawInput {
+                events,
+                ..Default::default()
+            };
+            let mut response = None;
+            let output = ctx.run(input, |ctx| {
+                CentralPanel::default().show(ctx, |ui| {
+                    if show {
+                        let r = ui.allocate_response(vec2(100.0, 100.0), Sense::drag());
+                        if r.drag_started() {
+                            r.request_pointer_lock();
+                        }
+                        response = Some(r);
+                    }
+                });
+            });
+            let commands = output.viewport_output[&ViewportId::ROOT].commands.clone();
+            (response, commands)
+        };
+        let button = |pos, pressed| Event::PointerButton {
+            pos,
+            button: PointerButton::Primary,
+            pressed,
+            modifiers: Default::default(),
+        };
+
+        let (response, _) = run(true, vec![]);
+        let origin = response.unwrap().rect.center();
+
+        let (_, mut commands) = run(
+            true,
+            vec![Event::PointerMoved(origin), button(origin, true)],
+        );
+        let (response, more_commands) =
+            run(true, vec![Event::PointerMoved(origin + vec2(10.0, 0.0))]);
+        commands.extend(more_commands);
+        let response = response.unwrap();
+        assert!(response.has_pointer_lock());
+        assert!(response.has_pointer_capture());
+        assert!(commands.contains(&ViewportCommand::CursorGrab(CursorGrab::Locked)));
+        assert!(commands.contains(&ViewportCommand::CursorVisible(false)));
+
+        // The lock outlives the drag:
+        let (response, _) = run(true, vec![button(origin + vec2(10.0, 0.0), false)]);
+        assert!(response.unwrap().has_pointer_lock());
+
+        // When the widget is no longer shown, the lock is released and the cursor moved back:
+        run(false, vec![]);
+        let (_, commands) = run(false, vec![]);
+        assert_eq!(ctx.pointer_lock_id(), None);
+        assert_eq!(ctx.pointer_capture_id(), None);
+        assert!(commands.contains(&ViewportCommand::CursorGrab(CursorGrab::None)));
+        assert!(commands.contains(&ViewportCommand::CursorVisible(true)));
+        assert!(commands.contains(&ViewportCommand::CursorPosition(origin)));
+    }
 }
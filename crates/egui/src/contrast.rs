@@ -0,0 +1,186 @@
+//! Checking that text is readable against its background, see [`crate::Context::contrast_issues`].
+
+use ahash::HashMap;
+use epaint::{Color32, Rgba, Shape, emath::TSTransform};
+
+use crate::{LayerId, Rect, layers::PaintList};
+
+/// The minimum contrast ratio for normal text according to WCAG 2 level AA.
+pub const WCAG_AA: f32 = 4.5;
+
+/// The minimum contrast ratio for large text (18pt, or 14pt bold) according to WCAG 2 level AA.
+pub const WCAG_AA_LARGE_TEXT: f32 = 3.0;
+
+/// The relative luminance of a color, as defined by WCAG 2, ignoring alpha.
+///
+/// `0.0` for black, `1.0` for white.
+pub fn relative_luminance(color: Color32) -> f32 {
+    let [r, g, b, _] = Rgba::from(color.to_opaque()).to_array();
+    0.2126 * r + 0.7152 * g + 0.0722 * b
+}
+
+/// The WCAG 2 contrast ratio between two colors, from `1.0` (no contrast) to `21.0` (black on white).
+///
+/// ```
+/// use egui::{Color32, contrast::contrast_ratio};
+/// assert!((contrast_ratio(Color32::BLACK, Color32::WHITE) - 21.0).abs() < 1e-3);
+/// assert_eq!(contrast_ratio(Color32::RED, Color32::RED), 1.0);
+/// ```
+pub fn contrast_ratio(a: Color32, b: Color32) -> f32 {
+    let (a, b) = (relative_luminance(a), relative_luminance(b));
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
+
+/// Some text that doesn't have enough contrast against its background.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ContrastIssue {
+    /// The layer the text was painted in.
+    pub layer_id: LayerId,
+
+    /// Where the text is, in global coordinates.
+    pub rect: Rect,
+
+    /// The text itself.
+    pub text: String,
+
+    /// The font size of the text, in points.
+    pub font_size: f32,
+
+    /// The color of the text, blended with the background.
+    pub text_color: Color32,
+
+    /// The background below the text.
+    pub background: Color32,
+
+    /// The contrast ratio between [`Self::text_color`] and [`Self::background`].
+    pub ratio: f32,
+}
+
+/// Walks through painted shapes, keeping track of what the background looks like.
+pub(crate) struct ContrastChecker {
+    /// What is behind everything, e.g. the panel fill.
+    base_background: Color32,
+
+    /// Filled rectangles painted so far, in global coordinates.
+    fills: Vec<(Rect, Color32)>,
+
+    min_ratio: f32,
+    issues: Vec<ContrastIssue>,
+}
+
+impl ContrastChecker {
+    pub(crate) fn new(base_background: Color32, min_ratio: f32) -> Self {
+        Self {
+            base_background,
+            fills: Vec::new(),
+            min_ratio,
+            issues: Vec::new(),
+        }
+    }
+
+    /// Check all the layers, which must be in the order they are painted.
+    pub(crate) fn check(
+        mut self,
+        layers: &[(LayerId, &PaintList)],
+        to_global: &HashMap<LayerId, TSTransform>,
+    ) -> Vec<ContrastIssue> {
+        for (layer_id, paint_list) in layers {
+            let transform = to_global.get(layer_id).copied().unwrap_or_default();
+            for clipped_shape in paint_list.all_entries() {
+                let clip_rect = transform * clipped_shape.clip_rect;
+                self.visit(*layer_id, transform, clip_rect, &clipped_shape.shape);
+            }
+        }
+        self.issues
+    }
+
+    fn visit(&mut self, layer_id: LayerId, transform: TSTransform, clip_rect: Rect, shape: &Shape) {
+        match shape {
+            Shape::Vec(shapes) => {
+                for shape in shapes {
+                    self.visit(layer_id, transform, clip_rect, shape);
+                }
+            }
+            Shape::Rect(rect_shape) if rect_shape.fill.a() > 0 => {
+                let rect = (transform * rect_shape.rect).intersect(clip_rect);
+                if rect.is_positive() {
+                    self.fills.push((rect, rect_shape.fill));
+                }
+            }
+            Shape::Text(text_shape) => {
+                let rect = (transform * text_shape.visual_bounding_rect()).intersect(clip_rect);
+                if !rect.is_positive() || text_shape.galley.job.text.trim().is_empty() {
+                    return;
+                }
+                let background = self.background_at(rect.center());
+
+                let job = &text_shape.galley.job;
+                let worst = job
+                    .sections
+                    .iter()
+                    .filter(|section| !job.text[section.byte_range.clone()].trim().is_empty())
+                    .map(|section| {
+                        let color = if let Some(color) = text_shape.override_text_color {
+                            color
+                        } else if section.format.color == Color32::PLACEHOLDER {
+                            text_shape.fallback_color
+                        } else {
+                            section.format.color
+                        };
+                        let color =
+                            background.blend(color.gamma_multiply(text_shape.opacity_factor));
+                        (color, contrast_ratio(color, background), section)
+                    })
+                    .min_by(|a, b| a.1.total_cmp(&b.1));
+
+                if let Some((text_color, ratio, section)) = worst {
+                    if ratio < self.min_ratio {
+                        self.issues.push(ContrastIssue {
+                            layer_id,
+                            rect,
+                            text: job.text.clone(),
+                            font_size: section.format.font_id.size,
+                            text_color,
+                            background,
+                            ratio,
+                        });
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn background_at(&self, pos: crate::Pos2) -> Color32 {
+        self.fills
+            .iter()
+            .filter(|(rect, _)| rect.contains(pos))
+            .fold(self.base_background, |background, (_, fill)| {
+                background.blend(*fill)
+            })
+    }
+}
+
+#[test]
+fn test_contrast_ratio() {
+    // Example from WCAG: #777 on white is just below 4.5:1
+    let ratio = contrast_ratio(Color32::from_gray(0x77), Color32::WHITE);
+    assert!((4.47..4.49).contains(&ratio), "{ratio}");
+}
+
+#[test]
+fn test_contrast_issues() {
+    let ctx = crate::Context::default();
+    let _ = ctx.run(Default::default(), |ctx| {
+        crate::CentralPanel::default().show(ctx, |ui| {
+            ui.label("Readable");
+            ui.colored_label(Color32::from_gray(40), "Unreadable");
+        });
+
+        let issues = ctx.contrast_issues(WCAG_AA);
+        assert_eq!(issues.len(), 1, "{issues:#?}");
+        assert_eq!(issues[0].text, "Unreadable");
+        assert_eq!(issues[0].background, ctx.style().visuals.panel_fill);
+    });
+}
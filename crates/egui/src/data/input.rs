@@ -77,10 +77,25 @@ pub struct RawInput {
     /// False when the user alt-tab away from the application, for instance.
     pub focused: bool,
 
+    /// The native window is completely hidden, e.g. behind other windows or on another virtual desktop.
+    ///
+    /// egui then slows down or stops repainting, see [`crate::Options::occluded_repaint`].
+    pub occluded: bool,
+
+    /// The device is running on battery, or is in a power saving mode.
+    ///
+    /// egui then slows down repainting, see [`crate::Options::power_saving_repaint`].
+    pub power_saving: bool,
+
     /// Does the OS use dark or light mode?
     ///
     /// `None` means "don't know".
     pub system_theme: Option<Theme>,
+
+    /// What the backend can do.
+    ///
+    /// The default assumes the lowest common denominator.
+    pub capabilities: BackendCapabilities,
 }
 
 impl Default for RawInput {
@@ -97,7 +112,10 @@ impl Default for RawInput {
             hovered_files: Default::default(),
             dropped_files: Default::default(),
             focused: true, // integrations opt into global focus tracking
+            occluded: false,
+            power_saving: false,
             system_theme: None,
+            capabilities: Default::default(),
         }
     }
 }
@@ -130,7 +148,10 @@ impl RawInput {
             hovered_files: self.hovered_files.clone(),
             dropped_files: std::mem::take(&mut self.dropped_files),
             focused: self.focused,
+            occluded: self.occluded,
+            power_saving: self.power_saving,
             system_theme: self.system_theme,
+            capabilities: self.capabilities,
         }
     }
 
@@ -148,7 +169,10 @@ impl RawInput {
             mut hovered_files,
             mut dropped_files,
             focused,
+            occluded,
+            power_saving,
             system_theme,
+            capabilities,
         } = newer;
 
         self.viewport_id = viewport_ids;
@@ -162,10 +186,45 @@ impl RawInput {
         self.hovered_files.append(&mut hovered_files);
         self.dropped_files.append(&mut dropped_files);
         self.focused = focused;
+        self.occluded = occluded;
+        self.power_saving = power_saving;
         self.system_theme = system_theme;
+        self.capabilities = capabilities;
     }
 }
 
+/// What the backend (renderer and platform integration) supports.
+///
+/// Set by the integration in [`RawInput::capabilities`],
+/// and read with [`crate::Context::backend_capabilities`],
+/// so that egui and its widgets can adapt instead of assuming the lowest common denominator.
+///
+/// The maximum texture size is given separately, in [`RawInput::max_texture_side`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct BackendCapabilities {
+    /// Can the renderer paint [`epaint::Primitive::Instanced`]?
+    ///
+    /// If not, egui tessellates each instance of an [`epaint::InstancedShape`] on its own.
+    pub instancing: bool,
+
+    /// Does the renderer paint into an HDR target, and can it paint [`epaint::Primitive::Hdr`]?
+    ///
+    /// If not, the intensity of [`epaint::HdrShape`]s (e.g. [`crate::style::Selection::intensity`]) is ignored.
+    pub hdr: bool,
+
+    /// Can the renderer paint [`epaint::Primitive::Composite`], i.e. paint into an offscreen target and blend it in?
+    ///
+    /// If not, the shapes of [`crate::Ui::compose`] are faded one by one.
+    pub composite: bool,
+
+    /// Does the renderer support [`epaint::PaintCallback`]s?
+    pub paint_callbacks: bool,
+
+    /// Can images be copied to the clipboard with [`crate::Context::copy_image`]?
+    pub clipboard_images: bool,
+}
+
 /// An input event from the backend into egui, about a specific [viewport](crate::viewport).
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -244,6 +303,12 @@ pub struct ViewportInfo {
     ///
     /// This should be the same as [`RawInput::focused`].
     pub focused: Option<bool>,
+
+    /// Is the window always-on-top, always-on-bottom, or neither?
+    pub window_level: Option<crate::WindowLevel>,
+
+    /// The opacity of the whole window, in `0.0..=1.0`.
+    pub opacity: Option<f32>,
 }
 
 impl ViewportInfo {
@@ -273,6 +338,8 @@ impl ViewportInfo {
             maximized: self.maximized,
             fullscreen: self.fullscreen,
             focused: self.focused,
+            window_level: self.window_level,
+            opacity: self.opacity,
         }
     }
 
@@ -289,6 +356,8 @@ impl ViewportInfo {
             maximized,
             fullscreen,
             focused,
+            window_level,
+            opacity,
         } = self;
 
         crate::Grid::new("viewport_info").show(ui, |ui| {
@@ -336,6 +405,14 @@ impl ViewportInfo {
             ui.label(opt_as_str(focused));
             ui.end_row();
 
+            ui.label("Window level:");
+            ui.label(opt_as_str(window_level));
+            ui.end_row();
+
+            ui.label("Opacity:");
+            ui.label(opt_as_str(opacity));
+            ui.end_row();
+
             fn opt_rect_as_string(v: &Option<Rect>) -> String {
                 v.as_ref().map_or(String::new(), |r| {
                     format!("Pos: {:?}, size: {:?}", r.min, r.size())
@@ -349,6 +426,41 @@ impl ViewportInfo {
     }
 }
 
+/// The outcome of a [`crate::FileDialogRequest`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum FileDialogResult {
+    /// The user picked these files or folders.
+    ///
+    /// Contains exactly one path unless [`crate::FileDialogRequest::multiple`] was set.
+    Picked(Vec<std::path::PathBuf>),
+
+    /// The user closed the dialog without picking anything.
+    Cancelled,
+
+    /// The integration can't show native file dialogs.
+    ///
+    /// egui handles this by showing its own fallback dialog,
+    /// so you will never see this in [`RawInput::events`] from user code.
+    Unsupported,
+}
+
+/// An interaction with the system tray icon.
+///
+/// See [`crate::OutputCommand::SetTrayIcon`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum TrayEvent {
+    /// The icon itself was clicked.
+    Click { button: PointerButton },
+
+    /// The icon was double-clicked with the primary button.
+    DoubleClick,
+
+    /// A [`crate::MenuItem::Button`] in the tray menu was activated.
+    MenuItem(crate::Id),
+}
+
 /// A file about to be dropped into egui.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -530,6 +642,19 @@ pub enum Event {
     /// The native window gained or lost focused (e.g. the user clicked alt-tab).
     WindowFocused(bool),
 
+    /// The reply to a [`crate::OutputCommand::FileDialog`].
+    FileDialogResult {
+        /// The [`crate::FileDialogRequest::id`] of the request.
+        id: crate::Id,
+
+        result: FileDialogResult,
+    },
+
+    /// The user interacted with the system tray icon.
+    ///
+    /// See [`crate::OutputCommand::SetTrayIcon`].
+    Tray(TrayEvent),
+
     /// An assistive technology (e.g. screen reader) requested an action.
     #[cfg(feature = "accesskit")]
     AccessKitActionRequest(accesskit::ActionRequest),
@@ -1128,7 +1253,10 @@ impl RawInput {
             hovered_files,
             dropped_files,
             focused,
+            occluded,
+            power_saving,
             system_theme,
+            capabilities,
         } = self;
 
         ui.label(format!("Active viewport: {viewport_id:?}"));
@@ -1153,7 +1281,10 @@ impl RawInput {
         ui.label(format!("hovered_files: {}", hovered_files.len()));
         ui.label(format!("dropped_files: {}", dropped_files.len()));
         ui.label(format!("focused: {focused}"));
+        ui.label(format!("occluded: {occluded}"));
+        ui.label(format!("power_saving: {power_saving}"));
         ui.label(format!("system_theme: {system_theme:?}"));
+        ui.label(format!("capabilities: {capabilities:?}"));
         ui.scope(|ui| {
             ui.set_min_height(150.0);
             ui.label(format!("events: {events:#?}"))
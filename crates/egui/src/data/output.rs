@@ -33,6 +33,19 @@ pub struct FullOutput {
     /// It is up to the integration to spawn a native window for each viewport,
     /// and to close any window that no longer has a viewport in this map.
     pub viewport_output: ViewportIdMap<ViewportOutput>,
+
+    /// Parts of the screen, in points, that look the same as in the previous frame.
+    ///
+    /// These come from [`crate::Ui::static_region`].
+    /// A backend that keeps the previous frame around may skip repainting these,
+    /// but everything outside of them must be repainted.
+    /// The shapes in them are still part of [`Self::shapes`].
+    pub unchanged_rects: Vec<crate::Rect>,
+
+    /// The color space of the vertex colors, from [`crate::Options::color_space`].
+    ///
+    /// The backend should blend accordingly.
+    pub color_space: epaint::ecolor::ColorSpace,
 }
 
 impl FullOutput {
@@ -44,12 +57,16 @@ impl FullOutput {
             shapes,
             pixels_per_point,
             viewport_output,
+            unchanged_rects,
+            color_space,
         } = newer;
 
         self.platform_output.append(platform_output);
         self.textures_delta.append(textures_delta);
         self.shapes = shapes; // Only paint the latest
         self.pixels_per_point = pixels_per_point; // Use latest
+        self.unchanged_rects = unchanged_rects; // Use latest
+        self.color_space = color_space; // Use latest
 
         for (id, new_viewport) in viewport_output {
             match self.viewport_output.entry(id) {
@@ -95,6 +112,21 @@ pub enum OutputCommand {
 
     /// Open this url in a browser.
     OpenUrl(OpenUrl),
+
+    /// Show a system tray (status bar) icon, or update the one that is already shown.
+    ///
+    /// Interactions with the icon are reported back as [`crate::Event::Tray`].
+    /// Use with [`crate::Context::set_tray_icon`].
+    SetTrayIcon(TrayIcon),
+
+    /// Remove the system tray icon, if any.
+    RemoveTrayIcon,
+
+    /// Show a native file dialog.
+    ///
+    /// The integration replies with [`crate::Event::FileDialogResult`].
+    /// Use with [`crate::Context::open_file_dialog`].
+    FileDialog(FileDialogRequest),
 }
 
 /// The non-rendering part of what egui emits each frame.
@@ -130,6 +162,8 @@ pub struct PlatformOutput {
     pub copied_text: String,
 
     /// Events that may be useful to e.g. a screen reader.
+    ///
+    /// Turn on [`crate::Options::ui_events`] for even more events.
     pub events: Vec<OutputEvent>,
 
     /// Is there a mutable [`TextEdit`](crate::TextEdit) under the cursor?
@@ -175,7 +209,9 @@ impl PlatformOutput {
                 | OutputEvent::TripleClicked(widget_info)
                 | OutputEvent::FocusGained(widget_info)
                 | OutputEvent::TextSelectionChanged(widget_info)
-                | OutputEvent::ValueChanged(widget_info) => {
+                | OutputEvent::ValueChanged(widget_info)
+                | OutputEvent::WindowOpened(widget_info)
+                | OutputEvent::WindowClosed(widget_info) => {
                     return widget_info.description();
                 }
             }
@@ -269,6 +305,276 @@ impl OpenUrl {
     }
 }
 
+/// A system tray (status bar) icon, with an optional menu.
+///
+/// Use with [`crate::Context::set_tray_icon`].
+///
+/// ```
+/// # let ctx = egui::Context::default();
+/// ctx.set_tray_icon(
+///     egui::TrayIcon::new("My app").with_menu(vec![
+///         egui::MenuItem::button(egui::Id::new("show"), "Show window"),
+///         egui::MenuItem::Separator,
+///         egui::MenuItem::button(egui::Id::new("quit"), "Quit"),
+///     ]),
+/// );
+///
+/// ctx.input(|i| {
+///     for event in &i.events {
+///         if event == &egui::Event::Tray(egui::TrayEvent::MenuItem(egui::Id::new("quit"))) {
+///             // …
+///         }
+///     }
+/// });
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct TrayIcon {
+    /// Shown when hovering the icon.
+    pub tooltip: String,
+
+    /// The icon image. If `None`, the integration picks one (usually the app icon).
+    pub icon: Option<std::sync::Arc<crate::IconData>>,
+
+    /// Shown when the icon is clicked (or right-clicked, depending on the platform).
+    ///
+    /// If empty, no menu is shown.
+    pub menu: Vec<MenuItem>,
+}
+
+impl TrayIcon {
+    pub fn new(tooltip: impl Into<String>) -> Self {
+        Self {
+            tooltip: tooltip.into(),
+            ..Default::default()
+        }
+    }
+
+    #[inline]
+    pub fn with_icon(mut self, icon: impl Into<std::sync::Arc<crate::IconData>>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    #[inline]
+    pub fn with_menu(mut self, menu: Vec<MenuItem>) -> Self {
+        self.menu = menu;
+        self
+    }
+}
+
+/// An item in a native menu, e.g. the menu of a [`TrayIcon`].
+///
+/// When the user activates a button, the integration sends back its [`crate::Id`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum MenuItem {
+    /// Something the user can click.
+    Button {
+        /// Sent back when the button is activated.
+        id: crate::Id,
+
+        label: String,
+
+        /// If `false`, the button is grayed out and can't be clicked.
+        enabled: bool,
+
+        /// If `Some`, the button is shown with a check mark when `true`.
+        checked: Option<bool>,
+    },
+
+    /// A line between groups of items.
+    Separator,
+
+    /// A nested menu.
+    Submenu { label: String, items: Vec<MenuItem> },
+}
+
+impl MenuItem {
+    /// An enabled, non-checkable button.
+    pub fn button(id: crate::Id, label: impl Into<String>) -> Self {
+        Self::Button {
+            id,
+            label: label.into(),
+            enabled: true,
+            checked: None,
+        }
+    }
+
+    /// An enabled button with a check mark.
+    pub fn checkbox(id: crate::Id, label: impl Into<String>, checked: bool) -> Self {
+        Self::Button {
+            id,
+            label: label.into(),
+            enabled: true,
+            checked: Some(checked),
+        }
+    }
+
+    pub fn submenu(label: impl Into<String>, items: Vec<Self>) -> Self {
+        Self::Submenu {
+            label: label.into(),
+            items,
+        }
+    }
+
+    /// Gray out a button. Does nothing for other items.
+    #[inline]
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        if let Self::Button { enabled: e, .. } = &mut self {
+            *e = enabled;
+        }
+        self
+    }
+}
+
+/// What kind of file dialog to show.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum FileDialogKind {
+    /// Pick one or more existing files.
+    #[default]
+    Open,
+
+    /// Pick where to save a file.
+    Save,
+
+    /// Pick one or more existing directories.
+    PickFolder,
+}
+
+/// Only show files with these extensions.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct FileDialogFilter {
+    /// Human readable name, e.g. "Images".
+    pub name: String,
+
+    /// Extensions without the leading dot, e.g. `["png", "jpg"]`.
+    pub extensions: Vec<String>,
+}
+
+impl FileDialogFilter {
+    /// Does this filter allow the given path?
+    pub fn matches(&self, path: &std::path::Path) -> bool {
+        path.extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| {
+                self.extensions
+                    .iter()
+                    .any(|allowed| allowed.eq_ignore_ascii_case(ext))
+            })
+    }
+}
+
+/// Ask the integration to show a native file dialog.
+///
+/// Use with [`crate::Context::open_file_dialog`].
+/// The answer arrives as a [`crate::Event::FileDialogResult`] with the same [`Self::id`],
+/// usually a few frames later.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// let id = egui::Id::new("open_image");
+/// if ui.button("Open image…").clicked() {
+///     ui.ctx().open_file_dialog(
+///         egui::FileDialogRequest::open(id).with_filter("Images", &["png", "jpg"]),
+///     );
+/// }
+/// if let Some(egui::FileDialogResult::Picked(paths)) = ui.input(|i| i.file_dialog_result(id)) {
+///     // …
+/// }
+/// # });
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct FileDialogRequest {
+    /// Identifies the reply, see [`crate::Event::FileDialogResult`].
+    pub id: crate::Id,
+
+    pub kind: FileDialogKind,
+
+    /// Title of the dialog window. If `None`, the integration picks one.
+    pub title: Option<String>,
+
+    /// If non-empty, only show files matching one of these.
+    pub filters: Vec<FileDialogFilter>,
+
+    /// The directory to start in.
+    pub directory: Option<std::path::PathBuf>,
+
+    /// The suggested file name, mostly useful for [`FileDialogKind::Save`].
+    pub file_name: Option<String>,
+
+    /// Can the user pick more than one file or folder?
+    ///
+    /// Ignored for [`FileDialogKind::Save`].
+    pub multiple: bool,
+}
+
+impl FileDialogRequest {
+    pub fn new(id: crate::Id, kind: FileDialogKind) -> Self {
+        Self {
+            id,
+            kind,
+            title: None,
+            filters: Vec::new(),
+            directory: None,
+            file_name: None,
+            multiple: false,
+        }
+    }
+
+    /// Pick one existing file (or several, with [`Self::with_multiple`]).
+    pub fn open(id: crate::Id) -> Self {
+        Self::new(id, FileDialogKind::Open)
+    }
+
+    /// Pick where to save a file.
+    pub fn save(id: crate::Id) -> Self {
+        Self::new(id, FileDialogKind::Save)
+    }
+
+    /// Pick one existing directory (or several, with [`Self::with_multiple`]).
+    pub fn pick_folder(id: crate::Id) -> Self {
+        Self::new(id, FileDialogKind::PickFolder)
+    }
+
+    #[inline]
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Add a filter, e.g. `.with_filter("Images", &["png", "jpg"])`.
+    #[inline]
+    pub fn with_filter(mut self, name: impl Into<String>, extensions: &[&str]) -> Self {
+        self.filters.push(FileDialogFilter {
+            name: name.into(),
+            extensions: extensions.iter().map(|ext| (*ext).to_owned()).collect(),
+        });
+        self
+    }
+
+    #[inline]
+    pub fn with_directory(mut self, directory: impl Into<std::path::PathBuf>) -> Self {
+        self.directory = Some(directory.into());
+        self
+    }
+
+    #[inline]
+    pub fn with_file_name(mut self, file_name: impl Into<String>) -> Self {
+        self.file_name = Some(file_name.into());
+        self
+    }
+
+    #[inline]
+    pub fn with_multiple(mut self, multiple: bool) -> Self {
+        self.multiple = multiple;
+        self
+    }
+}
+
 /// Types of attention to request from a user when a native window is not in focus.
 ///
 /// See [winit's documentation][user_attention_type] for platform-specific meaning of the attention types.
@@ -483,7 +789,19 @@ pub enum OutputEvent {
     TextSelectionChanged(WidgetInfo),
 
     /// A widget's value changed.
+    ///
+    /// The old value is in [`WidgetInfo::prev_value`] or [`WidgetInfo::prev_text_value`], if known.
     ValueChanged(WidgetInfo),
+
+    /// A [`crate::Window`] was opened.
+    ///
+    /// Only emitted if [`crate::Options::ui_events`] is set.
+    WindowOpened(WidgetInfo),
+
+    /// A [`crate::Window`] was closed.
+    ///
+    /// Only emitted if [`crate::Options::ui_events`] is set.
+    WindowClosed(WidgetInfo),
 }
 
 impl OutputEvent {
@@ -494,7 +812,9 @@ impl OutputEvent {
             | Self::TripleClicked(info)
             | Self::FocusGained(info)
             | Self::TextSelectionChanged(info)
-            | Self::ValueChanged(info) => info,
+            | Self::ValueChanged(info)
+            | Self::WindowOpened(info)
+            | Self::WindowClosed(info) => info,
         }
     }
 }
@@ -508,6 +828,8 @@ impl std::fmt::Debug for OutputEvent {
             Self::FocusGained(wi) => write!(f, "FocusGained({wi:?})"),
             Self::TextSelectionChanged(wi) => write!(f, "TextSelectionChanged({wi:?})"),
             Self::ValueChanged(wi) => write!(f, "ValueChanged({wi:?})"),
+            Self::WindowOpened(wi) => write!(f, "WindowOpened({wi:?})"),
+            Self::WindowClosed(wi) => write!(f, "WindowClosed({wi:?})"),
         }
     }
 }
@@ -537,6 +859,9 @@ pub struct WidgetInfo {
     /// The current value of sliders etc.
     pub value: Option<f64>,
 
+    /// The previous value of sliders etc, if it changed.
+    pub prev_value: Option<f64>,
+
     /// Selected range of characters in [`Self::current_text_value`].
     pub text_selection: Option<std::ops::RangeInclusive<usize>>,
 
@@ -554,6 +879,7 @@ impl std::fmt::Debug for WidgetInfo {
             prev_text_value,
             selected,
             value,
+            prev_value,
             text_selection,
             hint_text,
         } = self;
@@ -581,6 +907,9 @@ impl std::fmt::Debug for WidgetInfo {
         if let Some(value) = value {
             s.field("value", value);
         }
+        if let Some(prev_value) = prev_value {
+            s.field("prev_value", prev_value);
+        }
         if let Some(text_selection) = text_selection {
             s.field("text_selection", text_selection);
         }
@@ -602,6 +931,7 @@ impl WidgetInfo {
             prev_text_value: None,
             selected: None,
             value: None,
+            prev_value: None,
             text_selection: None,
             hint_text: None,
         }
@@ -646,6 +976,15 @@ impl WidgetInfo {
         }
     }
 
+    /// Set [`Self::prev_value`], if it differs from [`Self::value`].
+    #[inline]
+    pub fn with_prev_value(mut self, prev_value: f64) -> Self {
+        if self.value != Some(prev_value) {
+            self.prev_value = Some(prev_value);
+        }
+        self
+    }
+
     #[expect(clippy::needless_pass_by_value)]
     pub fn text_edit(
         enabled: bool,
@@ -694,6 +1033,7 @@ impl WidgetInfo {
             prev_text_value: _,
             selected,
             value,
+            prev_value: _,
             text_selection: _,
             hint_text: _,
         } = self;
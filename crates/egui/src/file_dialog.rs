@@ -0,0 +1,267 @@
+//! A pure-egui fallback for [`crate::OutputCommand::FileDialog`],
+//! used when the integration can't show native file dialogs.
+
+use std::path::PathBuf;
+
+use crate::{
+    Align2, Context, Event, FileDialogKind, FileDialogRequest, FileDialogResult, Id, IdMap,
+    TextEdit, Window,
+};
+
+/// Keeps track of file dialogs requested with [`Context::open_file_dialog`].
+#[derive(Default)]
+pub(crate) struct FileDialogs {
+    /// Sent to the integration, waiting for a reply.
+    pending: IdMap<FileDialogRequest>,
+
+    /// Shown by egui itself, because the integration replied with [`FileDialogResult::Unsupported`].
+    fallbacks: Vec<FallbackFileDialog>,
+
+    /// Results of closed fallback dialogs, delivered as events at the start of the next pass.
+    finished: Vec<Event>,
+}
+
+impl FileDialogs {
+    pub(crate) fn request(&mut self, request: FileDialogRequest) {
+        self.pending.insert(request.id, request);
+    }
+
+    /// Intercept [`FileDialogResult::Unsupported`] replies, and deliver the results of fallback dialogs.
+    pub(crate) fn begin_pass(&mut self, events: &mut Vec<Event>) {
+        events.retain(|event| {
+            let Event::FileDialogResult { id, result } = event else {
+                return true;
+            };
+            let Some(request) = self.pending.remove(id) else {
+                return true;
+            };
+            if result == &FileDialogResult::Unsupported {
+                self.fallbacks.push(FallbackFileDialog::new(request));
+                false
+            } else {
+                true
+            }
+        });
+
+        events.append(&mut self.finished);
+    }
+
+    pub(crate) fn has_fallbacks(&self) -> bool {
+        !self.fallbacks.is_empty()
+    }
+
+    /// Show all the fallback dialogs. Called at the end of each pass.
+    pub(crate) fn show_fallbacks(&mut self, ctx: &Context) {
+        self.fallbacks.retain_mut(|dialog| {
+            if let Some(result) = dialog.show(ctx) {
+                self.finished.push(Event::FileDialogResult {
+                    id: dialog.request.id,
+                    result,
+                });
+                false
+            } else {
+                true
+            }
+        });
+
+        if !self.finished.is_empty() {
+            // Make sure the results are delivered:
+            ctx.request_repaint();
+        }
+    }
+}
+
+struct FallbackFileDialog {
+    request: FileDialogRequest,
+
+    /// One path per line.
+    paths: String,
+
+    #[cfg(feature = "std-fs")]
+    browser: crate::FileBrowser,
+}
+
+impl FallbackFileDialog {
+    fn new(request: FileDialogRequest) -> Self {
+        #[cfg(feature = "std-fs")]
+        let browser = {
+            let mut browser = crate::FileBrowser::std()
+                .id_salt(request.id)
+                .with_filters(request.filters.clone())
+                .pick_folders(request.kind == FileDialogKind::PickFolder)
+                .multiple(request.multiple && request.kind != FileDialogKind::Save);
+            if let Some(directory) = &request.directory {
+                browser = browser.with_directory(directory.clone());
+            }
+            browser
+        };
+
+        // Without a browser, the user has to type the path, so give them a head start:
+        #[cfg(not(feature = "std-fs"))]
+        let paths = {
+            let mut path = request.directory.clone().unwrap_or_default();
+            if let Some(file_name) = &request.file_name {
+                path.push(file_name);
+            }
+            path.to_string_lossy().into_owned()
+        };
+
+        #[cfg(feature = "std-fs")]
+        let paths = match &request.file_name {
+            Some(file_name) if request.kind == FileDialogKind::Save => browser
+                .current_dir()
+                .join(file_name)
+                .to_string_lossy()
+                .into_owned(),
+            _ => String::new(),
+        };
+
+        Self {
+            request,
+            paths,
+            #[cfg(feature = "std-fs")]
+            browser,
+        }
+    }
+
+    /// Returns the result when the dialog is closed.
+    fn show(&mut self, ctx: &Context) -> Option<FileDialogResult> {
+        let request = &self.request;
+        let multiple = request.multiple && request.kind != FileDialogKind::Save;
+        let (default_title, confirm) = match request.kind {
+            FileDialogKind::Open => (
+                ctx.tr("file_dialog.title_open", "Open file"),
+                ctx.tr("file_dialog.open", "Open"),
+            ),
+            FileDialogKind::Save => (
+                ctx.tr("file_dialog.title_save", "Save file"),
+                ctx.tr("file_dialog.save", "Save"),
+            ),
+            FileDialogKind::PickFolder => (
+                ctx.tr("file_dialog.title_pick_folder", "Select folder"),
+                ctx.tr("file_dialog.select", "Select"),
+            ),
+        };
+
+        let mut open = true;
+        let mut result = None;
+
+        let window = Window::new(request.title.clone().unwrap_or(default_title))
+            .id(Id::new("__egui_file_dialog").with(request.id))
+            .open(&mut open)
+            .collapsible(false)
+            .anchor(Align2::CENTER_CENTER, [0.0, 0.0]);
+
+        #[cfg(feature = "std-fs")]
+        let window = window.default_size([640.0, 400.0]);
+        #[cfg(not(feature = "std-fs"))]
+        let window = window.resizable(false);
+
+        window.show(ctx, |ui| {
+            crate::TopBottomPanel::bottom(Id::new("__egui_file_dialog_bottom").with(request.id))
+                .show_inside(ui, |ui| {
+                    ui.add_space(4.0);
+                    if multiple {
+                        ui.label(ctx.tr("file_dialog.paths", "Paths, one per line:"));
+                        ui.add(TextEdit::multiline(&mut self.paths).desired_width(f32::INFINITY));
+                    } else {
+                        ui.label(ctx.tr("file_dialog.path", "Path:"));
+                        ui.add(TextEdit::singleline(&mut self.paths).desired_width(f32::INFINITY));
+                    }
+
+                    #[cfg(not(feature = "std-fs"))]
+                    for filter in &request.filters {
+                        let extensions = filter
+                            .extensions
+                            .iter()
+                            .map(|ext| format!("*.{ext}"))
+                            .collect::<Vec<_>>()
+                            .join(", ");
+                        ui.weak(format!("{} ({extensions})", filter.name));
+                    }
+
+                    let picked = parse_paths(&self.paths, multiple);
+                    ui.horizontal(|ui| {
+                        if ui
+                            .add_enabled(!picked.is_empty(), crate::Button::new(confirm))
+                            .clicked()
+                        {
+                            result = Some(FileDialogResult::Picked(picked));
+                        }
+                        if ui.button(ctx.tr("file_dialog.cancel", "Cancel")).clicked() {
+                            result = Some(FileDialogResult::Cancelled);
+                        }
+                    });
+                });
+
+            #[cfg(feature = "std-fs")]
+            {
+                let response = self.browser.show(ui);
+                if response.selection_changed {
+                    self.paths = self
+                        .browser
+                        .selected()
+                        .iter()
+                        .map(|path| path.to_string_lossy())
+                        .collect::<Vec<_>>()
+                        .join("\n");
+                }
+                if response.dir_changed && request.kind == FileDialogKind::Save {
+                    // Keep the file name, but save it in the new directory:
+                    if let Some(file_name) = PathBuf::from(&self.paths).file_name() {
+                        self.paths = self
+                            .browser
+                            .current_dir()
+                            .join(file_name)
+                            .to_string_lossy()
+                            .into_owned();
+                    }
+                }
+                if let Some(path) = response.activated {
+                    result = Some(FileDialogResult::Picked(vec![path]));
+                }
+            }
+        });
+
+        if !open {
+            result = Some(FileDialogResult::Cancelled);
+        }
+        result
+    }
+}
+
+/// One path per line if `multiple`, otherwise the whole text is a single path.
+fn parse_paths(text: &str, multiple: bool) -> Vec<PathBuf> {
+    if multiple {
+        text.lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(PathBuf::from)
+            .collect()
+    } else {
+        let path = text.trim();
+        if path.is_empty() {
+            vec![]
+        } else {
+            vec![PathBuf::from(path)]
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_paths() {
+        assert!(parse_paths("  ", false).is_empty());
+        assert_eq!(
+            parse_paths(" a b.txt\n", false),
+            vec![PathBuf::from("a b.txt")]
+        );
+        assert_eq!(
+            parse_paths("a.txt\n\n  b.txt \n", true),
+            vec![PathBuf::from("a.txt"), PathBuf::from("b.txt")]
+        );
+    }
+}
@@ -0,0 +1,270 @@
+//! Every built-in widget in all its states, see [`show_all_widgets`].
+//!
+//! This is useful as a preview when designing a theme,
+//! for generating screenshots, and for snapshot tests of egui itself.
+
+use crate::{
+    Button, Checkbox, CollapsingHeader, Color32, ColorImage, ComboBox, DragValue, Grid, Hyperlink,
+    Image, ImageButton, Label, Link, ProgressBar, RadioButton, Separator, Slider, Spinner,
+    TextEdit, TextureHandle, TextureOptions, Ui, style::WidgetState, vec2,
+};
+
+/// The states [`show_all_widgets`] shows each widget in.
+///
+/// Hovered and active are simulated by painting the widget with the visuals of that state,
+/// so they look the same no matter where the mouse is.
+const STATES: [WidgetState; 4] = [
+    WidgetState::Inactive,
+    WidgetState::Hovered,
+    WidgetState::Active,
+    WidgetState::Disabled,
+];
+
+/// Make the widgets in this [`Ui`] look like they are in this state.
+fn apply(state: WidgetState, ui: &mut Ui) {
+    let widgets = &mut ui.visuals_mut().widgets;
+    match state {
+        WidgetState::Noninteractive | WidgetState::Inactive => {}
+        WidgetState::Hovered => widgets.inactive = widgets.hovered,
+        WidgetState::Active => widgets.inactive = widgets.active,
+        WidgetState::Focused => widgets.inactive = widgets.focused,
+        WidgetState::Disabled => ui.disable(),
+    }
+}
+
+/// Show every built-in widget as inactive, hovered, active and disabled, in a grid.
+///
+/// The widgets are given the same values every frame,
+/// so the result only depends on the style (and the time, for animated widgets like [`Spinner`]).
+/// Interacting with them has no lasting effect.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// egui::gallery::show_all_widgets(ui);
+/// # });
+/// ```
+pub fn show_all_widgets(ui: &mut Ui) {
+    Grid::new("egui_gallery")
+        .striped(true)
+        .num_columns(1 + STATES.len())
+        .show(ui, |ui| {
+            ui.label("");
+            for state in STATES {
+                ui.strong(format!("{state:?}"));
+            }
+            ui.end_row();
+
+            for (name, add_widget) in widgets() {
+                ui.label(name);
+                for state in STATES {
+                    ui.push_id(state, |ui| {
+                        apply(state, ui);
+                        add_widget(ui);
+                    });
+                }
+                ui.end_row();
+            }
+        });
+}
+
+/// All the widgets, with a name for each.
+fn widgets() -> Vec<(&'static str, fn(&mut Ui))> {
+    vec![
+        ("Label", |ui| {
+            ui.add(Label::new("Some text"));
+        }),
+        ("Weak label", |ui| {
+            ui.weak("Some weak text");
+        }),
+        ("Link", |ui| {
+            ui.add(Link::new("A link"));
+        }),
+        ("Hyperlink", |ui| {
+            ui.add(Hyperlink::from_label_and_url(
+                "egui",
+                "https://github.com/emilk/egui",
+            ));
+        }),
+        ("Button", |ui| {
+            ui.add(Button::new("Button"));
+        }),
+        ("Selected button", |ui| {
+            ui.add(Button::new("Button").selected(true));
+        }),
+        ("Frameless button", |ui| {
+            ui.add(Button::new("Button").frame(false));
+        }),
+        ("Image button", |ui| {
+            ui.add(ImageButton::new(sized_image(ui)));
+        }),
+        ("Image", |ui| {
+            ui.add(sized_image(ui));
+        }),
+        ("Checkbox", |ui| {
+            ui.add(Checkbox::new(&mut false, "Unchecked"));
+        }),
+        ("Checked checkbox", |ui| {
+            ui.add(Checkbox::new(&mut true, "Checked"));
+        }),
+        ("Indeterminate checkbox", |ui| {
+            ui.add(Checkbox::new(&mut false, "Indeterminate").indeterminate(true));
+        }),
+        ("Radio button", |ui| {
+            ui.add(RadioButton::new(false, "Unselected"));
+        }),
+        ("Selected radio button", |ui| {
+            ui.add(RadioButton::new(true, "Selected"));
+        }),
+        ("Selectable label", |ui| {
+            ui.add(Button::selectable(false, "Unselected"));
+        }),
+        ("Selected selectable label", |ui| {
+            ui.add(Button::selectable(true, "Selected"));
+        }),
+        ("Slider", |ui| {
+            ui.add(Slider::new(&mut 42.0, 0.0..=100.0));
+        }),
+        ("Drag value", |ui| {
+            ui.add(DragValue::new(&mut 42.0));
+        }),
+        ("Text edit", |ui| {
+            ui.add(TextEdit::singleline(&mut String::from("Some text")).desired_width(100.0));
+        }),
+        ("Text edit hint", |ui| {
+            ui.add(
+                TextEdit::singleline(&mut String::new())
+                    .hint_text("Hint")
+                    .desired_width(100.0),
+            );
+        }),
+        ("Combo box", |ui| {
+            ComboBox::from_id_salt("combo_box")
+                .selected_text("First")
+                .show_ui(ui, |ui| {
+                    ui.add(Button::selectable(true, "First"));
+                    ui.add(Button::selectable(false, "Second"));
+                });
+        }),
+        ("Color button", |ui| {
+            ui.color_edit_button_srgba(&mut Color32::from_rgb(100, 150, 250));
+        }),
+        ("Collapsing header", |ui| {
+            CollapsingHeader::new("Collapsed").show(ui, |ui| {
+                ui.label("Hidden");
+            });
+        }),
+        ("Progress bar", |ui| {
+            ui.add(ProgressBar::new(0.4).desired_width(100.0).show_percentage());
+        }),
+        ("Spinner", |ui| {
+            ui.add(Spinner::new());
+        }),
+        ("Separator", |ui| {
+            ui.add(Separator::default().horizontal().spacing(0.0));
+        }),
+    ]
+}
+
+/// A small checkerboard image, for the image widgets.
+fn sized_image(ui: &Ui) -> Image<'static> {
+    let id = crate::Id::new("egui_gallery_texture");
+    let texture = ui.data(|data| data.get_temp::<TextureHandle>(id));
+    let texture = texture.unwrap_or_else(|| {
+        let pixels = (0..64)
+            .map(|i| {
+                if (i % 8 + i / 8) % 2 == 0 {
+                    Color32::WHITE
+                } else {
+                    Color32::GRAY
+                }
+            })
+            .collect();
+        let texture = ui.ctx().load_texture(
+            "egui_gallery",
+            ColorImage::new([8, 8], pixels),
+            TextureOptions::NEAREST,
+        );
+        ui.data_mut(|data| data.insert_temp(id, texture.clone()));
+        texture
+    });
+    Image::from_texture((texture.id(), vec2(16.0, 16.0)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CentralPanel, Context, WidgetType};
+
+    /// Make sure we don't forget to add new widgets to the gallery.
+    #[cfg(debug_assertions)] // Widget infos are only registered for all widgets in debug builds
+    #[test]
+    fn test_gallery_shows_all_widget_types() {
+        let ctx = Context::default();
+        ctx.style_mut(|style| style.debug.show_interactive_widgets = true);
+
+        let mut types = Vec::new();
+        for _ in 0..2 {
+            let _ = ctx.run(Default::default(), |ctx| {
+                CentralPanel::default().show(ctx, show_all_widgets);
+            });
+        }
+        ctx.viewport(|viewport| {
+            let widgets = &viewport.prev_pass.widgets;
+            for layer_id in widgets.layer_ids() {
+                for widget in widgets.get_layer(layer_id) {
+                    if let Some(info) = widgets.info(widget.id) {
+                        types.push(info.typ);
+                    }
+                }
+            }
+        });
+
+        let should_be_in_gallery = |typ| match typ {
+            WidgetType::Label
+            | WidgetType::Link
+            | WidgetType::TextEdit
+            | WidgetType::Button
+            | WidgetType::Checkbox
+            | WidgetType::RadioButton
+            | WidgetType::ComboBox
+            | WidgetType::Slider
+            | WidgetType::DragValue
+            | WidgetType::ColorButton
+            | WidgetType::ImageButton
+            | WidgetType::Image
+            | WidgetType::CollapsingHeader
+            | WidgetType::ProgressIndicator => true,
+
+            // Containers, or not emitted by any built-in widget:
+            WidgetType::RadioGroup
+            | WidgetType::SelectableLabel
+            | WidgetType::Window
+            | WidgetType::Other => false,
+        };
+
+        for typ in [
+            WidgetType::Label,
+            WidgetType::Link,
+            WidgetType::TextEdit,
+            WidgetType::Button,
+            WidgetType::Checkbox,
+            WidgetType::RadioButton,
+            WidgetType::RadioGroup,
+            WidgetType::SelectableLabel,
+            WidgetType::ComboBox,
+            WidgetType::Slider,
+            WidgetType::DragValue,
+            WidgetType::ColorButton,
+            WidgetType::ImageButton,
+            WidgetType::Image,
+            WidgetType::CollapsingHeader,
+            WidgetType::ProgressIndicator,
+            WidgetType::Window,
+            WidgetType::Other,
+        ] {
+            if should_be_in_gallery(typ) {
+                assert!(types.contains(&typ), "{typ:?} is missing from the gallery");
+            }
+        }
+    }
+}
@@ -1,8 +1,35 @@
+//! Finding which widget is under the pointer.
+//!
+//! ## Precedence
+//! Widgets are hit-tested back-to-front:
+//! * Layers are ordered as in [`crate::Memory::layer_ids`], and only the top-most layers
+//!   under the pointer are considered.
+//! * Within a layer, widgets added later are on top of widgets added earlier,
+//!   except that a widget registered with [`crate::Ui::interact_bg_behind_children`]
+//!   is always behind the widgets it contains.
+//!
+//! Widgets with [`HitTestMode::Transparent`] are ignored completely.
+//! Widgets with [`HitTestMode::ChildrenOnly`] are never hit themselves,
+//! but still hide the layers behind them.
+//!
+//! A widget that is hit directly wins over one that is only close to the pointer,
+//! and of two direct hits the top one wins, unless the other one has a higher
+//! [`WidgetRect::interact_priority`].
+//! Widgets are hit within their [`WidgetRect::interact_rect`], expanded by [`WidgetRect::interact_expand`].
+//! The exceptions (e.g. helping the user hit a small button on a big draggable background)
+//! are documented in [`hit_test`].
+//!
+//! Hovering is decided in `interaction.rs`: all non-interactive widgets on top of
+//! the clicked/dragged widget are hovered, and so are the widgets behind their children
+//! that contain the pointer.
+
 use ahash::HashMap;
 
 use emath::TSTransform;
 
-use crate::{LayerId, Pos2, Rect, Sense, WidgetRect, WidgetRects, ahash, emath, id::IdSet};
+use crate::{
+    HitTestMode, LayerId, Pos2, Rect, Sense, WidgetRect, WidgetRects, ahash, emath, id::IdSet,
+};
 
 /// Result of a hit-test against [`WidgetRects`].
 ///
@@ -64,8 +91,15 @@ pub fn hit_test(
         .iter()
         .filter(|layer| layer.order.allow_interaction())
         .flat_map(|&layer_id| widgets.get_layer(layer_id))
-        .filter(|&w| {
-            if w.interact_rect.is_negative() || w.interact_rect.any_nan() {
+        .map(|w| WidgetRect {
+            interact_rect: w.hit_test_rect(),
+            ..*w
+        })
+        .filter(|w| {
+            if w.interact_rect.is_negative()
+                || w.interact_rect.any_nan()
+                || w.hit_test_mode == HitTestMode::Transparent
+            {
                 return false;
             }
 
@@ -76,12 +110,11 @@ pub fn hit_test(
             // In tie, pick last = topmost.
             if dist_sq <= closest_dist_sq {
                 closest_dist_sq = dist_sq;
-                closest_hit = Some(w);
+                closest_hit = Some(*w);
             }
 
             dist_sq <= search_radius_sq
         })
-        .copied()
         .collect();
 
     // Transform to global coordinates:
@@ -125,6 +158,8 @@ pub fn hit_test(
 
     close.retain(|hit| included_layers.contains(&hit.layer_id));
 
+    move_behind_children(&mut close, widgets);
+
     // If a widget is disabled, treat it as if it isn't sensing anything.
     // This simplifies the code in `hit_test_on_close` so it doesn't have to check
     // the `enabled` flag everywhere:
@@ -152,6 +187,9 @@ pub fn hit_test(
 
     close.retain(|c| !hidden.contains(&c.id));
 
+    // These have done their job of hiding the layers behind them:
+    close.retain(|c| c.hit_test_mode != HitTestMode::ChildrenOnly);
+
     let mut hits = hit_test_on_close(&close, pos);
 
     hits.contains_pointer = close
@@ -195,6 +233,29 @@ pub fn hit_test(
     hits
 }
 
+/// Move each widget registered with [`WidgetRects::set_behind_children`]
+/// to just behind the first of its children.
+///
+/// Usually a [`crate::Ui`] background is added before the widgets in it, but not always,
+/// and we don't want the result to depend on the order.
+fn move_behind_children(close: &mut Vec<WidgetRect>, widgets: &WidgetRects) {
+    let mut i = 0;
+    while i < close.len() {
+        let background = close[i];
+        if widgets.is_behind_children(background.id) {
+            let first_child = close[..i].iter().position(|w| {
+                w.layer_id == background.layer_id
+                    && background.interact_rect.contains_rect(w.interact_rect)
+            });
+            if let Some(first_child) = first_child {
+                close.remove(i);
+                close.insert(first_child, background);
+            }
+        }
+        i += 1;
+    }
+}
+
 /// Returns true if the rectangle contains the whole circle.
 fn contains_circle(interact_rect: emath::Rect, pos: Pos2, radius: f32) -> bool {
     interact_rect.shrink(radius).contains(pos)
@@ -365,7 +426,12 @@ fn hit_test_on_close(close: &[WidgetRect], pos: Pos2) -> WidgetHits {
             let click_idx = close.iter().position(|w| *w == hit_click).unwrap();
             let drag_idx = close.iter().position(|w| *w == hit_drag).unwrap();
 
-            let click_is_on_top_of_drag = drag_idx < click_idx;
+            let click_is_on_top_of_drag =
+                if hit_click.interact_priority == hit_drag.interact_priority {
+                    drag_idx < click_idx
+                } else {
+                    hit_drag.interact_priority < hit_click.interact_priority
+                };
             if click_is_on_top_of_drag {
                 if hit_click.sense.senses_drag() {
                     // The top thing senses both clicks and drags.
@@ -427,10 +493,20 @@ fn find_closest_within(
 
         if let Some(closest) = closest {
             if dist_sq == closest_dist_sq {
-                // It's a tie! Pick the thin candidate over the thick one.
-                // This makes it easier to hit a thin resize-handle, for instance:
-                if should_prioritize_hits_on_back(closest.interact_rect, widget.interact_rect) {
-                    continue;
+                // It's a tie! Pick the one with the highest priority.
+                match widget.interact_priority.cmp(&closest.interact_priority) {
+                    std::cmp::Ordering::Less => continue,
+                    std::cmp::Ordering::Greater => {}
+                    std::cmp::Ordering::Equal => {
+                        // Pick the thin candidate over the thick one.
+                        // This makes it easier to hit a thin resize-handle, for instance:
+                        if should_prioritize_hits_on_back(
+                            closest.interact_rect,
+                            widget.interact_rect,
+                        ) {
+                            continue;
+                        }
+                    }
                 }
             }
         }
@@ -470,7 +546,7 @@ fn should_prioritize_hits_on_back(back: Rect, front: Rect) -> bool {
 mod tests {
     use emath::{Rect, pos2, vec2};
 
-    use crate::{Id, Sense};
+    use crate::{Id, Margin, Sense};
 
     use super::*;
 
@@ -482,6 +558,9 @@ mod tests {
             interact_rect: rect,
             sense,
             enabled: true,
+            hit_test_mode: HitTestMode::Opaque,
+            interact_priority: 0,
+            interact_expand: Default::default(),
         }
     }
 
@@ -526,6 +605,140 @@ mod tests {
         assert_eq!(hits.drag.unwrap().id, Id::new("click-and-drag"));
     }
 
+    #[test]
+    fn interact_priority_and_expand() {
+        let area = wr(
+            Id::new("area"),
+            Sense::click_and_drag(),
+            Rect::from_min_size(pos2(0.0, 0.0), vec2(100.0, 100.0)),
+        );
+        let handle = wr(
+            Id::new("handle"),
+            Sense::drag(),
+            Rect::from_min_size(pos2(100.0, 0.0), vec2(2.0, 100.0)),
+        );
+
+        let hit = |handle: WidgetRect| {
+            let mut widgets = WidgetRects::default();
+            widgets.insert(handle.layer_id, handle);
+            widgets.insert(area.layer_id, area);
+            hit_test(
+                &widgets,
+                &[LayerId::background()],
+                &Default::default(),
+                pos2(98.0, 50.0),
+                0.0,
+            )
+        };
+
+        // The area is on top, and hit directly:
+        let hits = hit(handle);
+        assert_eq!(hits.drag.unwrap().id, area.id);
+
+        // The expanded handle is also hit directly, but the area is on top:
+        let handle = WidgetRect {
+            interact_expand: Margin::same(4),
+            ..handle
+        };
+        let hits = hit(handle);
+        assert_eq!(hits.drag.unwrap().id, area.id);
+
+        // …unless the handle has a higher priority:
+        let hits = hit(WidgetRect {
+            interact_priority: 1,
+            ..handle
+        });
+        assert_eq!(hits.click, None);
+        assert_eq!(hits.drag.unwrap().id, handle.id);
+        assert_eq!(
+            hits.drag.unwrap().interact_rect,
+            handle.interact_rect,
+            "Returns the original rect"
+        );
+    }
+
+    #[test]
+    fn background_behind_children() {
+        let mut widgets = WidgetRects::default();
+        let button = wr(
+            Id::new("button"),
+            Sense::click(),
+            Rect::from_min_size(pos2(10.0, 10.0), vec2(10.0, 10.0)),
+        );
+        let background = wr(
+            Id::new("background"),
+            Sense::click(),
+            Rect::from_min_size(pos2(0.0, 0.0), vec2(100.0, 30.0)),
+        );
+
+        // The background is added last, and would normally be on top:
+        widgets.insert(button.layer_id, button);
+        widgets.insert(background.layer_id, background);
+        widgets.set_behind_children(background.id);
+
+        let layers = [LayerId::background()];
+        let hit = |pos| hit_test(&widgets, &layers, &Default::default(), pos, 5.0);
+
+        assert_eq!(hit(pos2(15.0, 15.0)).click.unwrap().id, button.id);
+        assert_eq!(hit(pos2(50.0, 15.0)).click.unwrap().id, background.id);
+        assert_eq!(hit(pos2(15.0, 15.0)).contains_pointer.len(), 2);
+    }
+
+    #[test]
+    fn hit_test_modes() {
+        let button = wr(
+            Id::new("button"),
+            Sense::click(),
+            Rect::from_min_size(pos2(10.0, 10.0), vec2(10.0, 10.0)),
+        );
+        let overlay_layer = LayerId::new(crate::Order::Foreground, Id::new("overlay"));
+        let overlay = WidgetRect {
+            layer_id: overlay_layer,
+            ..wr(
+                Id::new("overlay"),
+                Sense::hover(),
+                Rect::from_min_size(pos2(0.0, 0.0), vec2(100.0, 100.0)),
+            )
+        };
+        let layers = [LayerId::background(), overlay_layer];
+
+        let hit_with_overlay = |hit_test_mode| {
+            let mut widgets = WidgetRects::default();
+            widgets.insert(button.layer_id, button);
+            widgets.insert(
+                overlay_layer,
+                WidgetRect {
+                    hit_test_mode,
+                    ..overlay
+                },
+            );
+            hit_test(
+                &widgets,
+                &layers,
+                &Default::default(),
+                pos2(15.0, 15.0),
+                5.0,
+            )
+        };
+
+        // The overlay hides the button:
+        let hits = hit_with_overlay(HitTestMode::Opaque);
+        assert_eq!(hits.click, None);
+        assert_eq!(hits.contains_pointer.len(), 1);
+        assert_eq!(hits.contains_pointer[0].id, overlay.id);
+
+        // The clicks go through the overlay:
+        let hits = hit_with_overlay(HitTestMode::Transparent);
+        assert_eq!(hits.click.unwrap().id, button.id);
+        assert_eq!(hits.contains_pointer.len(), 1);
+        assert_eq!(hits.contains_pointer[0].id, button.id);
+
+        // The overlay still hides the button, but isn't hovered itself:
+        let hits = hit_with_overlay(HitTestMode::ChildrenOnly);
+        assert_eq!(hits.click, None);
+        assert!(hits.contains_pointer.is_empty());
+    }
+
     #[test]
     fn thin_resize_handle_next_to_label() {
         let widgets = vec![
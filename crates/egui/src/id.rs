@@ -53,22 +53,76 @@ impl Id {
     }
 
     /// Generate a new [`Id`] by hashing some source (e.g. a string or integer).
+    ///
+    /// With the `id_names` feature, the call site is registered as the debug name of the [`Id`],
+    /// unless it already has one.
+    #[cfg_attr(feature = "id_names", track_caller)]
     pub fn new(source: impl std::hash::Hash) -> Self {
-        Self::from_hash(ahash::RandomState::with_seeds(1, 2, 3, 4).hash_one(source))
+        let id = Self::from_hash(ahash::RandomState::with_seeds(1, 2, 3, 4).hash_one(source));
+        #[cfg(feature = "id_names")]
+        names::register_location(id, None);
+        id
+    }
+
+    /// Generate a new [`Id`] from a human-readable name.
+    ///
+    /// This is the same [`Id`] as `Id::new(name)`, but with the `id_names` feature
+    /// the name is also registered, so that debug output such as id-clash warnings,
+    /// [`crate::Context::memory_ui`] and [`crate::Context::inspection_ui`]
+    /// shows `inventory_window` instead of an opaque hash.
+    ///
+    /// ```
+    /// # use egui::Id;
+    /// assert_eq!(Id::named("inventory_window"), Id::new("inventory_window"));
+    /// ```
+    pub fn named(name: &str) -> Self {
+        let id = Self::from_hash(ahash::RandomState::with_seeds(1, 2, 3, 4).hash_one(name));
+        #[cfg(feature = "id_names")]
+        names::register(id, name.to_owned());
+        id
     }
 
     /// Generate a new [`Id`] by hashing the parent [`Id`] and the given argument.
+    ///
+    /// With the `id_names` feature, if the parent has a debug name,
+    /// the child is named after the parent and the call site.
+    #[cfg_attr(feature = "id_names", track_caller)]
     pub fn with(self, child: impl std::hash::Hash) -> Self {
         use std::hash::{BuildHasher as _, Hasher as _};
         let mut hasher = ahash::RandomState::with_seeds(1, 2, 3, 4).build_hasher();
         hasher.write_u64(self.0.get());
         child.hash(&mut hasher);
-        Self::from_hash(hasher.finish())
+        let id = Self::from_hash(hasher.finish());
+        #[cfg(feature = "id_names")]
+        names::register_location(id, Some(self));
+        id
+    }
+
+    /// The human-readable name registered for this [`Id`], if any.
+    ///
+    /// Always `None` unless the `id_names` feature is enabled.
+    #[cfg_attr(not(feature = "id_names"), expect(clippy::unused_self))]
+    pub fn debug_name(&self) -> Option<String> {
+        #[cfg(feature = "id_names")]
+        {
+            names::get(*self)
+        }
+        #[cfg(not(feature = "id_names"))]
+        {
+            None
+        }
     }
 
     /// Short and readable summary
+    ///
+    /// Includes the [`Self::debug_name`], if any.
     pub fn short_debug_format(&self) -> String {
-        format!("{:04X}", self.value() as u16)
+        let hash = self.value() as u16;
+        if let Some(name) = self.debug_name() {
+            format!("{name} ({hash:04X})")
+        } else {
+            format!("{hash:04X}")
+        }
     }
 
     /// The inner value of the [`Id`].
@@ -87,13 +141,14 @@ impl Id {
 
 impl std::fmt::Debug for Id {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:04X}", self.value() as u16)
+        f.write_str(&self.short_debug_format())
     }
 }
 
 /// Convenience
 impl From<&'static str> for Id {
     #[inline]
+    #[cfg_attr(feature = "id_names", track_caller)]
     fn from(string: &'static str) -> Self {
         Self::new(string)
     }
@@ -101,6 +156,7 @@ impl From<&'static str> for Id {
 
 impl From<String> for Id {
     #[inline]
+    #[cfg_attr(feature = "id_names", track_caller)]
     fn from(string: String) -> Self {
         Self::new(string)
     }
@@ -112,6 +168,29 @@ fn id_size() {
     assert_eq!(std::mem::size_of::<Option<Id>>(), 8);
 }
 
+#[cfg(feature = "id_names")]
+#[test]
+fn id_names() {
+    let named = Id::named("inventory_window");
+    assert_eq!(named, Id::new("inventory_window"));
+    assert_eq!(named.debug_name().as_deref(), Some("inventory_window"));
+    assert!(named.short_debug_format().starts_with("inventory_window ("));
+
+    let child = named.with(42);
+    let child_name = child.debug_name().unwrap();
+    assert!(child_name.starts_with("inventory_window/"), "{child_name}");
+    assert!(child_name.contains("id.rs"), "{child_name}");
+
+    let automatic = Id::new("some unnamed source");
+    assert!(automatic.debug_name().unwrap().contains("id.rs"));
+
+    // Explicit names replace automatic ones:
+    assert_eq!(
+        Id::named("some unnamed source").debug_name().as_deref(),
+        Some("some unnamed source")
+    );
+}
+
 // ----------------------------------------------------------------------------
 
 /// `IdSet` is a `HashSet<Id>` optimized by knowing that [`Id`] has good entropy, and doesn't need more hashing.
@@ -119,3 +198,58 @@ pub type IdSet = nohash_hasher::IntSet<Id>;
 
 /// `IdMap<V>` is a `HashMap<Id, V>` optimized by knowing that [`Id`] has good entropy, and doesn't need more hashing.
 pub type IdMap<V> = nohash_hasher::IntMap<Id, V>;
+
+// ----------------------------------------------------------------------------
+
+/// The side table of debug names, used with the `id_names` feature.
+#[cfg(feature = "id_names")]
+mod names {
+    use super::{Id, IdMap};
+    use epaint::mutex::RwLock;
+    use std::sync::LazyLock;
+
+    static NAMES: LazyLock<RwLock<IdMap<String>>> = LazyLock::new(Default::default);
+
+    /// Explicit names always win over automatic ones.
+    pub(super) fn register(id: Id, name: String) {
+        NAMES.write().insert(id, name);
+    }
+
+    /// Name the id after the call site, unless it already has a name.
+    #[track_caller]
+    pub(super) fn register_location(id: Id, parent: Option<Id>) {
+        if NAMES.read().contains_key(&id) {
+            return;
+        }
+
+        let location = std::panic::Location::caller();
+        let name = match parent {
+            Some(parent) => {
+                // Only name children of named parents, or most ids would be named after egui internals.
+                let Some(parent_name) = get(parent) else {
+                    return;
+                };
+                format!("{parent_name}/{}:{}", location.file(), location.line())
+            }
+            None => format!("{}:{}", location.file(), location.line()),
+        };
+
+        NAMES.write().entry(id).or_insert(name);
+    }
+
+    pub(super) fn get(id: Id) -> Option<String> {
+        NAMES.read().get(&id).cloned()
+    }
+
+    /// All registered names.
+    pub(crate) fn all() -> Vec<(Id, String)> {
+        NAMES
+            .read()
+            .iter()
+            .map(|(id, name)| (*id, name.clone()))
+            .collect()
+    }
+}
+
+#[cfg(feature = "id_names")]
+pub(crate) use names::all as all_debug_names;
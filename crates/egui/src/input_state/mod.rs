@@ -9,7 +9,7 @@ use crate::{
     util::History,
 };
 use std::{
-    collections::{BTreeMap, HashSet},
+    collections::{BTreeMap, HashMap, HashSet},
     time::Duration,
 };
 
@@ -58,6 +58,19 @@ pub struct InputOptions {
     /// and when combined with [`Self::zoom_modifier`] it will result in zooming
     /// on only the vertical axis.
     pub vertical_scroll_modifier: Modifiers,
+
+    /// Time constant (in seconds) of the low-pass filter used for
+    /// [`PointerState::smoothed_velocity`] and [`PointerState::acceleration`].
+    ///
+    /// Larger values give smoother but laggier estimates.
+    /// Set to zero to disable smoothing.
+    pub velocity_smoothing_time: f32,
+
+    /// Merge the [`Event::Text`] events of a pass into one, where possible.
+    ///
+    /// With a high key-repeat rate many characters can arrive in one pass,
+    /// and this lets text editors handle them as one edit.
+    pub coalesce_text_events: bool,
 }
 
 impl Default for InputOptions {
@@ -79,6 +92,8 @@ impl Default for InputOptions {
             zoom_modifier: Modifiers::COMMAND,
             horizontal_scroll_modifier: Modifiers::SHIFT,
             vertical_scroll_modifier: Modifiers::ALT,
+            velocity_smoothing_time: 0.05,
+            coalesce_text_events: true,
         }
     }
 }
@@ -95,6 +110,8 @@ impl InputOptions {
             zoom_modifier,
             horizontal_scroll_modifier,
             vertical_scroll_modifier,
+            velocity_smoothing_time,
+            coalesce_text_events,
         } = self;
         crate::Grid::new("InputOptions")
             .num_columns(2)
@@ -143,6 +160,16 @@ impl InputOptions {
                 .on_hover_text("Max time interval for double click to count");
                 ui.end_row();
 
+                ui.label("Velocity smoothing time");
+                ui.add(
+                    crate::DragValue::new(velocity_smoothing_time)
+                        .range(0.0..=1.0)
+                        .speed(0.001)
+                        .suffix(" s"),
+                )
+                .on_hover_text("How much to smooth the pointer velocity. Zero means no smoothing");
+                ui.end_row();
+
                 ui.label("zoom_modifier");
                 zoom_modifier.ui(ui);
                 ui.end_row();
@@ -155,6 +182,11 @@ impl InputOptions {
                 vertical_scroll_modifier.ui(ui);
                 ui.end_row();
 
+                ui.label("Coalesce text events");
+                ui.checkbox(coalesce_text_events, "")
+                    .on_hover_text("Merge the text typed in one frame into one event");
+                ui.end_row();
+
             });
     }
 }
@@ -292,6 +324,9 @@ pub struct InputState {
     // The keys that are currently being held down.
     pub keys_down: HashSet<Key>,
 
+    /// Key-repeat information for the keys in [`Self::keys_down`].
+    key_repeats: HashMap<Key, KeyRepeat>,
+
     /// In-order events received this frame
     pub events: Vec<Event>,
 
@@ -325,6 +360,7 @@ impl Default for InputState {
             focused: false,
             modifiers: Default::default(),
             keys_down: Default::default(),
+            key_repeats: Default::default(),
             events: Default::default(),
             options: Default::default(),
         }
@@ -361,6 +397,7 @@ impl InputState {
         let pointer = self.pointer.begin_pass(time, &new, options);
 
         let mut keys_down = self.keys_down;
+        let mut key_repeats = self.key_repeats;
         let mut zoom_factor_delta = 1.0; // TODO(emilk): smoothing for zoom factor
         let mut raw_scroll_delta = Vec2::ZERO;
 
@@ -369,6 +406,10 @@ impl InputState {
         let mut smooth_scroll_delta = Vec2::ZERO;
         let mut smooth_scroll_delta_for_zoom = 0.0;
 
+        if options.coalesce_text_events {
+            coalesce_text_events(&mut new.events);
+        }
+
         for event in &mut new.events {
             match event {
                 Event::Key {
@@ -380,8 +421,20 @@ impl InputState {
                     if *pressed {
                         let first_press = keys_down.insert(*key);
                         *repeat = !first_press;
+                        key_repeats
+                            .entry(*key)
+                            .and_modify(|key_repeat| {
+                                key_repeat.count += 1;
+                                key_repeat.last_press_time = time;
+                            })
+                            .or_insert(KeyRepeat {
+                                count: 0,
+                                first_press_time: time,
+                                last_press_time: time,
+                            });
                     } else {
                         keys_down.remove(key);
+                        key_repeats.remove(key);
                     }
                 }
                 Event::MouseWheel {
@@ -447,6 +500,7 @@ impl InputState {
                     // So we take the safe route and just clear all the keys and modifiers when
                     // the app loses focus.
                     keys_down.clear();
+                    key_repeats.clear();
                 }
                 _ => {}
             }
@@ -513,6 +567,7 @@ impl InputState {
             focused: new.focused,
             modifiers: new.modifiers,
             keys_down,
+            key_repeats,
             events: new.events.clone(), // TODO(emilk): remove clone() and use raw.events
             raw: new,
             options,
@@ -719,6 +774,15 @@ impl InputState {
         self.keys_down.contains(&desired_key)
     }
 
+    /// Key-repeat information about a key that is held down.
+    ///
+    /// Returns `None` if the key is not held down.
+    ///
+    /// Useful for implementing your own auto-repeat, e.g. one that speeds up the longer a key is held.
+    pub fn key_repeat(&self, desired_key: Key) -> Option<KeyRepeat> {
+        self.key_repeats.get(&desired_key).copied()
+    }
+
     /// Was the given key released this frame?
     pub fn key_released(&self, desired_key: Key) -> bool {
         self.events.iter().any(|event| {
@@ -733,6 +797,17 @@ impl InputState {
         })
     }
 
+    /// The reply to the [`crate::FileDialogRequest`] with the given id, if it arrived this frame.
+    pub fn file_dialog_result(&self, id: crate::Id) -> Option<crate::FileDialogResult> {
+        self.events.iter().find_map(|event| match event {
+            Event::FileDialogResult {
+                id: result_id,
+                result,
+            } if *result_id == id => Some(result.clone()),
+            _ => None,
+        })
+    }
+
     /// Also known as device pixel ratio, > 1 for high resolution screens.
     #[inline(always)]
     pub fn pixels_per_point(&self) -> f32 {
@@ -871,6 +946,75 @@ impl InputState {
 
 // ----------------------------------------------------------------------------
 
+/// Key-repeat information about a key that is held down, see [`InputState::key_repeat`].
+///
+/// The times are those of the passes the presses arrived in (see [`InputState::time`]),
+/// since the integration does not report when each event happened.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct KeyRepeat {
+    /// How many key-repeat events have been received since the key was first pressed.
+    pub count: u32,
+
+    /// When the key was first pressed.
+    pub first_press_time: f64,
+
+    /// When the latest press or key-repeat was received.
+    pub last_press_time: f64,
+}
+
+/// Merge the [`Event::Text`] events of a pass, see [`InputOptions::coalesce_text_events`].
+///
+/// Texts are only merged across the [`Event::Key`]s that typed them,
+/// not across e.g. [`Key::Backspace`] or pointer events.
+/// Newlines are left alone, since they come together with [`Key::Enter`].
+fn coalesce_text_events(events: &mut Vec<Event>) {
+    let is_mergeable = |text: &str| text != "\n" && text != "\r";
+
+    let mut coalesced: Vec<Event> = Vec::with_capacity(events.len());
+    let mut merge_into = None;
+    for event in events.drain(..) {
+        match &event {
+            Event::Text(text) if is_mergeable(text) => {
+                if let Some(Event::Text(previous)) = merge_into.and_then(|i| coalesced.get_mut(i)) {
+                    previous.push_str(text);
+                    continue;
+                }
+                merge_into = Some(coalesced.len());
+            }
+            Event::Key { key, modifiers, .. } if is_typing_key(*key, *modifiers) => {}
+            _ => merge_into = None,
+        }
+        coalesced.push(event);
+    }
+    *events = coalesced;
+}
+
+/// Does this key (probably) only produce text, without any command?
+fn is_typing_key(key: Key, modifiers: Modifiers) -> bool {
+    let is_command = matches!(
+        key,
+        Key::ArrowDown
+            | Key::ArrowLeft
+            | Key::ArrowRight
+            | Key::ArrowUp
+            | Key::Escape
+            | Key::Tab
+            | Key::Backspace
+            | Key::Enter
+            | Key::Insert
+            | Key::Delete
+            | Key::Home
+            | Key::End
+            | Key::PageUp
+            | Key::PageDown
+            | Key::Copy
+            | Key::Cut
+            | Key::Paste
+    );
+    !is_command && !modifiers.alt && !modifiers.ctrl && !modifiers.command && !modifiers.mac_cmd
+}
+
 /// A pointer (mouse or touch) click.
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -955,6 +1099,12 @@ pub struct PointerState {
     /// Current velocity of pointer.
     velocity: Vec2,
 
+    /// Low-pass filtered version of [`Self::velocity`].
+    smoothed_velocity: Vec2,
+
+    /// Rate of change of [`Self::smoothed_velocity`].
+    acceleration: Vec2,
+
     /// Current direction of pointer.
     direction: Vec2,
 
@@ -996,6 +1146,10 @@ pub struct PointerState {
     /// All button events that occurred this frame
     pub(crate) pointer_events: Vec<PointerEvent>,
 
+    /// egui asked the integration to move the cursor here,
+    /// and we haven't seen it land yet.
+    pending_warp: Option<Pos2>,
+
     /// Input state management configuration.
     ///
     /// This gets copied from `egui::Options` at the start of each frame for convenience.
@@ -1011,6 +1165,8 @@ impl Default for PointerState {
             delta: Vec2::ZERO,
             motion: None,
             velocity: Vec2::ZERO,
+            smoothed_velocity: Vec2::ZERO,
+            acceleration: Vec2::ZERO,
             direction: Vec2::ZERO,
             pos_history: History::new(2..1000, 0.1),
             down: Default::default(),
@@ -1022,6 +1178,7 @@ impl Default for PointerState {
             last_last_click_time: f64::NEG_INFINITY,
             last_move_time: f64::NEG_INFINITY,
             pointer_events: vec![],
+            pending_warp: None,
             options: Default::default(),
         }
     }
@@ -1032,6 +1189,7 @@ impl PointerState {
     pub(crate) fn begin_pass(mut self, time: f64, new: &RawInput, options: InputOptions) -> Self {
         let was_decidedly_dragging = self.is_decidedly_dragging();
 
+        let dt = (time - self.time) as f32;
         self.time = time;
         self.options = options;
 
@@ -1044,11 +1202,24 @@ impl PointerState {
         }
 
         let mut clear_history_after_velocity_calculation = false;
+        let mut warp_offset = Vec2::ZERO;
         for event in &new.events {
             match event {
                 Event::PointerMoved(pos) => {
                     let pos = *pos;
 
+                    if let (Some(warp_target), Some(prev_pos)) =
+                        (self.pending_warp, self.latest_pos)
+                    {
+                        if pos.distance(warp_target) < pos.distance(prev_pos) {
+                            // The cursor was moved by egui, not by the user.
+                            // Don't count the jump as movement:
+                            warp_offset += warp_target - prev_pos;
+                            self.pending_warp = None;
+                            self.pos_history.clear();
+                        }
+                    }
+
                     self.latest_pos = Some(pos);
                     self.interact_pos = Some(pos);
 
@@ -1078,6 +1249,7 @@ impl PointerState {
                         // Start of a drag: we want to track the velocity for during the drag
                         // and ignore any incoming movement
                         self.pos_history.clear();
+                        self.smoothed_velocity = Vec2::ZERO;
                     }
 
                     if pressed {
@@ -1142,7 +1314,7 @@ impl PointerState {
         }
 
         self.delta = if let (Some(old_pos), Some(new_pos)) = (old_pos, self.latest_pos) {
-            new_pos - old_pos
+            new_pos - old_pos - warp_offset
         } else {
             Vec2::ZERO
         };
@@ -1165,6 +1337,21 @@ impl PointerState {
         if self.velocity != Vec2::ZERO {
             self.last_move_time = time;
         }
+
+        let prev_smoothed_velocity = self.smoothed_velocity;
+        let smoothing_time = self.options.velocity_smoothing_time;
+        let dt_is_valid = 0.0 < dt && dt.is_finite();
+        self.smoothed_velocity = if dt_is_valid && 0.0 < smoothing_time {
+            let t = 1.0 - (-dt / smoothing_time).exp();
+            emath::lerp(prev_smoothed_velocity..=self.velocity, t)
+        } else {
+            self.velocity
+        };
+        self.acceleration = if dt_is_valid {
+            (self.smoothed_velocity - prev_smoothed_velocity) / dt
+        } else {
+            Vec2::ZERO
+        };
         if clear_history_after_velocity_calculation {
             self.pos_history.clear();
         }
@@ -1176,6 +1363,19 @@ impl PointerState {
         self
     }
 
+    /// egui has asked the integration to move the cursor to `pos`
+    /// (using [`crate::ViewportCommand::CursorPosition`]).
+    ///
+    /// When the cursor lands there, the jump will not be counted as pointer movement.
+    pub(crate) fn expect_warp(&mut self, pos: Pos2) {
+        self.pending_warp = Some(pos);
+    }
+
+    /// Is egui waiting for the cursor to land after having moved it?
+    pub(crate) fn is_warp_pending(&self) -> bool {
+        self.pending_warp.is_some()
+    }
+
     fn wants_repaint(&self) -> bool {
         !self.pointer_events.is_empty() || self.delta != Vec2::ZERO
     }
@@ -1203,6 +1403,23 @@ impl PointerState {
         self.velocity
     }
 
+    /// Current velocity of the pointer, low-pass filtered over time.
+    ///
+    /// This is less jittery than [`Self::velocity`], which makes it a better choice
+    /// for flick gestures and kinetic scrolling.
+    ///
+    /// The amount of smoothing is controlled by [`InputOptions::velocity_smoothing_time`].
+    #[inline(always)]
+    pub fn smoothed_velocity(&self) -> Vec2 {
+        self.smoothed_velocity
+    }
+
+    /// Current acceleration of the pointer, i.e. the rate of change of [`Self::smoothed_velocity`].
+    #[inline(always)]
+    pub fn acceleration(&self) -> Vec2 {
+        self.acceleration
+    }
+
     /// Current direction of the pointer.
     ///
     /// This is less sensitive to bad framerate than [`Self::velocity`].
@@ -1498,6 +1715,7 @@ impl InputState {
             focused,
             modifiers,
             keys_down,
+            key_repeats,
             events,
             options: _,
         } = self;
@@ -1557,6 +1775,7 @@ impl InputState {
         ui.label(format!("focused:   {focused}"));
         ui.label(format!("modifiers: {modifiers:#?}"));
         ui.label(format!("keys_down: {keys_down:?}"));
+        ui.label(format!("key_repeats: {key_repeats:?}"));
         ui.scope(|ui| {
             ui.set_min_height(150.0);
             ui.label(format!("events: {events:#?}"))
@@ -1574,6 +1793,8 @@ impl PointerState {
             delta,
             motion,
             velocity,
+            smoothed_velocity,
+            acceleration,
             direction,
             pos_history: _,
             down,
@@ -1585,6 +1806,7 @@ impl PointerState {
             last_last_click_time,
             pointer_events,
             last_move_time,
+            pending_warp: _,
             options: _,
         } = self;
 
@@ -1596,6 +1818,14 @@ impl PointerState {
             "velocity: [{:3.0} {:3.0}] points/sec",
             velocity.x, velocity.y
         ));
+        ui.label(format!(
+            "smoothed_velocity: [{:3.0} {:3.0}] points/sec",
+            smoothed_velocity.x, smoothed_velocity.y
+        ));
+        ui.label(format!(
+            "acceleration: [{:3.0} {:3.0}] points/sec²",
+            acceleration.x, acceleration.y
+        ));
         ui.label(format!("direction: {direction:?}"));
         ui.label(format!("down: {down:#?}"));
         ui.label(format!("press_origin: {press_origin:?}"));
@@ -1612,3 +1842,101 @@ impl PointerState {
         ui.label(format!("pointer_events: {pointer_events:?}"));
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DT: f32 = 1.0 / 60.0;
+
+    /// Move the pointer to the right at 100 points per second, at 60 frames per second,
+    /// and return the pointer state after each frame.
+    fn move_right(velocity_smoothing_time: f32, num_frames: usize) -> Vec<PointerState> {
+        let options = InputOptions {
+            velocity_smoothing_time,
+            ..Default::default()
+        };
+        let mut pointer = PointerState::default();
+        (0..num_frames)
+            .map(|frame| {
+                let time = frame as f64 * DT as f64;
+                let new = RawInput {
+                    events: vec![Event::PointerMoved(Pos2::new(100.0 * time as f32, 0.0))],
+                    ..Default::default()
+                };
+                pointer = std::mem::take(&mut pointer).begin_pass(time, &new, options);
+                pointer.clone()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn smoothed_velocity_without_smoothing() {
+        for pointer in move_right(0.0, 30) {
+            assert_eq!(pointer.smoothed_velocity(), pointer.velocity());
+        }
+    }
+
+    #[test]
+    fn smoothed_velocity_follows_velocity() {
+        let smoothing_time = 0.05;
+        let frames = move_right(smoothing_time, 60);
+
+        let first_moving = frames
+            .iter()
+            .position(|pointer| pointer.velocity() != Vec2::ZERO)
+            .expect("The pointer should get a velocity");
+
+        // The first step is exponential smoothing from zero:
+        let pointer = &frames[first_moving];
+        let t = 1.0 - (-DT / smoothing_time).exp();
+        let expected = t * pointer.velocity();
+        assert!((pointer.smoothed_velocity() - expected).length() < 1e-3);
+        assert!((pointer.acceleration() - expected / DT).length() < 1e-1);
+
+        // While catching up, the smoothed velocity lags behind and the pointer accelerates:
+        for pair in frames[first_moving..first_moving + 5].windows(2) {
+            assert!(pair[0].smoothed_velocity().x < pair[1].smoothed_velocity().x);
+            assert!(pair[1].smoothed_velocity().x < pair[1].velocity().x);
+            assert!(0.0 < pair[1].acceleration().x);
+        }
+
+        // Eventually it settles on the real velocity:
+        let last = frames.last().unwrap();
+        assert!(
+            (last.velocity().x - 100.0).abs() < 1.0,
+            "{:?}",
+            last.velocity()
+        );
+        assert!((last.smoothed_velocity() - last.velocity()).length() < 1.0);
+        assert!(last.acceleration().length() < 10.0);
+        assert_eq!(last.smoothed_velocity().y, 0.0);
+    }
+
+    #[test]
+    fn warp_is_consumed_once() {
+        let mut pointer = PointerState::default();
+        let move_to = |pointer: PointerState, frame: u32, pos: Pos2| {
+            let new = RawInput {
+                events: vec![Event::PointerMoved(pos)],
+                ..Default::default()
+            };
+            pointer.begin_pass(frame as f64 * DT as f64, &new, InputOptions::default())
+        };
+
+        pointer = move_to(pointer, 0, Pos2::new(10.0, 10.0));
+        pointer = move_to(pointer, 1, Pos2::new(390.0, 10.0));
+        assert_eq!(pointer.delta(), Vec2::new(380.0, 0.0));
+
+        // egui moves the cursor back to the middle, which is not movement by the user:
+        pointer.expect_warp(Pos2::new(200.0, 10.0));
+        assert!(pointer.is_warp_pending());
+        pointer = move_to(pointer, 2, Pos2::new(205.0, 10.0));
+        assert_eq!(pointer.delta(), Vec2::new(5.0, 0.0));
+        assert!(!pointer.is_warp_pending());
+
+        // The next jump back towards the warp target is real movement:
+        pointer = move_to(pointer, 3, Pos2::new(200.0, 10.0));
+        assert_eq!(pointer.delta(), Vec2::new(-5.0, 0.0));
+    }
+}
@@ -272,7 +272,11 @@ pub(crate) fn interact(
 
         for w in &hits.contains_pointer {
             let is_interactive = w.sense.senses_click() || w.sense.senses_drag();
-            if is_interactive {
+            if widgets.is_behind_children(w.id) {
+                // A background is hovered while hovering its children,
+                // see `Ui::interact_bg_behind_children`.
+                hovered.insert(w.id);
+            } else if is_interactive {
                 // The only interactive widgets we mark as hovered are the ones
                 // in `hits.click` and `hits.drag`!
             } else {
@@ -97,6 +97,8 @@ impl Widget for &epaint::stats::PaintStats {
                 clipped_primitives,
                 vertices,
                 indices,
+                num_coarsened_curves,
+                num_occluded_shapes,
             } = self;
 
             ui.label("Intermediate:");
@@ -122,6 +124,10 @@ impl Widget for &epaint::stats::PaintStats {
                 .on_hover_text("Number of separate clip rectangles");
             label(ui, vertices, "vertices");
             label(ui, indices, "indices").on_hover_text("Three 32-bit indices per triangles");
+            ui.label(format!("{num_coarsened_curves:6} coarsened curves"))
+                .on_hover_text("Curves flattened more coarsely to stay within the vertex budget");
+            ui.label(format!("{num_occluded_shapes:6} occluded shapes"))
+                .on_hover_text("Shapes dropped because they were covered by opaque rectangles");
             ui.add_space(10.0);
 
             // ui.label("Total:");
@@ -150,9 +156,18 @@ impl Widget for &mut epaint::TessellationOptions {
                 debug_paint_text_rects,
                 debug_ignore_clip_rects,
                 bezier_tolerance,
+                bezier_tolerance_in_pixels,
+                vertex_budget,
+                occlusion_culling,
                 epsilon: _,
                 parallel_tessellation,
+                instancing: _, // Decided by the backend
+                hdr: _,        // Decided by the backend
+                composite: _,  // Decided by the backend
+                u32_indices,
                 validate_meshes,
+                simulate_color_vision_deficiency,
+                color_space: _, // Set from `Options::color_space`
             } = self;
 
             ui.horizontal(|ui| {
@@ -173,12 +188,28 @@ impl Widget for &mut epaint::TessellationOptions {
                     crate::DragValue::new(bezier_tolerance).range(0.0001..=10.0)
                         .speed(speed)
                 );
+                ui.checkbox(bezier_tolerance_in_pixels, "in pixels")
+                    .on_hover_text("Use physical pixels instead of points, so curves get less detailed when zoomed out");
+            });
+
+            ui.horizontal(|ui| {
+                let mut has_budget = vertex_budget.is_some();
+                ui.checkbox(&mut has_budget, "Vertex budget")
+                    .on_hover_text("Flatten curves more coarsely once this many vertices have been produced");
+                if has_budget {
+                    let budget = vertex_budget.get_or_insert(100_000);
+                    ui.add(crate::DragValue::new(budget).range(1..=10_000_000).speed(100.0));
+                } else {
+                    *vertex_budget = None;
+                }
             });
 
             ui.add_enabled(epaint::HAS_RAYON, crate::Checkbox::new(parallel_tessellation, "Parallelize tessellation")
                 ).on_hover_text("Only available if epaint was compiled with the rayon feature")
                 .on_disabled_hover_text("epaint was not compiled with the rayon feature");
 
+            ui.checkbox(u32_indices, "32-bit indices").on_hover_text("Don't split up meshes with more vertices than fit in 16-bit indices");
+
             ui.checkbox(validate_meshes, "Validate meshes").on_hover_text("Check that incoming meshes are valid, i.e. that all indices are in range, etc.");
 
             ui.collapsing("Align to pixel grid", |ui| {
@@ -197,10 +228,30 @@ impl Widget for &mut epaint::TessellationOptions {
                     coarse_tessellation_culling,
                     "Do coarse culling in the tessellator",
                 );
+                ui.checkbox(occlusion_culling, "Cull shapes covered by opaque rectangles");
 
                 ui.checkbox(debug_ignore_clip_rects, "Ignore clip rectangles");
                 ui.checkbox(debug_paint_clip_rects, "Paint clip rectangles");
                 ui.checkbox(debug_paint_text_rects, "Paint text bounds");
+
+                ui.horizontal(|ui| {
+                    ui.label("Simulate color vision deficiency");
+                    crate::ComboBox::from_id_salt("simulate_color_vision_deficiency")
+                        .selected_text(match simulate_color_vision_deficiency {
+                            Some(deficiency) => format!("{deficiency:?}"),
+                            None => "None".to_owned(),
+                        })
+                        .show_ui(ui, |ui| {
+                            ui.selectable_value(simulate_color_vision_deficiency, None, "None");
+                            for deficiency in epaint::ecolor::ColorVisionDeficiency::ALL {
+                                ui.selectable_value(
+                                    simulate_color_vision_deficiency,
+                                    Some(deficiency),
+                                    format!("{deficiency:?}"),
+                                );
+                            }
+                        });
+                });
             });
         })
         .response
@@ -212,11 +263,15 @@ impl Widget for &memory::InteractionState {
         let memory::InteractionState {
             potential_click_id,
             potential_drag_id,
+            pointer_capture,
+            pointer_lock,
         } = self;
 
         ui.vertical(|ui| {
             ui.label(format!("potential_click_id: {potential_click_id:?}"));
             ui.label(format!("potential_drag_id: {potential_drag_id:?}"));
+            ui.label(format!("pointer_capture: {pointer_capture:?}"));
+            ui.label(format!("pointer_lock: {pointer_lock:?}"));
         })
         .response
     }
@@ -1,11 +1,15 @@
 //! Handles paint layers, i.e. how things
 //! are sometimes painted behind or in front of other things.
 
-use crate::{Id, IdMap, Rect, ahash, epaint};
+use std::collections::BTreeMap;
+
+use crate::{Color32, Id, IdMap, Rect, ahash, epaint};
 use epaint::{ClippedShape, Shape, emath::TSTransform};
 
 /// Different layer categories
-#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, Ord, PartialOrd)]
+///
+/// Use [`Order::Custom`] to add your own layer categories in between the built-in ones.
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum Order {
     /// Painted behind all floating windows
@@ -24,25 +28,43 @@ pub enum Order {
 
     /// Debug layer, always painted last / on top
     Debug,
+
+    /// A user-defined layer category, placed according to its [`Self::z`] value.
+    ///
+    /// For instance, `Order::Custom(Order::Foreground.z() + 50)` is painted
+    /// above all popups but below the tooltips.
+    /// A custom order with the same value as a built-in one is painted right above it.
+    Custom(i16),
 }
 
 impl Order {
-    const COUNT: usize = 5;
-    const ALL: [Self; Self::COUNT] = [
-        Self::Background,
-        Self::Middle,
-        Self::Foreground,
-        Self::Tooltip,
-        Self::Debug,
-    ];
     pub const TOP: Self = Self::Debug;
 
+    /// Where this order is placed, compared to the other ones.
+    ///
+    /// The built-in orders are 100 apart, starting with [`Self::Background`] at 0,
+    /// so there is plenty of room for [`Self::Custom`] orders in between.
+    #[inline]
+    pub const fn z(self) -> i16 {
+        match self {
+            Self::Background => 0,
+            Self::Middle => 100,
+            Self::Foreground => 200,
+            Self::Tooltip => 300,
+            Self::Debug => 400,
+            Self::Custom(z) => z,
+        }
+    }
+
     #[inline(always)]
     pub fn allow_interaction(&self) -> bool {
         match self {
-            Self::Background | Self::Middle | Self::Foreground | Self::Tooltip | Self::Debug => {
-                true
-            }
+            Self::Background
+            | Self::Middle
+            | Self::Foreground
+            | Self::Tooltip
+            | Self::Debug
+            | Self::Custom(_) => true,
         }
     }
 
@@ -54,10 +76,26 @@ impl Order {
             Self::Foreground => "foreg",
             Self::Tooltip => "toolt",
             Self::Debug => "debug",
+            Self::Custom(_) => "custm",
         }
     }
 }
 
+impl PartialOrd for Order {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Order {
+    #[inline]
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let is_custom = |order: &Self| matches!(order, Self::Custom(_));
+        (self.z(), is_custom(self)).cmp(&(other.z(), is_custom(other)))
+    }
+}
+
 /// An identifier for a paint layer.
 /// Also acts as an identifier for [`crate::Area`]:s.
 #[derive(Clone, Copy, Hash, Eq, PartialEq)]
@@ -109,6 +147,59 @@ impl std::fmt::Debug for LayerId {
     }
 }
 
+/// A color adjustment of everything painted on a layer,
+/// see [`crate::Context::set_layer_color_transform`].
+///
+/// For instance, to gray out the contents behind a modal dialog:
+/// `ColorTransform { saturation: 0.0, brightness: 0.7 }`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ColorTransform {
+    /// `0.0` is grayscale, `1.0` leaves the colors as they are.
+    pub saturation: f32,
+
+    /// Multiplies the colors, so `0.0` is black and `1.0` leaves the colors as they are.
+    pub brightness: f32,
+}
+
+impl Default for ColorTransform {
+    #[inline]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl ColorTransform {
+    /// Leaves the colors as they are.
+    pub const IDENTITY: Self = Self {
+        saturation: 1.0,
+        brightness: 1.0,
+    };
+
+    /// Removes all color.
+    pub const GRAYSCALE: Self = Self {
+        saturation: 0.0,
+        brightness: 1.0,
+    };
+
+    #[inline]
+    pub fn is_identity(&self) -> bool {
+        *self == Self::IDENTITY
+    }
+
+    /// Apply the transform to a (premultiplied) color.
+    pub fn apply(&self, color: Color32) -> Color32 {
+        let [r, g, b, a] = color.to_array().map(f32::from);
+        let luma = 0.299 * r + 0.587 * g + 0.114 * b;
+        // Premultiplied colors can't be brighter than they are opaque:
+        let adjust = |c: f32| {
+            let c = luma + self.saturation * (c - luma);
+            (c * self.brightness).round().clamp(0.0, a) as u8
+        };
+        Color32::from_rgba_premultiplied(adjust(r), adjust(g), adjust(b), a as u8)
+    }
+}
+
 /// A unique identifier of a specific [`Shape`] in a [`PaintList`].
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -116,28 +207,33 @@ pub struct ShapeIdx(pub usize);
 
 /// A list of [`Shape`]s paired with a clip rectangle.
 #[derive(Clone, Default)]
-pub struct PaintList(Vec<ClippedShape>);
+pub struct PaintList {
+    shapes: Vec<ClippedShape>,
+
+    /// Ranges of [`Self::shapes`] to paint on top of the rest, see [`Self::elevate_from`].
+    elevated: Vec<std::ops::Range<usize>>,
+}
 
 impl PaintList {
     #[inline(always)]
     pub fn is_empty(&self) -> bool {
-        self.0.is_empty()
+        self.shapes.is_empty()
     }
 
     pub fn next_idx(&self) -> ShapeIdx {
-        ShapeIdx(self.0.len())
+        ShapeIdx(self.shapes.len())
     }
 
     /// Returns the index of the new [`Shape`] that can be used with `PaintList::set`.
     #[inline(always)]
     pub fn add(&mut self, clip_rect: Rect, shape: Shape) -> ShapeIdx {
         let idx = self.next_idx();
-        self.0.push(ClippedShape { clip_rect, shape });
+        self.shapes.push(ClippedShape { clip_rect, shape });
         idx
     }
 
     pub fn extend<I: IntoIterator<Item = Shape>>(&mut self, clip_rect: Rect, shapes: I) {
-        self.0.extend(
+        self.shapes.extend(
             shapes
                 .into_iter()
                 .map(|shape| ClippedShape { clip_rect, shape }),
@@ -153,29 +249,29 @@ impl PaintList {
     /// and then later setting it using `paint_list.set(idx, cr, frame);`.
     #[inline(always)]
     pub fn set(&mut self, idx: ShapeIdx, clip_rect: Rect, shape: Shape) {
-        if self.0.len() <= idx.0 {
+        if self.shapes.len() <= idx.0 {
             #[cfg(feature = "log")]
             log::warn!("Index {} is out of bounds for PaintList", idx.0);
             return;
         }
 
-        self.0[idx.0] = ClippedShape { clip_rect, shape };
+        self.shapes[idx.0] = ClippedShape { clip_rect, shape };
     }
 
     /// Set the given shape to be empty (a `Shape::Noop`).
     #[inline(always)]
     pub fn reset_shape(&mut self, idx: ShapeIdx) {
-        self.0[idx.0].shape = Shape::Noop;
+        self.shapes[idx.0].shape = Shape::Noop;
     }
 
     /// Mutate the shape at the given index, if any.
     pub fn mutate_shape(&mut self, idx: ShapeIdx, f: impl FnOnce(&mut ClippedShape)) {
-        self.0.get_mut(idx.0).map(f);
+        self.shapes.get_mut(idx.0).map(f);
     }
 
     /// Transform each [`Shape`] and clip rectangle by this much, in-place
     pub fn transform(&mut self, transform: TSTransform) {
-        for ClippedShape { clip_rect, shape } in &mut self.0 {
+        for ClippedShape { clip_rect, shape } in &mut self.shapes {
             *clip_rect = transform.mul_rect(*clip_rect);
             shape.transform(transform);
         }
@@ -183,52 +279,177 @@ impl PaintList {
 
     /// Transform each [`Shape`] and clip rectangle in range by this much, in-place
     pub fn transform_range(&mut self, start: ShapeIdx, end: ShapeIdx, transform: TSTransform) {
-        for ClippedShape { clip_rect, shape } in &mut self.0[start.0..end.0] {
+        for ClippedShape { clip_rect, shape } in &mut self.shapes[start.0..end.0] {
             *clip_rect = transform.mul_rect(*clip_rect);
             shape.transform(transform);
         }
     }
 
+    /// The shapes in the range, e.g. to record them for later.
+    pub fn entries_in_range(&self, start: ShapeIdx, end: ShapeIdx) -> &[ClippedShape] {
+        &self.shapes[start.0..end.0]
+    }
+
+    /// Add shapes that already have a clip rectangle,
+    /// e.g. ones recorded with [`Self::entries_in_range`].
+    pub fn extend_clipped<I: IntoIterator<Item = ClippedShape>>(&mut self, shapes: I) {
+        self.shapes.extend(shapes);
+    }
+
+    /// Remove and return the shapes from `start` and on, e.g. to wrap them in an [`epaint::CompositeShape`].
+    ///
+    /// The removed shapes are no longer elevated, see [`Self::elevate_from`].
+    pub fn take_from(&mut self, start: ShapeIdx) -> Vec<ClippedShape> {
+        for range in &mut self.elevated {
+            range.end = range.end.min(start.0);
+        }
+        self.elevated.retain(|range| !range.is_empty());
+        self.shapes.split_off(start.0.min(self.shapes.len()))
+    }
+
+    /// Paint the shapes from `start` and on after all other shapes of this layer,
+    /// including the ones added later.
+    ///
+    /// The elevated shapes keep their order among themselves.
+    /// This is applied when the layer is finished, so the [`ShapeIdx`]:s stay valid until then.
+    pub fn elevate_from(&mut self, start: ShapeIdx) {
+        let range = start.0..self.shapes.len();
+        if !range.is_empty() {
+            self.elevated.push(range);
+        }
+    }
+
     /// Read-only access to all held shapes.
     pub fn all_entries(&self) -> impl ExactSizeIterator<Item = &ClippedShape> {
-        self.0.iter()
+        self.shapes.iter()
+    }
+
+    /// Apply the transform, colors and opacity of the layer,
+    /// before handing the shapes over for tessellation.
+    fn finish_layer(
+        &mut self,
+        layer_id: LayerId,
+        to_global: &ahash::HashMap<LayerId, TSTransform>,
+        layer_colors: &ahash::HashMap<LayerId, ColorTransform>,
+        layer_opacity: &ahash::HashMap<LayerId, f32>,
+    ) {
+        if !self.elevated.is_empty() {
+            let mut is_elevated = vec![false; self.shapes.len()];
+            for range in self.elevated.drain(..) {
+                let end = range.end.min(is_elevated.len());
+                is_elevated[range.start.min(end)..end].fill(true);
+            }
+            let (below, above): (Vec<_>, Vec<_>) = std::mem::take(&mut self.shapes)
+                .into_iter()
+                .zip(is_elevated)
+                .partition(|(_, is_elevated)| !is_elevated);
+            self.shapes = below
+                .into_iter()
+                .chain(above)
+                .map(|(shape, _)| shape)
+                .collect();
+        }
+        if let Some(to_global) = to_global.get(&layer_id) {
+            self.transform(*to_global);
+        }
+        if let Some(&colors) = layer_colors.get(&layer_id) {
+            for clipped_shape in &mut self.shapes {
+                epaint::shape_transform::adjust_colors(&mut clipped_shape.shape, move |color| {
+                    if *color != Color32::PLACEHOLDER {
+                        *color = colors.apply(*color);
+                    }
+                });
+            }
+        }
+        if let Some(&opacity) = layer_opacity.get(&layer_id) {
+            for clipped_shape in &mut self.shapes {
+                crate::painter::multiply_opacity(&mut clipped_shape.shape, opacity);
+            }
+        }
     }
 }
 
 /// This is where painted [`Shape`]s end up during a frame.
 #[derive(Clone, Default)]
-pub struct GraphicLayers([IdMap<PaintList>; Order::COUNT]);
+pub struct GraphicLayers(BTreeMap<Order, IdMap<PaintList>>);
 
 impl GraphicLayers {
     /// Get or insert the [`PaintList`] for the given [`LayerId`].
     pub fn entry(&mut self, layer_id: LayerId) -> &mut PaintList {
-        self.0[layer_id.order as usize]
+        self.0
+            .entry(layer_id.order)
+            .or_default()
             .entry(layer_id.id)
             .or_default()
     }
 
     /// Get the [`PaintList`] for the given [`LayerId`].
     pub fn get(&self, layer_id: LayerId) -> Option<&PaintList> {
-        self.0[layer_id.order as usize].get(&layer_id.id)
+        self.0.get(&layer_id.order)?.get(&layer_id.id)
     }
 
     /// Get the [`PaintList`] for the given [`LayerId`].
     pub fn get_mut(&mut self, layer_id: LayerId) -> Option<&mut PaintList> {
-        self.0[layer_id.order as usize].get_mut(&layer_id.id)
+        self.0.get_mut(&layer_id.order)?.get_mut(&layer_id.id)
+    }
+
+    /// Free the spare capacity of all [`PaintList`]s.
+    ///
+    /// Returns the number of bytes freed.
+    pub fn shrink_to_fit(&mut self) -> usize {
+        let mut bytes_freed = 0;
+        for list in self
+            .0
+            .values_mut()
+            .flat_map(|order_map| order_map.values_mut())
+        {
+            let spare = list.shapes.capacity() - list.shapes.len();
+            bytes_freed += spare * std::mem::size_of::<ClippedShape>();
+            list.shapes.shrink_to_fit();
+            list.elevated.shrink_to_fit();
+        }
+        bytes_freed
+    }
+
+    /// All non-empty layers, in the order they are painted (back to front).
+    pub fn layers_in_paint_order(&self, area_order: &[LayerId]) -> Vec<(LayerId, &PaintList)> {
+        let mut layers = Vec::new();
+
+        // `BTreeMap` iterates in `Order` order:
+        for (&order, order_map) in &self.0 {
+            for layer_id in area_order {
+                if layer_id.order == order {
+                    if let Some(list) = order_map.get(&layer_id.id) {
+                        layers.push((*layer_id, list));
+                    }
+                }
+            }
+
+            // Also areas that are missing in `area_order`:
+            for (id, list) in order_map {
+                let layer_id = LayerId::new(order, *id);
+                if !area_order.contains(&layer_id) {
+                    layers.push((layer_id, list));
+                }
+            }
+        }
+
+        layers.retain(|(_, list)| !list.is_empty());
+        layers
     }
 
     pub fn drain(
         &mut self,
         area_order: &[LayerId],
         to_global: &ahash::HashMap<LayerId, TSTransform>,
+        layer_colors: &ahash::HashMap<LayerId, ColorTransform>,
+        layer_opacity: &ahash::HashMap<LayerId, f32>,
     ) -> Vec<ClippedShape> {
         profiling::function_scope!();
 
         let mut all_shapes: Vec<_> = Default::default();
 
-        for &order in &Order::ALL {
-            let order_map = &mut self.0[order as usize];
-
+        for (&order, order_map) in &mut self.0 {
             // If a layer is empty at the start of the frame
             // then nobody has added to it, and it is old and defunct.
             // Free it to save memory:
@@ -238,13 +459,8 @@ impl GraphicLayers {
             for layer_id in area_order {
                 if layer_id.order == order {
                     if let Some(list) = order_map.get_mut(&layer_id.id) {
-                        if let Some(to_global) = to_global.get(layer_id) {
-                            for clipped_shape in &mut list.0 {
-                                clipped_shape.clip_rect = *to_global * clipped_shape.clip_rect;
-                                clipped_shape.shape.transform(*to_global);
-                            }
-                        }
-                        all_shapes.append(&mut list.0);
+                        list.finish_layer(*layer_id, to_global, layer_colors, layer_opacity);
+                        all_shapes.append(&mut list.shapes);
                     }
                 }
             }
@@ -252,15 +468,8 @@ impl GraphicLayers {
             // Also draw areas that are missing in `area_order`:
             for (id, list) in order_map {
                 let layer_id = LayerId::new(order, *id);
-
-                if let Some(to_global) = to_global.get(&layer_id) {
-                    for clipped_shape in &mut list.0 {
-                        clipped_shape.clip_rect = *to_global * clipped_shape.clip_rect;
-                        clipped_shape.shape.transform(*to_global);
-                    }
-                }
-
-                all_shapes.append(&mut list.0);
+                list.finish_layer(layer_id, to_global, layer_colors, layer_opacity);
+                all_shapes.append(&mut list.shapes);
             }
         }
 
@@ -117,6 +117,56 @@ impl Direction {
 
 // ----------------------------------------------------------------------------
 
+/// How to distribute the leftover space on the main axis between the widgets of a [`Layout`].
+///
+/// See [`Layout::with_justify_content`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum JustifyContent {
+    /// Put the widgets next to each other, with the normal item spacing.
+    #[default]
+    Start,
+
+    /// The first widget is at the start, the last widget at the end,
+    /// and the leftover space is evenly distributed between the widgets.
+    SpaceBetween,
+
+    /// Each widget gets the same amount of space on either side,
+    /// so the space at the start and end is half of the space between widgets.
+    SpaceAround,
+
+    /// The space before, between and after the widgets is the same.
+    SpaceEvenly,
+}
+
+impl JustifyContent {
+    /// The space before the first widget and between the widgets,
+    /// given the leftover space and the number of widgets.
+    pub(crate) fn leading_and_gap(self, leftover: f32, num_items: usize) -> (f32, f32) {
+        let n = num_items as f32;
+        match self {
+            Self::Start => (0.0, 0.0),
+            Self::SpaceBetween => {
+                if num_items <= 1 {
+                    (0.0, 0.0)
+                } else {
+                    (0.0, leftover / (n - 1.0))
+                }
+            }
+            Self::SpaceAround => {
+                let gap = leftover / n.max(1.0);
+                (gap / 2.0, gap)
+            }
+            Self::SpaceEvenly => {
+                let gap = leftover / (n + 1.0);
+                (gap, gap)
+            }
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 /// The layout of a [`Ui`][`crate::Ui`], e.g. "vertical & centered".
 ///
 /// ```
@@ -153,6 +203,12 @@ pub struct Layout {
     /// For vertical layouts justify mean all widgets get maximum width.
     /// For horizontal layouts justify mean all widgets get maximum height.
     pub cross_justify: bool,
+
+    /// How to distribute leftover space on the main axis between the widgets.
+    ///
+    /// Ignored for wrapping and main-justified layouts.
+    /// Set with [`Self::with_justify_content`].
+    pub(crate) justify_content: JustifyContent,
 }
 
 impl Default for Layout {
@@ -176,6 +232,7 @@ impl Layout {
             main_justify: false,
             cross_align: valign,
             cross_justify: false,
+            justify_content: JustifyContent::Start,
         }
     }
 
@@ -191,6 +248,7 @@ impl Layout {
             main_justify: false,
             cross_align: valign,
             cross_justify: false,
+            justify_content: JustifyContent::Start,
         }
     }
 
@@ -206,6 +264,7 @@ impl Layout {
             main_justify: false,
             cross_align: halign,
             cross_justify: false,
+            justify_content: JustifyContent::Start,
         }
     }
 
@@ -227,6 +286,7 @@ impl Layout {
             main_justify: false,
             cross_align: halign,
             cross_justify: false,
+            justify_content: JustifyContent::Start,
         }
     }
 
@@ -239,6 +299,7 @@ impl Layout {
             main_justify: false,
             cross_align,
             cross_justify: false,
+            justify_content: JustifyContent::Start,
         }
     }
 
@@ -255,6 +316,7 @@ impl Layout {
             main_justify: true,
             cross_align: Align::Center,
             cross_justify: true,
+            justify_content: JustifyContent::Start,
         }
     }
 
@@ -309,6 +371,32 @@ impl Layout {
             ..self
         }
     }
+
+    /// Distribute the leftover space on the main axis between the widgets.
+    ///
+    /// The widgets are still added in order, so tab order and reading order are preserved.
+    /// The sizes of the widgets are remembered from the previous pass,
+    /// so the first time a layout is shown an extra pass is requested.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui::{Align, JustifyContent, Layout};
+    ///
+    /// let layout = Layout::left_to_right(Align::Center)
+    ///     .with_justify_content(JustifyContent::SpaceBetween);
+    /// ui.with_layout(layout, |ui| {
+    ///     ui.label("Volume");
+    ///     ui.label("42 %");
+    /// });
+    /// # });
+    /// ```
+    #[inline(always)]
+    pub fn with_justify_content(self, justify_content: JustifyContent) -> Self {
+        Self {
+            justify_content,
+            ..self
+        }
+    }
 }
 
 /// ## Inspectors
@@ -333,6 +421,11 @@ impl Layout {
         self.cross_justify
     }
 
+    #[inline(always)]
+    pub fn justify_content(&self) -> JustifyContent {
+        self.justify_content
+    }
+
     #[inline(always)]
     pub fn is_horizontal(&self) -> bool {
         self.main_dir().is_horizontal()
@@ -409,9 +409,12 @@ mod animation_manager;
 pub mod cache;
 pub mod containers;
 mod context;
+pub mod contrast;
 mod data;
 pub mod debug_text;
 mod drag_and_drop;
+mod file_dialog;
+pub mod gallery;
 pub(crate) mod grid;
 pub mod gui_zoom;
 mod hit_test;
@@ -422,22 +425,31 @@ pub mod introspection;
 pub mod layers;
 mod layout;
 pub mod load;
+mod macro_recording;
 mod memory;
 #[deprecated = "Use `egui::containers::menu` instead"]
 pub mod menu;
+mod navigator;
+mod offscreen;
 pub mod os;
 mod painter;
 mod pass_state;
 pub(crate) mod placer;
+pub mod reflect;
 pub mod response;
+mod rubber_band;
 mod sense;
 pub mod style;
 pub mod text_selection;
+#[cfg(feature = "theme_watcher")]
+pub mod theme_watcher;
+pub mod translation;
 mod ui;
 mod ui_builder;
 mod ui_stack;
 pub mod util;
 pub mod viewport;
+mod warnings;
 mod widget_rect;
 pub mod widget_text;
 pub mod widgets;
@@ -475,45 +487,62 @@ pub mod text {
     pub use crate::text_selection::CCursorRange;
     pub use epaint::text::{
         FontData, FontDefinitions, FontFamily, Fonts, Galley, LayoutJob, LayoutSection, TAB_SIZE,
-        TextFormat, TextWrapping, cursor::CCursor,
+        TextFormat, TextShadow, TextWrapping, cursor::CCursor,
     };
 }
 
 pub use self::{
     atomics::*,
     containers::{menu::MenuBar, *},
-    context::{Context, RepaintCause, RequestRepaintInfo},
+    context::{
+        Context, InputMiddleware, MemoryPressure, RepaintCause, RepaintReason, RepaintReasons,
+        RequestRepaintInfo,
+    },
     data::{
         Key, UserData,
         input::*,
         output::{
-            self, CursorIcon, FullOutput, OpenUrl, OutputCommand, PlatformOutput,
-            UserAttentionType, WidgetInfo,
+            self, CursorIcon, FileDialogFilter, FileDialogKind, FileDialogRequest, FullOutput,
+            MenuItem, OpenUrl, OutputCommand, PlatformOutput, TrayIcon, UserAttentionType,
+            WidgetInfo,
         },
     },
     drag_and_drop::DragAndDrop,
     epaint::text::TextWrapMode,
     grid::Grid,
-    id::{Id, IdMap},
-    input_state::{InputOptions, InputState, MultiTouchInfo, PointerState},
-    layers::{LayerId, Order},
+    id::{Id, IdMap, IdSet},
+    input_state::{InputOptions, InputState, KeyRepeat, MultiTouchInfo, PointerState},
+    layers::{ColorTransform, LayerId, Order},
     layout::*,
     load::SizeHint,
-    memory::{Memory, Options, Theme, ThemePreference},
+    macro_recording::MacroAction,
+    memory::{Memory, Options, RepaintThrottle, SessionState, Theme, ThemePreference},
+    navigator::Navigator,
     painter::Painter,
     response::{InnerResponse, Response},
+    rubber_band::{RubberBand, RubberBandResponse},
     sense::Sense,
     style::{FontSelection, Spacing, Style, TextStyle, Visuals},
     text::{Galley, TextFormat},
+    translation::Translator,
     ui::Ui,
     ui_builder::UiBuilder,
     ui_stack::*,
     viewport::*,
-    widget_rect::{WidgetRect, WidgetRects},
+    warnings::{Warning, WarningKind, WarningSeverity},
+    widget_rect::{
+        HitTestMode, WidgetNode, WidgetNodeKind, WidgetRect, WidgetRects, WidgetTreeSnapshot,
+    },
     widget_text::{RichText, WidgetText},
     widgets::*,
 };
 
+#[cfg(feature = "memory_stats")]
+pub use context::MemoryStats;
+
+#[cfg(feature = "theme_json")]
+pub use memory::{THEME_FILE_VERSION, ThemeFileError};
+
 #[deprecated = "Renamed to CornerRadius"]
 pub type Rounding = CornerRadius;
 
@@ -0,0 +1,156 @@
+//! Recording and replaying user actions on widgets,
+//! see [`crate::Context::start_macro_recording`] and [`crate::Context::play_macro`].
+
+use std::collections::VecDeque;
+
+use crate::{Event, Id, IdMap, Modifiers, PointerButton, Pos2, output::OutputEvent};
+
+/// A user action on a widget, recorded with [`crate::Context::start_macro_recording`].
+///
+/// Widgets are identified by their [`Id`], so a macro still works if the layout changes,
+/// as long as the ids stay the same.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum MacroAction {
+    /// Click the widget with the primary mouse button.
+    Click(Id),
+
+    /// Set the value of a [`crate::Slider`] or [`crate::DragValue`].
+    SetValue(Id, f64),
+
+    /// Set the text of a [`crate::TextEdit`].
+    SetText(Id, String),
+}
+
+impl MacroAction {
+    /// The widget the action is performed on.
+    pub fn id(&self) -> Id {
+        match self {
+            Self::Click(id) | Self::SetValue(id, _) | Self::SetText(id, _) => *id,
+        }
+    }
+}
+
+/// Keeps track of the macro being recorded or played.
+#[derive(Default)]
+pub(crate) struct Macros {
+    /// Set while recording.
+    recording: Option<Vec<MacroAction>>,
+
+    /// The actions left to play, one per pass.
+    playing: VecDeque<MacroAction>,
+
+    /// Values for the widgets to pick up this pass.
+    values: IdMap<f64>,
+
+    /// Texts for the widgets to pick up this pass.
+    texts: IdMap<String>,
+}
+
+impl Macros {
+    pub fn start_recording(&mut self) {
+        self.recording = Some(Vec::new());
+    }
+
+    pub fn stop_recording(&mut self) -> Vec<MacroAction> {
+        self.recording.take().unwrap_or_default()
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.recording.is_some()
+    }
+
+    pub fn play(&mut self, actions: impl IntoIterator<Item = MacroAction>) {
+        self.playing.extend(actions);
+    }
+
+    pub fn is_playing(&self) -> bool {
+        !self.playing.is_empty()
+    }
+
+    /// Record the action behind an [`OutputEvent`] of a widget.
+    pub fn record(&mut self, id: Id, event: &OutputEvent) {
+        let Some(actions) = &mut self.recording else {
+            return;
+        };
+
+        let (OutputEvent::Clicked(info) | OutputEvent::ValueChanged(info)) = event else {
+            return;
+        };
+        let action = if let Some(value) = info.value {
+            // Clicking a slider sets its value, so we record the value rather than the click:
+            MacroAction::SetValue(id, value)
+        } else if matches!(event, OutputEvent::Clicked(_)) {
+            MacroAction::Click(id)
+        } else if let Some(text) = &info.current_text_value {
+            MacroAction::SetText(id, text.clone())
+        } else {
+            return;
+        };
+
+        // Dragging a slider or typing changes the value every pass, but only the last one matters:
+        if let Some(MacroAction::SetValue(last_id, _) | MacroAction::SetText(last_id, _)) =
+            actions.last()
+        {
+            if *last_id == id && !matches!(action, MacroAction::Click(_)) {
+                actions.pop();
+            }
+        }
+        actions.push(action);
+    }
+
+    /// Play the next action, if any. Called at the start of each pass.
+    ///
+    /// Clicks are added to the `events`, at the position given by `widget_pos`.
+    pub fn begin_pass(&mut self, events: &mut Vec<Event>, widget_pos: impl Fn(Id) -> Option<Pos2>) {
+        // Drop values that no widget picked up last pass:
+        self.values.clear();
+        self.texts.clear();
+
+        let Some(action) = self.playing.pop_front() else {
+            return;
+        };
+        match action {
+            MacroAction::Click(id) => {
+                if let Some(pos) = widget_pos(id) {
+                    events.push(Event::PointerMoved(pos));
+                    for pressed in [true, false] {
+                        events.push(Event::PointerButton {
+                            pos,
+                            button: PointerButton::Primary,
+                            pressed,
+                            modifiers: Modifiers::default(),
+                        });
+                    }
+                } else {
+                    #[cfg(feature = "log")]
+                    log::warn!("Macro: can't click {id:?}, because it is not shown");
+                }
+            }
+            MacroAction::SetValue(id, value) => {
+                self.values.insert(id, value);
+            }
+            MacroAction::SetText(id, text) => {
+                self.texts.insert(id, text);
+            }
+        }
+    }
+
+    /// Is there any value for a widget to pick up this pass?
+    pub fn has_values(&self) -> bool {
+        !self.values.is_empty()
+    }
+
+    /// Is there any text for a widget to pick up this pass?
+    pub fn has_texts(&self) -> bool {
+        !self.texts.is_empty()
+    }
+
+    pub fn take_value(&mut self, id: Id) -> Option<f64> {
+        self.values.remove(&id)
+    }
+
+    pub fn take_text(&mut self, id: Id) -> Option<String> {
+        self.texts.remove(&id)
+    }
+}
@@ -1,18 +1,23 @@
 #![warn(missing_docs)] // Let's keep this file well-documented.` to memory.rs
 
-use std::num::NonZeroUsize;
+use std::{num::NonZeroUsize, time::Duration};
 
 use ahash::{HashMap, HashSet};
 use epaint::emath::TSTransform;
 
 use crate::{
-    EventFilter, Id, IdMap, LayerId, Order, Pos2, Rangef, RawInput, Rect, Style, Vec2, ViewportId,
-    ViewportIdMap, ViewportIdSet, area, vec2,
+    ColorTransform, EventFilter, Id, IdMap, LayerId, Order, Pos2, Rangef, RawInput, Rect, Style,
+    Vec2, ViewportId, ViewportIdMap, ViewportIdSet, area,
+    util::{IdTypeMap, id_type_map::SerializableAny},
+    vec2,
 };
 
 mod theme;
 pub use theme::{Theme, ThemePreference};
 
+#[cfg(feature = "theme_json")]
+pub use theme::{THEME_FILE_VERSION, ThemeFileError};
+
 // ----------------------------------------------------------------------------
 
 /// The data that egui persists between frames.
@@ -98,6 +103,22 @@ pub struct Memory {
     /// * [`crate::Context::layer_transform_from_global`]
     pub to_global: HashMap<LayerId, TSTransform>,
 
+    /// Opacity per layer, applied when the shapes are handed over for tessellation.
+    ///
+    /// Set with [`crate::Context::set_layer_opacity`].
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    pub(crate) layer_opacity: HashMap<LayerId, f32>,
+
+    /// Color adjustment per layer, applied when the shapes are handed over for tessellation.
+    ///
+    /// Set with [`crate::Context::set_layer_color_transform`].
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    pub(crate) layer_colors: HashMap<LayerId, ColorTransform>,
+
+    /// Set by [`crate::Context::navigate_to_anchor`], and handled by [`crate::Ui::anchor`].
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    pub(crate) anchor_navigation: Option<AnchorNavigation>,
+
     // -------------------------------------------------
     // Per-viewport:
     areas: ViewportIdMap<Areas>,
@@ -116,6 +137,12 @@ pub struct Memory {
     /// (e.g. relative to some other widget).
     #[cfg_attr(feature = "persistence", serde(skip))]
     popups: ViewportIdMap<OpenPopup>,
+
+    /// Temporary values of [`Self::data`] to include in [`Self::save_session`].
+    ///
+    /// See [`Self::include_in_session`].
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    session_entries: Vec<SessionEntry>,
 }
 
 impl Default for Memory {
@@ -130,9 +157,13 @@ impl Default for Memory {
             viewport_id: Default::default(),
             areas: Default::default(),
             to_global: Default::default(),
+            layer_opacity: Default::default(),
+            layer_colors: Default::default(),
+            anchor_navigation: Default::default(),
             popups: Default::default(),
             everything_is_visible: Default::default(),
             add_fonts: Default::default(),
+            session_entries: Default::default(),
         };
         slf.interactions.entry(slf.viewport_id).or_default();
         slf.areas.entry(slf.viewport_id).or_default();
@@ -140,6 +171,18 @@ impl Default for Memory {
     }
 }
 
+/// An ongoing [`crate::Context::navigate_to_anchor`].
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct AnchorNavigation {
+    pub name: String,
+
+    /// Flash a highlight over the anchor once we get there?
+    pub highlight: bool,
+
+    /// When we scrolled to the anchor, if we have.
+    pub arrived_at: Option<f64>,
+}
+
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 enum FocusDirection {
     /// Select the widget closest above the current focused widget.
@@ -209,6 +252,18 @@ pub struct Options {
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) system_theme: Option<Theme>,
 
+    /// How many seconds to spend cross-fading the colors when switching between
+    /// the dark and light theme, e.g. when the system theme changes.
+    ///
+    /// Set to `0.0` to switch instantly.
+    ///
+    /// Default: `0.25`.
+    pub theme_transition_time: f32,
+
+    /// The blended style while a theme transition is in progress.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) theme_transition_style: Option<std::sync::Arc<Style>>,
+
     /// Global zoom factor of the UI.
     ///
     /// This is used to calculate the `pixels_per_point`
@@ -233,9 +288,27 @@ pub struct Options {
     #[cfg_attr(feature = "serde", serde(skip))]
     pub zoom_with_keyboard: bool,
 
+    /// If `true`, a widget that gets keyboard focus with Tab or the arrow keys
+    /// is scrolled into view, if it is in a [`crate::ScrollArea`].
+    ///
+    /// Default is `true`.
+    pub scroll_to_focused: bool,
+
     /// Controls the tessellator.
     pub tessellation_options: epaint::TessellationOptions,
 
+    /// What color space to convert vertex colors into before handing them to the backend.
+    ///
+    /// Only change this if your backend blends in the chosen color space,
+    /// e.g. [`epaint::ecolor::ColorSpace::LinearSrgb`] when painting into an sRGB framebuffer.
+    /// The choice is reported to the backend in [`crate::FullOutput::color_space`].
+    ///
+    /// The backends that come with egui (`egui_glow`, `egui-wgpu` and `eframe`) only support sRGB,
+    /// so this is only useful with a custom backend.
+    ///
+    /// Default: [`epaint::ecolor::ColorSpace::Srgb`].
+    pub color_space: epaint::ecolor::ColorSpace,
+
     /// If any widget moves or changes id, repaint everything.
     ///
     /// It is recommended you keep this OFF, as it may
@@ -284,6 +357,25 @@ pub struct Options {
     /// By default this is `true` in debug builds.
     pub warn_on_id_clash: bool,
 
+    /// Show how many warnings egui has found in the top right corner of the screen,
+    /// until they are taken with [`crate::Context::take_warnings`].
+    ///
+    /// Default is `false`.
+    pub show_warnings_badge: bool,
+
+    /// Turn misuse of egui that is otherwise silently ignored into errors, e.g.
+    /// * creating widgets outside of a pass,
+    /// * calling [`crate::Context::read_response`] with an id that is never created,
+    /// * locking the [`crate::Context`] from within one of its closures, which would deadlock.
+    ///
+    /// In debug builds such misuse panics with a helpful message,
+    /// in release builds it is reported as a [`crate::WarningKind::Misuse`].
+    ///
+    /// See also [`crate::Context::report_misuse`].
+    ///
+    /// Default is `false`.
+    pub strict_mode: bool,
+
     /// Options related to input state handling.
     pub input_options: crate::input_state::InputOptions,
 
@@ -299,6 +391,44 @@ pub struct Options {
     ///
     /// Default is `false`.
     pub reduce_texture_memory: bool,
+
+    /// If `true`, the positions of [`crate::Window`]s and other areas are stored
+    /// together with a signature of the monitor they were shown on.
+    ///
+    /// When the monitor layout changes (e.g. a laptop is undocked),
+    /// areas that would end up off-screen are placed again
+    /// instead of being restored to their old positions.
+    ///
+    /// Default is `true`.
+    pub remember_monitor_layout: bool,
+
+    /// If `true`, egui emits more [`crate::output::OutputEvent`]s,
+    /// e.g. when a [`crate::Window`] is opened or closed.
+    ///
+    /// Together with the events of all the widgets, this is a stream of everything the user did,
+    /// useful for analytics or macro recording.
+    ///
+    /// Default is `false`.
+    pub ui_events: bool,
+
+    /// How long a pass may take, in seconds, before expensive widgets start deferring
+    /// themselves to the next pass.
+    ///
+    /// See [`crate::Ui::defer_if_over_budget`] and [`crate::Context::frame_budget_exceeded`].
+    /// Only has an effect with the `frame_budget` feature, which measures how long each pass takes.
+    ///
+    /// Default is `None` (no budget).
+    pub frame_budget: Option<f32>,
+
+    /// How to repaint while the window is hidden, see [`crate::RawInput::occluded`].
+    ///
+    /// Default is [`RepaintThrottle::Pause`].
+    pub occluded_repaint: RepaintThrottle,
+
+    /// How to repaint while saving power, see [`crate::RawInput::power_saving`].
+    ///
+    /// Default is 30 frames per second.
+    pub power_saving_repaint: RepaintThrottle,
 }
 
 impl Default for Options {
@@ -309,23 +439,45 @@ impl Default for Options {
             theme_preference: Default::default(),
             fallback_theme: Theme::Dark,
             system_theme: None,
+            theme_transition_time: 0.25,
+            theme_transition_style: None,
             zoom_factor: 1.0,
             zoom_with_keyboard: true,
+            scroll_to_focused: true,
             tessellation_options: Default::default(),
+            color_space: Default::default(),
             repaint_on_widget_change: false,
             max_passes: NonZeroUsize::new(2).unwrap(),
             screen_reader: false,
             preload_font_glyphs: true,
             warn_on_id_clash: cfg!(debug_assertions),
+            show_warnings_badge: false,
+            strict_mode: false,
 
             // Input:
             input_options: Default::default(),
             reduce_texture_memory: false,
+            remember_monitor_layout: true,
+            ui_events: false,
+            frame_budget: None,
+            occluded_repaint: RepaintThrottle::Pause,
+            power_saving_repaint: RepaintThrottle::MinInterval(1.0 / 30.0),
         }
     }
 }
 
 impl Options {
+    /// The delay to actually repaint after, see [`Self::occluded_repaint`] and [`Self::power_saving_repaint`].
+    pub(crate) fn throttle_repaint(&self, raw: &RawInput, mut delay: Duration) -> Duration {
+        if raw.occluded {
+            delay = self.occluded_repaint.apply(delay);
+        }
+        if raw.power_saving {
+            delay = self.power_saving_repaint.apply(delay);
+        }
+        delay
+    }
+
     pub(crate) fn begin_pass(&mut self, new_raw_input: &RawInput) {
         self.system_theme = new_raw_input.system_theme;
     }
@@ -340,12 +492,33 @@ impl Options {
     }
 
     pub(crate) fn style(&self) -> &std::sync::Arc<Style> {
+        if let Some(style) = &self.theme_transition_style {
+            return style;
+        }
         match self.theme() {
             Theme::Dark => &self.dark_style,
             Theme::Light => &self.light_style,
         }
     }
 
+    /// Cross-fade between the light and dark style.
+    ///
+    /// `how_dark` is `0.0` for the light style and `1.0` for the dark style.
+    pub(crate) fn set_theme_transition(&mut self, how_dark: f32) {
+        self.theme_transition_style = (0.0 < how_dark && how_dark < 1.0).then(|| {
+            let target = match self.theme() {
+                Theme::Dark => &self.dark_style,
+                Theme::Light => &self.light_style,
+            };
+            let mut style = (**target).clone();
+            style.visuals = self
+                .light_style
+                .visuals
+                .lerp(&self.dark_style.visuals, how_dark);
+            std::sync::Arc::new(style)
+        });
+    }
+
     pub(crate) fn style_mut(&mut self) -> &mut std::sync::Arc<Style> {
         match self.theme() {
             Theme::Dark => &mut self.dark_style,
@@ -365,16 +538,27 @@ impl Options {
             theme_preference,
             fallback_theme: _,
             system_theme: _,
+            theme_transition_time,
+            theme_transition_style: _,
             zoom_factor: _, // TODO(emilk)
             zoom_with_keyboard,
+            scroll_to_focused,
             tessellation_options,
+            color_space: _, // only for custom backends, so no point in changing it here
             repaint_on_widget_change,
             max_passes,
             screen_reader: _, // needs to come from the integration
             preload_font_glyphs: _,
             warn_on_id_clash,
+            show_warnings_badge,
+            strict_mode,
             input_options,
             reduce_texture_memory,
+            remember_monitor_layout,
+            ui_events,
+            frame_budget,
+            occluded_repaint,
+            power_saving_repaint,
         } = self;
 
         use crate::Widget as _;
@@ -398,9 +582,51 @@ impl Options {
                     "Zoom with keyboard (Cmd +, Cmd -, Cmd 0)",
                 );
 
+                ui.checkbox(
+                    scroll_to_focused,
+                    "Scroll widgets into view when focused with the keyboard",
+                );
+
                 ui.checkbox(warn_on_id_clash, "Warn if two widgets have the same Id");
 
+                ui.checkbox(show_warnings_badge, "Show the number of egui warnings");
+
+                ui.checkbox(strict_mode, "Strict mode: turn misuse into errors");
+
                 ui.checkbox(reduce_texture_memory, "Reduce texture memory");
+
+                ui.checkbox(
+                    remember_monitor_layout,
+                    "Forget window positions when the monitor layout changes",
+                );
+
+                ui.checkbox(ui_events, "Output events when windows are opened or closed");
+
+                ui.horizontal(|ui| {
+                    let mut has_budget = frame_budget.is_some();
+                    ui.checkbox(&mut has_budget, "Frame budget:");
+                    match (has_budget, frame_budget.as_mut()) {
+                        (true, Some(budget)) => {
+                            ui.add(
+                                crate::DragValue::new(budget)
+                                    .range(0.0..=1.0)
+                                    .speed(0.001)
+                                    .suffix(" s"),
+                            );
+                        }
+                        (true, None) => *frame_budget = Some(1.0 / 60.0),
+                        (false, _) => *frame_budget = None,
+                    }
+                });
+
+                ui.horizontal(|ui| {
+                    ui.label("Repaint while hidden:");
+                    occluded_repaint.ui(ui);
+                });
+                ui.horizontal(|ui| {
+                    ui.label("Repaint while saving power:");
+                    power_saving_repaint.ui(ui);
+                });
             });
 
         CollapsingHeader::new("🎑 Style")
@@ -408,6 +634,16 @@ impl Options {
             .show(ui, |ui| {
                 theme_preference.radio_buttons(ui);
 
+                ui.horizontal(|ui| {
+                    ui.label("Theme transition:");
+                    ui.add(
+                        crate::DragValue::new(theme_transition_time)
+                            .range(0.0..=2.0)
+                            .speed(0.01)
+                            .suffix(" s"),
+                    );
+                });
+
                 let style = std::sync::Arc::make_mut(match theme {
                     Theme::Dark => dark_style,
                     Theme::Light => light_style,
@@ -434,6 +670,60 @@ impl Options {
     }
 }
 
+/// How to slow down repaints,
+/// see [`Options::occluded_repaint`] and [`Options::power_saving_repaint`].
+///
+/// This applies to all repaints requested with [`crate::Context::request_repaint`] and friends,
+/// including the ones of animations.
+/// Input events still repaint as usual, if the integration asks for it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum RepaintThrottle {
+    /// Repaint as requested.
+    Off,
+
+    /// Wait at least this many seconds before repainting,
+    /// i.e. run animations at a lower frame rate.
+    MinInterval(f32),
+
+    /// Ignore requested repaints, i.e. pause all animations.
+    Pause,
+}
+
+impl RepaintThrottle {
+    /// The delay to actually repaint after, when a repaint is requested after `delay`.
+    pub fn apply(self, delay: Duration) -> Duration {
+        match self {
+            Self::Off => delay,
+            Self::MinInterval(seconds) => {
+                delay.max(Duration::try_from_secs_f32(seconds.max(0.0)).unwrap_or(Duration::MAX))
+            }
+            Self::Pause => Duration::MAX,
+        }
+    }
+
+    /// Show a selector for the throttle.
+    pub fn ui(&mut self, ui: &mut crate::Ui) {
+        ui.selectable_value(self, Self::Off, "Off");
+        if ui
+            .selectable_label(matches!(self, Self::MinInterval(_)), "Min interval")
+            .clicked()
+            && !matches!(self, Self::MinInterval(_))
+        {
+            *self = Self::MinInterval(1.0 / 30.0);
+        }
+        ui.selectable_value(self, Self::Pause, "Pause");
+        if let Self::MinInterval(seconds) = self {
+            ui.add(
+                crate::DragValue::new(seconds)
+                    .range(0.0..=10.0)
+                    .speed(0.001)
+                    .suffix(" s"),
+            );
+        }
+    }
+}
+
 // ----------------------------------------------------------------------------
 
 /// The state of the interaction in egui,
@@ -458,6 +748,18 @@ pub(crate) struct InteractionState {
     /// as that can only happen after the mouse has moved a bit
     /// (at least if the widget is interesated in both clicks and drags).
     pub potential_drag_id: Option<Id>,
+
+    /// A widget that has captured the pointer, and will receive all pointer input
+    /// until it releases it.
+    ///
+    /// See [`crate::Response::request_pointer_capture`].
+    pub pointer_capture: Option<Id>,
+
+    /// A widget that has locked the pointer, together with the position
+    /// of the pointer when the lock started.
+    ///
+    /// See [`crate::Response::request_pointer_lock`].
+    pub pointer_lock: Option<(Id, Option<Pos2>)>,
 }
 
 /// Keeps tracks of what widget has keyboard focus
@@ -485,6 +787,15 @@ pub(crate) struct Focus {
     /// Set when looking for widget with navigational keys like arrows, tab, shift+tab.
     focus_direction: FocusDirection,
 
+    /// A widget that got focus with the keyboard, and should be scrolled into view
+    /// the next time it is shown.
+    scroll_to_focused: Option<Id>,
+
+    /// The last widget that got focus with the keyboard.
+    ///
+    /// Used for [`crate::style::FocusRing::keyboard_only`].
+    keyboard_focused: Option<Id>,
+
     /// The top-most modal layer from the previous frame.
     top_modal_layer: Option<LayerId>,
 
@@ -529,6 +840,10 @@ impl Focus {
         if let Some(id) = self.id_next_frame.take() {
             self.focused_widget = Some(FocusWidget::new(id));
         }
+        if self.scroll_to_focused != self.focused() {
+            // Focus moved on without the widget being shown.
+            self.scroll_to_focused = None;
+        }
         let event_filter = self.focused_widget.map(|w| w.filter).unwrap_or_default();
 
         #[cfg(feature = "accesskit")]
@@ -589,6 +904,7 @@ impl Focus {
         if self.focus_direction.is_cardinal() {
             if let Some(found_widget) = self.find_widget_in_direction(used_ids) {
                 self.focused_widget = Some(FocusWidget::new(found_widget));
+                self.focused_with_keyboard(Some(found_widget));
             }
         }
 
@@ -605,6 +921,11 @@ impl Focus {
         self.top_modal_layer = self.top_modal_layer_current_frame.take();
     }
 
+    fn focused_with_keyboard(&mut self, id: Option<Id>) {
+        self.scroll_to_focused = id;
+        self.keyboard_focused = id;
+    }
+
     pub(crate) fn had_focus_last_frame(&self, id: impl Into<Id>) -> bool {
         self.id_previous_frame == Some(id.into())
     }
@@ -628,6 +949,7 @@ impl Focus {
 
         if self.give_to_next && !self.had_focus_last_frame(id) {
             self.focused_widget = Some(FocusWidget::new(id));
+            self.focused_with_keyboard(Some(id));
             self.give_to_next = false;
         } else if self.focused() == Some(id) {
             if self.focus_direction == FocusDirection::Next {
@@ -636,6 +958,7 @@ impl Focus {
                 self.reset_focus();
             } else if self.focus_direction == FocusDirection::Previous {
                 self.id_next_frame = self.last_interested; // frame-delay so gained_focus works
+                self.focused_with_keyboard(self.last_interested);
                 self.reset_focus();
             }
         } else if self.focus_direction == FocusDirection::Next
@@ -644,6 +967,7 @@ impl Focus {
         {
             // nothing has focus and the user pressed tab - give focus to the first widgets that wants it:
             self.focused_widget = Some(FocusWidget::new(id));
+            self.focused_with_keyboard(Some(id));
             self.reset_focus();
         } else if self.focus_direction == FocusDirection::Previous
             && self.focused_widget.is_none()
@@ -651,6 +975,7 @@ impl Focus {
         {
             // nothing has focus and the user pressed Shift+Tab - give focus to the last widgets that wants it:
             self.focused_widget = self.last_interested.map(FocusWidget::new);
+            self.focused_with_keyboard(self.last_interested);
             self.reset_focus();
         }
 
@@ -756,11 +1081,25 @@ impl Memory {
         self.areas.retain(|id, _| viewports.contains(id));
         self.popups.retain(|id, _| viewports.contains(id));
 
-        self.areas.entry(self.viewport_id).or_default();
+        self.options.begin_pass(new_raw_input);
 
-        // self.interactions  is handled elsewhere
+        let monitor_signature = if self.options.remember_monitor_layout {
+            new_raw_input
+                .viewports
+                .get(&new_raw_input.viewport_id)
+                .and_then(|info| info.monitor_size)
+                .map(|monitor_size| {
+                    crate::util::hash((monitor_size.x.to_bits(), monitor_size.y.to_bits()))
+                })
+        } else {
+            None
+        };
+        self.areas
+            .entry(self.viewport_id)
+            .or_default()
+            .begin_pass(monitor_signature, new_raw_input.screen_rect);
 
-        self.options.begin_pass(new_raw_input);
+        // self.interactions  is handled elsewhere
 
         self.focus
             .entry(self.viewport_id)
@@ -968,11 +1307,138 @@ impl Memory {
         }
     }
 
+    /// Move all state stored for the `old` [`Id`] to the `new` one.
+    ///
+    /// Use this when you change the id of a widget or window (e.g. by renaming the title of a [`crate::Window`])
+    /// to keep its state (position, size, scroll offset, collapsed or not, …),
+    /// e.g. when loading memory persisted by an older version of your app.
+    ///
+    /// This moves everything in [`Self::data`] and the [`Areas`] stored for the id,
+    /// as well as the resize and collapsing state of a [`crate::Window`] with that id.
+    /// State stored under other ids derived from the id (e.g. of widgets inside a window)
+    /// is not moved.
+    pub fn rename_id(&mut self, old: impl Into<Id>, new: impl Into<Id>) {
+        self.rename_ids([(old.into(), new.into())]);
+    }
+
+    /// Like [`Self::rename_id`], for many ids at once.
+    ///
+    /// The renames may form chains or swaps, e.g. `[(a, b), (b, a)]`.
+    pub fn rename_ids(&mut self, renames: impl IntoIterator<Item = (Id, Id)>) {
+        let renames: Vec<(Id, Id)> = renames.into_iter().collect();
+
+        // A `Window` stores some of its state under derived ids:
+        let data_renames: Vec<(Id, Id)> = renames
+            .iter()
+            .flat_map(|&(old, new)| {
+                [
+                    (old, new),
+                    (old.with("resize"), new.with("resize")),
+                    (old.with("collapsing"), new.with("collapsing")),
+                ]
+            })
+            .collect();
+        self.data.rename_ids(&data_renames);
+
+        for areas in self.areas.values_mut() {
+            areas.rename_ids(&renames);
+        }
+    }
+
     /// Obtain the previous rectangle of an area.
     pub fn area_rect(&self, id: impl Into<Id>) -> Option<Rect> {
         self.areas().get(id.into()).map(|state| state.rect())
     }
 
+    /// Capture the state of the ui, so that it can be restored with [`Self::restore_session`],
+    /// e.g. the next time your app starts.
+    ///
+    /// Besides what is persisted with [`Memory`], this includes the focused widget,
+    /// the open popup of the current viewport,
+    /// and the temporary values registered with [`Self::include_in_session`].
+    pub fn save_session(&self) -> SessionState {
+        let Self {
+            options,
+            data,
+            areas,
+            to_global,
+            session_entries,
+            ..
+        } = self;
+
+        let mut volatile = IdTypeMap::default();
+        for entry in session_entries {
+            (entry.save)(data, entry.id, &mut volatile);
+        }
+
+        SessionState {
+            memory: Self {
+                options: options.clone(),
+                data: data.clone(),
+                areas: areas.clone(),
+                to_global: to_global.clone(),
+                ..Default::default()
+            },
+            focused: self.focused(),
+            open_popup: self
+                .popups
+                .get(&self.viewport_id)
+                .map(|popup| (popup.id, popup.pos)),
+            volatile,
+        }
+    }
+
+    /// Restore the state captured with [`Self::save_session`].
+    ///
+    /// Caches and ongoing interactions are left as they are.
+    pub fn restore_session(&mut self, session: SessionState) {
+        let SessionState {
+            memory,
+            focused,
+            open_popup,
+            mut volatile,
+        } = session;
+
+        self.options = memory.options;
+        self.data = memory.data;
+        self.areas = memory.areas;
+        self.to_global = memory.to_global;
+        for entry in &self.session_entries {
+            (entry.restore)(&mut volatile, entry.id, &mut self.data);
+        }
+
+        if let Some(focused) = focused {
+            self.request_focus(focused);
+        }
+        match open_popup {
+            Some((id, pos)) => {
+                self.popups
+                    .insert(self.viewport_id, OpenPopup::new(id, pos));
+            }
+            None => {
+                self.popups.remove(&self.viewport_id);
+            }
+        }
+    }
+
+    /// Include a temporary value of [`Self::data`] in [`Self::save_session`],
+    /// e.g. the selected tab of your app that you store with [`IdTypeMap::insert_temp`].
+    ///
+    /// Values stored with [`IdTypeMap::insert_persisted`], like how far each [`crate::ScrollArea`]
+    /// is scrolled, are always included, so this is only needed for the other ones.
+    ///
+    /// The registration itself is not saved, so call this at startup, before [`Self::restore_session`].
+    pub fn include_in_session<T: SerializableAny>(&mut self, id: impl Into<Id>) {
+        let entry = SessionEntry::new::<T>(id.into());
+        if !self
+            .session_entries
+            .iter()
+            .any(|e| e.id == entry.id && e.type_id == entry.type_id)
+        {
+            self.session_entries.push(entry);
+        }
+    }
+
     pub(crate) fn interaction(&self) -> &InteractionState {
         self.interactions
             .get(&self.viewport_id)
@@ -990,6 +1456,88 @@ impl Memory {
     pub(crate) fn focus_mut(&mut self) -> &mut Focus {
         self.focus.entry(self.viewport_id).or_default()
     }
+
+    /// The widget with focus, if it got it with the keyboard.
+    pub(crate) fn keyboard_focused(&self) -> Option<Id> {
+        let focus = self.focus()?;
+        focus
+            .focused()
+            .filter(|&id| focus.keyboard_focused == Some(id))
+    }
+
+    /// Should the widget be scrolled into view, because it just got focus with the keyboard?
+    ///
+    /// Only returns `true` once.
+    pub(crate) fn take_scroll_to_focused(&mut self, id: Id) -> bool {
+        if !self.options.scroll_to_focused {
+            return false;
+        }
+        let focus = self.focus_mut();
+        if focus.scroll_to_focused == Some(id) {
+            focus.scroll_to_focused = None;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// The state of the ui, so that you can restore it where the user left off.
+///
+/// See [`crate::Context::save_session`] and [`crate::Context::restore_session`].
+/// Serialize it to store it between runs (requires the `persistence` feature).
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "persistence", serde(default))]
+pub struct SessionState {
+    /// The persisted parts of [`Memory`]: options, window positions and [`Memory::data`].
+    ///
+    /// The latter includes how far each [`crate::ScrollArea`] is scrolled,
+    /// which [`crate::CollapsingHeader`]s are open, etc.
+    pub memory: Memory,
+
+    /// The widget that had keyboard focus.
+    pub focused: Option<Id>,
+
+    /// The popup that was open, and where it was opened (if known).
+    pub open_popup: Option<(Id, Option<Pos2>)>,
+
+    /// The temporary values registered with [`Memory::include_in_session`].
+    ///
+    /// They are stored as persisted values here, so that they are serialized too.
+    pub volatile: IdTypeMap,
+}
+
+/// A temporary value of [`Memory::data`] to include in [`SessionState::volatile`].
+#[derive(Clone, Copy, Debug)]
+struct SessionEntry {
+    id: Id,
+    type_id: std::any::TypeId,
+
+    /// Copy the value from [`Memory::data`] into the session.
+    save: fn(&IdTypeMap, Id, &mut IdTypeMap),
+
+    /// Copy the value from the session back into [`Memory::data`].
+    restore: fn(&mut IdTypeMap, Id, &mut IdTypeMap),
+}
+
+impl SessionEntry {
+    fn new<T: SerializableAny>(id: Id) -> Self {
+        Self {
+            id,
+            type_id: std::any::TypeId::of::<T>(),
+            save: |data, id, session| {
+                if let Some(value) = data.get_temp::<T>(id) {
+                    session.insert_persisted(id, value);
+                }
+            },
+            restore: |session, id, data| {
+                if let Some(value) = session.get_persisted::<T>(id) {
+                    data.insert_temp(id, value);
+                }
+            },
+        }
+    }
 }
 
 /// State of an open popup.
@@ -1162,6 +1710,10 @@ pub struct Areas {
     ///
     /// The parent sublayer is moved directly above the child sublayers in the ordering.
     sublayers: ahash::HashMap<LayerId, HashSet<LayerId>>,
+
+    /// Signature of the current monitor layout, stamped on all areas that are shown.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    monitor_signature: Option<u64>,
 }
 
 impl Areas {
@@ -1191,7 +1743,10 @@ impl Areas {
         }
     }
 
-    pub(crate) fn set_state(&mut self, layer_id: LayerId, state: area::AreaState) {
+    pub(crate) fn set_state(&mut self, layer_id: LayerId, mut state: area::AreaState) {
+        if self.monitor_signature.is_some() {
+            state.monitor_signature = self.monitor_signature;
+        }
         self.visible_areas_current_frame.insert(layer_id);
         self.areas.insert(layer_id.id, state);
         if !self.order.contains(&layer_id) {
@@ -1199,6 +1754,42 @@ impl Areas {
         }
     }
 
+    /// Move the state of the areas with the `old` ids to the `new` ids,
+    /// keeping their place in the layer order.
+    pub(crate) fn rename_ids(&mut self, renames: &[(Id, Id)]) {
+        let moved: Vec<_> = renames
+            .iter()
+            .filter_map(|&(old, new)| Some((new, self.areas.remove(&old)?)))
+            .collect();
+        self.areas.extend(moved);
+
+        let rename = |layer_id: LayerId| {
+            renames
+                .iter()
+                .find(|(old, _)| *old == layer_id.id)
+                .map_or(layer_id, |&(_, new)| LayerId::new(layer_id.order, new))
+        };
+        let rename_set = |set: &mut ahash::HashSet<LayerId>| {
+            *set = set.iter().copied().map(rename).collect();
+        };
+        rename_set(&mut self.visible_areas_last_frame);
+        rename_set(&mut self.visible_areas_current_frame);
+        rename_set(&mut self.wants_to_be_on_top);
+        for layer_id in &mut self.order {
+            *layer_id = rename(*layer_id);
+        }
+        self.order_map = self
+            .order
+            .iter()
+            .enumerate()
+            .map(|(i, id)| (*id, i))
+            .collect();
+        self.sublayers = std::mem::take(&mut self.sublayers)
+            .into_iter()
+            .map(|(parent, children)| (rename(parent), children.into_iter().map(rename).collect()))
+            .collect();
+    }
+
     /// Top-most layer at the given position.
     pub fn layer_id_at(
         &self,
@@ -1313,6 +1904,28 @@ impl Areas {
         self.parent_layer(*layer).is_some()
     }
 
+    /// Forget the positions of areas that were placed on another monitor layout
+    /// and would now be off-screen.
+    pub(crate) fn begin_pass(&mut self, monitor_signature: Option<u64>, screen_rect: Option<Rect>) {
+        self.monitor_signature = monitor_signature;
+
+        let (Some(monitor_signature), Some(screen_rect)) = (monitor_signature, screen_rect) else {
+            return;
+        };
+
+        for state in self.areas.values_mut() {
+            if state
+                .monitor_signature
+                .is_some_and(|signature| signature != monitor_signature)
+            {
+                if !screen_rect.contains(state.left_top_pos()) {
+                    state.pivot_pos = None;
+                }
+                state.monitor_signature = Some(monitor_signature);
+            }
+        }
+    }
+
     pub(crate) fn end_pass(&mut self) {
         let Self {
             visible_areas_last_frame,
@@ -1364,6 +1977,45 @@ fn memory_impl_send_sync() {
     assert_send_sync::<Memory>();
 }
 
+#[test]
+fn rename_ids() {
+    let mut memory = Memory::default();
+    let (a, b, c) = (Id::new("a"), Id::new("b"), Id::new("c"));
+    memory.data.insert_persisted(a, 1_i32);
+    memory.data.insert_temp(a, "a");
+    memory.data.insert_persisted(a.with("collapsing"), true);
+    memory.data.insert_persisted(b, 2_i32);
+    memory.data.insert_persisted(c, 3_i32);
+    let area_state = area::AreaState {
+        pivot_pos: Some(Pos2::new(10.0, 20.0)),
+        ..Default::default()
+    };
+    memory
+        .areas_mut()
+        .set_state(LayerId::new(Order::Middle, a), area_state);
+
+    memory.rename_id(a, b);
+    assert_eq!(memory.data.get_persisted::<i32>(a), None);
+    assert_eq!(memory.data.get_temp::<&str>(a), None);
+    assert_eq!(memory.data.get_persisted::<i32>(b), Some(1));
+    assert_eq!(memory.data.get_temp::<&str>(b), Some("a"));
+    assert_eq!(
+        memory.data.get_persisted::<bool>(b.with("collapsing")),
+        Some(true)
+    );
+    assert!(memory.areas().get(a).is_none());
+    assert_eq!(
+        memory.areas().get(b).unwrap().pivot_pos,
+        area_state.pivot_pos
+    );
+    assert_eq!(memory.areas().order(), &[LayerId::new(Order::Middle, b)]);
+
+    // Swap:
+    memory.rename_ids([(b, c), (c, b)]);
+    assert_eq!(memory.data.get_persisted::<i32>(b), Some(3));
+    assert_eq!(memory.data.get_persisted::<i32>(c), Some(1));
+}
+
 #[test]
 fn order_map_total_ordering() {
     let mut layers = [
@@ -1407,3 +2059,75 @@ fn order_map_total_ordering() {
         }
     }
 }
+
+#[test]
+fn forget_off_screen_areas_after_monitor_change() {
+    let on_screen = LayerId::new(Order::Middle, Id::new("on_screen"));
+    let off_screen = LayerId::new(Order::Middle, Id::new("off_screen"));
+    let big_screen = Rect::from_min_size(Pos2::ZERO, vec2(3000.0, 1000.0));
+    let small_screen = Rect::from_min_size(Pos2::ZERO, vec2(1000.0, 1000.0));
+
+    let mut areas = Areas::default();
+    areas.begin_pass(Some(1), Some(big_screen));
+    for (layer, pos) in [
+        (on_screen, Pos2::new(100.0, 100.0)),
+        (off_screen, Pos2::new(2000.0, 100.0)),
+    ] {
+        let state = crate::AreaState {
+            pivot_pos: Some(pos),
+            ..Default::default()
+        };
+        areas.set_state(layer, state);
+    }
+    areas.end_pass();
+
+    // Same monitor layout: nothing is forgotten, even if it is off-screen.
+    areas.begin_pass(Some(1), Some(small_screen));
+    assert!(areas.get(off_screen.id).unwrap().pivot_pos.is_some());
+
+    areas.begin_pass(Some(2), Some(small_screen));
+    assert_eq!(
+        areas.get(on_screen.id).unwrap().pivot_pos,
+        Some(Pos2::new(100.0, 100.0))
+    );
+    assert_eq!(areas.get(off_screen.id).unwrap().pivot_pos, None);
+    assert_eq!(areas.get(on_screen.id).unwrap().monitor_signature, Some(2));
+}
+
+#[test]
+fn keep_area_positions_without_remember_monitor_layout() {
+    let off_screen = LayerId::new(Order::Middle, Id::new("off_screen"));
+    let raw_input = |monitor_width: f32| {
+        let mut raw_input = RawInput {
+            screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(1000.0, 1000.0))),
+            ..Default::default()
+        };
+        raw_input
+            .viewports
+            .entry(ViewportId::ROOT)
+            .or_default()
+            .monitor_size = Some(vec2(monitor_width, 1000.0));
+        raw_input
+    };
+    let viewports = ViewportIdSet::from_iter([ViewportId::ROOT]);
+
+    for remember_monitor_layout in [true, false] {
+        let mut memory = Memory::default();
+        memory.options.remember_monitor_layout = remember_monitor_layout;
+
+        memory.begin_pass(&raw_input(3000.0), &viewports);
+        let state = crate::AreaState {
+            pivot_pos: Some(Pos2::new(2000.0, 100.0)),
+            ..Default::default()
+        };
+        memory.areas_mut().set_state(off_screen, state);
+
+        memory.begin_pass(&raw_input(1000.0), &viewports);
+        let pivot_pos = memory.areas().get(off_screen.id).unwrap().pivot_pos;
+        assert_eq!(
+            pivot_pos.is_none(),
+            remember_monitor_layout,
+            "remember_monitor_layout: {remember_monitor_layout}"
+        );
+    }
+}
@@ -118,3 +118,178 @@ impl ThemePreference {
         });
     }
 }
+
+/// The version of the theme file format written by [`Theme::to_json`].
+///
+/// Bumped whenever a change to [`crate::Style`] means older versions of egui can no longer
+/// make sense of the file.
+#[cfg(feature = "theme_json")]
+pub const THEME_FILE_VERSION: u32 = 1;
+
+/// Error when reading a theme file with [`Theme::from_json`].
+#[cfg(feature = "theme_json")]
+#[derive(Debug)]
+pub enum ThemeFileError {
+    /// The file is not valid JSON, or doesn't describe a theme.
+    Json(serde_json::Error),
+
+    /// The file lacks a `"version"`.
+    MissingVersion,
+
+    /// The file was written by a newer version of egui.
+    UnsupportedVersion(u64),
+}
+
+#[cfg(feature = "theme_json")]
+impl From<serde_json::Error> for ThemeFileError {
+    fn from(err: serde_json::Error) -> Self {
+        Self::Json(err)
+    }
+}
+
+#[cfg(feature = "theme_json")]
+impl std::fmt::Display for ThemeFileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Json(err) => write!(f, "Invalid theme file: {err}"),
+            Self::MissingVersion => f.write_str("Invalid theme file: missing \"version\""),
+            Self::UnsupportedVersion(version) => write!(
+                f,
+                "Theme file version {version} is not supported, expected at most {THEME_FILE_VERSION}"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "theme_json")]
+impl std::error::Error for ThemeFileError {}
+
+/// Theme files, so apps can ship themes and let their users import and export them.
+///
+/// A theme file looks like this:
+///
+/// ```json
+/// {
+///   "version": 1,
+///   "theme": "Dark",
+///   "style": { "visuals": { "panel_fill": [30, 30, 40, 255] } }
+/// }
+/// ```
+///
+/// Anything missing from `"style"` falls back to the default style of the theme,
+/// and fields that this version of egui doesn't know about are ignored.
+/// This means a theme file only needs to mention what it changes,
+/// and it keeps working when egui adds, renames, or removes style options.
+///
+/// Load a theme file (e.g. whenever it changes on disk) with:
+///
+/// ```
+/// # let ctx = egui::Context::default();
+/// # let json = egui::Theme::Light.to_json(&egui::Style::default()).unwrap();
+/// match egui::Theme::from_json(&json) {
+///     Ok((theme, style)) => ctx.set_style_of(theme, style),
+///     Err(err) => eprintln!("{err}"),
+/// }
+/// ```
+#[cfg(feature = "theme_json")]
+impl Theme {
+    /// Read a theme file written by [`Self::to_json`] (or by hand).
+    ///
+    /// Returns which theme the style is for, and the style.
+    ///
+    /// # Errors
+    /// If the file isn't valid JSON, has no version, or is from a newer version of egui.
+    pub fn from_json(json: &str) -> Result<(Self, crate::Style), ThemeFileError> {
+        let mut file: serde_json::Value = serde_json::from_str(json)?;
+
+        let version = file
+            .get("version")
+            .and_then(|version| version.as_u64())
+            .ok_or(ThemeFileError::MissingVersion)?;
+        if u64::from(THEME_FILE_VERSION) < version {
+            return Err(ThemeFileError::UnsupportedVersion(version));
+        }
+
+        let theme: Self = serde_json::from_value(file["theme"].take())?;
+
+        let mut style = serde_json::to_value(theme.default_style())?;
+        merge_json(&mut style, file["style"].take());
+        Ok((theme, serde_json::from_value(style)?))
+    }
+
+    /// Write a theme file with the given style for this theme, to be read by [`Self::from_json`].
+    ///
+    /// # Errors
+    /// Only if the style can't be represented as JSON, which shouldn't happen.
+    pub fn to_json(self, style: &crate::Style) -> Result<String, ThemeFileError> {
+        let file = serde_json::json!({
+            "version": THEME_FILE_VERSION,
+            "theme": self,
+            "style": style,
+        });
+        Ok(serde_json::to_string_pretty(&file)?)
+    }
+}
+
+/// Recursively overwrite the fields of `base` with the ones in `patch`.
+#[cfg(feature = "theme_json")]
+fn merge_json(base: &mut serde_json::Value, patch: serde_json::Value) {
+    match (base, patch) {
+        (serde_json::Value::Object(base), serde_json::Value::Object(patch)) => {
+            for (key, value) in patch {
+                if let Some(base_value) = base.get_mut(&key) {
+                    merge_json(base_value, value);
+                } else {
+                    base.insert(key, value);
+                }
+            }
+        }
+        (base, patch) => *base = patch,
+    }
+}
+
+#[cfg(all(test, feature = "theme_json"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_theme_file_roundtrip() {
+        let mut style = Theme::Light.default_style();
+        style.visuals.panel_fill = crate::Color32::from_rgb(1, 2, 3);
+        style.spacing.item_spacing = crate::vec2(3.0, 4.0);
+
+        let json = Theme::Light.to_json(&style).unwrap();
+        let (theme, loaded) = Theme::from_json(&json).unwrap();
+        assert_eq!(theme, Theme::Light);
+        assert_eq!(loaded.visuals, style.visuals);
+        assert_eq!(loaded.spacing, style.spacing);
+    }
+
+    #[test]
+    fn test_theme_file_tolerance() {
+        let json = r#"{
+            "version": 1,
+            "theme": "Light",
+            "style": {
+                "visuals": { "panel_fill": [1, 2, 3, 255], "from_the_future": true },
+                "also_from_the_future": 42
+            }
+        }"#;
+        let (theme, style) = Theme::from_json(json).unwrap();
+        assert_eq!(theme, Theme::Light);
+        assert_eq!(style.visuals.panel_fill, crate::Color32::from_rgb(1, 2, 3));
+        assert_eq!(
+            style.visuals.window_fill,
+            crate::Visuals::light().window_fill
+        );
+
+        assert!(matches!(
+            Theme::from_json(r#"{ "version": 2, "theme": "Dark", "style": {} }"#),
+            Err(ThemeFileError::UnsupportedVersion(2))
+        ));
+        assert!(matches!(
+            Theme::from_json(r#"{ "theme": "Dark" }"#),
+            Err(ThemeFileError::MissingVersion)
+        ));
+    }
+}
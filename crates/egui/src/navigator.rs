@@ -0,0 +1,280 @@
+use crate::{Context, Id, Key, KeyboardShortcut, Modifiers, PointerButton};
+
+/// A back/forward history of pages ("routes"), for tools with several pages.
+///
+/// The navigator is stored in [`crate::Memory`], so you load it at the start of the pass and store it at the end.
+/// [`Self::handle_input`] goes back and forward with the extra mouse buttons (4 and 5)
+/// and the usual keyboard shortcuts, so all multi-page tools behave the same.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// #[derive(Clone, Debug, PartialEq)]
+/// enum Page {
+///     Home,
+///     Settings,
+/// }
+///
+/// let id = ui.id().with("navigator");
+/// let mut navigator = egui::Navigator::load_or(ui.ctx(), id, Page::Home);
+/// navigator.handle_input(ui.ctx());
+///
+/// ui.horizontal(|ui| {
+///     if ui.add_enabled(navigator.can_go_back(), egui::Button::new("⏴")).clicked() {
+///         navigator.back();
+///     }
+///     if ui.add_enabled(navigator.can_go_forward(), egui::Button::new("⏵")).clicked() {
+///         navigator.forward();
+///     }
+/// });
+///
+/// match navigator.current() {
+///     Page::Home => {
+///         if ui.link("Settings").clicked() {
+///             navigator.push(Page::Settings);
+///         }
+///     }
+///     Page::Settings => {
+///         ui.label("Settings");
+///     }
+/// }
+///
+/// if navigator.changed() {
+///     // e.g. reset the scroll position
+/// }
+///
+/// navigator.store(ui.ctx());
+/// # });
+/// ```
+#[derive(Clone, Debug)]
+pub struct Navigator<R> {
+    id: Id,
+    back: Vec<R>,
+    current: R,
+    forward: Vec<R>,
+
+    /// Has [`Self::current`] changed since we were loaded?
+    changed: bool,
+}
+
+impl<R: Clone + Send + Sync + 'static> Navigator<R> {
+    /// Go back.
+    pub const BACK_SHORTCUTS: [KeyboardShortcut; 2] = [
+        KeyboardShortcut::new(Modifiers::ALT, Key::ArrowLeft),
+        KeyboardShortcut::new(Modifiers::COMMAND, Key::OpenBracket),
+    ];
+
+    /// Go forward.
+    pub const FORWARD_SHORTCUTS: [KeyboardShortcut; 2] = [
+        KeyboardShortcut::new(Modifiers::ALT, Key::ArrowRight),
+        KeyboardShortcut::new(Modifiers::COMMAND, Key::CloseBracket),
+    ];
+
+    /// A new navigator, starting at the given route.
+    pub fn new(id: Id, home: R) -> Self {
+        Self {
+            id,
+            back: Vec::new(),
+            current: home,
+            forward: Vec::new(),
+            changed: false,
+        }
+    }
+
+    /// Load the navigator with the given id from [`crate::Memory`], if it was stored.
+    pub fn load(ctx: &Context, id: Id) -> Option<Self> {
+        let (back, current, forward) = ctx.data(|d| d.get_temp::<(Vec<R>, R, Vec<R>)>(id))?;
+        Some(Self {
+            id,
+            back,
+            current,
+            forward,
+            changed: false,
+        })
+    }
+
+    /// Load the navigator with the given id, or create a new one starting at `home`.
+    pub fn load_or(ctx: &Context, id: Id, home: R) -> Self {
+        Self::load(ctx, id).unwrap_or_else(|| Self::new(id, home))
+    }
+
+    /// Store the navigator in [`crate::Memory`], so it can be loaded the next pass.
+    pub fn store(self, ctx: &Context) {
+        if self.changed {
+            ctx.request_repaint();
+        }
+        let Self {
+            id,
+            back,
+            current,
+            forward,
+            changed: _,
+        } = self;
+        ctx.data_mut(|d| d.insert_temp(id, (back, current, forward)));
+    }
+
+    /// Go back or forward if the user pressed the extra mouse buttons,
+    /// [`Key::BrowserBack`], or any of [`Self::BACK_SHORTCUTS`] and [`Self::FORWARD_SHORTCUTS`].
+    ///
+    /// The keyboard shortcuts are consumed,
+    /// but ignored while a widget (e.g. a [`crate::TextEdit`]) has keyboard focus.
+    pub fn handle_input(&mut self, ctx: &Context) {
+        let keyboard = ctx.memory(|mem| mem.focused().is_none());
+        let (back, forward) = ctx.input_mut(|i| {
+            let mut back = i.pointer.button_pressed(PointerButton::Extra1);
+            let mut forward = i.pointer.button_pressed(PointerButton::Extra2);
+            if keyboard {
+                back |= i.consume_key(Modifiers::NONE, Key::BrowserBack);
+                back |= Self::BACK_SHORTCUTS.iter().any(|s| i.consume_shortcut(s));
+                forward |= Self::FORWARD_SHORTCUTS
+                    .iter()
+                    .any(|s| i.consume_shortcut(s));
+            }
+            (back, forward)
+        });
+        if back {
+            self.back();
+        }
+        if forward {
+            self.forward();
+        }
+    }
+
+    /// The route we are currently at.
+    pub fn current(&self) -> &R {
+        &self.current
+    }
+
+    /// Did the current route change since the navigator was loaded?
+    ///
+    /// Useful for e.g. resetting the scroll position of the page.
+    pub fn changed(&self) -> bool {
+        self.changed
+    }
+
+    /// Go to a new route, clearing the forward history.
+    pub fn push(&mut self, route: R) {
+        let previous = std::mem::replace(&mut self.current, route);
+        self.back.push(previous);
+        self.forward.clear();
+        self.changed = true;
+    }
+
+    /// Replace the current route, without adding it to the history.
+    pub fn replace(&mut self, route: R) {
+        self.current = route;
+        self.changed = true;
+    }
+
+    pub fn can_go_back(&self) -> bool {
+        !self.back.is_empty()
+    }
+
+    pub fn can_go_forward(&self) -> bool {
+        !self.forward.is_empty()
+    }
+
+    /// Go back to the previous route, if any.
+    ///
+    /// Returns `false` if there was nothing to go back to.
+    pub fn back(&mut self) -> bool {
+        let Some(previous) = self.back.pop() else {
+            return false;
+        };
+        let current = std::mem::replace(&mut self.current, previous);
+        self.forward.push(current);
+        self.changed = true;
+        true
+    }
+
+    /// Go forward again after [`Self::back`], if possible.
+    ///
+    /// Returns `false` if there was nothing to go forward to.
+    pub fn forward(&mut self) -> bool {
+        let Some(next) = self.forward.pop() else {
+            return false;
+        };
+        let current = std::mem::replace(&mut self.current, next);
+        self.back.push(current);
+        self.changed = true;
+        true
+    }
+
+    /// All the routes we can go back to, oldest first.
+    pub fn back_history(&self) -> &[R] {
+        &self.back
+    }
+
+    /// All the routes we can go forward to, with the next one last.
+    pub fn forward_history(&self) -> &[R] {
+        &self.forward
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn navigator() -> Navigator<&'static str> {
+        Navigator::new(Id::new("navigator"), "home")
+    }
+
+    #[test]
+    fn push_back_forward() {
+        let mut navigator = navigator();
+        assert!(!navigator.can_go_back());
+        assert!(!navigator.can_go_forward());
+        assert!(!navigator.back());
+        assert!(!navigator.forward());
+        assert!(!navigator.changed());
+
+        navigator.push("a");
+        navigator.push("b");
+        assert_eq!(*navigator.current(), "b");
+        assert_eq!(navigator.back_history(), ["home", "a"]);
+        assert!(navigator.changed());
+
+        assert!(navigator.back());
+        assert_eq!(*navigator.current(), "a");
+        assert!(navigator.back());
+        assert_eq!(*navigator.current(), "home");
+        assert!(!navigator.can_go_back());
+        assert_eq!(navigator.forward_history(), ["b", "a"]);
+
+        assert!(navigator.forward());
+        assert_eq!(*navigator.current(), "a");
+        assert!(navigator.forward());
+        assert_eq!(*navigator.current(), "b");
+        assert!(!navigator.forward());
+        assert_eq!(navigator.back_history(), ["home", "a"]);
+    }
+
+    #[test]
+    fn push_after_back_clears_forward_history() {
+        let mut navigator = navigator();
+        navigator.push("a");
+        navigator.push("b");
+        navigator.back();
+        navigator.back();
+        assert!(navigator.can_go_forward());
+
+        navigator.push("c");
+        assert_eq!(*navigator.current(), "c");
+        assert!(!navigator.can_go_forward());
+        assert!(navigator.forward_history().is_empty());
+        assert_eq!(navigator.back_history(), ["home"]);
+    }
+
+    #[test]
+    fn store_and_load() {
+        let ctx = Context::default();
+        let mut navigator = navigator();
+        navigator.push("a");
+        navigator.back();
+        navigator.store(&ctx);
+
+        let navigator = Navigator::<&'static str>::load(&ctx, Id::new("navigator")).unwrap();
+        assert_eq!(*navigator.current(), "home");
+        assert_eq!(navigator.forward_history(), ["a"]);
+        assert!(!navigator.changed());
+    }
+}
@@ -0,0 +1,131 @@
+//! Painting tessellated primitives into a [`ColorImage`] on the CPU,
+//! used by [`crate::Context::render_ui_to_image`].
+
+use ahash::HashMap;
+use epaint::{ClippedPrimitive, Color32, ColorImage, ImageData, Mesh, Pos2, Primitive, Rect};
+
+use crate::{TextureId, epaint::textures::TexturesDelta};
+
+/// The textures available to the painted meshes.
+#[derive(Default)]
+pub(crate) struct Textures(HashMap<TextureId, ColorImage>);
+
+impl Textures {
+    pub fn apply(&mut self, delta: &TexturesDelta) {
+        for (id, image_delta) in &delta.set {
+            let ImageData::Color(image) = &image_delta.image;
+            if let Some(pos) = image_delta.pos {
+                let Some(texture) = self.0.get_mut(id) else {
+                    continue;
+                };
+                for y in 0..image.height() {
+                    for x in 0..image.width() {
+                        let (tx, ty) = (pos[0] + x, pos[1] + y);
+                        if tx < texture.width() && ty < texture.height() {
+                            texture[(tx, ty)] = image[(x, y)];
+                        }
+                    }
+                }
+            } else {
+                self.0.insert(*id, (**image).clone());
+            }
+        }
+        for id in &delta.free {
+            self.0.remove(id);
+        }
+    }
+
+    /// Nearest-neighbor sampling. Unknown textures are white.
+    fn sample(&self, id: TextureId, uv: Pos2) -> Color32 {
+        let Some(texture) = self.0.get(&id) else {
+            return Color32::WHITE;
+        };
+        let [w, h] = texture.size;
+        if w == 0 || h == 0 {
+            return Color32::WHITE;
+        }
+        let x = ((uv.x * w as f32) as usize).min(w - 1);
+        let y = ((uv.y * h as f32) as usize).min(h - 1);
+        texture[(x, y)]
+    }
+}
+
+/// Paint the primitives, in order, on top of a transparent image.
+///
+/// Paint callbacks are skipped.
+pub(crate) fn paint(
+    primitives: &[ClippedPrimitive],
+    textures: &Textures,
+    pixels_per_point: f32,
+    size_px: [usize; 2],
+) -> ColorImage {
+    let mut image = ColorImage::filled(size_px, Color32::TRANSPARENT);
+    for ClippedPrimitive {
+        clip_rect,
+        primitive,
+    } in primitives
+    {
+        if let Primitive::Mesh(mesh) = primitive {
+            let clip_rect = (*clip_rect * pixels_per_point).intersect(Rect::from_min_size(
+                Pos2::ZERO,
+                epaint::vec2(size_px[0] as f32, size_px[1] as f32),
+            ));
+            paint_mesh(&mut image, mesh, textures, pixels_per_point, clip_rect);
+        }
+    }
+    image
+}
+
+fn paint_mesh(
+    image: &mut ColorImage,
+    mesh: &Mesh,
+    textures: &Textures,
+    pixels_per_point: f32,
+    clip_rect: Rect,
+) {
+    if !clip_rect.is_positive() {
+        return;
+    }
+
+    // Twice the signed area of the triangle `a, b, p`:
+    let edge = |a: Pos2, b: Pos2, p: Pos2| (b.x - a.x) * (p.y - a.y) - (b.y - a.y) * (p.x - a.x);
+
+    for triangle in mesh.indices.chunks_exact(3) {
+        let [a, b, c] = [0, 1, 2].map(|i| &mesh.vertices[triangle[i] as usize]);
+        let [pa, pb, pc] = [a, b, c].map(|v| (v.pos.to_vec2() * pixels_per_point).to_pos2());
+        let area = edge(pa, pb, pc);
+        if area == 0.0 {
+            continue;
+        }
+
+        let bounds = Rect::from_points(&[pa, pb, pc]).intersect(clip_rect);
+        if !bounds.is_positive() {
+            continue;
+        }
+        let (x_min, x_max) = (bounds.min.x.floor() as usize, bounds.max.x.ceil() as usize);
+        let (y_min, y_max) = (bounds.min.y.floor() as usize, bounds.max.y.ceil() as usize);
+
+        for y in y_min..y_max.min(image.height()) {
+            for x in x_min..x_max.min(image.width()) {
+                let p = Pos2::new(x as f32 + 0.5, y as f32 + 0.5);
+                let wa = edge(pb, pc, p) / area;
+                let wb = edge(pc, pa, p) / area;
+                let wc = 1.0 - wa - wb;
+                if wa < 0.0 || wb < 0.0 || wc < 0.0 || !clip_rect.contains(p) {
+                    continue; // Outside the triangle or clipped
+                }
+
+                let lerp = |f: &dyn Fn(&epaint::Vertex) -> f32| wa * f(a) + wb * f(b) + wc * f(c);
+                let color = Color32::from_rgba_premultiplied(
+                    lerp(&|v| v.color.r() as f32).round() as u8,
+                    lerp(&|v| v.color.g() as f32).round() as u8,
+                    lerp(&|v| v.color.b() as f32).round() as u8,
+                    lerp(&|v| v.color.a() as f32).round() as u8,
+                );
+                let uv = Pos2::new(lerp(&|v| v.uv.x), lerp(&|v| v.uv.y));
+                let src = color * textures.sample(mesh.texture_id, uv);
+                image[(x, y)] = image[(x, y)].blend(src);
+            }
+        }
+    }
+}
@@ -3,12 +3,12 @@ use std::sync::Arc;
 use emath::GuiRounding as _;
 use epaint::{
     CircleShape, ClippedShape, CornerRadius, PathStroke, RectShape, Shape, Stroke, StrokeKind,
-    text::{Fonts, Galley, LayoutJob},
+    text::{Fonts, Galley, LayoutJob, TextWrapping},
 };
 
 use crate::{
     Color32, Context, FontId,
-    emath::{Align2, Pos2, Rangef, Rect, Vec2},
+    emath::{Align2, Pos2, Rangef, Rect, TSTransform, Vec2},
     layers::{LayerId, PaintList, ShapeIdx},
 };
 
@@ -82,6 +82,31 @@ impl Painter {
         self.layer_id = layer_id;
     }
 
+    /// Redirect painting to a new sublayer of the current layer,
+    /// transformed by `transform` relative to the current layer.
+    ///
+    /// This is what [`crate::UiBuilder::transform`] uses.
+    pub(crate) fn set_sublayer_transform(&mut self, id: crate::Id, transform: TSTransform) {
+        let parent_layer_id = self.layer_id;
+        let layer_id = LayerId::new(parent_layer_id.order, id.with("transform"));
+        let parent_to_global = self
+            .ctx
+            .layer_transform_to_global(parent_layer_id)
+            .unwrap_or_default();
+
+        // Only one level of sublayers is supported, so nested transforms share the same root:
+        let root_layer_id = self
+            .ctx
+            .memory(|mem| mem.areas().parent_layer(parent_layer_id))
+            .unwrap_or(parent_layer_id);
+        self.ctx.set_sublayer(root_layer_id, layer_id);
+        self.ctx
+            .set_transform_layer(layer_id, parent_to_global * transform);
+
+        self.layer_id = layer_id;
+        self.clip_rect = transform.inverse() * self.clip_rect;
+    }
+
     /// If set, colors will be modified to look like this
     #[deprecated = "Use `multiply_opacity` instead"]
     pub fn set_fade_to_color(&mut self, fade_to_color: Option<Color32>) {
@@ -271,6 +296,16 @@ impl Painter {
         }
     }
 
+    /// Paint the same shape many times, e.g. the markers of a scatter plot.
+    ///
+    /// This is much cheaper than adding each copy on its own,
+    /// especially if the backend supports instancing (see [`crate::BackendCapabilities::instancing`]).
+    ///
+    /// The `shape` is in the local coordinates of each instance.
+    pub fn add_instanced(&self, shape: impl Into<Shape>, instances: Vec<TSTransform>) -> ShapeIdx {
+        self.add(Shape::instanced(shape, instances))
+    }
+
     /// Modify an existing [`Shape`].
     pub fn set(&self, idx: ShapeIdx, shape: impl Into<Shape>) {
         if self.fade_to_color == Some(Color32::TRANSPARENT) {
@@ -513,6 +548,95 @@ impl Painter {
         rect
     }
 
+    /// Lay out and paint a single line of text,
+    /// replacing the end with `…` if it is wider than `max_width`.
+    ///
+    /// Returns where the text ended up.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let rect = ui.painter().text_elided(
+    ///     ui.min_rect().left_top(),
+    ///     egui::Align2::LEFT_TOP,
+    ///     "A long text that will not fit in the given width",
+    ///     egui::FontId::proportional(14.0),
+    ///     ui.visuals().text_color(),
+    ///     100.0,
+    /// );
+    /// assert!(rect.width() <= 100.0);
+    /// # });
+    /// ```
+    #[expect(clippy::needless_pass_by_value)]
+    pub fn text_elided(
+        &self,
+        pos: Pos2,
+        anchor: Align2,
+        text: impl ToString,
+        font_id: FontId,
+        text_color: Color32,
+        max_width: f32,
+    ) -> Rect {
+        let galley = self.layout_elided(text.to_string(), font_id, text_color, max_width, 1);
+        let rect = anchor.anchor_size(pos, galley.size());
+        self.galley(rect.min, galley, text_color);
+        rect
+    }
+
+    /// Lay out and paint text wrapped to fit within the given rectangle,
+    /// with as many rows as fit in it (but at least one).
+    /// If there is more text than that, the end of the last row is replaced with `…`.
+    ///
+    /// The text is aligned within the rectangle using `align`.
+    ///
+    /// This is useful for e.g. custom-painted table cells.
+    ///
+    /// Returns where the text ended up.
+    #[expect(clippy::needless_pass_by_value)]
+    pub fn text_in_rect(
+        &self,
+        rect: Rect,
+        align: Align2,
+        text: impl ToString,
+        font_id: FontId,
+        text_color: Color32,
+    ) -> Rect {
+        let row_height = self.fonts(|f| f.row_height(&font_id));
+        let max_rows = (rect.height() / row_height).floor().max(1.0) as usize;
+        let galley = self.layout_elided(
+            text.to_string(),
+            font_id,
+            text_color,
+            rect.width(),
+            max_rows,
+        );
+        let text_rect = align.align_size_within_rect(galley.size(), rect);
+        self.galley(text_rect.min, galley, text_color);
+        text_rect
+    }
+
+    /// Will wrap text at the given width and line break at `\n`,
+    /// but only show up to `max_rows` rows.
+    /// If there is more text than that, the end of the last row is replaced with `…`.
+    ///
+    /// Paint the results with [`Self::galley`].
+    #[must_use]
+    pub fn layout_elided(
+        &self,
+        text: String,
+        font_id: FontId,
+        color: crate::Color32,
+        max_width: f32,
+        max_rows: usize,
+    ) -> Arc<Galley> {
+        let mut job = LayoutJob::simple(text, font_id, color, max_width);
+        job.wrap = TextWrapping {
+            max_rows,
+            break_anywhere: max_rows == 1,
+            ..TextWrapping::wrap_at_width(max_width)
+        };
+        self.layout_job(job)
+    }
+
     /// Will wrap text at the given width and line break at `\n`.
     ///
     /// Paint the results with [`Self::galley`].
@@ -593,7 +717,12 @@ fn tint_shape_towards(shape: &mut Shape, target: Color32) {
     });
 }
 
-fn multiply_opacity(shape: &mut Shape, opacity: f32) {
+pub(crate) fn multiply_opacity(shape: &mut Shape, opacity: f32) {
+    if let Shape::Composite(composite) = shape {
+        // Fade the shapes as one:
+        std::sync::Arc::make_mut(composite).opacity *= opacity;
+        return;
+    }
     epaint::shape_transform::adjust_colors(shape, move |color| {
         if *color != Color32::PLACEHOLDER {
             *color = color.gamma_multiply(opacity);
@@ -1,6 +1,8 @@
 use ahash::HashMap;
 
-use crate::{Align, Id, IdMap, LayerId, Rangef, Rect, Vec2, WidgetRects, id::IdSet, style};
+use crate::{
+    Align, Id, IdMap, LayerId, Rangef, Rect, Vec2, WidgetRects, id::IdSet, layers::ShapeIdx, style,
+};
 
 #[cfg(debug_assertions)]
 use crate::{Align2, Color32, FontId, NumExt as _, Painter, pos2};
@@ -27,6 +29,9 @@ pub struct PerWidgetTooltipState {
 
     /// How many tooltips have been shown for this widget this frame?
     pub tooltip_count: usize,
+
+    /// Where the last tooltip for this widget was shown.
+    pub last_tooltip_rect: Option<Rect>,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -55,6 +60,9 @@ pub struct ScrollTarget {
 
     /// How should the scroll be animated?
     pub animation: style::ScrollAnimation,
+
+    /// Should all the parent scroll areas also scroll, or only the innermost one?
+    pub scroll_parents: bool,
 }
 
 impl ScrollTarget {
@@ -63,8 +71,16 @@ impl ScrollTarget {
             range,
             align,
             animation,
+            scroll_parents: false,
         }
     }
+
+    /// Also scroll all the parent scroll areas, so that the target ends up on screen.
+    #[inline]
+    pub fn with_parents(mut self) -> Self {
+        self.scroll_parents = true;
+        self
+    }
 }
 
 #[cfg(feature = "accesskit")]
@@ -179,6 +195,58 @@ impl DebugRect {
     }
 }
 
+/// A part of a [`crate::Ui`] that only changes when interacted with,
+/// see [`crate::Ui::static_region`].
+#[derive(Clone, Copy, Debug)]
+pub struct StaticRegion {
+    pub id: Id,
+    pub layer_id: LayerId,
+
+    /// In layer coordinates, clipped.
+    pub rect: Rect,
+
+    /// Do the shapes of the region start with an opaque background covering all of [`Self::rect`]?
+    ///
+    /// If not, changes to what is beneath the region would show through.
+    pub opaque: bool,
+
+    /// The range of the shapes of the region in its layer.
+    pub shapes: (ShapeIdx, ShapeIdx),
+
+    /// Was anything in the region hovered, focused or dragged this pass?
+    pub interacted: bool,
+
+    /// The [`epaint::text::Fonts::atlas_generation`] the text of the region was laid out with.
+    pub font_atlas_generation: u64,
+}
+
+/// What we remember about a [`StaticRegion`] between passes.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct StaticRegionState {
+    /// In global coordinates.
+    pub rect: Rect,
+
+    /// When the region last looked different, in [`crate::InputState::time`].
+    pub last_changed: f64,
+
+    /// Set by [`crate::Context::invalidate_static_region`].
+    pub invalidated: bool,
+
+    /// See [`StaticRegion::font_atlas_generation`].
+    pub font_atlas_generation: u64,
+}
+
+impl Default for StaticRegionState {
+    fn default() -> Self {
+        Self {
+            rect: Rect::NOTHING,
+            last_changed: 0.0,
+            invalidated: false,
+            font_atlas_generation: 0,
+        }
+    }
+}
+
 /// State that is collected during a pass, then saved for the next pass,
 /// and then cleared.
 ///
@@ -231,6 +299,19 @@ pub struct PassState {
     /// Highlight these widgets the next pass.
     pub highlight_next_pass: IdSet,
 
+    /// The widgets that were marked as changed this pass, see [`crate::Response::mark_changed`].
+    pub changed_widgets: IdSet,
+
+    /// All [`StaticRegion`]s shown this pass.
+    pub static_regions: Vec<StaticRegion>,
+
+    /// The names and rectangles of all [`crate::Ui::anchor`]s shown this pass, in order.
+    pub anchors: Vec<(String, Rect)>,
+
+    /// With [`crate::Options::strict_mode`]: the ids passed to [`crate::Context::read_response`]
+    /// that were not known at the time, and must be created later this pass.
+    pub unknown_response_ids: IdSet,
+
     #[cfg(debug_assertions)]
     pub debug_rect: Option<DebugRect>,
 }
@@ -250,6 +331,10 @@ impl Default for PassState {
             #[cfg(feature = "accesskit")]
             accesskit_state: None,
             highlight_next_pass: Default::default(),
+            changed_widgets: Default::default(),
+            static_regions: Default::default(),
+            anchors: Default::default(),
+            unknown_response_ids: Default::default(),
 
             #[cfg(debug_assertions)]
             debug_rect: None,
@@ -273,12 +358,20 @@ impl PassState {
             #[cfg(feature = "accesskit")]
             accesskit_state,
             highlight_next_pass,
+            changed_widgets,
+            static_regions,
+            anchors,
+            unknown_response_ids,
 
             #[cfg(debug_assertions)]
             debug_rect,
         } = self;
 
         used_ids.clear();
+        changed_widgets.clear();
+        static_regions.clear();
+        anchors.clear();
+        unknown_response_ids.clear();
         widgets.clear();
         tooltips.clear();
         layers.clear();
@@ -1,4 +1,4 @@
-use crate::{Layout, Painter, Pos2, Rect, Region, Vec2, grid, vec2};
+use crate::{JustifyContent, Layout, NumExt as _, Painter, Pos2, Rect, Region, Vec2, grid, vec2};
 
 #[cfg(debug_assertions)]
 use crate::{Align2, Color32, Stroke};
@@ -8,6 +8,38 @@ pub(crate) struct Placer {
     grid: Option<grid::GridLayout>,
     layout: Layout,
     region: Region,
+
+    /// The widgets added so far, for [`Layout::justify_content`].
+    items: JustifyContentItems,
+
+    /// The main-axis spacing between widgets, for [`Layout::justify_content`].
+    gap: Option<f32>,
+
+    /// Limits the size of the widgets, see [`crate::Ui::add_sized_range`].
+    size_constraint: Option<SizeConstraint>,
+}
+
+/// A minimum and maximum size for the widgets of a [`Placer`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct SizeConstraint {
+    pub min: Vec2,
+    pub max: Vec2,
+}
+
+/// The widgets of a [`Layout`] with [`Layout::justify_content`],
+/// remembered from the previous pass.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) struct JustifyContentItems {
+    pub count: usize,
+
+    /// Their total size along the main axis.
+    pub length: f32,
+}
+
+impl JustifyContentItems {
+    pub fn roughly_eq(&self, other: &Self) -> bool {
+        self.count == other.count && (self.length - other.length).abs() < 0.5
+    }
 }
 
 impl Placer {
@@ -17,9 +49,17 @@ impl Placer {
             grid: None,
             layout,
             region,
+            items: Default::default(),
+            gap: None,
+            size_constraint: None,
         }
     }
 
+    #[inline(always)]
+    pub(crate) fn set_size_constraint(&mut self, size_constraint: SizeConstraint) {
+        self.size_constraint = Some(size_constraint);
+    }
+
     #[inline(always)]
     pub(crate) fn set_grid(&mut self, grid: grid::GridLayout) {
         self.grid = Some(grid);
@@ -104,6 +144,61 @@ impl Placer {
         }
     }
 
+    /// The size a widget gets when it asks for `desired_size`.
+    pub(crate) fn constrain_size(&self, desired_size: Vec2) -> Vec2 {
+        if let Some(SizeConstraint { min, max }) = self.size_constraint {
+            desired_size.min(max).max(min)
+        } else {
+            desired_size
+        }
+    }
+
+    /// Does this distribute leftover space between the widgets?
+    pub(crate) fn justifies_content(&self) -> bool {
+        self.grid.is_none()
+            && self.layout.justify_content != JustifyContent::Start
+            && !self.layout.main_wrap
+            && !self.layout.main_justify
+    }
+
+    /// The widgets added so far.
+    pub(crate) fn justify_content_items(&self) -> JustifyContentItems {
+        self.items
+    }
+
+    /// Space out the widgets, given the widgets of the previous pass.
+    pub(crate) fn start_justify_content(&mut self, items: JustifyContentItems, item_spacing: Vec2) {
+        let (max_length, min_gap) = if self.layout.is_horizontal() {
+            (self.region.max_rect.width(), item_spacing.x)
+        } else {
+            (self.region.max_rect.height(), item_spacing.y)
+        };
+        if !max_length.is_finite() {
+            return;
+        }
+        let leftover = (max_length - items.length).at_least(0.0);
+        let (leading, gap) = self
+            .layout
+            .justify_content
+            .leading_and_gap(leftover, items.count);
+        self.gap = Some(gap.at_least(min_gap));
+        if 0.0 < leading {
+            self.advance_cursor(leading);
+        }
+    }
+
+    /// The item spacing, with the main-axis spacing replaced for [`Layout::justify_content`].
+    fn item_spacing(&self, mut item_spacing: Vec2) -> Vec2 {
+        if let Some(gap) = self.gap {
+            if self.layout.is_horizontal() {
+                item_spacing.x = gap;
+            } else {
+                item_spacing.y = gap;
+            }
+        }
+        item_spacing
+    }
+
     /// Returns where to put the next widget that is of the given size.
     /// The returned `frame_rect` will always be justified along the cross axis.
     /// This is what you then pass to `advance_after_rects`.
@@ -118,7 +213,7 @@ impl Placer {
             grid.next_cell(self.region.cursor, child_size)
         } else {
             self.layout
-                .next_frame(&self.region, child_size, item_spacing)
+                .next_frame(&self.region, child_size, self.item_spacing(item_spacing))
         }
     }
 
@@ -174,6 +269,13 @@ impl Placer {
         if let Some(grid) = &mut self.grid {
             grid.advance(&mut self.region.cursor, frame_rect, widget_rect);
         } else {
+            self.items.count += 1;
+            self.items.length += if self.layout.is_horizontal() {
+                frame_rect.width()
+            } else {
+                frame_rect.height()
+            };
+            let item_spacing = self.item_spacing(item_spacing);
             self.layout.advance_after_rects(
                 &mut self.region.cursor,
                 frame_rect,
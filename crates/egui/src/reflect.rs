@@ -0,0 +1,247 @@
+//! Generic editors for your own data, e.g. for settings and inspector panels.
+//!
+//! Implement [`UiInspect`] for your types, and show them with [`crate::Ui::inspect`].
+//! Structs list their fields with [`inspect_fields`], in the order and with the labels you choose:
+//!
+//! ```
+//! use egui::reflect::{UiInspect, inspect_fields};
+//!
+//! #[derive(Default)]
+//! struct Settings {
+//!     name: String,
+//!     volume: f32,
+//!     fullscreen: bool,
+//!     recent_files: Vec<String>,
+//!     proxy: Option<String>,
+//! }
+//!
+//! impl UiInspect for Settings {
+//!     fn ui_inspect(&mut self, ui: &mut egui::Ui) -> egui::Response {
+//!         inspect_fields(ui, |fields| {
+//!             fields.field("Name", &mut self.name);
+//!             fields.field("Volume", &mut self.volume);
+//!             fields.field("Fullscreen", &mut self.fullscreen);
+//!             fields.field("Recent files", &mut self.recent_files);
+//!             fields.field("Proxy", &mut self.proxy);
+//!         })
+//!     }
+//! }
+//!
+//! # egui::__run_test_ui(|ui| {
+//! # let mut settings = Settings::default();
+//! if ui.inspect(&mut settings).changed() {
+//!     // save the settings
+//! }
+//! # });
+//! ```
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Display;
+use std::hash::{BuildHasher, Hash};
+
+use crate::{
+    Button, Checkbox, Color32, ComboBox, DragValue, Grid, Response, SelectableEnum, TextEdit, Ui,
+    Vec2,
+};
+
+/// Something that can show an editor for itself.
+///
+/// See the [module-level docs](crate::reflect) for an example.
+pub trait UiInspect {
+    /// Show an editor for the value, without a label.
+    ///
+    /// The returned [`Response`] should be marked changed when the value changes.
+    fn ui_inspect(&mut self, ui: &mut Ui) -> Response;
+}
+
+/// The fields of a struct, see [`inspect_fields`].
+pub struct Fields<'a> {
+    ui: &'a mut Ui,
+    changed: bool,
+}
+
+impl Fields<'_> {
+    /// Add a row with the given label and an editor for the value.
+    pub fn field(&mut self, label: &str, value: &mut (impl UiInspect + ?Sized)) -> &mut Self {
+        self.ui.label(label);
+        let response = self.ui.push_id(label, |ui| value.ui_inspect(ui)).inner;
+        self.changed |= response.changed();
+        self.ui.end_row();
+        self
+    }
+
+    /// Add a row with arbitrary ui code, e.g. for a field that needs a special editor.
+    pub fn custom(
+        &mut self,
+        label: &str,
+        add_contents: impl FnOnce(&mut Ui) -> Response,
+    ) -> &mut Self {
+        self.ui.label(label);
+        let response = self.ui.push_id(label, add_contents).inner;
+        self.changed |= response.changed();
+        self.ui.end_row();
+        self
+    }
+
+    /// The [`Ui`] the fields are added to.
+    pub fn ui(&mut self) -> &mut Ui {
+        self.ui
+    }
+}
+
+/// Show the fields of a struct in a [`Grid`], with the labels on the left.
+///
+/// The returned [`Response`] is marked changed if any of the fields changed.
+pub fn inspect_fields(ui: &mut Ui, add_fields: impl FnOnce(&mut Fields<'_>)) -> Response {
+    let mut changed = false;
+    let mut response = Grid::new(ui.next_auto_id())
+        .num_columns(2)
+        .striped(true)
+        .show(ui, |ui| {
+            let mut fields = Fields { ui, changed: false };
+            add_fields(&mut fields);
+            changed = fields.changed;
+        })
+        .response;
+    if changed {
+        response.mark_changed();
+    }
+    response
+}
+
+impl UiInspect for bool {
+    fn ui_inspect(&mut self, ui: &mut Ui) -> Response {
+        ui.add(Checkbox::without_text(self))
+    }
+}
+
+macro_rules! impl_inspect_numeric {
+    ($($t: ty)*) => {
+        $(
+            impl UiInspect for $t {
+                fn ui_inspect(&mut self, ui: &mut Ui) -> Response {
+                    ui.add(DragValue::new(self))
+                }
+            }
+        )*
+    };
+}
+
+impl_inspect_numeric!(i8 u8 i16 u16 i32 u32 i64 u64 isize usize f32 f64);
+
+impl UiInspect for String {
+    fn ui_inspect(&mut self, ui: &mut Ui) -> Response {
+        ui.add(TextEdit::singleline(self))
+    }
+}
+
+impl UiInspect for Color32 {
+    fn ui_inspect(&mut self, ui: &mut Ui) -> Response {
+        ui.color_edit_button_srgba(self)
+    }
+}
+
+impl UiInspect for Vec2 {
+    fn ui_inspect(&mut self, ui: &mut Ui) -> Response {
+        ui.horizontal(|ui| {
+            ui.add(DragValue::new(&mut self.x).prefix("x: "))
+                | ui.add(DragValue::new(&mut self.y).prefix("y: "))
+        })
+        .inner
+    }
+}
+
+/// Enums are picked from a [`ComboBox`].
+impl<T: SelectableEnum> UiInspect for T {
+    fn ui_inspect(&mut self, ui: &mut Ui) -> Response {
+        ui.add(ComboBox::from_enum(self))
+    }
+}
+
+/// A checkbox for whether there is a value, followed by the value.
+impl<T: UiInspect + Default> UiInspect for Option<T> {
+    fn ui_inspect(&mut self, ui: &mut Ui) -> Response {
+        ui.horizontal(|ui| {
+            let mut is_some = self.is_some();
+            let mut response = ui.add(Checkbox::without_text(&mut is_some));
+            if response.changed() {
+                *self = is_some.then(T::default);
+            }
+            if let Some(value) = self {
+                response |= value.ui_inspect(ui);
+            }
+            response
+        })
+        .inner
+    }
+}
+
+/// The elements, each with a button to remove it, and a button to add a new element.
+impl<T: UiInspect + Default> UiInspect for Vec<T> {
+    fn ui_inspect(&mut self, ui: &mut Ui) -> Response {
+        let mut changed = false;
+        let mut response = ui
+            .vertical(|ui| {
+                let mut remove = None;
+                for (i, value) in self.iter_mut().enumerate() {
+                    ui.push_id(i, |ui| {
+                        ui.horizontal(|ui| {
+                            changed |= value.ui_inspect(ui).changed();
+                            if ui.small_button("🗑").on_hover_text("Remove").clicked() {
+                                remove = Some(i);
+                            }
+                        });
+                    });
+                }
+                if let Some(i) = remove {
+                    self.remove(i);
+                    changed = true;
+                }
+                if ui
+                    .add(Button::new("➕").small())
+                    .on_hover_text("Add")
+                    .clicked()
+                {
+                    self.push(T::default());
+                    changed = true;
+                }
+            })
+            .response;
+        if changed {
+            response.mark_changed();
+        }
+        response
+    }
+}
+
+/// The entries sorted by key, with the keys as labels.
+impl<K, V, S> UiInspect for HashMap<K, V, S>
+where
+    K: Display + Ord + Hash,
+    V: UiInspect,
+    S: BuildHasher,
+{
+    fn ui_inspect(&mut self, ui: &mut Ui) -> Response {
+        let mut entries: Vec<_> = self.iter_mut().collect();
+        entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+        inspect_entries(ui, entries)
+    }
+}
+
+/// The entries, with the keys as labels.
+impl<K: Display, V: UiInspect> UiInspect for BTreeMap<K, V> {
+    fn ui_inspect(&mut self, ui: &mut Ui) -> Response {
+        inspect_entries(ui, self.iter_mut())
+    }
+}
+
+fn inspect_entries<'a, K: Display + 'a, V: UiInspect + 'a>(
+    ui: &mut Ui,
+    entries: impl IntoIterator<Item = (&'a K, &'a mut V)>,
+) -> Response {
+    inspect_fields(ui, |fields| {
+        for (key, value) in entries {
+            fields.field(&key.to_string(), value);
+        }
+    })
+}
@@ -1,8 +1,8 @@
 use std::{any::Any, sync::Arc};
 
 use crate::{
-    Context, CursorIcon, Id, LayerId, PointerButton, Popup, PopupKind, Sense, Tooltip, Ui,
-    WidgetRect, WidgetText,
+    Context, CursorIcon, HitTestMode, Id, LayerId, Margin, PointerButton, Popup, PopupAnchor,
+    PopupKind, Sense, Tooltip, Ui, WidgetRect, WidgetText,
     emath::{Align, Pos2, Rect, Vec2},
     pass_state,
 };
@@ -424,6 +424,69 @@ impl Response {
         }
     }
 
+    /// Capture the pointer, so that this widget keeps receiving pointer input
+    /// (hover, clicks and drags) even when the pointer leaves its rectangle or the viewport.
+    ///
+    /// This is useful for things like eyedroppers and sliders that should keep
+    /// working when dragged past the edge of the window.
+    ///
+    /// The capture lasts until [`Self::release_pointer_capture`] is called,
+    /// or until the widget is no longer shown.
+    ///
+    /// See also [`Context::set_pointer_capture`].
+    pub fn request_pointer_capture(&self) {
+        self.ctx.set_pointer_capture(Some(self.id));
+    }
+
+    /// Release a pointer capture previously requested with [`Self::request_pointer_capture`].
+    ///
+    /// Does nothing if this widget does not have the pointer capture.
+    pub fn release_pointer_capture(&self) {
+        if self.has_pointer_capture() {
+            self.ctx.set_pointer_capture(None);
+        }
+    }
+
+    /// Has this widget captured the pointer?
+    ///
+    /// See [`Self::request_pointer_capture`].
+    pub fn has_pointer_capture(&self) -> bool {
+        self.ctx.pointer_capture_id() == Some(self.id)
+    }
+
+    /// Lock the pointer, so that this widget can be dragged forever
+    /// without the cursor hitting the edge of the screen.
+    ///
+    /// The cursor is hidden while locked, and is moved back to where it was
+    /// when the lock is released.
+    /// Use [`Self::drag_motion`] to read how much the pointer moved.
+    ///
+    /// This is useful for scrubbing values over large ranges, or orbiting a 3D camera.
+    ///
+    /// The lock lasts until [`Self::release_pointer_lock`] is called,
+    /// or until the widget is no longer shown.
+    ///
+    /// See also [`Context::set_pointer_lock`].
+    pub fn request_pointer_lock(&self) {
+        self.ctx.set_pointer_lock(Some(self.id));
+    }
+
+    /// Release a pointer lock previously requested with [`Self::request_pointer_lock`].
+    ///
+    /// Does nothing if this widget does not have the pointer lock.
+    pub fn release_pointer_lock(&self) {
+        if self.has_pointer_lock() {
+            self.ctx.set_pointer_lock(None);
+        }
+    }
+
+    /// Has this widget locked the pointer?
+    ///
+    /// See [`Self::request_pointer_lock`].
+    pub fn has_pointer_lock(&self) -> bool {
+        self.ctx.pointer_lock_id() == Some(self.id)
+    }
+
     /// If the user started dragging this widget this frame, store the payload for drag-and-drop.
     #[doc(alias = "drag and drop")]
     pub fn dnd_set_drag_payload<Payload: Any + Send + Sync>(&self, payload: Payload) {
@@ -529,9 +592,14 @@ impl Response {
     ///
     /// This should be called when the *content* changes, but not when the view does.
     /// So we call this when the text of a [`crate::TextEdit`], but not when the cursor changes.
-    #[inline(always)]
+    #[inline]
     pub fn mark_changed(&mut self) {
         self.flags.set(Flags::CHANGED, true);
+
+        // For `Ui::capture`:
+        let id = self.id;
+        self.ctx
+            .pass_state_mut(|state| state.changed_widgets.insert(id));
     }
 
     /// Should the container be closed?
@@ -590,6 +658,55 @@ impl Response {
         self
     }
 
+    /// Like `on_hover_ui`, but show the ui relative to the given [`PopupAnchor`].
+    ///
+    /// For instance, pass the rectangle of a table cell to align the tooltip with the cell
+    /// instead of the widget or the wandering mouse pointer.
+    /// Use [`Tooltip`] directly for more control over the placement.
+    ///
+    /// The tooltip flips to the other side of the anchor if it doesn't fit on the screen.
+    /// Use [`Self::tooltip_rect`] to find out where it ended up.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let cell_rect = egui::Rect::ZERO;
+    /// ui.label("Hover me")
+    ///     .on_hover_ui_at(egui::PopupAnchor::ParentRect(cell_rect), |ui| {
+    ///         ui.label("Aligned with the cell");
+    ///     });
+    /// # });
+    /// ```
+    pub fn on_hover_ui_at(
+        self,
+        anchor: impl Into<PopupAnchor>,
+        add_contents: impl FnOnce(&mut Ui),
+    ) -> Self {
+        let anchor = anchor.into();
+        let gap = if anchor == PopupAnchor::Pointer {
+            12.0
+        } else {
+            4.0
+        };
+        Tooltip::for_enabled(&self)
+            .anchor(anchor)
+            .gap(gap)
+            .show(add_contents);
+        self
+    }
+
+    /// Where the last tooltip of this widget was shown this pass, if any.
+    ///
+    /// Only knows about tooltips that have already been shown,
+    /// e.g. by [`Self::on_hover_ui`] or [`Self::on_hover_ui_at`].
+    pub fn tooltip_rect(&self) -> Option<Rect> {
+        self.ctx.pass_state(|fs| {
+            fs.tooltips
+                .widget_tooltips
+                .get(&self.id)
+                .and_then(|state| state.last_tooltip_rect)
+        })
+    }
+
     /// Always show this tooltip, even if disabled and the user isn't hovering it.
     ///
     /// This can be used to give attention to a widget during a tutorial.
@@ -722,11 +839,84 @@ impl Response {
                 interact_rect: self.interact_rect,
                 sense: self.sense | sense,
                 enabled: self.enabled(),
+                // These keep anything set with `Self::hit_test_mode` etc:
+                hit_test_mode: Default::default(),
+                interact_priority: 0,
+                interact_expand: Default::default(),
             },
             true,
         )
     }
 
+    /// Change how this widget is hit-tested, e.g. to let clicks through a decorative overlay.
+    ///
+    /// Like all hit-testing, this takes effect the next pass.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.label("A label under a decoration")
+    ///     .hit_test_mode(egui::HitTestMode::Transparent);
+    /// # });
+    /// ```
+    pub fn hit_test_mode(self, mode: HitTestMode) -> Self {
+        let id = self.id;
+        self.ctx
+            .pass_state_mut(|state| state.widgets.set_hit_test_mode(id, mode));
+        self
+    }
+
+    /// When hit-testing, win over overlapping or equally close widgets with a lower priority.
+    ///
+    /// The default priority is zero.
+    /// Use this e.g. to let a small resize handle win over a big clickable area next to it.
+    ///
+    /// Like all hit-testing, this takes effect the next pass.
+    pub fn interact_priority(self, priority: i8) -> Self {
+        let id = self.id;
+        self.ctx
+            .pass_state_mut(|state| state.widgets.set_interact_priority(id, priority));
+        self
+    }
+
+    /// Make the area in which this widget can be hovered, clicked or dragged larger
+    /// (or smaller, with a negative margin).
+    ///
+    /// Like all hit-testing, this takes effect the next pass.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// // Easier to hit:
+    /// ui.small_button("x").interact_expand(egui::Margin::same(4));
+    /// # });
+    /// ```
+    pub fn interact_expand(self, expand: impl Into<Margin>) -> Self {
+        let id = self.id;
+        let expand = expand.into();
+        self.ctx
+            .pass_state_mut(|state| state.widgets.set_interact_expand(id, expand));
+        self
+    }
+
+    /// Is all of the widget inside the visible area of its [`Ui`], e.g. the viewport of a [`crate::ScrollArea`]?
+    ///
+    /// This only checks clipping, not whether some other window covers the widget.
+    ///
+    /// See also [`Self::is_partially_visible`] and [`Self::scroll_to_me`].
+    #[inline]
+    pub fn is_fully_visible(&self) -> bool {
+        self.interact_rect.contains_rect(self.rect)
+    }
+
+    /// Is any part of the widget inside the visible area of its [`Ui`], e.g. the viewport of a [`crate::ScrollArea`]?
+    ///
+    /// This only checks clipping, not whether some other window covers the widget.
+    ///
+    /// See also [`Self::is_fully_visible`].
+    #[inline]
+    pub fn is_partially_visible(&self) -> bool {
+        self.interact_rect.is_positive()
+    }
+
     /// Adjust the scroll position until this UI becomes visible.
     ///
     /// If `align` is [`Align::TOP`] it means "put the top of the rect at the top of the scroll area", etc.
@@ -811,6 +1001,7 @@ impl Response {
         self.ctx
             .register_widget_info(self.id, || event.widget_info().clone());
 
+        self.ctx.record_macro_event(self.id, &event);
         self.ctx.output_mut(|o| o.events.push(event));
     }
 
@@ -0,0 +1,275 @@
+use crate::{Id, IdSet, Modifiers, Pos2, Rect, Response, Shape, StrokeKind, Ui, Vec2};
+
+/// Select widgets by dragging out a rectangle (or a freeform lasso) over them.
+///
+/// The drag is started on the empty space of a canvas or list,
+/// i.e. on a [`Response`] that senses drags and is behind the selectable widgets.
+/// When the drag is released, [`RubberBandResponse::selected`] contains the ids of
+/// the widgets under the rubber band that sense clicks.
+///
+/// The rubber band is stored relative to the [`Response::rect`] of the background,
+/// so it stays in place if the background is scrolled or transformed while dragging.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut selection = egui::IdSet::default();
+/// let background = ui.interact_bg_behind_children(egui::Sense::drag());
+/// for i in 0..10 {
+///     let id = egui::Id::new(i);
+///     let selected = selection.contains(&id);
+///     ui.push_id(i, |ui| ui.add(egui::Button::selectable(selected, format!("Item {i}"))));
+/// }
+///
+/// let rubber_band = egui::RubberBand::new().show(ui, &background);
+/// rubber_band.update_selection(&mut selection);
+/// # });
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RubberBand {
+    lasso: bool,
+}
+
+/// What happened with a [`RubberBand`] this pass.
+#[derive(Clone, Debug, Default)]
+pub struct RubberBandResponse {
+    /// Is the rubber band being dragged out right now?
+    pub active: bool,
+
+    /// Set on the pass the rubber band is released:
+    /// the ids of the widgets that were selected with it.
+    pub selected: Option<Vec<Id>>,
+
+    /// The modifiers that were held when the rubber band was released.
+    ///
+    /// Used by [`Self::update_selection`].
+    pub modifiers: Modifiers,
+}
+
+impl RubberBandResponse {
+    /// Update a multi-selection with [`Self::selected`], if the rubber band was released.
+    ///
+    /// The selection is replaced, unless shift is held (add to the selection)
+    /// or the command key is held (toggle the selected widgets).
+    pub fn update_selection(&self, selection: &mut IdSet) {
+        let Some(selected) = &self.selected else {
+            return;
+        };
+        if self.modifiers.command {
+            for id in selected {
+                if !selection.remove(id) {
+                    selection.insert(*id);
+                }
+            }
+        } else {
+            if !self.modifiers.shift {
+                selection.clear();
+            }
+            selection.extend(selected.iter().copied());
+        }
+    }
+}
+
+/// The points of the rubber band, relative to the background.
+#[derive(Clone, Default)]
+struct State {
+    points: Vec<Vec2>,
+}
+
+impl RubberBand {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Select with a freeform lasso instead of a rectangle.
+    ///
+    /// With a lasso, a widget is selected if its center is inside the lasso.
+    /// With a rectangle, a widget is selected if it intersects the rectangle.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn lasso(mut self, lasso: bool) -> Self {
+        self.lasso = lasso;
+        self
+    }
+
+    /// Track drags on the given background [`Response`] and paint the rubber band.
+    ///
+    /// The background must sense drags, and should be behind the selectable widgets,
+    /// e.g. created with [`Ui::interact_bg_behind_children`].
+    pub fn show(self, ui: &Ui, background: &Response) -> RubberBandResponse {
+        let Self { lasso } = self;
+
+        let state_id = background.id.with("rubber_band");
+        let origin = background.rect.min;
+        let mut state = ui.data(|data| data.get_temp::<State>(state_id));
+
+        if background.drag_started() {
+            // Start where the pointer was pressed, not where the drag was detected:
+            let press_origin = ui.input(|i| i.pointer.press_origin()).map(|pos| {
+                ui.ctx()
+                    .layer_transform_from_global(ui.layer_id())
+                    .map_or(pos, |from_global| from_global * pos)
+            });
+            state = Some(State {
+                points: press_origin.map(|pos| pos - origin).into_iter().collect(),
+            });
+        }
+
+        let Some(mut state) = state else {
+            return RubberBandResponse::default();
+        };
+
+        if let Some(pointer_pos) = background.interact_pointer_pos() {
+            let point = pointer_pos - origin;
+            if lasso || state.points.len() < 2 {
+                if state.points.last() != Some(&point) {
+                    state.points.push(point);
+                }
+            } else {
+                state.points[1] = point;
+            }
+        }
+
+        let points: Vec<Pos2> = state.points.iter().map(|&point| origin + point).collect();
+
+        if background.drag_stopped() || !background.dragged() {
+            ui.data_mut(|data| data.remove::<State>(state_id));
+            let selected = selected_widgets(ui, background.id, lasso, &points);
+            return RubberBandResponse {
+                active: false,
+                selected: Some(selected),
+                modifiers: ui.input(|i| i.modifiers),
+            };
+        }
+
+        paint(ui, lasso, &points);
+        ui.data_mut(|data| data.insert_temp(state_id, state));
+
+        RubberBandResponse {
+            active: true,
+            selected: None,
+            modifiers: ui.input(|i| i.modifiers),
+        }
+    }
+}
+
+fn paint(ui: &Ui, lasso: bool, points: &[Pos2]) {
+    let selection = &ui.visuals().selection;
+    let fill = selection.bg_fill.gamma_multiply(0.25);
+    if lasso {
+        if 2 <= points.len() {
+            ui.painter()
+                .add(Shape::closed_line(points.to_vec(), selection.stroke));
+        }
+    } else if let [a, b] = points {
+        ui.painter().rect(
+            Rect::from_two_pos(*a, *b),
+            0.0,
+            fill,
+            selection.stroke,
+            StrokeKind::Inside,
+        );
+    }
+}
+
+/// The clickable widgets in the same layer as the background that are under the rubber band.
+fn selected_widgets(ui: &Ui, background_id: Id, lasso: bool, points: &[Pos2]) -> Vec<Id> {
+    let band_rect = Rect::from_points(points);
+    if !band_rect.is_positive() && !lasso {
+        return vec![];
+    }
+
+    let layer_id = ui.layer_id();
+
+    // The widgets of the last pass are all there, no matter where `show` is called:
+    let mut selected: Vec<(Id, Rect)> = ui.ctx().viewport(|viewport| {
+        viewport
+            .prev_pass
+            .widgets
+            .get_layer(layer_id)
+            .filter(|widget| widget.id != background_id && widget.sense.senses_click())
+            .filter(|widget| {
+                if lasso {
+                    polygon_contains(points, widget.rect.center())
+                } else {
+                    band_rect.intersects(widget.rect)
+                }
+            })
+            .map(|widget| (widget.id, widget.rect))
+            .collect()
+    });
+
+    // Sort in reading order:
+    selected.sort_by(|(_, a), (_, b)| {
+        (a.top(), a.left())
+            .partial_cmp(&(b.top(), b.left()))
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    selected.into_iter().map(|(id, _)| id).collect()
+}
+
+fn polygon_contains(points: &[Pos2], pos: Pos2) -> bool {
+    let mut inside = false;
+    for (i, &a) in points.iter().enumerate() {
+        let b = points[(i + 1) % points.len()];
+        if (a.y > pos.y) != (b.y > pos.y) && pos.x < a.x + (pos.y - a.y) / (b.y - a.y) * (b.x - a.x)
+        {
+            inside = !inside;
+        }
+    }
+    inside
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CentralPanel, Context, Event, PointerButton, RawInput, Sense, vec2};
+
+    #[test]
+    fn test_rubber_band() {
+        let ctx = Context::default();
+        let run = |events: Vec<Event>| {
+            let input = RawInput {
+                events,
+                ..Default::default()
+            };
+            let mut result = RubberBandResponse::default();
+            let mut button_rects = Vec::new();
+            let _ = ctx.run(input, |ctx| {
+                CentralPanel::default().show(ctx, |ui| {
+                    let background = ui.interact_bg_behind_children(Sense::drag());
+                    for i in 0..5 {
+                        let response = ui.push_id(i, |ui| ui.button(format!("Item {i}"))).inner;
+                        button_rects.push((response.id, response.rect));
+                    }
+                    result = RubberBand::new().show(ui, &background);
+                });
+            });
+            (result, button_rects)
+        };
+        run(vec![]);
+        let (_, button_rects) = run(vec![]);
+
+        // Drag out a band over the first two buttons, starting to the right of them:
+        let (first, first_rect) = button_rects[0];
+        let (second, second_rect) = button_rects[1];
+        let start = first_rect.right_top() + vec2(100.0, -1.0);
+        let end = second_rect.center();
+        let button = |pos, pressed| Event::PointerButton {
+            pos,
+            button: PointerButton::Primary,
+            pressed,
+            modifiers: Default::default(),
+        };
+        run(vec![Event::PointerMoved(start), button(start, true)]);
+        run(vec![Event::PointerMoved(start + vec2(-10.0, 0.0))]);
+        let (response, _) = run(vec![Event::PointerMoved(end)]);
+        assert!(response.active);
+        let (response, _) = run(vec![button(end, false)]);
+        assert_eq!(response.selected, Some(vec![first, second]));
+
+        let mut selection = IdSet::default();
+        response.update_selection(&mut selection);
+        assert_eq!(selection.len(), 2);
+    }
+}
@@ -9,9 +9,10 @@ use std::{collections::BTreeMap, ops::RangeInclusive, sync::Arc};
 use crate::{
     ComboBox, CursorIcon, FontFamily, FontId, Grid, Margin, Response, RichText, TextWrapMode,
     WidgetText,
-    ecolor::Color32,
+    ecolor::{Color32, HdrColor},
     emath::{Rangef, Rect, Vec2, pos2, vec2},
     reset_button_with,
+    widgets::unit::{DisplayUnits, Quantity, Unit},
 };
 
 /// How to format numbers in e.g. a [`crate::DragValue`].
@@ -59,6 +60,205 @@ impl PartialEq for NumberFormatter {
     }
 }
 
+/// Locale-specific number conventions: decimal separator, digit grouping and percent style.
+///
+/// This is applied on top of [`Style::number_formatter`] in e.g. a [`crate::DragValue`],
+/// and is also used to parse numbers the user types in.
+///
+/// ```
+/// use egui::style::NumberFormat;
+///
+/// let format = NumberFormat::european();
+/// assert_eq!(format.localize("-1234567.5"), "-1.234.567,5");
+/// assert_eq!(format.parse("-1.234.567,5"), Some(-1234567.5));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct NumberFormat {
+    /// Separates the integer part from the fraction, e.g. `.` or `,`.
+    pub decimal_separator: char,
+
+    /// Inserted between every group of three digits in the integer part, e.g. `,` in `1,000,000`.
+    ///
+    /// `None` means no grouping.
+    pub grouping_separator: Option<char>,
+
+    /// Show the value multiplied by 100, followed by `%`.
+    ///
+    /// A value of `0.25` is then shown as `25%`, and typing `25` or `25%` results in `0.25`.
+    pub percent: bool,
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        Self::PLAIN
+    }
+}
+
+impl NumberFormat {
+    /// `1234567.5`
+    pub const PLAIN: Self = Self {
+        decimal_separator: '.',
+        grouping_separator: None,
+        percent: false,
+    };
+
+    /// `1,234,567.5`
+    pub fn english() -> Self {
+        Self::PLAIN.grouping_separator(Some(','))
+    }
+
+    /// `1.234.567,5`, as used in e.g. Germany, Italy and Spain.
+    pub fn european() -> Self {
+        Self::PLAIN
+            .decimal_separator(',')
+            .grouping_separator(Some('.'))
+    }
+
+    /// `1 234 567,5` (with a narrow no-break space), as used in e.g. France and Sweden.
+    pub fn si() -> Self {
+        Self::PLAIN
+            .decimal_separator(',')
+            .grouping_separator(Some('\u{202F}'))
+    }
+
+    /// Default: `.`
+    #[inline]
+    pub fn decimal_separator(mut self, decimal_separator: char) -> Self {
+        self.decimal_separator = decimal_separator;
+        self
+    }
+
+    /// Default: `None`
+    #[inline]
+    pub fn grouping_separator(mut self, grouping_separator: Option<char>) -> Self {
+        self.grouping_separator = grouping_separator;
+        self
+    }
+
+    /// Default: `false`
+    #[inline]
+    pub fn percent(mut self, percent: bool) -> Self {
+        self.percent = percent;
+        self
+    }
+
+    /// Format a number using the given formatter, then apply these conventions.
+    ///
+    /// The decimal range refers to the value itself, so a percentage shows two decimals fewer.
+    pub fn format(
+        &self,
+        formatter: &NumberFormatter,
+        value: f64,
+        decimals: RangeInclusive<usize>,
+    ) -> String {
+        if self.percent {
+            let decimals = decimals.start().saturating_sub(2)..=decimals.end().saturating_sub(2);
+            let mut text = self.localize(&formatter.format(100.0 * value, decimals));
+            text.push('%');
+            text
+        } else {
+            self.localize(&formatter.format(value, decimals))
+        }
+    }
+
+    /// Convert a number formatted with `.` as decimal separator and no grouping,
+    /// like `-1234.5`, to these conventions.
+    ///
+    /// Does not add a `%` sign.
+    /// Text that isn't a plain number (e.g. `inf` or `1e20`) is only given the new decimal separator.
+    pub fn localize(&self, text: &str) -> String {
+        let (sign, unsigned) = match text.strip_prefix(['-', '−']) {
+            Some(rest) => (&text[..text.len() - rest.len()], rest),
+            None => ("", text),
+        };
+        let (integer, fraction) = match unsigned.split_once('.') {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (unsigned, None),
+        };
+
+        let mut out = String::with_capacity(text.len() + integer.len() / 3);
+        out.push_str(sign);
+
+        match self.grouping_separator {
+            Some(separator) if integer.bytes().all(|b| b.is_ascii_digit()) => {
+                for (i, digit) in integer.chars().enumerate() {
+                    if i > 0 && (integer.len() - i) % 3 == 0 {
+                        out.push(separator);
+                    }
+                    out.push(digit);
+                }
+            }
+            _ => out.push_str(integer),
+        }
+
+        if let Some(fraction) = fraction {
+            out.push(self.decimal_separator);
+            out.push_str(fraction);
+        }
+        out
+    }
+
+    /// Parse a number written with these conventions.
+    ///
+    /// Whitespace and grouping separators are ignored, the special minus character (U+2212)
+    /// is treated as a normal minus, and a trailing `%` is optional when [`Self::percent`] is set.
+    pub fn parse(&self, text: &str) -> Option<f64> {
+        let text = text.trim();
+        let text = if self.percent {
+            text.strip_suffix('%').unwrap_or(text)
+        } else {
+            text
+        };
+
+        let text: String = text
+            .chars()
+            .filter(|&c| !c.is_whitespace() && Some(c) != self.grouping_separator)
+            .map(|c| match c {
+                '−' => '-',
+                c if c == self.decimal_separator => '.',
+                c => c,
+            })
+            .collect();
+
+        let value: f64 = text.parse().ok()?;
+        Some(if self.percent { value / 100.0 } else { value })
+    }
+}
+
+#[test]
+fn test_number_format() {
+    let formatter = NumberFormatter::new(emath::format_with_decimals_in_range);
+
+    let plain = NumberFormat::PLAIN;
+    assert_eq!(plain.format(&formatter, 1234567.5, 1..=1), "1234567.5");
+
+    let english = NumberFormat::english();
+    assert_eq!(english.format(&formatter, -1234.5, 1..=1), "-1,234.5");
+    assert_eq!(english.format(&formatter, 123.0, 0..=0), "123");
+    assert_eq!(english.parse("-1,234.5"), Some(-1234.5));
+
+    let european = NumberFormat::european();
+    assert_eq!(
+        european.format(&formatter, 1234567.25, 2..=2),
+        "1.234.567,25"
+    );
+    assert_eq!(european.parse("1.234.567,25"), Some(1234567.25));
+    assert_eq!(european.parse("1,5"), Some(1.5));
+    assert_eq!(european.parse("−0,5"), Some(-0.5));
+
+    let si = NumberFormat::si();
+    assert_eq!(si.format(&formatter, 1234.5, 1..=1), "1\u{202F}234,5");
+    assert_eq!(si.parse("1 234,5"), Some(1234.5));
+
+    let percent = NumberFormat::european().percent(true);
+    assert_eq!(percent.format(&formatter, 0.255, 0..=3), "25,5%");
+    assert_eq!(percent.parse("25,5%"), Some(0.255));
+    assert_eq!(percent.parse("25,5"), Some(0.255));
+
+    assert_eq!(english.localize("inf"), "inf");
+}
+
 // ----------------------------------------------------------------------------
 
 /// Alias for a [`FontId`] (font of a certain size).
@@ -283,6 +483,18 @@ pub struct Style {
     #[cfg_attr(feature = "serde", serde(skip))]
     pub number_formatter: NumberFormatter,
 
+    /// Decimal separator, digit grouping, etc. used when showing and parsing numbers,
+    /// e.g. in a [`crate::DragValue`].
+    ///
+    /// Can be overridden per widget with e.g. [`crate::DragValue::number_format`].
+    pub number_format: NumberFormat,
+
+    /// The preferred units to show e.g. lengths and angles in,
+    /// for a [`crate::DragValue`] with a [`crate::DragValue::unit`].
+    ///
+    /// Can be overridden per widget with [`crate::DragValue::display_unit`].
+    pub display_units: DisplayUnits,
+
     /// If set, labels, buttons, etc. will use this to determine whether to wrap the text at the
     /// right edge of the [`Ui`] they are in. By default, this is `None`.
     ///
@@ -314,6 +526,9 @@ pub struct Style {
     /// How many seconds a typical animation should last.
     pub animation_time: f32,
 
+    /// How widgets animate between hovered, active etc, see [`Ui::interact_visuals`].
+    pub widget_transition: WidgetTransition,
+
     /// Options to help debug why egui behaves strangely.
     ///
     /// Only available in debug builds.
@@ -354,7 +569,15 @@ impl Style {
     }
 
     pub fn interact_selectable(&self, response: &Response, selected: bool) -> WidgetVisuals {
-        let mut visuals = *self.visuals.widgets.style(response);
+        self.selectable_visuals(*self.visuals.widgets.style(response), selected)
+    }
+
+    /// Use the [`Visuals::selection`] colors if `selected`.
+    pub(crate) fn selectable_visuals(
+        &self,
+        mut visuals: WidgetVisuals,
+        selected: bool,
+    ) -> WidgetVisuals {
         if selected {
             visuals.weak_bg_fill = self.visuals.selection.bg_fill;
             visuals.bg_fill = self.visuals.selection.bg_fill;
@@ -848,6 +1071,12 @@ pub struct Interaction {
     /// Radius of the interactive area of the corner of a window during drag-to-resize.
     pub resize_grab_radius_corner: f32,
 
+    /// How much further the interactive areas of [`Self::resize_grab_radius_side`]
+    /// and [`Self::resize_grab_radius_corner`] reach outside of a window.
+    ///
+    /// This makes it easier to grab the edges of a window, since there is nothing else to hit there.
+    pub resize_grab_outside: f32,
+
     /// If `false`, tooltips will show up anytime you hover anything, even if mouse is still moving
     pub show_tooltips_only_when_still: bool,
 
@@ -904,6 +1133,51 @@ impl Default for TextCursorStyle {
     }
 }
 
+/// Look and feel of the ring around the widget with keyboard focus.
+///
+/// The ring is painted on top of the focused widget at the end of each pass,
+/// so widgets don't need to show focus themselves.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct FocusRing {
+    /// The color and width of the ring.
+    ///
+    /// Use [`Stroke::NONE`] to not show a focus ring.
+    pub stroke: Stroke,
+
+    /// How far outside the widget the ring is painted.
+    pub offset: f32,
+
+    /// The rounding of the ring.
+    pub corner_radius: CornerRadius,
+
+    /// If set, the ring is dashed, with dashes and gaps of this length.
+    pub dash_length: Option<f32>,
+
+    /// How fast the dashes move around the ring, in points per second.
+    ///
+    /// Only used together with [`Self::dash_length`].
+    pub dash_speed: f32,
+
+    /// Only show the ring when the focus was moved with the keyboard,
+    /// and not e.g. when a [`crate::TextEdit`] was clicked.
+    pub keyboard_only: bool,
+}
+
+impl Default for FocusRing {
+    fn default() -> Self {
+        Self {
+            stroke: Stroke::new(2.0, Color32::from_rgb(90, 170, 255)), // Dark mode
+            offset: 2.0,
+            corner_radius: CornerRadius::same(4),
+            dash_length: None,
+            dash_speed: 20.0,
+            keyboard_only: true,
+        }
+    }
+}
+
 /// Controls the visual style (colors etc) of egui.
 ///
 /// You can change the visuals of a [`Ui`] with [`Ui::visuals_mut`]
@@ -1001,6 +1275,9 @@ pub struct Visuals {
     /// How the text cursor acts.
     pub text_cursor: TextCursorStyle,
 
+    /// How the widget with keyboard focus is highlighted.
+    pub focus_ring: FocusRing,
+
     /// Allow child widgets to be just on the border and still have a stroke with some thickness
     pub clip_rect_margin: f32,
 
@@ -1013,6 +1290,11 @@ pub struct Visuals {
     /// Draw a vertical line left of indented region, in e.g. [`crate::CollapsingHeader`].
     pub indent_has_left_vline: bool,
 
+    /// The stroke of the line drawn left of indented regions, see [`Self::indent_has_left_vline`].
+    ///
+    /// If `None`, `widgets.noninteractive.bg_stroke` is used.
+    pub indent_guide_stroke: Option<Stroke>,
+
     /// Whether or not Grids and Tables should be striped by default
     /// (have alternating rows differently colored).
     pub striped: bool,
@@ -1119,6 +1401,14 @@ impl Visuals {
 pub struct Selection {
     pub bg_fill: Color32,
     pub stroke: Stroke,
+
+    /// Make accent fills (e.g. of sliders and progress bars) glow by brightening them beyond white.
+    ///
+    /// Only has an effect if the backend paints into an HDR target,
+    /// see [`crate::BackendCapabilities::hdr`].
+    ///
+    /// Default: 1.0 (no glow).
+    pub intensity: f32,
 }
 
 /// Shape of the handle for sliders and similar widgets.
@@ -1157,21 +1447,147 @@ pub struct Widgets {
     /// The style of an interactive widget as you are clicking or dragging it.
     pub active: WidgetVisuals,
 
+    /// The style of an interactive widget with keyboard focus.
+    ///
+    /// Defaults to the same as [`Self::inactive`], since focus is shown with [`Visuals::focus_ring`].
+    pub focused: WidgetVisuals,
+
     /// The style of a button that has an open menu beneath it (e.g. a combo-box)
     pub open: WidgetVisuals,
 }
 
 impl Widgets {
     pub fn style(&self, response: &Response) -> &WidgetVisuals {
+        self.get(WidgetState::from_response(response))
+    }
+
+    /// The visuals for the given state.
+    pub fn get(&self, state: WidgetState) -> &WidgetVisuals {
+        match state {
+            WidgetState::Noninteractive => &self.noninteractive,
+            WidgetState::Disabled | WidgetState::Inactive => &self.inactive,
+            WidgetState::Hovered => &self.hovered,
+            WidgetState::Active => &self.active,
+            WidgetState::Focused => &self.focused,
+        }
+    }
+}
+
+/// The state of interaction of a widget, which decides which [`WidgetVisuals`] of [`Widgets`] it uses.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum WidgetState {
+    /// Something you cannot interact with, e.g. a label.
+    Noninteractive,
+
+    /// An interactive widget that is disabled.
+    ///
+    /// Uses [`Widgets::inactive`], and is then faded out by [`Visuals::disabled_alpha`].
+    Disabled,
+
+    /// An interactive widget at rest.
+    Inactive,
+
+    /// Hovered or highlighted.
+    Hovered,
+
+    /// Being clicked or dragged.
+    Active,
+
+    /// Has keyboard focus.
+    Focused,
+}
+
+impl WidgetState {
+    pub fn from_response(response: &Response) -> Self {
         if !response.sense.interactive() {
-            &self.noninteractive
-        } else if response.is_pointer_button_down_on() || response.has_focus() || response.clicked()
-        {
-            &self.active
+            Self::Noninteractive
+        } else if !response.enabled() {
+            Self::Disabled
+        } else if response.is_pointer_button_down_on() || response.clicked() {
+            Self::Active
         } else if response.hovered() || response.highlighted() {
-            &self.hovered
+            Self::Hovered
+        } else if response.has_focus() {
+            Self::Focused
         } else {
-            &self.inactive
+            Self::Inactive
+        }
+    }
+}
+
+/// How widgets animate from one [`WidgetState`] to another, see [`Style::widget_transition`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct WidgetTransition {
+    /// How many seconds the transition takes.
+    ///
+    /// Zero (the default) means widgets change style instantly.
+    pub duration: f32,
+
+    /// How the transition progresses over time.
+    pub easing: Easing,
+}
+
+impl Default for WidgetTransition {
+    fn default() -> Self {
+        Self {
+            duration: 0.0,
+            easing: Easing::CubicOut,
+        }
+    }
+}
+
+impl WidgetTransition {
+    fn ui(&mut self, ui: &mut Ui) {
+        let Self { duration, easing } = self;
+        ui.horizontal(|ui| {
+            ui.add(
+                DragValue::new(duration)
+                    .range(0.0..=1.0)
+                    .speed(0.01)
+                    .suffix(" s"),
+            );
+            ComboBox::from_id_salt("widget_transition_easing")
+                .selected_text(format!("{easing:?}"))
+                .show_ui(ui, |ui| {
+                    for value in Easing::ALL {
+                        ui.selectable_value(easing, value, format!("{value:?}"));
+                    }
+                });
+        });
+    }
+}
+
+/// An easing function that can be stored in a [`Style`], see [`emath::easing`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Easing {
+    #[default]
+    Linear,
+    QuadraticOut,
+    CubicOut,
+    CubicInOut,
+    SinInOut,
+}
+
+impl Easing {
+    pub const ALL: [Self; 5] = [
+        Self::Linear,
+        Self::QuadraticOut,
+        Self::CubicOut,
+        Self::CubicInOut,
+        Self::SinInOut,
+    ];
+
+    /// Map `t` in `[0, 1]` to `[0, 1]`.
+    pub fn apply(self, t: f32) -> f32 {
+        match self {
+            Self::Linear => emath::easing::linear(t),
+            Self::QuadraticOut => emath::easing::quadratic_out(t),
+            Self::CubicOut => emath::easing::cubic_out(t),
+            Self::CubicInOut => emath::easing::cubic_in_out(t),
+            Self::SinInOut => emath::easing::sin_in_out(t),
         }
     }
 }
@@ -1216,6 +1632,28 @@ impl WidgetVisuals {
     pub fn rounding(&self) -> CornerRadius {
         self.corner_radius
     }
+
+    /// Interpolate between `self` (at `t = 0`) and `other` (at `t = 1`).
+    pub fn lerp(&self, other: &Self, t: f32) -> Self {
+        let color = |a: Color32, b: Color32| a.lerp_to_gamma(b, t);
+        let stroke = |a: Stroke, b: Stroke| {
+            Stroke::new(emath::lerp(a.width..=b.width, t), color(a.color, b.color))
+        };
+        let radius = |a: u8, b: u8| emath::lerp(a as f32..=b as f32, t).round() as u8;
+        Self {
+            bg_fill: color(self.bg_fill, other.bg_fill),
+            weak_bg_fill: color(self.weak_bg_fill, other.weak_bg_fill),
+            bg_stroke: stroke(self.bg_stroke, other.bg_stroke),
+            corner_radius: CornerRadius {
+                nw: radius(self.corner_radius.nw, other.corner_radius.nw),
+                ne: radius(self.corner_radius.ne, other.corner_radius.ne),
+                sw: radius(self.corner_radius.sw, other.corner_radius.sw),
+                se: radius(self.corner_radius.se, other.corner_radius.se),
+            },
+            fg_stroke: stroke(self.fg_stroke, other.fg_stroke),
+            expansion: emath::lerp(self.expansion..=other.expansion, t),
+        }
+    }
 }
 
 /// Options for help debug egui by adding extra visualization
@@ -1267,6 +1705,14 @@ pub struct DebugOptions {
     ///
     /// See [`emath::GuiRounding`] for more.
     pub show_unaligned: bool,
+
+    /// Highlight text that doesn't have enough contrast against its background,
+    /// according to WCAG 2 level AA.
+    ///
+    /// Red means the text is hard to read even if large; orange means it is only acceptable for large text.
+    ///
+    /// See also [`crate::Context::contrast_issues`].
+    pub show_contrast_issues: bool,
 }
 
 #[cfg(debug_assertions)]
@@ -1283,6 +1729,7 @@ impl Default for DebugOptions {
             show_interactive_widgets: false,
             show_widget_hits: false,
             show_unaligned: cfg!(debug_assertions),
+            show_contrast_issues: false,
         }
     }
 }
@@ -1313,12 +1760,15 @@ impl Default for Style {
             text_styles: default_text_styles(),
             drag_value_text_style: TextStyle::Button,
             number_formatter: NumberFormatter(Arc::new(emath::format_with_decimals_in_range)),
+            number_format: NumberFormat::default(),
+            display_units: DisplayUnits::default(),
             wrap: None,
             wrap_mode: None,
             spacing: Spacing::default(),
             interaction: Interaction::default(),
             visuals: Visuals::default(),
             animation_time: 1.0 / 12.0,
+            widget_transition: WidgetTransition::default(),
             #[cfg(debug_assertions)]
             debug: Default::default(),
             explanation_tooltips: false,
@@ -1363,6 +1813,7 @@ impl Default for Interaction {
             interact_radius: 5.0,
             resize_grab_radius_side: 5.0,
             resize_grab_radius_corner: 10.0,
+            resize_grab_outside: 4.0,
             show_tooltips_only_when_still: true,
             tooltip_delay: 0.5,
             tooltip_grace_time: 0.2,
@@ -1417,10 +1868,13 @@ impl Visuals {
 
             text_cursor: Default::default(),
 
+            focus_ring: Default::default(),
+
             clip_rect_margin: 3.0, // should be at least half the size of the widest frame stroke + max WidgetVisuals::expansion
             button_frame: true,
             collapsing_header_frame: false,
             indent_has_left_vline: true,
+            indent_guide_stroke: None,
 
             striped: false,
 
@@ -1473,6 +1927,11 @@ impl Visuals {
                 ..Default::default()
             },
 
+            focus_ring: FocusRing {
+                stroke: Stroke::new(2.0, Color32::from_rgb(0, 110, 220)),
+                ..Default::default()
+            },
+
             ..Self::dark()
         }
     }
@@ -1484,11 +1943,224 @@ impl Default for Visuals {
     }
 }
 
+impl Visuals {
+    /// Interpolate all colors between `self` (at `t = 0`) and `other` (at `t = 1`).
+    ///
+    /// Everything that isn't a color is taken from whichever is closest.
+    /// This is used to cross-fade between the dark and light theme,
+    /// see [`crate::Options::theme_transition_time`].
+    pub fn lerp(&self, other: &Self, t: f32) -> Self {
+        fn lerp_color(color: &mut Color32, other: Color32, t: f32) {
+            *color = color.lerp_to_gamma(other, t);
+        }
+        fn lerp_optional_color(color: &mut Option<Color32>, other: Option<Color32>, t: f32) {
+            if let (Some(color), Some(other)) = (color.as_mut(), other) {
+                lerp_color(color, other, t);
+            }
+        }
+        fn lerp_widget(widget: &mut WidgetVisuals, other: &WidgetVisuals, t: f32) {
+            let WidgetVisuals {
+                bg_fill,
+                weak_bg_fill,
+                bg_stroke,
+                corner_radius: _,
+                fg_stroke,
+                expansion: _,
+            } = widget;
+            lerp_color(bg_fill, other.bg_fill, t);
+            lerp_color(weak_bg_fill, other.weak_bg_fill, t);
+            lerp_color(&mut bg_stroke.color, other.bg_stroke.color, t);
+            lerp_color(&mut fg_stroke.color, other.fg_stroke.color, t);
+        }
+
+        // Start with whichever is closest, and move the colors towards the other one:
+        let (mut out, to, t) = if t < 0.5 {
+            (self.clone(), other, t)
+        } else {
+            (other.clone(), self, 1.0 - t)
+        };
+
+        let Self {
+            dark_mode: _,
+            text_alpha_from_coverage: _,
+            override_text_color,
+            weak_text_alpha,
+            weak_text_color,
+            widgets,
+            selection,
+            hyperlink_color,
+            faint_bg_color,
+            extreme_bg_color,
+            text_edit_bg_color,
+            code_bg_color,
+            warn_fg_color,
+            error_fg_color,
+            window_corner_radius: _,
+            window_shadow,
+            window_fill,
+            window_stroke,
+            window_highlight_topmost: _,
+            menu_corner_radius: _,
+            panel_fill,
+            popup_shadow,
+            resize_corner_size: _,
+            text_cursor,
+            focus_ring,
+            clip_rect_margin: _,
+            button_frame: _,
+            collapsing_header_frame: _,
+            indent_has_left_vline: _,
+            indent_guide_stroke,
+            striped: _,
+            slider_trailing_fill: _,
+            handle_shape: _,
+            interact_cursor: _,
+            image_loading_spinners: _,
+            numeric_color_space: _,
+            disabled_alpha,
+        } = &mut out;
+
+        lerp_optional_color(override_text_color, to.override_text_color, t);
+        *weak_text_alpha = emath::lerp(*weak_text_alpha..=to.weak_text_alpha, t);
+        lerp_optional_color(weak_text_color, to.weak_text_color, t);
+        lerp_widget(&mut widgets.noninteractive, &to.widgets.noninteractive, t);
+        lerp_widget(&mut widgets.inactive, &to.widgets.inactive, t);
+        lerp_widget(&mut widgets.hovered, &to.widgets.hovered, t);
+        lerp_widget(&mut widgets.active, &to.widgets.active, t);
+        lerp_widget(&mut widgets.focused, &to.widgets.focused, t);
+        lerp_widget(&mut widgets.open, &to.widgets.open, t);
+        lerp_color(&mut selection.bg_fill, to.selection.bg_fill, t);
+        lerp_color(&mut selection.stroke.color, to.selection.stroke.color, t);
+        lerp_color(hyperlink_color, to.hyperlink_color, t);
+        lerp_color(faint_bg_color, to.faint_bg_color, t);
+        lerp_color(extreme_bg_color, to.extreme_bg_color, t);
+        lerp_optional_color(text_edit_bg_color, to.text_edit_bg_color, t);
+        lerp_color(code_bg_color, to.code_bg_color, t);
+        lerp_color(warn_fg_color, to.warn_fg_color, t);
+        lerp_color(error_fg_color, to.error_fg_color, t);
+        lerp_color(&mut window_shadow.color, to.window_shadow.color, t);
+        lerp_color(window_fill, to.window_fill, t);
+        lerp_color(&mut window_stroke.color, to.window_stroke.color, t);
+        lerp_color(panel_fill, to.panel_fill, t);
+        lerp_color(&mut popup_shadow.color, to.popup_shadow.color, t);
+        lerp_color(
+            &mut text_cursor.stroke.color,
+            to.text_cursor.stroke.color,
+            t,
+        );
+        lerp_color(&mut focus_ring.stroke.color, to.focus_ring.stroke.color, t);
+        if let (Some(stroke), Some(to_stroke)) = (indent_guide_stroke, to.indent_guide_stroke) {
+            lerp_color(&mut stroke.color, to_stroke.color, t);
+        }
+        *disabled_alpha = emath::lerp(*disabled_alpha..=to.disabled_alpha, t);
+
+        out
+    }
+}
+
+/// A set of accent colors: selection, hyperlinks, warnings and errors.
+///
+/// See [`Visuals::set_accent_palette`].
+/// To check how your UI looks to color-blind users,
+/// see [`epaint::TessellationOptions::simulate_color_vision_deficiency`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum AccentPalette {
+    /// The colors of [`Visuals::dark`] and [`Visuals::light`].
+    Default,
+
+    /// Based on the palette by Okabe and Ito, which stays distinguishable with protanopia and deuteranopia.
+    ///
+    /// Warnings are yellow/orange, errors vermillion and hyperlinks blue.
+    OkabeIto,
+
+    /// Based on the color-blind safe palette of the IBM Design Library.
+    ///
+    /// Warnings are gold/orange, errors magenta and hyperlinks blue.
+    Ibm,
+}
+
+impl AccentPalette {
+    pub const ALL: [Self; 3] = [Self::Default, Self::OkabeIto, Self::Ibm];
+}
+
+impl Visuals {
+    /// Replace the accent colors (selection, hyperlinks, warnings and errors) with those of the given palette.
+    ///
+    /// The colors depend on [`Self::dark_mode`], so call this after choosing dark or light mode.
+    ///
+    /// ```
+    /// let mut visuals = egui::Visuals::light();
+    /// visuals.set_accent_palette(egui::style::AccentPalette::OkabeIto);
+    /// ```
+    pub fn set_accent_palette(&mut self, palette: AccentPalette) {
+        let rgb = Color32::from_rgb;
+        let (selection_fill, selection_text, hyperlink, warn, error) =
+            match (palette, self.dark_mode) {
+                (AccentPalette::Default, dark_mode) => {
+                    let default = if dark_mode {
+                        Self::dark()
+                    } else {
+                        Self::light()
+                    };
+                    self.selection = default.selection;
+                    self.hyperlink_color = default.hyperlink_color;
+                    self.warn_fg_color = default.warn_fg_color;
+                    self.error_fg_color = default.error_fg_color;
+                    return;
+                }
+                (AccentPalette::OkabeIto, true) => (
+                    rgb(0, 114, 178),
+                    rgb(200, 230, 255),
+                    rgb(86, 180, 233),
+                    rgb(240, 228, 66),
+                    rgb(213, 94, 0),
+                ),
+                (AccentPalette::OkabeIto, false) => (
+                    rgb(86, 180, 233),
+                    rgb(0, 60, 100),
+                    rgb(0, 114, 178),
+                    rgb(200, 130, 0),
+                    rgb(213, 94, 0),
+                ),
+                (AccentPalette::Ibm, true) => (
+                    rgb(40, 70, 160),
+                    rgb(220, 228, 255),
+                    rgb(100, 143, 255),
+                    rgb(255, 176, 0),
+                    rgb(220, 38, 127),
+                ),
+                (AccentPalette::Ibm, false) => (
+                    rgb(190, 205, 255),
+                    rgb(30, 50, 130),
+                    rgb(70, 110, 230),
+                    rgb(254, 97, 0),
+                    rgb(220, 38, 127),
+                ),
+            };
+        self.selection.bg_fill = selection_fill;
+        self.selection.stroke.color = selection_text;
+        self.hyperlink_color = hyperlink;
+        self.warn_fg_color = warn;
+        self.error_fg_color = error;
+    }
+}
+
 impl Selection {
+    /// A selection with the given fill and stroke, and no HDR glow.
+    pub fn new(bg_fill: impl Into<Color32>, stroke: impl Into<Stroke>) -> Self {
+        Self {
+            bg_fill: bg_fill.into(),
+            stroke: stroke.into(),
+            intensity: 1.0,
+        }
+    }
+
     fn dark() -> Self {
         Self {
             bg_fill: Color32::from_rgb(0, 92, 128),
             stroke: Stroke::new(1.0, Color32::from_rgb(192, 222, 255)),
+            intensity: 1.0,
         }
     }
 
@@ -1496,8 +2168,14 @@ impl Selection {
         Self {
             bg_fill: Color32::from_rgb(144, 209, 255),
             stroke: Stroke::new(1.0, Color32::from_rgb(0, 83, 125)),
+            intensity: 1.0,
         }
     }
+
+    /// [`Self::bg_fill`] at [`Self::intensity`].
+    pub fn hdr_fill(&self) -> HdrColor {
+        HdrColor::new(self.bg_fill, self.intensity)
+    }
 }
 
 impl Default for Selection {
@@ -1508,6 +2186,14 @@ impl Default for Selection {
 
 impl Widgets {
     pub fn dark() -> Self {
+        let inactive = WidgetVisuals {
+            weak_bg_fill: Color32::from_gray(60), // button background
+            bg_fill: Color32::from_gray(60),      // checkbox background
+            bg_stroke: Default::default(),
+            fg_stroke: Stroke::new(1.0, Color32::from_gray(180)), // button text
+            corner_radius: CornerRadius::same(2),
+            expansion: 0.0,
+        };
         Self {
             noninteractive: WidgetVisuals {
                 weak_bg_fill: Color32::from_gray(27),
@@ -1517,14 +2203,7 @@ impl Widgets {
                 corner_radius: CornerRadius::same(2),
                 expansion: 0.0,
             },
-            inactive: WidgetVisuals {
-                weak_bg_fill: Color32::from_gray(60), // button background
-                bg_fill: Color32::from_gray(60),      // checkbox background
-                bg_stroke: Default::default(),
-                fg_stroke: Stroke::new(1.0, Color32::from_gray(180)), // button text
-                corner_radius: CornerRadius::same(2),
-                expansion: 0.0,
-            },
+            inactive,
             hovered: WidgetVisuals {
                 weak_bg_fill: Color32::from_gray(70),
                 bg_fill: Color32::from_gray(70),
@@ -1541,6 +2220,7 @@ impl Widgets {
                 corner_radius: CornerRadius::same(2),
                 expansion: 1.0,
             },
+            focused: inactive,
             open: WidgetVisuals {
                 weak_bg_fill: Color32::from_gray(45),
                 bg_fill: Color32::from_gray(27),
@@ -1553,6 +2233,14 @@ impl Widgets {
     }
 
     pub fn light() -> Self {
+        let inactive = WidgetVisuals {
+            weak_bg_fill: Color32::from_gray(230), // button background
+            bg_fill: Color32::from_gray(230),      // checkbox background
+            bg_stroke: Default::default(),
+            fg_stroke: Stroke::new(1.0, Color32::from_gray(60)), // button text
+            corner_radius: CornerRadius::same(2),
+            expansion: 0.0,
+        };
         Self {
             noninteractive: WidgetVisuals {
                 weak_bg_fill: Color32::from_gray(248),
@@ -1562,14 +2250,7 @@ impl Widgets {
                 corner_radius: CornerRadius::same(2),
                 expansion: 0.0,
             },
-            inactive: WidgetVisuals {
-                weak_bg_fill: Color32::from_gray(230), // button background
-                bg_fill: Color32::from_gray(230),      // checkbox background
-                bg_stroke: Default::default(),
-                fg_stroke: Stroke::new(1.0, Color32::from_gray(60)), // button text
-                corner_radius: CornerRadius::same(2),
-                expansion: 0.0,
-            },
+            inactive,
             hovered: WidgetVisuals {
                 weak_bg_fill: Color32::from_gray(220),
                 bg_fill: Color32::from_gray(220),
@@ -1586,6 +2267,7 @@ impl Widgets {
                 corner_radius: CornerRadius::same(2),
                 expansion: 1.0,
             },
+            focused: inactive,
             open: WidgetVisuals {
                 weak_bg_fill: Color32::from_gray(220),
                 bg_fill: Color32::from_gray(220),
@@ -1621,12 +2303,15 @@ impl Style {
             text_styles,
             drag_value_text_style,
             number_formatter: _, // can't change callbacks in the UI
+            number_format,
+            display_units,
             wrap: _,
             wrap_mode,
             spacing,
             interaction,
             visuals,
             animation_time,
+            widget_transition,
             #[cfg(debug_assertions)]
             debug,
             explanation_tooltips,
@@ -1703,6 +2388,46 @@ impl Style {
                 });
             ui.end_row();
 
+            ui.label("Number format");
+            crate::ComboBox::from_id_salt("number_format")
+                .selected_text(number_format.localize("1234.5"))
+                .show_ui(ui, |ui| {
+                    for format in [
+                        NumberFormat::PLAIN,
+                        NumberFormat::english(),
+                        NumberFormat::european(),
+                        NumberFormat::si(),
+                    ] {
+                        let format = format.percent(number_format.percent);
+                        ui.selectable_value(number_format, format, format.localize("1234.5"));
+                    }
+                });
+            ui.end_row();
+
+            let DisplayUnits {
+                length,
+                angle,
+                data_size,
+            } = display_units;
+            for (name, quantity, display_unit) in [
+                ("Length unit", Quantity::Length, length),
+                ("Angle unit", Quantity::Angle, angle),
+                ("Data size unit", Quantity::DataSize, data_size),
+            ] {
+                ui.label(name);
+                crate::ComboBox::from_id_salt(name)
+                    .selected_text(display_unit.map_or("As stored", Unit::symbol))
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(display_unit, None, "As stored");
+                        for unit in Unit::ALL {
+                            if unit.quantity() == quantity {
+                                ui.selectable_value(display_unit, Some(unit), unit.symbol());
+                            }
+                        }
+                    });
+                ui.end_row();
+            }
+
             ui.label("Text Wrap Mode");
             crate::ComboBox::from_id_salt("text_wrap_mode")
                 .selected_text(format!("{wrap_mode:?}"))
@@ -1728,6 +2453,10 @@ impl Style {
                     .suffix(" s"),
             );
             ui.end_row();
+
+            ui.label("Widget transitions");
+            widget_transition.ui(ui);
+            ui.end_row();
         });
 
         ui.collapsing("🔠 Text styles", |ui| text_styles_ui(ui, text_styles));
@@ -1904,6 +2633,7 @@ impl Interaction {
             interact_radius,
             resize_grab_radius_side,
             resize_grab_radius_corner,
+            resize_grab_outside,
             show_tooltips_only_when_still,
             tooltip_delay,
             tooltip_grace_time,
@@ -1930,6 +2660,10 @@ impl Interaction {
                 ui.add(DragValue::new(resize_grab_radius_corner).range(0.0..=20.0));
                 ui.end_row();
 
+                ui.label("resize_grab_outside").on_hover_text("How much further the resize grips of a window reach outside of it");
+                ui.add(DragValue::new(resize_grab_outside).range(0.0..=20.0));
+                ui.end_row();
+
                 ui.label("Tooltip delay").on_hover_text(
                     "Delay in seconds before showing tooltips after the mouse stops moving",
                 );
@@ -1976,6 +2710,7 @@ impl Widgets {
             hovered,
             inactive,
             noninteractive,
+            focused,
             open,
         } = self;
 
@@ -1997,6 +2732,10 @@ impl Widgets {
             ui.label("The style of an interactive widget as you are clicking or dragging it.");
             active.ui(ui);
         });
+        ui.collapsing("Interactive and focused", |ui| {
+            ui.label("The style of an interactive widget with keyboard focus.");
+            focused.ui(ui);
+        });
         ui.collapsing("Open menu", |ui| {
             ui.label("The style of an open combo-box or menu button");
             open.ui(ui);
@@ -2008,7 +2747,11 @@ impl Widgets {
 
 impl Selection {
     pub fn ui(&mut self, ui: &mut crate::Ui) {
-        let Self { bg_fill, stroke } = self;
+        let Self {
+            bg_fill,
+            stroke,
+            intensity,
+        } = self;
         ui.label("Selectable labels");
 
         Grid::new("selectiom").num_columns(2).show(ui, |ui| {
@@ -2019,6 +2762,11 @@ impl Selection {
             ui.label("Stroke");
             ui.add(stroke);
             ui.end_row();
+
+            ui.label("HDR intensity")
+                .on_hover_text("Only has an effect if the backend paints into an HDR target");
+            ui.add(DragValue::new(intensity).range(1.0..=16.0).speed(0.05));
+            ui.end_row();
         });
     }
 }
@@ -2071,6 +2819,15 @@ impl WidgetVisuals {
 
 impl Visuals {
     pub fn ui(&mut self, ui: &mut crate::Ui) {
+        ui.horizontal(|ui| {
+            ui.label("Accent palette:");
+            for palette in AccentPalette::ALL {
+                if ui.button(format!("{palette:?}")).clicked() {
+                    self.set_accent_palette(palette);
+                }
+            }
+        });
+
         let Self {
             dark_mode,
             text_alpha_from_coverage,
@@ -2103,10 +2860,13 @@ impl Visuals {
 
             text_cursor,
 
+            focus_ring,
+
             clip_rect_margin,
             button_frame,
             collapsing_header_frame,
             indent_has_left_vline,
+            indent_guide_stroke,
 
             striped,
 
@@ -2232,6 +2992,10 @@ impl Visuals {
             text_cursor.ui(ui);
         });
 
+        ui.collapsing("Focus ring", |ui| {
+            focus_ring.ui(ui);
+        });
+
         ui.collapsing("Window", |ui| {
             Grid::new("window")
                 .num_columns(2)
@@ -2287,6 +3051,15 @@ impl Visuals {
                 indent_has_left_vline,
                 "Paint a vertical line to the left of indented regions",
             );
+            ui.horizontal(|ui| {
+                let mut custom = indent_guide_stroke.is_some();
+                ui.checkbox(&mut custom, "Custom indent line stroke");
+                if custom {
+                    ui.add(indent_guide_stroke.get_or_insert(widgets.noninteractive.bg_stroke));
+                } else {
+                    *indent_guide_stroke = None;
+                }
+            });
 
             ui.checkbox(striped, "Default stripes on grids and tables");
 
@@ -2413,6 +3186,59 @@ impl TextCursorStyle {
     }
 }
 
+impl FocusRing {
+    fn ui(&mut self, ui: &mut Ui) {
+        let Self {
+            stroke,
+            offset,
+            corner_radius,
+            dash_length,
+            dash_speed,
+            keyboard_only,
+        } = self;
+
+        Grid::new("focus_ring").num_columns(2).show(ui, |ui| {
+            ui.label("Stroke");
+            ui.add(stroke);
+            ui.end_row();
+
+            ui.label("Offset");
+            ui.add(DragValue::new(offset).speed(0.1).range(-8.0..=8.0));
+            ui.end_row();
+
+            ui.label("Corner radius");
+            ui.add(corner_radius);
+            ui.end_row();
+
+            ui.label("Dash length");
+            ui.horizontal(|ui| {
+                let mut dashed = dash_length.is_some();
+                ui.checkbox(&mut dashed, "");
+                if dashed {
+                    let length = dash_length.get_or_insert(4.0);
+                    ui.add(DragValue::new(length).speed(0.1).range(1.0..=32.0));
+                } else {
+                    *dash_length = None;
+                }
+            });
+            ui.end_row();
+
+            if dash_length.is_some() {
+                ui.label("Dash speed");
+                ui.add(
+                    DragValue::new(dash_speed)
+                        .speed(1.0)
+                        .range(-200.0..=200.0)
+                        .suffix(" pt/s"),
+                );
+                ui.end_row();
+            }
+        });
+
+        ui.checkbox(keyboard_only, "Only when focused with the keyboard");
+    }
+}
+
 #[cfg(debug_assertions)]
 impl DebugOptions {
     pub fn ui(&mut self, ui: &mut crate::Ui) {
@@ -2426,6 +3252,7 @@ impl DebugOptions {
             show_interactive_widgets,
             show_widget_hits,
             show_unaligned,
+            show_contrast_issues,
         } = self;
 
         {
@@ -2460,6 +3287,8 @@ impl DebugOptions {
             "Show rectangles not aligned to integer point coordinates",
         );
 
+        ui.checkbox(show_contrast_issues, "Show text with too little contrast");
+
         ui.vertical_centered(|ui| reset_button(ui, self, "Reset debug options"));
     }
 }
@@ -144,7 +144,10 @@ pub fn paint_text_cursor(
             total_duration - time_in_cycle
         };
 
-        ui.ctx().request_repaint_after_secs(wake_in);
+        if let Ok(wake_in) = std::time::Duration::try_from_secs_f32(wake_in) {
+            ui.ctx()
+                .request_repaint_after_because(wake_in, crate::RepaintReason::Animation);
+        }
     } else {
         paint_cursor_end(painter, ui.visuals(), primary_cursor_rect);
     }
@@ -0,0 +1,217 @@
+//! A plugin for live-reloading theme files, see [`Theme::from_json`].
+//!
+//! Useful while designing a theme: edit the theme file in your text editor
+//! and see the changes immediately, without rebuilding your app.
+//!
+//! ```no_run
+//! # let ctx = &egui::Context::default();
+//! // Native: reload whenever the file changes on disk.
+//! egui::theme_watcher::watch_file(ctx, "theme.json");
+//!
+//! // Web (or anywhere else): push the contents of the theme file yourself,
+//! // e.g. when it arrives over a websocket.
+//! # let bytes: &[u8] = &[];
+//! egui::theme_watcher::push_bytes(ctx, bytes);
+//! ```
+//!
+//! Errors (e.g. a typo in the theme file) are shown in a small window in the corner of the screen
+//! until the theme file has been fixed, so you don't lose the previous, working theme.
+
+use std::sync::Arc;
+
+use crate::{Align2, Context, Frame, Id, Theme};
+
+/// How often to check the theme file for changes, in seconds.
+#[cfg(not(target_arch = "wasm32"))]
+const POLL_INTERVAL: f64 = 0.5;
+
+/// Reload the style whenever the theme file at the given path changes.
+///
+/// The file is polled for changes every half second,
+/// which means egui will repaint at least that often while watching it.
+///
+/// Calling this again replaces the watched file.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn watch_file(ctx: &Context, path: impl Into<std::path::PathBuf>) {
+    let path = path.into();
+    register(ctx);
+    ctx.data_mut(|data| {
+        let state = data.get_temp_mut_or_default::<State>(Id::NULL);
+        state.watched = Some(WatchedFile {
+            path,
+            modified: None,
+            last_poll: f64::NEG_INFINITY,
+        });
+    });
+}
+
+/// Stop watching the file set with [`watch_file`].
+#[cfg(not(target_arch = "wasm32"))]
+pub fn stop_watching(ctx: &Context) {
+    ctx.data_mut(|data| {
+        let state = data.get_temp_mut_or_default::<State>(Id::NULL);
+        state.watched = None;
+        state.error = None;
+    });
+}
+
+/// Apply the contents of a theme file, e.g. one received over the network.
+///
+/// This is how you hot-reload themes on the web, where there is no file system to watch.
+pub fn push_bytes(ctx: &Context, bytes: &[u8]) {
+    register(ctx);
+    let result = apply(ctx, bytes);
+    ctx.data_mut(|data| {
+        data.get_temp_mut_or_default::<State>(Id::NULL).error = result.err();
+    });
+}
+
+/// The error from the last attempt to load a theme file, if it failed.
+pub fn last_error(ctx: &Context) -> Option<String> {
+    ctx.data(|data| data.get_temp::<State>(Id::NULL))
+        .and_then(|state| state.error)
+}
+
+/// Parse the theme file and set the style of its theme.
+fn apply(ctx: &Context, bytes: &[u8]) -> Result<Theme, String> {
+    let json = std::str::from_utf8(bytes).map_err(|err| format!("Invalid theme file: {err}"))?;
+    let (theme, style) = Theme::from_json(json).map_err(|err| err.to_string())?;
+    ctx.set_style_of(theme, style);
+    Ok(theme)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Clone)]
+struct WatchedFile {
+    path: std::path::PathBuf,
+
+    /// When the file was last modified, when we last loaded it.
+    modified: Option<std::time::SystemTime>,
+
+    /// When we last checked the file, in [`crate::InputState::time`].
+    last_poll: f64,
+}
+
+#[derive(Clone, Default)]
+struct State {
+    registered: bool,
+
+    #[cfg(not(target_arch = "wasm32"))]
+    watched: Option<WatchedFile>,
+
+    error: Option<String>,
+}
+
+/// Register the plugin on the context, unless it already is.
+fn register(ctx: &Context) {
+    let registered = ctx.data_mut(|data| {
+        let state = data.get_temp_mut_or_default::<State>(Id::NULL);
+        std::mem::replace(&mut state.registered, true)
+    });
+    if !registered {
+        #[cfg(not(target_arch = "wasm32"))]
+        ctx.on_begin_pass("theme_watcher", Arc::new(State::begin_pass));
+        ctx.on_end_pass("theme_watcher", Arc::new(State::end_pass));
+    }
+}
+
+impl State {
+    /// Check if the watched file has changed, and if so reload it.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn begin_pass(ctx: &Context) {
+        let Some(mut watched) = ctx
+            .data(|data| data.get_temp::<Self>(Id::NULL))
+            .and_then(|state| state.watched)
+        else {
+            return;
+        };
+
+        let now = ctx.input(|i| i.time);
+        ctx.request_repaint_after_secs(POLL_INTERVAL as f32);
+        if now < watched.last_poll + POLL_INTERVAL {
+            return;
+        }
+        watched.last_poll = now;
+
+        let modified = std::fs::metadata(&watched.path).and_then(|metadata| metadata.modified());
+        let result = match modified {
+            Ok(modified) if Some(modified) == watched.modified => None, // Unchanged
+            Ok(modified) => {
+                watched.modified = Some(modified);
+                Some(
+                    std::fs::read(&watched.path)
+                        .map_err(|err| format!("Failed to read {}: {err}", watched.path.display()))
+                        .and_then(|bytes| apply(ctx, &bytes)),
+                )
+            }
+            Err(err) => {
+                watched.modified = None;
+                Some(Err(format!(
+                    "Failed to read {}: {err}",
+                    watched.path.display()
+                )))
+            }
+        };
+
+        #[cfg(feature = "log")]
+        if let Some(Err(err)) = &result {
+            if last_error(ctx).as_ref() != Some(err) {
+                log::warn!("{err}");
+            }
+        }
+
+        ctx.data_mut(|data| {
+            let state = data.get_temp_mut_or_default::<Self>(Id::NULL);
+            state.watched = Some(watched);
+            if let Some(result) = result {
+                state.error = result.err();
+            }
+        });
+    }
+
+    /// Show the last error, if any.
+    fn end_pass(ctx: &Context) {
+        let Some(error) = last_error(ctx) else {
+            return;
+        };
+
+        crate::Area::new(Id::new("theme_watcher_error"))
+            .anchor(Align2::LEFT_BOTTOM, [8.0, -8.0])
+            .order(crate::Order::Debug)
+            .interactable(false)
+            .show(ctx, |ui| {
+                Frame::popup(ui.style()).show(ui, |ui| {
+                    ui.set_max_width(400.0);
+                    ui.strong("Failed to load theme");
+                    ui.colored_label(ui.visuals().error_fg_color, error);
+                });
+            });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_bytes() {
+        let ctx = Context::default();
+
+        let mut style = Theme::Dark.default_style();
+        style.visuals.panel_fill = crate::Color32::from_rgb(1, 2, 3);
+        push_bytes(&ctx, Theme::Dark.to_json(&style).unwrap().as_bytes());
+        assert_eq!(last_error(&ctx), None);
+        assert_eq!(
+            ctx.style_of(Theme::Dark).visuals.panel_fill,
+            style.visuals.panel_fill
+        );
+
+        // A broken file keeps the previous style:
+        push_bytes(&ctx, b"{ \"version\": 1, ");
+        assert!(last_error(&ctx).is_some());
+        assert_eq!(
+            ctx.style_of(Theme::Dark).visuals.panel_fill,
+            style.visuals.panel_fill
+        );
+    }
+}
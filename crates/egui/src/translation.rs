@@ -0,0 +1,102 @@
+//! Translating the strings that egui itself shows to the user.
+//!
+//! Every built-in user-facing string has a key, e.g. `"color_picker.alpha"`,
+//! and an English default. Install a [`Translator`] with [`crate::Context::set_translator`]
+//! to replace them. Any key the translator doesn't know falls back to English.
+//!
+//! See [`BUILTIN_STRINGS`] for all the keys used by egui.
+//! `egui_extras` uses keys starting with `"egui_extras."`.
+
+/// Translates the built-in strings of egui.
+///
+/// This is implemented for closures, so you can do:
+///
+/// ```
+/// # let ctx = egui::Context::default();
+/// ctx.set_translator(|key: &str| match key {
+///     "file_dialog.cancel" => Some("Abbrechen".to_owned()),
+///     _ => None, // Use English
+/// });
+/// assert_eq!(ctx.tr("file_dialog.cancel", "Cancel"), "Abbrechen");
+/// ```
+pub trait Translator: Send + Sync {
+    /// Translate the string with the given key.
+    ///
+    /// Return `None` to use the English default.
+    fn translate(&self, key: &str) -> Option<String>;
+
+    /// Translate a string that depends on a count, e.g. "1 file" vs "3 files".
+    ///
+    /// The returned string may contain `{count}`, which is replaced with the count.
+    /// Return `None` to use the English default.
+    ///
+    /// The default implementation calls [`Self::translate`] with the key,
+    /// which is fine for languages without plural forms.
+    fn translate_plural(&self, key: &str, count: u64) -> Option<String> {
+        _ = count;
+        self.translate(key)
+    }
+}
+
+impl<F> Translator for F
+where
+    F: Fn(&str) -> Option<String> + Send + Sync,
+{
+    fn translate(&self, key: &str) -> Option<String> {
+        self(key)
+    }
+}
+
+/// The key and English text of every built-in string in egui.
+///
+/// Useful as a starting point for a translation.
+pub const BUILTIN_STRINGS: &[(&str, &str)] = &[
+    ("color_picker.selected_color", "Selected color"),
+    ("color_picker.blending", "Blending:"),
+    ("color_picker.blending_normal", "Normal"),
+    ("color_picker.blending_additive", "Additive"),
+    ("color_picker.hue", "Hue"),
+    ("color_picker.saturation", "Saturation"),
+    ("color_picker.value", "Value"),
+    ("color_picker.alpha", "Alpha"),
+    ("color_picker.copy_values", "Click to copy color values"),
+    ("color_picker.edit", "Click to edit color"),
+    ("window_chrome.close", "Close the window"),
+    ("window_chrome.maximize", "Maximize window"),
+    ("window_chrome.restore", "Restore window"),
+    ("window_chrome.minimize", "Minimize the window"),
+    ("file_dialog.title_open", "Open file"),
+    ("file_dialog.title_save", "Save file"),
+    ("file_dialog.title_pick_folder", "Select folder"),
+    ("file_dialog.open", "Open"),
+    ("file_dialog.save", "Save"),
+    ("file_dialog.select", "Select"),
+    ("file_dialog.cancel", "Cancel"),
+    ("file_dialog.path", "Path:"),
+    ("file_dialog.paths", "Paths, one per line:"),
+    ("file_browser.parent", "Parent directory"),
+    ("file_browser.refresh", "Refresh"),
+    ("file_browser.filter", "Filter"),
+    ("file_browser.all_files", "All files"),
+    ("file_browser.show_hidden", "Show hidden"),
+    ("file_browser.name", "Name"),
+    ("file_browser.size", "Size"),
+    ("file_browser.modified", "Modified"),
+    ("wizard.back", "Back"),
+    ("wizard.next", "Next"),
+    ("wizard.finish", "Finish"),
+    ("menu.loading", "Loading…"),
+    ("combo_box.filter", "Filter…"),
+];
+
+#[test]
+fn builtin_keys_are_unique() {
+    let mut keys: Vec<&str> = BUILTIN_STRINGS.iter().map(|(key, _)| *key).collect();
+    keys.sort_unstable();
+    keys.dedup();
+    assert_eq!(
+        keys.len(),
+        BUILTIN_STRINGS.len(),
+        "Duplicate translation key"
+    );
+}
@@ -12,22 +12,22 @@ use crate::containers::menu;
 use crate::{
     Align, Color32, Context, CursorIcon, DragAndDrop, Id, InnerResponse, InputState, IntoAtoms,
     LayerId, Memory, Order, Painter, PlatformOutput, Pos2, Rangef, Rect, Response, Rgba, RichText,
-    Sense, Style, TextStyle, TextWrapMode, UiBuilder, UiKind, UiStack, UiStackInfo, Vec2,
+    Sense, Style, TextStyle, TextWrapMode, UiBuilder, UiKind, UiStack, UiStackInfo, Vec2, Vec2b,
     WidgetRect, WidgetText,
-    containers::{CollapsingHeader, CollapsingResponse, Frame},
+    containers::{CollapsingHeader, CollapsingResponse, Frame, scroll_area},
     ecolor::Hsva,
     emath, epaint,
     epaint::text::Fonts,
     grid,
     layout::{Direction, Layout},
     pass_state,
-    placer::Placer,
+    placer::{JustifyContentItems, Placer, SizeConstraint},
     pos2, style,
     util::IdTypeMap,
     vec2, widgets,
     widgets::{
         Button, Checkbox, DragValue, Hyperlink, Image, ImageSource, Label, Link, RadioButton,
-        Separator, Spinner, TextEdit, Widget, color_picker,
+        RadioGroup, Separator, Spinner, TextEdit, Widget, color_picker,
     },
 };
 // ----------------------------------------------------------------------------
@@ -135,6 +135,7 @@ impl Ui {
             sizing_pass,
             style,
             sense,
+            transform,
         } = ui_builder;
 
         let layer_id = layer_id.unwrap_or(LayerId::background());
@@ -160,11 +161,15 @@ impl Ui {
             min_rect: placer.min_rect(),
             max_rect: placer.max_rect(),
         };
+        let mut painter = Painter::new(ctx, layer_id, clip_rect);
+        if let Some(transform) = transform {
+            painter.set_sublayer_transform(id, transform);
+        }
         let mut ui = Ui {
             id,
             unique_id: id,
             next_auto_id_salt: id.with("auto").value(),
-            painter: Painter::new(ctx, layer_id, clip_rect),
+            painter,
             style,
             placer,
             enabled: true,
@@ -185,9 +190,15 @@ impl Ui {
                 interact_rect: start_rect,
                 sense,
                 enabled: ui.enabled,
+                hit_test_mode: Default::default(),
+                interact_priority: 0,
+                interact_expand: Default::default(),
             },
             true,
         );
+        ui.ctx().pass_state_mut(|state| {
+            state.widgets.set_ui_kind(ui.unique_id, ui.stack.kind());
+        });
 
         if disabled {
             ui.disable();
@@ -261,6 +272,7 @@ impl Ui {
             sizing_pass,
             style,
             sense,
+            transform,
         } = ui_builder;
 
         let mut painter = self.painter.clone();
@@ -288,14 +300,24 @@ impl Ui {
             }
         }
 
-        debug_assert!(!max_rect.any_nan(), "max_rect is NaN: {max_rect:?}");
+        let max_rect = self.sanitize_max_rect(max_rect, "max_rect");
         let stable_id = self.id.with(id_salt);
         let unique_id = stable_id.with(self.next_auto_id_salt);
         let next_auto_id_salt = unique_id.value().wrapping_add(1);
 
         self.next_auto_id_salt = self.next_auto_id_salt.wrapping_add(1);
 
-        let placer = Placer::new(max_rect, layout);
+        if let Some(transform) = transform {
+            painter.set_sublayer_transform(unique_id, transform);
+        }
+
+        let mut placer = Placer::new(max_rect, layout);
+        if placer.justifies_content() {
+            let items = self.ctx().data(|data| data.get_temp(unique_id));
+            if let Some(items) = items {
+                placer.start_justify_content(items, style.spacing.item_spacing);
+            }
+        }
         let ui_stack = UiStack {
             id: unique_id,
             layout_direction: layout.main_dir,
@@ -333,9 +355,18 @@ impl Ui {
                 interact_rect: start_rect,
                 sense,
                 enabled: child_ui.enabled,
+                hit_test_mode: Default::default(),
+                interact_priority: 0,
+                interact_expand: Default::default(),
             },
             true,
         );
+        child_ui.ctx().pass_state_mut(|state| {
+            state
+                .widgets
+                .set_ui_kind(child_ui.unique_id, child_ui.stack.kind());
+            state.widgets.set_parent(child_ui.unique_id, self.unique_id);
+        });
 
         child_ui
     }
@@ -467,6 +498,79 @@ impl Ui {
         &mut self.style_mut().visuals
     }
 
+    /// The visuals to paint a widget with, given its current [`crate::style::WidgetState`]
+    /// (hovered, active, …).
+    ///
+    /// Like [`Style::interact`], but animates between the states
+    /// according to [`Style::widget_transition`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let (rect, response) = ui.allocate_exact_size(egui::vec2(20.0, 20.0), egui::Sense::click());
+    /// let visuals = ui.interact_visuals(&response);
+    /// ui.painter().rect(
+    ///     rect.expand(visuals.expansion),
+    ///     visuals.corner_radius,
+    ///     visuals.bg_fill,
+    ///     visuals.bg_stroke,
+    ///     egui::StrokeKind::Inside,
+    /// );
+    /// # });
+    /// ```
+    pub fn interact_visuals(&self, response: &Response) -> crate::style::WidgetVisuals {
+        /// Where we are in the transition between two states.
+        #[derive(Clone, Copy)]
+        struct Transition {
+            state: crate::style::WidgetState,
+            from: crate::style::WidgetVisuals,
+            current: crate::style::WidgetVisuals,
+            start_time: f64,
+        }
+
+        let state = crate::style::WidgetState::from_response(response);
+        let target = *self.style.visuals.widgets.get(state);
+        let crate::style::WidgetTransition { duration, easing } = self.style.widget_transition;
+        if duration <= 0.0 {
+            return target;
+        }
+
+        let id = response.id.with("interact_visuals");
+        let now = self.input(|i| i.time);
+        let mut transition = self
+            .data(|d| d.get_temp::<Transition>(id))
+            .unwrap_or(Transition {
+                state,
+                from: target,
+                current: target,
+                start_time: f64::NEG_INFINITY,
+            });
+
+        if transition.state != state {
+            transition.state = state;
+            transition.from = transition.current;
+            transition.start_time = now;
+        }
+
+        let t = ((now - transition.start_time) / duration as f64).clamp(0.0, 1.0) as f32;
+        transition.current = transition.from.lerp(&target, easing.apply(t));
+        if t < 1.0 {
+            self.ctx().request_repaint();
+        }
+
+        self.data_mut(|d| d.insert_temp(id, transition));
+        transition.current
+    }
+
+    /// Like [`Self::interact_visuals`], but uses the [`crate::Visuals::selection`] colors if `selected`.
+    pub fn interact_selectable_visuals(
+        &self,
+        response: &Response,
+        selected: bool,
+    ) -> crate::style::WidgetVisuals {
+        self.style
+            .selectable_visuals(self.interact_visuals(response), selected)
+    }
+
     /// Get a reference to this [`Ui`]'s [`UiStack`].
     #[inline]
     pub fn stack(&self) -> &Arc<UiStack> {
@@ -895,12 +999,14 @@ impl Ui {
     /// Set the maximum width of the ui.
     /// You won't be able to shrink it below the current minimum size.
     pub fn set_max_width(&mut self, width: f32) {
+        let width = self.sanitize_length(width, f32::INFINITY, "max width");
         self.placer.set_max_width(width);
     }
 
     /// Set the maximum height of the ui.
     /// You won't be able to shrink it below the current minimum size.
     pub fn set_max_height(&mut self, height: f32) {
+        let height = self.sanitize_length(height, f32::INFINITY, "max height");
         self.placer.set_max_height(height);
     }
 
@@ -916,6 +1022,7 @@ impl Ui {
     /// Set the minimum width of the ui.
     /// This can't shrink the ui, only make it larger.
     pub fn set_min_width(&mut self, width: f32) {
+        let width = self.sanitize_length(width, 0.0, "min width");
         debug_assert!(
             0.0 <= width,
             "Negative width makes no sense, but got: {width}"
@@ -926,6 +1033,7 @@ impl Ui {
     /// Set the minimum height of the ui.
     /// This can't shrink the ui, only make it larger.
     pub fn set_min_height(&mut self, height: f32) {
+        let height = self.sanitize_length(height, 0.0, "min height");
         debug_assert!(
             0.0 <= height,
             "Negative height makes no sense, but got: {height}"
@@ -950,6 +1058,7 @@ impl Ui {
 
     /// Expand the `min_rect` and `max_rect` of this ui to include a child at the given rect.
     pub fn expand_to_include_rect(&mut self, rect: Rect) {
+        let rect = self.sanitize_rect(rect, "expand_to_include_rect");
         self.placer.expand_to_include_rect(rect);
     }
 
@@ -982,12 +1091,14 @@ impl Ui {
     /// Ensure we are big enough to contain the given x-coordinate.
     /// This is sometimes useful to expand a ui to stretch to a certain place.
     pub fn expand_to_include_x(&mut self, x: f32) {
+        let x = self.sanitize_length(x, self.min_rect().right(), "expand_to_include_x");
         self.placer.expand_to_include_x(x);
     }
 
     /// Ensure we are big enough to contain the given y-coordinate.
     /// This is sometimes useful to expand a ui to stretch to a certain place.
     pub fn expand_to_include_y(&mut self, y: f32) {
+        let y = self.sanitize_length(y, self.min_rect().bottom(), "expand_to_include_y");
         self.placer.expand_to_include_y(y);
     }
 
@@ -1066,14 +1177,20 @@ impl Ui {
 impl Ui {
     /// Check for clicks, drags and/or hover on a specific region of this [`Ui`].
     pub fn interact(&self, rect: Rect, id: impl Into<Id>, sense: Sense) -> Response {
+        let id = id.into();
+        self.ctx()
+            .pass_state_mut(|state| state.widgets.set_parent(id, self.unique_id));
         self.ctx().create_widget(
             WidgetRect {
-                id: id.into(),
+                id,
                 layer_id: self.layer_id(),
                 rect,
                 interact_rect: self.clip_rect().intersect(rect),
                 sense,
                 enabled: self.enabled,
+                hit_test_mode: Default::default(),
+                interact_priority: 0,
+                interact_expand: Default::default(),
             },
             true,
         )
@@ -1142,6 +1259,9 @@ impl Ui {
                 interact_rect: self.clip_rect().intersect(self.min_rect()),
                 sense: self.sense,
                 enabled: self.enabled,
+                hit_test_mode: Default::default(),
+                interact_priority: 0,
+                interact_expand: Default::default(),
             },
             false,
         );
@@ -1151,6 +1271,42 @@ impl Ui {
         response
     }
 
+    /// Sense interaction with the background of this [`Ui`], behind all its child widgets.
+    ///
+    /// The child widgets always win over the background,
+    /// no matter if they were added before or after calling this:
+    /// clicking a button in the [`Ui`] clicks only the button.
+    /// Clicks anywhere else (including secondary clicks, for [`Response::context_menu`])
+    /// go to the background.
+    ///
+    /// Unlike other interactive widgets, the background is hovered
+    /// while any of its children are hovered.
+    ///
+    /// Like with [`UiBuilder::sense`], the [`Response`] is based on the [`Self::min_rect`] of the last pass.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let row = ui.horizontal(|ui| {
+    ///     let background = ui.interact_bg_behind_children(egui::Sense::click());
+    ///     ui.label("Some row");
+    ///     if ui.button("Delete").clicked() {
+    ///         // Only the button was clicked
+    ///     }
+    ///     background
+    /// });
+    /// if row.inner.clicked() {
+    ///     // The row was clicked outside the button
+    /// }
+    /// # });
+    /// ```
+    pub fn interact_bg_behind_children(&mut self, sense: Sense) -> Response {
+        self.sense |= sense;
+        let id = self.unique_id;
+        self.ctx()
+            .pass_state_mut(|state| state.widgets.set_behind_children(id));
+        self.response()
+    }
+
     /// Interact with the background of this [`Ui`],
     /// i.e. behind all the widgets.
     ///
@@ -1305,6 +1461,7 @@ impl Ui {
     /// # });
     /// ```
     pub fn allocate_response(&mut self, desired_size: Vec2, sense: Sense) -> Response {
+        let desired_size = self.sanitize_size(desired_size, "desired_size");
         let (id, rect) = self.allocate_space(desired_size);
         let mut response = self.interact(rect, id, sense);
         response.intrinsic_size = Some(desired_size);
@@ -1317,6 +1474,7 @@ impl Ui {
     /// This means that if this is a narrow widget in a wide justified layout, then
     /// the widget will react to interactions outside the returned [`Rect`].
     pub fn allocate_exact_size(&mut self, desired_size: Vec2, sense: Sense) -> (Rect, Response) {
+        let desired_size = self.sanitize_size(desired_size, "desired_size");
         let response = self.allocate_response(desired_size, sense);
         let rect = self
             .placer
@@ -1354,6 +1512,8 @@ impl Ui {
     /// # });
     /// ```
     pub fn allocate_space(&mut self, desired_size: Vec2) -> (Id, Rect) {
+        let desired_size = self.sanitize_size(desired_size, "desired_size");
+
         #[cfg(debug_assertions)]
         let original_available = self.available_size_before_wrap();
 
@@ -1405,9 +1565,9 @@ impl Ui {
     /// Reserve this much space and move the cursor.
     /// Returns where to put the widget.
     fn allocate_space_impl(&mut self, desired_size: Vec2) -> Rect {
+        let desired_size = self.placer.constrain_size(desired_size);
         let item_spacing = self.spacing().item_spacing;
         let frame_rect = self.placer.next_space(desired_size, item_spacing);
-        debug_assert!(!frame_rect.any_nan(), "frame_rect is nan in allocate_space");
         let widget_rect = self.placer.justify_and_align(frame_rect, desired_size);
 
         self.placer
@@ -1430,8 +1590,7 @@ impl Ui {
 
     /// Allocate a rect without interacting with it.
     pub fn advance_cursor_after_rect(&mut self, rect: Rect) -> Id {
-        debug_assert!(!rect.any_nan(), "rect is nan in advance_cursor_after_rect");
-        let rect = rect.round_ui();
+        let rect = self.sanitize_rect(rect, "rect").round_ui();
 
         let item_spacing = self.spacing().item_spacing;
         self.placer.advance_after_rects(rect, rect, item_spacing);
@@ -1442,6 +1601,89 @@ impl Ui {
         id
     }
 
+    /// Replace any NaN or infinity in a size given to the layout with zero.
+    ///
+    /// A single NaN would otherwise spread to the cursor and every widget after it.
+    fn sanitize_size(&self, size: Vec2, what: &str) -> Vec2 {
+        if size.is_finite() {
+            return size;
+        }
+        let sanitized = vec2(finite_or(size.x, 0.0), finite_or(size.y, 0.0));
+        self.report_nan_layout(what, size, sanitized);
+        sanitized
+    }
+
+    /// Like [`Self::sanitize_size`], but positive infinity is left alone,
+    /// since it means "as much as possible".
+    fn sanitize_max_size(&self, size: Vec2, what: &str) -> Vec2 {
+        let sanitized = vec2(max_or(size.x, 0.0), max_or(size.y, 0.0));
+        if sanitized != size {
+            self.report_nan_layout(what, size, sanitized);
+        }
+        sanitized
+    }
+
+    /// Replace any NaN or infinite coordinate of a rect given to the layout.
+    ///
+    /// A bad `min` is replaced with where the next widget would go,
+    /// and a bad `max` with `min`.
+    fn sanitize_rect(&self, rect: Rect, what: &str) -> Rect {
+        if rect.is_finite() {
+            return rect;
+        }
+        let min = self.sanitize_min(rect.min);
+        let max = pos2(finite_or(rect.max.x, min.x), finite_or(rect.max.y, min.y));
+        let sanitized = Rect::from_min_max(min, max);
+        self.report_nan_layout(what, rect, sanitized);
+        sanitized
+    }
+
+    /// Like [`Self::sanitize_rect`], but a `max` of positive infinity is left alone,
+    /// since it means "as much as possible".
+    fn sanitize_max_rect(&self, rect: Rect, what: &str) -> Rect {
+        let min = if rect.min.is_finite() {
+            rect.min
+        } else {
+            self.sanitize_min(rect.min)
+        };
+        let max = pos2(max_or(rect.max.x, min.x), max_or(rect.max.y, min.y));
+        let sanitized = Rect::from_min_max(min, max);
+        if sanitized != rect {
+            self.report_nan_layout(what, rect, sanitized);
+        }
+        sanitized
+    }
+
+    fn sanitize_min(&self, min: Pos2) -> Pos2 {
+        let fallback = self.next_widget_position();
+        pos2(finite_or(min.x, fallback.x), finite_or(min.y, fallback.y))
+    }
+
+    /// Replace NaN and infinities with `fallback`,
+    /// unless it is the fallback itself (e.g. an infinite max width).
+    fn sanitize_length(&self, length: f32, fallback: f32, what: &str) -> f32 {
+        if length.is_finite() || length == fallback {
+            return length;
+        }
+        self.report_nan_layout(what, length, fallback);
+        fallback
+    }
+
+    fn report_nan_layout(
+        &self,
+        what: &str,
+        value: impl std::fmt::Debug,
+        sanitized: impl std::fmt::Debug,
+    ) {
+        self.ctx().report_warning(crate::Warning::new(
+            crate::WarningKind::NanLayout,
+            format!(
+                "{what} was NaN or infinite in {:?}: {value:?}, using {sanitized:?} instead",
+                self.id
+            ),
+        ));
+    }
+
     pub(crate) fn placer(&self) -> &Placer {
         &self.placer
     }
@@ -1502,6 +1744,7 @@ impl Ui {
         layout: Layout,
         add_contents: Box<dyn FnOnce(&mut Self) -> R + 'c>,
     ) -> InnerResponse<R> {
+        let desired_size = self.sanitize_max_size(desired_size, "desired_size");
         debug_assert!(
             desired_size.x >= 0.0 && desired_size.y >= 0.0,
             "Negative desired size: {desired_size:?}"
@@ -1543,6 +1786,24 @@ impl Ui {
         self.scope_dyn(ui_builder, Box::new(add_contents))
     }
 
+    /// Allocate all the space that is left, and return a child [`Ui`] that exactly fills it.
+    ///
+    /// The child has the same layout as this [`Ui`].
+    /// Nothing more can be added after this, except on top of the child.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.label("Header");
+    /// let mut rest = ui.allocate_remaining_space();
+    /// rest.centered_and_justified(|ui| ui.label("Fills the rest"));
+    /// # });
+    /// ```
+    pub fn allocate_remaining_space(&mut self) -> Self {
+        let rect = self.available_rect_before_wrap();
+        self.advance_cursor_after_rect(rect);
+        self.new_child(UiBuilder::new().max_rect(rect).layout(*self.layout()))
+    }
+
     /// Convenience function to get a region to paint on.
     ///
     /// Note that egui uses screen coordinates for everything.
@@ -1597,6 +1858,78 @@ impl Ui {
         self.scroll_to_rect_animation(rect, align, self.style.scroll_animation);
     }
 
+    /// Register a named place that [`Context::navigate_to_anchor`] can scroll to, e.g. a section heading.
+    ///
+    /// The anchor is at the cursor (where the next widget goes).
+    /// See also [`Context::anchors`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// if ui.link("Go to the network settings").clicked() {
+    ///     ui.ctx().navigate_to_anchor("settings/network", true);
+    /// }
+    /// egui::ScrollArea::vertical().show(ui, |ui| {
+    ///     // …
+    ///     ui.anchor("settings/network");
+    ///     ui.heading("Network");
+    /// });
+    /// # });
+    /// ```
+    pub fn anchor(&self, name: impl Into<String>) {
+        /// How long the highlight flashes after navigating to an anchor.
+        const HIGHLIGHT_DURATION: f64 = 1.0;
+
+        let name = name.into();
+        let rect = Rect::from_min_size(
+            self.cursor().min,
+            vec2(self.available_width(), self.spacing().interact_size.y),
+        );
+        let now = self.input(|i| i.time);
+
+        let navigation = self.ctx().memory_mut(|mem| {
+            let navigation = mem.anchor_navigation.as_mut()?;
+            if navigation.name != name {
+                return None;
+            }
+            let arrived_now = navigation.arrived_at.is_none();
+            let arrived_at = *navigation.arrived_at.get_or_insert(now);
+            let highlight = navigation.highlight;
+            if !highlight || HIGHLIGHT_DURATION <= now - arrived_at {
+                mem.anchor_navigation = None;
+            }
+            Some((arrived_now, highlight, arrived_at))
+        });
+
+        if let Some((arrived_now, highlight, arrived_at)) = navigation {
+            if arrived_now {
+                let animation = self.style.scroll_animation;
+                self.ctx().pass_state_mut(|state| {
+                    for d in 0..2 {
+                        let range = Rangef::new(rect.min[d], rect.max[d]);
+                        state.scroll_target[d] = Some(
+                            pass_state::ScrollTarget::new(range, Some(Align::Min), animation)
+                                .with_parents(),
+                        );
+                    }
+                });
+            }
+
+            let t = ((now - arrived_at) / HIGHLIGHT_DURATION) as f32;
+            if highlight && t < 1.0 {
+                let color = self.visuals().selection.bg_fill.gamma_multiply(1.0 - t);
+                self.painter().rect_filled(
+                    rect,
+                    self.visuals().widgets.noninteractive.corner_radius,
+                    color,
+                );
+                self.ctx().request_repaint();
+            }
+        }
+
+        self.ctx()
+            .pass_state_mut(|state| state.anchors.push((name, rect)));
+    }
+
     /// Same as [`Self::scroll_to_rect`], but allows you to specify the [`style::ScrollAnimation`].
     pub fn scroll_to_rect_animation(
         &self,
@@ -1742,6 +2075,41 @@ impl Ui {
             .inner
     }
 
+    /// Add a [`Widget`] to this [`Ui`], with a minimum and maximum size.
+    ///
+    /// The widget gets the size it wants, but at least `min_size` and at most `max_size`.
+    /// Text in the widget wraps at the maximum width.
+    /// Unlike [`Self::add_sized`], the widget is not stretched to fill the layout,
+    /// so a button can grow with its text, up to a point.
+    ///
+    /// Use [`f32::INFINITY`] for no maximum.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.add_sized_range([80.0, 0.0], [200.0, f32::INFINITY], egui::Button::new("OK"));
+    /// # });
+    /// ```
+    pub fn add_sized_range(
+        &mut self,
+        min_size: impl Into<Vec2>,
+        max_size: impl Into<Vec2>,
+        widget: impl Widget,
+    ) -> Response {
+        let (min_size, max_size) = (min_size.into(), max_size.into());
+        let frame_size = max_size
+            .min(self.available_size_before_wrap())
+            .max(min_size);
+        let layout = Layout::from_main_dir_and_cross_align(self.layout().main_dir(), Align::Min);
+        self.allocate_ui_with_layout(frame_size, layout, |ui| {
+            ui.placer.set_size_constraint(SizeConstraint {
+                min: min_size,
+                max: max_size,
+            });
+            ui.add(widget)
+        })
+        .inner
+    }
+
     /// Add a [`Widget`] to this [`Ui`] at a specific location (manual layout).
     ///
     /// See also [`Self::add`] and [`Self::add_sized`].
@@ -2134,6 +2502,34 @@ impl Ui {
         response
     }
 
+    /// Show a set of [`RadioButton`]s that act as one widget.
+    ///
+    /// Unlike a series of [`Self::radio_value`], the group is a single tab stop,
+    /// and the arrow keys move the selection between the options.
+    ///
+    /// The returned [`Response`] is marked changed when the value changes.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// #[derive(PartialEq)]
+    /// enum Enum { First, Second, Third }
+    /// let mut my_enum = Enum::First;
+    ///
+    /// ui.radio_group(&mut my_enum, |group| {
+    ///     group.option(Enum::First, "First");
+    ///     group.option(Enum::Second, "Second");
+    ///     group.option(Enum::Third, "Third");
+    /// });
+    /// # });
+    /// ```
+    pub fn radio_group<Value: PartialEq>(
+        &mut self,
+        current_value: &mut Value,
+        add_options: impl FnOnce(&mut RadioGroup<'_, Value>),
+    ) -> Response {
+        RadioGroup::show(self, current_value, add_options)
+    }
+
     /// Show a label which can be selected or not.
     ///
     /// See also [`Button::selectable`] and [`Self::toggle_value`].
@@ -2178,6 +2574,14 @@ impl Ui {
         Spinner::new().ui(self)
     }
 
+    /// Show an editor for any value implementing [`crate::reflect::UiInspect`],
+    /// e.g. a whole settings struct.
+    ///
+    /// The returned [`Response`] is marked changed when the value changes.
+    pub fn inspect(&mut self, value: &mut (impl crate::reflect::UiInspect + ?Sized)) -> Response {
+        value.ui_inspect(self)
+    }
+
     /// Modify an angle. The given angle should be in radians, but is shown to the user in degrees.
     /// The angle is NOT wrapped, so the user may select, for instance 720° = 2𝞃 = 4π
     pub fn drag_angle(&mut self, radians: &mut f32) -> Response {
@@ -2373,6 +2777,9 @@ impl Ui {
     /// }
     /// # });
     /// ```
+    ///
+    /// For lists that can be reordered, filtered or have items inserted,
+    /// use [`Self::push_stable_id`] with a key of the item instead of its index.
     pub fn push_id<R>(
         &mut self,
         id_salt: impl Hash,
@@ -2381,6 +2788,52 @@ impl Ui {
         self.scope_dyn(UiBuilder::new().id_salt(id_salt), Box::new(add_contents))
     }
 
+    /// Create a child Ui for an item in a collection, identified by a key unique to that item.
+    ///
+    /// Use this instead of [`Self::push_id`] with the index of the item
+    /// for lists that can be reordered, filtered or have items inserted.
+    /// Widget state (scroll position, collapsing headers, text cursors, …)
+    /// then follows the item instead of staying at the same row.
+    ///
+    /// Unlike with [`Self::push_id`], the automatic [`Id`]s of the widgets in the child
+    /// (e.g. of a [`crate::TextEdit`] without an explicit id)
+    /// only depend on the key, and not on what came before it in the parent.
+    ///
+    /// Using the same key twice in the same [`Ui`] is reported when
+    /// [`crate::Options::warn_on_id_clash`] is on.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// struct Item { uuid: u64, name: String }
+    /// let mut items = vec![Item { uuid: 7, name: "Seven".to_owned() }];
+    /// for item in &mut items {
+    ///     ui.push_stable_id(item.uuid, |ui| {
+    ///         ui.collapsing("Details", |ui| {
+    ///             ui.text_edit_singleline(&mut item.name);
+    ///         });
+    ///     });
+    /// }
+    /// # });
+    /// ```
+    pub fn push_stable_id<R>(
+        &mut self,
+        key: impl Hash,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        let mut stable_id = Id::NULL;
+        let inner_response = self.scope_dyn(
+            UiBuilder::new().id_salt(key),
+            Box::new(|ui: &mut Ui| {
+                stable_id = ui.id;
+                ui.next_auto_id_salt = stable_id.with("auto").value();
+                add_contents(ui)
+            }),
+        );
+        self.ctx()
+            .check_for_id_clash(stable_id, inner_response.response.rect, "item key");
+        inner_response
+    }
+
     /// Push another level onto the [`UiStack`].
     ///
     /// You can use this, for instance, to tag a group of widgets.
@@ -2396,6 +2849,58 @@ impl Ui {
         )
     }
 
+    /// Add some widgets, and get a single [`Response`] for all of them.
+    ///
+    /// The response covers all the widgets added in `add_contents`,
+    /// and is hovered, clicked, dragged, changed, … if any of them is.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut enabled = true;
+    /// let response = ui.capture(|ui| {
+    ///     ui.horizontal(|ui| {
+    ///         ui.checkbox(&mut enabled, "Enabled");
+    ///         ui.label("Some row");
+    ///         if ui.button("Delete").clicked() { /* … */ }
+    ///     });
+    /// });
+    /// if response.clicked() {
+    ///     // Something in the row was clicked
+    /// }
+    /// # });
+    /// ```
+    ///
+    /// Only the widgets in the same layer as this [`Ui`] are included,
+    /// so e.g. the contents of a popup are not.
+    pub fn capture(&mut self, add_contents: impl FnOnce(&mut Ui)) -> Response {
+        let layer_id = self.layer_id();
+        let num_widgets_before = self
+            .ctx()
+            .pass_state(|state| state.widgets.get_layer(layer_id).count());
+
+        let mut response = self.scope(add_contents).response;
+
+        let (widgets, any_changed) = self.ctx().pass_state(|state| {
+            let widgets: Vec<WidgetRect> = state
+                .widgets
+                .get_layer(layer_id)
+                .skip(num_widgets_before)
+                .copied()
+                .collect();
+            let any_changed = widgets
+                .iter()
+                .any(|widget| state.changed_widgets.contains(&widget.id));
+            (widgets, any_changed)
+        });
+        for widget in widgets {
+            response |= self.ctx().get_response(widget);
+        }
+        if any_changed {
+            response.mark_changed();
+        }
+        response
+    }
+
     /// Create a scoped child ui.
     ///
     /// You can use this to temporarily change the [`Style`] of a sub-region, for instance:
@@ -2436,6 +2941,271 @@ impl Ui {
         InnerResponse::new(ret, response)
     }
 
+    /// Add static contents, which are only re-run when something changes.
+    ///
+    /// When `cache_key` is the same as in the previous pass, and nothing inside
+    /// is hovered, focused or dragged, the shapes and widgets from the previous pass are
+    /// replayed instead of calling `add_contents`.
+    /// The contents are also re-run if the available space, style, or scale changes,
+    /// or if the font atlas was recreated (see [`epaint::text::Fonts::atlas_generation`]).
+    ///
+    /// Use this for large regions that rarely change, like help texts or about boxes.
+    /// Change `cache_key` whenever the contents should change.
+    ///
+    /// Only what is painted on the layer of this [`Ui`] is recorded,
+    /// and animations in the contents are frozen while replaying.
+    /// The AccessKit nodes and the widget info shown by [`crate::style::DebugOptions::show_interactive_widgets`]
+    /// can't be replayed, so the contents are run every pass while either of those is on.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let help_text = "A lot of text…";
+    /// ui.cached("help", help_text, |ui| {
+    ///     ui.heading("Help");
+    ///     ui.label(help_text);
+    /// });
+    /// # });
+    /// ```
+    pub fn cached(
+        &mut self,
+        id_salt: impl Hash,
+        cache_key: impl Hash,
+        add_contents: impl FnOnce(&mut Ui),
+    ) -> Response {
+        let cache_key = Id::new(cache_key).value();
+        self.scope_builder(UiBuilder::new().id_salt(id_salt), |ui| {
+            #[cfg(feature = "accesskit")]
+            let accesskit = ui
+                .ctx()
+                .viewport(|viewport| viewport.this_pass.accesskit_state.is_some());
+            #[cfg(not(feature = "accesskit"))]
+            let accesskit = false;
+            if accesskit || ui.style().debug.show_interactive_widgets {
+                add_contents(ui);
+                return;
+            }
+
+            let id = ui.id();
+            let layer_id = ui.layer_id();
+            let pixels_per_point = ui.ctx().pixels_per_point();
+            let font_atlas_generation = ui.fonts(|fonts| fonts.atlas_generation());
+
+            let cached = ui
+                .data(|data| data.get_temp::<CachedContents>(id))
+                .filter(|cached| {
+                    cached.cache_key == cache_key
+                        && cached.layer_id == layer_id
+                        && cached.max_rect == ui.max_rect()
+                        && cached.clip_rect == ui.clip_rect()
+                        && cached.enabled == ui.is_enabled()
+                        && Arc::ptr_eq(&cached.style, ui.style())
+                        && cached.pixels_per_point == pixels_per_point
+                        && cached.font_atlas_generation == font_atlas_generation
+                        && !cached.is_interacted_with(ui)
+                });
+
+            if let Some(cached) = cached {
+                ui.ctx().graphics_mut(|graphics| {
+                    graphics
+                        .entry(layer_id)
+                        .extend_clipped(cached.shapes.iter().cloned());
+                });
+                for widget_rect in cached.widgets.iter() {
+                    ui.ctx().create_widget(*widget_rect, true);
+                }
+                ui.expand_to_include_rect(cached.min_rect);
+                return;
+            }
+
+            let first_shape = ui
+                .ctx()
+                .graphics_mut(|graphics| graphics.entry(layer_id).next_idx());
+            let first_widget = ui
+                .ctx()
+                .viewport(|viewport| viewport.this_pass.widgets.get_layer(layer_id).count());
+
+            add_contents(ui);
+
+            let shapes = ui.ctx().graphics_mut(|graphics| {
+                let paint_list = graphics.entry(layer_id);
+                paint_list
+                    .entries_in_range(first_shape, paint_list.next_idx())
+                    .into()
+            });
+            let widgets = ui.ctx().viewport(|viewport| {
+                viewport
+                    .this_pass
+                    .widgets
+                    .get_layer(layer_id)
+                    .skip(first_widget)
+                    .copied()
+                    .collect()
+            });
+            let cached = CachedContents {
+                cache_key,
+                layer_id,
+                max_rect: ui.max_rect(),
+                clip_rect: ui.clip_rect(),
+                enabled: ui.is_enabled(),
+                style: ui.style().clone(),
+                pixels_per_point,
+                font_atlas_generation,
+                min_rect: ui.min_rect(),
+                shapes,
+                widgets,
+            };
+            ui.data_mut(|data| data.insert_temp(id, cached));
+        })
+        .response
+    }
+
+    /// Add contents that only change when interacted with.
+    ///
+    /// The contents are still run every pass, but while nothing in them is hovered, focused or dragged,
+    /// the region is reported in [`crate::FullOutput::unchanged_rects`],
+    /// so that a backend can skip repainting it.
+    /// Use [`Context::invalidate_static_region`] with the [`Response::id`]
+    /// when the contents change for some other reason.
+    ///
+    /// We can't tell if what is painted beneath the region changes,
+    /// so the contents must start with an opaque background covering the whole region,
+    /// e.g. a [`crate::Frame`] with an opaque fill and no rounding.
+    /// Otherwise the region is never reported as unchanged.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let response = ui.static_region("dashboard_tile", |ui| {
+    ///     egui::Frame::new()
+    ///         .fill(ui.visuals().panel_fill)
+    ///         .show(ui, |ui| ui.label("Uptime: 99.9%"));
+    /// });
+    /// # let uptime_changed = false;
+    /// if uptime_changed {
+    ///     ui.ctx().invalidate_static_region(response.response.id);
+    /// }
+    /// # });
+    /// ```
+    pub fn static_region<R>(
+        &mut self,
+        id_salt: impl Hash,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        self.scope_builder(UiBuilder::new().id_salt(id_salt), |ui| {
+            let layer_id = ui.layer_id();
+            let first_shape = ui
+                .ctx()
+                .graphics_mut(|graphics| graphics.entry(layer_id).next_idx());
+            let first_widget = ui
+                .ctx()
+                .viewport(|viewport| viewport.this_pass.widgets.get_layer(layer_id).count());
+
+            let ret = add_contents(ui);
+
+            let last_shape = ui
+                .ctx()
+                .graphics_mut(|graphics| graphics.entry(layer_id).next_idx());
+            let widgets: Vec<WidgetRect> = ui.ctx().viewport(|viewport| {
+                viewport
+                    .this_pass
+                    .widgets
+                    .get_layer(layer_id)
+                    .skip(first_widget)
+                    .copied()
+                    .collect()
+            });
+            let rect = ui.min_rect().intersect(ui.clip_rect());
+            let opaque = ui.ctx().graphics_mut(|graphics| {
+                graphics
+                    .entry(layer_id)
+                    .entries_in_range(first_shape, last_shape)
+                    .iter()
+                    .any(|clipped| {
+                        clipped.clip_rect.contains_rect(rect)
+                            && covers_opaquely(&clipped.shape, rect)
+                    })
+            });
+            let region = pass_state::StaticRegion {
+                id: ui.unique_id(),
+                layer_id,
+                rect,
+                opaque,
+                shapes: (first_shape, last_shape),
+                interacted: is_interacted_with(ui, ui.min_rect(), &widgets),
+                font_atlas_generation: ui.fonts(|fonts| fonts.atlas_generation()),
+            };
+            ui.ctx()
+                .pass_state_mut(|state| state.static_regions.push(region));
+            ret
+        })
+    }
+
+    /// Has the current pass taken longer than [`crate::Options::frame_budget`]?
+    ///
+    /// See [`Context::frame_budget_exceeded`].
+    pub fn frame_budget_exceeded(&self) -> bool {
+        self.ctx().frame_budget_exceeded()
+    }
+
+    /// Add expensive contents (plots, big tables, …), unless the pass is over its [`crate::Options::frame_budget`].
+    ///
+    /// When over budget, a spinner the size of the contents from last time is shown instead,
+    /// and a repaint is requested so the contents are added in the next pass.
+    /// The contents are never deferred two passes in a row.
+    ///
+    /// Returns `None` if the contents were deferred.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.defer_if_over_budget("plot", |ui| {
+    ///     ui.label("Something expensive");
+    /// });
+    /// # });
+    /// ```
+    pub fn defer_if_over_budget<R>(
+        &mut self,
+        id_salt: impl Hash,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<Option<R>> {
+        self.scope_builder(UiBuilder::new().id_salt(id_salt), |ui| {
+            let id = ui.unique_id();
+            let state: DeferState = ui.data(|data| data.get_temp(id)).unwrap_or_default();
+
+            if !state.deferred && ui.frame_budget_exceeded() {
+                let size = state.size.max(ui.spacing().interact_size);
+                let (rect, _) = ui.allocate_exact_size(size, Sense::hover());
+                let spinner_size = ui.spacing().interact_size.y.min(size.min_elem());
+                Spinner::new().size(spinner_size).paint_at(
+                    ui,
+                    Rect::from_center_size(rect.center(), Vec2::splat(spinner_size)),
+                );
+                ui.ctx().request_repaint();
+                ui.data_mut(|data| {
+                    data.insert_temp(
+                        id,
+                        DeferState {
+                            deferred: true,
+                            ..state
+                        },
+                    );
+                });
+                None
+            } else {
+                let ret = add_contents(ui);
+                let size = ui.min_rect().size();
+                ui.data_mut(|data| {
+                    data.insert_temp(
+                        id,
+                        DeferState {
+                            size,
+                            deferred: false,
+                        },
+                    );
+                });
+                Some(ret)
+            }
+        })
+    }
+
     /// Redirect shapes to another paint layer.
     ///
     /// ```
@@ -2507,7 +3277,10 @@ impl Ui {
                 child_ui.add_space(4.0);
             }
 
-            let stroke = self.visuals().widgets.noninteractive.bg_stroke;
+            let visuals = self.visuals();
+            let stroke = visuals
+                .indent_guide_stroke
+                .unwrap_or(visuals.widgets.noninteractive.bg_stroke);
             let left_top = child_rect.min - 0.5 * indent * Vec2::X;
             let left_bottom = pos2(left_top.x, child_ui.min_rect().bottom() - 2.0);
 
@@ -2717,6 +3490,37 @@ impl Ui {
         self.scope_builder(UiBuilder::new().layout(layout), add_contents)
     }
 
+    /// Add contents from the bottom up, in all the space that is left.
+    ///
+    /// Use this to pin a footer to the bottom, with the content above it:
+    /// add the footer first, then the content in a top-down layout.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.with_bottom_up(|ui| {
+    ///     ui.label("Footer");
+    ///     ui.separator();
+    ///     ui.with_layout(egui::Layout::top_down(egui::Align::Min), |ui| {
+    ///         ui.label("Content");
+    ///     });
+    /// });
+    /// # });
+    /// ```
+    pub fn with_bottom_up<R>(
+        &mut self,
+        add_contents: impl FnOnce(&mut Self) -> R,
+    ) -> InnerResponse<R> {
+        let layout = Layout::bottom_up(self.layout().horizontal_align())
+            .with_cross_justify(self.layout().horizontal_justify());
+        let max_rect = self.available_rect_before_wrap();
+        self.scope_builder(UiBuilder::new().max_rect(max_rect).layout(layout), |ui| {
+            let inner = add_contents(ui);
+            // Use all the space, so the footer stays at the bottom:
+            ui.expand_to_include_rect(max_rect);
+            inner
+        })
+    }
+
     /// This will make the next added widget centered and justified in the available space.
     ///
     /// Only one widget may be added to the inner `Ui`!
@@ -2980,6 +3784,148 @@ impl Ui {
         (InnerResponse { inner, response }, payload)
     }
 
+    /// Show a list of items that can be reordered by dragging them by a handle.
+    ///
+    /// Each item is shown in a horizontal row, after a drag handle.
+    /// While dragging, the item follows the pointer and the other items move aside to make room for it.
+    /// If the list is in a [`crate::ScrollArea`], it scrolls when dragging near its edges.
+    ///
+    /// When an item is dropped, it is moved in `items`,
+    /// and the move is returned as `(from_index, to_index)`.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let mut items = vec!["First", "Second", "Third"];
+    /// if let Some((from, to)) = ui.reorderable_list("my_list", &mut items, |ui, item| {
+    ///     ui.label(*item);
+    /// }) {
+    ///     println!("Moved item from {from} to {to}");
+    /// }
+    /// # });
+    /// ```
+    #[doc(alias = "drag and drop")]
+    pub fn reorderable_list<T>(
+        &mut self,
+        id_salt: impl Hash,
+        items: &mut Vec<T>,
+        mut add_item: impl FnMut(&mut Self, &mut T),
+    ) -> Option<(usize, usize)> {
+        let id = self.make_persistent_id(id_salt);
+        let handle_id = |index: usize| id.with(index);
+        let mut state = self
+            .data(|data| data.get_temp::<ReorderState>(id))
+            .unwrap_or_default();
+        state.row_heights.resize(items.len(), 0.0);
+
+        let ctx = self.ctx().clone();
+        let dragged = (0..items.len()).find(|&index| ctx.is_being_dragged(handle_id(index)));
+        let dropped = ctx
+            .drag_stopped_id()
+            .and_then(|stopped| (0..items.len()).find(|&index| handle_id(index) == stopped));
+        let moving = dragged.or(dropped);
+
+        let pointer_pos = ctx.pointer_interact_pos().map(|pos| {
+            ctx.layer_transform_from_global(self.layer_id())
+                .map_or(pos, |from_global| from_global * pos)
+        });
+
+        let moving_height = moving.map_or(0.0, |index| {
+            state.row_heights[index] + self.spacing().item_spacing.y
+        });
+        let animation_time = self.style().animation_time;
+        let drag_session = state.drag_session;
+        let add_gap = |ui: &mut Self, slot: usize| {
+            // The gap where the dragged item would be dropped:
+            let target = if moving.is_some() && state.insert_at == slot {
+                moving_height
+            } else {
+                0.0
+            };
+            let height = ctx.animate_value_with_time(
+                id.with(("gap", drag_session, slot)),
+                target,
+                animation_time,
+            );
+            if 0.0 < height {
+                ui.add_space(height);
+            }
+        };
+
+        let mut insert_at = 0;
+        let mut num_rows = 0;
+        for (index, item) in items.iter_mut().enumerate() {
+            if Some(index) == moving {
+                if dragged.is_some() {
+                    if let Some(pointer_pos) = pointer_pos {
+                        // Paint the dragged item at the pointer, on top of everything else:
+                        let height = state.row_heights[index];
+                        let rect = Rect::from_min_size(
+                            pos2(self.cursor().left(), pointer_pos.y - height / 2.0),
+                            vec2(self.available_width(), height),
+                        );
+                        let mut ui = self.new_child(
+                            UiBuilder::new()
+                                .layer_id(LayerId::new(Order::Tooltip, handle_id(index)))
+                                .max_rect(rect)
+                                .layout(Layout::left_to_right(Align::Center)),
+                        );
+                        ui.add(Label::new("☰").selectable(false));
+                        add_item(&mut ui, item);
+                    }
+                }
+                continue;
+            }
+
+            add_gap(self, num_rows);
+            let row = self.horizontal(|ui| {
+                let handle = ui.add(Label::new("☰").selectable(false));
+                ui.interact(handle.rect, handle_id(index), Sense::drag())
+                    .on_hover_cursor(CursorIcon::Grab);
+                add_item(ui, item);
+            });
+            state.row_heights[index] = row.response.rect.height();
+            num_rows += 1;
+
+            if pointer_pos.is_some_and(|pos| row.response.rect.center().y < pos.y) {
+                insert_at = num_rows;
+            }
+        }
+        add_gap(self, num_rows);
+
+        let mut result = None;
+        if let Some(from) = moving {
+            state.insert_at = insert_at;
+            ctx.request_repaint();
+
+            if dragged.is_some() {
+                if let Some(pointer_pos) = pointer_pos {
+                    // Scroll the enclosing scroll area when dragging near its top or bottom edge:
+                    let dt = self.input(|i| i.stable_dt).min(0.1);
+                    let delta = scroll_area::edge_scroll_delta(
+                        self,
+                        self.clip_rect(),
+                        pointer_pos,
+                        Vec2b::new(false, true),
+                        dt,
+                    );
+                    if delta != Vec2::ZERO {
+                        self.scroll_with_delta_animation(-delta, style::ScrollAnimation::none());
+                    }
+                }
+            } else {
+                let item = items.remove(from);
+                items.insert(insert_at, item);
+                state.drag_session = state.drag_session.wrapping_add(1);
+                if from != insert_at {
+                    result = Some((from, insert_at));
+                }
+            }
+        }
+
+        self.data_mut(|data| data.insert_temp(id, state));
+        result
+    }
+
     /// Create a new Scope and transform its contents via a [`emath::TSTransform`].
     /// This only affects visuals, inputs will not be transformed. So this is mostly useful
     /// to create visual effects on interactions, e.g. scaling a button on hover / click.
@@ -3006,6 +3952,101 @@ impl Ui {
 
         r
     }
+
+    /// Create a new Scope whose contents are blended together, and then faded by `opacity` as a whole.
+    ///
+    /// With [`Self::multiply_opacity`] each shape is faded on its own,
+    /// so e.g. the frame of a [`crate::Frame`] shows through the faded widgets on top of it.
+    /// This instead looks like fading out a picture of the contents,
+    /// which is what you want when fading out a whole panel or window.
+    ///
+    /// This needs support from the backend, see [`crate::BackendCapabilities::composite`].
+    /// Without it, each shape is faded on its own.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.compose(0.5, |ui| {
+    ///     ui.group(|ui| {
+    ///         ui.label("Faded out as one");
+    ///         ui.button("Button");
+    ///     });
+    /// });
+    /// # });
+    /// ```
+    pub fn compose<R>(
+        &mut self,
+        opacity: f32,
+        add_contents: impl FnOnce(&mut Self) -> R,
+    ) -> InnerResponse<R> {
+        let opacity = self.opacity() * opacity.clamp(0.0, 1.0);
+        if opacity >= 1.0 {
+            return self.scope_dyn(UiBuilder::new(), Box::new(add_contents));
+        }
+
+        // Placeholder for the composite shape:
+        let idx = self.painter().add(epaint::Shape::Noop);
+
+        let r = self.scope_dyn(
+            UiBuilder::new(),
+            Box::new(|ui: &mut Self| {
+                ui.set_opacity(1.0);
+                add_contents(ui)
+            }),
+        );
+
+        self.ctx().graphics_mut(|g| {
+            let list = g.entry(self.layer_id());
+            let shapes = list.take_from(crate::layers::ShapeIdx(idx.0 + 1));
+            if 0.0 < opacity {
+                list.set(
+                    idx,
+                    Rect::EVERYTHING,
+                    epaint::CompositeShape::new(shapes, opacity).into(),
+                );
+            }
+        });
+
+        r
+    }
+
+    /// Create a new Scope that is painted on top of everything else in this layer,
+    /// including the siblings added after it.
+    ///
+    /// The widgets in it are also on top of the others when hit-testing.
+    /// Unlike putting the contents in a new [`crate::Area`], they keep the clip rectangle,
+    /// layout and layer of this [`Ui`].
+    ///
+    /// Useful for e.g. a dragged item, or a custom dropdown that overlaps the widgets below it.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.paint_on_top_of_siblings(|ui| {
+    ///     ui.label("Painted last");
+    /// });
+    /// ui.label("Painted below");
+    /// # });
+    /// ```
+    pub fn paint_on_top_of_siblings<R>(
+        &mut self,
+        add_contents: impl FnOnce(&mut Self) -> R,
+    ) -> InnerResponse<R> {
+        let layer_id = self.layer_id();
+        let first_shape = self
+            .ctx()
+            .graphics_mut(|graphics| graphics.entry(layer_id).next_idx());
+        let first_widget = self
+            .ctx()
+            .viewport(|viewport| viewport.this_pass.widgets.get_layer(layer_id).count());
+
+        let r = self.scope_dyn(UiBuilder::new(), Box::new(add_contents));
+
+        self.ctx()
+            .graphics_mut(|graphics| graphics.entry(layer_id).elevate_from(first_shape));
+        self.ctx()
+            .pass_state_mut(|state| state.widgets.elevate_from(layer_id, first_widget));
+
+        r
+    }
 }
 
 /// # Menus
@@ -3147,6 +4188,18 @@ impl Ui {
 
 impl Drop for Ui {
     fn drop(&mut self) {
+        if self.placer.justifies_content() {
+            // Remember the widgets, so we can space them out next pass:
+            let items = self.placer.justify_content_items();
+            let previous = self.ctx().data_mut(|data| {
+                let previous = data.get_temp::<JustifyContentItems>(self.unique_id);
+                data.insert_temp(self.unique_id, items);
+                previous
+            });
+            if !previous.is_some_and(|previous| previous.roughly_eq(&items)) {
+                self.ctx().request_discard("Layout::justify_content");
+            }
+        }
         if !self.min_rect_already_remembered {
             // Register our final `min_rect`
             self.remember_min_rect();
@@ -3156,6 +4209,21 @@ impl Drop for Ui {
     }
 }
 
+#[inline]
+fn finite_or(value: f32, fallback: f32) -> f32 {
+    if value.is_finite() { value } else { fallback }
+}
+
+/// Like [`finite_or`], but keeps positive infinity.
+#[inline]
+fn max_or(value: f32, fallback: f32) -> f32 {
+    if value == f32::INFINITY {
+        value
+    } else {
+        finite_or(value, fallback)
+    }
+}
+
 /// Show this rectangle to the user if certain debug options are set.
 #[cfg(debug_assertions)]
 fn register_rect(ui: &Ui, rect: Rect) {
@@ -3243,6 +4311,78 @@ fn register_rect(ui: &Ui, rect: Rect) {
 #[cfg(not(debug_assertions))]
 fn register_rect(_ui: &Ui, _rect: Rect) {}
 
+/// The state of [`Ui::reorderable_list`].
+#[derive(Clone, Default)]
+struct ReorderState {
+    /// The height of each item, from when it was last shown in the list.
+    row_heights: Vec<f32>,
+
+    /// Where the dragged item would be dropped, among the other items.
+    insert_at: usize,
+
+    /// Increased on every drop, so the gap animations start from zero on the next drag.
+    drag_session: u64,
+}
+
+/// The state of [`Ui::defer_if_over_budget`].
+#[derive(Clone, Copy, Default)]
+struct DeferState {
+    /// The size of the contents when last shown.
+    size: Vec2,
+
+    /// Were the contents deferred last pass?
+    deferred: bool,
+}
+
+/// The recorded contents of [`Ui::cached`].
+#[derive(Clone)]
+struct CachedContents {
+    cache_key: u64,
+    layer_id: LayerId,
+    max_rect: Rect,
+    clip_rect: Rect,
+    enabled: bool,
+    style: Arc<Style>,
+    pixels_per_point: f32,
+    font_atlas_generation: u64,
+
+    min_rect: Rect,
+    shapes: Arc<[epaint::ClippedShape]>,
+    widgets: Arc<[WidgetRect]>,
+}
+
+impl CachedContents {
+    fn is_interacted_with(&self, ui: &Ui) -> bool {
+        is_interacted_with(ui, self.min_rect, &self.widgets)
+    }
+}
+
+/// Is any of the widgets hovered, focused or dragged?
+fn is_interacted_with(ui: &Ui, min_rect: Rect, widgets: &[WidgetRect]) -> bool {
+    let interact_rect = widgets
+        .iter()
+        .fold(min_rect, |rect, widget| rect.union(widget.interact_rect));
+    ui.rect_contains_pointer(interact_rect)
+        || widgets.iter().any(|widget| {
+            ui.memory(|mem| mem.has_focus(widget.id)) || ui.ctx().is_being_dragged(widget.id)
+        })
+}
+
+/// Does this shape paint all of `rect` in opaque colors?
+fn covers_opaquely(shape: &epaint::Shape, rect: Rect) -> bool {
+    match shape {
+        epaint::Shape::Rect(rect_shape) => {
+            rect_shape.fill.is_opaque()
+                && rect_shape.corner_radius == epaint::CornerRadius::ZERO
+                && rect_shape.blur_width <= 0.0
+                && rect_shape.brush.is_none()
+                && rect_shape.rect.contains_rect(rect)
+        }
+        epaint::Shape::Vec(shapes) => shapes.iter().any(|shape| covers_opaquely(shape, rect)),
+        _ => false,
+    }
+}
+
 #[test]
 fn ui_impl_send_sync() {
     fn assert_send_sync<T: Send + Sync>() {}
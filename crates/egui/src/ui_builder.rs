@@ -3,7 +3,7 @@ use std::{hash::Hash, sync::Arc};
 use crate::ClosableTag;
 #[expect(unused_imports)] // Used for doclinks
 use crate::Ui;
-use crate::{Id, LayerId, Layout, Rect, Sense, Style, UiStackInfo};
+use crate::{Id, LayerId, Layout, Rect, Sense, Style, UiStackInfo, emath::TSTransform};
 
 /// Build a [`Ui`] as the child of another [`Ui`].
 ///
@@ -23,6 +23,7 @@ pub struct UiBuilder {
     pub sizing_pass: bool,
     pub style: Option<Arc<Style>>,
     pub sense: Option<Sense>,
+    pub transform: Option<TSTransform>,
 }
 
 impl UiBuilder {
@@ -137,6 +138,34 @@ impl UiBuilder {
         self
     }
 
+    /// Translate and scale the contents of the new [`Ui`], relative to its parent.
+    ///
+    /// This affects both painting and interaction,
+    /// by putting the contents on a sublayer of the parent layer with the given transform
+    /// (see [`crate::Context::set_transform_layer`]).
+    ///
+    /// The widgets are laid out as if there was no transform,
+    /// so the space allocated in the parent is not transformed.
+    ///
+    /// To scale around the center of some `rect`:
+    /// ```
+    /// # use egui::{emath::TSTransform, UiBuilder};
+    /// # egui::__run_test_ui(|ui| {
+    /// let rect = ui.available_rect_before_wrap();
+    /// let transform = TSTransform::from_translation(rect.center().to_vec2())
+    ///     * TSTransform::from_scaling(1.5)
+    ///     * TSTransform::from_translation(-rect.center().to_vec2());
+    /// ui.scope_builder(UiBuilder::new().transform(transform), |ui| {
+    ///     ui.label("Look at me!");
+    /// });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn transform(mut self, transform: TSTransform) -> Self {
+        self.transform = Some(transform);
+        self
+    }
+
     /// Make this [`Ui`] closable.
     ///
     /// Calling [`Ui::close`] in a child [`Ui`] will mark this [`Ui`] for closing.
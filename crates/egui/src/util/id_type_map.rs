@@ -491,6 +491,28 @@ impl IdTypeMap {
         Some(std::mem::take(element.get_mut_temp()?))
     }
 
+    /// Move all state of the `old` ids to the corresponding `new` ids, regardless of type.
+    ///
+    /// Any previous state of the `new` ids is overwritten.
+    /// All state is removed before any is inserted, so the renames may form chains or swaps.
+    pub fn rename_ids(&mut self, renames: &[(Id, Id)]) {
+        let mut moved = Vec::new();
+        for &(old, new) in renames {
+            let keys: Vec<u64> = self
+                .map
+                .iter()
+                .filter(|(key, element)| **key == hash(Element::type_id(element), old))
+                .map(|(key, _)| *key)
+                .collect();
+            for key in keys {
+                if let Some(element) = self.map.remove(&key) {
+                    moved.push((hash(element.type_id(), new), element));
+                }
+            }
+        }
+        self.map.extend(moved);
+    }
+
     /// Note all state of the given type.
     pub fn remove_by_type<T: 'static>(&mut self) {
         let key = TypeId::of::<T>();
@@ -515,6 +537,24 @@ impl IdTypeMap {
         self.map.len()
     }
 
+    /// Approximate number of heap bytes used by this map.
+    ///
+    /// This counts the map itself, the shallow size of each stored value,
+    /// and the length of any not-yet-deserialized data.
+    /// Heap allocations owned by the stored values are not included.
+    pub fn approximate_byte_size(&self) -> usize {
+        let entries = self.map.capacity() * std::mem::size_of::<(u64, Element)>();
+        let contents: usize = self
+            .map
+            .values()
+            .map(|element| match element {
+                Element::Value { value, .. } => std::mem::size_of_val(&**value),
+                Element::Serialized(SerializedElement { ron, .. }) => ron.len(),
+            })
+            .sum();
+        entries + contents
+    }
+
     /// Count how many values are stored but not yet deserialized.
     #[inline]
     pub fn count_serialized(&self) -> usize {
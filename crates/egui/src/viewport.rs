@@ -259,7 +259,7 @@ pub type ImmediateViewportRendererCallback = dyn for<'a> Fn(&Context, ImmediateV
 ///
 /// The default values are implementation defined, so you may want to explicitly
 /// configure the size of the window, and what buttons are shown.
-#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct ViewportBuilder {
     /// The title of the viewport.
     /// `eframe` will use this as the title of the native window.
@@ -308,6 +308,11 @@ pub struct ViewportBuilder {
 
     pub mouse_passthrough: Option<bool>,
 
+    /// The opacity of the whole window, from `0` (fully transparent) to `255` (fully opaque).
+    ///
+    /// See [`Self::with_opacity`].
+    pub opacity: Option<u8>,
+
     // X11
     pub window_type: Option<X11WindowType>,
 }
@@ -635,6 +640,18 @@ impl ViewportBuilder {
         self
     }
 
+    /// The opacity of the whole window, where `0.0` is fully transparent and `1.0` is fully opaque.
+    ///
+    /// Unlike [`Self::with_transparent`], this fades out everything, including the window decorations.
+    /// Not supported by all integrations.
+    ///
+    /// The opacity is stored with 8 bits of precision, see [`Self::opacity`].
+    #[inline]
+    pub fn with_opacity(mut self, opacity: f32) -> Self {
+        self.opacity = Some((opacity.clamp(0.0, 1.0) * 255.0).round() as u8);
+        self
+    }
+
     /// ### On X11
     /// This sets the window type.
     /// Maps directly to [`_NET_WM_WINDOW_TYPE`](https://specifications.freedesktop.org/wm-spec/wm-spec-1.5.html).
@@ -676,6 +693,7 @@ impl ViewportBuilder {
             maximize_button: new_maximize_button,
             window_level: new_window_level,
             mouse_passthrough: new_mouse_passthrough,
+            opacity: new_opacity,
             taskbar: new_taskbar,
             window_type: new_window_type,
         } = new_vp_builder;
@@ -785,6 +803,13 @@ impl ViewportBuilder {
             }
         }
 
+        if let Some(new_opacity) = new_opacity {
+            if Some(new_opacity) != self.opacity {
+                self.opacity = Some(new_opacity);
+                commands.push(ViewportCommand::Opacity(new_opacity));
+            }
+        }
+
         // --------------------------------------------------------------
         // Things we don't have commands for require a full window recreation.
         // The reason we don't have commands for them is that `winit` doesn't support
@@ -970,7 +995,7 @@ pub enum CursorGrab {
     Locked,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum ResizeDirection {
     North,
@@ -1103,11 +1128,38 @@ pub enum ViewportCommand {
 
     CursorGrab(CursorGrab),
 
+    /// Keep delivering pointer events to this viewport, even when the pointer leaves it.
+    ///
+    /// `egui-winit` does this by confining the cursor to the window, as winit has no pointer capture.
+    ///
+    /// Sent by egui when a widget captures the pointer,
+    /// see [`crate::Response::request_pointer_capture`].
+    PointerCapture(bool),
+
     CursorVisible(bool),
 
     /// Enable mouse pass-through: mouse clicks pass through the window, used for non-interactable overlays.
     MousePassthrough(bool),
 
+    /// Set the opacity of the whole window, from `0` (fully transparent) to `255` (fully opaque).
+    ///
+    /// See [`ViewportBuilder::with_opacity`].
+    Opacity(u8),
+
+    /// Only let the mouse interact with the window inside these regions, given in ui points.
+    ///
+    /// Outside of them, mouse clicks pass through the window to whatever is behind it.
+    /// An empty list means the whole window is pass-through, like [`Self::MousePassthrough`].
+    ///
+    /// See [`crate::Context::hit_test_regions`] for a sensible default,
+    /// and [`crate::Context::update_hit_test_regions`] for keeping them up-to-date.
+    ///
+    /// Not supported by `egui-winit` (and so not by native `eframe`):
+    /// winit can only turn hit-testing on or off for the whole window,
+    /// and then no longer tells us where the mouse is.
+    /// Use [`Self::MousePassthrough`] there instead.
+    SetHitTestRegions(Vec<crate::Rect>),
+
     /// Take a screenshot of the next frame after this.
     ///
     /// The results are returned in [`crate::Event::Screenshot`].
@@ -1191,6 +1243,20 @@ pub struct ViewportOutput {
     ///
     /// If the duration is zero, schedule a repaint immediately.
     pub repaint_delay: std::time::Duration,
+
+    /// When to repaint this viewport, on the same clock as [`crate::RawInput::time`].
+    ///
+    /// This is [`Self::repaint_delay`] as a point in time,
+    /// so that an integration with many viewports can serve them all with a single timer.
+    ///
+    /// `None` if no repaint is needed.
+    pub repaint_deadline: Option<f64>,
+
+    /// Why a repaint was requested.
+    ///
+    /// E.g. an integration could run repaints that are only for animations at a lower frame rate,
+    /// or skip them for hidden windows.
+    pub repaint_reasons: crate::RepaintReasons,
 }
 
 impl ViewportOutput {
@@ -1203,6 +1269,8 @@ impl ViewportOutput {
             viewport_ui_cb,
             mut commands,
             repaint_delay,
+            repaint_deadline,
+            repaint_reasons,
         } = newer;
 
         self.parent = parent;
@@ -1211,6 +1279,11 @@ impl ViewportOutput {
         self.viewport_ui_cb = viewport_ui_cb;
         self.commands.append(&mut commands);
         self.repaint_delay = self.repaint_delay.min(repaint_delay);
+        self.repaint_deadline = match (self.repaint_deadline, repaint_deadline) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+        self.repaint_reasons = self.repaint_reasons.union(repaint_reasons);
     }
 }
 
@@ -0,0 +1,114 @@
+//! Collecting the runtime warnings of egui,
+//! see [`crate::Context::take_warnings`].
+
+/// How bad is a [`Warning`]?
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum WarningSeverity {
+    /// Something is likely slow or looks wrong, but works.
+    Warning,
+
+    /// Something is broken, e.g. widgets that can't be interacted with.
+    Error,
+}
+
+/// What a [`Warning`] is about.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum WarningKind {
+    /// Two widgets used the same [`crate::Id`],
+    /// see [`crate::Options::warn_on_id_clash`].
+    IdClash,
+
+    /// Some text used characters that none of the fonts have.
+    MissingGlyphs,
+
+    /// [`crate::Context::request_discard`] was called many frames in a row.
+    RepeatedDiscard,
+
+    /// A texture was larger than [`crate::InputState::max_texture_side`].
+    TextureTooLarge,
+
+    /// egui was used the wrong way, see [`crate::Options::strict_mode`].
+    Misuse,
+
+    /// A size or rectangle given to the layout contained NaN or an infinity,
+    /// and was replaced with something sensible.
+    NanLayout,
+
+    /// Reported by user code with [`crate::Context::report_warning`].
+    Custom,
+}
+
+impl WarningKind {
+    /// The default severity for this kind of warning.
+    pub fn severity(self) -> WarningSeverity {
+        match self {
+            Self::IdClash | Self::TextureTooLarge | Self::Misuse => WarningSeverity::Error,
+            Self::MissingGlyphs | Self::RepeatedDiscard | Self::NanLayout | Self::Custom => {
+                WarningSeverity::Warning
+            }
+        }
+    }
+}
+
+/// A problem found by egui at runtime.
+///
+/// See [`crate::Context::take_warnings`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Warning {
+    pub severity: WarningSeverity,
+    pub kind: WarningKind,
+
+    /// Human-readable description.
+    pub message: String,
+}
+
+impl Warning {
+    /// A warning with the default severity of its kind.
+    pub fn new(kind: WarningKind, message: impl Into<String>) -> Self {
+        Self {
+            severity: kind.severity(),
+            kind,
+            message: message.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for Warning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?} ({:?}): {}", self.severity, self.kind, self.message)
+    }
+}
+
+/// The warnings that have not yet been taken.
+#[derive(Default)]
+pub(crate) struct Warnings {
+    warnings: Vec<Warning>,
+}
+
+impl Warnings {
+    /// Stop collecting new warnings when there are this many,
+    /// so that an app that never takes them doesn't leak memory.
+    const MAX_WARNINGS: usize = 1000;
+
+    /// Add a warning, unless the same warning is already waiting to be taken.
+    pub fn report(&mut self, warning: Warning) {
+        if self.warnings.len() < Self::MAX_WARNINGS && !self.warnings.contains(&warning) {
+            #[cfg(feature = "log")]
+            log::warn!("{warning}");
+
+            self.warnings.push(warning);
+        }
+    }
+
+    pub fn take(&mut self) -> Vec<Warning> {
+        std::mem::take(&mut self.warnings)
+    }
+
+    pub fn len(&self) -> usize {
+        self.warnings.len()
+    }
+
+    pub fn worst_severity(&self) -> Option<WarningSeverity> {
+        self.warnings.iter().map(|warning| warning.severity).max()
+    }
+}
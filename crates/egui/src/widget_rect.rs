@@ -1,6 +1,27 @@
 use ahash::HashMap;
 
-use crate::{Id, IdMap, LayerId, Rect, Sense, WidgetInfo};
+use crate::{Id, IdMap, LayerId, Margin, Rect, Sense, UiKind, WidgetInfo, WidgetType, id::IdSet};
+
+/// How a widget takes part in hit-testing, i.e. finding what is under the pointer.
+///
+/// See [`crate::Response::hit_test_mode`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum HitTestMode {
+    /// The widget can be hovered, clicked and dragged,
+    /// and hides the layers behind it.
+    #[default]
+    Opaque,
+
+    /// The widget is invisible to the pointer:
+    /// it is never hovered, clicked or dragged, and everything behind it can be.
+    ///
+    /// Useful for decorative overlays.
+    Transparent,
+
+    /// Like [`Self::Transparent`], except that it still hides the layers behind it,
+    /// so only the widgets on top of it (e.g. its children) can be interacted with.
+    ChildrenOnly,
+}
 
 /// Used to store each widget's [Id], [Rect] and [Sense] each frame.
 ///
@@ -40,6 +61,20 @@ pub struct WidgetRect {
 
     /// Is the widget enabled?
     pub enabled: bool,
+
+    /// How the widget takes part in hit-testing.
+    pub hit_test_mode: HitTestMode,
+
+    /// When hit-testing, a widget with a higher priority wins over
+    /// an overlapping or equally close widget with a lower priority.
+    ///
+    /// See [`crate::Response::interact_priority`].
+    pub interact_priority: i8,
+
+    /// How much to expand [`Self::interact_rect`] when hit-testing.
+    ///
+    /// See [`crate::Response::interact_expand`].
+    pub interact_expand: Margin,
 }
 
 impl WidgetRect {
@@ -51,6 +86,9 @@ impl WidgetRect {
             interact_rect,
             sense,
             enabled,
+            hit_test_mode,
+            interact_priority,
+            interact_expand,
         } = self;
         Self {
             id,
@@ -59,8 +97,17 @@ impl WidgetRect {
             interact_rect: transform * interact_rect,
             sense,
             enabled,
+            hit_test_mode,
+            interact_priority,
+            interact_expand,
         }
     }
+
+    /// The [`Self::interact_rect`] expanded by [`Self::interact_expand`].
+    #[inline]
+    pub fn hit_test_rect(&self) -> Rect {
+        self.interact_rect + self.interact_expand
+    }
 }
 
 /// Stores the [`WidgetRect`]s of all widgets generated during a single egui update/frame.
@@ -80,6 +127,20 @@ pub struct WidgetRects {
     /// Only filled in if the widget is interacted with,
     /// or if this is a debug build.
     infos: IdMap<WidgetInfo>,
+
+    /// Widgets that are behind all the widgets they contain when hit-testing,
+    /// see [`crate::Ui::interact_bg_behind_children`].
+    behind_children: IdSet,
+
+    /// Widgets to move on top of their layer at the end of the pass,
+    /// see [`crate::Ui::paint_on_top_of_siblings`].
+    elevated: IdSet,
+
+    /// The [`crate::Ui`] each widget was added to, see [`Self::set_parent`].
+    parents: IdMap<Id>,
+
+    /// The widgets that are [`crate::Ui`]s, and their kind.
+    uis: IdMap<Option<UiKind>>,
 }
 
 impl PartialEq for WidgetRects {
@@ -127,6 +188,10 @@ impl WidgetRects {
             by_layer,
             by_id,
             infos,
+            behind_children,
+            elevated,
+            parents,
+            uis,
         } = self;
 
         for rects in by_layer.values_mut() {
@@ -136,6 +201,14 @@ impl WidgetRects {
         by_id.clear();
 
         infos.clear();
+
+        behind_children.clear();
+
+        elevated.clear();
+
+        parents.clear();
+
+        uis.clear();
     }
 
     /// Insert the given widget rect in the given layer.
@@ -144,6 +217,10 @@ impl WidgetRects {
             by_layer,
             by_id,
             infos: _,
+            behind_children: _,
+            elevated: _,
+            parents: _,
+            uis: _,
         } = self;
 
         let layer_widgets = by_layer.entry(layer_id).or_default();
@@ -173,6 +250,15 @@ impl WidgetRects {
                 existing.interact_rect = widget_rect.interact_rect; // last wins
                 existing.sense |= widget_rect.sense;
                 existing.enabled |= widget_rect.enabled;
+                if widget_rect.hit_test_mode != HitTestMode::default() {
+                    existing.hit_test_mode = widget_rect.hit_test_mode;
+                }
+                if widget_rect.interact_priority != 0 {
+                    existing.interact_priority = widget_rect.interact_priority;
+                }
+                if widget_rect.interact_expand != Margin::ZERO {
+                    existing.interact_expand = widget_rect.interact_expand;
+                }
 
                 if existing.layer_id == widget_rect.layer_id {
                     layer_widgets[*idx_in_layer] = *existing;
@@ -181,6 +267,90 @@ impl WidgetRects {
         }
     }
 
+    /// Change how a widget that has already been added is hit-tested.
+    ///
+    /// See [`crate::Response::hit_test_mode`].
+    pub fn set_hit_test_mode(&mut self, id: impl Into<Id>, mode: HitTestMode) {
+        self.modify(id, |w| w.hit_test_mode = mode);
+    }
+
+    /// Change the hit-test priority of a widget that has already been added.
+    ///
+    /// See [`crate::Response::interact_priority`].
+    pub fn set_interact_priority(&mut self, id: impl Into<Id>, priority: i8) {
+        self.modify(id, |w| w.interact_priority = priority);
+    }
+
+    /// Change how much a widget that has already been added is expanded when hit-testing.
+    ///
+    /// See [`crate::Response::interact_expand`].
+    pub fn set_interact_expand(&mut self, id: impl Into<Id>, expand: Margin) {
+        self.modify(id, |w| w.interact_expand = expand);
+    }
+
+    fn modify(&mut self, id: impl Into<Id>, modify: impl Fn(&mut WidgetRect)) {
+        if let Some((idx_in_layer, widget_rect)) = self.by_id.get_mut(&id.into()) {
+            modify(widget_rect);
+            if let Some(layer_widgets) = self.by_layer.get_mut(&widget_rect.layer_id) {
+                if let Some(w) = layer_widgets.get_mut(*idx_in_layer) {
+                    modify(w);
+                }
+            }
+        }
+    }
+
+    /// Put this widget behind all the widgets it contains when hit-testing,
+    /// no matter in which order they were added.
+    ///
+    /// See [`crate::Ui::interact_bg_behind_children`].
+    pub fn set_behind_children(&mut self, id: impl Into<Id>) {
+        self.behind_children.insert(id.into());
+    }
+
+    /// See [`Self::set_behind_children`].
+    #[inline]
+    pub fn is_behind_children(&self, id: impl Into<Id>) -> bool {
+        self.behind_children.contains(&id.into())
+    }
+
+    /// Put the widgets of this layer from index `start` and on
+    /// on top of all other widgets in the layer, including the ones added later.
+    ///
+    /// This is applied by [`Self::sort_elevated_last`].
+    /// See [`crate::Ui::paint_on_top_of_siblings`].
+    pub fn elevate_from(&mut self, layer_id: LayerId, start: usize) {
+        if let Some(layer_widgets) = self.by_layer.get(&layer_id) {
+            self.elevated
+                .extend(layer_widgets.iter().skip(start).map(|w| w.id));
+        }
+    }
+
+    /// Move the widgets marked with [`Self::elevate_from`] on top of their layer,
+    /// keeping their order among themselves.
+    pub(crate) fn sort_elevated_last(&mut self) {
+        let Self {
+            by_layer,
+            by_id,
+            elevated,
+            ..
+        } = self;
+
+        if elevated.is_empty() {
+            return;
+        }
+
+        for layer_widgets in by_layer.values_mut() {
+            if layer_widgets.iter().any(|w| elevated.contains(&w.id)) {
+                layer_widgets.sort_by_key(|w| elevated.contains(&w.id)); // stable
+                for (idx_in_layer, w) in layer_widgets.iter().enumerate() {
+                    if let Some((idx, _)) = by_id.get_mut(&w.id) {
+                        *idx = idx_in_layer;
+                    }
+                }
+            }
+        }
+    }
+
     pub fn set_info(&mut self, id: impl Into<Id>, info: WidgetInfo) {
         self.infos.insert(id.into(), info);
     }
@@ -188,4 +358,113 @@ impl WidgetRects {
     pub fn info(&self, id: impl Into<Id>) -> Option<&WidgetInfo> {
         self.infos.get(&id.into())
     }
+
+    /// Remember which [`crate::Ui`] this widget was added to.
+    pub fn set_parent(&mut self, id: impl Into<Id>, parent: impl Into<Id>) {
+        let (id, parent) = (id.into(), parent.into());
+        if id != parent {
+            self.parents.insert(id, parent);
+        }
+    }
+
+    /// The [`crate::Ui`] this widget was added to, if known.
+    pub fn parent(&self, id: impl Into<Id>) -> Option<Id> {
+        self.parents.get(&id.into()).copied()
+    }
+
+    /// Mark this widget as being a [`crate::Ui`].
+    pub fn set_ui_kind(&mut self, id: impl Into<Id>, kind: Option<UiKind>) {
+        self.uis.insert(id.into(), kind);
+    }
+
+    /// Is this widget a [`crate::Ui`]?
+    pub fn is_ui(&self, id: impl Into<Id>) -> bool {
+        self.uis.contains_key(&id.into())
+    }
+
+    /// A [`WidgetTreeSnapshot`] of all widgets in the given layers,
+    /// in the given layer order.
+    pub fn tree_snapshot(&self, layers: &[LayerId]) -> WidgetTreeSnapshot {
+        let nodes = layers
+            .iter()
+            .flat_map(|&layer_id| self.get_layer(layer_id))
+            .map(|w| WidgetNode {
+                id: w.id,
+                parent: self.parent(w.id),
+                layer_id: w.layer_id,
+                rect: w.rect,
+                kind: match self.uis.get(&w.id) {
+                    Some(&ui_kind) => WidgetNodeKind::Ui(ui_kind),
+                    None => WidgetNodeKind::Widget(self.info(w.id).map(|info| info.typ)),
+                },
+            })
+            .collect();
+        WidgetTreeSnapshot { nodes }
+    }
+}
+
+/// What kind of widget is a [`WidgetNode`]?
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WidgetNodeKind {
+    /// A [`crate::Ui`], with its [`crate::UiStackInfo::kind`].
+    Ui(Option<UiKind>),
+
+    /// Any other widget.
+    ///
+    /// The [`WidgetType`] is only known for widgets that registered a [`WidgetInfo`],
+    /// which happens in debug builds, or when the widget is interacted with.
+    Widget(Option<WidgetType>),
+}
+
+/// One widget in a [`WidgetTreeSnapshot`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WidgetNode {
+    pub id: Id,
+
+    /// The [`crate::Ui`] the widget was added to.
+    ///
+    /// `None` for root [`crate::Ui`]s, and for widgets created directly with the [`crate::Context`],
+    /// like the move handle of an [`crate::Area`].
+    pub parent: Option<Id>,
+
+    pub layer_id: LayerId,
+
+    /// The full widget rectangle, in local layer coordinates.
+    pub rect: Rect,
+
+    pub kind: WidgetNodeKind,
+}
+
+/// The structure of the widgets produced by a pass,
+/// see [`crate::Context::widget_tree_snapshot`].
+///
+/// This is meant for tooling, e.g. to diff the structure of the UI across hot-reloads.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WidgetTreeSnapshot {
+    /// All widgets, layer by layer from back to front,
+    /// and in the order they were added within each layer.
+    ///
+    /// A [`crate::Ui`] is always before the widgets it contains.
+    pub nodes: Vec<WidgetNode>,
+}
+
+impl WidgetTreeSnapshot {
+    /// Look up a widget by [`Id`].
+    pub fn get(&self, id: impl Into<Id>) -> Option<&WidgetNode> {
+        let id = id.into();
+        self.nodes.iter().find(|node| node.id == id)
+    }
+
+    /// The direct children of the given widget, in order.
+    pub fn children(&self, parent: impl Into<Id>) -> impl Iterator<Item = &WidgetNode> + '_ {
+        let parent = parent.into();
+        self.nodes
+            .iter()
+            .filter(move |node| node.parent == Some(parent))
+    }
+
+    /// The widgets without a parent, in order.
+    pub fn roots(&self) -> impl Iterator<Item = &WidgetNode> + '_ {
+        self.nodes.iter().filter(|node| node.parent.is_none())
+    }
 }
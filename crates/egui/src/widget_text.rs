@@ -39,6 +39,8 @@ pub struct RichText {
     weak: bool,
     strikethrough: bool,
     underline: bool,
+    outline: crate::Stroke,
+    shadow: crate::text::TextShadow,
     italics: bool,
     raised: bool,
 }
@@ -60,6 +62,8 @@ impl Default for RichText {
             weak: Default::default(),
             strikethrough: Default::default(),
             underline: Default::default(),
+            outline: crate::Stroke::NONE,
+            shadow: crate::text::TextShadow::NONE,
             italics: Default::default(),
             raised: Default::default(),
         }
@@ -261,6 +265,24 @@ impl RichText {
         self
     }
 
+    /// Outline the characters, e.g. to keep text readable on top of an image.
+    ///
+    /// See [`TextFormat::outline`].
+    #[inline]
+    pub fn outline(mut self, outline: impl Into<crate::Stroke>) -> Self {
+        self.outline = outline.into();
+        self
+    }
+
+    /// Paint a drop shadow behind the characters.
+    ///
+    /// See [`TextFormat::shadow`].
+    #[inline]
+    pub fn shadow(mut self, shadow: crate::text::TextShadow) -> Self {
+        self.shadow = shadow;
+        self
+    }
+
     /// Tilt the characters to the right.
     #[inline]
     pub fn italics(mut self) -> Self {
@@ -396,6 +418,8 @@ impl RichText {
             weak: _,   // already used by `get_text_color`
             strikethrough,
             underline,
+            outline,
+            shadow,
             italics,
             raised,
         } = self;
@@ -454,6 +478,8 @@ impl RichText {
                 italics,
                 underline,
                 strikethrough,
+                outline,
+                shadow,
                 valign,
                 expand_bg,
             },
@@ -643,6 +669,18 @@ impl WidgetText {
         self.map_rich_text(|text| text.strikethrough())
     }
 
+    /// Prefer using [`RichText`] directly!
+    #[inline]
+    pub fn outline(self, outline: impl Into<crate::Stroke>) -> Self {
+        self.map_rich_text(|text| text.outline(outline))
+    }
+
+    /// Prefer using [`RichText`] directly!
+    #[inline]
+    pub fn shadow(self, shadow: crate::text::TextShadow) -> Self {
+        self.map_rich_text(|text| text.shadow(shadow))
+    }
+
     /// Prefer using [`RichText`] directly!
     #[inline]
     pub fn italics(self) -> Self {
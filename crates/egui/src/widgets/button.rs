@@ -296,15 +296,14 @@ impl<'a> Button<'a> {
             .allocate(ui);
 
         let response = if ui.is_rect_visible(prepared.response.rect) {
-            let visuals = ui.style().interact_selectable(&prepared.response, selected);
+            let visuals = ui.interact_selectable_visuals(&prepared.response, selected);
 
             let visible_frame = if frame_when_inactive {
                 has_frame_margin
             } else {
                 has_frame_margin
                     && (prepared.response.hovered()
-                        || prepared.response.is_pointer_button_down_on()
-                        || prepared.response.has_focus())
+                        || prepared.response.is_pointer_button_down_on())
             };
 
             if image_tint_follows_text_color {
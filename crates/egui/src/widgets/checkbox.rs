@@ -18,15 +18,58 @@ use crate::{
 /// ```
 #[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
 pub struct Checkbox<'a> {
-    checked: &'a mut bool,
+    checked: CheckedValue<'a>,
     atoms: Atoms<'a>,
     indeterminate: bool,
 }
 
+/// What a [`Checkbox`] shows and changes.
+enum CheckedValue<'a> {
+    Bool(&'a mut bool),
+
+    /// `None` is indeterminate.
+    TriState(&'a mut Option<bool>),
+
+    /// Checked if all are, unchecked if none are, and indeterminate otherwise.
+    All(Vec<&'a mut bool>),
+}
+
+impl CheckedValue<'_> {
+    fn get(&self) -> Option<bool> {
+        match self {
+            Self::Bool(checked) => Some(**checked),
+            Self::TriState(checked) => **checked,
+            Self::All(children) => {
+                if children.iter().all(|checked| **checked) {
+                    Some(true)
+                } else if children.iter().all(|checked| !**checked) {
+                    Some(false)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    /// An indeterminate checkbox becomes checked.
+    fn toggle(&mut self) {
+        let checked = !self.get().unwrap_or(false);
+        match self {
+            Self::Bool(value) => **value = checked,
+            Self::TriState(value) => **value = Some(checked),
+            Self::All(children) => {
+                for child in children {
+                    **child = checked;
+                }
+            }
+        }
+    }
+}
+
 impl<'a> Checkbox<'a> {
     pub fn new(checked: &'a mut bool, atoms: impl IntoAtoms<'a>) -> Self {
         Checkbox {
-            checked,
+            checked: CheckedValue::Bool(checked),
             atoms: atoms.into_atoms(),
             indeterminate: false,
         }
@@ -36,10 +79,61 @@ impl<'a> Checkbox<'a> {
         Self::new(checked, ())
     }
 
+    /// A checkbox that can also be indeterminate, which is `None`.
+    ///
+    /// Clicking an indeterminate checkbox checks it.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let mut value: Option<bool> = None;
+    /// ui.add(egui::Checkbox::tri_state(&mut value, "Maybe"));
+    /// # });
+    /// ```
+    pub fn tri_state(checked: &'a mut Option<bool>, atoms: impl IntoAtoms<'a>) -> Self {
+        Checkbox {
+            checked: CheckedValue::TriState(checked),
+            atoms: atoms.into_atoms(),
+            indeterminate: false,
+        }
+    }
+
+    /// A "select all" checkbox for a group of other checkboxes.
+    ///
+    /// It is checked if all the `children` are, unchecked if none are,
+    /// and indeterminate otherwise.
+    /// Clicking it checks or unchecks all the `children`.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// struct File { name: String, selected: bool }
+    /// let mut files: Vec<File> = vec![];
+    ///
+    /// ui.add(egui::Checkbox::select_all(
+    ///     files.iter_mut().map(|file| &mut file.selected),
+    ///     "All files",
+    /// ));
+    /// for file in &mut files {
+    ///     ui.checkbox(&mut file.selected, &file.name);
+    /// }
+    /// # });
+    /// ```
+    pub fn select_all(
+        children: impl IntoIterator<Item = &'a mut bool>,
+        atoms: impl IntoAtoms<'a>,
+    ) -> Self {
+        Checkbox {
+            checked: CheckedValue::All(children.into_iter().collect()),
+            atoms: atoms.into_atoms(),
+            indeterminate: false,
+        }
+    }
+
     /// Display an indeterminate state (neither checked nor unchecked)
     ///
     /// This only affects the checkbox's appearance. It will still toggle its boolean value when
     /// clicked.
+    ///
+    /// See also [`Self::tri_state`].
     #[inline]
     pub fn indeterminate(mut self, indeterminate: bool) -> Self {
         self.indeterminate = indeterminate;
@@ -50,7 +144,7 @@ impl<'a> Checkbox<'a> {
 impl Widget for Checkbox<'_> {
     fn ui(self, ui: &mut Ui) -> Response {
         let Checkbox {
-            checked,
+            mut checked,
             mut atoms,
             indeterminate,
         } = self;
@@ -75,9 +169,11 @@ impl Widget for Checkbox<'_> {
             .allocate(ui);
 
         if prepared.response.clicked() {
-            *checked = !*checked;
+            checked.toggle();
             prepared.response.mark_changed();
         }
+        let checked = checked.get();
+        let indeterminate = indeterminate || checked.is_none();
         prepared.response.widget_info(|| {
             if indeterminate {
                 WidgetInfo::labeled(
@@ -89,15 +185,15 @@ impl Widget for Checkbox<'_> {
                 WidgetInfo::selected(
                     WidgetType::Checkbox,
                     ui.is_enabled(),
-                    *checked,
+                    checked == Some(true),
                     text.as_deref().unwrap_or(""),
                 )
             }
         });
 
         if ui.is_rect_visible(prepared.response.rect) {
-            // let visuals = ui.style().interact_selectable(&response, *checked); // too colorful
-            let visuals = *ui.style().interact(&prepared.response);
+            // let visuals = ui.style().interact_selectable(&response, checked); // too colorful
+            let visuals = ui.interact_visuals(&prepared.response);
             prepared.fallback_text_color = visuals.text_color();
             let response = prepared.paint(ui);
 
@@ -118,7 +214,7 @@ impl Widget for Checkbox<'_> {
                         small_icon_rect.center().y,
                         visuals.fg_stroke,
                     ));
-                } else if *checked {
+                } else if checked == Some(true) {
                     // Check mark:
                     ui.painter().add(Shape::line(
                         vec![
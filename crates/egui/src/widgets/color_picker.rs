@@ -314,15 +314,24 @@ fn color_picker_hsvag_2d(ui: &mut Ui, hsvag: &mut HsvaGamma, alpha: Alpha) {
     }
 
     let current_color_size = vec2(ui.spacing().slider_width, ui.spacing().interact_size.y);
-    show_color(ui, *hsvag, current_color_size).on_hover_text("Selected color");
+    show_color(ui, *hsvag, current_color_size)
+        .on_hover_text(ui.ctx().tr("color_picker.selected_color", "Selected color"));
 
     if alpha == Alpha::BlendOrAdditive {
         let a = &mut hsvag.a;
         let mut additive = is_additive_alpha(*a);
         ui.horizontal(|ui| {
-            ui.label("Blending:");
-            ui.radio_value(&mut additive, false, "Normal");
-            ui.radio_value(&mut additive, true, "Additive");
+            ui.label(ui.ctx().tr("color_picker.blending", "Blending:"));
+            ui.radio_value(
+                &mut additive,
+                false,
+                ui.ctx().tr("color_picker.blending_normal", "Normal"),
+            );
+            ui.radio_value(
+                &mut additive,
+                true,
+                ui.ctx().tr("color_picker.blending_additive", "Additive"),
+            );
 
             if additive {
                 *a = -a.abs();
@@ -339,11 +348,13 @@ fn color_picker_hsvag_2d(ui: &mut Ui, hsvag: &mut HsvaGamma, alpha: Alpha) {
     let HsvaGamma { h, s, v, a: _ } = hsvag;
 
     if false {
-        color_slider_1d(ui, s, |s| HsvaGamma { s, ..opaque }.into()).on_hover_text("Saturation");
+        color_slider_1d(ui, s, |s| HsvaGamma { s, ..opaque }.into())
+            .on_hover_text(ui.ctx().tr("color_picker.saturation", "Saturation"));
     }
 
     if false {
-        color_slider_1d(ui, v, |v| HsvaGamma { v, ..opaque }.into()).on_hover_text("Value");
+        color_slider_1d(ui, v, |v| HsvaGamma { v, ..opaque }.into())
+            .on_hover_text(ui.ctx().tr("color_picker.value", "Value"));
     }
 
     color_slider_2d(ui, s, v, |s, v| HsvaGamma { s, v, ..opaque }.into());
@@ -357,7 +368,7 @@ fn color_picker_hsvag_2d(ui: &mut Ui, hsvag: &mut HsvaGamma, alpha: Alpha) {
         }
         .into()
     })
-    .on_hover_text("Hue");
+    .on_hover_text(ui.ctx().tr("color_picker.hue", "Hue"));
 
     let additive = is_additive_alpha(hsvag.a);
 
@@ -370,9 +381,11 @@ fn color_picker_hsvag_2d(ui: &mut Ui, hsvag: &mut HsvaGamma, alpha: Alpha) {
             if is_additive_alpha(*a) {
                 *a = 0.5; // was additive, but isn't allowed to be
             }
-            color_slider_1d(ui, a, |a| HsvaGamma { a, ..opaque }.into()).on_hover_text("Alpha");
+            color_slider_1d(ui, a, |a| HsvaGamma { a, ..opaque }.into())
+                .on_hover_text(ui.ctx().tr("color_picker.alpha", "Alpha"));
         } else if !additive {
-            color_slider_1d(ui, a, |a| HsvaGamma { a, ..opaque }.into()).on_hover_text("Alpha");
+            color_slider_1d(ui, a, |a| HsvaGamma { a, ..opaque }.into())
+                .on_hover_text(ui.ctx().tr("color_picker.alpha", "Alpha"));
         }
     }
 }
@@ -398,7 +411,10 @@ fn srgba_edit_ui(ui: &mut Ui, [r, g, b, a]: &mut [u8; 4], alpha: Alpha) -> bool
 
         if ui
             .button("📋")
-            .on_hover_text("Click to copy color values")
+            .on_hover_text(
+                ui.ctx()
+                    .tr("color_picker.copy_values", "Click to copy color values"),
+            )
             .clicked()
         {
             if alpha == Alpha::Opaque {
@@ -439,7 +455,10 @@ fn rgba_edit_ui(ui: &mut Ui, [r, g, b, a]: &mut [f32; 4], alpha: Alpha) -> bool
 
         if ui
             .button("📋")
-            .on_hover_text("Click to copy color values")
+            .on_hover_text(
+                ui.ctx()
+                    .tr("color_picker.copy_values", "Click to copy color values"),
+            )
             .clicked()
         {
             if alpha == Alpha::Opaque {
@@ -494,7 +513,8 @@ pub fn color_edit_button_hsva(ui: &mut Ui, hsva: &mut Hsva, alpha: Alpha) -> Res
     let open = Popup::is_id_open(ui.ctx(), popup_id);
     let mut button_response = color_button(ui, (*hsva).into(), open);
     if ui.style().explanation_tooltips {
-        button_response = button_response.on_hover_text("Click to edit color");
+        button_response =
+            button_response.on_hover_text(ui.ctx().tr("color_picker.edit", "Click to edit color"));
     }
 
     const COLOR_SLIDER_WIDTH: f32 = 275.0;
@@ -4,7 +4,7 @@ use std::{cmp::Ordering, ops::RangeInclusive};
 
 use crate::{
     Button, CursorIcon, Id, Key, MINUS_CHAR_STR, Modifiers, NumExt as _, Response, RichText, Sense,
-    TextEdit, TextWrapMode, Ui, Widget, WidgetInfo, emath, text,
+    TextEdit, TextWrapMode, Ui, Unit, Widget, WidgetInfo, emath, style::NumberFormat, text,
 };
 
 // ----------------------------------------------------------------------------
@@ -46,7 +46,11 @@ pub struct DragValue<'a> {
     max_decimals: Option<usize>,
     custom_formatter: Option<NumFormatter<'a>>,
     custom_parser: Option<NumParser<'a>>,
+    number_format: Option<NumberFormat>,
+    unit: Option<Unit>,
+    display_unit: Option<Unit>,
     update_while_editing: bool,
+    infinite_drag: bool,
 }
 
 impl<'a> DragValue<'a> {
@@ -77,7 +81,11 @@ impl<'a> DragValue<'a> {
             max_decimals: None,
             custom_formatter: None,
             custom_parser: None,
+            number_format: None,
+            unit: None,
+            display_unit: None,
             update_while_editing: true,
+            infinite_drag: false,
         }
     }
 
@@ -302,6 +310,62 @@ impl<'a> DragValue<'a> {
         self
     }
 
+    /// Set the decimal separator, digit grouping, etc. used to show and parse the value.
+    ///
+    /// This has no effect on a [`Self::custom_formatter`] or [`Self::custom_parser`].
+    ///
+    /// Default: [`crate::Style::number_format`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut fraction: f32 = 0.25;
+    /// ui.add(egui::DragValue::new(&mut fraction)
+    ///     .range(0.0..=1.0)
+    ///     .speed(0.01)
+    ///     .number_format(egui::style::NumberFormat::european().percent(true)));
+    /// # });
+    /// ```
+    #[inline]
+    pub fn number_format(mut self, number_format: NumberFormat) -> Self {
+        self.number_format = Some(number_format);
+        self
+    }
+
+    /// The unit the value is stored in.
+    ///
+    /// The value is shown in [`Self::display_unit`], or else in the unit preferred by
+    /// [`crate::Style::display_units`], or else in this unit, followed by the unit symbol.
+    /// The user can type a value in any unit of the same quantity, e.g. `2 in` or `5 cm`.
+    ///
+    /// [`Self::speed`] and [`Self::range`] are in this unit.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut angle_in_radians: f32 = 0.0;
+    /// ui.add(
+    ///     egui::DragValue::new(&mut angle_in_radians)
+    ///         .unit(egui::Unit::Radians)
+    ///         .display_unit(egui::Unit::Degrees),
+    /// );
+    /// # });
+    /// ```
+    #[inline]
+    pub fn unit(mut self, unit: Unit) -> Self {
+        self.unit = Some(unit);
+        self
+    }
+
+    /// The unit to show and edit the value in.
+    ///
+    /// Only has an effect together with [`Self::unit`], and only if both units measure the same quantity.
+    ///
+    /// Default: [`crate::Style::display_units`].
+    #[inline]
+    pub fn display_unit(mut self, display_unit: Unit) -> Self {
+        self.display_unit = Some(display_unit);
+        self
+    }
+
     /// Set `custom_formatter` and `custom_parser` to display and parse numbers as binary integers. Floating point
     /// numbers are *not* supported.
     ///
@@ -424,24 +488,63 @@ impl<'a> DragValue<'a> {
         self.update_while_editing = update;
         self
     }
+
+    /// Lock the pointer while dragging, so the value can be dragged
+    /// across large ranges without the cursor hitting the edge of the screen.
+    ///
+    /// See [`Response::request_pointer_lock`].
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn infinite_drag(mut self, infinite_drag: bool) -> Self {
+        self.infinite_drag = infinite_drag;
+        self
+    }
 }
 
 impl Widget for DragValue<'_> {
     fn ui(self, ui: &mut Ui) -> Response {
         let Self {
             mut get_set_value,
-            speed,
-            range,
+            mut speed,
+            mut range,
             clamp_existing_to_range,
             prefix,
-            suffix,
+            mut suffix,
             min_decimals,
-            max_decimals,
+            mut max_decimals,
             custom_formatter,
             custom_parser,
+            number_format,
+            unit,
+            display_unit,
             update_while_editing,
+            infinite_drag,
         } = self;
 
+        let display_unit = unit.map(|unit| {
+            display_unit
+                .or_else(|| ui.style().display_units.get(unit.quantity()))
+                .filter(|display_unit| display_unit.quantity() == unit.quantity())
+                .unwrap_or(unit)
+        });
+        if let (Some(unit), Some(display_unit)) = (unit, display_unit) {
+            // From here on everything is in the display unit:
+            let factor = unit.in_base_unit() / display_unit.in_base_unit();
+            if factor != 1.0 {
+                let mut get_set_stored = get_set_value;
+                get_set_value =
+                    Box::new(move |v: Option<f64>| get_set_stored(v.map(|v| v / factor)) * factor);
+                speed *= factor;
+                range = range.start() * factor..=range.end() * factor;
+
+                // E.g. a whole number of bytes needs decimals when shown in MiB:
+                let extra_decimals = (-factor.log10()).ceil().max(0.0) as usize;
+                max_decimals = max_decimals.map(|max_decimals| max_decimals + extra_decimals);
+            }
+            suffix = format!("{}{suffix}", display_unit.suffix());
+        }
+
         let shift = ui.input(|i| i.modifiers.shift_only());
         // The widget has the same ID whether it's in edit or button mode.
         let id = ui.next_auto_id();
@@ -511,6 +614,10 @@ impl Widget for DragValue<'_> {
             });
         }
 
+        if let Some(new_value) = ui.ctx().take_macro_value(id) {
+            value = new_value;
+        }
+
         if clamp_existing_to_range {
             value = clamp_value_to_range(value, range.clone());
         }
@@ -525,12 +632,15 @@ impl Widget for DragValue<'_> {
             ui.data_mut(|data| data.remove::<String>(id));
         }
 
+        let number_format = number_format.unwrap_or(ui.style().number_format);
+
         let value_text = match custom_formatter {
             Some(custom_formatter) => custom_formatter(value, auto_decimals..=max_decimals),
-            None => ui
-                .style()
-                .number_formatter
-                .format(value, auto_decimals..=max_decimals),
+            None => number_format.format(
+                &ui.style().number_formatter,
+                value,
+                auto_decimals..=max_decimals,
+            ),
         };
 
         let text_style = ui.style().drag_value_text_style.clone();
@@ -540,7 +650,7 @@ impl Widget for DragValue<'_> {
             if let Some(value_text) = value_text {
                 // We were editing the value as text last frame, but lost focus.
                 // Make sure we applied the last text value:
-                let parsed_value = parse(&custom_parser, &value_text);
+                let parsed_value = parse(&custom_parser, &number_format, display_unit, &value_text);
                 if let Some(mut parsed_value) = parsed_value {
                     // User edits always clamps:
                     parsed_value = clamp_value_to_range(parsed_value, range.clone());
@@ -582,7 +692,7 @@ impl Widget for DragValue<'_> {
                 response.lost_focus() && !ui.input(|i| i.key_pressed(Key::Escape))
             };
             if update {
-                let parsed_value = parse(&custom_parser, &value_text);
+                let parsed_value = parse(&custom_parser, &number_format, display_unit, &value_text);
                 if let Some(mut parsed_value) = parsed_value {
                     // User edits always clamps:
                     parsed_value = clamp_value_to_range(parsed_value, range.clone());
@@ -625,6 +735,18 @@ impl Widget for DragValue<'_> {
                 ui.data_mut(|data| data.remove::<f64>(id));
             }
 
+            if response.drag_started() {
+                if infinite_drag {
+                    response.request_pointer_lock();
+                } else {
+                    // Keep dragging even if the pointer leaves the window:
+                    response.request_pointer_capture();
+                }
+            } else if response.drag_stopped() {
+                response.release_pointer_lock();
+                response.release_pointer_capture();
+            }
+
             if response.clicked() {
                 ui.data_mut(|data| data.remove::<String>(id));
                 ui.memory_mut(|mem| mem.request_focus(id));
@@ -632,7 +754,12 @@ impl Widget for DragValue<'_> {
             } else if response.dragged() {
                 ui.ctx().set_cursor_icon(cursor_icon);
 
-                let mdelta = response.drag_delta();
+                let mdelta = if response.has_pointer_lock() {
+                    // The cursor may be held in place, so use the raw mouse motion:
+                    response.drag_motion()
+                } else {
+                    response.drag_delta()
+                };
                 let delta_points = mdelta.x - mdelta.y; // Increase to the right and up
 
                 let speed = if is_slow_speed { speed / 10.0 } else { speed };
@@ -663,11 +790,14 @@ impl Widget for DragValue<'_> {
             response
         };
 
-        if get(&mut get_set_value) != old_value {
+        let value = get(&mut get_set_value);
+        if value != old_value {
             response.mark_changed();
         }
 
-        response.widget_info(|| WidgetInfo::drag_value(ui.is_enabled(), value));
+        response.widget_info(|| {
+            WidgetInfo::drag_value(ui.is_enabled(), value).with_prev_value(old_value)
+        });
 
         #[cfg(feature = "accesskit")]
         ui.ctx().accesskit_node_builder(response.id, |builder| {
@@ -720,26 +850,23 @@ impl Widget for DragValue<'_> {
     }
 }
 
-fn parse(custom_parser: &Option<NumParser<'_>>, value_text: &str) -> Option<f64> {
-    match &custom_parser {
-        Some(parser) => parser(value_text),
-        None => default_parser(value_text),
+fn parse(
+    custom_parser: &Option<NumParser<'_>>,
+    number_format: &NumberFormat,
+    display_unit: Option<Unit>,
+    value_text: &str,
+) -> Option<f64> {
+    match (&custom_parser, display_unit) {
+        (Some(parser), _) => parser(value_text),
+        (None, Some(unit)) => unit.parse_with_suffix(value_text, |text| number_format.parse(text)),
+        (None, None) => number_format.parse(value_text),
     }
 }
 
-/// The default egui parser of numbers.
-///
-/// It ignored whitespaces anywhere in the input, and treats the special minus character (U+2212) as a normal minus.
+/// The default egui parser of numbers, when [`crate::Style::number_format`] hasn't been changed.
+#[cfg(test)]
 fn default_parser(text: &str) -> Option<f64> {
-    let text: String = text
-        .chars()
-        // Ignore whitespace (trailing, leading, and thousands separators):
-        .filter(|c| !c.is_whitespace())
-        // Replace special minus character with normal minus (hyphen):
-        .map(|c| if c == '−' { '-' } else { c })
-        .collect();
-
-    text.parse().ok()
+    NumberFormat::PLAIN.parse(text)
 }
 
 /// Clamp the given value with careful handling of negative zero, and other corner cases.
@@ -827,4 +954,83 @@ mod tests {
             "Should handle special minus character (https://www.compart.com/en/unicode/U+2212)"
         );
     }
+
+    #[test]
+    fn test_infinite_drag() {
+        use crate::{
+            CentralPanel, Context, DragValue, Event, PointerButton, Pos2, RawInput, Rect, Slider,
+            Vec2, ViewportCommand, ViewportId, pos2, vec2,
+        };
+
+        for use_slider in [false, true] {
+            let ctx = Context::default();
+            let run = |value: &mut f64, events: Vec<Event>| {
+                let input = RawInput {
+                    events,
+                    screen_rect: Some(Rect::from_min_size(Pos2::ZERO, Vec2::splat(400.0))),
+                    ..Default::default()
+                };
+                let mut response = None;
+                let output = ctx.run(input, |ctx| {
+                    CentralPanel::default().show(ctx, |ui| {
+                        response = Some(if use_slider {
+                            ui.add(
+                                Slider::new(value, 0.0..=1000.0)
+                                    .drag_value_speed(1.0)
+                                    .infinite_drag(true),
+                            )
+                        } else {
+                            ui.add(DragValue::new(value).speed(1.0).infinite_drag(true))
+                        });
+                    });
+                });
+                let commands = output.viewport_output[&ViewportId::ROOT].commands.clone();
+                (response.unwrap(), commands)
+            };
+            let button = |pos, pressed| Event::PointerButton {
+                pos,
+                button: PointerButton::Primary,
+                pressed,
+                modifiers: Default::default(),
+            };
+
+            let mut value = 0.0;
+            let (response, _) = run(&mut value, vec![]);
+            // The slider's `DragValue` is to the right of the rail:
+            let start = response.rect.right_center() - vec2(5.0, 0.0);
+
+            run(
+                &mut value,
+                vec![Event::PointerMoved(start), button(start, true)],
+            );
+            // The drag, and with it the lock, starts once the pointer moves:
+            let lock_origin = start + vec2(10.0, 0.0);
+            run(&mut value, vec![Event::PointerMoved(lock_origin)]);
+            assert!(ctx.pointer_lock_id().is_some());
+            assert!(0.0 < value);
+
+            // The integration could not lock the cursor, so it reaches the edge of the screen
+            // and egui moves it back to the center:
+            let (_, commands) = run(&mut value, vec![Event::PointerMoved(pos2(395.0, start.y))]);
+            let center = pos2(200.0, 200.0);
+            assert!(commands.contains(&ViewportCommand::CursorPosition(center)));
+
+            // Landing in the center does not change the value:
+            let value_before_warp = value;
+            run(&mut value, vec![Event::PointerMoved(center)]);
+            assert_eq!(value, value_before_warp);
+
+            // Dragging on keeps increasing the value:
+            run(
+                &mut value,
+                vec![Event::PointerMoved(center + vec2(10.0, 0.0))],
+            );
+            assert!(value_before_warp < value);
+
+            // Releasing unlocks the pointer and moves it back to where the lock started:
+            let (_, commands) = run(&mut value, vec![button(center + vec2(10.0, 0.0), false)]);
+            assert!(ctx.pointer_lock_id().is_none());
+            assert!(commands.contains(&ViewportCommand::CursorPosition(lock_origin)));
+        }
+    }
 }
@@ -0,0 +1,700 @@
+//! A file browser written in pure egui.
+//!
+//! It works on top of the [`FileSystem`] trait, so it can browse anything that looks like a
+//! file system: the real one (with the `std-fs` feature, see `StdFileSystem`),
+//! a virtual file system on the web, the contents of an archive, etc.
+
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    Button, CollapsingHeader, ComboBox, FileDialogFilter, Grid, Id, Modifiers, Response,
+    ScrollArea, TextEdit, Ui, vec2,
+};
+
+/// A file or directory, as listed by [`FileSystem::read_dir`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FsEntry {
+    pub path: PathBuf,
+
+    /// The last component of [`Self::path`].
+    pub name: String,
+
+    pub is_dir: bool,
+
+    /// Hidden entries are only shown if the user asks for them.
+    pub hidden: bool,
+
+    /// Size in bytes, for files.
+    pub size: Option<u64>,
+
+    /// When the entry was last modified, in seconds since the Unix epoch.
+    pub modified: Option<u64>,
+}
+
+/// Something that can be browsed with a [`FileBrowser`].
+pub trait FileSystem: Send + Sync {
+    /// List the contents of a directory, in no particular order.
+    ///
+    /// # Errors
+    /// If the directory can't be read, e.g. because it doesn't exist.
+    fn read_dir(&self, dir: &Path) -> std::io::Result<Vec<FsEntry>>;
+
+    /// The top-level directories to show in the directory tree.
+    fn roots(&self) -> Vec<PathBuf> {
+        vec![PathBuf::from("/")]
+    }
+
+    /// Where to start browsing, if nothing else is specified.
+    fn initial_dir(&self) -> PathBuf {
+        self.roots().into_iter().next().unwrap_or_default()
+    }
+}
+
+/// The real file system, using [`std::fs`].
+#[cfg(feature = "std-fs")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct StdFileSystem;
+
+#[cfg(feature = "std-fs")]
+impl FileSystem for StdFileSystem {
+    fn read_dir(&self, dir: &Path) -> std::io::Result<Vec<FsEntry>> {
+        let mut entries = vec![];
+        for entry in std::fs::read_dir(dir)? {
+            let entry = entry?;
+            let path = entry.path();
+            let name = entry.file_name().to_string_lossy().into_owned();
+            // Follow symlinks:
+            let metadata = std::fs::metadata(&path).ok();
+            let is_dir = metadata.as_ref().is_some_and(|m| m.is_dir());
+            entries.push(FsEntry {
+                hidden: name.starts_with('.'),
+                size: metadata.as_ref().filter(|_| !is_dir).map(|m| m.len()),
+                modified: metadata
+                    .and_then(|m| m.modified().ok())
+                    .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+                    .map(|d| d.as_secs()),
+                path,
+                name,
+                is_dir,
+            });
+        }
+        Ok(entries)
+    }
+
+    fn roots(&self) -> Vec<PathBuf> {
+        let mut roots = vec![];
+        if let Some(home) = std::env::var_os("HOME").or_else(|| std::env::var_os("USERPROFILE")) {
+            roots.push(PathBuf::from(home));
+        }
+        if cfg!(windows) {
+            roots.extend(
+                (b'A'..=b'Z')
+                    .map(|drive| PathBuf::from(format!("{}:\\", drive as char)))
+                    .filter(|drive| drive.exists()),
+            );
+        } else {
+            roots.push(PathBuf::from("/"));
+        }
+        roots
+    }
+
+    fn initial_dir(&self) -> PathBuf {
+        std::env::current_dir()
+            .unwrap_or_else(|_| self.roots().into_iter().next().unwrap_or_default())
+    }
+}
+
+/// What to sort the file list by.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SortColumn {
+    #[default]
+    Name,
+    Size,
+    Modified,
+}
+
+/// What happened in [`FileBrowser::show`].
+pub struct FileBrowserResponse {
+    pub response: Response,
+
+    /// A file was double-clicked.
+    ///
+    /// Double-clicking a folder navigates into it instead.
+    pub activated: Option<PathBuf>,
+
+    /// The selection changed, see [`FileBrowser::selected`].
+    pub selection_changed: bool,
+
+    /// The user navigated to another directory, see [`FileBrowser::current_dir`].
+    pub dir_changed: bool,
+}
+
+/// A file browser with a directory tree, breadcrumbs, and a sortable file list.
+///
+/// Unlike most widgets this holds state, so you need to store it somewhere
+/// and call [`Self::show`] each frame.
+///
+/// ```
+/// # use egui::widgets::file_browser::{FileBrowser, FileSystem, FsEntry};
+/// # struct MyFs;
+/// # impl FileSystem for MyFs {
+/// #     fn read_dir(&self, _: &std::path::Path) -> std::io::Result<Vec<FsEntry>> { Ok(vec![]) }
+/// # }
+/// # egui::__run_test_ui(|ui| {
+/// let mut browser = FileBrowser::new(MyFs).multiple(true);
+/// let response = browser.show(ui);
+/// if let Some(path) = response.activated {
+///     // …
+/// }
+/// # });
+/// ```
+pub struct FileBrowser {
+    fs: Box<dyn FileSystem>,
+    id_salt: Id,
+    current_dir: PathBuf,
+
+    /// `None` means it needs to be (re)loaded.
+    listing: Option<Result<Vec<FsEntry>, String>>,
+
+    /// Sub-directories of the directories expanded in the tree.
+    tree: HashMap<PathBuf, Vec<FsEntry>>,
+
+    selected: Vec<PathBuf>,
+
+    /// Where a shift-click selection starts.
+    anchor: Option<PathBuf>,
+
+    show_hidden: bool,
+    show_tree: bool,
+    name_filter: String,
+    filters: Vec<FileDialogFilter>,
+    active_filter: Option<usize>,
+    sort_column: SortColumn,
+    sort_ascending: bool,
+    pick_folders: bool,
+    multiple: bool,
+}
+
+impl FileBrowser {
+    pub fn new(fs: impl FileSystem + 'static) -> Self {
+        let current_dir = fs.initial_dir();
+        Self {
+            fs: Box::new(fs),
+            id_salt: Id::new("file_browser"),
+            current_dir,
+            listing: None,
+            tree: Default::default(),
+            selected: vec![],
+            anchor: None,
+            show_hidden: false,
+            show_tree: true,
+            name_filter: String::new(),
+            filters: vec![],
+            active_filter: None,
+            sort_column: SortColumn::Name,
+            sort_ascending: true,
+            pick_folders: false,
+            multiple: false,
+        }
+    }
+
+    /// Browse the real file system.
+    #[cfg(feature = "std-fs")]
+    pub fn std() -> Self {
+        Self::new(StdFileSystem)
+    }
+
+    /// Needed if you show more than one [`FileBrowser`] in the same [`Ui`].
+    #[inline]
+    pub fn id_salt(mut self, id_salt: impl std::hash::Hash) -> Self {
+        self.id_salt = Id::new(id_salt);
+        self
+    }
+
+    /// The directory to start in.
+    #[inline]
+    pub fn with_directory(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.set_current_dir(dir);
+        self
+    }
+
+    /// Let the user choose to only show files matching one of these.
+    ///
+    /// The first one is active from the start.
+    #[inline]
+    pub fn with_filters(mut self, filters: Vec<FileDialogFilter>) -> Self {
+        self.active_filter = (!filters.is_empty()).then_some(0);
+        self.filters = filters;
+        self
+    }
+
+    /// Select folders instead of files. Files are then hidden.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn pick_folders(mut self, pick_folders: bool) -> Self {
+        self.pick_folders = pick_folders;
+        self
+    }
+
+    /// Can more than one entry be selected, using ctrl/cmd-click and shift-click?
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn multiple(mut self, multiple: bool) -> Self {
+        self.multiple = multiple;
+        self
+    }
+
+    /// Show the directory tree to the left of the file list?
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn show_tree(mut self, show_tree: bool) -> Self {
+        self.show_tree = show_tree;
+        self
+    }
+
+    /// Show hidden files from the start?
+    ///
+    /// The user can always toggle this.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn show_hidden(mut self, show_hidden: bool) -> Self {
+        self.show_hidden = show_hidden;
+        self
+    }
+
+    /// The directory whose contents are shown.
+    pub fn current_dir(&self) -> &Path {
+        &self.current_dir
+    }
+
+    /// Navigate to another directory, clearing the selection.
+    pub fn set_current_dir(&mut self, dir: impl Into<PathBuf>) {
+        self.current_dir = dir.into();
+        self.listing = None;
+        self.selected.clear();
+        self.anchor = None;
+    }
+
+    /// The selected files (or folders, if [`Self::pick_folders`]).
+    pub fn selected(&self) -> &[PathBuf] {
+        &self.selected
+    }
+
+    /// Read the directories again, e.g. after a file has been created.
+    pub fn refresh(&mut self) {
+        self.listing = None;
+        self.tree.clear();
+    }
+
+    /// Show the browser, filling the available space.
+    pub fn show(&mut self, ui: &mut Ui) -> FileBrowserResponse {
+        let id = ui.make_persistent_id(self.id_salt);
+        let mut navigate_to = None;
+        let mut activated = None;
+        let mut selection_changed = false;
+
+        let response = ui
+            .vertical(|ui| {
+                self.toolbar_ui(ui, id, &mut navigate_to);
+
+                let body_height = ui.available_height();
+                ui.horizontal_top(|ui| {
+                    if self.show_tree {
+                        ui.allocate_ui(vec2(180.0, body_height), |ui| {
+                            ScrollArea::both()
+                                .id_salt(id.with("tree"))
+                                .auto_shrink(false)
+                                .show(ui, |ui| {
+                                    for root in self.fs.roots() {
+                                        self.tree_ui(ui, id, &root, &mut navigate_to);
+                                    }
+                                });
+                        });
+                        ui.separator();
+                    }
+
+                    ui.vertical(|ui| {
+                        ScrollArea::vertical()
+                            .id_salt(id.with("list"))
+                            .auto_shrink(false)
+                            .max_height(body_height)
+                            .show(ui, |ui| {
+                                self.list_ui(
+                                    ui,
+                                    id,
+                                    &mut navigate_to,
+                                    &mut activated,
+                                    &mut selection_changed,
+                                );
+                            });
+                    });
+                });
+            })
+            .response;
+
+        let dir_changed = navigate_to.is_some();
+        if let Some(dir) = navigate_to {
+            selection_changed |= !self.selected.is_empty();
+            self.set_current_dir(dir);
+        }
+
+        FileBrowserResponse {
+            response,
+            activated,
+            selection_changed,
+            dir_changed,
+        }
+    }
+
+    fn toolbar_ui(&mut self, ui: &mut Ui, id: Id, navigate_to: &mut Option<PathBuf>) {
+        ui.horizontal(|ui| {
+            let parent = self.current_dir.parent().map(Path::to_path_buf);
+            if ui
+                .add_enabled(parent.is_some(), Button::new("⬆"))
+                .on_hover_text(ui.ctx().tr("file_browser.parent", "Parent directory"))
+                .clicked()
+            {
+                *navigate_to = parent;
+            }
+            if ui
+                .button("🔄")
+                .on_hover_text(ui.ctx().tr("file_browser.refresh", "Refresh"))
+                .clicked()
+            {
+                self.refresh();
+            }
+
+            // Breadcrumbs:
+            let ancestors: Vec<&Path> = self.current_dir.ancestors().collect();
+            for (i, dir) in ancestors.iter().rev().enumerate() {
+                if 0 < i {
+                    ui.weak("›");
+                }
+                let name = dir
+                    .file_name()
+                    .map_or_else(|| dir.to_string_lossy(), |name| name.to_string_lossy());
+                let is_current = i + 1 == ancestors.len();
+                if ui.add(Button::new(name).selected(is_current)).clicked() && !is_current {
+                    *navigate_to = Some(dir.to_path_buf());
+                }
+            }
+        });
+
+        ui.horizontal(|ui| {
+            ui.add(
+                TextEdit::singleline(&mut self.name_filter)
+                    .hint_text(ui.ctx().tr("file_browser.filter", "Filter"))
+                    .desired_width(160.0),
+            );
+
+            if !self.filters.is_empty() {
+                let all_files = ui.ctx().tr("file_browser.all_files", "All files");
+                let selected_text = self
+                    .active_filter
+                    .and_then(|i| self.filters.get(i))
+                    .map_or_else(|| all_files.clone(), |filter| filter.name.clone());
+                ComboBox::from_id_salt(id.with("filter"))
+                    .selected_text(selected_text)
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(&mut self.active_filter, None, all_files);
+                        for (i, filter) in self.filters.iter().enumerate() {
+                            ui.selectable_value(&mut self.active_filter, Some(i), &filter.name);
+                        }
+                    });
+            }
+
+            ui.checkbox(
+                &mut self.show_hidden,
+                ui.ctx().tr("file_browser.show_hidden", "Show hidden"),
+            );
+        });
+
+        ui.separator();
+    }
+
+    fn tree_ui(&mut self, ui: &mut Ui, id: Id, dir: &Path, navigate_to: &mut Option<PathBuf>) {
+        let name = dir
+            .file_name()
+            .map_or_else(|| dir.to_string_lossy(), |name| name.to_string_lossy())
+            .into_owned();
+        let is_current = dir == self.current_dir;
+        let default_open = self.current_dir.starts_with(dir) && !is_current;
+
+        let header = CollapsingHeader::new(format!("🗀 {name}"))
+            .id_salt(id.with(dir))
+            .default_open(default_open)
+            .show(ui, |ui| {
+                let fs = &self.fs;
+                let subdirs: Vec<PathBuf> = self
+                    .tree
+                    .entry(dir.to_path_buf())
+                    .or_insert_with(|| {
+                        let mut subdirs: Vec<FsEntry> = fs
+                            .read_dir(dir)
+                            .unwrap_or_default()
+                            .into_iter()
+                            .filter(|entry| entry.is_dir)
+                            .collect();
+                        subdirs.sort_by(|a, b| compare_names(&a.name, &b.name));
+                        subdirs
+                    })
+                    .iter()
+                    .filter(|entry| self.show_hidden || !entry.hidden)
+                    .map(|entry| entry.path.clone())
+                    .collect();
+                for subdir in subdirs {
+                    self.tree_ui(ui, id, &subdir, navigate_to);
+                }
+            });
+
+        if header.header_response.clicked() && !is_current {
+            *navigate_to = Some(dir.to_path_buf());
+        }
+    }
+
+    fn list_ui(
+        &mut self,
+        ui: &mut Ui,
+        id: Id,
+        navigate_to: &mut Option<PathBuf>,
+        activated: &mut Option<PathBuf>,
+        selection_changed: &mut bool,
+    ) {
+        let entries = match self.listing.get_or_insert_with(|| {
+            self.fs
+                .read_dir(&self.current_dir)
+                .map_err(|err| err.to_string())
+        }) {
+            Ok(entries) => entries.clone(),
+            Err(err) => {
+                ui.colored_label(ui.visuals().error_fg_color, err.as_str());
+                return;
+            }
+        };
+
+        let entries = self.visible_entries(entries);
+        let visible_paths: Vec<PathBuf> = entries.iter().map(|entry| entry.path.clone()).collect();
+
+        Grid::new(id.with("list_grid"))
+            .num_columns(3)
+            .striped(true)
+            .show(ui, |ui| {
+                for (column, key, english) in [
+                    (SortColumn::Name, "file_browser.name", "Name"),
+                    (SortColumn::Size, "file_browser.size", "Size"),
+                    (SortColumn::Modified, "file_browser.modified", "Modified"),
+                ] {
+                    let mut label = ui.ctx().tr(key, english);
+                    if self.sort_column == column {
+                        label += if self.sort_ascending { " ⏶" } else { " ⏷" };
+                    }
+                    if ui.add(Button::new(label).frame(false)).clicked() {
+                        if self.sort_column == column {
+                            self.sort_ascending = !self.sort_ascending;
+                        } else {
+                            self.sort_column = column;
+                            self.sort_ascending = true;
+                        }
+                    }
+                }
+                ui.end_row();
+
+                for (index, entry) in entries.iter().enumerate() {
+                    let icon = if entry.is_dir { "🗀" } else { "🗋" };
+                    let is_selected = self.selected.contains(&entry.path);
+                    let response = ui.add(Button::selectable(
+                        is_selected,
+                        format!("{icon} {}", entry.name),
+                    ));
+
+                    let selectable = entry.is_dir == self.pick_folders;
+                    if response.double_clicked() {
+                        if entry.is_dir {
+                            *navigate_to = Some(entry.path.clone());
+                        } else {
+                            *activated = Some(entry.path.clone());
+                        }
+                    } else if response.clicked() && selectable {
+                        let modifiers = ui.input(|i| i.modifiers);
+                        select(
+                            &visible_paths,
+                            index,
+                            modifiers,
+                            self.multiple,
+                            &mut self.selected,
+                            &mut self.anchor,
+                        );
+                        *selection_changed = true;
+                    }
+
+                    ui.label(entry.size.map(format_size).unwrap_or_default());
+                    ui.label(entry.modified.map(format_unix_time).unwrap_or_default());
+                    ui.end_row();
+                }
+            });
+    }
+
+    /// Filter and sort the entries according to the user's settings.
+    fn visible_entries(&self, mut entries: Vec<FsEntry>) -> Vec<FsEntry> {
+        let name_filter = self.name_filter.to_lowercase();
+        let extension_filter = self.active_filter.and_then(|i| self.filters.get(i));
+
+        entries.retain(|entry| {
+            (self.show_hidden || !entry.hidden)
+                && (entry.is_dir || !self.pick_folders)
+                && (name_filter.is_empty() || entry.name.to_lowercase().contains(&name_filter))
+                && (entry.is_dir
+                    || extension_filter.is_none_or(|filter| filter.matches(&entry.path)))
+        });
+
+        entries.sort_by(|a, b| {
+            // Directories always go first:
+            b.is_dir.cmp(&a.is_dir).then_with(|| {
+                let ordering = match self.sort_column {
+                    SortColumn::Name => compare_names(&a.name, &b.name),
+                    SortColumn::Size => a.size.cmp(&b.size),
+                    SortColumn::Modified => a.modified.cmp(&b.modified),
+                };
+                if self.sort_ascending {
+                    ordering
+                } else {
+                    ordering.reverse()
+                }
+            })
+        });
+
+        entries
+    }
+}
+
+/// Update the selection after a click on `visible[index]`, the way file managers do it.
+fn select(
+    visible: &[PathBuf],
+    index: usize,
+    modifiers: Modifiers,
+    multiple: bool,
+    selected: &mut Vec<PathBuf>,
+    anchor: &mut Option<PathBuf>,
+) {
+    let clicked = &visible[index];
+
+    if multiple && modifiers.shift {
+        let anchor_index = anchor
+            .as_ref()
+            .and_then(|anchor| visible.iter().position(|path| path == anchor))
+            .unwrap_or(index);
+        let range = anchor_index.min(index)..=anchor_index.max(index);
+        if !modifiers.command {
+            selected.clear();
+        }
+        for path in &visible[range] {
+            if !selected.contains(path) {
+                selected.push(path.clone());
+            }
+        }
+        // Keep the anchor, so the user can adjust the range.
+    } else if multiple && modifiers.command {
+        if let Some(i) = selected.iter().position(|path| path == clicked) {
+            selected.remove(i);
+        } else {
+            selected.push(clicked.clone());
+        }
+        *anchor = Some(clicked.clone());
+    } else {
+        selected.clear();
+        selected.push(clicked.clone());
+        *anchor = Some(clicked.clone());
+    }
+}
+
+fn compare_names(a: &str, b: &str) -> std::cmp::Ordering {
+    a.to_lowercase()
+        .cmp(&b.to_lowercase())
+        .then_with(|| a.cmp(b))
+}
+
+fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["kB", "MB", "GB", "TB"];
+    if bytes < 1000 {
+        return format!("{bytes} B");
+    }
+    let mut size = bytes as f64 / 1000.0;
+    let mut unit = 0;
+    while 1000.0 <= size && unit + 1 < UNITS.len() {
+        size /= 1000.0;
+        unit += 1;
+    }
+    format!("{size:.1} {}", UNITS[unit])
+}
+
+/// Format as `YYYY-MM-DD HH:MM` in UTC.
+fn format_unix_time(secs: u64) -> String {
+    // See http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let days = (secs / 86_400) as i64;
+    let secs_of_day = secs % 86_400;
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02}",
+        secs_of_day / 3600,
+        secs_of_day % 3600 / 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format() {
+        assert_eq!(format_size(999), "999 B");
+        assert_eq!(format_size(1_500), "1.5 kB");
+        assert_eq!(format_size(2_000_000), "2.0 MB");
+        assert_eq!(format_unix_time(0), "1970-01-01 00:00");
+        assert_eq!(format_unix_time(1_700_000_000), "2023-11-14 22:13");
+        assert_eq!(format_unix_time(951_825_600), "2000-02-29 12:00");
+    }
+
+    #[test]
+    fn test_select() {
+        let visible: Vec<PathBuf> = ["a", "b", "c", "d"].iter().map(PathBuf::from).collect();
+        let mut selected = vec![];
+        let mut anchor = None;
+        let shift = Modifiers::SHIFT;
+        let command = Modifiers::COMMAND;
+
+        select(
+            &visible,
+            1,
+            Modifiers::NONE,
+            true,
+            &mut selected,
+            &mut anchor,
+        );
+        assert_eq!(selected, [visible[1].clone()]);
+
+        select(&visible, 3, shift, true, &mut selected, &mut anchor);
+        assert_eq!(selected, &visible[1..=3]);
+
+        select(&visible, 2, command, true, &mut selected, &mut anchor);
+        assert_eq!(selected, [visible[1].clone(), visible[3].clone()]);
+
+        // Without `multiple`, modifiers are ignored:
+        select(&visible, 0, shift, false, &mut selected, &mut anchor);
+        assert_eq!(selected, [visible[0].clone()]);
+    }
+}
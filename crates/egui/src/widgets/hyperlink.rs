@@ -47,7 +47,7 @@ impl Widget for Link {
             let color = ui.visuals().hyperlink_color;
             let visuals = ui.style().interact(&response);
 
-            let underline = if response.hovered() || response.has_focus() {
+            let underline = if response.hovered() {
                 Stroke::new(visuals.fg_stroke.width, color)
             } else {
                 Stroke::NONE
@@ -934,7 +934,10 @@ fn animated_image_frame_index(ctx: &Context, uri: &str) -> usize {
 
             if pos_ms < cumulative_ms {
                 let ms_until_next_frame = cumulative_ms - pos_ms;
-                ctx.request_repaint_after(Duration::from_millis(ms_until_next_frame as u64));
+                ctx.request_repaint_after_because(
+                    Duration::from_millis(ms_until_next_frame as u64),
+                    crate::RepaintReason::Animation,
+                );
                 return index;
             }
         }
@@ -116,7 +116,7 @@ impl Widget for ImageButton<'_> {
                     selection.stroke,
                 )
             } else if self.frame {
-                let visuals = ui.style().interact(&response);
+                let visuals = ui.interact_visuals(&response);
                 let expansion = Vec2::splat(visuals.expansion);
                 (
                     expansion,
@@ -291,7 +291,7 @@ impl Widget for Label {
                 ui.style().visuals.text_color()
             };
 
-            let underline = if response.has_focus() || response.highlighted() {
+            let underline = if response.highlighted() {
                 Stroke::new(1.0, response_color)
             } else {
                 Stroke::NONE
@@ -10,6 +10,7 @@ mod button;
 mod checkbox;
 pub mod color_picker;
 pub(crate) mod drag_value;
+pub mod file_browser;
 mod hyperlink;
 mod image;
 mod image_button;
@@ -21,6 +22,7 @@ mod separator;
 mod slider;
 mod spinner;
 pub mod text_edit;
+pub mod unit;
 
 #[expect(deprecated)]
 pub use self::selected_label::SelectableLabel;
@@ -28,6 +30,7 @@ pub use self::{
     button::Button,
     checkbox::Checkbox,
     drag_value::DragValue,
+    file_browser::FileBrowser,
     hyperlink::{Hyperlink, Link},
     image::{
         FrameDurations, Image, ImageFit, ImageOptions, ImageSize, ImageSource,
@@ -36,11 +39,12 @@ pub use self::{
     image_button::ImageButton,
     label::Label,
     progress_bar::ProgressBar,
-    radio_button::RadioButton,
+    radio_button::{RadioButton, RadioGroup},
     separator::Separator,
     slider::{Slider, SliderClamping, SliderOrientation},
     spinner::Spinner,
     text_edit::{TextBuffer, TextEdit},
+    unit::Unit,
 };
 
 // ----------------------------------------------------------------------------
@@ -135,7 +135,10 @@ impl Widget for ProgressBar {
 
         if ui.is_rect_visible(response.rect) {
             if animate {
-                ui.ctx().request_repaint();
+                ui.ctx().request_repaint_after_because(
+                    std::time::Duration::ZERO,
+                    crate::RepaintReason::Animation,
+                );
             }
 
             let visuals = ui.style().visuals.clone();
@@ -158,13 +161,21 @@ impl Widget for ProgressBar {
                 bright
             };
 
-            ui.painter().rect_filled(
-                inner_rect,
-                corner_radius,
-                Color32::from(
-                    Rgba::from(fill.unwrap_or(visuals.selection.bg_fill)) * color_factor as f32,
+            let intensity = if fill.is_some() {
+                1.0
+            } else {
+                visuals.selection.intensity
+            };
+            ui.painter().add(Shape::hdr(
+                epaint::RectShape::filled(
+                    inner_rect,
+                    corner_radius,
+                    Color32::from(
+                        Rgba::from(fill.unwrap_or(visuals.selection.bg_fill)) * color_factor as f32,
+                    ),
                 ),
-            );
+                intensity,
+            ));
 
             if animate && !has_custom_cr {
                 let n_points = 20;
@@ -1,6 +1,6 @@
 use crate::{
-    Atom, AtomLayout, Atoms, Id, IntoAtoms, NumExt as _, Response, Sense, Ui, Vec2, Widget,
-    WidgetInfo, WidgetType, epaint,
+    Atom, AtomLayout, Atoms, EventFilter, Id, InnerResponse, IntoAtoms, Key, Modifiers,
+    NumExt as _, Response, Sense, Ui, Vec2, Widget, WidgetInfo, WidgetType, epaint,
 };
 
 /// One out of several alternatives, either selected or not.
@@ -26,6 +26,9 @@ use crate::{
 pub struct RadioButton<'a> {
     checked: bool,
     atoms: Atoms<'a>,
+
+    /// Can it get keyboard focus? Only one button in a [`RadioGroup`] can.
+    focusable: bool,
 }
 
 impl<'a> RadioButton<'a> {
@@ -33,13 +36,18 @@ impl<'a> RadioButton<'a> {
         Self {
             checked,
             atoms: atoms.into_atoms(),
+            focusable: true,
         }
     }
 }
 
 impl Widget for RadioButton<'_> {
     fn ui(self, ui: &mut Ui) -> Response {
-        let Self { checked, mut atoms } = self;
+        let Self {
+            checked,
+            mut atoms,
+            focusable,
+        } = self;
 
         let spacing = &ui.spacing();
         let icon_width = spacing.icon_width;
@@ -56,7 +64,11 @@ impl Widget for RadioButton<'_> {
         let text = atoms.text().map(String::from);
 
         let mut prepared = AtomLayout::new(atoms)
-            .sense(Sense::click())
+            .sense(if focusable {
+                Sense::click()
+            } else {
+                Sense::CLICK
+            })
             .min_size(min_size)
             .allocate(ui);
 
@@ -71,7 +83,7 @@ impl Widget for RadioButton<'_> {
 
         if ui.is_rect_visible(prepared.response.rect) {
             // let visuals = ui.style().interact_selectable(&response, checked); // too colorful
-            let visuals = *ui.style().interact(&prepared.response);
+            let visuals = ui.interact_visuals(&prepared.response);
 
             prepared.fallback_text_color = visuals.text_color();
             let response = prepared.paint(ui);
@@ -104,3 +116,163 @@ impl Widget for RadioButton<'_> {
         }
     }
 }
+
+/// A set of radio buttons that act as one widget, created with [`Ui::radio_group`].
+///
+/// Only one of the buttons can be reached with tab,
+/// and the arrow keys move the selection between them.
+pub struct RadioGroup<'a, Value> {
+    ui: &'a mut Ui,
+    current_value: &'a mut Value,
+
+    /// Did any option match the value last pass?
+    had_selection: bool,
+
+    /// Has any option matched the value this pass?
+    has_selection: bool,
+
+    /// The buttons shown so far this pass.
+    option_ids: Vec<Id>,
+
+    /// Select this option because of the arrow keys.
+    select_index: Option<usize>,
+
+    changed: bool,
+}
+
+/// What a [`RadioGroup`] remembers between passes.
+#[derive(Clone, Default)]
+struct RadioGroupState {
+    option_ids: Vec<Id>,
+    has_selection: bool,
+}
+
+impl<Value: PartialEq> RadioGroup<'_, Value> {
+    pub(crate) fn show(
+        ui: &mut Ui,
+        current_value: &mut Value,
+        add_options: impl FnOnce(&mut RadioGroup<'_, Value>),
+    ) -> Response {
+        let InnerResponse {
+            inner: changed,
+            mut response,
+        } = ui.scope(|ui| {
+            let group_id = ui.unique_id();
+            let state_id = group_id.with("radio_group");
+            let last_state = ui
+                .data(|data| data.get_temp::<RadioGroupState>(state_id))
+                .unwrap_or_default();
+
+            let mut group = RadioGroup {
+                select_index: arrow_key_target(ui, &last_state.option_ids),
+                ui,
+                current_value,
+                had_selection: last_state.has_selection,
+                has_selection: false,
+                option_ids: vec![],
+                changed: false,
+            };
+
+            let ctx = group.ui.ctx().clone();
+            #[cfg(feature = "accesskit")]
+            ctx.accesskit_node_builder(group_id, |node| node.set_role(accesskit::Role::RadioGroup));
+            ctx.with_accessibility_parent(group_id, || add_options(&mut group));
+
+            let RadioGroup {
+                ui,
+                has_selection,
+                option_ids,
+                changed,
+                ..
+            } = group;
+            ui.data_mut(|data| {
+                data.insert_temp(
+                    state_id,
+                    RadioGroupState {
+                        option_ids,
+                        has_selection,
+                    },
+                );
+            });
+            changed
+        });
+
+        if changed {
+            response.mark_changed();
+        }
+        response
+            .widget_info(|| WidgetInfo::labeled(WidgetType::RadioGroup, response.enabled(), ""));
+        response
+    }
+
+    /// Show a [`RadioButton`] that selects `alternative` when clicked,
+    /// or when moved to with the arrow keys.
+    pub fn option<'b>(&mut self, alternative: Value, atoms: impl IntoAtoms<'b>) -> Response {
+        let index = self.option_ids.len();
+        let was_selected = *self.current_value == alternative;
+        let select_with_keyboard = self.select_index == Some(index);
+
+        // The group is a single tab stop: the selected option, or else the first one.
+        let mut radio = RadioButton::new(was_selected || select_with_keyboard, atoms);
+        radio.focusable =
+            was_selected || select_with_keyboard || (index == 0 && !self.had_selection);
+        let mut response = self.ui.add(radio);
+
+        if (select_with_keyboard || response.clicked()) && !was_selected {
+            *self.current_value = alternative;
+            self.changed = true;
+            response.mark_changed();
+        }
+        if select_with_keyboard {
+            response.request_focus();
+        }
+        if response.has_focus() {
+            // The arrow keys move the selection instead of the focus:
+            self.ui.memory_mut(|mem| {
+                mem.set_focus_lock_filter(
+                    response.id,
+                    EventFilter {
+                        tab: false,
+                        horizontal_arrows: true,
+                        vertical_arrows: true,
+                        escape: false,
+                    },
+                );
+            });
+        }
+
+        self.has_selection |= was_selected || self.changed;
+        self.option_ids.push(response.id);
+        response
+    }
+
+    /// The [`Ui`] the options are added to, e.g. for adding labels or changing the layout.
+    pub fn ui(&mut self) -> &mut Ui {
+        self.ui
+    }
+}
+
+/// Which option should be selected because an arrow key was pressed
+/// while one of the options had focus?
+fn arrow_key_target(ui: &Ui, option_ids: &[Id]) -> Option<usize> {
+    let focused = ui.memory(|mem| mem.focused())?;
+    let focused_index = option_ids.iter().position(|&id| id == focused)?;
+
+    let (previous, next) = ui.input_mut(|i| {
+        (
+            i.consume_key(Modifiers::NONE, Key::ArrowUp)
+                | i.consume_key(Modifiers::NONE, Key::ArrowLeft),
+            i.consume_key(Modifiers::NONE, Key::ArrowDown)
+                | i.consume_key(Modifiers::NONE, Key::ArrowRight),
+        )
+    });
+
+    let num_options = option_ids.len();
+    if previous {
+        Some((focused_index + num_options - 1) % num_options)
+    } else if next {
+        Some((focused_index + 1) % num_options)
+    } else {
+        None
+    }
+}
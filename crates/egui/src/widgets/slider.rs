@@ -1,11 +1,13 @@
 #![allow(clippy::needless_pass_by_value)] // False positives with `impl ToString`
 
-use std::ops::RangeInclusive;
+use std::{ops::RangeInclusive, sync::Arc};
 
 use crate::{
-    Color32, DragValue, EventFilter, Key, Label, MINUS_CHAR_STR, NumExt as _, Pos2, Rangef, Rect,
-    Response, Sense, TextStyle, TextWrapMode, Ui, Vec2, Widget, WidgetInfo, WidgetText, emath,
-    epaint, lerp, pos2, remap, remap_clamp, style, style::HandleShape, vec2,
+    Color32, DragValue, EventFilter, Galley, Key, Label, MINUS_CHAR_STR, NumExt as _, Pos2, Rangef,
+    Rect, Response, Sense, Shape, TextStyle, TextWrapMode, Ui, Vec2, Widget, WidgetInfo,
+    WidgetText, emath, epaint, lerp, pos2, remap, remap_clamp, style,
+    style::{HandleShape, NumberFormat},
+    vec2,
 };
 
 use super::drag_value::clamp_value_to_range;
@@ -15,6 +17,12 @@ use super::drag_value::clamp_value_to_range;
 type NumFormatter<'a> = Box<dyn 'a + Fn(f64, RangeInclusive<usize>) -> String>;
 type NumParser<'a> = Box<dyn 'a + Fn(&str) -> Option<f64>>;
 
+/// See [`Slider::custom_mapping`].
+struct SliderMapping<'a> {
+    to_normalized: Box<dyn 'a + Fn(f64) -> f64>,
+    from_normalized: Box<dyn 'a + Fn(f64) -> f64>,
+}
+
 // ----------------------------------------------------------------------------
 
 /// Combined into one function (rather than two) to make it easier
@@ -115,9 +123,15 @@ pub struct Slider<'a> {
     max_decimals: Option<usize>,
     custom_formatter: Option<NumFormatter<'a>>,
     custom_parser: Option<NumParser<'a>>,
+    number_format: Option<NumberFormat>,
     trailing_fill: Option<bool>,
     handle_shape: Option<HandleShape>,
     update_while_editing: bool,
+    infinite_drag: bool,
+    custom_mapping: Option<SliderMapping<'a>>,
+    ticks: Vec<(f64, Option<WidgetText>)>,
+    detents: Vec<f64>,
+    detent_radius: f32,
 }
 
 impl<'a> Slider<'a> {
@@ -162,9 +176,15 @@ impl<'a> Slider<'a> {
             max_decimals: None,
             custom_formatter: None,
             custom_parser: None,
+            number_format: None,
             trailing_fill: None,
             handle_shape: None,
             update_while_editing: true,
+            infinite_drag: false,
+            custom_mapping: None,
+            ticks: Vec::new(),
+            detents: Vec::new(),
+            detent_radius: 6.0,
         }
     }
 
@@ -227,6 +247,98 @@ impl<'a> Slider<'a> {
         self
     }
 
+    /// Use a custom mapping between the value and the position of the handle,
+    /// e.g. for an audio taper.
+    ///
+    /// `to_normalized` maps a value in the range to `0.0..=1.0`, where `0.0` is the start of the range
+    /// and `1.0` the end. `from_normalized` is its inverse. Both must be monotone.
+    ///
+    /// This overrides [`Self::logarithmic`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut gain: f32 = 1.0;
+    /// // A linear gain factor, with a slider that is linear in decibels:
+    /// let db_range = -60.0..=12.0;
+    /// ui.add(
+    ///     egui::Slider::new(&mut gain, 0.0..=4.0)
+    ///         .custom_mapping(
+    ///             move |gain| {
+    ///                 let db = 20.0 * gain.max(1e-6).log10();
+    ///                 egui::remap_clamp(db, db_range.clone(), 0.0..=1.0)
+    ///             },
+    ///             move |t| {
+    ///                 if t <= 0.0 {
+    ///                     0.0 // -∞ dB
+    ///                 } else {
+    ///                     10.0_f64.powf(egui::lerp(-60.0..=12.0, t) / 20.0)
+    ///                 }
+    ///             },
+    ///         ),
+    /// );
+    /// # });
+    /// ```
+    pub fn custom_mapping(
+        mut self,
+        to_normalized: impl 'a + Fn(f64) -> f64,
+        from_normalized: impl 'a + Fn(f64) -> f64,
+    ) -> Self {
+        self.custom_mapping = Some(SliderMapping {
+            to_normalized: Box::new(to_normalized),
+            from_normalized: Box::new(from_normalized),
+        });
+        self
+    }
+
+    /// Show tick marks next to the rail at these values.
+    ///
+    /// Can be combined with [`Self::labeled_tick`].
+    #[inline]
+    pub fn ticks(mut self, values: impl IntoIterator<Item = f64>) -> Self {
+        self.ticks
+            .extend(values.into_iter().map(|value| (value, None)));
+        self
+    }
+
+    /// Show a tick mark with a label next to the rail at this value.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut volume: f32 = 0.5;
+    /// ui.add(
+    ///     egui::Slider::new(&mut volume, 0.0..=1.0)
+    ///         .ticks([0.25, 0.75])
+    ///         .labeled_tick(0.0, "Mute")
+    ///         .labeled_tick(0.5, "½")
+    ///         .labeled_tick(1.0, "Max")
+    ///         .detents([0.5]),
+    /// );
+    /// # });
+    /// ```
+    #[inline]
+    pub fn labeled_tick(mut self, value: f64, label: impl Into<WidgetText>) -> Self {
+        self.ticks.push((value, Some(label.into())));
+        self
+    }
+
+    /// Snap to these values when the user drags the handle close to them.
+    ///
+    /// See also [`Self::detent_radius`] and [`Self::step_by`].
+    #[inline]
+    pub fn detents(mut self, values: impl IntoIterator<Item = f64>) -> Self {
+        self.detents.extend(values);
+        self
+    }
+
+    /// How close the pointer needs to be to a detent to snap to it, in points.
+    ///
+    /// Default: `6.0`.
+    #[inline]
+    pub fn detent_radius(mut self, detent_radius: f32) -> Self {
+        self.detent_radius = detent_radius;
+        self
+    }
+
     /// For logarithmic sliders that includes zero:
     /// what is the smallest positive value you want to be able to select?
     /// The default is `1` for integer sliders and `1e-6` for real sliders.
@@ -481,6 +593,17 @@ impl<'a> Slider<'a> {
         self
     }
 
+    /// Set the decimal separator, digit grouping, etc. used to show and parse the value.
+    ///
+    /// This has no effect on a [`Self::custom_formatter`] or [`Self::custom_parser`].
+    ///
+    /// Default: [`crate::Style::number_format`].
+    #[inline]
+    pub fn number_format(mut self, number_format: NumberFormat) -> Self {
+        self.number_format = Some(number_format);
+        self
+    }
+
     /// Set `custom_formatter` and `custom_parser` to display and parse numbers as binary integers. Floating point
     /// numbers are *not* supported.
     ///
@@ -632,14 +755,35 @@ impl<'a> Slider<'a> {
     /// For instance, `position` is the mouse position and `position_range` is the physical location of the slider on the screen.
     fn value_from_position(&self, position: f32, position_range: Rangef) -> f64 {
         let normalized = remap_clamp(position, position_range, 0.0..=1.0) as f64;
-        value_from_normalized(normalized, self.range(), &self.spec)
+        match &self.custom_mapping {
+            Some(mapping) => {
+                clamp_value_to_range((mapping.from_normalized)(normalized), self.range())
+            }
+            None => value_from_normalized(normalized, self.range(), &self.spec),
+        }
     }
 
     fn position_from_value(&self, value: f64, position_range: Rangef) -> f32 {
-        let normalized = normalized_from_value(value, self.range(), &self.spec);
+        let normalized = match &self.custom_mapping {
+            Some(mapping) => (mapping.to_normalized)(value).clamp(0.0, 1.0),
+            None => normalized_from_value(value, self.range(), &self.spec),
+        };
         lerp(position_range, normalized as f32)
     }
 
+    /// The closest detent within [`Self::detent_radius`] of the given position, if any.
+    fn snap_to_detent(&self, position: f32, position_range: Rangef) -> Option<f64> {
+        self.detents
+            .iter()
+            .map(|&detent| {
+                let distance = (self.position_from_value(detent, position_range) - position).abs();
+                (detent, distance)
+            })
+            .filter(|(_, distance)| *distance <= self.detent_radius)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(detent, _)| detent)
+    }
+
     /// Update the value on each key press when text-editing the value.
     ///
     /// Default: `true`.
@@ -649,21 +793,81 @@ impl<'a> Slider<'a> {
         self.update_while_editing = update;
         self
     }
+
+    /// Lock the pointer while dragging the value next to the slider,
+    /// see [`crate::DragValue::infinite_drag`].
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn infinite_drag(mut self, infinite_drag: bool) -> Self {
+        self.infinite_drag = infinite_drag;
+        self
+    }
 }
 
 impl Slider<'_> {
-    /// Just the slider, no text
-    fn allocate_slider_space(&self, ui: &mut Ui, thickness: f32) -> Response {
+    /// Just the slider and its tick labels, no text
+    fn allocate_slider_space(
+        &self,
+        ui: &mut Ui,
+        thickness: f32,
+        tick_label_space: f32,
+    ) -> Response {
         let desired_size = match self.orientation {
-            SliderOrientation::Horizontal => vec2(ui.spacing().slider_width, thickness),
-            SliderOrientation::Vertical => vec2(thickness, ui.spacing().slider_width),
+            SliderOrientation::Horizontal => {
+                vec2(ui.spacing().slider_width, thickness + tick_label_space)
+            }
+            SliderOrientation::Vertical => {
+                vec2(thickness + tick_label_space, ui.spacing().slider_width)
+            }
         };
         ui.allocate_response(desired_size, Sense::drag())
     }
 
+    fn layout_tick_labels(&self, ui: &Ui) -> Vec<(f64, Arc<Galley>)> {
+        self.ticks
+            .iter()
+            .filter_map(|(value, label)| {
+                let galley = label.clone()?.into_galley(
+                    ui,
+                    Some(TextWrapMode::Extend),
+                    f32::INFINITY,
+                    TextStyle::Small,
+                );
+                Some((*value, galley))
+            })
+            .collect()
+    }
+
+    /// How much room the tick labels need below (or to the right of) the slider.
+    fn tick_label_space(&self, tick_labels: &[(f64, Arc<Galley>)]) -> f32 {
+        let size = tick_labels
+            .iter()
+            .map(|(_, galley)| match self.orientation {
+                SliderOrientation::Horizontal => galley.size().y,
+                SliderOrientation::Vertical => galley.size().x,
+            })
+            .fold(0.0, f32::max);
+        if size > 0.0 {
+            size + TICK_LABEL_GAP
+        } else {
+            0.0
+        }
+    }
+
+    /// The part of the allocated rect used by the rail and the handle, i.e. excluding the tick labels.
+    fn slider_rect(&self, rect: Rect, tick_label_space: f32) -> Rect {
+        let mut rect = rect;
+        match self.orientation {
+            SliderOrientation::Horizontal => rect.max.y -= tick_label_space,
+            SliderOrientation::Vertical => rect.max.x -= tick_label_space,
+        }
+        rect
+    }
+
     /// Just the slider, no text
-    fn slider_ui(&mut self, ui: &Ui, response: &Response) {
-        let rect = &response.rect;
+    fn slider_ui(&mut self, ui: &Ui, response: &Response, tick_labels: &[(f64, Arc<Galley>)]) {
+        let rect = &self.slider_rect(response.rect, self.tick_label_space(tick_labels));
         let handle_shape = self
             .handle_shape
             .unwrap_or_else(|| ui.style().visuals.handle_shape);
@@ -671,7 +875,9 @@ impl Slider<'_> {
 
         if let Some(pointer_position_2d) = response.interact_pointer_pos() {
             let position = self.pointer_position(pointer_position_2d);
-            let new_value = if self.smart_aim {
+            let new_value = if let Some(detent) = self.snap_to_detent(position, position_range) {
+                detent
+            } else if self.smart_aim {
                 let aim_radius = ui.input(|i| i.aim_radius());
                 emath::smart_aim::best_in_range_f64(
                     self.value_from_position(position - aim_radius, position_range),
@@ -771,6 +977,10 @@ impl Slider<'_> {
             });
         }
 
+        if let Some(new_value) = ui.ctx().take_macro_value(response.id) {
+            self.set_value(new_value);
+        }
+
         // Paint it:
         if ui.is_rect_visible(response.rect) {
             let value = self.get_value();
@@ -808,13 +1018,15 @@ impl Slider<'_> {
                     }
                 };
 
-                ui.painter().rect_filled(
-                    trailing_rail_rect,
-                    corner_radius,
-                    ui.visuals().selection.bg_fill,
-                );
+                let fill = ui.visuals().selection.hdr_fill();
+                ui.painter().add(Shape::hdr(
+                    epaint::RectShape::filled(trailing_rail_rect, corner_radius, fill.color),
+                    fill.intensity,
+                ));
             }
 
+            self.paint_ticks(ui, rect, &rail_rect, position_range, tick_labels);
+
             let radius = self.handle_radius(rect);
 
             let handle_shape = self
@@ -848,6 +1060,60 @@ impl Slider<'_> {
         }
     }
 
+    fn paint_ticks(
+        &self,
+        ui: &Ui,
+        rect: &Rect,
+        rail_rect: &Rect,
+        position_range: Rangef,
+        tick_labels: &[(f64, Arc<Galley>)],
+    ) {
+        let stroke = ui.visuals().widgets.noninteractive.fg_stroke;
+        let range = self.range();
+        let in_range = |value: f64| clamp_value_to_range(value, range.clone()) == value;
+
+        for &(value, _) in &self.ticks {
+            if !in_range(value) {
+                continue;
+            }
+            let position = self.position_from_value(value, position_range);
+            let segment = match self.orientation {
+                SliderOrientation::Horizontal => [
+                    pos2(position, rail_rect.bottom()),
+                    pos2(position, rect.bottom()),
+                ],
+                SliderOrientation::Vertical => [
+                    pos2(rail_rect.right(), position),
+                    pos2(rect.right(), position),
+                ],
+            };
+            ui.painter().line_segment(segment, stroke);
+        }
+
+        let text_color = ui.visuals().weak_text_color();
+        for (value, galley) in tick_labels {
+            if !in_range(*value) {
+                continue;
+            }
+            let position = self.position_from_value(*value, position_range);
+            let size = galley.size();
+            let min = match self.orientation {
+                SliderOrientation::Horizontal => {
+                    // Keep the labels at the ends within the widget:
+                    let max_x = (rect.right() - size.x).at_least(rect.left());
+                    pos2(
+                        (position - size.x / 2.0).clamp(rect.left(), max_x),
+                        rect.bottom() + TICK_LABEL_GAP,
+                    )
+                }
+                SliderOrientation::Vertical => {
+                    pos2(rect.right() + TICK_LABEL_GAP, position - size.y / 2.0)
+                }
+            };
+            ui.painter().galley(min, galley.clone(), text_color);
+        }
+    }
+
     fn marker_center(&self, position_1d: f32, rail_rect: &Rect) -> Pos2 {
         match self.orientation {
             SliderOrientation::Horizontal => pos2(position_1d, rail_rect.center().y),
@@ -922,7 +1188,8 @@ impl Slider<'_> {
                 .max_decimals_opt(self.max_decimals)
                 .suffix(self.suffix.clone())
                 .prefix(self.prefix.clone())
-                .update_while_editing(self.update_while_editing);
+                .update_while_editing(self.update_while_editing)
+                .infinite_drag(self.infinite_drag);
 
             match self.clamping {
                 SliderClamping::Never => {}
@@ -940,6 +1207,9 @@ impl Slider<'_> {
             if let Some(parser) = &self.custom_parser {
                 dv = dv.custom_parser(parser);
             }
+            if let Some(number_format) = self.number_format {
+                dv = dv.number_format(number_format);
+            }
             dv
         });
         if value != self.get_value() {
@@ -969,14 +1239,18 @@ impl Slider<'_> {
         let thickness = ui
             .text_style_height(&TextStyle::Body)
             .at_least(ui.spacing().interact_size.y);
-        let mut response = self.allocate_slider_space(ui, thickness);
-        self.slider_ui(ui, &response);
+        let tick_labels = self.layout_tick_labels(ui);
+        let tick_label_space = self.tick_label_space(&tick_labels);
+        let mut response = self.allocate_slider_space(ui, thickness, tick_label_space);
+        self.slider_ui(ui, &response, &tick_labels);
 
         let value = self.get_value();
         if value != old_value {
             response.mark_changed();
         }
-        response.widget_info(|| WidgetInfo::slider(ui.is_enabled(), value, self.text.text()));
+        response.widget_info(|| {
+            WidgetInfo::slider(ui.is_enabled(), value, self.text.text()).with_prev_value(old_value)
+        });
 
         #[cfg(feature = "accesskit")]
         ui.ctx().accesskit_node_builder(response.id, |builder| {
@@ -1007,7 +1281,8 @@ impl Slider<'_> {
             let handle_shape = self
                 .handle_shape
                 .unwrap_or_else(|| ui.style().visuals.handle_shape);
-            let position_range = self.position_range(&response.rect, &handle_shape);
+            let slider_rect = self.slider_rect(response.rect, tick_label_space);
+            let position_range = self.position_range(&slider_rect, &handle_shape);
             let value_response = self.value_ui(ui, position_range);
             if value_response.gained_focus()
                 || value_response.has_focus()
@@ -1061,6 +1336,9 @@ impl Widget for Slider<'_> {
 
 const INFINITY: f64 = f64::INFINITY;
 
+/// Space between the slider and its tick labels, in points.
+const TICK_LABEL_GAP: f32 = 2.0;
+
 /// When the user asks for an infinitely large range (e.g. logarithmic from zero),
 /// give a scale that this many orders of magnitude in size.
 const INF_RANGE_MAGNITUDE: f64 = 10.0;
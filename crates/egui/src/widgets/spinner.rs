@@ -37,7 +37,10 @@ impl Spinner {
     /// Paint the spinner in the given rectangle.
     pub fn paint_at(&self, ui: &Ui, rect: Rect) {
         if ui.is_rect_visible(rect) {
-            ui.ctx().request_repaint(); // because it is animated
+            ui.ctx().request_repaint_after_because(
+                std::time::Duration::ZERO,
+                crate::RepaintReason::Animation,
+            );
 
             let color = self
                 .color
@@ -616,6 +616,14 @@ impl TextEdit<'_> {
             ui.ctx().set_cursor_icon(CursorIcon::Text);
         }
 
+        if interactive && text.is_mutable() {
+            if let Some(new_text) = ui.ctx().take_macro_text(id) {
+                text.replace_with(&new_text);
+                galley = layouter(ui, text, wrap_width);
+                response.mark_changed();
+            }
+        }
+
         let mut cursor_range = None;
         let prev_cursor_range = state.cursor.range(&galley);
         if interactive && ui.memory(|mem| mem.has_focus(id)) {
@@ -924,7 +932,15 @@ fn events(
         events.sort_by_key(|e| !matches!(e, Event::Ime(_)));
     }
 
+    // Inserting text doesn't need an up-to-date galley, so we only lay out again when needed:
+    let mut galley_is_outdated = false;
+
     for event in &events {
+        if galley_is_outdated && !matches!(event, Event::Text(_) | Event::Paste(_)) {
+            *galley = layouter(ui, text, wrap_width);
+            galley_is_outdated = false;
+        }
+
         let did_mutate_text = match event {
             // First handle events that only changes the selection cursor, not the text:
             event if cursor_range.on_event(os, event, galley, id) => None,
@@ -1100,15 +1116,16 @@ fn events(
 
         if let Some(new_ccursor_range) = did_mutate_text {
             any_change = true;
-
-            // Layout again to avoid frame delay, and to keep `text` and `galley` in sync.
-            *galley = layouter(ui, text, wrap_width);
-
-            // Set cursor_range using new galley:
+            galley_is_outdated = true;
             cursor_range = new_ccursor_range;
         }
     }
 
+    if galley_is_outdated {
+        // Layout again to avoid frame delay, and to keep `text` and `galley` in sync.
+        *galley = layouter(ui, text, wrap_width);
+    }
+
     state.cursor.set_char_range(Some(cursor_range));
 
     state.undoer.lock().feed_state(
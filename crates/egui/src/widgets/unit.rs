@@ -0,0 +1,246 @@
+//! Physical units for [`crate::DragValue`], with conversion between e.g. meters and inches.
+//!
+//! ```
+//! # egui::__run_test_ui(|ui| {
+//! # let mut length_in_meters: f64 = 0.5;
+//! // Stored in meters, shown and edited in millimeters:
+//! ui.add(
+//!     egui::DragValue::new(&mut length_in_meters)
+//!         .unit(egui::Unit::Meters)
+//!         .display_unit(egui::Unit::Millimeters),
+//! );
+//! # });
+//! ```
+
+/// What a [`Unit`] measures. Only units of the same quantity can be converted into each other.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Quantity {
+    Length,
+    Angle,
+    DataSize,
+}
+
+/// A unit of measurement that a [`crate::DragValue`] can show and parse.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Unit {
+    Millimeters,
+    Centimeters,
+    Meters,
+    Kilometers,
+    Inches,
+    Feet,
+
+    Radians,
+    Degrees,
+
+    Bytes,
+
+    /// 1024 bytes.
+    Kibibytes,
+
+    /// 1024² bytes.
+    Mebibytes,
+
+    /// 1024³ bytes.
+    Gibibytes,
+}
+
+impl Unit {
+    pub const ALL: [Self; 12] = [
+        Self::Millimeters,
+        Self::Centimeters,
+        Self::Meters,
+        Self::Kilometers,
+        Self::Inches,
+        Self::Feet,
+        Self::Radians,
+        Self::Degrees,
+        Self::Bytes,
+        Self::Kibibytes,
+        Self::Mebibytes,
+        Self::Gibibytes,
+    ];
+
+    pub fn quantity(self) -> Quantity {
+        match self {
+            Self::Millimeters
+            | Self::Centimeters
+            | Self::Meters
+            | Self::Kilometers
+            | Self::Inches
+            | Self::Feet => Quantity::Length,
+            Self::Radians | Self::Degrees => Quantity::Angle,
+            Self::Bytes | Self::Kibibytes | Self::Mebibytes | Self::Gibibytes => Quantity::DataSize,
+        }
+    }
+
+    /// The size of this unit in the base unit of its [`Quantity`]:
+    /// meters, radians or bytes.
+    pub fn in_base_unit(self) -> f64 {
+        match self {
+            Self::Millimeters => 1e-3,
+            Self::Centimeters => 1e-2,
+            Self::Meters | Self::Radians | Self::Bytes => 1.0,
+            Self::Kilometers => 1e3,
+            Self::Inches => 0.0254,
+            Self::Feet => 0.3048,
+            Self::Degrees => std::f64::consts::PI / 180.0,
+            Self::Kibibytes => 1024.0,
+            Self::Mebibytes => 1024.0 * 1024.0,
+            Self::Gibibytes => 1024.0 * 1024.0 * 1024.0,
+        }
+    }
+
+    /// The symbol shown after the value, e.g. `"mm"` or `"°"`.
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Self::Millimeters => "mm",
+            Self::Centimeters => "cm",
+            Self::Meters => "m",
+            Self::Kilometers => "km",
+            Self::Inches => "in",
+            Self::Feet => "ft",
+            Self::Radians => "rad",
+            Self::Degrees => "°",
+            Self::Bytes => "B",
+            Self::Kibibytes => "KiB",
+            Self::Mebibytes => "MiB",
+            Self::Gibibytes => "GiB",
+        }
+    }
+
+    /// What to put after a value: the symbol, preceded by a space unless it is `°`.
+    pub fn suffix(self) -> String {
+        match self {
+            Self::Degrees => self.symbol().to_owned(),
+            _ => format!(" {}", self.symbol()),
+        }
+    }
+
+    /// Find the unit with the given symbol, ignoring case (`"MB"` is not the same as `"MiB"` though).
+    ///
+    /// Also accepts a few common alternatives, like `"deg"` and `"\""`.
+    pub fn from_symbol(symbol: &str) -> Option<Self> {
+        let symbol = symbol.trim();
+        match symbol {
+            "deg" | "degrees" => return Some(Self::Degrees),
+            "\"" | "inch" | "inches" => return Some(Self::Inches),
+            "'" | "foot" | "feet" => return Some(Self::Feet),
+            _ => {}
+        }
+        Self::ALL
+            .into_iter()
+            .find(|unit| unit.symbol().eq_ignore_ascii_case(symbol))
+    }
+
+    /// Multiply a value in this unit with this to get the value in `other`.
+    ///
+    /// Returns `None` if they measure different quantities.
+    pub fn conversion_factor(self, other: Self) -> Option<f64> {
+        (self.quantity() == other.quantity()).then(|| self.in_base_unit() / other.in_base_unit())
+    }
+
+    /// Convert a value in this unit to `other`.
+    ///
+    /// Returns `None` if they measure different quantities.
+    ///
+    /// ```
+    /// use egui::Unit;
+    /// assert_eq!(Unit::Inches.convert(2.0, Unit::Millimeters), Some(50.8));
+    /// assert_eq!(Unit::Inches.convert(2.0, Unit::Degrees), None);
+    /// ```
+    pub fn convert(self, value: f64, other: Self) -> Option<f64> {
+        self.conversion_factor(other).map(|factor| value * factor)
+    }
+
+    /// Parse text like `"12.5"`, `"12.5 mm"` or `"3in"` into a value in this unit.
+    ///
+    /// The number itself is parsed with `parse_number`.
+    /// Without a unit suffix the number is assumed to already be in this unit.
+    /// Units of other quantities are rejected.
+    pub fn parse_with_suffix(
+        self,
+        text: &str,
+        parse_number: impl Fn(&str) -> Option<f64>,
+    ) -> Option<f64> {
+        let text = text.trim();
+
+        // Find where the number ends and the unit starts:
+        let split = text
+            .char_indices()
+            .rev()
+            .take_while(|(_, c)| !c.is_ascii_digit() && *c != '.' && *c != ',')
+            .last()
+            .map_or(text.len(), |(i, _)| i);
+        let (number, symbol) = text.split_at(split);
+
+        if symbol.trim().is_empty() {
+            return parse_number(number);
+        }
+        match Self::from_symbol(symbol) {
+            Some(unit) => unit.convert(parse_number(number)?, self),
+            None => parse_number(text), // Maybe the "unit" is part of the number, like "inf" or "1e3"
+        }
+    }
+}
+
+/// The preferred [`Unit`] to show each [`Quantity`] in, set in [`crate::Style::display_units`].
+///
+/// `None` means to show values in the unit they are stored in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct DisplayUnits {
+    pub length: Option<Unit>,
+    pub angle: Option<Unit>,
+    pub data_size: Option<Unit>,
+}
+
+impl DisplayUnits {
+    /// The preferred unit for the given quantity, if any.
+    pub fn get(&self, quantity: Quantity) -> Option<Unit> {
+        match quantity {
+            Quantity::Length => self.length,
+            Quantity::Angle => self.angle,
+            Quantity::DataSize => self.data_size,
+        }
+    }
+
+    /// Prefer to show values of this unit's quantity in this unit.
+    pub fn set(&mut self, unit: Unit) {
+        let slot = match unit.quantity() {
+            Quantity::Length => &mut self.length,
+            Quantity::Angle => &mut self.angle,
+            Quantity::DataSize => &mut self.data_size,
+        };
+        *slot = Some(unit);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_with_suffix() {
+        let parse =
+            |unit: Unit, text: &str| unit.parse_with_suffix(text, |s| s.trim().parse().ok());
+
+        assert_eq!(parse(Unit::Millimeters, "12.5"), Some(12.5));
+        assert_eq!(parse(Unit::Millimeters, "12.5 mm"), Some(12.5));
+        assert_eq!(parse(Unit::Millimeters, "1cm"), Some(10.0));
+        assert_eq!(parse(Unit::Millimeters, "2 in"), Some(50.8));
+        assert_eq!(parse(Unit::Millimeters, "2 deg"), None);
+        assert_eq!(parse(Unit::Degrees, "180°"), Some(180.0));
+        assert_eq!(
+            parse(Unit::Degrees, "3.141592653589793 rad").map(f64::round),
+            Some(180.0)
+        );
+        assert_eq!(parse(Unit::Bytes, "2 KiB"), Some(2048.0));
+        assert_eq!(parse(Unit::Kibibytes, "512 b"), Some(0.5));
+        assert_eq!(parse(Unit::Meters, "1e3"), Some(1000.0));
+        assert_eq!(parse(Unit::Meters, "inf"), Some(f64::INFINITY));
+    }
+}
@@ -0,0 +1,475 @@
+use std::ops::RangeInclusive;
+
+use egui::{Context, Event, EventFilter, Id, Key, Modifiers, Response, Sense, StrokeKind, Ui};
+
+/// Row and column of a cell.
+type Cell = (usize, usize);
+
+/// Stored in egui memory.
+#[derive(Clone, Copy, Debug, Default)]
+struct State {
+    /// The cell with the keyboard cursor.
+    active: Option<Cell>,
+
+    /// The other corner of the selected cells, if more than the active cell is selected.
+    anchor: Option<Cell>,
+
+    /// Is the active cell being edited?
+    editing: bool,
+
+    /// Should the editor get keyboard focus?
+    focus_editor: bool,
+
+    /// Should the active cell be scrolled into view?
+    scroll_to_active: bool,
+}
+
+/// Spreadsheet-like edit-in-place for the cells of a [`crate::Table`].
+///
+/// Click a cell to make it active, and move the active cell with the arrow keys.
+/// Hold Shift to select a rectangle of cells instead,
+/// which can be copied and pasted with [`Self::handle_clipboard`].
+/// Double-click a cell, or press F2 or Enter, to edit it.
+/// While editing, Enter commits and moves down, Tab commits and moves right
+/// (add Shift to move up or left instead), and Escape cancels, restoring the old value.
+/// Clicking elsewhere also commits.
+///
+/// The cell editor works on a copy of the value,
+/// which is only written back to the cell when the edit is committed.
+/// [`Self::cell`] then returns the old value, e.g. for your undo stack.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_extras::{CellEditor, Column, TableBuilder};
+///
+/// # let mut rows = vec![(String::from("Apples"), 3.0), (String::from("Pears"), 5.0)];
+/// # let mut undo_stack = Vec::new();
+/// let id = ui.make_persistent_id("fruit_table");
+/// let mut editor = CellEditor::load(ui.ctx(), id, rows.len(), 2);
+/// let paste = editor.handle_clipboard(ui.ctx(), |row, col| match col {
+///     0 => rows[row].0.clone(),
+///     _ => rows[row].1.to_string(),
+/// });
+/// if let Some(paste) = paste {
+///     for (row, cells) in rows.iter_mut().skip(paste.row).zip(&paste.cells) {
+///         if let (0, Some(name)) = (paste.col, cells.first()) {
+///             row.0 = name.clone();
+///         }
+///     }
+/// }
+/// TableBuilder::new(ui)
+///     .columns(Column::auto(), 2)
+///     .body(|mut body| {
+///         for (i, (name, amount)) in rows.iter_mut().enumerate() {
+///             body.row(20.0, |mut row| {
+///                 row.col(|ui| {
+///                     let old = editor.cell(ui, i, 0, name, |ui, name| {
+///                         ui.label(name.as_str());
+///                     }, |ui, name| ui.text_edit_singleline(name));
+///                     if let Some(old) = old {
+///                         undo_stack.push((i, old));
+///                     }
+///                 });
+///                 row.col(|ui| {
+///                     editor.cell(ui, i, 1, amount, |ui, amount| {
+///                         ui.label(amount.to_string());
+///                     }, |ui, amount| ui.add(egui::DragValue::new(amount)));
+///                 });
+///             });
+///         }
+///     });
+/// editor.store(ui.ctx());
+/// # });
+/// ```
+#[derive(Clone, Debug)]
+pub struct CellEditor {
+    id: Id,
+    num_rows: usize,
+    num_cols: usize,
+    state: State,
+}
+
+impl CellEditor {
+    /// Load the state of the editor from egui memory, and move the active cell with the keyboard.
+    ///
+    /// `num_rows` and `num_cols` is the size of the table, which limits where the active cell can go.
+    pub fn load(ctx: &Context, id: Id, num_rows: usize, num_cols: usize) -> Self {
+        let mut state = ctx.data(|d| d.get_temp::<State>(id)).unwrap_or_default();
+        let in_table = |&(row, col): &Cell| row < num_rows && col < num_cols;
+        state.active = state.active.filter(in_table);
+        state.anchor = state.anchor.filter(in_table);
+        if state.active.is_none() {
+            state.editing = false;
+        }
+
+        let mut slf = Self {
+            id,
+            num_rows,
+            num_cols,
+            state,
+        };
+        slf.handle_navigation(ctx);
+        slf
+    }
+
+    /// Store the state of the editor in egui memory, so it can be loaded the next pass.
+    pub fn store(self, ctx: &Context) {
+        ctx.data_mut(|d| d.insert_temp(self.id, self.state));
+    }
+
+    /// The cell with the keyboard cursor, as `(row, column)`.
+    pub fn active_cell(&self) -> Option<(usize, usize)> {
+        self.state.active
+    }
+
+    /// Make a cell active, or none of them.
+    ///
+    /// This also selects only that cell.
+    pub fn set_active_cell(&mut self, cell: Option<(usize, usize)>) {
+        self.state.anchor = None;
+        if cell != self.state.active {
+            self.state.active = cell;
+            self.state.editing = false;
+            self.state.scroll_to_active = cell.is_some();
+        }
+    }
+
+    /// The selected cells, as inclusive ranges of rows and columns.
+    ///
+    /// This is just the active cell, unless the user has selected more with Shift.
+    pub fn selection(&self) -> Option<(RangeInclusive<usize>, RangeInclusive<usize>)> {
+        let (row, col) = self.state.active?;
+        let (anchor_row, anchor_col) = self.state.anchor.unwrap_or((row, col));
+        Some((
+            row.min(anchor_row)..=row.max(anchor_row),
+            col.min(anchor_col)..=col.max(anchor_col),
+        ))
+    }
+
+    /// Is the given cell selected?
+    pub fn is_selected(&self, row: usize, col: usize) -> bool {
+        self.selection()
+            .is_some_and(|(rows, cols)| rows.contains(&row) && cols.contains(&col))
+    }
+
+    /// Copy the selected cells as TSV (tab-separated values) when the user presses Ctrl+C (or Cmd+C),
+    /// and return the cells the user pastes with Ctrl+V (or Cmd+V).
+    ///
+    /// `cell_text` gives the text of a cell, for copying.
+    /// It is up to you to apply the pasted cells, since only you know how to parse the text of your cells,
+    /// and which cells can be edited.
+    ///
+    /// Does nothing while a cell is being edited, or when some other widget has keyboard focus.
+    pub fn handle_clipboard(
+        &self,
+        ctx: &Context,
+        cell_text: impl Fn(usize, usize) -> String,
+    ) -> Option<CellPaste> {
+        let (rows, cols) = self.selection()?;
+        if self.state.editing || ctx.memory(|mem| mem.focused().is_some()) {
+            return None;
+        }
+
+        let (copy, paste) = ctx.input(|i| {
+            let copy = i.events.iter().any(|e| matches!(e, Event::Copy));
+            let paste = i.events.iter().find_map(|e| match e {
+                Event::Paste(text) => Some(text.clone()),
+                _ => None,
+            });
+            (copy, paste)
+        });
+
+        if copy {
+            let cells: Vec<Vec<String>> = rows
+                .clone()
+                .map(|row| cols.clone().map(|col| cell_text(row, col)).collect())
+                .collect();
+            ctx.copy_text(to_tsv(&cells));
+        }
+
+        let cells = parse_tsv(&paste?);
+        Some(CellPaste {
+            row: *rows.start(),
+            col: *cols.start(),
+            cells,
+        })
+    }
+
+    /// Is the active cell being edited?
+    pub fn is_editing(&self) -> bool {
+        self.state.editing
+    }
+
+    /// Start editing the given cell.
+    pub fn start_editing(&mut self, row: usize, col: usize) {
+        self.state.active = Some((row, col));
+        self.state.anchor = None;
+        self.state.editing = true;
+        self.state.focus_editor = true;
+    }
+
+    /// Show a cell. Call this inside [`crate::TableRow::col`].
+    ///
+    /// `show` shows the value normally, and should not contain interactive widgets,
+    /// since the whole cell is sensitive to clicks.
+    /// `edit` shows the editor for the value, and returns its [`Response`], which gets keyboard focus.
+    ///
+    /// Returns the previous value if an edit was committed this pass.
+    pub fn cell<T: Clone + Send + Sync + 'static>(
+        &mut self,
+        ui: &mut Ui,
+        row: usize,
+        col: usize,
+        value: &mut T,
+        show: impl FnOnce(&mut Ui, &T),
+        edit: impl FnOnce(&mut Ui, &mut T) -> Response,
+    ) -> Option<T> {
+        let is_active = self.state.active == Some((row, col));
+
+        if is_active && self.state.editing {
+            return self.edit_cell(ui, value, edit);
+        }
+
+        show(ui, value);
+
+        let rect = ui.max_rect();
+        let response = ui.interact(rect, self.id.with((row, col)), Sense::click());
+        if response.double_clicked() {
+            self.start_editing(row, col);
+            ui.ctx().request_repaint();
+        } else if response.clicked() {
+            if ui.input(|i| i.modifiers.shift) && self.state.active.is_some() {
+                self.state.anchor = self.state.anchor.or(self.state.active);
+                self.state.active = Some((row, col));
+            } else {
+                self.set_active_cell(Some((row, col)));
+                self.state.scroll_to_active = false;
+            }
+        }
+
+        if self.state.anchor.is_some() && self.is_selected(row, col) {
+            let fill = ui.visuals().selection.bg_fill.gamma_multiply(0.5);
+            ui.painter().rect_filled(rect, 0.0, fill);
+        }
+
+        if is_active {
+            let stroke = ui.visuals().selection.stroke;
+            ui.painter()
+                .rect_stroke(rect, 0.0, stroke, StrokeKind::Inside);
+            if std::mem::take(&mut self.state.scroll_to_active) {
+                ui.scroll_to_rect(rect, None);
+            }
+        }
+
+        None
+    }
+
+    fn edit_cell<T: Clone + Send + Sync + 'static>(
+        &mut self,
+        ui: &mut Ui,
+        value: &mut T,
+        edit: impl FnOnce(&mut Ui, &mut T) -> Response,
+    ) -> Option<T> {
+        let draft_id = self.id.with("draft");
+        let just_started = std::mem::take(&mut self.state.focus_editor);
+        let mut draft = if just_started {
+            value.clone()
+        } else {
+            ui.data(|d| d.get_temp::<T>(draft_id))
+                .unwrap_or_else(|| value.clone())
+        };
+
+        // Read the keys before the editor gets them, since e.g. a `TextEdit` gives up focus on Enter:
+        let (enter, tab, escape, shift) = ui.input(|i| {
+            (
+                i.key_pressed(Key::Enter),
+                i.key_pressed(Key::Tab),
+                i.key_pressed(Key::Escape),
+                i.modifiers.shift,
+            )
+        });
+
+        let response = edit(ui, &mut draft);
+        if just_started {
+            response.request_focus();
+        }
+
+        let commit = enter || tab || response.lost_focus();
+        if !escape && !commit {
+            // Tab commits the edit, so it must not also move the focus to the next widget:
+            ui.memory_mut(|mem| {
+                mem.set_focus_lock_filter(
+                    response.id,
+                    EventFilter {
+                        tab: true,
+                        horizontal_arrows: true,
+                        vertical_arrows: true,
+                        escape: false,
+                    },
+                );
+            });
+            ui.data_mut(|d| d.insert_temp(draft_id, draft));
+            return None;
+        }
+
+        self.state.editing = false;
+        ui.data_mut(|d| d.remove::<T>(draft_id));
+        ui.memory_mut(|mem| mem.surrender_focus(response.id));
+        ui.ctx().request_repaint();
+
+        if escape {
+            return None;
+        }
+
+        let delta = match (enter, tab) {
+            (true, _) => (if shift { -1 } else { 1 }, 0),
+            (false, true) => (0, if shift { -1 } else { 1 }),
+            (false, false) => (0, 0),
+        };
+        self.move_active(delta);
+        Some(std::mem::replace(value, draft))
+    }
+
+    /// Move the active cell, staying within the table.
+    fn move_active(&mut self, (d_row, d_col): (isize, isize)) {
+        if let Some((row, col)) = self.state.active {
+            let row = row
+                .saturating_add_signed(d_row)
+                .min(self.num_rows.saturating_sub(1));
+            let col = col
+                .saturating_add_signed(d_col)
+                .min(self.num_cols.saturating_sub(1));
+            self.set_active_cell(Some((row, col)));
+        }
+    }
+
+    fn handle_navigation(&mut self, ctx: &Context) {
+        let Some((row, col)) = self.state.active else {
+            return;
+        };
+        if self.state.editing || ctx.memory(|mem| mem.focused().is_some()) {
+            return;
+        }
+
+        let (start_editing, delta, extend) = ctx.input_mut(|i| {
+            let start_editing = i.consume_key(Modifiers::NONE, Key::F2)
+                || i.consume_key(Modifiers::NONE, Key::Enter);
+            let delta = if i.consume_key(Modifiers::NONE, Key::ArrowUp) {
+                (-1, 0)
+            } else if i.consume_key(Modifiers::NONE, Key::ArrowDown) {
+                (1, 0)
+            } else if i.consume_key(Modifiers::NONE, Key::ArrowLeft) {
+                (0, -1)
+            } else if i.consume_key(Modifiers::NONE, Key::ArrowRight) {
+                (0, 1)
+            } else {
+                (0, 0)
+            };
+            (start_editing, delta, i.modifiers.shift)
+        });
+
+        if start_editing {
+            self.start_editing(row, col);
+        } else if delta != (0, 0) && extend {
+            // Keep the anchor where it is, and move the other corner of the selection:
+            let anchor = self.state.anchor.unwrap_or((row, col));
+            self.move_active(delta);
+            self.state.anchor = Some(anchor);
+        } else if delta != (0, 0) {
+            self.move_active(delta);
+        }
+    }
+}
+
+/// Cells the user pasted into a [`CellEditor`], see [`CellEditor::handle_clipboard`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CellPaste {
+    /// The row of the top left pasted cell, which is the top left of the selection.
+    pub row: usize,
+
+    /// The column of the top left pasted cell.
+    pub col: usize,
+
+    /// The text of the pasted cells, row by row.
+    ///
+    /// Can go outside the table, and the rows can have different lengths.
+    pub cells: Vec<Vec<String>>,
+}
+
+/// Turn rows of cells into TSV (tab-separated values), as used by spreadsheets.
+///
+/// Cells containing tabs, newlines or quotes are quoted.
+pub fn to_tsv(rows: &[Vec<String>]) -> String {
+    let mut tsv = String::new();
+    for (i, row) in rows.iter().enumerate() {
+        if 0 < i {
+            tsv.push('\n');
+        }
+        for (j, cell) in row.iter().enumerate() {
+            if 0 < j {
+                tsv.push('\t');
+            }
+            if cell.contains(['\t', '\n', '\r', '"']) {
+                tsv.push('"');
+                tsv.push_str(&cell.replace('"', "\"\""));
+                tsv.push('"');
+            } else {
+                tsv.push_str(cell);
+            }
+        }
+    }
+    tsv
+}
+
+/// Parse TSV (tab-separated values), e.g. copied from a spreadsheet, into rows of cells.
+///
+/// ```
+/// use egui_extras::{parse_tsv, to_tsv};
+///
+/// let rows = vec![
+///     vec!["Name".to_owned(), "Notes".to_owned()],
+///     vec!["Emil".to_owned(), "Says \"hi\"\tand\nbye".to_owned()],
+/// ];
+/// assert_eq!(parse_tsv(&to_tsv(&rows)), rows);
+/// assert_eq!(parse_tsv("a\tb\r\nc\n"), vec![vec!["a", "b"], vec!["c"]]);
+/// ```
+pub fn parse_tsv(tsv: &str) -> Vec<Vec<String>> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut cell = String::new();
+    let mut chars = tsv.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if cell.is_empty() => {
+                // A quoted cell, where `""` is a quote:
+                while let Some(c) = chars.next() {
+                    if c == '"' {
+                        if chars.peek() == Some(&'"') {
+                            chars.next();
+                            cell.push('"');
+                        } else {
+                            break;
+                        }
+                    } else {
+                        cell.push(c);
+                    }
+                }
+            }
+            '\t' => row.push(std::mem::take(&mut cell)),
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' | '\r' => {
+                row.push(std::mem::take(&mut cell));
+                rows.push(std::mem::take(&mut row));
+            }
+            c => cell.push(c),
+        }
+    }
+
+    // Spreadsheets end the copied text with a newline, which doesn't start a new row:
+    if !cell.is_empty() || !row.is_empty() {
+        row.push(cell);
+        rows.push(row);
+    }
+    rows
+}
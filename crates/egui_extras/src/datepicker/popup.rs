@@ -111,14 +111,14 @@ impl DatePickerPopup<'_> {
                             });
                             strip.cell(|ui| {
                                 ComboBox::from_id_salt("date_picker_month")
-                                    .selected_text(month_name(popup_state.month))
+                                    .selected_text(month_name(ui.ctx(), popup_state.month))
                                     .show_ui(ui, |ui| {
                                         for month in 1..=12 {
                                             if ui
                                                 .selectable_value(
                                                     &mut popup_state.month,
                                                     month,
-                                                    month_name(month),
+                                                    month_name(ui.ctx(), month),
                                                 )
                                                 .changed()
                                             {
@@ -277,19 +277,30 @@ impl DatePickerPopup<'_> {
                                         ui.with_layout(
                                             Layout::centered_and_justified(Direction::TopDown),
                                             |ui| {
-                                                ui.label("Week");
+                                                ui.label(
+                                                    ui.ctx()
+                                                        .tr("egui_extras.date_picker.week", "Week"),
+                                                );
                                             },
                                         );
                                     });
                                 }
 
-                                //TODO(elwerene): Locale
-                                for name in ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"] {
+                                for (weekday, english) in ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"]
+                                    .into_iter()
+                                    .enumerate()
+                                {
                                     header.col(|ui| {
                                         ui.with_layout(
                                             Layout::centered_and_justified(Direction::TopDown),
                                             |ui| {
-                                                ui.label(name);
+                                                ui.label(ui.ctx().tr(
+                                                    &format!(
+                                                        "egui_extras.date_picker.weekday.{}",
+                                                        weekday + 1
+                                                    ),
+                                                    english,
+                                                ));
                                             },
                                         );
                                     });
@@ -388,14 +399,20 @@ impl DatePickerPopup<'_> {
                         strip.empty();
                         strip.cell(|ui| {
                             ui.with_layout(Layout::top_down_justified(Align::Center), |ui| {
-                                if ui.button("Cancel").clicked() {
+                                if ui
+                                    .button(ui.ctx().tr("egui_extras.date_picker.cancel", "Cancel"))
+                                    .clicked()
+                                {
                                     close = true;
                                 }
                             });
                         });
                         strip.cell(|ui| {
                             ui.with_layout(Layout::top_down_justified(Align::Center), |ui| {
-                                if ui.button("Save").clicked() {
+                                if ui
+                                    .button(ui.ctx().tr("egui_extras.date_picker.save", "Save"))
+                                    .clicked()
+                                {
                                     *self.selection = NaiveDate::from_ymd_opt(
                                         popup_state.year,
                                         popup_state.month,
@@ -424,8 +441,9 @@ impl DatePickerPopup<'_> {
     }
 }
 
-fn month_name(i: u32) -> &'static str {
-    match i {
+/// Translated with the key `egui_extras.date_picker.month.{i}`, see [`egui::Context::tr`].
+fn month_name(ctx: &egui::Context, i: u32) -> String {
+    let english = match i {
         1 => "January",
         2 => "February",
         3 => "March",
@@ -439,5 +457,6 @@ fn month_name(i: u32) -> &'static str {
         11 => "November",
         12 => "December",
         _ => panic!("Unknown month: {i}"),
-    }
+    };
+    ctx.tr(&format!("egui_extras.date_picker.month.{i}"), english)
 }
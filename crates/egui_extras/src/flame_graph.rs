@@ -0,0 +1,303 @@
+use egui::{
+    Align2, Color32, Id, Rect, Response, Sense, Stroke, StrokeKind, TextStyle, Ui, ecolor::Hsva,
+    pos2, vec2,
+};
+
+/// A timed span for a [`FlameGraph`], e.g. a profiling scope.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Span {
+    pub name: String,
+
+    /// Spans with the same category get the same color.
+    ///
+    /// If empty, the color is based on the name.
+    pub category: String,
+
+    /// When the span starts, in seconds.
+    pub start: f64,
+
+    /// When the span ends, in seconds.
+    pub end: f64,
+
+    /// How deeply nested the span is. Top level spans have depth 0.
+    pub depth: usize,
+}
+
+impl Span {
+    pub fn new(name: impl Into<String>, start: f64, end: f64, depth: usize) -> Self {
+        Self {
+            name: name.into(),
+            category: String::new(),
+            start,
+            end,
+            depth,
+        }
+    }
+
+    #[inline]
+    pub fn category(mut self, category: impl Into<String>) -> Self {
+        self.category = category.into();
+        self
+    }
+
+    /// How long the span is, in seconds.
+    #[inline]
+    pub fn duration(&self) -> f64 {
+        self.end - self.start
+    }
+}
+
+/// What [`FlameGraph::show`] returns.
+pub struct FlameGraphResponse {
+    /// The response of the whole flame graph.
+    pub response: Response,
+
+    /// The index of the span under the pointer, if any.
+    pub hovered: Option<usize>,
+
+    /// The index of the span that was clicked, if any.
+    pub clicked: Option<usize>,
+}
+
+/// Which part of the flame graph is visible.
+#[derive(Clone, Copy, Debug)]
+struct View {
+    /// The time at the left edge.
+    start: f64,
+
+    /// The zoom level.
+    points_per_second: f64,
+}
+
+/// Shows hierarchical timed [`Span`]s, like the scopes of a profiler.
+///
+/// Each span is a box, and nested spans are shown below their parent.
+/// Zoom with ctrl+scroll, pan by dragging or scrolling, and double-click to see everything.
+/// Hover a span to see its full name and duration.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_extras::{FlameGraph, Span};
+///
+/// let spans = [
+///     Span::new("frame", 0.0, 0.016, 0),
+///     Span::new("update", 0.0, 0.004, 1).category("logic"),
+///     Span::new("paint", 0.004, 0.015, 1).category("rendering"),
+///     Span::new("tessellate", 0.004, 0.006, 2).category("rendering"),
+/// ];
+/// # let search = String::new();
+/// let response = FlameGraph::new("flame_graph").search(&search).show(ui, &spans);
+/// if let Some(clicked) = response.clicked {
+///     println!("Clicked {}", spans[clicked].name);
+/// }
+/// # });
+/// ```
+#[derive(Clone, Debug)]
+#[must_use = "You should call .show()"]
+pub struct FlameGraph<'a> {
+    id_salt: Id,
+    row_height: f32,
+    search: &'a str,
+}
+
+impl<'a> FlameGraph<'a> {
+    pub fn new(id_salt: impl std::hash::Hash) -> Self {
+        Self {
+            id_salt: Id::new(id_salt),
+            row_height: 18.0,
+            search: "",
+        }
+    }
+
+    /// The height of each level of nesting.
+    ///
+    /// Default: `18.0`.
+    #[inline]
+    pub fn row_height(mut self, row_height: f32) -> Self {
+        self.row_height = row_height;
+        self
+    }
+
+    /// Highlight the spans whose names contain this text (ignoring case), and dim the others.
+    ///
+    /// Default: `""` (no highlighting).
+    #[inline]
+    pub fn search(mut self, search: &'a str) -> Self {
+        self.search = search;
+        self
+    }
+
+    /// Show the spans. They can be in any order.
+    pub fn show(self, ui: &mut Ui, spans: &[Span]) -> FlameGraphResponse {
+        let Self {
+            id_salt,
+            row_height,
+            search,
+        } = self;
+
+        let num_rows = spans.iter().map(|span| span.depth + 1).max().unwrap_or(0);
+        let size = vec2(ui.available_width(), num_rows as f32 * row_height);
+        let (rect, response) = ui.allocate_exact_size(size, Sense::click_and_drag());
+
+        let id = ui.make_persistent_id(id_salt);
+        let mut view = ui
+            .data(|data| data.get_temp::<View>(id))
+            .filter(|_| !response.double_clicked())
+            .unwrap_or_else(|| fit_view(spans, rect.width()));
+        if response.contains_pointer() {
+            zoom_and_pan(ui, &response, rect, &mut view);
+        }
+        ui.data_mut(|data| data.insert_temp(id, view));
+
+        let span_rect = |span: &Span| {
+            let x = |time: f64| rect.left() + ((time - view.start) * view.points_per_second) as f32;
+            let top = rect.top() + span.depth as f32 * row_height;
+            Rect::from_x_y_ranges(x(span.start)..=x(span.end), top..=top + row_height)
+        };
+
+        let hovered = response.hover_pos().and_then(|pointer_pos| {
+            spans
+                .iter()
+                .position(|span| span_rect(span).contains(pointer_pos))
+        });
+        let clicked = hovered.filter(|_| response.clicked());
+
+        let search = search.to_lowercase();
+        let painter = ui.painter_at(rect);
+        let font_id = TextStyle::Small.resolve(ui.style());
+        let text_color = Color32::BLACK;
+        for (index, span) in spans.iter().enumerate() {
+            let span_rect = span_rect(span);
+            if !span_rect.intersects(rect) || span_rect.width() < 0.5 {
+                continue; // Not visible
+            }
+
+            let matches_search = search.is_empty() || span.name.to_lowercase().contains(&search);
+            let color = span_color(span);
+            let fill = if matches_search {
+                color
+            } else {
+                color.gamma_multiply(0.3)
+            };
+            let stroke = if hovered == Some(index) {
+                ui.visuals().widgets.hovered.fg_stroke
+            } else {
+                Stroke::new(1.0, ui.visuals().extreme_bg_color)
+            };
+            painter.rect(span_rect, 2.0, fill, stroke, StrokeKind::Inside);
+
+            // Keep the name visible, even if the span starts left of the view:
+            let visible = span_rect.intersect(rect);
+            if 16.0 < visible.width() {
+                painter.text_elided(
+                    pos2(visible.left() + 3.0, visible.center().y),
+                    Align2::LEFT_CENTER,
+                    &span.name,
+                    font_id.clone(),
+                    if matches_search {
+                        text_color
+                    } else {
+                        text_color.gamma_multiply(0.5)
+                    },
+                    visible.width() - 6.0,
+                );
+            }
+        }
+
+        let response = if let Some(index) = hovered {
+            let span = &spans[index];
+            response.on_hover_ui_at_pointer(|ui| {
+                ui.strong(&span.name);
+                ui.label(format_duration(span.duration()));
+                if !span.category.is_empty() {
+                    ui.weak(&span.category);
+                }
+            })
+        } else {
+            response
+        };
+
+        FlameGraphResponse {
+            response,
+            hovered,
+            clicked,
+        }
+    }
+}
+
+/// A view that shows all the spans.
+fn fit_view(spans: &[Span], width: f32) -> View {
+    let start = spans
+        .iter()
+        .map(|span| span.start)
+        .fold(f64::INFINITY, f64::min);
+    let end = spans
+        .iter()
+        .map(|span| span.end)
+        .fold(f64::NEG_INFINITY, f64::max);
+    if start < end {
+        View {
+            start,
+            points_per_second: width as f64 / (end - start),
+        }
+    } else {
+        View {
+            start: 0.0,
+            points_per_second: 1000.0,
+        }
+    }
+}
+
+/// Zoom with ctrl+scroll around the pointer, and pan by dragging or scrolling.
+fn zoom_and_pan(ui: &Ui, response: &Response, rect: Rect, view: &mut View) {
+    let (zoom_delta, scroll_delta, pointer_pos) = ui.input(|i| {
+        (
+            i.zoom_delta(),
+            i.smooth_scroll_delta.x,
+            i.pointer.hover_pos(),
+        )
+    });
+
+    if zoom_delta != 1.0 {
+        let anchor_x = pointer_pos.map_or(rect.center().x, |pos| pos.x) - rect.left();
+        let anchor_time = view.start + anchor_x as f64 / view.points_per_second;
+        view.points_per_second = (view.points_per_second * zoom_delta as f64).clamp(1e-3, 1e12);
+        view.start = anchor_time - anchor_x as f64 / view.points_per_second;
+    }
+
+    let pan = response.drag_delta().x + scroll_delta;
+    if pan != 0.0 {
+        view.start -= pan as f64 / view.points_per_second;
+        ui.ctx().input_mut(|i| i.smooth_scroll_delta.x = 0.0);
+    }
+}
+
+/// The color of a span, based on its category (or name).
+fn span_color(span: &Span) -> Color32 {
+    let key = if span.category.is_empty() {
+        &span.name
+    } else {
+        &span.category
+    };
+    let hash = Id::new(key).value();
+    let hue = (hash % 360) as f32 / 360.0;
+    Hsva::new(hue, 0.45, 0.85, 1.0).into()
+}
+
+fn format_duration(seconds: f64) -> String {
+    if seconds < 1e-3 {
+        format!("{:.1} µs", seconds * 1e6)
+    } else if seconds < 1.0 {
+        format!("{:.2} ms", seconds * 1e3)
+    } else {
+        format!("{seconds:.3} s")
+    }
+}
+
+#[test]
+fn test_format_duration() {
+    assert_eq!(format_duration(0.000_012_34), "12.3 µs");
+    assert_eq!(format_duration(0.016), "16.00 ms");
+    assert_eq!(format_duration(2.5), "2.500 s");
+}
@@ -0,0 +1,393 @@
+//! Building blocks for node editors: [`Port`]s, [`Wire`]s between them, and the [`Connections`].
+//!
+//! How the nodes themselves look and where they are is up to you,
+//! e.g. each node could be an [`egui::Window`] or an [`egui::Area`] in a [`egui::Scene`].
+//!
+//! ```
+//! # egui::__run_test_ui(|ui| {
+//! use egui_extras::graph::{Connections, Port};
+//!
+//! let graph_id = egui::Id::new("my_graph");
+//! let mut connections = Connections::load(ui.ctx(), graph_id);
+//!
+//! // The nodes:
+//! ui.horizontal(|ui| {
+//!     ui.label("Source");
+//!     let port = Port::output(graph_id.with("source_out")).show(ui);
+//!     if let Some(link) = port.new_link {
+//!         connections.connect(link);
+//!     }
+//! });
+//! ui.horizontal(|ui| {
+//!     let port = Port::input(graph_id.with("sink_in")).show(ui);
+//!     if let Some(link) = port.new_link {
+//!         connections.connect(link);
+//!     }
+//!     ui.label("Sink");
+//! });
+//!
+//! // The wires between them:
+//! let wires = connections.show(ui);
+//! if let Some(link) = wires.clicked {
+//!     connections.disconnect(link);
+//! }
+//!
+//! connections.store(ui.ctx(), graph_id);
+//! # });
+//! ```
+
+use egui::{
+    Color32, Context, CursorIcon, DragAndDrop, Id, Pos2, Response, Sense, Shape, Stroke, Ui,
+    epaint::CubicBezierShape, vec2,
+};
+
+/// Is a [`Port`] where links start or end?
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum PortKind {
+    /// Links start at outputs.
+    Output,
+
+    /// Links end at inputs.
+    Input,
+}
+
+/// A link from an output [`Port`] to an input [`Port`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Link {
+    pub output: Id,
+    pub input: Id,
+}
+
+/// Where a [`Port`] was last shown.
+#[derive(Clone, Copy, Debug)]
+struct PortState {
+    /// In global coordinates.
+    center: Pos2,
+}
+
+/// The payload of a wire being dragged out from a [`Port`].
+#[derive(Clone, Copy, Debug)]
+struct PortPayload {
+    port: Id,
+    kind: PortKind,
+}
+
+/// A place on a node where wires start or end.
+///
+/// Drag from one port to a port of the other [`PortKind`] to link them.
+/// The new [`Link`] is reported in [`PortResponse::new_link`] of the port it was dropped on.
+#[must_use = "You should call .show()"]
+pub struct Port {
+    id: Id,
+    kind: PortKind,
+    radius: f32,
+    color: Option<Color32>,
+}
+
+/// What [`Port::show`] returns.
+pub struct PortResponse {
+    pub response: Response,
+
+    /// Set when a wire from a compatible port was dropped on this port.
+    pub new_link: Option<Link>,
+}
+
+impl Port {
+    /// The id must be unique, and stay the same from frame to frame.
+    pub fn new(id: impl Into<Id>, kind: PortKind) -> Self {
+        Self {
+            id: id.into(),
+            kind,
+            radius: 5.0,
+            color: None,
+        }
+    }
+
+    pub fn input(id: impl Into<Id>) -> Self {
+        Self::new(id, PortKind::Input)
+    }
+
+    pub fn output(id: impl Into<Id>) -> Self {
+        Self::new(id, PortKind::Output)
+    }
+
+    /// Default: `5.0`.
+    #[inline]
+    pub fn radius(mut self, radius: f32) -> Self {
+        self.radius = radius;
+        self
+    }
+
+    /// Default: the foreground color of the style.
+    #[inline]
+    pub fn color(mut self, color: Color32) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    pub fn show(self, ui: &mut Ui) -> PortResponse {
+        let Self {
+            id,
+            kind,
+            radius,
+            color,
+        } = self;
+
+        let (rect, _) = ui.allocate_exact_size(vec2(2.0 * radius, 2.0 * radius), Sense::hover());
+        let response = ui
+            .interact(rect, id, Sense::click_and_drag())
+            .on_hover_cursor(CursorIcon::Crosshair);
+
+        let center = ui
+            .ctx()
+            .layer_transform_to_global(ui.layer_id())
+            .map_or(rect.center(), |to_global| to_global * rect.center());
+        ui.data_mut(|data| data.insert_temp(id, PortState { center }));
+
+        response.dnd_set_drag_payload(PortPayload { port: id, kind });
+
+        let accepts = |payload: &PortPayload| payload.kind != kind && payload.port != id;
+        let is_drop_target = response
+            .dnd_hover_payload::<PortPayload>()
+            .is_some_and(|payload| accepts(&payload));
+        let new_link = response
+            .dnd_release_payload::<PortPayload>()
+            .filter(|payload| accepts(payload))
+            .map(|payload| match kind {
+                PortKind::Input => Link {
+                    output: payload.port,
+                    input: id,
+                },
+                PortKind::Output => Link {
+                    output: id,
+                    input: payload.port,
+                },
+            });
+
+        if ui.is_rect_visible(rect) {
+            let visuals = ui.style().interact(&response);
+            let fill = color.unwrap_or(visuals.fg_stroke.color);
+            let stroke = if is_drop_target {
+                ui.visuals().selection.stroke
+            } else {
+                visuals.bg_stroke
+            };
+            let radius = if is_drop_target || response.hovered() {
+                radius
+            } else {
+                0.8 * radius
+            };
+            ui.painter().circle(rect.center(), radius, fill, stroke);
+        }
+
+        PortResponse { response, new_link }
+    }
+}
+
+/// A wire from an output to an input, as a bezier curve.
+///
+/// The wire leaves the output to the right, and enters the input from the left.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Wire {
+    pub from: Pos2,
+    pub to: Pos2,
+    pub stroke: Stroke,
+}
+
+impl Wire {
+    pub fn new(from: Pos2, to: Pos2, stroke: impl Into<Stroke>) -> Self {
+        Self {
+            from,
+            to,
+            stroke: stroke.into(),
+        }
+    }
+
+    pub fn bezier(&self) -> CubicBezierShape {
+        let Self { from, to, stroke } = *self;
+        let bend = (0.5 * (to.x - from.x).abs()).max(40.0);
+        CubicBezierShape::from_points_stroke(
+            [from, from + vec2(bend, 0.0), to - vec2(bend, 0.0), to],
+            false,
+            Color32::TRANSPARENT,
+            stroke,
+        )
+    }
+
+    /// Is the position within `tolerance` of the wire?
+    pub fn hit_test(&self, pos: Pos2, tolerance: f32) -> bool {
+        Shape::from(self.bezier()).distance_to(pos) <= tolerance
+    }
+}
+
+impl From<Wire> for Shape {
+    fn from(wire: Wire) -> Self {
+        wire.bezier().into()
+    }
+}
+
+/// What [`Connections::show`] returns.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct WiresResponse {
+    /// The link under the pointer, if any.
+    pub hovered: Option<Link>,
+
+    /// The link that was clicked, if any.
+    pub clicked: Option<Link>,
+}
+
+/// The [`Link`]s of a node graph.
+///
+/// Store it in your app, or in egui memory with [`Self::load`] and [`Self::store`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Connections {
+    links: Vec<Link>,
+}
+
+impl Connections {
+    /// Load the links from egui memory.
+    pub fn load(ctx: &Context, id: Id) -> Self {
+        #![allow(clippy::needless_return)]
+
+        #[cfg(feature = "serde")]
+        {
+            return ctx.data_mut(|d| d.get_persisted(id).unwrap_or_default());
+        }
+
+        #[cfg(not(feature = "serde"))]
+        {
+            return ctx.data_mut(|d| d.get_temp(id).unwrap_or_default());
+        }
+    }
+
+    /// Store the links in egui memory.
+    pub fn store(self, ctx: &Context, id: Id) {
+        #[cfg(feature = "serde")]
+        ctx.data_mut(|d| d.insert_persisted(id, self));
+
+        #[cfg(not(feature = "serde"))]
+        ctx.data_mut(|d| d.insert_temp(id, self));
+    }
+
+    pub fn links(&self) -> &[Link] {
+        &self.links
+    }
+
+    /// Add a link, unless it already exists.
+    ///
+    /// Returns `true` if it was added.
+    pub fn connect(&mut self, link: Link) -> bool {
+        if self.links.contains(&link) {
+            false
+        } else {
+            self.links.push(link);
+            true
+        }
+    }
+
+    /// Returns `true` if the link existed.
+    pub fn disconnect(&mut self, link: Link) -> bool {
+        let len = self.links.len();
+        self.links.retain(|l| *l != link);
+        self.links.len() != len
+    }
+
+    /// Remove all links to and from the port.
+    pub fn disconnect_port(&mut self, port: Id) {
+        self.links
+            .retain(|link| link.output != port && link.input != port);
+    }
+
+    /// Is the port linked to anything?
+    pub fn is_connected(&self, port: Id) -> bool {
+        self.links
+            .iter()
+            .any(|link| link.output == port || link.input == port)
+    }
+
+    /// Paint the wires between the [`Port`]s, and the wire being dragged out from a port.
+    ///
+    /// Call this after showing the ports, in a [`Ui`] behind the nodes.
+    pub fn show(&self, ui: &Ui) -> WiresResponse {
+        let ctx = ui.ctx();
+        let from_global = ctx.layer_transform_from_global(ui.layer_id());
+        let port_pos = |port: Id| {
+            let state = ui.data(|data| data.get_temp::<PortState>(port))?;
+            Some(from_global.map_or(state.center, |from_global| from_global * state.center))
+        };
+
+        let pointer_pos = ctx.pointer_hover_pos();
+        let pointer_in_layer =
+            pointer_pos.map(|pos| from_global.map_or(pos, |from_global| from_global * pos));
+        // Only hover wires if there is nothing else on top of them:
+        let can_hover = pointer_pos.is_some_and(|pos| ctx.layer_id_at(pos) == Some(ui.layer_id()))
+            && !DragAndDrop::has_any_payload(ctx);
+
+        let visuals = ui.visuals();
+        let mut response = WiresResponse::default();
+        for &link in &self.links {
+            let (Some(from), Some(to)) = (port_pos(link.output), port_pos(link.input)) else {
+                continue;
+            };
+            let mut wire = Wire::new(from, to, visuals.widgets.inactive.fg_stroke);
+            wire.stroke.width = wire.stroke.width.max(2.0);
+            if can_hover
+                && response.hovered.is_none()
+                && pointer_in_layer.is_some_and(|pos| wire.hit_test(pos, 4.0))
+            {
+                response.hovered = Some(link);
+                wire.stroke = Stroke::new(wire.stroke.width + 1.0, visuals.selection.stroke.color);
+            }
+            ui.painter().add(wire);
+        }
+
+        if response.hovered.is_some() && ui.input(|i| i.pointer.primary_clicked()) {
+            response.clicked = response.hovered;
+        }
+
+        // The wire being dragged out:
+        if let (Some(payload), Some(pointer_pos)) =
+            (DragAndDrop::payload::<PortPayload>(ctx), pointer_in_layer)
+        {
+            if let Some(port_pos) = port_pos(payload.port) {
+                let (from, to) = match payload.kind {
+                    PortKind::Output => (port_pos, pointer_pos),
+                    PortKind::Input => (pointer_pos, port_pos),
+                };
+                ui.painter()
+                    .add(Wire::new(from, to, visuals.selection.stroke));
+            }
+        }
+
+        response
+    }
+}
+
+#[test]
+fn test_wire_hit_test() {
+    let wire = Wire::new(
+        Pos2::ZERO,
+        egui::pos2(200.0, 100.0),
+        Stroke::new(2.0, Color32::WHITE),
+    );
+    assert!(wire.hit_test(Pos2::ZERO, 1.0));
+    assert!(
+        wire.hit_test(egui::pos2(100.0, 50.0), 2.0),
+        "The middle is on the wire"
+    );
+    assert!(!wire.hit_test(egui::pos2(0.0, 100.0), 4.0));
+
+    let link = Link {
+        output: Id::new("a"),
+        input: Id::new("b"),
+    };
+    let mut connections = Connections::default();
+    assert!(connections.connect(link));
+    assert!(!connections.connect(link), "Links are only added once");
+    assert!(connections.is_connected(link.input));
+    connections.disconnect_port(link.output);
+    assert!(connections.links().is_empty());
+}
@@ -37,6 +37,14 @@ pub(crate) struct StripLayoutFlags {
 
     /// Used when we want to accruately measure the size of this cell.
     pub(crate) sizing_pass: bool,
+
+    /// Move the cell this far to the right, without moving the cursor.
+    ///
+    /// Used for frozen table columns, which stay in view when scrolling horizontally.
+    pub(crate) shift_x: f32,
+
+    /// Hide everything of the cell left of this, e.g. where the frozen columns are.
+    pub(crate) clip_left: Option<f32>,
 }
 
 /// Positions cells in [`CellDirection`] and starts a new line on [`StripLayout::end_line`]
@@ -120,14 +128,20 @@ impl<'l> StripLayout<'l> {
         child_ui_id_salt: impl Into<Id>,
         add_cell_contents: impl FnOnce(&mut Ui),
     ) -> (Rect, Response) {
-        let max_rect = self.cell_rect(&width, &height);
+        let shift = egui::vec2(flags.shift_x, 0.0);
+        let max_rect = self.cell_rect(&width, &height).translate(shift);
 
         // Make sure we don't have a gap in the stripe/frame/selection background:
         let item_spacing = self.ui.spacing().item_spacing;
         let gapless_rect = max_rect.expand2(0.5 * item_spacing).round_ui();
 
+        let mut painter = self.ui.painter().clone();
+        if let Some(clip_left) = flags.clip_left {
+            painter.shrink_clip_rect(Rect::everything_right_of(clip_left));
+        }
+
         if flags.striped {
-            self.ui.painter().rect_filled(
+            painter.rect_filled(
                 gapless_rect,
                 egui::CornerRadius::ZERO,
                 self.ui.visuals().faint_bg_color,
@@ -135,7 +149,7 @@ impl<'l> StripLayout<'l> {
         }
 
         if flags.selected {
-            self.ui.painter().rect_filled(
+            painter.rect_filled(
                 gapless_rect,
                 egui::CornerRadius::ZERO,
                 self.ui.visuals().selection.bg_fill,
@@ -143,7 +157,7 @@ impl<'l> StripLayout<'l> {
         }
 
         if flags.hovered && !flags.selected && self.sense.interactive() {
-            self.ui.painter().rect_filled(
+            painter.rect_filled(
                 gapless_rect,
                 egui::CornerRadius::ZERO,
                 self.ui.visuals().widgets.hovered.bg_fill,
@@ -163,7 +177,8 @@ impl<'l> StripLayout<'l> {
             max_rect
         } else {
             max_rect | used_rect
-        };
+        }
+        .translate(-shift);
 
         self.set_pos(allocation_rect);
 
@@ -228,6 +243,10 @@ impl<'l> StripLayout<'l> {
             }
         }
 
+        if let Some(clip_left) = flags.clip_left {
+            child_ui.shrink_clip_rect(Rect::everything_right_of(clip_left));
+        }
+
         if flags.selected {
             let stroke_color = child_ui.style().visuals.selection.stroke.color;
             child_ui.style_mut().visuals.override_text_color = Some(stroke_color);
@@ -14,6 +14,9 @@ mod datepicker;
 
 pub mod syntax_highlighting;
 
+mod cell_editor;
+mod flame_graph;
+pub mod graph;
 #[doc(hidden)]
 pub mod image;
 mod layout;
@@ -21,14 +24,22 @@ mod loaders;
 mod sizing;
 mod strip;
 mod table;
+mod timeline;
+mod value_editor;
 
 #[cfg(feature = "chrono")]
 pub use crate::datepicker::DatePickerButton;
 
+pub use crate::cell_editor::{CellEditor, CellPaste, parse_tsv, to_tsv};
+pub use crate::flame_graph::{FlameGraph, FlameGraphResponse, Span};
 pub(crate) use crate::layout::StripLayout;
 pub use crate::sizing::Size;
 pub use crate::strip::*;
 pub use crate::table::*;
+pub use crate::timeline::{Clip, Timeline, TimelineEvent, TimelineResponse, Track};
+pub use crate::value_editor::{
+    PathSegment, Value, ValueEditor, ValueEditorResponse, ValueEvent, ValueKind,
+};
 
 pub use loaders::install_image_loaders;
 
@@ -180,6 +180,9 @@ fn to_sizing(columns: &[Column]) -> crate::sizing::Sizing {
 
 struct TableScrollOptions {
     vscroll: bool,
+    hscroll: bool,
+    frozen_columns: usize,
+    sync_group: Option<Id>,
     drag_to_scroll: bool,
     stick_to_bottom: bool,
     scroll_to_row: Option<(usize, Option<Align>)>,
@@ -195,6 +198,9 @@ impl Default for TableScrollOptions {
     fn default() -> Self {
         Self {
             vscroll: true,
+            hscroll: false,
+            frozen_columns: 0,
+            sync_group: None,
             drag_to_scroll: true,
             stick_to_bottom: false,
             scroll_to_row: None,
@@ -327,6 +333,69 @@ impl<'a> TableBuilder<'a> {
         self
     }
 
+    /// Enable horizontal scrolling of the header and body (default: `false`).
+    ///
+    /// This is useful for tables with many columns.
+    /// Only the cells that are in view are shown,
+    /// except for [`Column::auto`] columns, which need to be measured every frame.
+    ///
+    /// See also [`Self::frozen_columns`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui_extras::{Column, TableBuilder};
+    /// TableBuilder::new(ui)
+    ///     .hscroll(true)
+    ///     .frozen_columns(1)
+    ///     .columns(Column::initial(80.0), 200)
+    ///     .header(20.0, |mut header| {
+    ///         for col in 0..200 {
+    ///             header.col(|ui| {
+    ///                 ui.strong(format!("Column {col}"));
+    ///             });
+    ///         }
+    ///     })
+    ///     .body(|body| {
+    ///         body.rows(18.0, 1_000, |mut row| {
+    ///             let row_index = row.index();
+    ///             for col in 0..200 {
+    ///                 row.col(|ui| {
+    ///                     ui.label(format!("{row_index}, {col}"));
+    ///                 });
+    ///             }
+    ///         });
+    ///     });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn hscroll(mut self, hscroll: bool) -> Self {
+        self.scroll_options.hscroll = hscroll;
+        self
+    }
+
+    /// Keep the first `frozen_columns` columns in view when scrolling horizontally,
+    /// e.g. for a column with row names.
+    ///
+    /// Only has an effect with [`Self::hscroll`].
+    /// The header row is always frozen at the top.
+    ///
+    /// Default: `0`.
+    #[inline]
+    pub fn frozen_columns(mut self, frozen_columns: usize) -> Self {
+        self.scroll_options.frozen_columns = frozen_columns;
+        self
+    }
+
+    /// Keep the horizontal scroll offset in sync with other scroll areas or tables,
+    /// see [`ScrollArea::sync_group`].
+    ///
+    /// With [`Self::hscroll`], the header is always kept in sync with the body.
+    #[inline]
+    pub fn sync_group(mut self, sync_group: impl Into<Id>) -> Self {
+        self.scroll_options.sync_group = Some(sync_group.into());
+        self
+    }
+
     /// Enables scrolling the table's contents using mouse drag (default: `true`).
     ///
     /// See [`ScrollArea::drag_to_scroll`] for more.
@@ -493,25 +562,49 @@ impl<'a> TableBuilder<'a> {
         if is_sizing_pass {
             ui_builder = ui_builder.sizing_pass();
         }
-        ui.scope_builder(ui_builder, |ui| {
-            let mut layout = StripLayout::new(ui, CellDirection::Horizontal, cell_layout, sense);
-            let mut response: Option<Response> = None;
-            add_header_row(TableRow {
-                layout: &mut layout,
-                columns: &columns,
-                widths: &state.column_widths,
-                max_used_widths: &mut max_used_widths,
-                row_index: 0,
-                col_index: 0,
-                height,
-                striped: false,
-                hovered: false,
-                selected: false,
-                overline: false,
-                response: &mut response,
+        let add_header = |ui: &mut Ui, viewport: Option<Rect>| {
+            ui.scope_builder(ui_builder, |ui| {
+                let mut layout =
+                    StripLayout::new(ui, CellDirection::Horizontal, cell_layout, sense);
+                let hscroll = viewport.map(|viewport| {
+                    HScroll::new(
+                        scroll_options.frozen_columns,
+                        &state.column_widths,
+                        &layout,
+                        viewport,
+                    )
+                });
+                let mut response: Option<Response> = None;
+                add_header_row(TableRow {
+                    layout: &mut layout,
+                    columns: &columns,
+                    widths: &state.column_widths,
+                    max_used_widths: &mut max_used_widths,
+                    row_index: 0,
+                    col_index: 0,
+                    height,
+                    striped: false,
+                    hovered: false,
+                    selected: false,
+                    overline: false,
+                    hscroll,
+                    response: &mut response,
+                });
+                layout.allocate_rect();
             });
-            layout.allocate_rect();
-        });
+        };
+        if scroll_options.hscroll {
+            // The header scrolls along with the body:
+            ScrollArea::horizontal()
+                .id_salt(state_id.with("__header_scroll_area"))
+                .scroll_bar_visibility(ScrollBarVisibility::AlwaysHidden)
+                .auto_shrink([false, true])
+                .sync_group(hscroll_group(state_id, scroll_options.sync_group))
+                .sync_axes([true, false])
+                .show_viewport(ui, |ui, viewport| add_header(ui, Some(viewport)));
+        } else {
+            add_header(ui, None);
+        }
 
         Table {
             ui,
@@ -730,6 +823,9 @@ impl Table<'_> {
 
         let TableScrollOptions {
             vscroll,
+            hscroll,
+            frozen_columns,
+            sync_group,
             drag_to_scroll,
             stick_to_bottom,
             scroll_to_row,
@@ -743,7 +839,7 @@ impl Table<'_> {
 
         let cursor_position = ui.cursor().min;
 
-        let mut scroll_area = ScrollArea::new([false, vscroll])
+        let mut scroll_area = ScrollArea::new([hscroll, vscroll])
             .id_salt(state_id.with("__scroll_area"))
             .scroll_source(ScrollSource {
                 drag: drag_to_scroll,
@@ -760,11 +856,17 @@ impl Table<'_> {
             scroll_area = scroll_area.vertical_scroll_offset(scroll_offset_y);
         }
 
+        if hscroll {
+            scroll_area = scroll_area
+                .sync_group(hscroll_group(state_id, sync_group))
+                .sync_axes([true, false]);
+        }
+
         let columns_ref = &columns;
         let widths_ref = &state.column_widths;
         let max_used_widths_ref = &mut max_used_widths;
 
-        let scroll_area_out = scroll_area.show(ui, move |ui| {
+        let scroll_area_out = scroll_area.show_viewport(ui, move |ui, viewport| {
             let mut scroll_to_y_range = None;
 
             let clip_rect = ui.clip_rect();
@@ -779,11 +881,14 @@ impl Table<'_> {
                     ui.data_mut(|data| data.remove_temp::<usize>(hovered_row_index_id));
 
                 let layout = StripLayout::new(ui, CellDirection::Horizontal, cell_layout, sense);
+                let hscroll =
+                    hscroll.then(|| HScroll::new(frozen_columns, widths_ref, &layout, viewport));
 
                 add_body_contents(TableBody {
                     layout,
                     columns: columns_ref,
                     widths: widths_ref,
+                    hscroll,
                     max_used_widths: max_used_widths_ref,
                     striped,
                     row_index: 0,
@@ -812,6 +917,16 @@ impl Table<'_> {
 
         let spacing_x = ui.spacing().item_spacing.x;
         let mut x = cursor_position.x - spacing_x * 0.5;
+        let frozen_right = cursor_position.x - spacing_x * 0.5
+            + state.column_widths[..frozen_columns.min(columns.len())]
+                .iter()
+                .map(|width| width + spacing_x)
+                .sum::<f32>();
+        let scroll_offset_x = if hscroll {
+            scroll_area_out.state.offset.x
+        } else {
+            0.0
+        };
         for (i, column_width) in state.column_widths.iter_mut().enumerate() {
             let column = &columns[i];
             let column_is_resizable = column.resizable.unwrap_or(resizable);
@@ -848,9 +963,17 @@ impl Table<'_> {
 
             x += *column_width + spacing_x;
 
+            // Where the resize handle is on screen, when scrolling horizontally:
+            let is_frozen = i < frozen_columns;
+            let handle_x = if is_frozen { x } else { x - scroll_offset_x };
+            let is_handle_visible = !hscroll
+                || is_frozen
+                || (frozen_right < handle_x && handle_x <= scroll_area_out.inner_rect.right());
+
             if column.is_auto() && (is_sizing_pass || !column_is_resizable) {
                 *column_width = width_range.clamp(max_used_widths[i]);
-            } else if column_is_resizable {
+            } else if column_is_resizable && is_handle_visible {
+                let x = handle_x;
                 let column_resize_id = ui.id().with("resize_column").with(i);
 
                 let mut p0 = egui::pos2(x, table_top);
@@ -917,6 +1040,50 @@ impl Table<'_> {
     }
 }
 
+/// Where things are when scrolling horizontally, see [`TableBuilder::hscroll`].
+#[derive(Clone, Copy, Debug)]
+struct HScroll {
+    frozen_columns: usize,
+
+    /// How far to move the frozen columns to keep them in view.
+    shift: f32,
+
+    /// The right edge of the frozen columns on screen.
+    /// The other columns are hidden left of this.
+    frozen_right: f32,
+
+    /// The part of the other columns that is in view.
+    visible: Rangef,
+}
+
+impl HScroll {
+    fn new(
+        frozen_columns: usize,
+        widths: &[f32],
+        layout: &StripLayout<'_>,
+        viewport: Rect,
+    ) -> Self {
+        let spacing_x = layout.ui.spacing().item_spacing.x;
+        let left = layout.rect.left();
+        let shift = viewport.left();
+        let frozen_width: f32 = widths[..frozen_columns.min(widths.len())]
+            .iter()
+            .map(|width| width + spacing_x)
+            .sum();
+        let frozen_right = left + shift + frozen_width - 0.5 * spacing_x;
+        Self {
+            frozen_columns,
+            shift,
+            frozen_right,
+            visible: Rangef::new(frozen_right, left + viewport.right()),
+        }
+    }
+}
+
+fn hscroll_group(state_id: Id, sync_group: Option<Id>) -> Id {
+    sync_group.unwrap_or_else(|| state_id.with("__hscroll_group"))
+}
+
 /// The body of a table.
 ///
 /// Is created by calling `body` on a [`Table`] (after adding a header row) or [`TableBuilder`] (without a header row).
@@ -928,6 +1095,9 @@ pub struct TableBody<'a> {
     /// Current column widths.
     widths: &'a [f32],
 
+    /// Set when scrolling horizontally.
+    hscroll: Option<HScroll>,
+
     /// Accumulated maximum used widths for each column.
     max_used_widths: &'a mut [f32],
 
@@ -995,6 +1165,7 @@ impl<'a> TableBody<'a> {
             hovered: self.hovered_row_index == Some(self.row_index),
             selected: false,
             overline: false,
+            hscroll: self.hscroll,
             response: &mut response,
         });
         self.capture_hover_state(&response, self.row_index);
@@ -1077,6 +1248,7 @@ impl<'a> TableBody<'a> {
                 hovered: self.hovered_row_index == Some(row_index),
                 selected: false,
                 overline: false,
+                hscroll: self.hscroll,
                 response: &mut response,
             });
             self.capture_hover_state(&response, row_index);
@@ -1159,6 +1331,7 @@ impl<'a> TableBody<'a> {
                     hovered: self.hovered_row_index == Some(row_index),
                     selected: false,
                     overline: false,
+                    hscroll: self.hscroll,
                     response: &mut response,
                 });
                 self.capture_hover_state(&response, row_index);
@@ -1182,6 +1355,7 @@ impl<'a> TableBody<'a> {
                 hovered: self.hovered_row_index == Some(row_index),
                 overline: false,
                 selected: false,
+                hscroll: self.hscroll,
                 response: &mut response,
             });
             self.capture_hover_state(&response, row_index);
@@ -1270,6 +1444,9 @@ pub struct TableRow<'a, 'b> {
     selected: bool,
     overline: bool,
 
+    /// Set when scrolling horizontally.
+    hscroll: Option<HScroll>,
+
     response: &'b mut Option<Response>,
 }
 
@@ -1298,25 +1475,41 @@ impl TableRow<'_, '_> {
             8.0 // anything will look wrong, so pick something that is obviously wrong
         };
 
-        let width = CellSize::Absolute(width);
-        let height = CellSize::Absolute(self.height);
-
-        let flags = StripLayoutFlags {
+        let mut flags = StripLayoutFlags {
             clip,
             striped: self.striped,
             hovered: self.hovered,
             selected: self.selected,
             overline: self.overline,
             sizing_pass: auto_size_this_frame || self.layout.ui.is_sizing_pass(),
+            ..Default::default()
         };
 
-        let (used_rect, response) = self.layout.add(
-            flags,
-            width,
-            height,
-            egui::Id::new((self.row_index, col_index)),
-            add_cell_contents,
-        );
+        let mut is_visible = true;
+        if let Some(hscroll) = self.hscroll {
+            if col_index < hscroll.frozen_columns {
+                flags.shift_x = hscroll.shift;
+            } else {
+                flags.clip_left = Some(hscroll.frozen_right);
+                let left = self.layout.cursor.x;
+                let is_auto = self.columns.get(col_index).is_some_and(|c| c.is_auto());
+                is_visible = is_auto
+                    || flags.sizing_pass
+                    || Rangef::new(left, left + width).intersects(hscroll.visible);
+            }
+        }
+
+        let width = CellSize::Absolute(width);
+        let height = CellSize::Absolute(self.height);
+        let id_salt = egui::Id::new((self.row_index, col_index));
+
+        let (used_rect, response) = if is_visible {
+            self.layout
+                .add(flags, width, height, id_salt, add_cell_contents)
+        } else {
+            // Out of view, so skip the contents:
+            self.layout.add(flags, width, height, id_salt, |_| {})
+        };
 
         if let Some(max_w) = self.max_used_widths.get_mut(col_index) {
             *max_w = max_w.max(used_rect.width());
@@ -0,0 +1,698 @@
+use egui::{
+    Align2, Color32, CursorIcon, Id, Pos2, Rangef, Rect, Response, Sense, Shape, Stroke,
+    StrokeKind, TextStyle, Ui, pos2, vec2,
+};
+
+/// A clip on a [`Track`] of a [`Timeline`], e.g. a video or audio clip.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Clip {
+    pub label: String,
+
+    /// When the clip starts, in seconds.
+    pub start: f64,
+
+    /// How long the clip is, in seconds.
+    pub duration: f64,
+
+    /// If `None`, the selection color of the style is used.
+    pub color: Option<Color32>,
+}
+
+impl Clip {
+    pub fn new(label: impl Into<String>, start: f64, duration: f64) -> Self {
+        Self {
+            label: label.into(),
+            start,
+            duration,
+            color: None,
+        }
+    }
+
+    #[inline]
+    pub fn color(mut self, color: Color32) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// When the clip ends, in seconds.
+    #[inline]
+    pub fn end(&self) -> f64 {
+        self.start + self.duration
+    }
+}
+
+/// A lane of [`Clip`]s in a [`Timeline`].
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Track {
+    pub name: String,
+    pub clips: Vec<Clip>,
+}
+
+impl Track {
+    pub fn new(name: impl Into<String>, clips: Vec<Clip>) -> Self {
+        Self {
+            name: name.into(),
+            clips,
+        }
+    }
+}
+
+/// An edit the user made in a [`Timeline`].
+///
+/// The edits have already been applied to the [`Track`]s when you get them.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TimelineEvent {
+    /// A clip was clicked.
+    ClipClicked { track: usize, clip: usize },
+
+    /// A clip was dragged to a new start time.
+    ClipMoved {
+        track: usize,
+        clip: usize,
+        start: f64,
+    },
+
+    /// A clip was resized by dragging one of its edges.
+    ClipResized {
+        track: usize,
+        clip: usize,
+        start: f64,
+        duration: f64,
+    },
+
+    /// The playhead was moved by clicking or dragging in the ruler.
+    PlayheadMoved { time: f64 },
+}
+
+/// What [`Timeline::show`] returns.
+pub struct TimelineResponse {
+    /// The response of the whole timeline.
+    pub response: Response,
+
+    /// The edits made by the user this pass, in the order they happened.
+    pub events: Vec<TimelineEvent>,
+}
+
+/// Which part of the timeline is visible.
+#[derive(Clone, Copy, Debug)]
+struct View {
+    /// The time at the left edge.
+    start: f64,
+
+    /// The zoom level.
+    points_per_second: f64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum DragKind {
+    Move,
+    ResizeStart,
+    ResizeEnd,
+}
+
+/// The clip being dragged, and where it was when the drag started.
+#[derive(Clone, Copy, Debug)]
+struct ClipDrag {
+    track: usize,
+    clip: usize,
+    kind: DragKind,
+    start: f64,
+    end: f64,
+    pointer_time: f64,
+}
+
+/// A timeline editor, with a time ruler, a playhead, and [`Track`]s of [`Clip`]s.
+///
+/// The clips can be moved by dragging them, and resized by dragging their edges.
+/// They snap to each other, to the playhead and (optionally) to a grid.
+/// Zoom with ctrl+scroll, and pan with horizontal scrolling.
+///
+/// The [`Track`]s are edited in place, and the edits are also reported
+/// as [`TimelineEvent`]s, so you can e.g. add them to an undo stack.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_extras::{Clip, Timeline, Track};
+///
+/// # let mut tracks = vec![
+/// #     Track::new("Video", vec![Clip::new("Intro", 0.0, 2.5), Clip::new("Main", 3.0, 10.0)]),
+/// #     Track::new("Audio", vec![Clip::new("Music", 0.0, 12.0)]),
+/// # ];
+/// # let mut playhead = 0.0;
+/// let response = Timeline::new("timeline")
+///     .snap_interval(Some(0.5))
+///     .show(ui, &mut tracks, &mut playhead);
+/// for event in response.events {
+///     println!("{event:?}");
+/// }
+/// # });
+/// ```
+#[derive(Clone, Debug)]
+#[must_use = "You should call .show()"]
+pub struct Timeline {
+    id_salt: Id,
+    header_width: f32,
+    ruler_height: f32,
+    lane_height: f32,
+    zoom_range: Rangef,
+    snap_interval: Option<f64>,
+    snap_distance: f32,
+    min_clip_duration: f64,
+}
+
+impl Timeline {
+    pub fn new(id_salt: impl std::hash::Hash) -> Self {
+        Self {
+            id_salt: Id::new(id_salt),
+            header_width: 100.0,
+            ruler_height: 24.0,
+            lane_height: 32.0,
+            zoom_range: Rangef::new(1.0, 10_000.0),
+            snap_interval: None,
+            snap_distance: 8.0,
+            min_clip_duration: 0.01,
+        }
+    }
+
+    /// The width of the track names to the left of the lanes.
+    ///
+    /// Default: `100.0`.
+    #[inline]
+    pub fn header_width(mut self, header_width: f32) -> Self {
+        self.header_width = header_width;
+        self
+    }
+
+    /// The height of the time ruler at the top.
+    ///
+    /// Default: `24.0`.
+    #[inline]
+    pub fn ruler_height(mut self, ruler_height: f32) -> Self {
+        self.ruler_height = ruler_height;
+        self
+    }
+
+    /// The height of each [`Track`].
+    ///
+    /// Default: `32.0`.
+    #[inline]
+    pub fn lane_height(mut self, lane_height: f32) -> Self {
+        self.lane_height = lane_height;
+        self
+    }
+
+    /// How far the user can zoom, in points per second.
+    ///
+    /// Default: `1.0..=10_000.0`.
+    #[inline]
+    pub fn zoom_range(mut self, zoom_range: impl Into<Rangef>) -> Self {
+        self.zoom_range = zoom_range.into();
+        self
+    }
+
+    /// Snap clips and the playhead to multiples of this many seconds.
+    ///
+    /// Clips always snap to the edges of other clips and to the playhead.
+    ///
+    /// Default: `None`.
+    #[inline]
+    pub fn snap_interval(mut self, snap_interval: Option<f64>) -> Self {
+        self.snap_interval = snap_interval;
+        self
+    }
+
+    /// How close (in points) something must be to snap.
+    ///
+    /// Default: `8.0`.
+    #[inline]
+    pub fn snap_distance(mut self, snap_distance: f32) -> Self {
+        self.snap_distance = snap_distance;
+        self
+    }
+
+    /// Clips can't be resized to be shorter than this, in seconds.
+    ///
+    /// Default: `0.01`.
+    #[inline]
+    pub fn min_clip_duration(mut self, min_clip_duration: f64) -> Self {
+        self.min_clip_duration = min_clip_duration;
+        self
+    }
+
+    /// Show the timeline, and let the user edit the `tracks` and move the `playhead`.
+    ///
+    /// The `playhead` is the current time, in seconds.
+    pub fn show(self, ui: &mut Ui, tracks: &mut [Track], playhead: &mut f64) -> TimelineResponse {
+        let id = ui.make_persistent_id(self.id_salt);
+        let mut view = ui.data(|data| data.get_temp::<View>(id)).unwrap_or(View {
+            start: 0.0,
+            points_per_second: 100.0,
+        });
+        let mut events = vec![];
+
+        let height = self.ruler_height + tracks.len() as f32 * self.lane_height;
+        let (rect, response) =
+            ui.allocate_exact_size(vec2(ui.available_width(), height), Sense::hover());
+        let lanes_rect = rect.with_min_x(rect.left() + self.header_width);
+        let ruler_rect = lanes_rect.with_max_y(lanes_rect.top() + self.ruler_height);
+
+        if response.contains_pointer() {
+            self.zoom_and_pan(ui, lanes_rect, &mut view);
+        }
+
+        let x_from_time =
+            |time: f64| lanes_rect.left() + ((time - view.start) * view.points_per_second) as f32;
+        let time_from_x =
+            |x: f32| view.start + (x - lanes_rect.left()) as f64 / view.points_per_second;
+
+        // Background and track names:
+        let painter = ui.painter_at(rect);
+        for (index, track) in tracks.iter().enumerate() {
+            let top = ruler_rect.bottom() + index as f32 * self.lane_height;
+            let lane_rect = Rect::from_x_y_ranges(rect.x_range(), top..=top + self.lane_height);
+            if index % 2 == 1 {
+                painter.rect_filled(lane_rect, 0.0, ui.visuals().faint_bg_color);
+            }
+            painter.text_elided(
+                pos2(rect.left() + 4.0, lane_rect.center().y),
+                Align2::LEFT_CENTER,
+                &track.name,
+                TextStyle::Body.resolve(ui.style()),
+                ui.visuals().text_color(),
+                self.header_width - 8.0,
+            );
+        }
+
+        // Move the playhead by clicking or dragging the ruler:
+        let ruler_response = ui
+            .interact(ruler_rect, id.with("ruler"), Sense::click_and_drag())
+            .on_hover_cursor(CursorIcon::ResizeHorizontal);
+        if ruler_response.clicked() || ruler_response.dragged() {
+            if let Some(pointer_pos) = ruler_response.interact_pointer_pos() {
+                let time = time_from_x(pointer_pos.x).max(0.0);
+                let time = self
+                    .snap_to_grid(time, view.points_per_second)
+                    .unwrap_or(time);
+                if time != *playhead {
+                    *playhead = time;
+                    events.push(TimelineEvent::PlayheadMoved { time });
+                }
+            }
+        }
+        Self::paint_ruler(ui, ruler_rect, &view);
+
+        // Clips:
+        let lanes_painter = ui.painter_at(lanes_rect.with_min_y(ruler_rect.bottom()));
+        let from_global = ui.ctx().layer_transform_from_global(ui.layer_id());
+        let to_layer = |pos: Pos2| from_global.map_or(pos, |from_global| from_global * pos);
+        let drag_id = id.with("drag");
+        let mut drag = ui.data(|data| data.get_temp::<ClipDrag>(drag_id));
+        for track_index in 0..tracks.len() {
+            let top = ruler_rect.bottom() + track_index as f32 * self.lane_height;
+            for clip_index in 0..tracks[track_index].clips.len() {
+                let clip = &tracks[track_index].clips[clip_index];
+                let clip_rect = Rect::from_x_y_ranges(
+                    x_from_time(clip.start)..=x_from_time(clip.end()),
+                    top + 2.0..=top + self.lane_height - 2.0,
+                );
+                let interact_rect = clip_rect.intersect(lanes_rect);
+                if !interact_rect.is_positive()
+                    && drag.is_none_or(|drag| (drag.track, drag.clip) != (track_index, clip_index))
+                {
+                    continue; // Not visible
+                }
+
+                let clip_id = id.with(("clip", track_index, clip_index));
+                let body = ui
+                    .interact(interact_rect, clip_id, Sense::click_and_drag())
+                    .on_hover_cursor(CursorIcon::Grab);
+                let handle_width = (clip_rect.width() / 4.0).min(6.0);
+                let start_handle = ui
+                    .interact(
+                        clip_rect
+                            .with_max_x(clip_rect.left() + handle_width)
+                            .intersect(lanes_rect),
+                        clip_id.with("start"),
+                        Sense::drag(),
+                    )
+                    .on_hover_cursor(CursorIcon::ResizeHorizontal);
+                let end_handle = ui
+                    .interact(
+                        clip_rect
+                            .with_min_x(clip_rect.right() - handle_width)
+                            .intersect(lanes_rect),
+                        clip_id.with("end"),
+                        Sense::drag(),
+                    )
+                    .on_hover_cursor(CursorIcon::ResizeHorizontal);
+
+                for (handle, kind) in [
+                    (&body, DragKind::Move),
+                    (&start_handle, DragKind::ResizeStart),
+                    (&end_handle, DragKind::ResizeEnd),
+                ] {
+                    if handle.drag_started() {
+                        // Start where the pointer was pressed, not where the drag was detected:
+                        let press_origin = ui.input(|i| i.pointer.press_origin());
+                        if let Some(pointer_pos) = press_origin.map(to_layer) {
+                            drag = Some(ClipDrag {
+                                track: track_index,
+                                clip: clip_index,
+                                kind,
+                                start: clip.start,
+                                end: clip.end(),
+                                pointer_time: time_from_x(pointer_pos.x),
+                            });
+                        }
+                    }
+                }
+
+                if body.clicked() {
+                    events.push(TimelineEvent::ClipClicked {
+                        track: track_index,
+                        clip: clip_index,
+                    });
+                }
+
+                let is_dragged = body.dragged() || start_handle.dragged() || end_handle.dragged();
+                if let Some(clip_drag) = drag.filter(|drag| {
+                    is_dragged && (drag.track, drag.clip) == (track_index, clip_index)
+                }) {
+                    if let Some(pointer_pos) = ui.ctx().pointer_interact_pos().map(to_layer) {
+                        let delta = time_from_x(pointer_pos.x) - clip_drag.pointer_time;
+                        let snap_targets =
+                            snap_targets(tracks, (track_index, clip_index), *playhead);
+                        if let Some(event) = self.drag_clip(
+                            &mut tracks[track_index].clips[clip_index],
+                            clip_drag,
+                            delta,
+                            &snap_targets,
+                            view.points_per_second,
+                        ) {
+                            events.push(event);
+                        }
+                    }
+                }
+                if body.drag_stopped() || start_handle.drag_stopped() || end_handle.drag_stopped() {
+                    drag = None;
+                }
+
+                let clip = &tracks[track_index].clips[clip_index];
+                let clip_rect = Rect::from_x_y_ranges(
+                    x_from_time(clip.start)..=x_from_time(clip.end()),
+                    clip_rect.y_range(),
+                );
+                let visuals = if is_dragged {
+                    &ui.visuals().widgets.active
+                } else if body.hovered() || start_handle.hovered() || end_handle.hovered() {
+                    &ui.visuals().widgets.hovered
+                } else {
+                    &ui.visuals().widgets.inactive
+                };
+                let fill = clip.color.unwrap_or(ui.visuals().selection.bg_fill);
+                lanes_painter.rect(
+                    clip_rect,
+                    visuals.corner_radius,
+                    fill,
+                    visuals.fg_stroke,
+                    StrokeKind::Inside,
+                );
+                let text_pos = pos2(
+                    clip_rect.left().max(lanes_rect.left()) + 4.0,
+                    clip_rect.center().y,
+                );
+                lanes_painter.text_elided(
+                    text_pos,
+                    Align2::LEFT_CENTER,
+                    &clip.label,
+                    TextStyle::Small.resolve(ui.style()),
+                    ui.visuals().strong_text_color(),
+                    clip_rect.right() - text_pos.x - 4.0,
+                );
+            }
+        }
+        ui.data_mut(|data| {
+            if let Some(drag) = drag {
+                data.insert_temp(drag_id, drag);
+            } else {
+                data.remove::<ClipDrag>(drag_id);
+            }
+        });
+
+        // Playhead:
+        let playhead_x = x_from_time(*playhead);
+        if lanes_rect.x_range().contains(playhead_x) {
+            let color = ui.visuals().error_fg_color;
+            painter.vline(playhead_x, lanes_rect.y_range(), Stroke::new(2.0, color));
+            let size = ruler_rect.height() / 3.0;
+            painter.add(Shape::convex_polygon(
+                vec![
+                    pos2(playhead_x - size, ruler_rect.top()),
+                    pos2(playhead_x + size, ruler_rect.top()),
+                    pos2(playhead_x, ruler_rect.top() + size),
+                ],
+                color,
+                Stroke::NONE,
+            ));
+        }
+
+        ui.data_mut(|data| data.insert_temp(id, view));
+
+        TimelineResponse { response, events }
+    }
+
+    /// Zoom with ctrl+scroll around the pointer, and pan with horizontal scrolling.
+    fn zoom_and_pan(&self, ui: &Ui, lanes_rect: Rect, view: &mut View) {
+        let (zoom_delta, scroll_delta, pointer_pos) = ui.input(|i| {
+            (
+                i.zoom_delta(),
+                i.smooth_scroll_delta.x,
+                i.pointer.hover_pos(),
+            )
+        });
+
+        if zoom_delta != 1.0 {
+            let anchor_x = pointer_pos.map_or(lanes_rect.left(), |pos| pos.x) - lanes_rect.left();
+            let anchor_time = view.start + anchor_x as f64 / view.points_per_second;
+            view.points_per_second = (view.points_per_second * zoom_delta as f64)
+                .clamp(self.zoom_range.min as f64, self.zoom_range.max as f64);
+            view.start = anchor_time - anchor_x as f64 / view.points_per_second;
+        }
+
+        if scroll_delta != 0.0 {
+            view.start -= scroll_delta as f64 / view.points_per_second;
+            ui.ctx().input_mut(|i| i.smooth_scroll_delta.x = 0.0);
+        }
+
+        view.start = view.start.max(0.0);
+    }
+
+    fn paint_ruler(ui: &Ui, ruler_rect: Rect, view: &View) {
+        let painter = ui.painter_at(ruler_rect);
+        let visuals = ui.visuals();
+        painter.rect_filled(ruler_rect, 0.0, visuals.extreme_bg_color);
+
+        let (step, minor_step) = tick_steps(view.points_per_second);
+        let end = view.start + ruler_rect.width() as f64 / view.points_per_second;
+        let font_id = TextStyle::Small.resolve(ui.style());
+
+        let first = (view.start / minor_step).floor() as i64;
+        let last = (end / minor_step).ceil() as i64;
+        let minor_per_step = (step / minor_step).round() as i64;
+        for i in first..=last {
+            let time = i as f64 * minor_step;
+            let x = ruler_rect.left() + ((time - view.start) * view.points_per_second) as f32;
+            let is_major = i.rem_euclid(minor_per_step) == 0;
+            let tick_height = if is_major {
+                ruler_rect.height()
+            } else {
+                ruler_rect.height() / 4.0
+            };
+            painter.vline(
+                x,
+                ruler_rect.bottom() - tick_height..=ruler_rect.bottom(),
+                visuals.widgets.noninteractive.bg_stroke,
+            );
+            if is_major {
+                painter.text(
+                    pos2(x + 3.0, ruler_rect.top() + 2.0),
+                    Align2::LEFT_TOP,
+                    format_time(time, step),
+                    font_id.clone(),
+                    visuals.weak_text_color(),
+                );
+            }
+        }
+    }
+
+    /// Apply a drag to the clip, snapping it to the targets.
+    fn drag_clip(
+        &self,
+        clip: &mut Clip,
+        drag: ClipDrag,
+        delta: f64,
+        snap_targets: &[f64],
+        points_per_second: f64,
+    ) -> Option<TimelineEvent> {
+        let snap = |time: f64| self.snap(time, snap_targets, points_per_second);
+        let (start, end) = match drag.kind {
+            DragKind::Move => {
+                let start = (drag.start + delta).max(0.0);
+                let duration = drag.end - drag.start;
+                // Snap whichever edge is closest to something:
+                let snapped_start = snap(start).map(|snapped| (snapped, snapped - start));
+                let snapped_end = snap(start + duration)
+                    .map(|snapped| (snapped - duration, snapped - (start + duration)));
+                let start = match (snapped_start, snapped_end) {
+                    (Some(a), Some(b)) => {
+                        if a.1.abs() <= b.1.abs() {
+                            a.0
+                        } else {
+                            b.0
+                        }
+                    }
+                    (Some((snapped, _)), None) | (None, Some((snapped, _))) => snapped,
+                    (None, None) => start,
+                };
+                let start = start.max(0.0);
+                (start, start + duration)
+            }
+            DragKind::ResizeStart => {
+                let start = drag.start + delta;
+                let start = snap(start).unwrap_or(start);
+                (
+                    start.clamp(0.0, drag.end - self.min_clip_duration),
+                    drag.end,
+                )
+            }
+            DragKind::ResizeEnd => {
+                let end = drag.end + delta;
+                let end = snap(end).unwrap_or(end);
+                (drag.start, end.max(drag.start + self.min_clip_duration))
+            }
+        };
+
+        if start == clip.start && end == clip.end() {
+            return None;
+        }
+        clip.start = start;
+        clip.duration = end - start;
+        Some(if drag.kind == DragKind::Move {
+            TimelineEvent::ClipMoved {
+                track: drag.track,
+                clip: drag.clip,
+                start,
+            }
+        } else {
+            TimelineEvent::ClipResized {
+                track: drag.track,
+                clip: drag.clip,
+                start,
+                duration: clip.duration,
+            }
+        })
+    }
+
+    /// The closest target or grid line, if it is within [`Self::snap_distance`].
+    fn snap(&self, time: f64, targets: &[f64], points_per_second: f64) -> Option<f64> {
+        let max_distance = self.snap_distance as f64 / points_per_second;
+        self.snap_to_grid(time, points_per_second)
+            .into_iter()
+            .chain(targets.iter().copied())
+            .filter(|target| (target - time).abs() <= max_distance)
+            .min_by(|a, b| (a - time).abs().total_cmp(&(b - time).abs()))
+    }
+
+    fn snap_to_grid(&self, time: f64, points_per_second: f64) -> Option<f64> {
+        let interval = self.snap_interval.filter(|interval| 0.0 < *interval)?;
+        let snapped = (time / interval).round() * interval;
+        let max_distance = self.snap_distance as f64 / points_per_second;
+        ((snapped - time).abs() <= max_distance).then_some(snapped)
+    }
+}
+
+/// The edges of all other clips, and the playhead.
+fn snap_targets(tracks: &[Track], (track, clip): (usize, usize), playhead: f64) -> Vec<f64> {
+    let mut targets = vec![playhead];
+    for (track_index, other_track) in tracks.iter().enumerate() {
+        for (clip_index, other) in other_track.clips.iter().enumerate() {
+            if (track_index, clip_index) != (track, clip) {
+                targets.extend([other.start, other.end()]);
+            }
+        }
+    }
+    targets
+}
+
+/// The time between labeled ticks, and between the small ticks in between,
+/// so that the labels have room.
+fn tick_steps(points_per_second: f64) -> (f64, f64) {
+    let min_label_spacing = 80.0; // points
+    let min_step = min_label_spacing / points_per_second;
+
+    // A "nice" step: 1, 2 or 5 times a power of ten, or a nice number of minutes:
+    let candidates = [1.0, 2.0, 5.0];
+    let magnitude = 10_f64.powf(min_step.log10().floor());
+    let mut step = candidates
+        .iter()
+        .map(|c| c * magnitude)
+        .find(|step| min_step <= *step)
+        .unwrap_or(10.0 * magnitude);
+    if 10.0 < step && step < 60.0 {
+        step = if step <= 15.0 {
+            15.0
+        } else if step <= 30.0 {
+            30.0
+        } else {
+            60.0
+        };
+    }
+
+    let mantissa = step / 10_f64.powf(step.log10().floor());
+    let minor_step = if (mantissa - 2.0).abs() < 1e-6 {
+        step / 4.0
+    } else if (mantissa - 1.5).abs() < 1e-6
+        || (mantissa - 3.0).abs() < 1e-6
+        || (mantissa - 6.0).abs() < 1e-6
+    {
+        step / 3.0
+    } else {
+        step / 5.0
+    };
+    (step, minor_step)
+}
+
+/// Format a time for the ruler, with as many decimals as the tick step needs.
+///
+/// Times of a minute or longer are shown as `m:ss`.
+fn format_time(time: f64, step: f64) -> String {
+    if time < 0.0 {
+        return format!("-{}", format_time(-time, step));
+    }
+    let decimals = (-step.log10().floor()).max(0.0) as usize;
+    if time < 60.0 {
+        format!("{time:.decimals$}s")
+    } else {
+        let minutes = (time / 60.0).floor();
+        let seconds = time - 60.0 * minutes;
+        let width = if decimals == 0 { 2 } else { decimals + 3 };
+        format!("{minutes}:{seconds:0width$.decimals$}")
+    }
+}
+
+#[test]
+fn test_ruler_ticks() {
+    let approx_eq =
+        |(a, b): (f64, f64), (c, d): (f64, f64)| (a - c).abs() < 1e-9 && (b - d).abs() < 1e-9;
+    assert!(approx_eq(tick_steps(100.0), (1.0, 0.2)));
+    assert!(approx_eq(tick_steps(1000.0), (0.1, 0.02)));
+    assert!(approx_eq(tick_steps(50.0), (2.0, 0.5)));
+    assert!(approx_eq(tick_steps(4.0), (30.0, 10.0)));
+
+    assert_eq!(format_time(1.5, 0.5), "1.5s");
+    assert_eq!(format_time(2.0, 1.0), "2s");
+    assert_eq!(format_time(90.0, 30.0), "1:30");
+    assert_eq!(format_time(61.5, 0.5), "1:01.5");
+}
@@ -0,0 +1,662 @@
+use egui::{
+    Checkbox, ComboBox, DragValue, Id, Response, TextEdit, Ui, collapsing_header::CollapsingState,
+};
+
+/// A dynamically typed value, like the ones in JSON and TOML documents.
+///
+/// With the `serde` feature, this can be (de)serialized with any serde format,
+/// e.g. `serde_json::from_str::<Value>(json)`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum Value {
+    #[default]
+    Null,
+    Bool(bool),
+
+    /// All numbers are stored as `f64`, so very large integers lose precision.
+    Number(f64),
+    String(String),
+    Array(Vec<Self>),
+
+    /// The entries of an object, in order.
+    Object(Vec<(String, Self)>),
+}
+
+/// The type of a [`Value`], without its contents.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ValueKind {
+    Null,
+    Bool,
+    Number,
+    String,
+    Array,
+    Object,
+}
+
+impl ValueKind {
+    pub const ALL: [Self; 6] = [
+        Self::Null,
+        Self::Bool,
+        Self::Number,
+        Self::String,
+        Self::Array,
+        Self::Object,
+    ];
+
+    /// Human-readable name, e.g. `"object"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Null => "null",
+            Self::Bool => "bool",
+            Self::Number => "number",
+            Self::String => "string",
+            Self::Array => "array",
+            Self::Object => "object",
+        }
+    }
+}
+
+impl Value {
+    pub fn kind(&self) -> ValueKind {
+        match self {
+            Self::Null => ValueKind::Null,
+            Self::Bool(_) => ValueKind::Bool,
+            Self::Number(_) => ValueKind::Number,
+            Self::String(_) => ValueKind::String,
+            Self::Array(_) => ValueKind::Array,
+            Self::Object(_) => ValueKind::Object,
+        }
+    }
+
+    /// Convert the value to another kind, keeping as much of it as makes sense.
+    ///
+    /// For instance, the string `"42"` becomes the number `42`,
+    /// and an array becomes an object with the indices as keys.
+    pub fn converted_to(&self, kind: ValueKind) -> Self {
+        match (self, kind) {
+            (_, ValueKind::Null) => Self::Null,
+            (Self::Bool(b), ValueKind::Bool) => Self::Bool(*b),
+            (Self::Number(n), ValueKind::Bool) => Self::Bool(*n != 0.0),
+            (Self::String(s), ValueKind::Bool) => Self::Bool(s == "true"),
+            (_, ValueKind::Bool) => Self::Bool(false),
+            (Self::Bool(b), ValueKind::Number) => Self::Number(if *b { 1.0 } else { 0.0 }),
+            (Self::Number(n), ValueKind::Number) => Self::Number(*n),
+            (Self::String(s), ValueKind::Number) => Self::Number(s.trim().parse().unwrap_or(0.0)),
+            (_, ValueKind::Number) => Self::Number(0.0),
+            (Self::Array(_) | Self::Object(_), ValueKind::String) => Self::String(String::new()),
+            (scalar, ValueKind::String) => Self::String(scalar.scalar_text()),
+            (Self::Array(values), ValueKind::Array) => Self::Array(values.clone()),
+            (Self::Object(entries), ValueKind::Array) => {
+                Self::Array(entries.iter().map(|(_, value)| value.clone()).collect())
+            }
+            (_, ValueKind::Array) => Self::Array(Vec::new()),
+            (Self::Array(values), ValueKind::Object) => Self::Object(
+                values
+                    .iter()
+                    .enumerate()
+                    .map(|(i, value)| (i.to_string(), value.clone()))
+                    .collect(),
+            ),
+            (Self::Object(entries), ValueKind::Object) => Self::Object(entries.clone()),
+            (_, ValueKind::Object) => Self::Object(Vec::new()),
+        }
+    }
+
+    /// The text of a scalar, used for searching. Empty for arrays and objects.
+    fn scalar_text(&self) -> String {
+        match self {
+            Self::Null => "null".to_owned(),
+            Self::Bool(b) => b.to_string(),
+            Self::Number(n) => n.to_string(),
+            Self::String(s) => s.clone(),
+            Self::Array(_) | Self::Object(_) => String::new(),
+        }
+    }
+
+    /// Does the value, or anything inside it, contain the lowercase `query`?
+    fn contains(&self, query: &str) -> bool {
+        match self {
+            Self::Array(values) => values.iter().any(|value| value.contains(query)),
+            Self::Object(entries) => entries
+                .iter()
+                .any(|(key, value)| key.to_lowercase().contains(query) || value.contains(query)),
+            scalar => scalar.scalar_text().to_lowercase().contains(query),
+        }
+    }
+}
+
+impl From<bool> for Value {
+    fn from(b: bool) -> Self {
+        Self::Bool(b)
+    }
+}
+
+impl From<f64> for Value {
+    fn from(n: f64) -> Self {
+        Self::Number(n)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(s: &str) -> Self {
+        Self::String(s.to_owned())
+    }
+}
+
+impl From<String> for Value {
+    fn from(s: String) -> Self {
+        Self::String(s)
+    }
+}
+
+/// One step into a [`Value`]: a key of an object, or an index into an array.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PathSegment {
+    Key(String),
+    Index(usize),
+}
+
+/// An edit the user made in a [`ValueEditor`].
+///
+/// The edits have already been applied to the [`Value`] when you get them.
+/// The paths are from the root value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ValueEvent {
+    /// A bool, number or string was edited.
+    Edited { path: Vec<PathSegment> },
+
+    /// The type of a value was changed.
+    KindChanged {
+        path: Vec<PathSegment>,
+        kind: ValueKind,
+    },
+
+    /// A key of an object was renamed. The path has the new key.
+    Renamed {
+        path: Vec<PathSegment>,
+        old_key: String,
+    },
+
+    /// A new element or key was added.
+    Added { path: Vec<PathSegment> },
+
+    /// An element or key was removed.
+    Removed { path: Vec<PathSegment> },
+}
+
+/// What [`ValueEditor::show`] returns.
+pub struct ValueEditorResponse {
+    /// The response of the whole editor. Marked as changed if anything was edited.
+    pub response: Response,
+
+    /// The edits made by the user this pass, in the order they happened.
+    pub events: Vec<ValueEvent>,
+}
+
+/// An editor for a tree of [`Value`]s, e.g. a JSON or TOML document.
+///
+/// The type of each value can be changed,
+/// keys and elements can be added and removed,
+/// and arrays and objects can be collapsed.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_extras::{Value, ValueEditor};
+///
+/// # let mut search = String::new();
+/// # let mut value = Value::Object(vec![
+/// #     ("name".to_owned(), Value::from("egui")),
+/// #     ("stars".to_owned(), Value::from(20_000.0)),
+/// #     ("tags".to_owned(), Value::Array(vec![Value::from("gui"), Value::from("rust")])),
+/// # ]);
+/// ui.text_edit_singleline(&mut search);
+/// let response = ValueEditor::new("config").search(&search).show(ui, &mut value);
+/// for event in response.events {
+///     println!("{event:?}");
+/// }
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct ValueEditor<'a> {
+    id_salt: Id,
+    search: &'a str,
+    default_open: bool,
+}
+
+impl<'a> ValueEditor<'a> {
+    pub fn new(id_salt: impl std::hash::Hash) -> Self {
+        Self {
+            id_salt: Id::new(id_salt),
+            search: "",
+            default_open: true,
+        }
+    }
+
+    /// Only show the keys and values containing this text (case-insensitive),
+    /// and the arrays and objects containing them.
+    ///
+    /// The matching arrays and objects are opened.
+    #[inline]
+    pub fn search(mut self, search: &'a str) -> Self {
+        self.search = search;
+        self
+    }
+
+    /// Are arrays and objects open the first time they are shown?
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn default_open(mut self, default_open: bool) -> Self {
+        self.default_open = default_open;
+        self
+    }
+
+    /// Show the editor, and let the user edit the `value`.
+    pub fn show(self, ui: &mut Ui, value: &mut Value) -> ValueEditorResponse {
+        let id = ui.make_persistent_id(self.id_salt);
+        let mut editor = Editor {
+            query: self.search.trim().to_lowercase(),
+            default_open: self.default_open,
+            path: Vec::new(),
+            events: Vec::new(),
+        };
+
+        let mut response = ui
+            .vertical(|ui| {
+                editor.show_value(ui, id, Label::Root, value);
+            })
+            .response;
+
+        if !editor.events.is_empty() {
+            response.mark_changed();
+        }
+        ValueEditorResponse {
+            response,
+            events: editor.events,
+        }
+    }
+}
+
+/// What a value is called by its parent.
+enum Label<'a> {
+    Root,
+    Index(usize),
+    Key(&'a mut String),
+}
+
+struct Editor {
+    /// Lowercase, empty if we are not searching.
+    query: String,
+    default_open: bool,
+
+    /// The path of the value being shown.
+    path: Vec<PathSegment>,
+    events: Vec<ValueEvent>,
+}
+
+impl Editor {
+    fn is_searching(&self) -> bool {
+        !self.query.is_empty()
+    }
+
+    fn path_with(&self, segment: PathSegment) -> Vec<PathSegment> {
+        let mut path = self.path.clone();
+        path.push(segment);
+        path
+    }
+
+    /// Show a value with its label.
+    ///
+    /// Returns `true` if the user wants to remove it.
+    fn show_value(&mut self, ui: &mut Ui, id: Id, label: Label<'_>, value: &mut Value) -> bool {
+        let mut remove = false;
+
+        if matches!(value, Value::Array(_) | Value::Object(_)) {
+            let mut state =
+                CollapsingState::load_with_default_open(ui.ctx(), id, self.default_open);
+            if self.is_searching() {
+                state.set_open(true);
+            }
+            let header = state.show_header(ui, |ui| {
+                remove = self.show_label_and_kind(ui, id, label, value);
+                let summary = match value {
+                    Value::Array(values) => format!("[{}]", values.len()),
+                    Value::Object(entries) => format!("{{{}}}", entries.len()),
+                    _ => String::new(),
+                };
+                ui.weak(summary);
+                if matches!(value, Value::Array(_) | Value::Object(_))
+                    && ui.small_button("➕").on_hover_text("Add").clicked()
+                {
+                    self.add_child(value);
+                }
+            });
+            header.body(|ui| self.show_children(ui, id, value));
+        } else {
+            ui.horizontal(|ui| {
+                remove = self.show_label_and_kind(ui, id, label, value);
+                self.show_scalar(ui, value);
+            });
+        }
+
+        remove
+    }
+
+    /// Returns `true` if the user wants to remove the value.
+    fn show_label_and_kind(
+        &mut self,
+        ui: &mut Ui,
+        id: Id,
+        label: Label<'_>,
+        value: &mut Value,
+    ) -> bool {
+        let mut remove = false;
+        let is_root = matches!(label, Label::Root);
+
+        match label {
+            Label::Root => {}
+            Label::Index(i) => {
+                ui.weak(format!("{i}:"));
+            }
+            Label::Key(key) => {
+                let old_key = key.clone();
+                let response = ui.add(
+                    TextEdit::singleline(key)
+                        .id(id.with("key"))
+                        .desired_width(100.0),
+                );
+                if response.changed() {
+                    let mut path = self.path.clone();
+                    if let Some(last) = path.last_mut() {
+                        *last = PathSegment::Key(key.clone());
+                    }
+                    self.events.push(ValueEvent::Renamed { path, old_key });
+                }
+            }
+        }
+
+        let kind = value.kind();
+        let mut new_kind = kind;
+        ComboBox::from_id_salt(id.with("kind"))
+            .selected_text(kind.name())
+            .width(70.0)
+            .show_ui(ui, |ui| {
+                for kind in ValueKind::ALL {
+                    ui.selectable_value(&mut new_kind, kind, kind.name());
+                }
+            });
+        if new_kind != kind {
+            *value = value.converted_to(new_kind);
+            self.events.push(ValueEvent::KindChanged {
+                path: self.path.clone(),
+                kind: new_kind,
+            });
+        }
+
+        if !is_root {
+            remove = ui.small_button("🗑").on_hover_text("Remove").clicked();
+        }
+
+        remove
+    }
+
+    fn show_scalar(&mut self, ui: &mut Ui, value: &mut Value) {
+        let response = match value {
+            Value::Null => {
+                ui.weak("null");
+                return;
+            }
+            Value::Bool(b) => ui.add(Checkbox::without_text(b)),
+            Value::Number(n) => ui.add(DragValue::new(n)),
+            Value::String(s) => ui.add(TextEdit::singleline(s)),
+            Value::Array(_) | Value::Object(_) => return,
+        };
+        if response.changed() {
+            self.events.push(ValueEvent::Edited {
+                path: self.path.clone(),
+            });
+        }
+    }
+
+    fn show_children(&mut self, ui: &mut Ui, id: Id, value: &mut Value) {
+        let mut removed = None;
+
+        match value {
+            Value::Array(values) => {
+                for (i, child) in values.iter_mut().enumerate() {
+                    if self.is_searching() && !child.contains(&self.query) {
+                        continue;
+                    }
+                    self.path.push(PathSegment::Index(i));
+                    if self.show_value(ui, id.with(i), Label::Index(i), child) {
+                        removed = Some((i, self.path.clone()));
+                    }
+                    self.path.pop();
+                }
+                if let Some((i, _)) = &removed {
+                    values.remove(*i);
+                }
+            }
+            Value::Object(entries) => {
+                for (i, (key, child)) in entries.iter_mut().enumerate() {
+                    if self.is_searching()
+                        && !key.to_lowercase().contains(&self.query)
+                        && !child.contains(&self.query)
+                    {
+                        continue;
+                    }
+                    self.path.push(PathSegment::Key(key.clone()));
+                    // The index keeps the id stable while the key is being edited:
+                    if self.show_value(ui, id.with(i), Label::Key(key), child) {
+                        removed = Some((i, self.path.clone()));
+                    }
+                    self.path.pop();
+                }
+                if let Some((i, _)) = &removed {
+                    entries.remove(*i);
+                }
+            }
+            _ => {}
+        }
+
+        if let Some((_, path)) = removed {
+            self.events.push(ValueEvent::Removed { path });
+        }
+    }
+
+    fn add_child(&mut self, value: &mut Value) {
+        let segment = match value {
+            Value::Array(values) => {
+                values.push(Value::Null);
+                PathSegment::Index(values.len() - 1)
+            }
+            Value::Object(entries) => {
+                let key = (1..)
+                    .map(|n| format!("key{n}"))
+                    .find(|key| entries.iter().all(|(existing, _)| existing != key))
+                    .unwrap_or_default();
+                entries.push((key.clone(), Value::Null));
+                PathSegment::Key(key)
+            }
+            _ => return,
+        };
+        self.events.push(ValueEvent::Added {
+            path: self.path_with(segment),
+        });
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use serde::de::{self, MapAccess, SeqAccess, Visitor};
+    use serde::ser::SerializeMap as _;
+
+    use super::Value;
+
+    impl serde::Serialize for Value {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            match self {
+                Self::Null => serializer.serialize_unit(),
+                Self::Bool(b) => serializer.serialize_bool(*b),
+                // Write whole numbers as integers, so they round-trip through e.g. TOML:
+                Self::Number(n) if n.fract() == 0.0 && n.abs() < 2f64.powi(53) => {
+                    serializer.serialize_i64(*n as i64)
+                }
+                Self::Number(n) => serializer.serialize_f64(*n),
+                Self::String(s) => serializer.serialize_str(s),
+                Self::Array(values) => values.serialize(serializer),
+                Self::Object(entries) => {
+                    let mut map = serializer.serialize_map(Some(entries.len()))?;
+                    for (key, value) in entries {
+                        map.serialize_entry(key, value)?;
+                    }
+                    map.end()
+                }
+            }
+        }
+    }
+
+    impl<'de> serde::Deserialize<'de> for Value {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_any(ValueVisitor)
+        }
+    }
+
+    struct ValueVisitor;
+
+    impl<'de> Visitor<'de> for ValueVisitor {
+        type Value = Value;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            formatter.write_str("any value")
+        }
+
+        fn visit_unit<E: de::Error>(self) -> Result<Value, E> {
+            Ok(Value::Null)
+        }
+
+        fn visit_none<E: de::Error>(self) -> Result<Value, E> {
+            Ok(Value::Null)
+        }
+
+        fn visit_some<D: serde::Deserializer<'de>>(
+            self,
+            deserializer: D,
+        ) -> Result<Value, D::Error> {
+            serde::Deserialize::deserialize(deserializer)
+        }
+
+        fn visit_bool<E: de::Error>(self, b: bool) -> Result<Value, E> {
+            Ok(Value::Bool(b))
+        }
+
+        fn visit_i64<E: de::Error>(self, n: i64) -> Result<Value, E> {
+            Ok(Value::Number(n as f64))
+        }
+
+        fn visit_u64<E: de::Error>(self, n: u64) -> Result<Value, E> {
+            Ok(Value::Number(n as f64))
+        }
+
+        fn visit_f64<E: de::Error>(self, n: f64) -> Result<Value, E> {
+            Ok(Value::Number(n))
+        }
+
+        fn visit_str<E: de::Error>(self, s: &str) -> Result<Value, E> {
+            Ok(Value::String(s.to_owned()))
+        }
+
+        fn visit_string<E: de::Error>(self, s: String) -> Result<Value, E> {
+            Ok(Value::String(s))
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Value, A::Error> {
+            let mut values = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+            while let Some(value) = seq.next_element()? {
+                values.push(value);
+            }
+            Ok(Value::Array(values))
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Value, A::Error> {
+            let mut entries = Vec::with_capacity(map.size_hint().unwrap_or_default());
+            while let Some(entry) = map.next_entry()? {
+                entries.push(entry);
+            }
+            Ok(Value::Object(entries))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converted_to() {
+        let array = Value::Array(vec![Value::from("a"), Value::from(true)]);
+        let object = Value::Object(vec![
+            ("0".to_owned(), Value::from("a")),
+            ("1".to_owned(), Value::from(true)),
+        ]);
+
+        for (value, kind, expected) in [
+            (Value::from(" 42 "), ValueKind::Number, Value::from(42.0)),
+            (Value::from("abc"), ValueKind::Number, Value::from(0.0)),
+            (Value::from(true), ValueKind::Number, Value::from(1.0)),
+            (Value::from(2.0), ValueKind::Bool, Value::from(true)),
+            (Value::from(0.0), ValueKind::Bool, Value::from(false)),
+            (Value::from("true"), ValueKind::Bool, Value::from(true)),
+            (Value::from(1.5), ValueKind::String, Value::from("1.5")),
+            (Value::Null, ValueKind::String, Value::from("null")),
+            (array.clone(), ValueKind::String, Value::from("")),
+            (array.clone(), ValueKind::Object, object.clone()),
+            (object.clone(), ValueKind::Array, array.clone()),
+            (Value::from(1.0), ValueKind::Array, Value::Array(vec![])),
+        ] {
+            assert_eq!(value.converted_to(kind), expected, "{value:?} to {kind:?}");
+        }
+
+        for value in [
+            Value::Null,
+            Value::from(1.0),
+            Value::from("x"),
+            array,
+            object,
+        ] {
+            for kind in ValueKind::ALL {
+                assert_eq!(value.converted_to(kind).kind(), kind);
+            }
+            assert_eq!(value.converted_to(value.kind()), value);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_roundtrip() {
+        let json = r#"{"name":"egui","stars":20000,"ratio":0.5,"tags":["gui",null,true],"nested":{"b":1,"a":[]}}"#;
+        let value: Value = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            value,
+            Value::Object(vec![
+                ("name".to_owned(), Value::from("egui")),
+                ("stars".to_owned(), Value::from(20_000.0)),
+                ("ratio".to_owned(), Value::from(0.5)),
+                (
+                    "tags".to_owned(),
+                    Value::Array(vec![Value::from("gui"), Value::Null, Value::from(true)])
+                ),
+                (
+                    "nested".to_owned(),
+                    Value::Object(vec![
+                        ("b".to_owned(), Value::from(1.0)),
+                        ("a".to_owned(), Value::Array(vec![])),
+                    ])
+                ),
+            ])
+        );
+
+        // The keys keep their order, and whole numbers are written as integers:
+        assert_eq!(serde_json::to_string(&value).unwrap(), json);
+    }
+}
@@ -415,6 +415,18 @@ impl Painter {
                 Primitive::Mesh(mesh) => {
                     self.paint_mesh(mesh);
                 }
+                Primitive::Instanced(instanced) => {
+                    // We don't set `BackendCapabilities::instancing`, so this should not happen.
+                    self.paint_mesh(&instanced.to_mesh());
+                }
+                Primitive::Hdr(hdr) => {
+                    // We don't set `BackendCapabilities::hdr`, so this should not happen.
+                    self.paint_mesh(&hdr.mesh);
+                }
+                Primitive::Composite(_) => {
+                    // We don't set `BackendCapabilities::composite`, so this should not happen.
+                    log::warn!("Composite primitives are not supported by egui_glow");
+                }
                 Primitive::Callback(callback) => {
                     if callback.rect.is_positive() {
                         profiling::scope!("callback");
@@ -424,6 +436,8 @@ impl Painter {
                             clip_rect: *clip_rect,
                             pixels_per_point,
                             screen_size_px,
+                            scaling: callback.scaling,
+                            user_data: callback.user_data.clone(),
                         };
 
                         let viewport_px = info.viewport_in_pixels();
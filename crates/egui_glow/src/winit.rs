@@ -75,6 +75,8 @@ impl EguiGlow {
             shapes,
             pixels_per_point,
             viewport_output,
+            unchanged_rects: _, // We always repaint everything
+            color_space: _,     // We only support sRGB
         } = self.egui_ctx.run(raw_input, run_ui);
 
         if viewport_output.len() > 1 {
@@ -0,0 +1,200 @@
+use std::ops::RangeInclusive;
+
+use egui::accesskit::Role;
+use egui::{Event, Key, Modifiers, OutputCommand};
+use egui_extras::{CellEditor, CellPaste, Column, TableBuilder};
+use egui_kittest::Harness;
+use kittest::Queryable as _;
+
+#[derive(Default)]
+struct State {
+    cells: Vec<Vec<String>>,
+
+    /// The committed edits, with the old values.
+    committed: Vec<((usize, usize), String)>,
+    active: Option<(usize, usize)>,
+    selection: Option<(RangeInclusive<usize>, RangeInclusive<usize>)>,
+    editing: bool,
+    paste: Option<CellPaste>,
+}
+
+/// A table with three rows of `a` and `b` columns, e.g. `"a0"` and `"b0"`.
+fn table_harness() -> Harness<'static, State> {
+    let cells = (0..3)
+        .map(|row| vec![format!("a{row}"), format!("b{row}")])
+        .collect();
+    Harness::new_ui_state(
+        |ui, state: &mut State| {
+            let id = ui.make_persistent_id("table");
+            let mut editor = CellEditor::load(ui.ctx(), id, state.cells.len(), 2);
+            let cells = &state.cells;
+            if let Some(paste) =
+                editor.handle_clipboard(ui.ctx(), |row, col| cells[row][col].clone())
+            {
+                state.paste = Some(paste);
+            }
+            TableBuilder::new(ui)
+                .columns(Column::exact(60.0), 2)
+                .body(|mut body| {
+                    for (row, cells) in state.cells.iter_mut().enumerate() {
+                        body.row(20.0, |mut table_row| {
+                            for (col, cell) in cells.iter_mut().enumerate() {
+                                table_row.col(|ui| {
+                                    let old = editor.cell(
+                                        ui,
+                                        row,
+                                        col,
+                                        cell,
+                                        |ui, cell| {
+                                            ui.label(cell.as_str());
+                                        },
+                                        |ui, cell| ui.text_edit_singleline(cell),
+                                    );
+                                    if let Some(old) = old {
+                                        state.committed.push(((row, col), old));
+                                    }
+                                });
+                            }
+                        });
+                    }
+                });
+            state.active = editor.active_cell();
+            state.selection = editor.selection();
+            state.editing = editor.is_editing();
+            editor.store(ui.ctx());
+        },
+        State {
+            cells,
+            ..Default::default()
+        },
+    )
+}
+
+#[test]
+fn commit_on_enter_and_cancel_on_escape() {
+    let mut harness = table_harness();
+    harness.run();
+    harness.get_by_label("a0").click();
+    harness.run();
+    assert_eq!(harness.state().active, Some((0, 0)));
+
+    harness.key_press(Key::Enter);
+    harness.run();
+    assert!(harness.state().editing);
+    harness.get_by_role(Role::TextInput).type_text("!");
+    harness.run();
+    // The edit is only written back when it is committed:
+    assert_eq!(harness.state().cells[0][0], "a0");
+
+    // Enter commits and moves down:
+    harness.key_press(Key::Enter);
+    harness.run();
+    assert!(!harness.state().editing);
+    assert_eq!(harness.state().cells[0][0], "a0!");
+    assert_eq!(harness.state().committed, vec![((0, 0), "a0".to_owned())]);
+    assert_eq!(harness.state().active, Some((1, 0)));
+
+    // Escape cancels and stays:
+    harness.key_press(Key::F2);
+    harness.run();
+    assert!(harness.state().editing);
+    harness.get_by_role(Role::TextInput).type_text("?");
+    harness.run();
+    harness.key_press(Key::Escape);
+    harness.run();
+    assert!(!harness.state().editing);
+    assert_eq!(harness.state().cells[1][0], "a1");
+    assert_eq!(harness.state().committed.len(), 1);
+    assert_eq!(harness.state().active, Some((1, 0)));
+}
+
+#[test]
+fn tab_and_arrow_navigation() {
+    let mut harness = table_harness();
+    harness.run();
+    harness.get_by_label("a0").click();
+    harness.run();
+
+    let mut press = |modifiers, key| {
+        harness.key_press_modifiers(modifiers, key);
+        harness.run();
+        harness.state().active
+    };
+
+    assert_eq!(press(Modifiers::NONE, Key::ArrowRight), Some((0, 1)));
+    assert_eq!(press(Modifiers::NONE, Key::ArrowDown), Some((1, 1)));
+    assert_eq!(press(Modifiers::NONE, Key::ArrowLeft), Some((1, 0)));
+    assert_eq!(press(Modifiers::NONE, Key::ArrowUp), Some((0, 0)));
+    assert_eq!(
+        press(Modifiers::NONE, Key::ArrowUp),
+        Some((0, 0)),
+        "Should stay in the table"
+    );
+
+    // Tab commits and moves right, Shift moves the other way:
+    press(Modifiers::NONE, Key::Enter);
+    assert_eq!(press(Modifiers::NONE, Key::Tab), Some((0, 1)));
+    press(Modifiers::NONE, Key::Enter);
+    assert_eq!(press(Modifiers::SHIFT, Key::Tab), Some((0, 0)));
+    press(Modifiers::NONE, Key::ArrowDown);
+    press(Modifiers::NONE, Key::Enter);
+    assert_eq!(press(Modifiers::SHIFT, Key::Enter), Some((0, 0)));
+
+    assert!(!harness.state().editing);
+    assert_eq!(
+        harness.state().committed,
+        vec![
+            ((0, 0), "a0".to_owned()),
+            ((0, 1), "b0".to_owned()),
+            ((1, 0), "a1".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn select_copy_and_paste() {
+    let mut harness = table_harness();
+    harness.run();
+    harness.get_by_label("a0").click();
+    harness.run();
+
+    // Shift extends the selection from the active cell:
+    harness.key_press_modifiers(Modifiers::SHIFT, Key::ArrowDown);
+    harness.key_press_modifiers(Modifiers::SHIFT, Key::ArrowRight);
+    harness.run();
+    assert_eq!(harness.state().active, Some((1, 1)));
+    assert_eq!(harness.state().selection, Some((0..=1, 0..=1)));
+
+    harness.input_mut().events.push(Event::Copy);
+    harness.step();
+    assert!(
+        harness
+            .output()
+            .platform_output
+            .commands
+            .contains(&OutputCommand::CopyText("a0\tb0\na1\tb1".to_owned()))
+    );
+
+    // Pasting starts at the top left of the selection:
+    harness
+        .input_mut()
+        .events
+        .push(Event::Paste("x\ty\nz\n".to_owned()));
+    harness.run();
+    assert_eq!(
+        harness.state().paste,
+        Some(CellPaste {
+            row: 0,
+            col: 0,
+            cells: vec![vec!["x".to_owned(), "y".to_owned()], vec!["z".to_owned()]],
+        })
+    );
+
+    // Shift-click extends the selection too, and moving without Shift collapses it:
+    harness.get_by_label("b2").click_modifiers(Modifiers::SHIFT);
+    harness.run();
+    assert_eq!(harness.state().selection, Some((0..=2, 0..=1)));
+    harness.key_press(Key::ArrowUp);
+    harness.run();
+    assert_eq!(harness.state().selection, Some((1..=1, 1..=1)));
+}
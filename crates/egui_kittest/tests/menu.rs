@@ -1,4 +1,4 @@
-use egui::containers::menu::{MenuBar, MenuConfig, SubMenuButton};
+use egui::containers::menu::{AsyncMenuItem, AsyncState, MenuBar, MenuConfig, SubMenuButton};
 use egui::{PopupCloseBehavior, Ui, include_image};
 use egui_kittest::{Harness, SnapshotResults};
 use kittest::Queryable as _;
@@ -160,6 +160,72 @@ fn clicking_submenu_button_should_never_close_menu() {
     assert!(harness.query_by_label("Button in Submenu B").is_none());
 }
 
+#[test]
+fn async_menu_item_keeps_menu_open() {
+    let mut harness = Harness::new_ui_state(
+        |ui, state: &mut AsyncState| {
+            ui.menu_button("Menu", |ui| {
+                if AsyncMenuItem::new("Sync", *state).ui(ui).clicked() {
+                    *state = AsyncState::Pending;
+                }
+                _ = ui.button("Other");
+            });
+        },
+        AsyncState::Idle,
+    );
+
+    harness.get_by_label("Menu").click();
+    harness.run();
+
+    // Starting the action shouldn't close the menu:
+    harness.get_by_label("Sync").click();
+    harness.run_steps(2);
+    assert_eq!(*harness.state(), AsyncState::Pending);
+    assert!(harness.query_by_label("Sync").is_some());
+
+    // Neither should clicking the pending (disabled) item:
+    harness.get_by_label("Sync").click();
+    harness.run_steps(2);
+    assert!(harness.query_by_label("Other").is_some());
+
+    *harness.state_mut() = AsyncState::Done;
+    harness.run();
+    assert!(harness.query_by_label("Sync").is_some());
+
+    // Other items still close the menu as usual:
+    harness.get_by_label("Other").click();
+    harness.run();
+    assert!(harness.query_by_label("Sync").is_none());
+}
+
+#[test]
+fn async_menu_item_closes_menu_when_done() {
+    let mut harness = Harness::new_ui_state(
+        |ui, state: &mut AsyncState| {
+            ui.menu_button("Menu", |ui| {
+                AsyncMenuItem::new("Sync", *state).ui(ui);
+                ui.label("Status");
+            });
+        },
+        AsyncState::Pending,
+    );
+
+    harness.get_by_label("Menu").click();
+    harness.run_steps(2);
+
+    // While the action is pending, clicking inside the menu doesn't close it:
+    harness.get_by_label("Status").click();
+    harness.run_steps(2);
+    assert!(harness.query_by_label("Status").is_some());
+
+    // Once it is done, the menu closes as usual:
+    *harness.state_mut() = AsyncState::Done;
+    harness.run();
+    harness.get_by_label("Status").click();
+    harness.run();
+    assert!(harness.query_by_label("Status").is_none());
+}
+
 #[test]
 fn menu_snapshots() {
     let mut harness = TestMenu::new(MenuConfig::new()).into_harness();
@@ -138,3 +138,332 @@ fn test_scroll_down() {
         "The button was not clicked after scrolling down. (Probably not scrolled enough / at all)"
     );
 }
+
+#[test]
+fn test_side_panel_show_animated_width() {
+    struct State {
+        target_width: f32,
+        content_widths: Vec<f32>,
+    }
+
+    let panel_width = |harness: &Harness<'_, State>| {
+        egui::containers::panel::PanelState::load(&harness.ctx, egui::Id::new("panel"))
+            .expect("panel was shown")
+            .rect
+            .width()
+    };
+
+    let mut harness = Harness::new_state(
+        |ctx, state: &mut State| {
+            egui::SidePanel::left("panel")
+                .min_width(50.0)
+                .show_animated_width(ctx, state.target_width, |ui| {
+                    state.content_widths.push(ui.available_width());
+                });
+        },
+        State {
+            target_width: 300.0,
+            content_widths: vec![],
+        },
+    );
+    // The harness turns off animations by default:
+    harness.ctx.style_mut(|style| style.animation_time = 1.0);
+    harness.run();
+    assert_eq!(panel_width(&harness), 300.0);
+
+    harness.state_mut().target_width = 100.0;
+    harness.state_mut().content_widths.clear();
+    harness.step();
+    harness.step();
+    let width = panel_width(&harness);
+    assert!(
+        100.0 < width && width < 300.0,
+        "Should be animating: {width}"
+    );
+
+    harness.run();
+    assert_eq!(panel_width(&harness), 100.0);
+
+    let content_widths = &harness.state().content_widths;
+    let (first, last) = (content_widths[0], content_widths[content_widths.len() - 1]);
+    assert!(last < first);
+    assert!(
+        content_widths.iter().all(|&w| w == first || w == last),
+        "Contents shouldn't reflow while animating: {content_widths:?}"
+    );
+}
+
+#[test]
+fn test_window_min_size_from_content() {
+    let mut harness = Harness::new_state(
+        |ctx, available_width: &mut f32| {
+            egui::Window::new("Window")
+                .default_pos([0.0, 0.0])
+                .default_width(300.0)
+                .min_size_from_content(true)
+                .show(ctx, |ui| {
+                    *available_width = ui.available_width();
+                    ui.allocate_exact_size(Vec2::new(200.0, 20.0), egui::Sense::hover());
+                });
+        },
+        0.0,
+    );
+    harness.run();
+    assert!(300.0 <= *harness.state());
+
+    // Drag the right edge of the window far to the left:
+    let rect = harness
+        .ctx
+        .memory(|mem| mem.area_rect(egui::Id::new("Window")))
+        .expect("window was shown");
+    let events = [
+        egui::Event::PointerMoved(rect.right_center()),
+        egui::Event::PointerButton {
+            pos: rect.right_center(),
+            button: egui::PointerButton::Primary,
+            pressed: true,
+            modifiers: Modifiers::NONE,
+        },
+        egui::Event::PointerMoved(rect.left_center() + Vec2::new(50.0, 0.0)),
+    ];
+    for event in events {
+        harness.input_mut().events.push(event);
+        harness.step();
+    }
+    harness.step();
+
+    let available_width = *harness.state();
+    assert!(
+        200.0 <= available_width && available_width < 300.0,
+        "The window should shrink, but not smaller than the content: {available_width}"
+    );
+}
+
+#[test]
+fn test_radio_group_keyboard() {
+    let mut harness = Harness::new_ui_state(
+        |ui, value: &mut u8| {
+            ui.radio_group(value, |group| {
+                group.option(1, "One");
+                group.option(2, "Two");
+                group.option(3, "Three");
+            });
+            let _ = ui.button("After");
+        },
+        2,
+    );
+    harness.run();
+
+    // The selected option is the only tab stop in the group:
+    harness.key_press(egui::Key::Tab);
+    harness.run();
+    assert!(harness.get_by_label("Two").is_focused());
+
+    harness.key_press(egui::Key::ArrowDown);
+    harness.run();
+    assert_eq!(*harness.state(), 3);
+    assert!(harness.get_by_label("Three").is_focused());
+
+    harness.key_press(egui::Key::ArrowDown);
+    harness.run();
+    assert_eq!(*harness.state(), 1, "Should wrap around");
+    assert!(harness.get_by_label("One").is_focused());
+
+    harness.key_press(egui::Key::Tab);
+    harness.run();
+    assert!(harness.get_by_label("After").is_focused());
+}
+
+#[test]
+fn test_checkbox_select_all() {
+    let mut harness = Harness::new_ui_state(
+        |ui, children: &mut [bool; 3]| {
+            ui.add(egui::Checkbox::select_all(children.iter_mut(), "All"));
+            for (i, child) in children.iter_mut().enumerate() {
+                ui.checkbox(child, format!("Child {i}"));
+            }
+        },
+        [true, false, false],
+    );
+    harness.run();
+
+    harness.get_by_label("All").click();
+    harness.run();
+    assert_eq!(
+        *harness.state(),
+        [true; 3],
+        "Indeterminate should become checked"
+    );
+
+    harness.get_by_label("All").click();
+    harness.run();
+    assert_eq!(*harness.state(), [false; 3]);
+
+    harness.get_by_label("Child 1").click();
+    harness.run();
+    assert_eq!(*harness.state(), [false, true, false]);
+}
+
+#[test]
+fn test_checkbox_tri_state() {
+    let mut harness = Harness::new_ui_state(
+        |ui, value: &mut Option<bool>| {
+            ui.add(egui::Checkbox::tri_state(value, "Maybe"));
+        },
+        None,
+    );
+    harness.run();
+
+    harness.get_by_label("Maybe").click();
+    harness.run();
+    assert_eq!(*harness.state(), Some(true));
+
+    harness.get_by_label("Maybe").click();
+    harness.run();
+    assert_eq!(*harness.state(), Some(false));
+}
+
+#[test]
+fn test_collapsing_header_show_lazy() {
+    let mut harness = Harness::new_ui_state(
+        |ui, num_bodies_shown: &mut usize| {
+            ScrollArea::vertical().max_height(100.0).show(ui, |ui| {
+                for i in 0..50 {
+                    egui::CollapsingHeader::new(format!("Header {i}"))
+                        .default_open(true)
+                        .show_lazy(ui, |ui| {
+                            *num_bodies_shown += 1;
+                            ui.label("Body");
+                        });
+                }
+            });
+        },
+        0,
+    );
+    harness.run();
+
+    *harness.state_mut() = 0;
+    harness.step();
+    let num_bodies_shown = *harness.state();
+    assert!(
+        0 < num_bodies_shown && num_bodies_shown < 10,
+        "Only the visible bodies should be shown, but {num_bodies_shown} were"
+    );
+}
+
+#[test]
+fn test_collapsing_header_show_lazy_collapsed() {
+    let mut harness = Harness::new_ui_state(
+        |ui, num_calls: &mut usize| {
+            egui::CollapsingHeader::new("Header").show_lazy(ui, |ui| {
+                *num_calls += 1;
+                ui.label("Body");
+            });
+        },
+        0,
+    );
+    harness.run();
+    harness.step();
+    assert_eq!(
+        *harness.state(),
+        0,
+        "Collapsed: the body should not be built"
+    );
+
+    harness.get_by_label("Header").click();
+    harness.run();
+    assert!(0 < *harness.state(), "Opening: the body should be built");
+    *harness.state_mut() = 0;
+    harness.step();
+    assert_eq!(
+        *harness.state(),
+        1,
+        "Open: the body should be built once per pass"
+    );
+
+    harness.get_by_label("Header").click();
+    harness.run();
+    *harness.state_mut() = 0;
+    harness.step();
+    harness.step();
+    assert_eq!(
+        *harness.state(),
+        0,
+        "Collapsed again: the body should not be built"
+    );
+}
+
+#[test]
+fn test_wizard() {
+    #[derive(Default)]
+    struct State {
+        name: String,
+        step: usize,
+        finished: bool,
+    }
+
+    let mut harness = Harness::new_ui_state(
+        |ui, state: &mut State| {
+            let response = egui::containers::Wizard::new("wizard", ["Name", "Confirm"]).show(
+                ui,
+                |ui, step| {
+                    if step == 0 {
+                        ui.text_edit_singleline(&mut state.name);
+                        !state.name.is_empty()
+                    } else {
+                        ui.label(format!("Hello {}", state.name));
+                        true
+                    }
+                },
+            );
+            state.step = response.step;
+            state.finished |= response.finished;
+        },
+        State::default(),
+    );
+    harness.run();
+
+    // An invalid step blocks Next:
+    assert!(harness.query_by_label("Finish").is_none());
+    harness.get_by_label("Next ⏵").click();
+    harness.run();
+    assert_eq!(harness.state().step, 0);
+
+    harness
+        .get_by_role(egui::accesskit::Role::TextInput)
+        .focus();
+    harness.run();
+    harness
+        .get_by_role(egui::accesskit::Role::TextInput)
+        .type_text("Ada");
+    harness.run();
+    harness.get_by_label("Next ⏵").click();
+    harness.run();
+    assert_eq!(harness.state().step, 1);
+
+    // Finish only appears on the last step:
+    assert!(harness.query_by_label("Next ⏵").is_none());
+    assert!(harness.query_by_label("Finish").is_some());
+
+    // Going back keeps what was entered:
+    harness.get_by_label("⏴ Back").click();
+    harness.run();
+    assert_eq!(harness.state().step, 0);
+    assert_eq!(harness.state().name, "Ada");
+    assert_eq!(
+        harness
+            .get_by_role(egui::accesskit::Role::TextInput)
+            .value()
+            .as_deref(),
+        Some("Ada")
+    );
+    assert!(harness.query_by_label("Finish").is_none());
+
+    harness.get_by_label("Next ⏵").click();
+    harness.run();
+    assert!(!harness.state().finished);
+    harness.get_by_label("Finish").click();
+    harness.run();
+    assert!(harness.state().finished);
+    assert_eq!(harness.state().step, 1);
+}
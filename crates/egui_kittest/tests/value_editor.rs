@@ -0,0 +1,95 @@
+use egui::accesskit::Role;
+use egui_extras::{PathSegment, Value, ValueEditor, ValueEvent};
+use egui_kittest::Harness;
+use kittest::Queryable as _;
+
+#[derive(Default)]
+struct State {
+    value: Value,
+    events: Vec<ValueEvent>,
+}
+
+fn key(key: &str) -> PathSegment {
+    PathSegment::Key(key.to_owned())
+}
+
+/// Matches the text field showing `value`, but not its text.
+fn text_input(value: &str) -> impl Fn(&kittest::AccessKitNode<'_>) -> bool + '_ {
+    move |node| node.role() == Role::TextInput && node.value().as_deref() == Some(value)
+}
+
+#[test]
+fn edit_nested_value() {
+    let value = Value::Object(vec![(
+        "config".to_owned(),
+        Value::Object(vec![(
+            "items".to_owned(),
+            Value::Array(vec![Value::from("e"), Value::from(1.0)]),
+        )]),
+    )]);
+    let mut harness = Harness::new_ui_state(
+        |ui, state: &mut State| {
+            let response = ValueEditor::new("editor").show(ui, &mut state.value);
+            state.events.extend(response.events);
+        },
+        State {
+            value,
+            ..Default::default()
+        },
+    );
+    harness.run();
+
+    // Edit the string inside the array inside the object:
+    harness.get_by(text_input("e")).focus();
+    harness.run();
+    harness.get_by(text_input("e")).type_text("gui");
+    harness.run();
+    assert_eq!(
+        harness.state_mut().events.drain(..).collect::<Vec<_>>(),
+        vec![ValueEvent::Edited {
+            path: vec![key("config"), key("items"), PathSegment::Index(0)],
+        }]
+    );
+
+    // Rename a key of the nested object:
+    harness.get_by(text_input("items")).focus();
+    harness.run();
+    harness.get_by(text_input("items")).type_text("_list");
+    harness.run();
+    assert_eq!(
+        harness.state_mut().events.drain(..).collect::<Vec<_>>(),
+        vec![ValueEvent::Renamed {
+            path: vec![key("config"), key("items_list")],
+            old_key: "items".to_owned(),
+        }]
+    );
+
+    // The buttons of the root object, `config` and `items_list`:
+    harness.get_all_by_label("➕").nth(2).unwrap().click();
+    harness.run();
+    // The buttons of `config`, `items_list` and its two elements:
+    harness.get_all_by_label("🗑").nth(3).unwrap().click();
+    harness.run();
+    assert_eq!(
+        harness.state().events,
+        vec![
+            ValueEvent::Added {
+                path: vec![key("config"), key("items_list"), PathSegment::Index(2)],
+            },
+            ValueEvent::Removed {
+                path: vec![key("config"), key("items_list"), PathSegment::Index(1)],
+            },
+        ]
+    );
+
+    assert_eq!(
+        harness.state().value,
+        Value::Object(vec![(
+            "config".to_owned(),
+            Value::Object(vec![(
+                "items_list".to_owned(),
+                Value::Array(vec![Value::from("egui"), Value::Null]),
+            )]),
+        )])
+    );
+}
@@ -53,11 +53,12 @@ pub use self::{
     image::{AlphaFromCoverage, ColorImage, ImageData, ImageDelta},
     margin::Margin,
     margin_f32::*,
-    mesh::{Mesh, Mesh16, Vertex},
+    mesh::{HdrMesh, InstancedMesh, Mesh, Mesh16, Vertex},
     shadow::Shadow,
     shapes::{
-        CircleShape, CubicBezierShape, EllipseShape, PaintCallback, PaintCallbackInfo, PathShape,
-        QuadraticBezierShape, RectShape, Shape, TextShape,
+        CircleShape, CompositeBoundary, CompositeShape, CubicBezierShape, EllipseShape, HdrShape,
+        InstancedShape, PaintCallback, PaintCallbackInfo, PathShape, QuadraticBezierShape,
+        RectShape, Shape, TextShape,
     },
     stats::PaintStats,
     stroke::{PathStroke, Stroke, StrokeKind},
@@ -145,6 +146,21 @@ pub struct ClippedPrimitive {
 pub enum Primitive {
     Mesh(Mesh),
     Callback(PaintCallback),
+
+    /// A mesh to paint many times.
+    ///
+    /// Only produced if [`TessellationOptions::instancing`] is set.
+    Instanced(InstancedMesh),
+
+    /// A mesh with overbright colors.
+    ///
+    /// Only produced if [`TessellationOptions::hdr`] is set.
+    Hdr(HdrMesh),
+
+    /// The start or end of the primitives of a [`CompositeShape`].
+    ///
+    /// Only produced if [`TessellationOptions::composite`] is set.
+    Composite(CompositeBoundary),
 }
 
 // ---------------------------------------------------------------------------
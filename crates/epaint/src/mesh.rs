@@ -48,7 +48,9 @@ pub struct Vertex {
 pub struct Mesh {
     /// Draw as triangles (i.e. the length is always multiple of three).
     ///
-    /// If you only support 16-bit indices you can use [`Mesh::split_to_u16`].
+    /// The meshes produced by the [`crate::Tessellator`] have at most [`u16::MAX`] vertices,
+    /// so the indices fit in 16 bits, unless [`crate::TessellationOptions::u32_indices`] is set.
+    /// For other meshes you can use [`Mesh::split_to_u16`].
     ///
     /// egui is NOT consistent with what winding order it uses, so turn off backface culling.
     pub indices: Vec<u32>,
@@ -233,60 +235,65 @@ impl Mesh {
     /// Splits this mesh into many smaller meshes (if needed)
     /// where the smaller meshes have 16-bit indices.
     pub fn split_to_u16(self) -> Vec<Mesh16> {
+        self.split_to_u16_sized()
+            .into_iter()
+            .map(|mesh| Mesh16 {
+                indices: mesh.indices.iter().map(|&i| i as u16).collect(),
+                vertices: mesh.vertices,
+                texture_id: mesh.texture_id,
+            })
+            .collect()
+    }
+
+    /// Splits this mesh into smaller meshes (if needed) of at most [`u16::MAX`] vertices each,
+    /// so that all their indices fit in 16 bits.
+    ///
+    /// Vertices shared by triangles that end up in different meshes are duplicated.
+    pub fn split_to_u16_sized(self) -> Vec<Self> {
         debug_assert!(self.is_valid(), "Mesh is invalid");
 
-        const MAX_SIZE: u32 = u16::MAX as u32;
+        const MAX_VERTICES: usize = u16::MAX as usize;
 
-        if self.vertices.len() <= MAX_SIZE as usize {
+        if self.vertices.len() <= MAX_VERTICES {
             // Common-case optimization:
-            return vec![Mesh16 {
-                indices: self.indices.iter().map(|&i| i as u16).collect(),
-                vertices: self.vertices,
-                texture_id: self.texture_id,
-            }];
+            return vec![self];
         }
 
         let mut output = vec![];
-        let mut index_cursor = 0;
-
-        while index_cursor < self.indices.len() {
-            let span_start = index_cursor;
-            let mut min_vindex = self.indices[index_cursor];
-            let mut max_vindex = self.indices[index_cursor];
-
-            while index_cursor < self.indices.len() {
-                let (mut new_min, mut new_max) = (min_vindex, max_vindex);
-                for i in 0..3 {
-                    let idx = self.indices[index_cursor + i];
-                    new_min = new_min.min(idx);
-                    new_max = new_max.max(idx);
+        let mut mesh = Self::with_texture(self.texture_id);
+
+        // Index into `mesh.vertices` of each vertex of `self`, if it has been added yet:
+        let mut new_indices = vec![u32::MAX; self.vertices.len()];
+        let mut added = vec![];
+
+        for triangle in self.indices.chunks_exact(3) {
+            let num_new_vertices = triangle
+                .iter()
+                .filter(|&&i| new_indices[i as usize] == u32::MAX)
+                .count();
+            if MAX_VERTICES < mesh.vertices.len() + num_new_vertices {
+                for &i in &added {
+                    new_indices[i] = u32::MAX;
                 }
+                added.clear();
+                output.push(std::mem::replace(
+                    &mut mesh,
+                    Self::with_texture(self.texture_id),
+                ));
+            }
 
-                let new_span_size = new_max - new_min + 1; // plus one, because it is an inclusive range
-                if new_span_size <= MAX_SIZE {
-                    // Triangle fits
-                    min_vindex = new_min;
-                    max_vindex = new_max;
-                    index_cursor += 3;
-                } else {
-                    break;
+            for &i in triangle {
+                let i = i as usize;
+                if new_indices[i] == u32::MAX {
+                    new_indices[i] = mesh.vertices.len() as u32;
+                    mesh.vertices.push(self.vertices[i]);
+                    added.push(i);
                 }
+                mesh.indices.push(new_indices[i]);
             }
+        }
 
-            assert!(
-                index_cursor > span_start,
-                "One triangle spanned more than {MAX_SIZE} vertices"
-            );
-
-            let mesh = Mesh16 {
-                indices: self.indices[span_start..index_cursor]
-                    .iter()
-                    .map(|vi| u16::try_from(vi - min_vindex).unwrap())
-                    .collect(),
-                vertices: self.vertices[(min_vindex as usize)..=(max_vindex as usize)].to_vec(),
-                texture_id: self.texture_id,
-            };
-            debug_assert!(mesh.is_valid(), "Mesh is invalid");
+        if !mesh.is_empty() {
             output.push(mesh);
         }
         output
@@ -318,6 +325,48 @@ impl Mesh {
 
 // ----------------------------------------------------------------------------
 
+/// A [`Mesh`] whose colors should be brightened, produced from a [`crate::HdrShape`].
+///
+/// See [`crate::TessellationOptions::hdr`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct HdrMesh {
+    /// The mesh, with its colors at intensity 1.0.
+    pub mesh: Mesh,
+
+    /// What to multiply the linear vertex colors with.
+    ///
+    /// The backend should do this in the vertex shader, after converting the colors to linear space.
+    pub intensity: f32,
+}
+
+// ----------------------------------------------------------------------------
+
+/// A [`Mesh`] to paint many times, produced from a [`crate::InstancedShape`].
+///
+/// See [`crate::TessellationOptions::instancing`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct InstancedMesh {
+    /// The mesh, in the local coordinates of each instance.
+    pub mesh: Mesh,
+
+    /// Where to paint each copy of [`Self::mesh`].
+    pub instances: Vec<TSTransform>,
+}
+
+impl InstancedMesh {
+    /// Paint all the instances into one [`Mesh`],
+    /// for backends that can't do instancing after all.
+    pub fn to_mesh(&self) -> Mesh {
+        let mut mesh = Mesh::with_texture(self.mesh.texture_id);
+        for &transform in &self.instances {
+            let mut instance = self.mesh.clone();
+            instance.transform(transform);
+            mesh.append(instance);
+        }
+        mesh
+    }
+}
+
 /// A version of [`Mesh`] that uses 16-bit indices.
 ///
 /// This is produced by [`Mesh::split_to_u16`] and is meant to be used for legacy render backends.
@@ -113,6 +113,20 @@ pub fn adjust_colors(
         Shape::Callback(_) => {
             // Can't tint user callback code
         }
+
+        Shape::Instanced(instanced) => {
+            adjust_colors(&mut Arc::make_mut(instanced).shape, adjust_color);
+        }
+
+        Shape::Hdr(hdr) => {
+            adjust_colors(&mut Arc::make_mut(hdr).shape, adjust_color);
+        }
+
+        Shape::Composite(composite) => {
+            for clipped in &mut Arc::make_mut(composite).shapes {
+                adjust_colors(&mut clipped.shape, adjust_color);
+            }
+        }
     }
 }
 
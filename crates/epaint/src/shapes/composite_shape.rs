@@ -0,0 +1,62 @@
+use emath::{Pos2, Rect};
+
+use crate::ClippedShape;
+
+/// Shapes that are blended together as one image, which is then faded by [`Self::opacity`].
+///
+/// This is different from fading each shape on its own:
+/// where two shapes overlap, only the top one is visible,
+/// just like when the shapes are fully opaque.
+///
+/// If the backend supports it (see [`crate::TessellationOptions::composite`]),
+/// the shapes are painted between a [`CompositeBoundary::Begin`] and a [`CompositeBoundary::End`].
+/// Otherwise each shape is faded on its own.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CompositeShape {
+    /// The shapes to blend together, each with their own clip rectangle.
+    pub shapes: Vec<ClippedShape>,
+
+    /// How opaque the result is, in `[0, 1]`.
+    pub opacity: f32,
+}
+
+impl CompositeShape {
+    pub fn new(shapes: Vec<ClippedShape>, opacity: f32) -> Self {
+        Self { shapes, opacity }
+    }
+
+    /// The visual bounding rectangle (includes stroke widths), within the clip rectangles.
+    pub fn visual_bounding_rect(&self) -> Rect {
+        self.shapes
+            .iter()
+            .map(|clipped| {
+                clipped
+                    .shape
+                    .visual_bounding_rect()
+                    .intersect(clipped.clip_rect)
+            })
+            .fold(Rect::NOTHING, |a, b| a.union(b))
+    }
+
+    pub(crate) fn distance_to(&self, pos: Pos2) -> f32 {
+        self.shapes
+            .iter()
+            .map(|clipped| clipped.shape.distance_to(pos))
+            .fold(f32::INFINITY, f32::min)
+    }
+}
+
+/// Marks where the primitives of a [`CompositeShape`] start and end.
+///
+/// Only produced if [`crate::TessellationOptions::composite`] is set.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CompositeBoundary {
+    /// Start painting into a new, fully transparent, offscreen target.
+    Begin,
+
+    /// Blend the offscreen target onto what was painted before the matching [`Self::Begin`],
+    /// multiplied by `opacity`.
+    ///
+    /// The clip rectangle of the primitive covers everything painted offscreen.
+    End { opacity: f32 },
+}
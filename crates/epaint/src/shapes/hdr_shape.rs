@@ -0,0 +1,32 @@
+use emath::Rect;
+
+use crate::Shape;
+
+/// A [`Shape`] whose colors are brightened beyond what fits in a [`ecolor::Color32`],
+/// e.g. for glowing accents in a game overlay painted into an HDR target.
+///
+/// If the backend supports HDR (see [`crate::TessellationOptions::hdr`]),
+/// the shape is handed over as a [`crate::Primitive::Hdr`].
+/// Otherwise the intensity is ignored, and the shape is painted as usual.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HdrShape {
+    /// The shape to paint, with its colors at intensity 1.0.
+    pub shape: Shape,
+
+    /// What to multiply the (linear) colors of [`Self::shape`] with.
+    pub intensity: f32,
+}
+
+impl HdrShape {
+    pub fn new(shape: impl Into<Shape>, intensity: f32) -> Self {
+        Self {
+            shape: shape.into(),
+            intensity,
+        }
+    }
+
+    /// The visual bounding rectangle (includes stroke widths).
+    pub fn visual_bounding_rect(&self) -> Rect {
+        self.shape.visual_bounding_rect()
+    }
+}
@@ -0,0 +1,50 @@
+use emath::{Pos2, Rect, TSTransform};
+
+use crate::Shape;
+
+/// The same [`Shape`] painted many times, e.g. the markers of a scatter plot or the cells of a grid.
+///
+/// If the backend supports instancing (see [`crate::TessellationOptions::instancing`]),
+/// the shape is only tessellated once and handed over as a [`crate::Primitive::Instanced`].
+/// Otherwise each instance is tessellated on its own.
+#[derive(Clone, Debug, PartialEq)]
+pub struct InstancedShape {
+    /// The shape to repeat, in the local coordinates of each instance.
+    pub shape: Shape,
+
+    /// Where to paint each copy of [`Self::shape`].
+    pub instances: Vec<TSTransform>,
+}
+
+impl InstancedShape {
+    pub fn new(shape: impl Into<Shape>, instances: Vec<TSTransform>) -> Self {
+        Self {
+            shape: shape.into(),
+            instances,
+        }
+    }
+
+    /// The visual bounding rectangle of all instances (includes stroke widths).
+    pub fn visual_bounding_rect(&self) -> Rect {
+        let rect = self.shape.visual_bounding_rect();
+        if !rect.is_positive() {
+            return Rect::NOTHING;
+        }
+        self.instances
+            .iter()
+            .fold(Rect::NOTHING, |bounds, transform| {
+                bounds | (*transform * rect)
+            })
+    }
+
+    /// The distance from the given point to the painted parts of the closest instance.
+    pub fn distance_to(&self, pos: Pos2) -> f32 {
+        self.instances
+            .iter()
+            .map(|transform| {
+                let local_pos = transform.inverse() * pos;
+                transform.scaling * self.shape.distance_to(local_pos)
+            })
+            .fold(f32::INFINITY, f32::min)
+    }
+}
@@ -1,6 +1,9 @@
 mod bezier_shape;
 mod circle_shape;
+mod composite_shape;
 mod ellipse_shape;
+mod hdr_shape;
+mod instanced_shape;
 mod paint_callback;
 mod path_shape;
 mod rect_shape;
@@ -10,7 +13,10 @@ mod text_shape;
 pub use self::{
     bezier_shape::{CubicBezierShape, QuadraticBezierShape},
     circle_shape::CircleShape,
+    composite_shape::{CompositeBoundary, CompositeShape},
     ellipse_shape::EllipseShape,
+    hdr_shape::HdrShape,
+    instanced_shape::InstancedShape,
     paint_callback::{PaintCallback, PaintCallbackInfo},
     path_shape::PathShape,
     rect_shape::RectShape,
@@ -15,6 +15,9 @@ pub struct PaintCallbackInfo {
     pub viewport: Rect,
 
     /// Clip rectangle in points.
+    ///
+    /// This is the clip rectangle of the `egui::Painter` that added the callback,
+    /// transformed together with the layer it was painted on.
     pub clip_rect: Rect,
 
     /// Pixels per point.
@@ -22,6 +25,14 @@ pub struct PaintCallbackInfo {
 
     /// Full size of the screen, in pixels.
     pub screen_size_px: [u32; 2],
+
+    /// How much the callback has been scaled since it was added.
+    ///
+    /// This comes from [`PaintCallback::scaling`].
+    pub scaling: f32,
+
+    /// This comes from [`PaintCallback::user_data`].
+    pub user_data: Option<Arc<dyn Any + Send + Sync>>,
 }
 
 #[test]
@@ -50,11 +61,26 @@ impl PaintCallbackInfo {
     pub fn clip_rect_in_pixels(&self) -> ViewportInPixels {
         ViewportInPixels::from_points(&self.clip_rect, self.pixels_per_point, self.screen_size_px)
     }
+
+    /// The [`Self::user_data`], if it is of type `T`.
+    pub fn user_data<T: Any>(&self) -> Option<&T> {
+        self.user_data.as_ref()?.downcast_ref()
+    }
 }
 
 /// If you want to paint some 3D shapes inside an egui region, you can use this.
 ///
 /// This is advanced usage, and is backend specific.
+///
+/// The callback is painted in the same order as the other shapes:
+/// shapes added before it to the same layer are painted below it,
+/// and shapes added after it are painted on top of it.
+/// Callbacks are never batched together with other shapes.
+///
+/// The callback is clipped to the clip rectangle of the painter that added it,
+/// e.g. use `egui::Painter::with_clip_rect` for a callback-specific clip.
+/// If the layer is transformed (e.g. in a scene), [`Self::rect`] and the clip rectangle
+/// are transformed with it, and [`Self::scaling`] keeps track of the scale.
 #[derive(Clone)]
 pub struct PaintCallback {
     /// Where to paint.
@@ -79,19 +105,63 @@ pub struct PaintCallback {
     ///
     /// Shape has to be clone, therefore this has to be an `Arc` instead of a `Box`.
     pub callback: Arc<dyn Any + Send + Sync>,
+
+    /// How much [`Self::rect`] has been scaled by [`Shape::transform`], e.g. by a zoomed layer.
+    ///
+    /// Use this to scale e.g. line widths in the callback.
+    /// This will become [`PaintCallbackInfo::scaling`].
+    pub scaling: f32,
+
+    /// Data for this frame, passed on to the callback in [`PaintCallbackInfo::user_data`].
+    ///
+    /// This lets you reuse the same `callback` from frame to frame,
+    /// and only send the things that changed (e.g. a camera).
+    pub user_data: Option<Arc<dyn Any + Send + Sync>>,
+}
+
+impl PaintCallback {
+    pub fn new(rect: Rect, callback: Arc<dyn Any + Send + Sync>) -> Self {
+        Self {
+            rect,
+            callback,
+            scaling: 1.0,
+            user_data: None,
+        }
+    }
+
+    /// Set [`Self::user_data`].
+    #[inline]
+    pub fn with_user_data(mut self, user_data: impl Any + Send + Sync) -> Self {
+        self.user_data = Some(Arc::new(user_data));
+        self
+    }
+
+    /// The [`Self::user_data`], if it is of type `T`.
+    pub fn user_data<T: Any>(&self) -> Option<&T> {
+        self.user_data.as_ref()?.downcast_ref()
+    }
 }
 
 impl std::fmt::Debug for PaintCallback {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("CustomShape")
             .field("rect", &self.rect)
+            .field("scaling", &self.scaling)
             .finish_non_exhaustive()
     }
 }
 
 impl std::cmp::PartialEq for PaintCallback {
     fn eq(&self, other: &Self) -> bool {
-        self.rect.eq(&other.rect) && Arc::ptr_eq(&self.callback, &other.callback)
+        let user_data_eq = match (&self.user_data, &other.user_data) {
+            (None, None) => true,
+            (Some(a), Some(b)) => Arc::ptr_eq(a, b),
+            _ => false,
+        };
+        self.rect.eq(&other.rect)
+            && self.scaling == other.scaling
+            && Arc::ptr_eq(&self.callback, &other.callback)
+            && user_data_eq
     }
 }
 
@@ -11,8 +11,8 @@ use crate::{
 };
 
 use super::{
-    CircleShape, CubicBezierShape, EllipseShape, PaintCallback, PathShape, QuadraticBezierShape,
-    RectShape, TextShape,
+    CircleShape, CompositeShape, CubicBezierShape, EllipseShape, HdrShape, InstancedShape,
+    PaintCallback, PathShape, QuadraticBezierShape, RectShape, TextShape,
 };
 
 /// A paint primitive such as a circle or a piece of text.
@@ -67,7 +67,24 @@ pub enum Shape {
     CubicBezier(CubicBezierShape),
 
     /// Backend-specific painting.
+    ///
+    /// Painted in order with the other shapes, see [`PaintCallback`].
     Callback(PaintCallback),
+
+    /// The same shape painted many times, see [`InstancedShape`].
+    ///
+    /// Wrapped in an [`Arc`] to minimize the size of [`Shape`].
+    Instanced(Arc<InstancedShape>),
+
+    /// A shape with overbright colors, see [`HdrShape`].
+    ///
+    /// Wrapped in an [`Arc`] to minimize the size of [`Shape`].
+    Hdr(Arc<HdrShape>),
+
+    /// Shapes that are blended together before being faded, see [`CompositeShape`].
+    ///
+    /// Wrapped in an [`Arc`] to minimize the size of [`Shape`].
+    Composite(Arc<CompositeShape>),
 }
 
 #[test]
@@ -110,6 +127,27 @@ impl From<Arc<Mesh>> for Shape {
     }
 }
 
+impl From<InstancedShape> for Shape {
+    #[inline(always)]
+    fn from(instanced: InstancedShape) -> Self {
+        Self::Instanced(instanced.into())
+    }
+}
+
+impl From<HdrShape> for Shape {
+    #[inline(always)]
+    fn from(hdr: HdrShape) -> Self {
+        Self::Hdr(hdr.into())
+    }
+}
+
+impl From<CompositeShape> for Shape {
+    #[inline(always)]
+    fn from(composite: CompositeShape) -> Self {
+        Self::Composite(composite.into())
+    }
+}
+
 /// ## Constructors
 impl Shape {
     /// A line between two points.
@@ -356,6 +394,26 @@ impl Shape {
         Self::mesh(mesh)
     }
 
+    /// Paint the same shape many times, e.g. the markers of a scatter plot.
+    ///
+    /// See [`InstancedShape`].
+    pub fn instanced(shape: impl Into<Self>, instances: Vec<TSTransform>) -> Self {
+        InstancedShape::new(shape, instances).into()
+    }
+
+    /// Brighten the colors of a shape by the given intensity, for glowing accents.
+    ///
+    /// An intensity of 1.0 (or less) returns the shape as-is.
+    /// See [`HdrShape`].
+    pub fn hdr(shape: impl Into<Self>, intensity: f32) -> Self {
+        let shape = shape.into();
+        if intensity <= 1.0 {
+            shape
+        } else {
+            HdrShape::new(shape, intensity).into()
+        }
+    }
+
     /// The visual bounding rectangle (includes stroke widths)
     pub fn visual_bounding_rect(&self) -> Rect {
         match self {
@@ -383,10 +441,204 @@ impl Shape {
             Self::QuadraticBezier(bezier) => bezier.visual_bounding_rect(),
             Self::CubicBezier(bezier) => bezier.visual_bounding_rect(),
             Self::Callback(custom) => custom.rect,
+            Self::Instanced(instanced) => instanced.visual_bounding_rect(),
+            Self::Hdr(hdr) => hdr.visual_bounding_rect(),
+            Self::Composite(composite) => composite.visual_bounding_rect(),
         }
     }
 }
 
+/// ## Hit-testing
+impl Shape {
+    /// Is the given point on the painted parts of this shape (fill or stroke)?
+    ///
+    /// See [`Self::distance_to`].
+    #[inline]
+    pub fn contains_point(&self, pos: Pos2) -> bool {
+        self.distance_to(pos) <= 0.0
+    }
+
+    /// The distance from the given point to the painted parts of this shape (fill or stroke),
+    /// or zero if the point is on them.
+    ///
+    /// Use this for precise picking in custom widgets,
+    /// e.g. `shape.distance_to(pointer_pos) <= interact_radius` for selecting thin lines.
+    ///
+    /// Beziers and ellipses are approximated with line segments,
+    /// and text (and [`Shape::Callback`]) with its bounding rectangle.
+    /// Feathering is ignored.
+    pub fn distance_to(&self, pos: Pos2) -> f32 {
+        match self {
+            Self::Noop => f32::INFINITY,
+            Self::Vec(shapes) => shapes
+                .iter()
+                .map(|shape| shape.distance_to(pos))
+                .fold(f32::INFINITY, f32::min),
+            Self::Circle(circle) => painted_distance(
+                pos.distance(circle.center) - circle.radius,
+                circle.fill != Color32::TRANSPARENT,
+                circle.stroke.width,
+                StrokeKind::Middle,
+            ),
+            Self::Ellipse(ellipse) => {
+                const NUM_POINTS: usize = 64;
+                let points: Vec<Pos2> = (0..NUM_POINTS)
+                    .map(|i| {
+                        let angle = std::f32::consts::TAU * i as f32 / NUM_POINTS as f32;
+                        ellipse.center + ellipse.radius * Vec2::angled(angle)
+                    })
+                    .collect();
+                painted_distance(
+                    polygon_signed_distance(&points, pos),
+                    ellipse.fill != Color32::TRANSPARENT,
+                    ellipse.stroke.width,
+                    StrokeKind::Middle,
+                )
+            }
+            Self::LineSegment { points, stroke } => painted_distance(
+                segment_distance(points[0], points[1], pos),
+                false,
+                stroke.width,
+                StrokeKind::Middle,
+            ),
+            Self::Path(path) => {
+                path_distance(&path.points, path.closed, path.fill, &path.stroke, pos)
+            }
+            Self::Rect(rect_shape) => painted_distance(
+                rounded_rect_signed_distance(rect_shape.rect, rect_shape.corner_radius, pos),
+                rect_shape.fill != Color32::TRANSPARENT || rect_shape.brush.is_some(),
+                rect_shape.stroke.width,
+                rect_shape.stroke_kind,
+            ),
+            Self::Text(text_shape) => text_shape.visual_bounding_rect().distance_to_pos(pos),
+            Self::Mesh(mesh) => mesh_distance(mesh, pos),
+            Self::QuadraticBezier(bezier) => path_distance(
+                &bezier.flatten(None),
+                bezier.closed,
+                bezier.fill,
+                &bezier.stroke,
+                pos,
+            ),
+            Self::CubicBezier(bezier) => path_distance(
+                &bezier.flatten(None),
+                bezier.closed,
+                bezier.fill,
+                &bezier.stroke,
+                pos,
+            ),
+            Self::Callback(callback) => callback.rect.distance_to_pos(pos),
+            Self::Instanced(instanced) => instanced.distance_to(pos),
+            Self::Hdr(hdr) => hdr.shape.distance_to(pos),
+            Self::Composite(composite) => composite.distance_to(pos),
+        }
+    }
+}
+
+/// The distance to the painted parts of a shape,
+/// given the signed distance to its outline (negative inside).
+fn painted_distance(
+    signed_distance: f32,
+    filled: bool,
+    stroke_width: f32,
+    kind: StrokeKind,
+) -> f32 {
+    let (outset, inset) = match kind {
+        StrokeKind::Inside => (0.0, stroke_width),
+        StrokeKind::Middle => (stroke_width / 2.0, stroke_width / 2.0),
+        StrokeKind::Outside => (stroke_width, 0.0),
+    };
+    let outside = signed_distance - outset;
+    if filled {
+        outside.max(0.0)
+    } else {
+        let inside = -signed_distance - inset;
+        outside.max(inside).max(0.0)
+    }
+}
+
+fn path_distance(
+    points: &[Pos2],
+    closed: bool,
+    fill: Color32,
+    stroke: &PathStroke,
+    pos: Pos2,
+) -> f32 {
+    if closed && 3 <= points.len() {
+        painted_distance(
+            polygon_signed_distance(points, pos),
+            fill != Color32::TRANSPARENT,
+            stroke.width,
+            stroke.kind,
+        )
+    } else {
+        let distance = match points {
+            [] => f32::INFINITY,
+            [point] => point.distance(pos),
+            _ => points
+                .windows(2)
+                .map(|segment| segment_distance(segment[0], segment[1], pos))
+                .fold(f32::INFINITY, f32::min),
+        };
+        painted_distance(distance, false, stroke.width, StrokeKind::Middle)
+    }
+}
+
+/// Distance from `pos` to the line segment between `a` and `b`.
+fn segment_distance(a: Pos2, b: Pos2, pos: Pos2) -> f32 {
+    let ab = b - a;
+    let length_sq = ab.length_sq();
+    let t = if length_sq > 0.0 {
+        ((pos - a).dot(ab) / length_sq).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    pos.distance(a + t * ab)
+}
+
+/// Distance from `pos` to the outline of a polygon, negative inside it.
+fn polygon_signed_distance(points: &[Pos2], pos: Pos2) -> f32 {
+    let mut distance = f32::INFINITY;
+    let mut inside = false;
+    for (i, &a) in points.iter().enumerate() {
+        let b = points[(i + 1) % points.len()];
+        distance = distance.min(segment_distance(a, b, pos));
+        if (a.y > pos.y) != (b.y > pos.y) && pos.x < a.x + (pos.y - a.y) / (b.y - a.y) * (b.x - a.x)
+        {
+            inside = !inside;
+        }
+    }
+    if inside { -distance } else { distance }
+}
+
+/// Distance from `pos` to the outline of a rounded rectangle, negative inside it.
+fn rounded_rect_signed_distance(rect: Rect, corner_radius: CornerRadius, pos: Pos2) -> f32 {
+    let half_size = rect.size() / 2.0;
+    let p = pos - rect.center();
+    let radius = match (p.x < 0.0, p.y < 0.0) {
+        (true, true) => corner_radius.nw,
+        (false, true) => corner_radius.ne,
+        (true, false) => corner_radius.sw,
+        (false, false) => corner_radius.se,
+    };
+    let radius = f32::from(radius).min(half_size.min_elem());
+    let q = p.abs() - half_size + Vec2::splat(radius);
+    q.max(Vec2::ZERO).length() + q.max_elem().min(0.0) - radius
+}
+
+fn mesh_distance(mesh: &Mesh, pos: Pos2) -> f32 {
+    let mut distance = f32::INFINITY;
+    for triangle in mesh.indices.chunks_exact(3) {
+        let [a, b, c] =
+            [triangle[0], triangle[1], triangle[2]].map(|i| mesh.vertices[i as usize].pos);
+        let triangle_distance = polygon_signed_distance(&[a, b, c], pos);
+        if triangle_distance <= 0.0 {
+            return 0.0;
+        }
+        distance = distance.min(triangle_distance);
+    }
+    distance
+}
+
 /// ## Inspection and transforms
 impl Shape {
     #[inline(always)]
@@ -395,6 +647,10 @@ impl Shape {
             mesh.texture_id
         } else if let Self::Rect(rect_shape) = self {
             rect_shape.fill_texture_id()
+        } else if let Self::Instanced(instanced) = self {
+            instanced.shape.texture_id()
+        } else if let Self::Hdr(hdr) = self {
+            hdr.shape.texture_id()
         } else {
             crate::TextureId::default()
         }
@@ -420,6 +676,7 @@ impl Shape {
     ///
     /// If using a [`PaintCallback`], note that only the rect is scaled as opposed
     /// to other shapes where the stroke is also scaled.
+    /// The scale is recorded in [`PaintCallback::scaling`].
     pub fn transform(&mut self, transform: TSTransform) {
         match self {
             Self::Noop => {}
@@ -476,6 +733,21 @@ impl Shape {
             }
             Self::Callback(shape) => {
                 shape.rect = transform * shape.rect;
+                shape.scaling *= transform.scaling;
+            }
+            Self::Instanced(instanced) => {
+                for instance in &mut Arc::make_mut(instanced).instances {
+                    *instance = transform * *instance;
+                }
+            }
+            Self::Hdr(hdr) => {
+                Arc::make_mut(hdr).shape.transform(transform);
+            }
+            Self::Composite(composite) => {
+                for clipped in &mut Arc::make_mut(composite).shapes {
+                    clipped.clip_rect = transform.mul_rect(clipped.clip_rect);
+                    clipped.shape.transform(transform);
+                }
             }
         }
     }
@@ -558,3 +830,57 @@ fn dashes_from_line(
         position_on_segment -= segment_length;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distance_to() {
+        let line = Shape::line_segment([pos2(0.0, 0.0), pos2(100.0, 0.0)], (4.0, Color32::WHITE));
+        assert!(line.contains_point(pos2(50.0, 1.5)));
+        assert!(!line.contains_point(pos2(50.0, 2.5)));
+        assert_eq!(line.distance_to(pos2(50.0, 12.0)), 10.0);
+        assert_eq!(line.distance_to(pos2(110.0, 0.0)), 8.0);
+
+        let filled = Shape::circle_filled(pos2(0.0, 0.0), 10.0, Color32::WHITE);
+        assert!(filled.contains_point(pos2(0.0, 0.0)));
+        assert_eq!(filled.distance_to(pos2(20.0, 0.0)), 10.0);
+
+        let ring = Shape::circle_stroke(pos2(0.0, 0.0), 10.0, (2.0, Color32::WHITE));
+        assert!(!ring.contains_point(pos2(0.0, 0.0)));
+        assert!(ring.contains_point(pos2(10.5, 0.0)));
+        assert_eq!(ring.distance_to(pos2(5.0, 0.0)), 4.0);
+
+        let rect = Rect::from_min_size(pos2(0.0, 0.0), Vec2::splat(100.0));
+        let rounded = Shape::rect_filled(rect, 20, Color32::WHITE);
+        assert!(rounded.contains_point(pos2(50.0, 50.0)));
+        assert!(rounded.contains_point(pos2(0.0, 50.0)));
+        assert!(
+            !rounded.contains_point(pos2(1.0, 1.0)),
+            "Outside the rounded corner"
+        );
+
+        let triangle = Shape::convex_polygon(
+            vec![pos2(0.0, 0.0), pos2(100.0, 0.0), pos2(0.0, 100.0)],
+            Color32::WHITE,
+            Stroke::NONE,
+        );
+        assert!(triangle.contains_point(pos2(10.0, 10.0)));
+        assert!(!triangle.contains_point(pos2(90.0, 90.0)));
+
+        let bezier = Shape::CubicBezier(CubicBezierShape::from_points_stroke(
+            [
+                pos2(0.0, 0.0),
+                pos2(0.0, 100.0),
+                pos2(100.0, 100.0),
+                pos2(100.0, 0.0),
+            ],
+            false,
+            Color32::TRANSPARENT,
+            (2.0, Color32::WHITE),
+        ));
+        assert!(bezier.contains_point(pos2(50.0, 75.0)));
+        assert!(!bezier.contains_point(pos2(50.0, 50.0)));
+    }
+}
@@ -177,6 +177,12 @@ pub struct PaintStats {
     pub clipped_primitives: AllocInfo,
     pub vertices: AllocInfo,
     pub indices: AllocInfo,
+
+    /// See [`crate::Tessellator::num_coarsened_curves`].
+    pub num_coarsened_curves: usize,
+
+    /// See [`crate::Tessellator::num_occluded_shapes`].
+    pub num_occluded_shapes: usize,
 }
 
 impl PaintStats {
@@ -226,6 +232,17 @@ impl PaintStats {
             Shape::Callback(_) => {
                 self.num_callbacks += 1;
             }
+            Shape::Instanced(instanced) => {
+                self.add(&instanced.shape);
+            }
+            Shape::Hdr(hdr) => {
+                self.add(&hdr.shape);
+            }
+            Shape::Composite(composite) => {
+                for clipped in &composite.shapes {
+                    self.add(&clipped.shape);
+                }
+            }
         }
     }
 
@@ -235,7 +252,10 @@ impl PaintStats {
     ) -> Self {
         self.clipped_primitives += AllocInfo::from_slice(clipped_primitives);
         for clipped_primitive in clipped_primitives {
-            if let Primitive::Mesh(mesh) = &clipped_primitive.primitive {
+            if let Primitive::Mesh(mesh)
+            | Primitive::Instanced(crate::InstancedMesh { mesh, .. })
+            | Primitive::Hdr(crate::HdrMesh { mesh, .. }) = &clipped_primitive.primitive
+            {
                 self.vertices += AllocInfo::from_slice(&mesh.vertices);
                 self.indices += AllocInfo::from_slice(&mesh.indices);
             }
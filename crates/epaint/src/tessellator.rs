@@ -8,10 +8,11 @@
 use emath::{GuiRounding as _, NumExt as _, Pos2, Rect, Rot2, Vec2, pos2, remap, vec2};
 
 use crate::{
-    CircleShape, ClippedPrimitive, ClippedShape, Color32, CornerRadiusF32, CubicBezierShape,
-    EllipseShape, Mesh, PathShape, Primitive, QuadraticBezierShape, RectShape, Shape, Stroke,
-    StrokeKind, TextShape, TextureId, Vertex, WHITE_UV, color::ColorMode, emath,
-    stroke::PathStroke, texture_atlas::PreparedDisc,
+    CircleShape, ClippedPrimitive, ClippedShape, Color32, CompositeBoundary, CompositeShape,
+    CornerRadius, CornerRadiusF32, CubicBezierShape, EllipseShape, HdrMesh, InstancedMesh, Mesh,
+    PathShape, Primitive, QuadraticBezierShape, RectShape, Shape, Stroke, StrokeKind, TextShape,
+    TextureId, Vertex, WHITE_UV, color::ColorMode, emath, stroke::PathStroke,
+    texture_atlas::PreparedDisc,
 };
 
 // ----------------------------------------------------------------------------
@@ -707,19 +708,98 @@ pub struct TessellationOptions {
     pub debug_ignore_clip_rects: bool,
 
     /// The maximum distance between the original curve and the flattened curve.
+    ///
+    /// In points, unless [`Self::bezier_tolerance_in_pixels`] is set.
     pub bezier_tolerance: f32,
 
+    /// If `true`, [`Self::bezier_tolerance`] is in physical pixels instead of points,
+    /// so curves are flattened with more detail on high-dpi screens and less when zoomed out.
+    ///
+    /// Default: `false`.
+    pub bezier_tolerance_in_pixels: bool,
+
+    /// If set, curves are flattened more coarsely once this many vertices have been produced,
+    /// the more the further over budget we are.
+    ///
+    /// This keeps scenes with many curves (e.g. a zoomed-out node graph) from producing huge meshes.
+    /// See [`Tessellator::num_coarsened_curves`] for how often it kicked in.
+    ///
+    /// Curves are then not tessellated in parallel, since they depend on what came before.
+    ///
+    /// Default: `None`.
+    pub vertex_budget: Option<usize>,
+
+    /// If `true`, shapes that are completely covered by a later opaque rectangle
+    /// (e.g. a window below another window) are dropped before tessellation.
+    ///
+    /// Only filled rectangles without texture or blur count as opaque, minus their rounded corners.
+    /// See [`Tessellator::num_occluded_shapes`] for how many shapes were dropped.
+    ///
+    /// Default: `false`.
+    pub occlusion_culling: bool,
+
     /// The default value will be 1.0e-5, it will be used during float compare.
     pub epsilon: f32,
 
     /// If `rayon` feature is activated, should we parallelize tessellation?
     pub parallel_tessellation: bool,
 
+    /// Set this if your backend can paint [`crate::Primitive::Instanced`],
+    /// so that each [`crate::InstancedShape`] is only tessellated once.
+    ///
+    /// If `false` (default), the instances are tessellated one by one.
+    ///
+    /// Note that the feathering of instanced meshes is scaled along with the instances.
+    ///
+    /// egui sets this from `BackendCapabilities::instancing`.
+    pub instancing: bool,
+
+    /// Set this if your backend paints into an HDR target and can paint [`crate::Primitive::Hdr`],
+    /// so that each [`crate::HdrShape`] keeps its intensity.
+    ///
+    /// If `false` (default), the intensity of [`crate::HdrShape`]s is ignored.
+    ///
+    /// egui sets this from `BackendCapabilities::hdr`.
+    pub hdr: bool,
+
+    /// Set this if your backend can paint [`crate::Primitive::Composite`],
+    /// i.e. paint the primitives of a [`crate::CompositeShape`] offscreen and then blend them in as one.
+    ///
+    /// If `false` (default), each shape of a [`crate::CompositeShape`] is faded on its own,
+    /// so where they overlap, the ones below show through.
+    ///
+    /// egui sets this from `BackendCapabilities::composite`.
+    pub composite: bool,
+
+    /// If `false` (default), no [`Mesh`] in the output has more than [`u16::MAX`] vertices,
+    /// so all indices fit in 16 bits. Larger meshes are split up.
+    ///
+    /// Set this to `true` if your backend supports 32-bit indices,
+    /// to skip the splitting of huge meshes.
+    pub u32_indices: bool,
+
     /// If `true`, invalid meshes will be silently ignored.
     /// If `false`, invalid meshes will cause a panic.
     ///
     /// The default is `false` to save performance.
     pub validate_meshes: bool,
+
+    /// Transform all vertex colors to simulate how someone with this color vision deficiency
+    /// would see them.
+    ///
+    /// This is a debug option for checking that your UI works for color-blind users.
+    /// It does not affect the colors of textures, nor paint callbacks.
+    ///
+    /// Default: `None`.
+    pub simulate_color_vision_deficiency: Option<ecolor::ColorVisionDeficiency>,
+
+    /// Convert all vertex colors into this color space.
+    ///
+    /// It does not affect the colors of textures, nor paint callbacks.
+    /// egui sets this from `Options::color_space`.
+    ///
+    /// Default: [`ecolor::ColorSpace::Srgb`] (no conversion).
+    pub color_space: ecolor::ColorSpace,
 }
 
 impl Default for TessellationOptions {
@@ -736,9 +816,18 @@ impl Default for TessellationOptions {
             debug_paint_clip_rects: false,
             debug_ignore_clip_rects: false,
             bezier_tolerance: 0.1,
+            bezier_tolerance_in_pixels: false,
+            vertex_budget: None,
+            occlusion_culling: false,
             epsilon: 1.0e-5,
             parallel_tessellation: true,
+            instancing: false,
+            hdr: false,
+            composite: false,
+            u32_indices: false,
             validate_meshes: false,
+            simulate_color_vision_deficiency: None,
+            color_space: ecolor::ColorSpace::Srgb,
         }
     }
 }
@@ -1317,6 +1406,15 @@ pub struct Tessellator {
 
     scratchpad_points: Vec<Pos2>,
     scratchpad_path: Path,
+
+    /// Vertices produced so far, for [`TessellationOptions::vertex_budget`].
+    num_vertices: usize,
+
+    /// Curves flattened more coarsely because of [`TessellationOptions::vertex_budget`].
+    num_coarsened_curves: usize,
+
+    /// Shapes dropped because of [`TessellationOptions::occlusion_culling`].
+    num_occluded_shapes: usize,
 }
 
 impl Tessellator {
@@ -1348,7 +1446,41 @@ impl Tessellator {
             clip_rect: Rect::EVERYTHING,
             scratchpad_points: Default::default(),
             scratchpad_path: Default::default(),
+            num_vertices: 0,
+            num_coarsened_curves: 0,
+            num_occluded_shapes: 0,
+        }
+    }
+
+    /// How many curves were flattened more coarsely to stay within
+    /// [`TessellationOptions::vertex_budget`].
+    pub fn num_coarsened_curves(&self) -> usize {
+        self.num_coarsened_curves
+    }
+
+    /// How many shapes were dropped because they were covered by opaque rectangles,
+    /// see [`TessellationOptions::occlusion_culling`].
+    pub fn num_occluded_shapes(&self) -> usize {
+        self.num_occluded_shapes
+    }
+
+    /// The tolerance to flatten the next curve with,
+    /// see [`TessellationOptions::bezier_tolerance`].
+    fn bezier_tolerance(&mut self) -> f32 {
+        let mut tolerance = self.options.bezier_tolerance;
+        if self.options.bezier_tolerance_in_pixels {
+            tolerance /= self.pixels_per_point;
+        }
+        if let Some(vertex_budget) = self.options.vertex_budget {
+            if vertex_budget < self.num_vertices {
+                // The number of points of a flattened curve is proportional to 1/sqrt(tolerance),
+                // so this at least halves it, and more the further we are over budget:
+                let overshoot = self.num_vertices as f32 / vertex_budget.max(1) as f32;
+                tolerance *= 4.0 * overshoot * overshoot;
+                self.num_coarsened_curves += 1;
+            }
         }
+        tolerance
     }
 
     /// Set the [`Rect`] to use for culling.
@@ -1383,6 +1515,58 @@ impl Tessellator {
             return;
         }
 
+        if let Shape::Instanced(instanced) = &shape {
+            if self.options.instancing {
+                if self.options.coarse_tessellation_culling
+                    && !instanced.visual_bounding_rect().intersects(clip_rect)
+                {
+                    return;
+                }
+
+                // The shape is in local coordinates, so we can't cull it against the clip rect:
+                self.clip_rect = Rect::EVERYTHING;
+                let mut mesh = Mesh::default();
+                self.tessellate_shape(instanced.shape.clone(), &mut mesh);
+                self.num_vertices += mesh.vertices.len() * instanced.instances.len();
+                out_primitives.push(ClippedPrimitive {
+                    clip_rect,
+                    primitive: Primitive::Instanced(InstancedMesh {
+                        mesh,
+                        instances: instanced.instances.clone(),
+                    }),
+                });
+                return;
+            }
+        }
+
+        if let Shape::Hdr(hdr) = &shape {
+            if self.options.hdr {
+                if self.options.coarse_tessellation_culling
+                    && !hdr.visual_bounding_rect().intersects(clip_rect)
+                {
+                    return;
+                }
+
+                self.clip_rect = clip_rect;
+                let mut mesh = Mesh::with_texture(hdr.shape.texture_id());
+                self.tessellate_shape(hdr.shape.clone(), &mut mesh);
+                self.num_vertices += mesh.vertices.len();
+                out_primitives.push(ClippedPrimitive {
+                    clip_rect,
+                    primitive: Primitive::Hdr(HdrMesh {
+                        mesh,
+                        intensity: hdr.intensity,
+                    }),
+                });
+                return;
+            }
+        }
+
+        if let Shape::Composite(composite) = &shape {
+            self.tessellate_composite(clip_rect, composite, out_primitives);
+            return;
+        }
+
         let start_new_mesh = match out_primitives.last() {
             None => true,
             Some(output_clipped_primitive) => {
@@ -1391,7 +1575,10 @@ impl Tessellator {
                         Primitive::Mesh(output_mesh) => {
                             output_mesh.texture_id != shape.texture_id()
                         }
-                        Primitive::Callback(_) => true,
+                        Primitive::Callback(_)
+                        | Primitive::Instanced(_)
+                        | Primitive::Hdr(_)
+                        | Primitive::Composite(_) => true,
                     }
             }
         };
@@ -1407,7 +1594,9 @@ impl Tessellator {
 
         if let Primitive::Mesh(out_mesh) = &mut out.primitive {
             self.clip_rect = clip_rect;
+            let num_vertices_before = out_mesh.vertices.len();
             self.tessellate_shape(shape, out_mesh);
+            self.num_vertices += out_mesh.vertices.len() - num_vertices_before;
         } else {
             unreachable!();
         }
@@ -1476,6 +1665,74 @@ impl Tessellator {
             Shape::Callback(_) => {
                 panic!("Shape::Callback passed to Tessellator");
             }
+            Shape::Instanced(instanced) => {
+                for &transform in &instanced.instances {
+                    let mut shape = instanced.shape.clone();
+                    shape.transform(transform);
+                    self.tessellate_shape(shape, out);
+                }
+            }
+            Shape::Hdr(hdr) => {
+                // The intensity is lost, since it doesn't fit in the vertex colors:
+                self.tessellate_shape(hdr.shape.clone(), out);
+            }
+            Shape::Composite(composite) => {
+                // Without the clip rectangles and a backend to help us, we can only fade the shapes one by one:
+                let num_vertices_before = out.vertices.len();
+                for clipped in &composite.shapes {
+                    self.tessellate_shape(clipped.shape.clone(), out);
+                }
+                fade_vertices(&mut out.vertices[num_vertices_before..], composite.opacity);
+            }
+        }
+    }
+
+    /// Tessellate the shapes of a [`CompositeShape`],
+    /// between [`CompositeBoundary`]s if [`TessellationOptions::composite`] is set.
+    fn tessellate_composite(
+        &mut self,
+        clip_rect: Rect,
+        composite: &CompositeShape,
+        out_primitives: &mut Vec<ClippedPrimitive>,
+    ) {
+        let bounds = composite.visual_bounding_rect().intersect(clip_rect);
+        if !bounds.is_positive() {
+            return;
+        }
+
+        let mut primitives = vec![];
+        for clipped in &composite.shapes {
+            self.tessellate_clipped_shape(
+                ClippedShape {
+                    clip_rect: clipped.clip_rect.intersect(clip_rect),
+                    shape: clipped.shape.clone(),
+                },
+                &mut primitives,
+            );
+        }
+
+        if self.options.composite {
+            out_primitives.push(ClippedPrimitive {
+                clip_rect: bounds,
+                primitive: Primitive::Composite(CompositeBoundary::Begin),
+            });
+            out_primitives.extend(primitives);
+            out_primitives.push(ClippedPrimitive {
+                clip_rect: bounds,
+                primitive: Primitive::Composite(CompositeBoundary::End {
+                    opacity: composite.opacity,
+                }),
+            });
+        } else {
+            for primitive in &mut primitives {
+                if let Primitive::Mesh(mesh)
+                | Primitive::Instanced(InstancedMesh { mesh, .. })
+                | Primitive::Hdr(HdrMesh { mesh, .. }) = &mut primitive.primitive
+                {
+                    fade_vertices(&mut mesh.vertices, composite.opacity);
+                }
+            }
+            out_primitives.extend(primitives);
         }
     }
 
@@ -2121,7 +2378,7 @@ impl Tessellator {
             return;
         }
 
-        let points = quadratic_shape.flatten(Some(options.bezier_tolerance));
+        let points = quadratic_shape.flatten(Some(self.bezier_tolerance()));
 
         self.tessellate_bezier_complete(
             &points,
@@ -2145,8 +2402,8 @@ impl Tessellator {
             return;
         }
 
-        let points_vec =
-            cubic_shape.flatten_closed(Some(options.bezier_tolerance), Some(options.epsilon));
+        let epsilon = options.epsilon;
+        let points_vec = cubic_shape.flatten_closed(Some(self.bezier_tolerance()), Some(epsilon));
 
         for points in points_vec {
             self.tessellate_bezier_complete(
@@ -2212,8 +2469,12 @@ impl Tessellator {
     pub fn tessellate_shapes(&mut self, mut shapes: Vec<ClippedShape>) -> Vec<ClippedPrimitive> {
         profiling::function_scope!();
 
+        if self.options.occlusion_culling && !self.options.debug_ignore_clip_rects {
+            self.cull_occluded_shapes(&mut shapes);
+        }
+
         #[cfg(feature = "rayon")]
-        if self.options.parallel_tessellation {
+        if self.options.parallel_tessellation && self.options.vertex_budget.is_none() {
             self.parallel_tessellation_of_large_shapes(&mut shapes);
         }
 
@@ -2236,16 +2497,56 @@ impl Tessellator {
             }
         }
 
-        clipped_primitives.retain(|p| {
-            p.clip_rect.is_positive()
-                && match &p.primitive {
-                    Primitive::Mesh(mesh) => !mesh.is_empty(),
-                    Primitive::Callback(_) => true,
+        if let Some(deficiency) = self.options.simulate_color_vision_deficiency {
+            profiling::scope!("simulate_color_vision_deficiency");
+            for clipped_primitive in &mut clipped_primitives {
+                if let Primitive::Mesh(mesh)
+                | Primitive::Instanced(InstancedMesh { mesh, .. })
+                | Primitive::Hdr(HdrMesh { mesh, .. }) = &mut clipped_primitive.primitive
+                {
+                    for vertex in &mut mesh.vertices {
+                        vertex.color = deficiency.simulate(vertex.color);
+                    }
+                }
+            }
+        }
+
+        if self.options.color_space != ecolor::ColorSpace::Srgb {
+            profiling::scope!("color_space");
+            let color_space = self.options.color_space;
+            for clipped_primitive in &mut clipped_primitives {
+                if let Primitive::Mesh(mesh)
+                | Primitive::Instanced(InstancedMesh { mesh, .. })
+                | Primitive::Hdr(HdrMesh { mesh, .. }) = &mut clipped_primitive.primitive
+                {
+                    for vertex in &mut mesh.vertices {
+                        vertex.color = color_space.from_srgb(vertex.color);
+                    }
                 }
+            }
+        }
+
+        clipped_primitives.retain(|p| match &p.primitive {
+            Primitive::Mesh(mesh) => p.clip_rect.is_positive() && !mesh.is_empty(),
+            Primitive::Callback(_) => p.clip_rect.is_positive(),
+            Primitive::Instanced(instanced) => {
+                p.clip_rect.is_positive()
+                    && !instanced.mesh.is_empty()
+                    && !instanced.instances.is_empty()
+            }
+            Primitive::Hdr(hdr) => p.clip_rect.is_positive() && !hdr.mesh.is_empty(),
+            Primitive::Composite(_) => true, // Always keep the pairs intact
         });
 
+        if !self.options.u32_indices {
+            clipped_primitives = split_large_meshes(clipped_primitives);
+        }
+
         for clipped_primitive in &clipped_primitives {
-            if let Primitive::Mesh(mesh) = &clipped_primitive.primitive {
+            if let Primitive::Mesh(mesh)
+            | Primitive::Instanced(InstancedMesh { mesh, .. })
+            | Primitive::Hdr(HdrMesh { mesh, .. }) = &clipped_primitive.primitive
+            {
                 debug_assert!(mesh.is_valid(), "Tessellator generated invalid Mesh");
             }
         }
@@ -2253,6 +2554,69 @@ impl Tessellator {
         clipped_primitives
     }
 
+    /// Drop the shapes that are completely covered by a later opaque rectangle,
+    /// see [`TessellationOptions::occlusion_culling`].
+    fn cull_occluded_shapes(&mut self, shapes: &mut Vec<ClippedShape>) {
+        profiling::function_scope!();
+
+        /// Checking against more occluders than this costs more than it saves.
+        const MAX_OCCLUDERS: usize = 64;
+
+        let mut occluders: Vec<Rect> = vec![];
+        let mut is_occluded = vec![false; shapes.len()];
+
+        for (i, ClippedShape { clip_rect, shape }) in shapes.iter().enumerate().rev() {
+            if matches!(shape, Shape::Callback(_)) {
+                continue; // The callback may do more than paint
+            }
+
+            let visible_rect = shape.visual_bounding_rect().intersect(*clip_rect);
+            if visible_rect.is_positive()
+                && occluders
+                    .iter()
+                    .any(|occluder| occluder.contains_rect(visible_rect))
+            {
+                is_occluded[i] = true;
+                continue;
+            }
+
+            if occluders.len() < MAX_OCCLUDERS {
+                if let Some(opaque_rect) = self.opaque_rect(shape) {
+                    let occluder = opaque_rect.intersect(*clip_rect);
+                    if occluder.is_positive() {
+                        occluders.push(occluder);
+                    }
+                }
+            }
+        }
+
+        let num_occluded = is_occluded.iter().filter(|&&occluded| occluded).count();
+        if num_occluded == 0 {
+            return;
+        }
+        self.num_occluded_shapes += num_occluded;
+
+        let mut is_occluded = is_occluded.into_iter();
+        shapes.retain(|_| !is_occluded.next().unwrap_or_default());
+    }
+
+    /// The part of the shape that is guaranteed to be painted fully opaque, if any.
+    fn opaque_rect(&self, shape: &Shape) -> Option<Rect> {
+        let Shape::Rect(rect_shape) = shape else {
+            return None;
+        };
+        if rect_shape.fill.a() != 255 || rect_shape.brush.is_some() || 0.0 < rect_shape.blur_width {
+            return None;
+        }
+
+        let CornerRadius { nw, ne, sw, se } = rect_shape.corner_radius;
+        let corner_radius = nw.max(ne).max(sw).max(se) as f32;
+
+        // Leave room for the feathering and for rounding to pixels:
+        let margin = corner_radius + self.feathering + 1.0 / self.pixels_per_point;
+        Some(rect_shape.rect.shrink(margin))
+    }
+
     /// Find large shapes and throw them on the rayon thread pool,
     /// then replace the original shape with their tessellated meshes.
     #[cfg(feature = "rayon")]
@@ -2278,7 +2642,10 @@ impl Tessellator {
                 | Shape::Mesh(_)
                 | Shape::LineSegment { .. }
                 | Shape::Rect(_)
-                | Shape::Callback(_) => false,
+                | Shape::Callback(_)
+                | Shape::Instanced(_)
+                | Shape::Hdr(_)
+                | Shape::Composite(_) => false,
             }
         }
 
@@ -2335,6 +2702,61 @@ impl Tessellator {
     }
 }
 
+/// Multiply the (premultiplied) colors of the vertices by `opacity`.
+fn fade_vertices(vertices: &mut [Vertex], opacity: f32) {
+    if opacity < 1.0 {
+        for vertex in vertices {
+            vertex.color = vertex.color.gamma_multiply(opacity);
+        }
+    }
+}
+
+/// See [`TessellationOptions::u32_indices`].
+fn split_large_meshes(clipped_primitives: Vec<ClippedPrimitive>) -> Vec<ClippedPrimitive> {
+    let is_large = |p: &ClippedPrimitive| matches!(&p.primitive, Primitive::Mesh(mesh) if (u16::MAX as usize) < mesh.vertices.len());
+    if !clipped_primitives.iter().any(is_large) {
+        return clipped_primitives;
+    }
+
+    profiling::function_scope!();
+    let mut split = Vec::with_capacity(clipped_primitives.len());
+    for clipped_primitive in clipped_primitives {
+        let ClippedPrimitive {
+            clip_rect,
+            primitive,
+        } = clipped_primitive;
+        match primitive {
+            Primitive::Mesh(mesh) => {
+                split.extend(
+                    mesh.split_to_u16_sized()
+                        .into_iter()
+                        .map(|mesh| ClippedPrimitive {
+                            clip_rect,
+                            primitive: Primitive::Mesh(mesh),
+                        }),
+                );
+            }
+            Primitive::Hdr(HdrMesh { mesh, intensity }) => {
+                split.extend(
+                    mesh.split_to_u16_sized()
+                        .into_iter()
+                        .map(|mesh| ClippedPrimitive {
+                            clip_rect,
+                            primitive: Primitive::Hdr(HdrMesh { mesh, intensity }),
+                        }),
+                );
+            }
+            Primitive::Callback(_) | Primitive::Instanced(_) | Primitive::Composite(_) => {
+                split.push(ClippedPrimitive {
+                    clip_rect,
+                    primitive,
+                });
+            }
+        }
+    }
+    split
+}
+
 #[test]
 fn test_tessellator() {
     use crate::*;
@@ -2367,6 +2789,325 @@ fn test_tessellator() {
     assert_eq!(primitives.len(), 2);
 }
 
+#[test]
+fn test_callback_order() {
+    use crate::*;
+
+    let rect = Rect::from_min_max(pos2(0.0, 0.0), pos2(10.0, 10.0));
+    let callback = PaintCallback::new(rect, std::sync::Arc::new(())).with_user_data(42_u32);
+    let shapes = [
+        Shape::rect_filled(rect, 0.0, Color32::RED),
+        callback.into(),
+        Shape::rect_filled(rect, 0.0, Color32::BLUE),
+    ];
+    let clipped_shapes = shapes
+        .into_iter()
+        .map(|shape| ClippedShape {
+            clip_rect: rect,
+            shape,
+        })
+        .collect();
+
+    let primitives = Tessellator::new(1.0, Default::default(), [1024, 1024], vec![])
+        .tessellate_shapes(clipped_shapes);
+
+    // The callback must not be batched with the shapes around it:
+    assert_eq!(primitives.len(), 3);
+    assert!(matches!(primitives[0].primitive, Primitive::Mesh(_)));
+    let Primitive::Callback(callback) = &primitives[1].primitive else {
+        panic!("Expected the callback in the middle");
+    };
+    assert_eq!(callback.user_data::<u32>(), Some(&42));
+    assert!(matches!(primitives[2].primitive, Primitive::Mesh(_)));
+}
+
+#[test]
+fn test_vertex_budget() {
+    use crate::*;
+
+    let curves: Vec<ClippedShape> = (0..100)
+        .map(|i| {
+            let y = i as f32;
+            ClippedShape {
+                clip_rect: Rect::EVERYTHING,
+                shape: CubicBezierShape::from_points_stroke(
+                    [
+                        pos2(0.0, y),
+                        pos2(300.0, y + 200.0),
+                        pos2(600.0, y - 200.0),
+                        pos2(900.0, y),
+                    ],
+                    false,
+                    Color32::TRANSPARENT,
+                    Stroke::new(1.0, Color32::WHITE),
+                )
+                .into(),
+            }
+        })
+        .collect();
+
+    let num_vertices = |options: TessellationOptions| {
+        let mut tessellator = Tessellator::new(1.0, options, [1024, 1024], vec![]);
+        let primitives = tessellator.tessellate_shapes(curves.clone());
+        let num_vertices: usize = primitives
+            .iter()
+            .map(|p| match &p.primitive {
+                Primitive::Mesh(mesh) => mesh.vertices.len(),
+                Primitive::Callback(_)
+                | Primitive::Instanced(_)
+                | Primitive::Hdr(_)
+                | Primitive::Composite(_) => 0,
+            })
+            .sum();
+        (num_vertices, tessellator.num_coarsened_curves())
+    };
+
+    let (unlimited, coarsened) = num_vertices(Default::default());
+    assert_eq!(coarsened, 0);
+
+    let (limited, coarsened) = num_vertices(TessellationOptions {
+        vertex_budget: Some(unlimited / 10),
+        ..Default::default()
+    });
+    assert!(0 < coarsened);
+    assert!(limited < unlimited / 2, "{limited} vs {unlimited}");
+}
+
+#[test]
+fn test_split_large_meshes() {
+    use crate::*;
+
+    // A filled circle is a triangle fan, so every triangle uses the first vertex:
+    let points: Vec<Pos2> = (0..100_000)
+        .map(|i| {
+            pos2(500.0, 500.0) + 400.0 * Vec2::angled(i as f32 * std::f32::consts::TAU / 100_000.0)
+        })
+        .collect();
+    let shape = ClippedShape {
+        clip_rect: Rect::EVERYTHING,
+        shape: Shape::convex_polygon(points, Color32::WHITE, Stroke::NONE),
+    };
+
+    let tessellate = |options: TessellationOptions| {
+        let primitives = Tessellator::new(1.0, options, [1024, 1024], vec![])
+            .tessellate_shapes(vec![shape.clone()]);
+        primitives
+            .into_iter()
+            .map(|p| match p.primitive {
+                Primitive::Mesh(mesh) => mesh,
+                Primitive::Callback(_)
+                | Primitive::Instanced(_)
+                | Primitive::Hdr(_)
+                | Primitive::Composite(_) => panic!("Expected only meshes"),
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let meshes = tessellate(TessellationOptions {
+        u32_indices: true,
+        ..Default::default()
+    });
+    assert_eq!(meshes.len(), 1);
+    let num_indices = meshes[0].indices.len();
+
+    let meshes = tessellate(Default::default());
+    assert!(1 < meshes.len());
+    for mesh in &meshes {
+        assert!(u16::try_from(mesh.vertices.len()).is_ok());
+        assert!(mesh.is_valid());
+    }
+    assert_eq!(
+        meshes.iter().map(|m| m.indices.len()).sum::<usize>(),
+        num_indices
+    );
+}
+
+#[test]
+fn test_instancing() {
+    use crate::{emath::TSTransform, *};
+
+    let instances: Vec<TSTransform> = (0..10)
+        .map(|i| TSTransform::from_translation(vec2(20.0 * i as f32, 0.0)))
+        .collect();
+    let shape = ClippedShape {
+        clip_rect: Rect::EVERYTHING,
+        shape: Shape::instanced(
+            Shape::rect_filled(
+                Rect::from_min_size(Pos2::ZERO, vec2(10.0, 10.0)),
+                0.0,
+                Color32::RED,
+            ),
+            instances,
+        ),
+    };
+
+    let tessellate = |options: TessellationOptions| {
+        Tessellator::new(1.0, options, [1024, 1024], vec![]).tessellate_shapes(vec![shape.clone()])
+    };
+
+    let primitives = tessellate(TessellationOptions {
+        instancing: true,
+        ..Default::default()
+    });
+    assert_eq!(primitives.len(), 1);
+    let Primitive::Instanced(instanced) = &primitives[0].primitive else {
+        panic!("Expected an instanced mesh");
+    };
+    assert_eq!(instanced.instances.len(), 10);
+
+    let primitives = tessellate(Default::default());
+    assert_eq!(primitives.len(), 1);
+    let Primitive::Mesh(mesh) = &primitives[0].primitive else {
+        panic!("Expected a mesh");
+    };
+    assert_eq!(mesh.vertices.len(), 10 * instanced.mesh.vertices.len());
+    assert_eq!(mesh, &instanced.to_mesh());
+}
+
+#[test]
+fn test_hdr() {
+    use crate::*;
+
+    let shape = ClippedShape {
+        clip_rect: Rect::EVERYTHING,
+        shape: Shape::hdr(
+            Shape::rect_filled(
+                Rect::from_min_size(Pos2::ZERO, vec2(10.0, 10.0)),
+                0.0,
+                Color32::RED,
+            ),
+            4.0,
+        ),
+    };
+
+    let tessellate = |options: TessellationOptions| {
+        Tessellator::new(1.0, options, [1024, 1024], vec![]).tessellate_shapes(vec![shape.clone()])
+    };
+
+    let primitives = tessellate(TessellationOptions {
+        hdr: true,
+        ..Default::default()
+    });
+    assert_eq!(primitives.len(), 1);
+    let Primitive::Hdr(hdr) = &primitives[0].primitive else {
+        panic!("Expected an HDR mesh");
+    };
+    assert_eq!(hdr.intensity, 4.0);
+
+    let primitives = tessellate(Default::default());
+    assert_eq!(primitives.len(), 1);
+    let Primitive::Mesh(mesh) = &primitives[0].primitive else {
+        panic!("Expected a mesh");
+    };
+    assert_eq!(mesh, &hdr.mesh);
+}
+
+#[test]
+fn test_composite() {
+    use crate::*;
+
+    let rect = Rect::from_min_size(Pos2::ZERO, vec2(10.0, 10.0));
+    let shapes = vec![
+        ClippedShape {
+            clip_rect: Rect::EVERYTHING,
+            shape: Shape::rect_filled(rect, 0.0, Color32::RED),
+        },
+        ClippedShape {
+            clip_rect: rect.shrink(2.0),
+            shape: Shape::rect_filled(rect, 0.0, Color32::BLUE),
+        },
+    ];
+    let shape = ClippedShape {
+        clip_rect: Rect::EVERYTHING,
+        shape: CompositeShape::new(shapes, 0.5).into(),
+    };
+
+    let tessellate = |options: TessellationOptions| {
+        Tessellator::new(1.0, options, [1024, 1024], vec![]).tessellate_shapes(vec![shape.clone()])
+    };
+
+    let primitives = tessellate(TessellationOptions {
+        composite: true,
+        ..Default::default()
+    });
+    assert_eq!(primitives.len(), 4, "Begin, one mesh per clip rect, End");
+    assert!(matches!(
+        primitives[0].primitive,
+        Primitive::Composite(CompositeBoundary::Begin)
+    ));
+    assert!(matches!(
+        primitives[3].primitive,
+        Primitive::Composite(CompositeBoundary::End { opacity: 0.5 })
+    ));
+    assert_eq!(primitives[3].clip_rect, rect);
+    let Primitive::Mesh(mesh) = &primitives[1].primitive else {
+        panic!("Expected a mesh");
+    };
+    let max_alpha = |mesh: &Mesh| mesh.vertices.iter().map(|v| v.color.a()).max();
+    assert_eq!(max_alpha(mesh), Some(255));
+
+    // Without backend support, the shapes are faded one by one:
+    let primitives = tessellate(Default::default());
+    assert_eq!(primitives.len(), 2);
+    let Primitive::Mesh(mesh) = &primitives[0].primitive else {
+        panic!("Expected a mesh");
+    };
+    assert_eq!(max_alpha(mesh), Some(Color32::RED.gamma_multiply(0.5).a()));
+}
+
+#[test]
+fn test_occlusion_culling() {
+    use crate::*;
+
+    let window = Rect::from_min_size(Pos2::ZERO, vec2(100.0, 100.0));
+    let clipped = |shape: Shape| ClippedShape {
+        clip_rect: Rect::EVERYTHING,
+        shape,
+    };
+    let shapes = vec![
+        clipped(Shape::circle_filled(pos2(50.0, 50.0), 10.0, Color32::RED)), // covered
+        clipped(Shape::circle_filled(pos2(0.0, 50.0), 10.0, Color32::RED)),  // sticks out
+        clipped(Shape::rect_filled(window, 4.0, Color32::GRAY)),
+        clipped(Shape::circle_filled(pos2(50.0, 50.0), 10.0, Color32::BLUE)), // on top
+    ];
+
+    let mut tessellator = Tessellator::new(
+        1.0,
+        TessellationOptions {
+            occlusion_culling: true,
+            ..Default::default()
+        },
+        [1024, 1024],
+        vec![],
+    );
+    let primitives = tessellator.tessellate_shapes(shapes.clone());
+    assert_eq!(tessellator.num_occluded_shapes(), 1);
+    let Primitive::Mesh(mesh) = &primitives[0].primitive else {
+        panic!("Expected a mesh");
+    };
+    let is_covered_red = |v: &Vertex| v.color == Color32::RED && 20.0 < v.pos.x;
+    assert!(!mesh.vertices.iter().any(is_covered_red));
+
+    // A translucent rectangle hides nothing:
+    let mut shapes = shapes;
+    shapes[2] = clipped(Shape::rect_filled(
+        window,
+        4.0,
+        Color32::GRAY.gamma_multiply(0.5),
+    ));
+    let mut tessellator = Tessellator::new(
+        1.0,
+        TessellationOptions {
+            occlusion_culling: true,
+            ..Default::default()
+        },
+        [1024, 1024],
+        vec![],
+    );
+    tessellator.tessellate_shapes(shapes);
+    assert_eq!(tessellator.num_occluded_shapes(), 0);
+}
+
 #[test]
 fn path_bounding_box() {
     use crate::*;
@@ -1,4 +1,4 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::sync::Arc;
 
 use emath::{GuiRounding as _, Vec2, vec2};
@@ -335,6 +335,9 @@ pub struct Font {
     pixels_per_point: f32,
     row_height: f32,
     glyph_info_cache: ahash::HashMap<char, (FontIndex, GlyphInfo)>,
+
+    /// Characters not found in any of the fonts, see [`Self::take_missing_chars`].
+    missing_chars: BTreeSet<char>,
 }
 
 impl Font {
@@ -347,6 +350,7 @@ impl Font {
                 pixels_per_point: 1.0,
                 row_height: 0.0,
                 glyph_info_cache: Default::default(),
+                missing_chars: Default::default(),
             };
         }
 
@@ -360,6 +364,7 @@ impl Font {
             pixels_per_point,
             row_height,
             glyph_info_cache: Default::default(),
+            missing_chars: Default::default(),
         };
 
         const PRIMARY_REPLACEMENT_CHAR: char = '◻'; // white medium square
@@ -440,6 +445,12 @@ impl Font {
         self.glyph_info(c) != self.replacement_glyph // TODO(emilk): this is a false negative if the user asks about the replacement character itself 🤦‍♂️
     }
 
+    /// The characters that were asked for since the last call,
+    /// but are not in any of the fonts, and so are shown as the replacement character.
+    pub fn take_missing_chars(&mut self) -> BTreeSet<char> {
+        std::mem::take(&mut self.missing_chars)
+    }
+
     /// Can we display all the glyphs in this text?
     pub fn has_glyphs(&mut self, s: &str) -> bool {
         s.chars().all(|c| self.has_glyph(c))
@@ -452,6 +463,9 @@ impl Font {
         }
 
         let font_index_glyph_info = self.glyph_info_no_cache_or_fallback(c);
+        if font_index_glyph_info.is_none() && !c.is_control() {
+            self.missing_chars.insert(c);
+        }
         let font_index_glyph_info = font_index_glyph_info.unwrap_or(self.replacement_glyph);
         self.glyph_info_cache.insert(c, font_index_glyph_info);
         font_index_glyph_info
@@ -1,4 +1,7 @@
-use std::{collections::BTreeMap, sync::Arc};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+};
 
 use crate::{
     AlphaFromCoverage, TextureAtlas,
@@ -441,6 +444,7 @@ impl Fonts {
                 definitions,
             ),
             galley_cache: Default::default(),
+            atlas_generation: 0,
         };
         Self(Arc::new(Mutex::new(fonts_and_cache)))
     }
@@ -481,12 +485,45 @@ impl Fonts {
                     definitions,
                 ),
                 galley_cache: Default::default(),
+                atlas_generation: fonts_and_cache.atlas_generation + 1,
             };
         }
 
         fonts_and_cache.galley_cache.flush_cache();
     }
 
+    /// Forget all font sizes and their rasterized glyphs, e.g. to free memory.
+    ///
+    /// The ones still in use are rasterized again when needed,
+    /// and the whole font texture is then sent again in [`Self::font_image_delta`].
+    /// This changes [`Self::atlas_generation`], since glyphs end up in new places in the atlas.
+    ///
+    /// Returns the number of bytes freed in the font atlas.
+    pub fn clear_font_sizes(&self) -> usize {
+        let mut fonts_and_cache = self.0.lock();
+
+        let atlas_bytes = |fonts: &FontsImpl| {
+            let [width, height] = fonts.atlas.lock().size();
+            width * height * std::mem::size_of::<crate::Color32>()
+        };
+        let bytes_before = atlas_bytes(&fonts_and_cache.fonts);
+
+        let fonts = &fonts_and_cache.fonts;
+        let text_alpha_from_coverage = fonts.atlas.lock().text_alpha_from_coverage;
+        *fonts_and_cache = FontsAndCache {
+            fonts: FontsImpl::new(
+                fonts.pixels_per_point,
+                fonts.max_texture_side,
+                text_alpha_from_coverage,
+                fonts.definitions.clone(),
+            ),
+            galley_cache: Default::default(),
+            atlas_generation: fonts_and_cache.atlas_generation + 1,
+        };
+
+        bytes_before.saturating_sub(atlas_bytes(&fonts_and_cache.fonts))
+    }
+
     /// Call at the end of each frame (before painting) to get the change to the font texture since last call.
     pub fn font_image_delta(&self) -> Option<crate::ImageDelta> {
         self.lock().fonts.atlas.lock().take_delta()
@@ -527,6 +564,15 @@ impl Fonts {
         self.lock().fonts.atlas.lock().size()
     }
 
+    /// Changes whenever the font atlas is recreated, e.g. when it is full.
+    ///
+    /// The glyphs of [`Galley`]s laid out before that point to the wrong places in the new atlas,
+    /// so anything that keeps galleys (or shapes containing them) between passes
+    /// must lay them out again when this changes.
+    pub fn atlas_generation(&self) -> u64 {
+        self.lock().atlas_generation
+    }
+
     /// Width of this character in points.
     #[inline]
     pub fn glyph_width(&self, font_id: &FontId, c: char) -> f32 {
@@ -579,6 +625,17 @@ impl Fonts {
         self.lock().galley_cache.num_galleys_in_cache()
     }
 
+    /// Approximate number of heap bytes used by the galleys in the layout cache.
+    pub fn galley_cache_byte_size(&self) -> usize {
+        self.lock().galley_cache.approximate_byte_size()
+    }
+
+    /// The characters that were laid out since the last call, but are not in any font,
+    /// and so are shown as the replacement character.
+    pub fn take_missing_chars(&self) -> BTreeSet<char> {
+        self.lock().fonts.take_missing_chars()
+    }
+
     /// How full is the font atlas?
     ///
     /// This increases as new fonts and/or glyphs are used,
@@ -632,6 +689,9 @@ impl Fonts {
 pub struct FontsAndCache {
     pub fonts: FontsImpl,
     galley_cache: GalleyCache,
+
+    /// See [`Fonts::atlas_generation`].
+    atlas_generation: u64,
 }
 
 impl FontsAndCache {
@@ -721,6 +781,15 @@ impl FontsImpl {
             })
     }
 
+    /// The characters that were laid out since the last call, but are not in any font.
+    pub fn take_missing_chars(&mut self) -> BTreeSet<char> {
+        let mut missing_chars = BTreeSet::new();
+        for font in self.sized_family.values_mut() {
+            missing_chars.append(&mut font.take_missing_chars());
+        }
+        missing_chars
+    }
+
     /// Width of this character in points.
     fn glyph_width(&mut self, font_id: &FontId, c: char) -> f32 {
         self.font(font_id).glyph_width(c)
@@ -976,6 +1045,15 @@ impl GalleyCache {
         self.cache.len()
     }
 
+    pub fn approximate_byte_size(&self) -> usize {
+        self.cache.capacity() * std::mem::size_of::<(u64, CachedGalley)>()
+            + self
+                .cache
+                .values()
+                .map(|cached| crate::stats::AllocInfo::from_galley(&cached.galley).num_bytes())
+                .sum::<usize>()
+    }
+
     /// Must be called once per frame to clear the [`Galley`] cache.
     pub fn flush_cache(&mut self) {
         let current_generation = self.generation;
@@ -4,7 +4,9 @@ use emath::{Align, GuiRounding as _, NumExt as _, Pos2, Rect, Vec2, pos2, vec2};
 
 use crate::{Color32, Mesh, Stroke, Vertex, stroke::PathStroke, text::font::Font};
 
-use super::{FontsImpl, Galley, Glyph, LayoutJob, LayoutSection, PlacedRow, Row, RowVisuals};
+use super::{
+    FontsImpl, Galley, Glyph, LayoutJob, LayoutSection, PlacedRow, Row, RowVisuals, TextFormat,
+};
 
 // ----------------------------------------------------------------------------
 
@@ -730,6 +732,8 @@ struct FormatSummary {
     any_background: bool,
     any_underline: bool,
     any_strikethrough: bool,
+    any_outline: bool,
+    any_shadow: bool,
 }
 
 fn format_summary(job: &LayoutJob) -> FormatSummary {
@@ -738,6 +742,8 @@ fn format_summary(job: &LayoutJob) -> FormatSummary {
         format_summary.any_background |= section.format.background != Color32::TRANSPARENT;
         format_summary.any_underline |= section.format.underline != Stroke::NONE;
         format_summary.any_strikethrough |= section.format.strikethrough != Stroke::NONE;
+        format_summary.any_outline |= !section.format.outline.is_empty();
+        format_summary.any_shadow |= !section.format.shadow.is_empty();
     }
     format_summary
 }
@@ -761,9 +767,29 @@ fn tessellate_row(
         add_row_backgrounds(point_scale, job, row, &mut mesh);
     }
 
+    // Shadows and outlines go behind the glyphs, and are not part of `glyph_vertex_range`,
+    // so that they keep their color when the text color is overridden.
+    if format_summary.any_shadow {
+        tessellate_glyphs(point_scale, job, row, &mut mesh, |format| {
+            let shadow = format.shadow;
+            (!shadow.is_empty()).then_some((shadow.offset, shadow.color))
+        });
+    }
+
+    if format_summary.any_outline {
+        for dir in OUTLINE_DIRECTIONS {
+            tessellate_glyphs(point_scale, job, row, &mut mesh, |format| {
+                let outline = format.outline;
+                (!outline.is_empty()).then_some((outline.width * dir, outline.color))
+            });
+        }
+    }
+
     let glyph_index_start = mesh.indices.len();
     let glyph_vertex_start = mesh.vertices.len();
-    tessellate_glyphs(point_scale, job, row, &mut mesh);
+    tessellate_glyphs(point_scale, job, row, &mut mesh, |format| {
+        Some((Vec2::ZERO, format.color))
+    });
     let glyph_vertex_end = mesh.vertices.len();
 
     if format_summary.any_underline {
@@ -841,11 +867,40 @@ fn add_row_backgrounds(point_scale: PointScale, job: &LayoutJob, row: &Row, mesh
     end_run(run_start.take(), last_rect.right());
 }
 
-fn tessellate_glyphs(point_scale: PointScale, job: &LayoutJob, row: &Row, mesh: &mut Mesh) {
+/// The outline is made by painting the glyphs offset in each of these directions.
+const OUTLINE_DIRECTIONS: [Vec2; 8] = {
+    const D: f32 = std::f32::consts::FRAC_1_SQRT_2;
+    [
+        vec2(-1.0, 0.0),
+        vec2(1.0, 0.0),
+        vec2(0.0, -1.0),
+        vec2(0.0, 1.0),
+        vec2(-D, -D),
+        vec2(D, -D),
+        vec2(-D, D),
+        vec2(D, D),
+    ]
+};
+
+/// Paint the glyphs of a row, with an offset and color decided by a callback.
+///
+/// Glyphs for which the callback returns `None` are skipped.
+fn tessellate_glyphs(
+    point_scale: PointScale,
+    job: &LayoutJob,
+    row: &Row,
+    mesh: &mut Mesh,
+    offset_and_color: impl Fn(&TextFormat) -> Option<(Vec2, Color32)>,
+) {
     for glyph in &row.glyphs {
         let uv_rect = glyph.uv_rect;
         if !uv_rect.is_nothing() {
-            let mut left_top = glyph.pos + uv_rect.offset;
+            let format = &job.sections[glyph.section_index as usize].format;
+            let Some((offset, color)) = offset_and_color(format) else {
+                continue;
+            };
+
+            let mut left_top = glyph.pos + uv_rect.offset + offset;
             left_top.x = point_scale.round_to_pixel(left_top.x);
             left_top.y = point_scale.round_to_pixel(left_top.y);
 
@@ -855,10 +910,6 @@ fn tessellate_glyphs(point_scale: PointScale, job: &LayoutJob, row: &Row, mesh:
                 pos2(uv_rect.max[0] as f32, uv_rect.max[1] as f32),
             );
 
-            let format = &job.sections[glyph.section_index as usize].format;
-
-            let color = format.color;
-
             if format.italics {
                 let idx = mesh.vertices.len() as u32;
                 mesh.add_triangle(idx, idx + 1, idx + 2);
@@ -1088,6 +1139,60 @@ mod tests {
         assert_eq!(galley.rows.len(), 1);
     }
 
+    #[test]
+    fn test_row_rects() {
+        let mut fonts = FontsImpl::new(
+            1.0,
+            1024,
+            AlphaFromCoverage::default(),
+            FontDefinitions::default(),
+        );
+        let layout_job = LayoutJob::single_section("one\ntwo\nthree".into(), TextFormat::default());
+        let galley = layout(&mut fonts, layout_job.into());
+        assert_eq!(galley.num_rows(), 3);
+        let rects: Vec<Rect> = galley.row_rects().collect();
+        assert!(rects[0].bottom() <= rects[1].top());
+        assert!(rects[1].width() < rects[2].width());
+        assert_eq!(rects[2].bottom(), galley.rect.bottom());
+    }
+
+    #[test]
+    fn test_outline_and_shadow() {
+        let mut fonts = FontsImpl::new(
+            1.0,
+            1024,
+            AlphaFromCoverage::default(),
+            FontDefinitions::default(),
+        );
+        let plain = layout(
+            &mut fonts,
+            LayoutJob::single_section("W".into(), TextFormat::default()).into(),
+        );
+        let styled = layout(
+            &mut fonts,
+            LayoutJob::single_section(
+                "W".into(),
+                TextFormat {
+                    outline: Stroke::new(1.0, Color32::BLACK),
+                    shadow: TextShadow::new(vec2(2.0, 2.0), Color32::BLACK),
+                    ..Default::default()
+                },
+            )
+            .into(),
+        );
+
+        let plain = &plain.rows[0].visuals;
+        let styled = &styled.rows[0].visuals;
+        // One shadow and eight outline copies behind the glyph:
+        assert_eq!(styled.mesh.vertices.len(), 10 * plain.mesh.vertices.len());
+        // …but only the glyph itself can have its color overridden:
+        assert_eq!(
+            styled.glyph_vertex_range.len(),
+            plain.glyph_vertex_range.len()
+        );
+        assert!(plain.mesh_bounds.max.x < styled.mesh_bounds.max.x);
+    }
+
     #[test]
     fn test_truncate_with_newline() {
         // No matter where we wrap, we should be appending the newline character.
@@ -298,6 +298,19 @@ pub struct TextFormat {
 
     pub strikethrough: Stroke,
 
+    /// Outline the glyphs with this color and width, e.g. to keep text readable on top of images.
+    ///
+    /// The outline is made by painting the glyphs several times,
+    /// so it looks best for widths of up to a couple of pixels.
+    ///
+    /// Default: [`Stroke::NONE`].
+    pub outline: Stroke,
+
+    /// Paint an offset copy of the glyphs behind them.
+    ///
+    /// Default: [`TextShadow::NONE`].
+    pub shadow: TextShadow,
+
     /// If you use a small font and [`Align::TOP`] you
     /// can get the effect of raised text.
     ///
@@ -323,6 +336,8 @@ impl Default for TextFormat {
             italics: false,
             underline: Stroke::NONE,
             strikethrough: Stroke::NONE,
+            outline: Stroke::NONE,
+            shadow: TextShadow::NONE,
             valign: Align::BOTTOM,
         }
     }
@@ -341,6 +356,8 @@ impl std::hash::Hash for TextFormat {
             italics,
             underline,
             strikethrough,
+            outline,
+            shadow,
             valign,
         } = self;
         font_id.hash(state);
@@ -354,6 +371,8 @@ impl std::hash::Hash for TextFormat {
         italics.hash(state);
         underline.hash(state);
         strikethrough.hash(state);
+        outline.hash(state);
+        shadow.hash(state);
         valign.hash(state);
     }
 }
@@ -369,6 +388,47 @@ impl TextFormat {
     }
 }
 
+/// A hard drop shadow behind text, see [`TextFormat::shadow`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct TextShadow {
+    /// How far to move the shadow from the text, in points.
+    pub offset: Vec2,
+
+    pub color: Color32,
+}
+
+impl TextShadow {
+    pub const NONE: Self = Self {
+        offset: Vec2::ZERO,
+        color: Color32::TRANSPARENT,
+    };
+
+    #[inline]
+    pub fn new(offset: impl Into<Vec2>, color: impl Into<Color32>) -> Self {
+        Self {
+            offset: offset.into(),
+            color: color.into(),
+        }
+    }
+
+    /// True if the shadow would not be visible.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.color == Color32::TRANSPARENT
+    }
+}
+
+impl std::hash::Hash for TextShadow {
+    #[inline]
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        let Self { offset, color } = self;
+        OrderedFloat(offset.x).hash(state);
+        OrderedFloat(offset.y).hash(state);
+        color.hash(state);
+    }
+}
+
 // ----------------------------------------------------------------------------
 
 /// How to wrap and elide text.
@@ -797,6 +857,21 @@ impl Galley {
         self.rect.size()
     }
 
+    /// The number of rows, i.e. lines after wrapping.
+    ///
+    /// This is at least 1, even for empty text.
+    #[inline]
+    pub fn num_rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// The bounding rectangle of each row, relative to the galley, from top to bottom.
+    ///
+    /// See also [`PlacedRow::rect`].
+    pub fn row_rects(&self) -> impl ExactSizeIterator<Item = Rect> + '_ {
+        self.rows.iter().map(PlacedRow::rect)
+    }
+
     /// This is the size that a non-wrapped, non-truncated, non-justified version of the text
     /// would have.
     ///
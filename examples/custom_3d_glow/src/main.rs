@@ -77,12 +77,12 @@ impl MyApp {
         let angle = self.angle;
         let rotating_triangle = self.rotating_triangle.clone();
 
-        let callback = egui::PaintCallback {
+        let callback = egui::PaintCallback::new(
             rect,
-            callback: std::sync::Arc::new(egui_glow::CallbackFn::new(move |_info, painter| {
+            std::sync::Arc::new(egui_glow::CallbackFn::new(move |_info, painter| {
                 rotating_triangle.lock().paint(painter.gl(), angle);
             })),
-        };
+        );
         ui.painter().add(callback);
     }
 }
@@ -27,19 +27,19 @@ fn setup_custom_style(ctx: &egui::Context) {
 fn use_light_green_accent(style: &mut Style) {
     style.visuals.hyperlink_color = Color32::from_rgb(18, 180, 85);
     style.visuals.text_cursor.stroke.color = Color32::from_rgb(28, 92, 48);
-    style.visuals.selection = Selection {
-        bg_fill: Color32::from_rgb(157, 218, 169),
-        stroke: Stroke::new(1.0, Color32::from_rgb(28, 92, 48)),
-    };
+    style.visuals.selection = Selection::new(
+        Color32::from_rgb(157, 218, 169),
+        Stroke::new(1.0, Color32::from_rgb(28, 92, 48)),
+    );
 }
 
 fn use_dark_purple_accent(style: &mut Style) {
     style.visuals.hyperlink_color = Color32::from_rgb(202, 135, 227);
     style.visuals.text_cursor.stroke.color = Color32::from_rgb(234, 208, 244);
-    style.visuals.selection = Selection {
-        bg_fill: Color32::from_rgb(105, 67, 119),
-        stroke: Stroke::new(1.0, Color32::from_rgb(234, 208, 244)),
-    };
+    style.visuals.selection = Selection::new(
+        Color32::from_rgb(105, 67, 119),
+        Stroke::new(1.0, Color32::from_rgb(234, 208, 244)),
+    );
 }
 
 struct MyApp {
@@ -216,14 +216,11 @@ fn test_widget_layout(name: &str, mut w: impl FnMut(&mut Ui) -> Response) -> Sna
                     row.main_dir, row.main_align, row.main_justify
                 ));
                 for col in &cols {
-                    let layout = Layout {
-                        main_dir: row.main_dir,
-                        main_align: row.main_align,
-                        main_justify: row.main_justify,
-                        cross_align: col.cross_align,
-                        cross_justify: col.cross_justify,
-                        main_wrap: false,
-                    };
+                    let layout =
+                        Layout::from_main_dir_and_cross_align(row.main_dir, col.cross_align)
+                            .with_main_align(row.main_align)
+                            .with_main_justify(row.main_justify)
+                            .with_cross_justify(col.cross_justify);
 
                     let (_, rect) = ui.allocate_space(test_size);
 